@@ -0,0 +1,21 @@
+use crate::AccessLevel;
+
+/// Numeric rank of an access level. Each level includes every level
+/// ranked below it: `None < Read < Write < Full`.
+pub fn level_rank(level: &AccessLevel) -> u32 {
+    match level {
+        AccessLevel::None => 0,
+        AccessLevel::Read => 1,
+        AccessLevel::Write => 2,
+        AccessLevel::Full => 3,
+    }
+}
+
+/// Returns true if holding `actual` is enough for an operation that
+/// needs `required`.
+///
+/// All access-level comparisons should go through this helper rather than
+/// matching on individual variants, so the ordering lives in one place.
+pub fn level_satisfies(required: &AccessLevel, actual: &AccessLevel) -> bool {
+    level_rank(actual) >= level_rank(required)
+}
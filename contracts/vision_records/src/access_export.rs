@@ -0,0 +1,73 @@
+use soroban_sdk::{contracttype, Address, Vec};
+
+use crate::approval::PendingGrant;
+use crate::patient_profile::OptionalEmergencyContact;
+use crate::{AccessGrantDetail, ConsentGrant};
+
+/// Maximum number of grantee list entries one export or grant page covers.
+pub const MAX_EXPORT_GRANTS: u32 = 20;
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A patient's access-control configuration, for backing it up before
+/// moving to a new wallet. Records themselves are not included.
+///
+/// Grants and consents cover the first `MAX_EXPORT_GRANTS` entries of the
+/// patient's grantee list; when there are more, `next_grant` is set and
+/// the rest come from `export_access_grants_page`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessStateExport {
+    pub patient: Address,
+    pub exported_at: u64,
+    /// Unexpired patient-wide grants, with purpose and creator.
+    pub grants: Vec<AccessGrantDetail>,
+    /// Unrevoked, unexpired consents held by grantees listed in `grants`'
+    /// range of the grantee list.
+    pub consents: Vec<ConsentGrant>,
+    /// Grants requested on the patient's behalf still awaiting approval.
+    pub pending_grants: Vec<PendingGrant>,
+    /// Addresses currently holding `ManageAccess` by the patient's
+    /// delegation.
+    pub guardians: Vec<Address>,
+    pub emergency_contact: OptionalEmergencyContact,
+    /// Whether the patient has frozen access. When not, the two freeze
+    /// fields below are `false` and 0.
+    pub frozen: bool,
+    /// Whether the freeze lets active emergency access through.
+    pub freeze_allows_emergency: bool,
+    pub frozen_at: u64,
+    /// Whether grants made on the patient's behalf need their approval.
+    pub approval_required: bool,
+    /// Grantee list position to pass to `export_access_grants_page`, or
+    /// `None` when `grants` and `consents` are complete.
+    pub next_grant: Option<u32>,
+}
+
+/// A further page of grants and consents for an `AccessStateExport`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessGrantPage {
+    pub grants: Vec<AccessGrantDetail>,
+    pub consents: Vec<ConsentGrant>,
+    /// `None` once the grantee list is exhausted.
+    pub next: Option<u32>,
+}
+
+/// An entry of an export that the patient's current state lacks and
+/// would need re-creating.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AccessStateGap {
+    /// No unexpired grant to this grantee at the exported level.
+    Grant(Address),
+    /// No active consent for this grantee.
+    Consent(Address),
+    /// No pending grant for this grantee; the requester must ask again.
+    PendingGrant(Address),
+    /// This address no longer holds `ManageAccess` for the patient.
+    Guardian(Address),
+    EmergencyContact,
+    Freeze,
+    ApprovalRequired,
+}
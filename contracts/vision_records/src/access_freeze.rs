@@ -0,0 +1,78 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::emergency;
+
+// ── Storage keys ──────────────────────────────────────────────
+const ACCESS_FREEZE: Symbol = symbol_short!("ACC_FRZ");
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a patient's access freeze.
+fn extend_ttl_freeze_key(env: &Env, key: &(Symbol, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A patient's freeze on all access to their records by anyone else.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessFreeze {
+    pub patient: Address,
+    /// Whether callers holding active emergency access still get through.
+    pub allow_emergency: bool,
+    pub frozen_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// The freeze sits in front of every other access rule and deletes
+// nothing, so lifting it brings back grants, sessions and consents
+// exactly as they were, expiries included.
+
+pub fn freeze_key(patient: &Address) -> (Symbol, Address) {
+    (ACCESS_FREEZE, patient.clone())
+}
+
+pub fn get(env: &Env, patient: &Address) -> Option<AccessFreeze> {
+    env.storage().persistent().get(&freeze_key(patient))
+}
+
+pub fn is_frozen(env: &Env, patient: &Address) -> bool {
+    env.storage().persistent().has(&freeze_key(patient))
+}
+
+/// Freezes the patient, replacing any earlier freeze.
+pub fn set(env: &Env, freeze: &AccessFreeze) {
+    let key = freeze_key(&freeze.patient);
+    env.storage().persistent().set(&key, freeze);
+    extend_ttl_freeze_key(env, &key);
+}
+
+/// Lifts the patient's freeze. Returns whether one was set.
+pub fn remove(env: &Env, patient: &Address) -> bool {
+    let key = freeze_key(patient);
+    let frozen = env.storage().persistent().has(&key);
+    if frozen {
+        env.storage().persistent().remove(&key);
+    }
+    frozen
+}
+
+/// Whether the patient's freeze shuts `caller` out. The patient is never
+/// shut out of their own records.
+pub fn blocks(env: &Env, patient: &Address, caller: &Address) -> bool {
+    if caller == patient {
+        return false;
+    }
+    match get(env, patient) {
+        Some(freeze) => {
+            !(freeze.allow_emergency
+                && emergency::has_active_emergency_access(env, patient, caller).is_some())
+        }
+        None => false,
+    }
+}
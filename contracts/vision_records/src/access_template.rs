@@ -0,0 +1,108 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::AccessLevel;
+
+// ── Storage keys ──────────────────────────────────────────────
+const ACCESS_TEMPLATE: Symbol = symbol_short!("ACC_TPL");
+const ACCESS_TEMPLATE_NAMES: Symbol = symbol_short!("ACC_TPLS");
+
+/// Maximum number of entries a single template may hold.
+pub const MAX_TEMPLATE_ENTRIES: u32 = 5;
+
+/// Maximum number of templates a single owner may keep.
+pub const MAX_TEMPLATES_PER_OWNER: u32 = 20;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a template key.
+fn extend_ttl_template_key(env: &Env, key: &(Symbol, Address, Symbol)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+/// Extends the time-to-live (TTL) for an owner's template name list.
+fn extend_ttl_names_key(env: &Env, key: &(Symbol, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// One grant in a template: the level and how long it lasts.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessTemplateEntry {
+    pub level: AccessLevel,
+    pub duration_seconds: u64,
+}
+
+/// A reusable set of grants a patient can apply in one call.
+///
+/// A template with a single entry applies that entry to every grantee.
+/// A template with several entries is zipped with the grantee list, which
+/// must then be the same length.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessTemplate {
+    pub owner: Address,
+    pub name: Symbol,
+    pub entries: Vec<AccessTemplateEntry>,
+    pub created_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn template_key(owner: &Address, name: &Symbol) -> (Symbol, Address, Symbol) {
+    (ACCESS_TEMPLATE, owner.clone(), name.clone())
+}
+
+pub fn template_names_key(owner: &Address) -> (Symbol, Address) {
+    (ACCESS_TEMPLATE_NAMES, owner.clone())
+}
+
+pub fn get_template(env: &Env, owner: &Address, name: &Symbol) -> Option<AccessTemplate> {
+    env.storage().persistent().get(&template_key(owner, name))
+}
+
+pub fn get_template_names(env: &Env, owner: &Address) -> Vec<Symbol> {
+    env.storage()
+        .persistent()
+        .get(&template_names_key(owner))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Stores a template, replacing any existing template of the same name.
+pub fn set_template(env: &Env, template: &AccessTemplate) {
+    let key = template_key(&template.owner, &template.name);
+    env.storage().persistent().set(&key, template);
+    extend_ttl_template_key(env, &key);
+
+    let names_key = template_names_key(&template.owner);
+    let mut names = get_template_names(env, &template.owner);
+    if !names.contains(&template.name) {
+        names.push_back(template.name.clone());
+        env.storage().persistent().set(&names_key, &names);
+        extend_ttl_names_key(env, &names_key);
+    }
+}
+
+/// Removes a template. Returns false if it did not exist.
+pub fn remove_template(env: &Env, owner: &Address, name: &Symbol) -> bool {
+    let key = template_key(owner, name);
+    if !env.storage().persistent().has(&key) {
+        return false;
+    }
+    env.storage().persistent().remove(&key);
+
+    let mut names = get_template_names(env, owner);
+    if let Some(index) = names.first_index_of(name) {
+        names.remove(index);
+        env.storage()
+            .persistent()
+            .set(&template_names_key(owner), &names);
+    }
+    true
+}
@@ -0,0 +1,135 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+// ── Storage keys ──────────────────────────────────────────────
+const ACTIVITY_ENTRY: Symbol = symbol_short!("ACT_ENT");
+const ACTIVITY_COUNT: Symbol = symbol_short!("ACT_CNT");
+
+/// Number of most recent entries kept per patient.
+pub const MAX_ACTIVITY_ENTRIES: u32 = 100;
+
+/// Most entries one feed page returns. Each entry is a separate read.
+pub const MAX_FEED_PAGE: u32 = 50;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+// ── Actions ───────────────────────────────────────────────────
+
+pub const RECORD_ADDED: Symbol = symbol_short!("REC_ADD");
+pub const RECORD_UPDATED: Symbol = symbol_short!("REC_UPD");
+pub const RECORD_SUPERSEDED: Symbol = symbol_short!("REC_SUPER");
+pub const ACCESS_GRANTED: Symbol = symbol_short!("ACC_GRT");
+pub const RECORD_ACCESS_GRANTED: Symbol = symbol_short!("REC_GRT");
+pub const ACCESS_REVOKED: Symbol = symbol_short!("ACC_REV");
+/// Grant removed because the grantee's account was closed.
+pub const GRANTEE_CLOSED: Symbol = symbol_short!("GRT_CLOSE");
+
+/// Extends the time-to-live (TTL) for a ring-buffer slot.
+fn extend_ttl_entry_key(env: &Env, key: &(Symbol, Address, u32)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+/// Extends the time-to-live (TTL) for a patient's entry counter.
+fn extend_ttl_count_key(env: &Env, key: &(Symbol, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// One item in a patient's activity feed.
+///
+/// `subject_id` is the record the action concerns, or 0 for actions that
+/// are not tied to a single record, such as patient-wide grants.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActivityEntry {
+    pub action: Symbol,
+    pub actor: Address,
+    pub subject_id: u64,
+    pub timestamp: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// Each patient's feed is a ring buffer of `MAX_ACTIVITY_ENTRIES` slots,
+// each in its own storage entry. The counter holds the number of entries
+// ever logged; entry `n` lives in slot `n % MAX_ACTIVITY_ENTRIES`, so
+// appending overwrites the oldest slot instead of shifting a vector.
+
+pub fn entry_key(patient: &Address, slot: u32) -> (Symbol, Address, u32) {
+    (ACTIVITY_ENTRY, patient.clone(), slot)
+}
+
+pub fn count_key(patient: &Address) -> (Symbol, Address) {
+    (ACTIVITY_COUNT, patient.clone())
+}
+
+/// Number of entries ever logged for `patient`, including evicted ones.
+pub fn total_logged(env: &Env, patient: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&count_key(patient))
+        .unwrap_or(0)
+}
+
+/// Number of entries currently retained for `patient`.
+pub fn retained(env: &Env, patient: &Address) -> u32 {
+    let total = total_logged(env, patient);
+    u32::try_from(total)
+        .unwrap_or(MAX_ACTIVITY_ENTRIES)
+        .min(MAX_ACTIVITY_ENTRIES)
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+fn slot_for(index: u64) -> u32 {
+    (index % u64::from(MAX_ACTIVITY_ENTRIES)) as u32
+}
+
+/// Appends an entry to `patient`'s feed, evicting the oldest one once the
+/// feed is full.
+pub fn log(env: &Env, patient: &Address, action: Symbol, actor: &Address, subject_id: u64) {
+    let total = total_logged(env, patient);
+    let entry = ActivityEntry {
+        action,
+        actor: actor.clone(),
+        subject_id,
+        timestamp: env.ledger().timestamp(),
+    };
+
+    let key = entry_key(patient, slot_for(total));
+    env.storage().persistent().set(&key, &entry);
+    extend_ttl_entry_key(env, &key);
+
+    let counter = count_key(patient);
+    env.storage()
+        .persistent()
+        .set(&counter, &total.saturating_add(1));
+    extend_ttl_count_key(env, &counter);
+}
+
+/// Returns up to `limit` retained entries, at most `MAX_FEED_PAGE`, in
+/// chronological order, starting `offset` entries after the oldest one
+/// still retained.
+pub fn get_feed(env: &Env, patient: &Address, offset: u32, limit: u32) -> Vec<ActivityEntry> {
+    let total = total_logged(env, patient);
+    let kept = retained(env, patient);
+    let oldest = total.saturating_sub(u64::from(kept));
+
+    let mut page = Vec::new(env);
+    let end = offset.saturating_add(limit.min(MAX_FEED_PAGE)).min(kept);
+    for i in offset..end {
+        let index = oldest.saturating_add(u64::from(i));
+        if let Some(entry) = env
+            .storage()
+            .persistent()
+            .get(&entry_key(patient, slot_for(index)))
+        {
+            page.push_back(entry);
+        }
+    }
+    page
+}
@@ -1,5 +1,9 @@
 use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
 
+use crate::counters;
+use crate::errors::ContractError;
+use crate::instance_usage::InstanceKey;
+
 // ── Storage keys ──────────────────────────────────────────────
 pub const APPT_CTR: Symbol = symbol_short!("APPT_CTR");
 const APPT_RECORD: Symbol = symbol_short!("APPT_REC");
@@ -7,6 +11,9 @@ const APPT_PATIENT: Symbol = symbol_short!("APPT_PAT");
 const APPT_PROVIDER: Symbol = symbol_short!("APPT_PROV");
 const APPT_HISTORY: Symbol = symbol_short!("APPT_HIST");
 
+/// Instance-storage keys written here.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] = &[InstanceKey::number(APPT_CTR)];
+
 const TTL_THRESHOLD: u32 = 5184000;
 const TTL_EXTEND_TO: u32 = 10368000;
 
@@ -95,11 +102,8 @@ pub struct AppointmentHistoryEntry {
 // ── Storage Functions ────────────────────────────────────────
 
 /// Increments and returns the next appointment ID
-pub fn increment_appointment_counter(env: &Env) -> u64 {
-    let current: u64 = env.storage().instance().get(&APPT_CTR).unwrap_or(0);
-    let next = current + 1;
-    env.storage().instance().set(&APPT_CTR, &next);
-    next
+pub fn increment_appointment_counter(env: &Env) -> Result<u64, ContractError> {
+    counters::next_id(env, &APPT_CTR)
 }
 
 /// Stores an appointment record
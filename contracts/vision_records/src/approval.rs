@@ -0,0 +1,138 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::expiry;
+use crate::pending_gc::{self, PendingCategory, PendingEntry};
+use crate::purpose::GrantPurpose;
+use crate::temp_storage;
+use crate::AccessLevel;
+
+// ── Storage keys ──────────────────────────────────────────────
+const APPROVAL_REQUIRED: Symbol = symbol_short!("APPR_REQ");
+const PENDING_GRANT: Symbol = symbol_short!("PEND_GRT");
+const PENDING_LIST: Symbol = symbol_short!("PEND_LST");
+
+/// How long a pending grant waits for patient approval before it lapses.
+pub const PENDING_GRANT_WINDOW_SECONDS: u64 = 604_800; // 7 days
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A grant requested on a patient's behalf that only becomes active once
+/// the patient approves it. Held in temporary storage, so an unanswered
+/// request is dropped by the network shortly after its deadline.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingGrant {
+    pub patient: Address,
+    pub grantee: Address,
+    pub level: AccessLevel,
+    pub purpose: GrantPurpose,
+    pub duration_seconds: u64,
+    pub requested_by: Address,
+    pub requested_at: u64,
+    /// Approval deadline; the grant is discarded if not approved by then.
+    pub expires_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn approval_required_key(patient: &Address) -> (Symbol, Address) {
+    (APPROVAL_REQUIRED, patient.clone())
+}
+
+pub fn pending_grant_key(patient: &Address, grantee: &Address) -> (Symbol, Address, Address) {
+    (PENDING_GRANT, patient.clone(), grantee.clone())
+}
+
+/// Whether non-patient grants for `patient` need the patient's approval.
+pub fn is_approval_required(env: &Env, patient: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&approval_required_key(patient))
+        .unwrap_or(false)
+}
+
+pub fn set_approval_required(env: &Env, patient: &Address, required: bool) {
+    let key = approval_required_key(patient);
+    if required {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Grantees with a pending grant for the patient. Entries may outlive the
+/// pending grant itself once temporary storage drops it.
+pub fn pending_list_key(patient: &Address) -> (Symbol, Address) {
+    (PENDING_LIST, patient.clone())
+}
+
+fn get_pending_list(env: &Env, patient: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&pending_list_key(patient))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn get_pending_grant(env: &Env, patient: &Address, grantee: &Address) -> Option<PendingGrant> {
+    temp_storage::get_temp(env, &pending_grant_key(patient, grantee))
+}
+
+/// Stores a pending grant until slightly past its approval deadline.
+pub fn set_pending_grant(env: &Env, pending: &PendingGrant) {
+    let key = pending_grant_key(&pending.patient, &pending.grantee);
+    let ttl = temp_storage::ledgers_for_seconds(PENDING_GRANT_WINDOW_SECONDS)
+        .saturating_add(temp_storage::TEMP_TTL_DAY);
+    temp_storage::set_temp(env, &key, pending, ttl);
+    pending_gc::register(
+        env,
+        &PendingCategory::AccessRequest,
+        &PendingEntry::Grant(pending.patient.clone(), pending.grantee.clone()),
+        pending.expires_at,
+    );
+
+    let mut grantees = get_pending_list(env, &pending.patient);
+    if !grantees.contains(&pending.grantee) {
+        grantees.push_back(pending.grantee.clone());
+        env.storage()
+            .persistent()
+            .set(&pending_list_key(&pending.patient), &grantees);
+    }
+}
+
+pub fn remove_pending_grant(env: &Env, patient: &Address, grantee: &Address) {
+    temp_storage::remove_temp(env, &pending_grant_key(patient, grantee));
+
+    let mut grantees = get_pending_list(env, patient);
+    if let Some(index) = grantees.first_index_of(grantee) {
+        grantees.remove(index);
+        env.storage()
+            .persistent()
+            .set(&pending_list_key(patient), &grantees);
+    }
+}
+
+/// The patient's pending grants still within their approval window.
+pub fn get_pending_grants(env: &Env, patient: &Address) -> Vec<PendingGrant> {
+    let mut pending = Vec::new(env);
+    for grantee in get_pending_list(env, patient).iter() {
+        if let Some(grant) = get_pending_grant(env, patient, &grantee) {
+            if !expiry::is_expired(env, grant.expires_at) {
+                pending.push_back(grant);
+            }
+        }
+    }
+    pending
+}
+
+/// Counts the patient's pending grants still within their approval window.
+pub fn count_pending(env: &Env, patient: &Address) -> u32 {
+    let mut count: u32 = 0;
+    for grantee in get_pending_list(env, patient).iter() {
+        if let Some(pending) = get_pending_grant(env, patient, &grantee) {
+            if !expiry::is_expired(env, pending.expires_at) {
+                count = count.saturating_add(1);
+            }
+        }
+    }
+    count
+}
@@ -1,11 +1,20 @@
 use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
 
+use crate::counters;
+use crate::errors::ContractError;
+use crate::instance_usage::InstanceKey;
+
 // ── Storage keys ──────────────────────────────────────────────
 pub const AUDIT_CTR: Symbol = symbol_short!("AUD_CTR");
 const AUDIT_ENTRY: Symbol = symbol_short!("AUD_ENT");
 const AUDIT_RECORD: Symbol = symbol_short!("AUD_REC");
 const AUDIT_USER: Symbol = symbol_short!("AUD_USR");
 const AUDIT_PATIENT: Symbol = symbol_short!("AUD_PAT");
+const AUDIT_PAT_CNT: Symbol = symbol_short!("AUD_PCNT");
+const AUDIT_PAT_SEQ: Symbol = symbol_short!("AUD_PSEQ");
+
+/// Instance-storage keys written here.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] = &[InstanceKey::number(AUDIT_CTR)];
 
 const TTL_THRESHOLD: u32 = 5184000;
 const TTL_EXTEND_TO: u32 = 10368000;
@@ -85,11 +94,8 @@ pub struct AuditEntry {
 // ── Storage Functions ────────────────────────────────────────
 
 /// Increments and returns the next audit entry ID
-pub fn increment_audit_counter(env: &Env) -> u64 {
-    let current: u64 = env.storage().instance().get(&AUDIT_CTR).unwrap_or(0);
-    let next = current + 1;
-    env.storage().instance().set(&AUDIT_CTR, &next);
-    next
+pub fn increment_audit_counter(env: &Env) -> Result<u64, ContractError> {
+    counters::next_id(env, &AUDIT_CTR)
 }
 
 /// Stores an audit entry
@@ -115,6 +121,29 @@ pub fn add_audit_entry(env: &Env, entry: &AuditEntry) {
     let patient_key = (AUDIT_PATIENT, entry.patient.clone(), entry.id);
     env.storage().persistent().set(&patient_key, &true);
     extend_ttl_audit_patient_key(env, &patient_key);
+
+    // Number the entry within the patient's trail for positional reads
+    let count = patient_audit_count(env, &entry.patient);
+    let seq_key = (AUDIT_PAT_SEQ, entry.patient.clone(), count);
+    env.storage().persistent().set(&seq_key, &entry.id);
+    env.storage()
+        .persistent()
+        .extend_ttl(&seq_key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    let count_key = (AUDIT_PAT_CNT, entry.patient.clone());
+    env.storage()
+        .persistent()
+        .set(&count_key, &count.saturating_add(1));
+    env.storage()
+        .persistent()
+        .extend_ttl(&count_key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+/// Number of audit entries ever numbered in a patient's trail.
+pub fn patient_audit_count(env: &Env, patient: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&(AUDIT_PAT_CNT, patient.clone()))
+        .unwrap_or(0)
 }
 
 /// Retrieves an audit entry by ID
@@ -192,6 +221,27 @@ pub fn get_patient_audit_log(env: &Env, patient: &Address) -> Vec<AuditEntry> {
     entries
 }
 
+/// Gets the patient's audit entries numbered `offset` up to `end`, oldest
+/// first. Each position costs one index read and one entry read; entries
+/// whose storage has lapsed are skipped.
+pub fn get_patient_audit_slice(
+    env: &Env,
+    patient: &Address,
+    offset: u32,
+    end: u32,
+) -> Vec<AuditEntry> {
+    let mut entries = Vec::new(env);
+    for position in offset..end {
+        let seq_key = (AUDIT_PAT_SEQ, patient.clone(), position);
+        if let Some(id) = env.storage().persistent().get::<_, u64>(&seq_key) {
+            if let Some(entry) = get_audit_entry(env, id) {
+                entries.push_back(entry);
+            }
+        }
+    }
+    entries
+}
+
 /// Gets audit entries filtered by action type
 pub fn get_audit_log_by_action(env: &Env, action: AccessAction) -> Vec<AuditEntry> {
     let mut entries = Vec::new(env);
@@ -263,9 +313,9 @@ pub fn create_audit_entry(
     action: AccessAction,
     result: AccessResult,
     reason: Option<String>,
-) -> AuditEntry {
-    let id = increment_audit_counter(env);
-    AuditEntry {
+) -> Result<AuditEntry, ContractError> {
+    let id = increment_audit_counter(env)?;
+    Ok(AuditEntry {
         id,
         timestamp: env.ledger().timestamp(),
         actor,
@@ -276,5 +326,5 @@ pub fn create_audit_entry(
         reason,
         ip_address: None,
         user_agent: None,
-    }
+    })
 }
@@ -0,0 +1,440 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::rbac::{self, Permission, Role};
+use crate::{
+    access, access_freeze, breach, dispute, embargo, has_active_consent, history_scope,
+    provisional, record_override, record_policy, session, versioning, write_scope, AccessGrant,
+    AccessLevel, RecordType, VisionRecord, VisionRecordsContract,
+};
+
+// ── Reasons ───────────────────────────────────────────────────
+//
+// Each decision names the rule that matched. Allowing rules:
+
+/// The caller is the record's patient, or the patient managing their own access.
+pub const PATIENT_SELF: &str = "patient_self";
+/// The caller is the record's provider.
+pub const PROVIDER_SELF: &str = "provider_self";
+/// The caller is the user being managed.
+pub const USER_SELF: &str = "user_self";
+/// The caller holds a live session key for the patient.
+pub const SESSION: &str = "session";
+/// The caller holds a patient-wide grant of at least `Read`.
+pub const GRANT_READ: &str = "grant_read";
+/// The caller holds a patient-wide grant of at least `Write`.
+pub const GRANT_WRITE: &str = "grant_write";
+/// The caller holds a grant on this one record.
+pub const RECORD_GRANT: &str = "record_grant";
+/// The caller holds active consent from the patient.
+pub const CONSENT: &str = "consent";
+/// The caller's own role carries the permission.
+pub const ROLE: &str = "role";
+/// The permission was delegated to the caller.
+pub const DELEGATED: &str = "delegated";
+/// The caller is a ClinicalAdmin.
+pub const ADMIN: &str = "admin";
+/// The caller holds a patient-wide `Full` grant, which lets it share
+/// `Read` access on the patient's behalf.
+pub const FULL_GRANTEE: &str = "full_grantee";
+
+// Denying rules:
+
+/// The caller is frozen.
+pub const FROZEN: &str = "frozen";
+/// The record is a patient note, which nobody may modify.
+pub const IMMUTABLE: &str = "immutable";
+/// The record is provisional and not yet claimed.
+pub const PROVISIONAL: &str = "provisional";
+/// The caller's role may not see this record type.
+pub const ROLE_HIDDEN: &str = "role_hidden";
+/// The record is embargoed and the caller reads through the patient.
+pub const EMBARGOED: &str = "embargoed";
+/// The patient's per-record override has cut the caller off from this record.
+pub const OVERRIDDEN: &str = "overridden";
+/// The provider does not meet the admin policy for this record type.
+pub const TYPE_RESTRICTED: &str = "type_restricted";
+/// The record has an unresolved dispute, which blocks rollback.
+pub const DISPUTED: &str = "disputed";
+/// The patient shared the record with the caller but not its history.
+pub const HISTORY_HIDDEN: &str = "history_hidden";
+/// The patient has frozen all access to their records.
+pub const PATIENT_FROZEN: &str = "patient_frozen";
+/// The patient is under breach lockdown.
+pub const LOCKDOWN: &str = "lockdown";
+/// The record does not exist.
+pub const NOT_FOUND: &str = "not_found";
+/// No rule grants the caller access.
+pub const NO_ACCESS: &str = "no_access";
+/// The caller gets in only as a ClinicalAdmin, which for record reads takes
+/// a stated reason through `read_record_as_admin`.
+pub const ADMIN_REASON_REQUIRED: &str = "admin_reason_required";
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// An action whose authorization can be checked with `authorize`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AuthAction {
+    ReadRecord(u64),
+    WriteRecord(u64),
+    /// Grant access to the given patient's records.
+    GrantAccess(Address),
+    /// Manage the given user's profile.
+    ManageUser(Address),
+    /// Roll a record back to an earlier version. A rollback writes a new
+    /// version, so it follows the write rule, and waits for any open
+    /// dispute on the record to be resolved.
+    Rollback(u64),
+    /// Read earlier versions of a record. Follows the read rule, unless the
+    /// patient's grant to the caller leaves out history.
+    ReadHistory(u64),
+}
+
+/// Whether an action is allowed, and the rule that decided it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuthDecision {
+    pub allowed: bool,
+    pub reason: Symbol,
+}
+
+impl AuthDecision {
+    fn allow(env: &Env, reason: &str) -> Self {
+        AuthDecision {
+            allowed: true,
+            reason: Symbol::new(env, reason),
+        }
+    }
+
+    fn deny(env: &Env, reason: &str) -> Self {
+        AuthDecision {
+            allowed: false,
+            reason: Symbol::new(env, reason),
+        }
+    }
+
+    /// Whether this decision denies for `reason`.
+    pub fn denied_for(&self, env: &Env, reason: &str) -> bool {
+        !self.allowed && self.reason == Symbol::new(env, reason)
+    }
+}
+
+// ── Decisions ────────────────────────────────────────────────
+//
+// These are the checks the endpoints themselves run; `authorize` only
+// dispatches to them.
+
+/// The rule by which `caller` holds `permission` over `provider`'s
+/// records: as the provider itself, through a delegation from the
+/// provider, or as a ClinicalAdmin.
+pub fn record_permission(
+    env: &Env,
+    caller: &Address,
+    provider: &Address,
+    permission: &Permission,
+) -> Option<&'static str> {
+    if caller == provider {
+        if rbac::has_permission(env, caller, permission) {
+            return Some(PROVIDER_SELF);
+        }
+    } else if rbac::has_delegated_permission(env, provider, caller, permission) {
+        return Some(DELEGATED);
+    }
+    if rbac::has_clinical_admin(env, caller) {
+        return Some(ADMIN);
+    }
+    None
+}
+
+/// Whether `grantee` holds a live `Write` or `Full` grant from `patient`.
+/// Unless `append` is set, append-only grants do not count.
+fn write_grant_allows(env: &Env, patient: &Address, grantee: &Address, append: bool) -> bool {
+    let level = VisionRecordsContract::check_access(env.clone(), patient.clone(), grantee.clone());
+    access::level_satisfies(&AccessLevel::Write, &level)
+        && (append || !write_scope::is_append_only(env, patient, grantee))
+}
+
+/// Whether `caller` may read `record`.
+pub fn read_record(env: &Env, caller: &Address, record: &VisionRecord) -> AuthDecision {
+    if rbac::is_frozen(env, caller) {
+        // Frozen users are denied regardless of ownership or grants
+        return AuthDecision::deny(env, FROZEN);
+    }
+    if access_freeze::blocks(env, &record.patient, caller) {
+        return AuthDecision::deny(env, PATIENT_FROZEN);
+    }
+    if provisional::is_placeholder(env, &record.patient) {
+        // Unclaimed provisional records stay with their provider and admins
+        return if *caller == record.provider {
+            AuthDecision::allow(env, PROVIDER_SELF)
+        } else if rbac::has_clinical_admin(env, caller) {
+            AuthDecision::deny(env, ADMIN_REASON_REQUIRED)
+        } else {
+            AuthDecision::deny(env, PROVISIONAL)
+        };
+    }
+    if *caller == record.provider {
+        return AuthDecision::allow(env, PROVIDER_SELF);
+    }
+    // Until release, only the provider and role-based readers get through
+    let embargoed = embargo::is_embargoed(env, record.id);
+    if *caller == record.patient {
+        if embargoed {
+            return AuthDecision::deny(env, EMBARGOED);
+        }
+        return AuthDecision::allow(env, PATIENT_SELF);
+    }
+    if session::can_read(env, &record.patient, caller) {
+        // A session reads with the patient's own rights
+        if embargoed {
+            return AuthDecision::deny(env, EMBARGOED);
+        }
+        return AuthDecision::allow(env, SESSION);
+    }
+    if !VisionRecordsContract::role_can_see(env, caller, &record.record_type) {
+        return AuthDecision::deny(env, ROLE_HIDDEN);
+    }
+
+    // A ClinicalAdmin reads through what the patient handed out, or else
+    // states a reason via `read_record_as_admin`; its role is not enough
+    let is_admin = rbac::has_clinical_admin(env, caller);
+    let reason = if !is_admin && rbac::has_permission(env, caller, &Permission::ReadAnyRecord) {
+        ROLE
+    } else if access::level_satisfies(
+        &AccessLevel::Read,
+        &VisionRecordsContract::check_access(env.clone(), record.patient.clone(), caller.clone()),
+    ) {
+        GRANT_READ
+    } else if access::level_satisfies(
+        &AccessLevel::Read,
+        &VisionRecordsContract::check_record_access(env.clone(), record.id, caller.clone()),
+    ) {
+        RECORD_GRANT
+    } else if has_active_consent(env, &record.patient, caller) {
+        CONSENT
+    } else if is_admin {
+        return AuthDecision::deny(env, ADMIN_REASON_REQUIRED);
+    } else {
+        return AuthDecision::deny(env, NO_ACCESS);
+    };
+    // Anything the patient handed out is capped by their override
+    if reason != ROLE && record_override::excludes(env, record.id, caller) {
+        return AuthDecision::deny(env, OVERRIDDEN);
+    }
+    if embargoed && reason != ROLE {
+        return AuthDecision::deny(env, EMBARGOED);
+    }
+    AuthDecision::allow(env, reason)
+}
+
+/// Whether `caller` may read `record` as a ClinicalAdmin with a stated
+/// reason. Admins see provisional and embargoed records, but not past a
+/// freeze on themselves or on the patient.
+pub fn read_record_as_admin(env: &Env, caller: &Address, record: &VisionRecord) -> AuthDecision {
+    if rbac::is_frozen(env, caller) {
+        return AuthDecision::deny(env, FROZEN);
+    }
+    if !rbac::has_clinical_admin(env, caller) {
+        return AuthDecision::deny(env, NO_ACCESS);
+    }
+    if access_freeze::blocks(env, &record.patient, caller) {
+        return AuthDecision::deny(env, PATIENT_FROZEN);
+    }
+    AuthDecision::allow(env, ADMIN)
+}
+
+/// Whether `caller` may read earlier versions of `record`. Readers who got
+/// in through something the patient handed out lose history when the
+/// patient's grant to them hides it, unless they wrote a version of the
+/// record themselves.
+pub fn read_history(env: &Env, caller: &Address, record: &VisionRecord) -> AuthDecision {
+    let decision = read_record(env, caller, record);
+    let granted = [GRANT_READ, RECORD_GRANT, CONSENT]
+        .iter()
+        .any(|reason| decision.reason == Symbol::new(env, reason));
+    if decision.allowed
+        && granted
+        && history_scope::is_history_hidden(env, &record.patient, caller)
+        && !versioning::is_author(env, record.id, caller)
+    {
+        return AuthDecision::deny(env, HISTORY_HIDDEN);
+    }
+    decision
+}
+
+/// Whether `caller` may modify an existing record. `AppendRecord` and
+/// append-only grants do not qualify.
+pub fn write_record(env: &Env, caller: &Address, record: &VisionRecord) -> AuthDecision {
+    if record.record_type == RecordType::PatientNote {
+        return AuthDecision::deny(env, IMMUTABLE);
+    }
+    if rbac::is_frozen(env, caller) {
+        return AuthDecision::deny(env, FROZEN);
+    }
+    if access_freeze::blocks(env, &record.patient, caller) {
+        return AuthDecision::deny(env, PATIENT_FROZEN);
+    }
+    if let Some(reason) = record_permission(env, caller, &record.provider, &Permission::WriteRecord)
+    {
+        return AuthDecision::allow(env, reason);
+    }
+    if write_grant_allows(env, &record.patient, caller, false) {
+        if record_override::excludes(env, record.id, caller) {
+            return AuthDecision::deny(env, OVERRIDDEN);
+        }
+        return AuthDecision::allow(env, GRANT_WRITE);
+    }
+    AuthDecision::deny(env, NO_ACCESS)
+}
+
+/// Whether `provider` may author records of `record_type` when `caller`
+/// adds them. A ClinicalAdmin caller is exempt from record type policies.
+pub fn record_type_allowed(
+    env: &Env,
+    caller: &Address,
+    provider: &Address,
+    record_type: &RecordType,
+) -> bool {
+    record_policy::allows(env, provider, record_type) || rbac::has_clinical_admin(env, caller)
+}
+
+/// Whether `caller` may roll `record` back to an earlier version.
+pub fn rollback(env: &Env, caller: &Address, record: &VisionRecord) -> AuthDecision {
+    let decision = write_record(env, caller, record);
+    if decision.allowed && dispute::is_open(env, record.id) {
+        return AuthDecision::deny(env, DISPUTED);
+    }
+    decision
+}
+
+/// Whether `caller` may add a new record of `record_type` for `patient`
+/// under `provider`. Anything that lets a caller modify records also lets
+/// it add them; `AppendRecord` and append-only grants only allow this.
+pub fn append_record(
+    env: &Env,
+    caller: &Address,
+    patient: &Address,
+    provider: &Address,
+    record_type: &RecordType,
+) -> AuthDecision {
+    if rbac::is_frozen(env, caller) {
+        return AuthDecision::deny(env, FROZEN);
+    }
+    if access_freeze::blocks(env, patient, caller) {
+        return AuthDecision::deny(env, PATIENT_FROZEN);
+    }
+    let reason = if let Some(reason) =
+        record_permission(env, caller, provider, &Permission::WriteRecord)
+            .or_else(|| record_permission(env, caller, provider, &Permission::AppendRecord))
+    {
+        reason
+    } else if caller == provider && write_grant_allows(env, patient, caller, true) {
+        GRANT_WRITE
+    } else {
+        return AuthDecision::deny(env, NO_ACCESS);
+    };
+    if !record_type_allowed(env, caller, provider, record_type) {
+        return AuthDecision::deny(env, TYPE_RESTRICTED);
+    }
+    AuthDecision::allow(env, reason)
+}
+
+/// Whether `caller` may add a patient note for `patient` under
+/// `provider`. Only a patient writing about themself may, and needs no
+/// write permission to do so.
+pub fn append_note(
+    env: &Env,
+    caller: &Address,
+    patient: &Address,
+    provider: &Address,
+) -> AuthDecision {
+    if rbac::is_frozen(env, caller) {
+        return AuthDecision::deny(env, FROZEN);
+    }
+    let is_patient = rbac::get_active_assignment(env, caller)
+        .is_some_and(|assignment| assignment.role == Role::Patient);
+    if is_patient && caller == patient && caller == provider {
+        return AuthDecision::allow(env, PATIENT_SELF);
+    }
+    AuthDecision::deny(env, NO_ACCESS)
+}
+
+/// Whether `caller` may grant others access to `patient`'s records.
+pub fn grant_access(env: &Env, caller: &Address, patient: &Address) -> AuthDecision {
+    if caller == patient {
+        // Patient manages own access
+        return AuthDecision::allow(env, PATIENT_SELF);
+    }
+    let reason = if rbac::has_delegated_permission(env, patient, caller, &Permission::ManageAccess)
+    {
+        DELEGATED
+    } else if rbac::has_clinical_admin(env, caller) {
+        ADMIN
+    } else if full_grant_expiry(env, patient, caller).is_some() {
+        FULL_GRANTEE
+    } else {
+        return AuthDecision::deny(env, NO_ACCESS);
+    };
+    // A breached patient's records may only be shared by the patient
+    if breach::is_flagged(env, patient) {
+        return AuthDecision::deny(env, LOCKDOWN);
+    }
+    AuthDecision::allow(env, reason)
+}
+
+/// When `caller`'s own `Full` grant from `patient` runs out, if it is
+/// live and in effect. Only a personal grant counts: organization grants
+/// do not let their members share access, and a frozen caller or a
+/// patient freeze suspends the right.
+pub fn full_grant_expiry(env: &Env, patient: &Address, caller: &Address) -> Option<u64> {
+    if rbac::is_frozen(env, caller) || access_freeze::blocks(env, patient, caller) {
+        return None;
+    }
+    let grant: AccessGrant = env.storage().persistent().get(&(
+        symbol_short!("ACCESS"),
+        patient.clone(),
+        caller.clone(),
+    ))?;
+    let level = VisionRecordsContract::check_access(env.clone(), patient.clone(), caller.clone());
+    (grant.level == AccessLevel::Full && level == AccessLevel::Full).then_some(grant.expires_at)
+}
+
+/// Whether `caller` may manage `user`'s profile.
+pub fn manage_user(env: &Env, caller: &Address, user: &Address) -> AuthDecision {
+    if caller == user {
+        AuthDecision::allow(env, USER_SELF)
+    } else if rbac::has_permission(env, caller, &Permission::ManageUsers) {
+        AuthDecision::allow(env, ROLE)
+    } else {
+        AuthDecision::deny(env, NO_ACCESS)
+    }
+}
+
+/// Decides `action` for `caller` with the same checks the endpoints run.
+pub fn authorize(env: &Env, caller: &Address, action: &AuthAction) -> AuthDecision {
+    let load = |record_id: &u64| -> Option<VisionRecord> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), *record_id))
+    };
+    match action {
+        AuthAction::ReadRecord(record_id) => match load(record_id) {
+            Some(record) => read_record(env, caller, &record),
+            None => AuthDecision::deny(env, NOT_FOUND),
+        },
+        AuthAction::WriteRecord(record_id) => match load(record_id) {
+            Some(record) => write_record(env, caller, &record),
+            None => AuthDecision::deny(env, NOT_FOUND),
+        },
+        AuthAction::Rollback(record_id) => match load(record_id) {
+            Some(record) => rollback(env, caller, &record),
+            None => AuthDecision::deny(env, NOT_FOUND),
+        },
+        AuthAction::ReadHistory(record_id) => match load(record_id) {
+            Some(record) => read_history(env, caller, &record),
+            None => AuthDecision::deny(env, NOT_FOUND),
+        },
+        AuthAction::GrantAccess(patient) => grant_access(env, caller, patient),
+        AuthAction::ManageUser(user) => manage_user(env, caller, user),
+    }
+}
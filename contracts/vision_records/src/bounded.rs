@@ -0,0 +1,59 @@
+use soroban_sdk::{contracttype, Vec};
+
+use crate::audit::AuditEntry;
+use crate::versioning::RecordVersion;
+use crate::AccessGrantDetail;
+
+/// Hard cap on the versions or IDs one bounded query walks and returns.
+pub const MAX_BOUNDED_ITEMS: u32 = 50;
+
+/// Cap for grant pages. Each grantee costs several reads (grant, consent,
+/// purpose, creator, contract marker), so pages are much shorter.
+pub const MAX_BOUNDED_GRANTS: u32 = 10;
+
+/// Cap for audit pages: each entry is an index read plus the entry itself.
+pub const MAX_BOUNDED_AUDIT: u32 = 40;
+
+// ── Types ─────────────────────────────────────────────────────
+//
+// Bounded queries stop after a fixed number of entries, sized so one
+// page stays within a single invocation's read limit, instead of walking
+// an unbounded list. `truncated` says entries remain past the
+// returned ones; pass `next_offset` back as `offset` to continue. When
+// nothing remains, `next_offset` is where the list currently ends.
+
+/// A bounded slice of a record's version history, oldest first.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BoundedVersions {
+    pub items: Vec<RecordVersion>,
+    pub truncated: bool,
+    pub next_offset: u32,
+}
+
+/// A bounded slice of a patient's unexpired grants. `next_offset` is a
+/// grantee list position, so `items` may hold fewer than the cap when
+/// expired grants were skipped.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BoundedGrants {
+    pub items: Vec<AccessGrantDetail>,
+    pub truncated: bool,
+    pub next_offset: u32,
+}
+
+/// A bounded slice of a patient's retained audit trail, oldest first.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BoundedAuditEntries {
+    pub items: Vec<AuditEntry>,
+    pub truncated: bool,
+    pub next_offset: u32,
+}
+
+/// End of the window of at most `cap` entries starting at `offset` in a
+/// list of `len` entries, and whether entries remain after it.
+pub fn window(offset: u32, len: u32, cap: u32) -> (u32, bool) {
+    let end = offset.saturating_add(cap).min(len);
+    (end, end < len)
+}
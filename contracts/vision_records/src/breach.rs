@@ -0,0 +1,65 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
+
+// ── Storage keys ──────────────────────────────────────────────
+const BREACH: Symbol = symbol_short!("BREACH");
+
+/// Most patients one `flag_breach` call may flag. Each costs a write and
+/// an event.
+pub const MAX_BREACH_BATCH: u32 = 25;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a patient's breach flag.
+fn extend_ttl_breach_key(env: &Env, key: &(Symbol, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Marks a patient as affected by a breach of the off-chain store.
+///
+/// While a patient is flagged, only the patient may grant new access to
+/// their records. The flag stays until an admin clears it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BreachFlag {
+    pub patient: Address,
+    /// Hash of the off-chain incident report.
+    pub incident_ref_hash: String,
+    pub flagged_by: Address,
+    pub flagged_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn breach_key(patient: &Address) -> (Symbol, Address) {
+    (BREACH, patient.clone())
+}
+
+pub fn get_flag(env: &Env, patient: &Address) -> Option<BreachFlag> {
+    env.storage().persistent().get(&breach_key(patient))
+}
+
+pub fn is_flagged(env: &Env, patient: &Address) -> bool {
+    env.storage().persistent().has(&breach_key(patient))
+}
+
+/// Flags the patient, replacing any earlier flag.
+pub fn set_flag(env: &Env, flag: &BreachFlag) {
+    let key = breach_key(&flag.patient);
+    env.storage().persistent().set(&key, flag);
+    extend_ttl_breach_key(env, &key);
+}
+
+/// Clears the patient's flag. Returns whether one was set.
+pub fn remove_flag(env: &Env, patient: &Address) -> bool {
+    let key = breach_key(patient);
+    let flagged = env.storage().persistent().has(&key);
+    if flagged {
+        env.storage().persistent().remove(&key);
+    }
+    flagged
+}
@@ -1,8 +1,5 @@
-use crate::{
-    events,
-    rbac::{self, Permission},
-    ContractError,
-};
+use crate::instance_usage::InstanceKey;
+use crate::{events, rbac, ContractError};
 use teye_common::admin_tiers::{self, AdminTier};
 use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
 
@@ -20,12 +17,21 @@ pub enum PauseScope {
 
 // ── Storage Keys ─────────────────────────────────────────────
 
+const GLOBAL_PAUSE: Symbol = symbol_short!("P_GLOB");
+const FUNCTION_PAUSE: Symbol = symbol_short!("P_FUNC");
+
+/// Instance-storage keys written here.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] = &[
+    InstanceKey::flag(GLOBAL_PAUSE),
+    InstanceKey::keyed(FUNCTION_PAUSE, 16),
+];
+
 pub fn global_pause_key() -> Symbol {
-    symbol_short!("P_GLOB")
+    GLOBAL_PAUSE
 }
 
 pub fn function_pause_key(func: &Symbol) -> (Symbol, Symbol) {
-    (symbol_short!("P_FUNC"), func.clone())
+    (FUNCTION_PAUSE, func.clone())
 }
 
 // ── Core Logistics ───────────────────────────────────────────
@@ -58,10 +64,10 @@ pub fn require_not_paused(env: &Env, scope: &PauseScope) -> Result<(), ContractE
 }
 
 /// Engages a circuit breaker for the specified scope.
-/// Requires at least `OperatorAdmin` tier, or the existing SystemAdmin RBAC permission.
+/// Requires at least `OperatorAdmin` tier, or the InfraAdmin RBAC permission.
 pub fn pause_contract(env: &Env, caller: &Address, scope: PauseScope) -> Result<(), ContractError> {
     let has_tier = admin_tiers::require_tier(env, caller, &AdminTier::OperatorAdmin);
-    let has_rbac = rbac::has_permission(env, caller, &Permission::SystemAdmin);
+    let has_rbac = rbac::has_infra_admin(env, caller);
     if !has_tier && !has_rbac {
         return Err(ContractError::Unauthorized);
     }
@@ -83,14 +89,14 @@ pub fn pause_contract(env: &Env, caller: &Address, scope: PauseScope) -> Result<
 }
 
 /// Resumes operation of a circuit breaker for the specified scope.
-/// Requires at least `OperatorAdmin` tier, or the existing SystemAdmin RBAC permission.
+/// Requires at least `OperatorAdmin` tier, or the InfraAdmin RBAC permission.
 pub fn resume_contract(
     env: &Env,
     caller: &Address,
     scope: PauseScope,
 ) -> Result<(), ContractError> {
     let has_tier = admin_tiers::require_tier(env, caller, &AdminTier::OperatorAdmin);
-    let has_rbac = rbac::has_permission(env, caller, &Permission::SystemAdmin);
+    let has_rbac = rbac::has_infra_admin(env, caller);
     if !has_tier && !has_rbac {
         return Err(ContractError::Unauthorized);
     }
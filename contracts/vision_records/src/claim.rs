@@ -0,0 +1,116 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::errors::ContractError;
+
+// ── Storage keys ──────────────────────────────────────────────
+const CLAIM_ATTESTATIONS: Symbol = symbol_short!("CLM_ATT");
+
+/// Maximum number of attestations, voided or not, kept per record.
+pub const MAX_ATTESTATIONS_PER_RECORD: u32 = 50;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a record's attestation list.
+fn extend_ttl_claim_key(env: &Env, key: &(Symbol, u64)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A provider's assertion that a record supports an insurance claim.
+///
+/// The claim itself stays off-chain; `claim_ref_hash` identifies it to the
+/// insurer. Attestations are never edited or removed. Voiding one sets the
+/// `voided_*` fields and keeps the original assertion alongside them.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimAttestation {
+    pub record_id: u64,
+    pub provider: Address,
+    pub claim_ref_hash: String,
+    /// Claimed amount as reported by the provider; informational only.
+    pub amount_hint: i128,
+    pub attested_at: u64,
+    pub voided_by: Option<Address>,
+    pub void_reason: Option<String>,
+    pub voided_at: Option<u64>,
+}
+
+impl ClaimAttestation {
+    pub fn is_voided(&self) -> bool {
+        self.voided_at.is_some()
+    }
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn attestations_key(record_id: u64) -> (Symbol, u64) {
+    (CLAIM_ATTESTATIONS, record_id)
+}
+
+/// A record's attestations, oldest first.
+pub fn get_attestations(env: &Env, record_id: u64) -> Vec<ClaimAttestation> {
+    env.storage()
+        .persistent()
+        .get(&attestations_key(record_id))
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_attestations(env: &Env, record_id: u64, attestations: &Vec<ClaimAttestation>) {
+    let key = attestations_key(record_id);
+    env.storage().persistent().set(&key, attestations);
+    extend_ttl_claim_key(env, &key);
+}
+
+fn find_active(attestations: &Vec<ClaimAttestation>, claim_ref_hash: &String) -> Option<u32> {
+    attestations
+        .iter()
+        .position(|a| !a.is_voided() && a.claim_ref_hash == *claim_ref_hash)
+        .and_then(|i| u32::try_from(i).ok())
+}
+
+/// Whether the record has an attestation for the claim that has not been
+/// voided.
+pub fn is_attested(env: &Env, record_id: u64, claim_ref_hash: &String) -> bool {
+    find_active(&get_attestations(env, record_id), claim_ref_hash).is_some()
+}
+
+/// Appends an attestation. A claim may only have one active attestation
+/// per record; it can be attested again once the earlier one is voided.
+pub fn add_attestation(env: &Env, attestation: ClaimAttestation) -> Result<(), ContractError> {
+    let record_id = attestation.record_id;
+    let mut attestations = get_attestations(env, record_id);
+    if find_active(&attestations, &attestation.claim_ref_hash).is_some() {
+        return Err(ContractError::AlreadyExists);
+    }
+    if attestations.len() >= MAX_ATTESTATIONS_PER_RECORD {
+        return Err(ContractError::InvalidInput);
+    }
+    attestations.push_back(attestation);
+    set_attestations(env, record_id, &attestations);
+    Ok(())
+}
+
+/// Voids the active attestation for the claim and returns it.
+pub fn void_attestation(
+    env: &Env,
+    record_id: u64,
+    claim_ref_hash: &String,
+    voided_by: &Address,
+    reason: &String,
+) -> Result<ClaimAttestation, ContractError> {
+    let mut attestations = get_attestations(env, record_id);
+    let index = find_active(&attestations, claim_ref_hash)
+        .ok_or(ContractError::ClaimAttestationNotFound)?;
+
+    let mut attestation = attestations.get_unchecked(index);
+    attestation.voided_by = Some(voided_by.clone());
+    attestation.void_reason = Some(reason.clone());
+    attestation.voided_at = Some(env.ledger().timestamp());
+    attestations.set(index, attestation.clone());
+    set_attestations(env, record_id, &attestations);
+    Ok(attestation)
+}
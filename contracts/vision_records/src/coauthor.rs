@@ -0,0 +1,91 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::rbac::{self, Permission};
+use crate::ContractError;
+
+// ── Storage keys ──────────────────────────────────────────────
+const PROVIDER_RECORDS: Symbol = symbol_short!("PRV_RECS");
+
+/// Maximum number of coauthors credited on a single record.
+pub const MAX_COAUTHORS: u32 = 3;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a provider's record index.
+fn extend_ttl_provider_key(env: &Env, key: &(Symbol, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A record a provider is credited on.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProviderRecord {
+    pub record_id: u64,
+    /// Credited as a coauthor rather than as the record's provider.
+    pub coauthored: bool,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// Each provider keeps one index of the records they are credited on, as
+// the record's provider or as a coauthor, in creation order. Records
+// created before the index existed are not listed.
+
+pub fn provider_records_key(provider: &Address) -> (Symbol, Address) {
+    (PROVIDER_RECORDS, provider.clone())
+}
+
+pub fn get_provider_records(env: &Env, provider: &Address) -> Vec<ProviderRecord> {
+    env.storage()
+        .persistent()
+        .get(&provider_records_key(provider))
+        .unwrap_or(Vec::new(env))
+}
+
+fn add_to_index(env: &Env, provider: &Address, record_id: u64, coauthored: bool) {
+    let key = provider_records_key(provider);
+    let mut records = get_provider_records(env, provider);
+    records.push_back(ProviderRecord {
+        record_id,
+        coauthored,
+    });
+    env.storage().persistent().set(&key, &records);
+    extend_ttl_provider_key(env, &key);
+}
+
+/// Lists a new record under its provider and each of its coauthors.
+pub fn index_record(env: &Env, provider: &Address, coauthors: &Vec<Address>, record_id: u64) {
+    add_to_index(env, provider, record_id, false);
+    for coauthor in coauthors.iter() {
+        add_to_index(env, &coauthor, record_id, true);
+    }
+}
+
+/// Checks a record's coauthor list: at most `MAX_COAUTHORS` distinct
+/// addresses other than the provider, each holding `WriteRecord` or
+/// `AppendRecord` in their own right.
+pub fn validate(
+    env: &Env,
+    provider: &Address,
+    coauthors: &Vec<Address>,
+) -> Result<(), ContractError> {
+    if coauthors.len() > MAX_COAUTHORS {
+        return Err(ContractError::InvalidInput);
+    }
+    for (i, coauthor) in coauthors.iter().enumerate() {
+        if coauthor == *provider || coauthors.first_index_of(&coauthor) != Some(i as u32) {
+            return Err(ContractError::InvalidInput);
+        }
+        if !rbac::has_permission(env, &coauthor, &Permission::WriteRecord)
+            && !rbac::has_permission(env, &coauthor, &Permission::AppendRecord)
+        {
+            return Err(ContractError::InsufficientPermissions);
+        }
+    }
+    Ok(())
+}
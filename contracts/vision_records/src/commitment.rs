@@ -0,0 +1,116 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Bytes, BytesN, Env, Symbol, Vec};
+
+// ── Storage keys ──────────────────────────────────────────────
+const PATIENT_COMMITMENT: Symbol = symbol_short!("PAT_CMT");
+const COMMITMENT_ENTRY: Symbol = symbol_short!("PAT_CLOG");
+
+/// Maximum number of log entries returned by one `get_commitment_log` call.
+pub const MAX_COMMITMENT_PAGE: u32 = 100;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a patient's commitment head.
+fn extend_ttl_patient_key(env: &Env, key: &(Symbol, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+/// Extends the time-to-live (TTL) for a commitment log entry.
+fn extend_ttl_entry_key(env: &Env, key: &(Symbol, Address, u32)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// The head of a patient's record commitment chain.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PatientCommitment {
+    /// All zeroes until the first record is indexed.
+    pub commitment: BytesN<32>,
+    /// Number of entries folded into `commitment`.
+    pub entries: u32,
+}
+
+/// One append to a patient's record index.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitmentEntry {
+    pub record_id: u64,
+    /// Ledger time the record entered the index.
+    pub timestamp: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// Each entry advances the chain as
+// `commitment = sha256(prev_commitment || record_id || timestamp)`, with
+// both integers big-endian and `prev_commitment` starting at 32 zero
+// bytes. Entries are only ever appended: anything that later removes a
+// record from the index must append its own entry rather than rewrite
+// the log, or previously shared commitments stop verifying.
+
+pub fn head_key(patient: &Address) -> (Symbol, Address) {
+    (PATIENT_COMMITMENT, patient.clone())
+}
+
+pub fn entry_key(patient: &Address, index: u32) -> (Symbol, Address, u32) {
+    (COMMITMENT_ENTRY, patient.clone(), index)
+}
+
+pub fn get(env: &Env, patient: &Address) -> PatientCommitment {
+    env.storage()
+        .persistent()
+        .get(&head_key(patient))
+        .unwrap_or(PatientCommitment {
+            commitment: BytesN::from_array(env, &[0; 32]),
+            entries: 0,
+        })
+}
+
+/// The commitment following `prev` once `entry` is appended.
+pub fn chain(env: &Env, prev: &BytesN<32>, entry: &CommitmentEntry) -> BytesN<32> {
+    let mut preimage = Bytes::from_array(env, &prev.to_array());
+    preimage.extend_from_array(&entry.record_id.to_be_bytes());
+    preimage.extend_from_array(&entry.timestamp.to_be_bytes());
+    env.crypto().sha256(&preimage).to_bytes()
+}
+
+/// Records that `record_id` entered the patient's index now.
+pub fn append(env: &Env, patient: &Address, record_id: u64) {
+    let entry = CommitmentEntry {
+        record_id,
+        timestamp: env.ledger().timestamp(),
+    };
+    let head = get(env, patient);
+    let entry_key = entry_key(patient, head.entries);
+    env.storage().persistent().set(&entry_key, &entry);
+    extend_ttl_entry_key(env, &entry_key);
+
+    let head = PatientCommitment {
+        commitment: chain(env, &head.commitment, &entry),
+        entries: head.entries.saturating_add(1),
+    };
+    let head_key = head_key(patient);
+    env.storage().persistent().set(&head_key, &head);
+    extend_ttl_patient_key(env, &head_key);
+}
+
+/// Up to `limit` (capped at `MAX_COMMITMENT_PAGE`) entries starting at
+/// `offset`, oldest first.
+pub fn get_log(env: &Env, patient: &Address, offset: u32, limit: u32) -> Vec<CommitmentEntry> {
+    let end = get(env, patient)
+        .entries
+        .min(offset.saturating_add(limit.min(MAX_COMMITMENT_PAGE)));
+    let mut entries = Vec::new(env);
+    for index in offset..end {
+        if let Some(entry) = env.storage().persistent().get(&entry_key(patient, index)) {
+            entries.push_back(entry);
+        }
+    }
+    entries
+}
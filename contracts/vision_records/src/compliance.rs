@@ -0,0 +1,112 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::instance_usage::InstanceKey;
+
+// ── Storage keys ──────────────────────────────────────────────
+const COMPLIANCE_SEQ: Symbol = symbol_short!("COMP_SEQ");
+const COMPLIANCE_LOG: Symbol = symbol_short!("COMP_LOG");
+
+/// Instance-storage keys written here.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] = &[InstanceKey::number(COMPLIANCE_SEQ)];
+
+/// Entries per stored page.
+pub const COMPLIANCE_PAGE_SIZE: u64 = 50;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+// ── Actions ───────────────────────────────────────────────────
+
+pub const GRANT: Symbol = symbol_short!("GRANT");
+pub const REVOKE: Symbol = symbol_short!("REVOKE");
+pub const ADMIN_READ: Symbol = symbol_short!("ADM_READ");
+pub const ERASURE: Symbol = symbol_short!("ERASURE");
+
+/// Extends the time-to-live (TTL) for a log page.
+fn extend_ttl_page_key(env: &Env, key: &(Symbol, u64)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// One privileged action in the compliance log.
+///
+/// Only addresses and IDs are kept, never record content or reasons, so
+/// nothing here needs erasing when a record is redacted. `record_id` is 0
+/// for actions not tied to one record, and `counterparty` is the grantee
+/// for grants and revocations.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ComplianceEntry {
+    pub seq: u64,
+    pub action: Symbol,
+    pub actor: Address,
+    pub patient: Address,
+    pub counterparty: Option<Address>,
+    pub record_id: u64,
+    pub timestamp: u64,
+}
+
+/// One page of the compliance log, with the number of entries logged so
+/// far so readers can tell a partly filled last page from a gap.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CompliancePage {
+    pub page_no: u64,
+    pub entries: Vec<ComplianceEntry>,
+    pub total: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// The log is append-only. Entry `n` is the `n % COMPLIANCE_PAGE_SIZE`-th
+// item of page `n / COMPLIANCE_PAGE_SIZE`; pages are never rewritten once
+// the counter has moved past them, and nothing deletes them.
+
+pub fn page_key(page_no: u64) -> (Symbol, u64) {
+    (COMPLIANCE_LOG, page_no)
+}
+
+/// Number of entries ever logged, which is also the next sequence number.
+pub fn total_logged(env: &Env) -> u64 {
+    env.storage().instance().get(&COMPLIANCE_SEQ).unwrap_or(0)
+}
+
+pub fn get_page(env: &Env, page_no: u64) -> Vec<ComplianceEntry> {
+    env.storage()
+        .persistent()
+        .get(&page_key(page_no))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Appends an entry under the next sequence number.
+#[allow(clippy::arithmetic_side_effects)]
+pub fn log(
+    env: &Env,
+    action: Symbol,
+    actor: &Address,
+    patient: &Address,
+    counterparty: Option<&Address>,
+    record_id: u64,
+) {
+    let seq = total_logged(env);
+    let page_no = seq / COMPLIANCE_PAGE_SIZE;
+    let mut page = get_page(env, page_no);
+    page.push_back(ComplianceEntry {
+        seq,
+        action,
+        actor: actor.clone(),
+        patient: patient.clone(),
+        counterparty: counterparty.cloned(),
+        record_id,
+        timestamp: env.ledger().timestamp(),
+    });
+    let key = page_key(page_no);
+    env.storage().persistent().set(&key, &page);
+    extend_ttl_page_key(env, &key);
+    env.storage()
+        .instance()
+        .set(&COMPLIANCE_SEQ, &seq.saturating_add(1));
+}
@@ -0,0 +1,37 @@
+use soroban_sdk::{symbol_short, xdr::ToXdr, Address, Bytes, BytesN, Env, Symbol};
+
+use crate::errors::ContractError;
+
+// ── Operations ────────────────────────────────────────────────
+//
+// Irreversible operations take a `confirm` value computed from the
+// operation and the address it acts on, so a call with swapped or
+// pasted-over addresses fails instead of acting on the wrong account. The
+// value is public: it guards against mistakes, not against attackers.
+
+pub const FINALIZE_RECOVERY: Symbol = symbol_short!("FIN_RECOV");
+pub const MIGRATE_GRANTEE: Symbol = symbol_short!("MIG_GRNT");
+
+/// SHA-256 of the contract address, `operation` and `target`, each XDR
+/// encoded, in that order.
+pub fn compute(env: &Env, operation: &Symbol, target: &Address) -> BytesN<32> {
+    let mut preimage = Bytes::new(env);
+    preimage.append(&env.current_contract_address().to_xdr(env));
+    preimage.append(&operation.clone().to_xdr(env));
+    preimage.append(&target.clone().to_xdr(env));
+    env.crypto().sha256(&preimage).to_bytes()
+}
+
+/// Fails with `ConfirmationMismatch` unless `confirm` was computed for
+/// `operation` on `target`.
+pub fn require(
+    env: &Env,
+    operation: &Symbol,
+    target: &Address,
+    confirm: &BytesN<32>,
+) -> Result<(), ContractError> {
+    if compute(env, operation, target) != *confirm {
+        return Err(ContractError::ConfirmationMismatch);
+    }
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use soroban_sdk::{contracttype, symbol_short, xdr::ToXdr, Address, BytesN, Env, Symbol};
+
+use crate::{ConsentGrant, ConsentType};
+
+// ── Storage keys ──────────────────────────────────────────────
+const CONSENT_WITHDRAWN: Symbol = symbol_short!("CST_WDRN");
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a consent's withdrawal time.
+fn extend_ttl_withdrawn_key(env: &Env, key: &(Symbol, Address, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// The fields a consent proof commits to. `proof_digest` is the SHA-256 of
+/// this struct's XDR encoding, so a verifier holding the same fields can
+/// recompute it off-chain.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConsentProofFields {
+    /// The contract the consent was recorded in.
+    pub contract: Address,
+    pub patient: Address,
+    pub provider: Address,
+    pub scope: ConsentType,
+    pub given_at: u64,
+    pub withdrawn_at: Option<u64>,
+}
+
+/// A receipt showing a patient consented to a provider, and whether and
+/// when that consent was withdrawn.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConsentProof {
+    pub patient: Address,
+    pub provider: Address,
+    pub scope: ConsentType,
+    pub given_at: u64,
+    pub withdrawn_at: Option<u64>,
+    pub proof_digest: BytesN<32>,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// A withdrawn consent stays in storage flagged `revoked`; its withdrawal
+// time is kept alongside until the patient consents to the same provider
+// again.
+
+pub fn withdrawn_key(patient: &Address, grantee: &Address) -> (Symbol, Address, Address) {
+    (CONSENT_WITHDRAWN, patient.clone(), grantee.clone())
+}
+
+pub fn get_withdrawn_at(env: &Env, patient: &Address, grantee: &Address) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&withdrawn_key(patient, grantee))
+}
+
+pub fn set_withdrawn_at(env: &Env, patient: &Address, grantee: &Address, withdrawn_at: u64) {
+    let key = withdrawn_key(patient, grantee);
+    env.storage().persistent().set(&key, &withdrawn_at);
+    extend_ttl_withdrawn_key(env, &key);
+}
+
+pub fn clear_withdrawn_at(env: &Env, patient: &Address, grantee: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&withdrawn_key(patient, grantee));
+}
+
+pub fn digest(env: &Env, fields: &ConsentProofFields) -> BytesN<32> {
+    env.crypto().sha256(&fields.clone().to_xdr(env)).to_bytes()
+}
+
+/// Builds the proof for a stored consent.
+pub fn prove(env: &Env, consent: &ConsentGrant) -> ConsentProof {
+    let withdrawn_at = get_withdrawn_at(env, &consent.patient, &consent.grantee);
+    let fields = ConsentProofFields {
+        contract: env.current_contract_address(),
+        patient: consent.patient.clone(),
+        provider: consent.grantee.clone(),
+        scope: consent.consent_type.clone(),
+        given_at: consent.granted_at,
+        withdrawn_at,
+    };
+    ConsentProof {
+        proof_digest: digest(env, &fields),
+        patient: fields.patient,
+        provider: fields.provider,
+        scope: fields.scope,
+        given_at: fields.given_at,
+        withdrawn_at: fields.withdrawn_at,
+    }
+}
@@ -0,0 +1,101 @@
+use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
+
+use crate::instance_usage::{InstanceKey, ADDRESS_BYTES};
+use crate::purpose::GrantPurpose;
+use crate::ContractError;
+
+// ── Storage keys ──────────────────────────────────────────────
+const CONTRACT_GRANTEES: Symbol = symbol_short!("CTR_GRTS");
+const CONTRACT_GRANT_MAX: Symbol = symbol_short!("CTR_GMAX");
+
+/// Instance-storage keys written here.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] = &[
+    InstanceKey::list(CONTRACT_GRANTEES, ADDRESS_BYTES),
+    InstanceKey::number(CONTRACT_GRANT_MAX),
+];
+
+/// Most integration contracts that may be marked at once.
+pub const MAX_CONTRACT_GRANTEES: u32 = 100;
+
+/// Longest grant to a marked contract until an admin changes it.
+pub const DEFAULT_CONTRACT_GRANT_MAX_DURATION: u64 = 2_592_000; // 30 days
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// The contract cannot tell a contract address from an account, so admins
+// mark known integration contracts (analytics, claims and the like) by
+// hand. Grants to marked addresses must declare a purpose and end within
+// the contract grant cap; grants to unmarked contracts are treated like
+// any other.
+
+/// Every marked integration contract, in the order they were marked.
+pub fn get_marked(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&CONTRACT_GRANTEES)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn is_marked(env: &Env, address: &Address) -> bool {
+    get_marked(env).contains(address)
+}
+
+/// Marks `address` as an integration contract. Fails with `InvalidInput`
+/// once `MAX_CONTRACT_GRANTEES` are marked.
+pub fn mark(env: &Env, address: &Address) -> Result<(), ContractError> {
+    let mut marked = get_marked(env);
+    if marked.contains(address) {
+        return Ok(());
+    }
+    if marked.len() >= MAX_CONTRACT_GRANTEES {
+        return Err(ContractError::InvalidInput);
+    }
+    marked.push_back(address.clone());
+    env.storage().instance().set(&CONTRACT_GRANTEES, &marked);
+    Ok(())
+}
+
+/// Removes the mark from `address`. Returns whether it was marked.
+pub fn unmark(env: &Env, address: &Address) -> bool {
+    let mut marked = get_marked(env);
+    match marked.first_index_of(address) {
+        Some(index) => {
+            marked.remove(index);
+            env.storage().instance().set(&CONTRACT_GRANTEES, &marked);
+            true
+        }
+        None => false,
+    }
+}
+
+pub fn get_max_duration(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&CONTRACT_GRANT_MAX)
+        .unwrap_or(DEFAULT_CONTRACT_GRANT_MAX_DURATION)
+}
+
+pub fn set_max_duration(env: &Env, seconds: u64) {
+    env.storage().instance().set(&CONTRACT_GRANT_MAX, &seconds);
+}
+
+/// Checks the terms of a grant to `grantee`. A marked contract needs a
+/// declared `purpose` (`PurposeRequired`) and a duration within the
+/// contract grant cap (`DurationTooLong`).
+pub fn check_grant(
+    env: &Env,
+    grantee: &Address,
+    purpose: Option<&GrantPurpose>,
+    duration_seconds: u64,
+) -> Result<(), ContractError> {
+    if !is_marked(env, grantee) {
+        return Ok(());
+    }
+    if purpose.is_none() {
+        return Err(ContractError::PurposeRequired);
+    }
+    if duration_seconds > get_max_duration(env) {
+        return Err(ContractError::DurationTooLong);
+    }
+    Ok(())
+}
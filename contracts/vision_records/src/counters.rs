@@ -0,0 +1,27 @@
+use soroban_sdk::{Env, Symbol};
+
+use crate::errors::ContractError;
+
+/// Returns `current + 1`, or `CounterOverflow` instead of wrapping.
+pub fn checked_next_u64(current: u64) -> Result<u64, ContractError> {
+    current.checked_add(1).ok_or(ContractError::CounterOverflow)
+}
+
+/// Returns `current + 1`, or `CounterOverflow` instead of wrapping.
+pub fn checked_next_u32(current: u32) -> Result<u32, ContractError> {
+    current.checked_add(1).ok_or(ContractError::CounterOverflow)
+}
+
+/// Returns the last ID handed out by an instance-storage counter, or 0 if
+/// the counter has never been used.
+pub fn current_id(env: &Env, key: &Symbol) -> u64 {
+    env.storage().instance().get(key).unwrap_or(0)
+}
+
+/// Advances an instance-storage ID counter and returns the new ID.
+/// The counter is left untouched if it would overflow.
+pub fn next_id(env: &Env, key: &Symbol) -> Result<u64, ContractError> {
+    let next = checked_next_u64(current_id(env, key))?;
+    env.storage().instance().set(key, &next);
+    Ok(next)
+}
@@ -0,0 +1,127 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::errors::ContractError;
+use crate::versioning::RecordVersion;
+
+// ── Storage keys ──────────────────────────────────────────────
+const RECORDS_GENERATION: Symbol = symbol_short!("GEN_PREC");
+const HISTORY_GENERATION: Symbol = symbol_short!("GEN_HIST");
+
+/// Maximum number of entries returned by one cursor page.
+pub const MAX_CURSOR_PAGE: u32 = 50;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a patient's record list generation.
+fn extend_ttl_patient_key(env: &Env, key: &(Symbol, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+/// Extends the time-to-live (TTL) for a record's history generation.
+fn extend_ttl_record_key(env: &Env, key: &(Symbol, u64)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Where the next page of a list starts. Clients should pass it back
+/// unchanged rather than build one.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cursor {
+    pub position: u64,
+    /// Generation of the list when the cursor was issued.
+    pub generation: u32,
+}
+
+/// A page of a patient's record IDs.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordIdPage {
+    pub record_ids: Vec<u64>,
+    /// Whether the list continues past this page.
+    pub has_more: bool,
+    /// Where the following page starts. Once `has_more` is false it points
+    /// past the end, and resuming from it returns an empty page.
+    pub next: Cursor,
+}
+
+/// A page of a record's version history, oldest first.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionPage {
+    pub versions: Vec<RecordVersion>,
+    /// Whether the history continues past this page.
+    pub has_more: bool,
+    /// Where the following page starts, as for `RecordIdPage::next`.
+    pub next: Cursor,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// A list's generation is bumped whenever entries move or disappear, which
+// would make a position taken earlier skip or repeat entries. Appends
+// leave positions intact and do not bump it. Version history is only ever
+// appended to today, so its generation stays at zero until something
+// prunes or rewrites versions.
+
+pub fn records_generation_key(patient: &Address) -> (Symbol, Address) {
+    (RECORDS_GENERATION, patient.clone())
+}
+
+pub fn history_generation_key(record_id: u64) -> (Symbol, u64) {
+    (HISTORY_GENERATION, record_id)
+}
+
+pub fn records_generation(env: &Env, patient: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&records_generation_key(patient))
+        .unwrap_or(0)
+}
+
+/// Invalidates cursors into `patient`'s record list.
+pub fn bump_records_generation(env: &Env, patient: &Address) {
+    let key = records_generation_key(patient);
+    let next = records_generation(env, patient).wrapping_add(1);
+    env.storage().persistent().set(&key, &next);
+    extend_ttl_patient_key(env, &key);
+}
+
+pub fn history_generation(env: &Env, record_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&history_generation_key(record_id))
+        .unwrap_or(0)
+}
+
+/// Invalidates cursors into a record's version history.
+pub fn bump_history_generation(env: &Env, record_id: u64) {
+    let key = history_generation_key(record_id);
+    let next = history_generation(env, record_id).wrapping_add(1);
+    env.storage().persistent().set(&key, &next);
+    extend_ttl_record_key(env, &key);
+}
+
+/// The index `cursor` resumes from in a list now at `generation`, or the
+/// start of the list when there is no cursor.
+pub fn resume(cursor: &Option<Cursor>, generation: u32) -> Result<u32, ContractError> {
+    match cursor {
+        None => Ok(0),
+        Some(cursor) if cursor.generation != generation => Err(ContractError::StaleCursor),
+        Some(cursor) => u32::try_from(cursor.position).map_err(|_| ContractError::InvalidInput),
+    }
+}
+
+/// The cursor for the page after one that ended at `end`.
+pub fn next(end: u32, generation: u32) -> Cursor {
+    Cursor {
+        position: u64::from(end),
+        generation,
+    }
+}
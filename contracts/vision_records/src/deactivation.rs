@@ -0,0 +1,104 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+// ── Storage keys ──────────────────────────────────────────────
+const DEACTIVATED: Symbol = symbol_short!("DEACTIV");
+const CLOSURE: Symbol = symbol_short!("ACCT_CLS");
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a user's deactivation flag or
+/// closure progress.
+fn extend_ttl_flag_key(env: &Env, key: &(Symbol, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// What else to clean up when a user is deactivated.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DeactivationCascade {
+    /// Only mark the user inactive.
+    None,
+    /// Also revoke every role and scoped delegation the user made.
+    RevokeOutgoingDelegations,
+    /// Also revoke the patient-wide grants the user received.
+    RevokeAll,
+}
+
+/// What one `deactivate_user` call cleaned up. Call again with the same
+/// cascade while `remaining` is non-zero to finish.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeactivationSummary {
+    pub user: Address,
+    pub cascade: DeactivationCascade,
+    pub delegations_revoked: u32,
+    pub grants_revoked: u32,
+    /// Delegations and grants in scope of the cascade still to revoke.
+    pub remaining: u32,
+}
+
+/// Progress of removing what points at a closed account. Counts add up
+/// over every `close_account` call; the cleanup is done once `remaining`
+/// reaches zero.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CleanupProgress {
+    pub user: Address,
+    pub closed_by: Address,
+    pub closed_at: u64,
+    pub delegations_removed: u32,
+    pub grants_removed: u32,
+    /// Delegations to or from the user and grants to the user still to
+    /// remove.
+    pub remaining: u32,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// The flag is kept beside the user rather than read from `User.is_active`
+// so permission checks need not load the profile.
+
+pub fn deactivated_key(user: &Address) -> (Symbol, Address) {
+    (DEACTIVATED, user.clone())
+}
+
+/// Whether the user has been deactivated. Deactivated users hold no
+/// permissions until reactivated.
+pub fn is_deactivated(env: &Env, user: &Address) -> bool {
+    env.storage().persistent().has(&deactivated_key(user))
+}
+
+pub fn set_deactivated(env: &Env, user: &Address, deactivated: bool) {
+    let key = deactivated_key(user);
+    if deactivated {
+        env.storage().persistent().set(&key, &true);
+        extend_ttl_flag_key(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn closure_key(user: &Address) -> (Symbol, Address) {
+    (CLOSURE, user.clone())
+}
+
+/// Whether the user's account was closed. Closed accounts stay
+/// deactivated for good.
+pub fn is_closed(env: &Env, user: &Address) -> bool {
+    env.storage().persistent().has(&closure_key(user))
+}
+
+pub fn get_cleanup_progress(env: &Env, user: &Address) -> Option<CleanupProgress> {
+    env.storage().persistent().get(&closure_key(user))
+}
+
+pub fn set_cleanup_progress(env: &Env, progress: &CleanupProgress) {
+    let key = closure_key(&progress.user);
+    env.storage().persistent().set(&key, progress);
+    extend_ttl_flag_key(env, &key);
+}
@@ -0,0 +1,78 @@
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+use crate::instance_usage::InstanceKey;
+use crate::rbac::Role;
+use crate::temp_storage;
+
+// ── Storage keys ──────────────────────────────────────────────
+const COOLDOWN_SECONDS: Symbol = symbol_short!("DLG_CDS");
+const COOLDOWN_UNTIL: Symbol = symbol_short!("DLG_CD");
+
+/// Instance-storage keys written here.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] = &[InstanceKey::number(COOLDOWN_SECONDS)];
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// A revoked role delegation cannot be made again for the same
+// (delegator, delegatee, role) until its cooldown ends. Each cooldown is
+// a temporary entry holding the end time, kept a day past it so the
+// network drops it on its own.
+
+/// Seconds a revoked delegation stays blocked. 0, the default, turns
+/// cooldowns off.
+pub fn get_cooldown_seconds(env: &Env) -> u64 {
+    env.storage().instance().get(&COOLDOWN_SECONDS).unwrap_or(0)
+}
+
+pub fn set_cooldown_seconds(env: &Env, seconds: u64) {
+    env.storage().instance().set(&COOLDOWN_SECONDS, &seconds);
+}
+
+pub fn cooldown_key(
+    delegator: &Address,
+    delegatee: &Address,
+    role: &Role,
+) -> (Symbol, Address, Address, Role) {
+    (
+        COOLDOWN_UNTIL,
+        delegator.clone(),
+        delegatee.clone(),
+        role.clone(),
+    )
+}
+
+/// Starts the cooldown for a delegation revoked now, unless cooldowns
+/// are off. Later changes to the setting leave running cooldowns alone.
+pub fn start(env: &Env, delegator: &Address, delegatee: &Address, role: &Role) {
+    let seconds = get_cooldown_seconds(env);
+    if seconds == 0 {
+        return;
+    }
+    let until = env.ledger().timestamp().saturating_add(seconds);
+    let ttl = temp_storage::ledgers_for_seconds(seconds).saturating_add(temp_storage::TEMP_TTL_DAY);
+    temp_storage::set_temp(env, &cooldown_key(delegator, delegatee, role), &until, ttl);
+}
+
+/// When the cooldown on this delegation ends, or `None` if there is none
+/// running.
+pub fn active_until(
+    env: &Env,
+    delegator: &Address,
+    delegatee: &Address,
+    role: &Role,
+) -> Option<u64> {
+    let until: u64 = temp_storage::get_temp(env, &cooldown_key(delegator, delegatee, role))?;
+    if until <= env.ledger().timestamp() {
+        return None;
+    }
+    Some(until)
+}
+
+/// Ends a cooldown early. Returns false if none was running.
+pub fn clear(env: &Env, delegator: &Address, delegatee: &Address, role: &Role) -> bool {
+    if active_until(env, delegator, delegatee, role).is_none() {
+        return false;
+    }
+    temp_storage::remove_temp(env, &cooldown_key(delegator, delegatee, role));
+    true
+}
@@ -0,0 +1,123 @@
+use soroban_sdk::{
+    contracttype, symbol_short, xdr::ToXdr, Address, BytesN, Env, String, Symbol, Vec,
+};
+
+use crate::{redaction, versioning};
+use crate::{AccessLevel, VisionRecord};
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Which part of the contract state a digest covers.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DigestScope {
+    Patient(Address),
+    Record(u64),
+    Global,
+}
+
+/// Canonical form of one record for digesting: the record as stored
+/// (data hash still encrypted), its version count, the data hash of its
+/// latest version and which earlier versions have been redacted.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RecordState {
+    pub record: VisionRecord,
+    pub version_count: u32,
+    pub latest_hash: Option<String>,
+    /// Redaction never touches the latest version, so without this marker
+    /// a redaction would leave the digest unchanged.
+    pub redacted_versions: Vec<u32>,
+}
+
+/// Canonical form of a patient for digesting: the digest of each of their
+/// records, in the order of their record list.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PatientState {
+    pub patient: Address,
+    pub record_digests: Vec<BytesN<32>>,
+}
+
+/// Canonical form of contract-wide state for digesting: ID counters and
+/// configuration only, so computing it never scans stored entries.
+///
+/// Audit and error-log counters are left out. They move on reads, so
+/// including them would change the digest without any data changing.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GlobalState {
+    pub admin: Option<Address>,
+    /// Records created so far, summed over the record counter shards.
+    pub record_counter: u64,
+    pub prescription_counter: u64,
+    pub provider_counter: u64,
+    pub appointment_counter: u64,
+    pub emergency_counter: u64,
+    /// `(max_requests, window_seconds)`, or `(0, 0)` when unset.
+    pub rate_limit: (u64, u64),
+    pub encryption_key_version: Option<String>,
+    pub default_patient_quota: u32,
+    pub expiry_grace: u64,
+    pub offer_window: u64,
+    /// 0 when grants are not capped.
+    pub max_grant_duration: u64,
+    pub auto_critical: bool,
+    pub self_registration: bool,
+    pub self_registration_daily_cap: u32,
+    /// Registration auto-grant. When it is off, the level is `None` and
+    /// the duration 0.
+    pub auto_grant_enabled: bool,
+    pub auto_grant_level: AccessLevel,
+    pub auto_grant_duration: u64,
+    pub hash_algorithms: Vec<Symbol>,
+    pub paused: bool,
+}
+
+// ── Digest Functions ─────────────────────────────────────────
+//
+// A digest is the SHA-256 of the XDR encoding of one of the state structs
+// above. XDR is canonical for a given value, so the digest is stable while
+// the covered entries are unchanged and moves when any field does.
+
+pub fn record_state(env: &Env, record_id: u64) -> Option<RecordState> {
+    let record: VisionRecord = env
+        .storage()
+        .persistent()
+        .get(&(symbol_short!("RECORD"), record_id))?;
+    let version_count = versioning::get_version_count(env, record_id);
+    let latest_hash = versioning::get_version(env, record_id, version_count).map(|v| v.data_hash);
+    Some(RecordState {
+        record,
+        version_count,
+        latest_hash,
+        redacted_versions: redaction::get_redacted_versions(env, record_id),
+    })
+}
+
+pub fn record_digest(env: &Env, state: &RecordState) -> BytesN<32> {
+    env.crypto().sha256(&state.clone().to_xdr(env)).to_bytes()
+}
+
+/// Builds a patient's state from their record list. Records missing from
+/// storage are skipped.
+pub fn patient_state(env: &Env, patient: &Address, record_ids: &Vec<u64>) -> PatientState {
+    let mut record_digests = Vec::new(env);
+    for record_id in record_ids.iter() {
+        if let Some(state) = record_state(env, record_id) {
+            record_digests.push_back(record_digest(env, &state));
+        }
+    }
+    PatientState {
+        patient: patient.clone(),
+        record_digests,
+    }
+}
+
+pub fn patient_digest(env: &Env, state: &PatientState) -> BytesN<32> {
+    env.crypto().sha256(&state.clone().to_xdr(env)).to_bytes()
+}
+
+pub fn global_digest(env: &Env, state: &GlobalState) -> BytesN<32> {
+    env.crypto().sha256(&state.clone().to_xdr(env)).to_bytes()
+}
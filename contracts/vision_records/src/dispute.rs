@@ -0,0 +1,82 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
+
+// ── Storage keys ──────────────────────────────────────────────
+const RECORD_DISPUTE: Symbol = symbol_short!("DISPUTE");
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a record's dispute.
+fn extend_ttl_dispute_key(env: &Env, key: &(Symbol, u64)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Where a dispute stands. `Open` and `Responded` disputes are unresolved.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DisputeStatus {
+    Open,
+    /// The provider has answered; awaiting resolution.
+    Responded,
+    Resolved,
+}
+
+/// How a dispute was settled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DisputeResolution {
+    /// Not settled yet.
+    Pending,
+    /// The record was corrected.
+    Amended,
+    /// The record stands as written.
+    Upheld,
+    /// The patient dropped the dispute.
+    Withdrawn,
+}
+
+/// A patient's challenge to one of their records.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dispute {
+    pub record_id: u64,
+    pub patient: Address,
+    /// Hash of the patient's off-chain statement of what is wrong.
+    pub reason_hash: String,
+    pub status: DisputeStatus,
+    pub opened_at: u64,
+    /// Hash of the provider's off-chain response.
+    pub response_hash: Option<String>,
+    pub responded_at: Option<u64>,
+    pub resolution: DisputeResolution,
+    pub resolved_by: Option<Address>,
+    pub resolved_at: Option<u64>,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// Each record keeps its latest dispute only. A resolved dispute stays
+// readable until the patient opens a new one, which replaces it.
+
+pub fn dispute_key(record_id: u64) -> (Symbol, u64) {
+    (RECORD_DISPUTE, record_id)
+}
+
+pub fn get(env: &Env, record_id: u64) -> Option<Dispute> {
+    env.storage().persistent().get(&dispute_key(record_id))
+}
+
+pub fn set(env: &Env, dispute: &Dispute) {
+    let key = dispute_key(dispute.record_id);
+    env.storage().persistent().set(&key, dispute);
+    extend_ttl_dispute_key(env, &key);
+}
+
+/// Whether the record has a dispute that is not yet resolved.
+pub fn is_open(env: &Env, record_id: u64) -> bool {
+    get(env, record_id).is_some_and(|dispute| dispute.status != DisputeStatus::Resolved)
+}
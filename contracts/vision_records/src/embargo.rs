@@ -0,0 +1,63 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+// ── Storage keys ──────────────────────────────────────────────
+const EMBARGO: Symbol = symbol_short!("EMBARGO");
+
+/// How long after a record is created its provider may still embargo it.
+pub const EMBARGO_SET_WINDOW: u64 = 86_400;
+/// Furthest ahead of now a release date may be set (one year).
+pub const MAX_EMBARGO_DURATION: u64 = 31_536_000;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a record's embargo.
+fn extend_ttl_embargo_key(env: &Env, key: &(Symbol, u64)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A record withheld from its patient, and anyone reading through the
+/// patient's grants, until `release_at`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordEmbargo {
+    pub record_id: u64,
+    pub provider: Address,
+    pub release_at: u64,
+    pub set_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// An embargo lapses on its own once `release_at` passes; the stored
+// entry is left in place and simply ignored from then on.
+
+pub fn embargo_key(record_id: u64) -> (Symbol, u64) {
+    (EMBARGO, record_id)
+}
+
+/// The record's embargo, if one is still in force.
+pub fn get_active(env: &Env, record_id: u64) -> Option<RecordEmbargo> {
+    env.storage()
+        .persistent()
+        .get::<_, RecordEmbargo>(&embargo_key(record_id))
+        .filter(|embargo| env.ledger().timestamp() < embargo.release_at)
+}
+
+pub fn is_embargoed(env: &Env, record_id: u64) -> bool {
+    get_active(env, record_id).is_some()
+}
+
+pub fn set(env: &Env, embargo: &RecordEmbargo) {
+    let key = embargo_key(embargo.record_id);
+    env.storage().persistent().set(&key, embargo);
+    extend_ttl_embargo_key(env, &key);
+}
+
+pub fn clear(env: &Env, record_id: u64) {
+    env.storage().persistent().remove(&embargo_key(record_id));
+}
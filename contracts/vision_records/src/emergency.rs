@@ -1,11 +1,19 @@
 use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
 
+use crate::counters;
+use crate::errors::ContractError;
+use crate::expiry;
+use crate::instance_usage::InstanceKey;
+
 // ── Storage keys ──────────────────────────────────────────────
 pub const EMRG_CTR: Symbol = symbol_short!("EMRG_CTR");
 const EMRG_ACCESS: Symbol = symbol_short!("EMRG_ACC");
 const EMRG_AUDIT: Symbol = symbol_short!("EMRG_AUD");
 const EMRG_PATIENT: Symbol = symbol_short!("EMRG_PAT");
 
+/// Instance-storage keys written here.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] = &[InstanceKey::number(EMRG_CTR)];
+
 const TTL_THRESHOLD: u32 = 5184000;
 const TTL_EXTEND_TO: u32 = 10368000;
 
@@ -73,11 +81,8 @@ pub struct EmergencyAuditEntry {
 // ── Storage Functions ────────────────────────────────────────
 
 /// Increments and returns the next emergency access ID
-pub fn increment_emergency_counter(env: &Env) -> u64 {
-    let current: u64 = env.storage().instance().get(&EMRG_CTR).unwrap_or(0);
-    let next = current + 1;
-    env.storage().instance().set(&EMRG_CTR, &next);
-    next
+pub fn increment_emergency_counter(env: &Env) -> Result<u64, ContractError> {
+    counters::next_id(env, &EMRG_CTR)
 }
 
 /// Stores an emergency access grant
@@ -115,7 +120,7 @@ pub fn has_active_emergency_access(
             if access.patient == *patient
                 && access.requester == *requester
                 && access.status == EmergencyStatus::Active
-                && access.expires_at > env.ledger().timestamp()
+                && !expiry::is_expired(env, access.expires_at)
             {
                 return Some(access);
             }
@@ -194,12 +199,13 @@ pub fn expire_emergency_accesses(env: &Env) -> u32 {
     let mut expired_count = 0u32;
     let counter: u64 = env.storage().instance().get(&EMRG_CTR).unwrap_or(0);
     let start_id = if counter > 100 { counter - 100 } else { 1 };
-    let current_time = env.ledger().timestamp();
 
     for id in start_id..=counter {
         let key = (EMRG_ACCESS, id);
         if let Some(mut access) = env.storage().persistent().get::<_, EmergencyAccess>(&key) {
-            if access.status == EmergencyStatus::Active && access.expires_at <= current_time {
+            if access.status == EmergencyStatus::Active
+                && expiry::is_expired(env, access.expires_at)
+            {
                 access.status = EmergencyStatus::Expired;
                 env.storage().persistent().set(&key, &access);
                 extend_ttl_emergency_key(env, &key);
@@ -1,10 +1,20 @@
 #![allow(clippy::arithmetic_side_effects)]
 use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
 
+use crate::instance_usage::{InstanceKey, NUMBER_BYTES};
+
 pub const ERROR_LOG_KEY: Symbol = symbol_short!("ERR_LOG");
 pub const ERROR_COUNT_KEY: Symbol = symbol_short!("ERR_CNT");
 pub const MAX_ERROR_LOG_SIZE: u32 = 100;
 
+/// Instance-storage keys written here. Retry counters are keyed by caller
+/// and operation.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] = &[
+    InstanceKey::list(ERROR_LOG_KEY, 200),
+    InstanceKey::number(ERROR_COUNT_KEY),
+    InstanceKey::keyed(RETRY_COUNT_KEY, 96 + NUMBER_BYTES),
+];
+
 const TTL_THRESHOLD: u32 = 5184000;
 const TTL_EXTEND_TO: u32 = 10368000;
 
@@ -113,6 +123,63 @@ pub enum ContractError {
     InvalidAttestation = 34,
     InvalidAppointmentTime = 35,
     InvalidAppointmentStatus = 36,
+    PendingGrantNotFound = 37,
+    VersionSpanTooLarge = 38,
+    AlreadyExists = 39,
+    RecordSuperseded = 40,
+    CounterOverflow = 41,
+    TemplateNotFound = 42,
+    AccessOfferNotFound = 43,
+    UnsupportedHashAlgorithm = 44,
+    PinLimitReached = 45,
+    ClaimAttestationNotFound = 46,
+    SessionNotFound = 47,
+    QuotaExceeded = 48,
+    DuplicateExternalRef = 49,
+    ProvisionalAlreadyClaimed = 50,
+    BreachLockdown = 51,
+    SigningKeyNotFound = 52,
+    RecordImmutable = 53,
+    VersionPinned = 54,
+    Embargoed = 55,
+    DurationTooLong = 56,
+    ShareCodeNotFound = 57,
+    AccessOverrideNotFound = 58,
+    MaintenanceStale = 59,
+    ConsentNotFound = 60,
+    IndexTooLarge = 61,
+    RevocationNotPending = 62,
+    RecoveryNotFound = 63,
+    RecoveryLocked = 64,
+    InvalidRecoveryProof = 65,
+    DisputeNotFound = 66,
+    InvalidDisputeStatus = 67,
+    StaleCursor = 68,
+    ObserverLimitReached = 69,
+    LegacyEndpointDisabled = 70,
+    OrganizationNotFound = 71,
+    ResidencyMismatch = 72,
+    AdminReasonRequired = 73,
+    ConfirmationMismatch = 74,
+    ShareLimitReached = 75,
+    RecordPending = 76,
+    FeatureDisabled = 77,
+    PresetNotFound = 78,
+    PresetRequirementUnmet = 79,
+    DelegationCooldown = 80,
+    AccountClosed = 81,
+    SelfGrantViaDelegation = 82,
+    JobNotFound = 83,
+    JobAlreadyRunning = 84,
+    JobNotRunning = 85,
+    PurposeRequired = 86,
+    LegalHold = 87,
+    AccessGrantNotFound = 88,
+    ContractGranteeNotFound = 89,
+    LegalHoldNotFound = 90,
+    PatientLabelNotFound = 91,
+    ShareCodeLimitReached = 92,
+    RecoveryInProgress = 93,
 }
 
 impl ContractError {
@@ -134,24 +201,80 @@ impl ContractError {
             | ContractError::InvalidAttestation
             | ContractError::InvalidAppointmentTime
             | ContractError::InvalidAppointmentStatus
+            | ContractError::InvalidDisputeStatus
             | ContractError::AppointmentNotVerified
+            | ContractError::VersionSpanTooLarge
+            | ContractError::UnsupportedHashAlgorithm
+            | ContractError::PinLimitReached
+            | ContractError::ObserverLimitReached
+            | ContractError::ShareLimitReached
+            | ContractError::ShareCodeLimitReached
+            | ContractError::QuotaExceeded
+            | ContractError::DurationTooLong
+            | ContractError::PurposeRequired
+            | ContractError::IndexTooLarge
+            | ContractError::ConfirmationMismatch
+            | ContractError::PresetRequirementUnmet
             | ContractError::MetaTxExpired => ErrorCategory::Validation,
             ContractError::Unauthorized
             | ContractError::AccessDenied
             | ContractError::InsufficientPermissions
             | ContractError::ExpiredAccess
             | ContractError::ConsentRequired
-            | ContractError::ConsentExpired => ErrorCategory::Authorization,
+            | ContractError::ConsentExpired
+            | ContractError::BreachLockdown
+            | ContractError::MaintenanceStale
+            | ContractError::InvalidRecoveryProof
+            | ContractError::Embargoed
+            | ContractError::LegacyEndpointDisabled
+            | ContractError::FeatureDisabled
+            | ContractError::ResidencyMismatch
+            | ContractError::AdminReasonRequired
+            | ContractError::SelfGrantViaDelegation => ErrorCategory::Authorization,
             ContractError::UserNotFound
             | ContractError::RecordNotFound
             | ContractError::ProviderNotFound
             | ContractError::EmergencyAccessNotFound
-            | ContractError::AppointmentNotFound => ErrorCategory::NotFound,
+            | ContractError::AppointmentNotFound
+            | ContractError::PendingGrantNotFound
+            | ContractError::TemplateNotFound
+            | ContractError::PresetNotFound
+            | ContractError::AccessOfferNotFound
+            | ContractError::AccessOverrideNotFound
+            | ContractError::ClaimAttestationNotFound
+            | ContractError::ConsentNotFound
+            | ContractError::RevocationNotPending
+            | ContractError::RecoveryNotFound
+            | ContractError::DisputeNotFound
+            | ContractError::OrganizationNotFound
+            | ContractError::SessionNotFound
+            | ContractError::ShareCodeNotFound
+            | ContractError::SigningKeyNotFound
+            | ContractError::JobNotFound
+            | ContractError::AccessGrantNotFound
+            | ContractError::ContractGranteeNotFound
+            | ContractError::LegalHoldNotFound
+            | ContractError::PatientLabelNotFound => ErrorCategory::NotFound,
             ContractError::ProviderAlreadyRegistered
             | ContractError::DuplicateRecord
+            | ContractError::DuplicateExternalRef
+            | ContractError::ProvisionalAlreadyClaimed
+            | ContractError::RecordImmutable
+            | ContractError::VersionPinned
             | ContractError::DelegationExpired
+            | ContractError::AlreadyExists
+            | ContractError::RecordSuperseded
+            | ContractError::RecordPending
+            | ContractError::RecoveryLocked
+            | ContractError::RecoveryInProgress
+            | ContractError::DelegationCooldown
+            | ContractError::AccountClosed
+            | ContractError::LegalHold
+            | ContractError::JobAlreadyRunning
+            | ContractError::JobNotRunning
+            | ContractError::StaleCursor
             | ContractError::NonceAlreadyUsed => ErrorCategory::StateConflict,
-            ContractError::StorageError => ErrorCategory::Storage,
+            ContractError::StorageError | ContractError::CounterOverflow => ErrorCategory::Storage,
             ContractError::TransientFailure | ContractError::RateLimitExceeded => {
                 ErrorCategory::Transient
             }
@@ -177,10 +300,29 @@ impl ContractError {
             | ContractError::InvalidAttestation
             | ContractError::InvalidAppointmentTime
             | ContractError::InvalidAppointmentStatus
+            | ContractError::InvalidDisputeStatus
             | ContractError::UserNotFound
             | ContractError::RecordNotFound
             | ContractError::ProviderNotFound
             | ContractError::DuplicateRecord
+            | ContractError::DuplicateExternalRef
+            | ContractError::ProvisionalAlreadyClaimed
+            | ContractError::RecordImmutable
+            | ContractError::VersionPinned
+            | ContractError::VersionSpanTooLarge
+            | ContractError::RecordSuperseded
+            | ContractError::RecordPending
+            | ContractError::UnsupportedHashAlgorithm
+            | ContractError::PinLimitReached
+            | ContractError::ObserverLimitReached
+            | ContractError::ShareLimitReached
+            | ContractError::ShareCodeLimitReached
+            | ContractError::DurationTooLong
+            | ContractError::PurposeRequired
+            | ContractError::IndexTooLarge
+            | ContractError::StaleCursor
+            | ContractError::ConfirmationMismatch
+            | ContractError::PresetRequirementUnmet
             | ContractError::MetaTxExpired => ErrorSeverity::Low,
             ContractError::Unauthorized
             | ContractError::AccessDenied
@@ -188,14 +330,53 @@ impl ContractError {
             | ContractError::ExpiredAccess
             | ContractError::ConsentRequired
             | ContractError::ConsentExpired
+            | ContractError::Embargoed
+            | ContractError::LegacyEndpointDisabled
+            | ContractError::FeatureDisabled
+            | ContractError::ResidencyMismatch
+            | ContractError::AdminReasonRequired
             | ContractError::ProviderAlreadyRegistered
+            | ContractError::AlreadyExists
             | ContractError::DelegationExpired
             | ContractError::RateLimitExceeded
+            | ContractError::QuotaExceeded
+            | ContractError::RecoveryLocked
+            | ContractError::RecoveryInProgress
+            | ContractError::DelegationCooldown
+            | ContractError::AccountClosed
+            | ContractError::LegalHold
+            | ContractError::JobAlreadyRunning
+            | ContractError::JobNotRunning
             | ContractError::NonceAlreadyUsed => ErrorSeverity::Medium,
             ContractError::EmergencyAccessNotFound
             | ContractError::AppointmentNotFound
-            | ContractError::AppointmentNotVerified => ErrorSeverity::Low,
-            ContractError::StorageError | ContractError::TransientFailure => ErrorSeverity::High,
+            | ContractError::AppointmentNotVerified
+            | ContractError::PendingGrantNotFound
+            | ContractError::TemplateNotFound
+            | ContractError::PresetNotFound
+            | ContractError::AccessOfferNotFound
+            | ContractError::AccessOverrideNotFound
+            | ContractError::ClaimAttestationNotFound
+            | ContractError::ConsentNotFound
+            | ContractError::RevocationNotPending
+            | ContractError::RecoveryNotFound
+            | ContractError::DisputeNotFound
+            | ContractError::OrganizationNotFound
+            | ContractError::SessionNotFound
+            | ContractError::ShareCodeNotFound
+            | ContractError::SigningKeyNotFound
+            | ContractError::JobNotFound
+            | ContractError::AccessGrantNotFound
+            | ContractError::ContractGranteeNotFound
+            | ContractError::LegalHoldNotFound
+            | ContractError::PatientLabelNotFound => ErrorSeverity::Low,
+            ContractError::StorageError
+            | ContractError::TransientFailure
+            | ContractError::CounterOverflow
+            | ContractError::BreachLockdown
+            | ContractError::MaintenanceStale
+            | ContractError::InvalidRecoveryProof
+            | ContractError::SelfGrantViaDelegation => ErrorSeverity::High,
             ContractError::Paused | ContractError::ContractPaused => ErrorSeverity::Critical,
         }
     }
@@ -251,6 +432,95 @@ impl ContractError {
             ContractError::InvalidAttestation => "Invalid emergency attestation provided",
             ContractError::InvalidAppointmentTime => "Invalid appointment time provided",
             ContractError::InvalidAppointmentStatus => "Invalid appointment status provided",
+            ContractError::PendingGrantNotFound => "No pending grant awaiting approval",
+            ContractError::VersionSpanTooLarge => "Version range is too large to compare",
+            ContractError::AlreadyExists => "Entry already exists",
+            ContractError::RecordSuperseded => "Record has been superseded by a newer record",
+            ContractError::CounterOverflow => "Counter reached its maximum value",
+            ContractError::TemplateNotFound => "Access template not found",
+            ContractError::AccessOfferNotFound => "No access offer awaiting acceptance",
+            ContractError::UnsupportedHashAlgorithm => "Hash algorithm is not registered",
+            ContractError::PinLimitReached => "Maximum number of pins reached",
+            ContractError::ClaimAttestationNotFound => "No active claim attestation found",
+            ContractError::SessionNotFound => "No active session found for this key",
+            ContractError::QuotaExceeded => "Patient has reached their record quota",
+            ContractError::DuplicateExternalRef => "External reference is already in use",
+            ContractError::ProvisionalAlreadyClaimed => {
+                "Provisional identity has already been claimed"
+            }
+            ContractError::BreachLockdown => "Patient is under breach lockdown",
+            ContractError::SigningKeyNotFound => "Patient has no registered signing key",
+            ContractError::RecordImmutable => "Patient notes cannot be modified",
+            ContractError::VersionPinned => "Record version is pinned",
+            ContractError::Embargoed => "Record is embargoed until its release date",
+            ContractError::DurationTooLong => "Grant duration exceeds the configured maximum",
+            ContractError::ShareCodeNotFound => "No share code matches for this record",
+            ContractError::AccessOverrideNotFound => {
+                "No access override is set for this grantee and record"
+            }
+            ContractError::MaintenanceStale => {
+                "Maintenance heartbeat is stale; grants on a patient's behalf are paused"
+            }
+            ContractError::ConsentNotFound => "No consent recorded for this patient and provider",
+            ContractError::IndexTooLarge => {
+                "Index is too large to rebuild in one call; a cursor-driven rebuild is required"
+            }
+            ContractError::RevocationNotPending => "No revocation is pending for this grant",
+            ContractError::RecoveryNotFound => "No recovery is pending for this patient",
+            ContractError::RecoveryLocked => "Recovery timelock has not yet elapsed",
+            ContractError::RecoveryInProgress => {
+                "Recovery has started moving the chart and can no longer be cancelled"
+            }
+            ContractError::InvalidRecoveryProof => {
+                "Recovery preimage does not match the challenge hash"
+            }
+            ContractError::DisputeNotFound => "No dispute has been opened for this record",
+            ContractError::InvalidDisputeStatus => "Dispute is not in a state that allows this",
+            ContractError::StaleCursor => "Cursor is stale; restart from the first page",
+            ContractError::ObserverLimitReached => "Maximum number of record observers reached",
+            ContractError::LegacyEndpointDisabled => {
+                "Endpoint is disabled; use its authenticated _as variant"
+            }
+            ContractError::OrganizationNotFound => "Organization or unit not found",
+            ContractError::ResidencyMismatch => {
+                "Provider's jurisdictions do not include the patient's residency region"
+            }
+            ContractError::AdminReasonRequired => {
+                "Admin record reads must go through read_record_as_admin with a reason"
+            }
+            ContractError::ConfirmationMismatch => {
+                "Confirmation does not match the operation and target address"
+            }
+            ContractError::ShareLimitReached => "Maximum number of active user shares reached",
+            ContractError::ShareCodeLimitReached => "Maximum number of live share codes reached",
+            ContractError::RecordPending => {
+                "Record is reserved but its data has not been committed"
+            }
+            ContractError::FeatureDisabled => "Feature is disabled for this deployment",
+            ContractError::PresetNotFound => "Record preset not found",
+            ContractError::PresetRequirementUnmet => {
+                "Record does not meet its preset's requirements"
+            }
+            ContractError::DelegationCooldown => {
+                "Delegation was revoked too recently to be made again"
+            }
+            ContractError::AccountClosed => "Account is closed and cannot be reactivated",
+            ContractError::SelfGrantViaDelegation => {
+                "A patient's delegate cannot grant itself access"
+            }
+            ContractError::JobNotFound => "Maintenance job not found",
+            ContractError::JobAlreadyRunning => "A maintenance job of this kind is already running",
+            ContractError::JobNotRunning => "Maintenance job has already finished",
+            ContractError::PurposeRequired => {
+                "Grants to an integration contract must declare a purpose"
+            }
+            ContractError::LegalHold => "Record is under a legal hold",
+            ContractError::AccessGrantNotFound => "Access grant not found",
+            ContractError::ContractGranteeNotFound => {
+                "Address is not a marked integration contract"
+            }
+            ContractError::LegalHoldNotFound => "Legal hold not found",
+            ContractError::PatientLabelNotFound => "Record has no patient label",
         }
     }
 }
@@ -1,12 +1,304 @@
 #![allow(deprecated)] // events().publish migration tracked separately
 
 use crate::appointment::AppointmentType;
+use crate::approval::PendingGrant;
 use crate::audit::{AccessAction, AccessResult, AuditEntry};
+use crate::breach::BreachFlag;
 use crate::circuit_breaker::PauseScope;
+use crate::claim::ClaimAttestation;
+use crate::deactivation::DeactivationCascade;
+use crate::dispute::DisputeResolution;
 use crate::emergency::EmergencyCondition;
 use crate::errors::{ErrorCategory, ErrorContext, ErrorSeverity};
+use crate::grant_creator;
+use crate::grant_revision;
+use crate::handoff_note::HandoffNote;
+use crate::jobs::{JobStatus, MaintenanceJob};
+use crate::legacy_read::LegacyReadMode;
+use crate::legal_hold::LegalHold;
+use crate::offer::AccessOffer;
+use crate::organization::OrgGrant;
+use crate::pending_gc::PendingCategory;
+use crate::pending_record::PendingRecord;
+use crate::priority::RecordPriority;
+use crate::purpose::GrantPurpose;
+use crate::rbac::Permission;
+use crate::session::Session;
+use crate::share_code::ShareCode;
+use crate::user_share::UserShare;
+use crate::write_session::WriteSession;
 use crate::{AccessLevel, RecordType, Role, VerificationStatus};
-use soroban_sdk::{symbol_short, Address, Env, String};
+use soroban_sdk::{Address, BytesN, Env, String, Symbol, Vec};
+
+/// Topic symbols for every event this contract publishes.
+///
+/// Each constant is the first topic of its event. The doc comment gives the
+/// full topic tuple and the payload type, so publishers, tests and indexers
+/// share one definition of each event's schema.
+pub mod topics {
+    use soroban_sdk::{symbol_short, Symbol};
+
+    /// `(ADM_PROP, current_admin)` → [`AdminTransferProposedEvent`](super::AdminTransferProposedEvent)
+    pub const ADM_PROP: Symbol = symbol_short!("ADM_PROP");
+    /// `(ADM_ACPT, new_admin)` → [`AdminTransferAcceptedEvent`](super::AdminTransferAcceptedEvent)
+    pub const ADM_ACPT: Symbol = symbol_short!("ADM_ACPT");
+    /// `(ADM_CNCL, admin)` → [`AdminTransferCancelledEvent`](super::AdminTransferCancelledEvent)
+    pub const ADM_CNCL: Symbol = symbol_short!("ADM_CNCL");
+    /// `(INIT,)` → [`InitializedEvent`](super::InitializedEvent)
+    pub const INIT: Symbol = symbol_short!("INIT");
+    /// `(USR_REG, user)` → [`UserRegisteredEvent`](super::UserRegisteredEvent)
+    pub const USR_REG: Symbol = symbol_short!("USR_REG");
+    /// `(REC_ADD, patient, provider)` → [`RecordAddedEvent`](super::RecordAddedEvent)
+    pub const REC_ADD: Symbol = symbol_short!("REC_ADD");
+    /// `(ACC_GRT, patient, grantee)` → [`AccessGrantedEvent`](super::AccessGrantedEvent)
+    pub const ACC_GRT: Symbol = symbol_short!("ACC_GRT");
+    /// `(REC_GRT, patient, grantee, record_id)` → [`RecordAccessGrantedEvent`](super::RecordAccessGrantedEvent)
+    pub const REC_GRT: Symbol = symbol_short!("REC_GRT");
+    /// `(ACC_REV, patient, grantee)` → [`AccessRevokedEvent`](super::AccessRevokedEvent)
+    pub const ACC_REV: Symbol = symbol_short!("ACC_REV");
+    /// `(BATCH_R, provider)` → [`BatchRecordsAddedEvent`](super::BatchRecordsAddedEvent)
+    pub const BATCH_R: Symbol = symbol_short!("BATCH_R");
+    /// `(PAUSE,)` → [`ContractPausedEvent`](super::ContractPausedEvent)
+    pub const PAUSE: Symbol = symbol_short!("PAUSE");
+    /// `(RESUME,)` → [`ContractResumedEvent`](super::ContractResumedEvent)
+    pub const RESUME: Symbol = symbol_short!("RESUME");
+    /// `(ACC_VIOL, caller, action)` → [`AccessViolationEvent`](super::AccessViolationEvent)
+    pub const ACC_VIOL: Symbol = symbol_short!("ACC_VIOL");
+    /// `(ACC_EXP, patient, grantee)` → [`AccessExpiredEvent`](super::AccessExpiredEvent)
+    pub const ACC_EXP: Symbol = symbol_short!("ACC_EXP");
+    /// `(PROV_REG, provider)` → [`ProviderRegisteredEvent`](super::ProviderRegisteredEvent)
+    pub const PROV_REG: Symbol = symbol_short!("PROV_REG");
+    /// `(PROV_VER, provider, verifier)` → [`ProviderVerifiedEvent`](super::ProviderVerifiedEvent)
+    pub const PROV_VER: Symbol = symbol_short!("PROV_VER");
+    /// `(BATCH_A, patient)` → [`BatchAccessGrantedEvent`](super::BatchAccessGrantedEvent)
+    pub const BATCH_A: Symbol = symbol_short!("BATCH_A");
+    /// `(EXAM_ADD, record_id)` → [`ExaminationAddedEvent`](super::ExaminationAddedEvent)
+    pub const EXAM_ADD: Symbol = symbol_short!("EXAM_ADD");
+    /// `(META_GRT, patient, grantee)` → [`MetaAccessGrantedEvent`](super::MetaAccessGrantedEvent)
+    pub const META_GRT: Symbol = symbol_short!("META_GRT");
+    /// `(CST_GRT, patient, grantee)` → [`ConsentGrantedEvent`](super::ConsentGrantedEvent)
+    pub const CST_GRT: Symbol = symbol_short!("CST_GRT");
+    /// `(CST_REV, patient, grantee)` → [`ConsentRevokedEvent`](super::ConsentRevokedEvent)
+    pub const CST_REV: Symbol = symbol_short!("CST_REV");
+    /// `(PROF_C, patient)` → [`ProfileCreatedEvent`](super::ProfileCreatedEvent)
+    pub const PROF_C: Symbol = symbol_short!("PROF_C");
+    /// `(PROF_U, patient)` → [`ProfileUpdatedEvent`](super::ProfileUpdatedEvent)
+    pub const PROF_U: Symbol = symbol_short!("PROF_U");
+    /// `(ERROR, category, severity)` → [`ErrorEvent`](super::ErrorEvent)
+    pub const ERROR: Symbol = symbol_short!("ERROR");
+    /// `(EMRG_GRT, patient, requester)` → [`EmergencyAccessGrantedEvent`](super::EmergencyAccessGrantedEvent)
+    pub const EMRG_GRT: Symbol = symbol_short!("EMRG_GRT");
+    /// `(EMRG_REV, patient, revoker)` → [`EmergencyAccessRevokedEvent`](super::EmergencyAccessRevokedEvent)
+    pub const EMRG_REV: Symbol = symbol_short!("EMRG_REV");
+    /// `(EMRG_NOT, patient, contact)` → [`EmergencyContactNotifiedEvent`](super::EmergencyContactNotifiedEvent)
+    pub const EMRG_NOT: Symbol = symbol_short!("EMRG_NOT");
+    /// `(EMRG_USE, patient, requester)` → [`EmergencyAccessUsedEvent`](super::EmergencyAccessUsedEvent)
+    pub const EMRG_USE: Symbol = symbol_short!("EMRG_USE");
+    /// `(APPT_SCH, patient, provider)` → [`AppointmentScheduledEvent`](super::AppointmentScheduledEvent)
+    pub const APPT_SCH: Symbol = symbol_short!("APPT_SCH");
+    /// `(APPT_CFM, patient, provider)` → [`AppointmentConfirmedEvent`](super::AppointmentConfirmedEvent)
+    pub const APPT_CFM: Symbol = symbol_short!("APPT_CFM");
+    /// `(APPT_CNL, patient, provider)` → [`AppointmentCancelledEvent`](super::AppointmentCancelledEvent)
+    pub const APPT_CNL: Symbol = symbol_short!("APPT_CNL");
+    /// `(APPT_RSCH, patient, provider)` → [`AppointmentRescheduledEvent`](super::AppointmentRescheduledEvent)
+    pub const APPT_RSCH: Symbol = symbol_short!("APPT_RSCH");
+    /// `(APPT_CMP, patient, provider)` → [`AppointmentCompletedEvent`](super::AppointmentCompletedEvent)
+    pub const APPT_CMP: Symbol = symbol_short!("APPT_CMP");
+    /// `(APPT_RMD, patient, provider)` → [`AppointmentReminderEvent`](super::AppointmentReminderEvent)
+    pub const APPT_RMD: Symbol = symbol_short!("APPT_RMD");
+    /// `(APPT_VER, patient, provider)` → [`AppointmentVerifiedEvent`](super::AppointmentVerifiedEvent)
+    pub const APPT_VER: Symbol = symbol_short!("APPT_VER");
+    /// `(AUDIT, actor, patient)` → [`AuditLogEntryEvent`](super::AuditLogEntryEvent)
+    pub const AUDIT: Symbol = symbol_short!("AUDIT");
+    /// `(RL_EXCD, address, operation)` → [`RateLimitExceededEvent`](super::RateLimitExceededEvent)
+    pub const RL_EXCD: Symbol = symbol_short!("RL_EXCD");
+    /// `(RL_CONFIG, operation)` → [`RateLimitConfigUpdatedEvent`](super::RateLimitConfigUpdatedEvent)
+    pub const RL_CONFIG: Symbol = symbol_short!("RL_CONFIG");
+    /// `(RL_BYPASS, address)` → [`RateLimitBypassUpdatedEvent`](super::RateLimitBypassUpdatedEvent)
+    pub const RL_BYPASS: Symbol = symbol_short!("RL_BYPASS");
+    /// `(POL_CRT,)` → [`PolicyCreatedEvent`](super::PolicyCreatedEvent)
+    pub const POL_CRT: Symbol = symbol_short!("POL_CRT");
+    /// `(CRED_SET, user)` → [`CredentialSetEvent`](super::CredentialSetEvent)
+    pub const CRED_SET: Symbol = symbol_short!("CRED_SET");
+    /// `(SENS_SET, record_id)` → [`SensitivitySetEvent`](super::SensitivitySetEvent)
+    pub const SENS_SET: Symbol = symbol_short!("SENS_SET");
+    /// `(PRIO_CHG, record_id)` → [`RecordPriorityChangedEvent`](super::RecordPriorityChangedEvent)
+    pub const PRIO_CHG: Symbol = symbol_short!("PRIO_CHG");
+    /// `(ACC_SWEEP, grantee)` → [`GranteeAccessSweptEvent`](super::GranteeAccessSweptEvent)
+    pub const ACC_SWEEP: Symbol = symbol_short!("ACC_SWEEP");
+    /// `(USR_FRZ, user)` → [`UserFrozenEvent`](super::UserFrozenEvent)
+    pub const USR_FRZ: Symbol = symbol_short!("USR_FRZ");
+    /// `(USR_UNFRZ, user)` → [`UserUnfrozenEvent`](super::UserUnfrozenEvent)
+    pub const USR_UNFRZ: Symbol = symbol_short!("USR_UNFRZ");
+    /// `(GRT_PEND, patient, grantee)` → [`GrantPendingEvent`](super::GrantPendingEvent)
+    pub const GRT_PEND: Symbol = symbol_short!("GRT_PEND");
+    /// `(GRT_APPR, patient, grantee)` → [`GrantApprovedEvent`](super::GrantApprovedEvent)
+    pub const GRT_APPR: Symbol = symbol_short!("GRT_APPR");
+    /// `(GRT_FORCE, patient, grantee)` → [`ForcedGrantEvent`](super::ForcedGrantEvent)
+    pub const GRT_FORCE: Symbol = symbol_short!("GRT_FORCE");
+    /// `(REC_SUPER, old_record_id, new_record_id)` → [`RecordSupersededEvent`](super::RecordSupersededEvent)
+    pub const REC_SUPER: Symbol = symbol_short!("REC_SUPER");
+    /// `(ACC_OFR, patient, grantee)` → [`AccessOfferedEvent`](super::AccessOfferedEvent)
+    pub const ACC_OFR: Symbol = symbol_short!("ACC_OFR");
+    /// `(ACC_ACPT, patient, grantee)` → [`AccessOfferAcceptedEvent`](super::AccessOfferAcceptedEvent)
+    pub const ACC_ACPT: Symbol = symbol_short!("ACC_ACPT");
+    /// `(ACC_DECL, patient, grantee)` → [`AccessOfferDeclinedEvent`](super::AccessOfferDeclinedEvent)
+    pub const ACC_DECL: Symbol = symbol_short!("ACC_DECL");
+    /// `(HASH_REG, hash_alg)` → [`HashAlgorithmRegisteredEvent`](super::HashAlgorithmRegisteredEvent)
+    pub const HASH_REG: Symbol = symbol_short!("HASH_REG");
+    /// `(CLM_ATT, record_id, provider)` → [`ClaimAttestedEvent`](super::ClaimAttestedEvent)
+    pub const CLM_ATT: Symbol = symbol_short!("CLM_ATT");
+    /// `(CLM_VOID, record_id)` → [`ClaimAttestationVoidedEvent`](super::ClaimAttestationVoidedEvent)
+    pub const CLM_VOID: Symbol = symbol_short!("CLM_VOID");
+    /// `(ADMIN_OP, operation)` → [`AdminOpEvent`](super::AdminOpEvent)
+    pub const ADMIN_OP: Symbol = symbol_short!("ADMIN_OP");
+    /// `(SESS_NEW, patient, session_key)` → [`SessionCreatedEvent`](super::SessionCreatedEvent)
+    pub const SESS_NEW: Symbol = symbol_short!("SESS_NEW");
+    /// `(SESS_REV, patient, session_key)` → [`SessionRevokedEvent`](super::SessionRevokedEvent)
+    pub const SESS_REV: Symbol = symbol_short!("SESS_REV");
+    /// `(XREF_SET, record_id)` → [`ExternalRefSetEvent`](super::ExternalRefSetEvent)
+    pub const XREF_SET: Symbol = symbol_short!("XREF_SET");
+    /// `(PROV_CLM, patient)` → [`ProvisionalClaimedEvent`](super::ProvisionalClaimedEvent)
+    pub const PROV_CLM: Symbol = symbol_short!("PROV_CLM");
+    /// `(BRCH_SET, patient)` → [`BreachFlaggedEvent`](super::BreachFlaggedEvent)
+    pub const BRCH_SET: Symbol = symbol_short!("BRCH_SET");
+    /// `(BRCH_CLR, patient)` → [`BreachClearedEvent`](super::BreachClearedEvent)
+    pub const BRCH_CLR: Symbol = symbol_short!("BRCH_CLR");
+    /// `(SIGN_KEY, patient)` → [`SigningKeyRegisteredEvent`](super::SigningKeyRegisteredEvent)
+    pub const SIGN_KEY: Symbol = symbol_short!("SIGN_KEY");
+    /// `(GRT_RLY, patient, grantee)` → [`GrantRelayedEvent`](super::GrantRelayedEvent)
+    pub const GRT_RLY: Symbol = symbol_short!("GRT_RLY");
+    /// `(GRT_MIG, patient, old_grantee)` → [`GrantMigratedEvent`](super::GrantMigratedEvent)
+    pub const GRT_MIG: Symbol = symbol_short!("GRT_MIG");
+    /// `(GRT_AUTO, patient, grantee)` → [`AutoGrantEvent`](super::AutoGrantEvent)
+    pub const GRT_AUTO: Symbol = symbol_short!("GRT_AUTO");
+    /// `(VER_PIN, record_id)` → [`VersionPinnedEvent`](super::VersionPinnedEvent)
+    pub const VER_PIN: Symbol = symbol_short!("VER_PIN");
+    /// `(VER_UNPIN, record_id)` → [`VersionUnpinnedEvent`](super::VersionUnpinnedEvent)
+    pub const VER_UNPIN: Symbol = symbol_short!("VER_UNPIN");
+    /// `(USR_DEACT, user)` → [`UserDeactivatedEvent`](super::UserDeactivatedEvent)
+    pub const USR_DEACT: Symbol = symbol_short!("USR_DEACT");
+    /// `(USR_REACT, user)` → [`UserReactivatedEvent`](super::UserReactivatedEvent)
+    pub const USR_REACT: Symbol = symbol_short!("USR_REACT");
+    /// `(DLG_CLEAN, user)` → [`DelegationsCleanedEvent`](super::DelegationsCleanedEvent)
+    pub const DLG_CLEAN: Symbol = symbol_short!("DLG_CLEAN");
+    /// `(GRT_CLEAN, user)` → [`GrantsCleanedEvent`](super::GrantsCleanedEvent)
+    pub const GRT_CLEAN: Symbol = symbol_short!("GRT_CLEAN");
+    /// `(EMB_SET, record_id)` → [`EmbargoSetEvent`](super::EmbargoSetEvent)
+    pub const EMB_SET: Symbol = symbol_short!("EMB_SET");
+    /// `(EMB_CLEAR, record_id)` → [`EmbargoClearedEvent`](super::EmbargoClearedEvent)
+    pub const EMB_CLEAR: Symbol = symbol_short!("EMB_CLEAR");
+    /// `(GRT_OVCAP, patient, grantee)` → [`GrantCapOverriddenEvent`](super::GrantCapOverriddenEvent)
+    pub const GRT_OVCAP: Symbol = symbol_short!("GRT_OVCAP");
+    /// `(SHR_NEW, patient, record_id)` → [`ShareCodeCreatedEvent`](super::ShareCodeCreatedEvent)
+    pub const SHR_NEW: Symbol = symbol_short!("SHR_NEW");
+    /// `(SHR_RDM, patient, record_id)` → [`ShareCodeRedeemedEvent`](super::ShareCodeRedeemedEvent)
+    pub const SHR_RDM: Symbol = symbol_short!("SHR_RDM");
+    /// `(SHR_CNCL, patient, record_id)` → [`ShareCodeCancelledEvent`](super::ShareCodeCancelledEvent)
+    pub const SHR_CNCL: Symbol = symbol_short!("SHR_CNCL");
+    /// `(PEND_GC,)` → [`PendingGcEvent`](super::PendingGcEvent)
+    pub const PEND_GC: Symbol = symbol_short!("PEND_GC");
+    /// `(OVR_SET, patient, record_id)` → [`RecordOverrideSetEvent`](super::RecordOverrideSetEvent)
+    pub const OVR_SET: Symbol = symbol_short!("OVR_SET");
+    /// `(OVR_CLEAR, patient, record_id)` → [`RecordOverrideClearedEvent`](super::RecordOverrideClearedEvent)
+    pub const OVR_CLEAR: Symbol = symbol_short!("OVR_CLEAR");
+    /// `(HEARTBEAT, admin)` → [`HeartbeatEvent`](super::HeartbeatEvent)
+    pub const HEARTBEAT: Symbol = symbol_short!("HEARTBEAT");
+    /// `(REV_SCHED, patient, grantee)` → [`RevocationScheduledEvent`](super::RevocationScheduledEvent)
+    pub const REV_SCHED: Symbol = symbol_short!("REV_SCHED");
+    /// `(REV_CNCL, patient, grantee)` → [`RevocationCancelledEvent`](super::RevocationCancelledEvent)
+    pub const REV_CNCL: Symbol = symbol_short!("REV_CNCL");
+    /// `(REV_IMM, patient, grantee)` → [`AccessRevokedImmediatelyEvent`](super::AccessRevokedImmediatelyEvent)
+    pub const REV_IMM: Symbol = symbol_short!("REV_IMM");
+    /// `(RCV_INIT, old_patient, new_patient)` → [`RecoveryInitiatedEvent`](super::RecoveryInitiatedEvent)
+    pub const RCV_INIT: Symbol = symbol_short!("RCV_INIT");
+    /// `(RCV_CNCL, old_patient, new_patient)` → [`RecoveryCancelledEvent`](super::RecoveryCancelledEvent)
+    pub const RCV_CNCL: Symbol = symbol_short!("RCV_CNCL");
+    /// `(RCV_REC, old_patient, new_patient)` → [`RecoveryRecordReboundEvent`](super::RecoveryRecordReboundEvent)
+    pub const RCV_REC: Symbol = symbol_short!("RCV_REC");
+    /// `(RCV_GRT, old_patient, new_patient)` → [`RecoveryGrantReboundEvent`](super::RecoveryGrantReboundEvent)
+    pub const RCV_GRT: Symbol = symbol_short!("RCV_GRT");
+    /// `(RCV_USER, old_patient, new_patient)` → [`RecoveryUserReboundEvent`](super::RecoveryUserReboundEvent)
+    pub const RCV_USER: Symbol = symbol_short!("RCV_USER");
+    /// `(RCV_FIN, old_patient, new_patient)` → [`RecoveryFinalizedEvent`](super::RecoveryFinalizedEvent)
+    pub const RCV_FIN: Symbol = symbol_short!("RCV_FIN");
+    /// `(DSP_OPEN, patient, record_id)` → [`DisputeOpenedEvent`](super::DisputeOpenedEvent)
+    pub const DSP_OPEN: Symbol = symbol_short!("DSP_OPEN");
+    /// `(DSP_RESP, provider, record_id)` → [`DisputeRespondedEvent`](super::DisputeRespondedEvent)
+    pub const DSP_RESP: Symbol = symbol_short!("DSP_RESP");
+    /// `(DSP_RSLV, resolved_by, record_id)` → [`DisputeResolvedEvent`](super::DisputeResolvedEvent)
+    pub const DSP_RSLV: Symbol = symbol_short!("DSP_RSLV");
+    /// `(PAT_FRZ, patient)` → [`PatientAccessFrozenEvent`](super::PatientAccessFrozenEvent)
+    pub const PAT_FRZ: Symbol = symbol_short!("PAT_FRZ");
+    /// `(PAT_UNFRZ, patient)` → [`PatientAccessUnfrozenEvent`](super::PatientAccessUnfrozenEvent)
+    pub const PAT_UNFRZ: Symbol = symbol_short!("PAT_UNFRZ");
+    /// `(OBS_REG, observer)` → [`ObserverRegisteredEvent`](super::ObserverRegisteredEvent)
+    pub const OBS_REG: Symbol = symbol_short!("OBS_REG");
+    /// `(OBS_UNREG, observer)` → [`ObserverUnregisteredEvent`](super::ObserverUnregisteredEvent)
+    pub const OBS_UNREG: Symbol = symbol_short!("OBS_UNREG");
+    /// `(OBS_FAIL, observer, record_id)` → [`ObserverFailedEvent`](super::ObserverFailedEvent)
+    pub const OBS_FAIL: Symbol = symbol_short!("OBS_FAIL");
+    /// `(LEG_READ,)` → [`LegacyReadModeChangedEvent`](super::LegacyReadModeChangedEvent)
+    pub const LEG_READ: Symbol = symbol_short!("LEG_READ");
+    /// `(VER_RDCT, record_id, version)` → [`VersionRedactedEvent`](super::VersionRedactedEvent)
+    pub const VER_RDCT: Symbol = symbol_short!("VER_RDCT");
+    /// `(ORG_GRANT, patient, org)` → [`OrgAccessGrantedEvent`](super::OrgAccessGrantedEvent)
+    pub const ORG_GRANT: Symbol = symbol_short!("ORG_GRANT");
+    /// `(ORG_RVK, patient, org)` → [`OrgAccessRevokedEvent`](super::OrgAccessRevokedEvent)
+    pub const ORG_RVK: Symbol = symbol_short!("ORG_RVK");
+    /// `(WS_OPEN, caller, record_id)` → [`WriteSessionOpenedEvent`](super::WriteSessionOpenedEvent)
+    pub const WS_OPEN: Symbol = symbol_short!("WS_OPEN");
+    /// `(WS_CLOSE, caller, record_id)` → [`WriteSessionClosedEvent`](super::WriteSessionClosedEvent)
+    pub const WS_CLOSE: Symbol = symbol_short!("WS_CLOSE");
+    /// `(RES_OVRD, patient, provider)` → [`ResidencyOverriddenEvent`](super::ResidencyOverriddenEvent)
+    pub const RES_OVRD: Symbol = symbol_short!("RES_OVRD");
+    /// `(EXP_BCN, patient, grantee)` → [`ExpiryBeaconEvent`](super::ExpiryBeaconEvent)
+    pub const EXP_BCN: Symbol = symbol_short!("EXP_BCN");
+    /// `(ADM_READ, patient, admin)` → [`AdminReadEvent`](super::AdminReadEvent)
+    pub const ADM_READ: Symbol = symbol_short!("ADM_READ");
+    /// `(VER_DIFF, record_id)` → [`VersionDiffAttachedEvent`](super::VersionDiffAttachedEvent)
+    pub const VER_DIFF: Symbol = symbol_short!("VER_DIFF");
+    /// `(USR_SHR, patient, grantee)` → [`UserShareEvent`](super::UserShareEvent)
+    pub const USR_SHR: Symbol = symbol_short!("USR_SHR");
+    /// `(REC_RSV, patient, provider)` → [`RecordReservedEvent`](super::RecordReservedEvent)
+    pub const REC_RSV: Symbol = symbol_short!("REC_RSV");
+    /// `(REC_ABDN, patient, provider)` → [`RecordAbandonedEvent`](super::RecordAbandonedEvent)
+    pub const REC_ABDN: Symbol = symbol_short!("REC_ABDN");
+    /// `(ADM_XFER, from, to)` → [`AdminPermissionTransferredEvent`](super::AdminPermissionTransferredEvent)
+    pub const ADM_XFER: Symbol = symbol_short!("ADM_XFER");
+    /// `(SYS_ADM,)` → [`LegacySystemAdminChangedEvent`](super::LegacySystemAdminChangedEvent)
+    pub const SYS_ADM: Symbol = symbol_short!("SYS_ADM");
+    /// `(ACCT_CLS, user)` → [`AccountClosedEvent`](super::AccountClosedEvent)
+    pub const ACCT_CLS: Symbol = symbol_short!("ACCT_CLS");
+    /// `(GRT_CLOSE, patient, grantee)` → [`ClosedGranteeRemovedEvent`](super::ClosedGranteeRemovedEvent)
+    pub const GRT_CLOSE: Symbol = symbol_short!("GRT_CLOSE");
+    /// `(REV_NOTE, patient, grantee)` → [`AccessRevokedWithNoteEvent`](super::AccessRevokedWithNoteEvent)
+    pub const REV_NOTE: Symbol = symbol_short!("REV_NOTE");
+    /// `(JOB_UPD, kind, job_id)` → [`JobUpdatedEvent`](super::JobUpdatedEvent)
+    pub const JOB_UPD: Symbol = symbol_short!("JOB_UPD");
+    /// `(HOLD_SET, record_id)` → [`LegalHoldEvent`](super::LegalHoldEvent)
+    pub const HOLD_SET: Symbol = symbol_short!("HOLD_SET");
+    /// `(HOLD_REL, record_id)` → [`LegalHoldEvent`](super::LegalHoldEvent)
+    pub const HOLD_REL: Symbol = symbol_short!("HOLD_REL");
+}
+
+/// Operation symbols carried by [`AdminOpEvent`], one per bulk or
+/// maintenance endpoint.
+pub mod admin_ops {
+    use soroban_sdk::{symbol_short, Symbol};
+
+    /// `purge_expired_grants`: the patient's grantee list.
+    pub const PURGE_GRANTS: Symbol = symbol_short!("PURGE_GRT");
+    /// `revoke_all_access_for_grantee`: the grantee's patient index.
+    pub const GRANTEE_SWEEP: Symbol = symbol_short!("GRT_SWEEP");
+    /// `gc_pending`: the category's pending-entry index, owned by the
+    /// contract itself.
+    pub const PENDING_GC: Symbol = symbol_short!("PEND_GC");
+    /// `compact_grant_indexes`: the patient's grantee list.
+    pub const COMPACT_INDEX: Symbol = symbol_short!("CMPCT_IDX");
+    /// `repair_patient_index`: the patient's record list.
+    pub const REPAIR_INDEX: Symbol = symbol_short!("REPR_IDX");
+}
 
 /// Event published when the contract is initialized.
 #[soroban_sdk::contracttype]
@@ -50,6 +342,8 @@ pub struct UserRegisteredEvent {
     pub user: Address,
     pub role: Role,
     pub name: String,
+    /// True when the user registered themselves rather than via an admin.
+    pub self_registered: bool,
     pub timestamp: u64,
 }
 
@@ -61,6 +355,7 @@ pub struct RecordAddedEvent {
     pub patient: Address,
     pub provider: Address,
     pub record_type: RecordType,
+    pub coauthors: Vec<Address>,
     pub timestamp: u64,
 }
 
@@ -71,8 +366,15 @@ pub struct AccessGrantedEvent {
     pub patient: Address,
     pub grantee: Address,
     pub level: AccessLevel,
+    pub purpose: GrantPurpose,
     pub duration_seconds: u64,
     pub expires_at: u64,
+    /// The patient, or whoever granted on the patient's behalf.
+    pub created_by: Address,
+    /// Revision of the patient's grants to the grantee after this change.
+    pub grant_revision: u64,
+    /// The patient's access epoch after this change.
+    pub access_epoch: u64,
     pub timestamp: u64,
 }
 
@@ -86,6 +388,8 @@ pub struct RecordAccessGrantedEvent {
     pub level: AccessLevel,
     pub duration_seconds: u64,
     pub expires_at: u64,
+    pub grant_revision: u64,
+    pub access_epoch: u64,
     pub timestamp: u64,
 }
 
@@ -95,6 +399,8 @@ pub struct RecordAccessGrantedEvent {
 pub struct AccessRevokedEvent {
     pub patient: Address,
     pub grantee: Address,
+    pub grant_revision: u64,
+    pub access_epoch: u64,
     pub timestamp: u64,
 }
 
@@ -105,6 +411,8 @@ pub struct AccessExpiredEvent {
     pub patient: Address,
     pub grantee: Address,
     pub expired_at: u64,
+    pub grant_revision: u64,
+    pub access_epoch: u64,
     pub purged_at: u64,
 }
 
@@ -155,7 +463,7 @@ pub struct AccessViolationEvent {
 }
 
 pub fn publish_admin_transfer_proposed(env: &Env, current_admin: Address, proposed_admin: Address) {
-    let topics = (symbol_short!("ADM_PROP"), current_admin.clone());
+    let topics = (topics::ADM_PROP, current_admin.clone());
     let data = AdminTransferProposedEvent {
         current_admin,
         proposed_admin,
@@ -165,7 +473,7 @@ pub fn publish_admin_transfer_proposed(env: &Env, current_admin: Address, propos
 }
 
 pub fn publish_admin_transfer_accepted(env: &Env, old_admin: Address, new_admin: Address) {
-    let topics = (symbol_short!("ADM_ACPT"), new_admin.clone());
+    let topics = (topics::ADM_ACPT, new_admin.clone());
     let data = AdminTransferAcceptedEvent {
         old_admin,
         new_admin,
@@ -175,7 +483,7 @@ pub fn publish_admin_transfer_accepted(env: &Env, old_admin: Address, new_admin:
 }
 
 pub fn publish_admin_transfer_cancelled(env: &Env, admin: Address, cancelled_proposed: Address) {
-    let topics = (symbol_short!("ADM_CNCL"), admin.clone());
+    let topics = (topics::ADM_CNCL, admin.clone());
     let data = AdminTransferCancelledEvent {
         admin,
         cancelled_proposed,
@@ -185,7 +493,7 @@ pub fn publish_admin_transfer_cancelled(env: &Env, admin: Address, cancelled_pro
 }
 
 pub fn publish_initialized(env: &Env, admin: Address) {
-    let topics = (symbol_short!("INIT"),);
+    let topics = (topics::INIT,);
     let data = InitializedEvent {
         admin,
         timestamp: env.ledger().timestamp(),
@@ -194,13 +502,21 @@ pub fn publish_initialized(env: &Env, admin: Address) {
 }
 
 /// Publishes an event when a new user is registered.
-/// This event includes the user address, role, name, and registration timestamp.
-pub fn publish_user_registered(env: &Env, user: Address, role: Role, name: String) {
-    let topics = (symbol_short!("USR_REG"), user.clone());
+/// This event includes the user address, role, name, whether the user
+/// registered themselves, and registration timestamp.
+pub fn publish_user_registered(
+    env: &Env,
+    user: Address,
+    role: Role,
+    name: String,
+    self_registered: bool,
+) {
+    let topics = (topics::USR_REG, user.clone());
     let data = UserRegisteredEvent {
         user,
         role,
         name,
+        self_registered,
         timestamp: env.ledger().timestamp(),
     };
     env.events().publish(topics, data);
@@ -214,35 +530,46 @@ pub fn publish_record_added(
     patient: Address,
     provider: Address,
     record_type: RecordType,
+    coauthors: Vec<Address>,
 ) {
-    let topics = (symbol_short!("REC_ADD"), patient.clone(), provider.clone());
+    let topics = (topics::REC_ADD, patient.clone(), provider.clone());
     let data = RecordAddedEvent {
         record_id,
         patient,
         provider,
         record_type,
+        coauthors,
         timestamp: env.ledger().timestamp(),
     };
     env.events().publish(topics, data);
 }
 
 /// Publishes an event when access is granted to a record.
-/// This event includes patient, grantee, access level, duration, expiration, and timestamp.
+/// This event includes patient, grantee, access level, purpose, duration, expiration, the
+/// pair's grant revision and access epoch, and timestamp.
 pub fn publish_access_granted(
     env: &Env,
     patient: Address,
     grantee: Address,
     level: AccessLevel,
+    purpose: GrantPurpose,
     duration_seconds: u64,
     expires_at: u64,
 ) {
-    let topics = (symbol_short!("ACC_GRT"), patient.clone(), grantee.clone());
+    let topics = (topics::ACC_GRT, patient.clone(), grantee.clone());
+    let created_by = grant_creator::get_creator(env, &patient, &grantee);
+    let revision = grant_revision::get_revision(env, &patient, &grantee);
+    let epoch = grant_revision::get_epoch(env, &patient);
     let data = AccessGrantedEvent {
         patient,
         grantee,
         level,
+        purpose,
         duration_seconds,
         expires_at,
+        created_by,
+        grant_revision: revision,
+        access_epoch: epoch,
         timestamp: env.ledger().timestamp(),
     };
     env.events().publish(topics, data);
@@ -257,12 +584,9 @@ pub fn publish_record_access_granted(
     duration_seconds: u64,
     expires_at: u64,
 ) {
-    let topics = (
-        symbol_short!("REC_GRT"),
-        patient.clone(),
-        grantee.clone(),
-        record_id,
-    );
+    let topics = (topics::REC_GRT, patient.clone(), grantee.clone(), record_id);
+    let revision = grant_revision::get_revision(env, &patient, &grantee);
+    let epoch = grant_revision::get_epoch(env, &patient);
     let data = RecordAccessGrantedEvent {
         patient,
         grantee,
@@ -270,6 +594,8 @@ pub fn publish_record_access_granted(
         level,
         duration_seconds,
         expires_at,
+        grant_revision: revision,
+        access_epoch: epoch,
         timestamp: env.ledger().timestamp(),
     };
     env.events().publish(topics, data);
@@ -278,17 +604,21 @@ pub fn publish_record_access_granted(
 /// Publishes an event when access to a record is revoked.
 /// This event includes the patient, grantee, and revocation timestamp.
 pub fn publish_access_revoked(env: &Env, patient: Address, grantee: Address) {
-    let topics = (symbol_short!("ACC_REV"), patient.clone(), grantee.clone());
+    let topics = (topics::ACC_REV, patient.clone(), grantee.clone());
+    let revision = grant_revision::get_revision(env, &patient, &grantee);
+    let epoch = grant_revision::get_epoch(env, &patient);
     let data = AccessRevokedEvent {
         patient,
         grantee,
+        grant_revision: revision,
+        access_epoch: epoch,
         timestamp: env.ledger().timestamp(),
     };
     env.events().publish(topics, data);
 }
 
 pub fn publish_batch_records_added(env: &Env, provider: Address, count: u32) {
-    let topics = (symbol_short!("BATCH_R"), provider.clone());
+    let topics = (topics::BATCH_R, provider.clone());
     let data = BatchRecordsAddedEvent {
         provider,
         count,
@@ -298,7 +628,7 @@ pub fn publish_batch_records_added(env: &Env, provider: Address, count: u32) {
 }
 
 pub fn publish_contract_paused(env: &Env, caller: Address, scope: PauseScope) {
-    let topics = (symbol_short!("PAUSE"),);
+    let topics = (topics::PAUSE,);
     let data = ContractPausedEvent {
         caller,
         scope,
@@ -308,7 +638,7 @@ pub fn publish_contract_paused(env: &Env, caller: Address, scope: PauseScope) {
 }
 
 pub fn publish_contract_resumed(env: &Env, caller: Address, scope: PauseScope) {
-    let topics = (symbol_short!("RESUME"),);
+    let topics = (topics::RESUME,);
     let data = ContractResumedEvent {
         caller,
         scope,
@@ -323,7 +653,7 @@ pub fn publish_access_violation(
     action: String,
     required_permission: String,
 ) {
-    let topics = (symbol_short!("ACC_VIOL"), caller.clone(), action.clone());
+    let topics = (topics::ACC_VIOL, caller.clone(), action.clone());
     let data = AccessViolationEvent {
         caller,
         action,
@@ -334,11 +664,15 @@ pub fn publish_access_violation(
 }
 
 pub fn publish_access_expired(env: &Env, patient: Address, grantee: Address, expired_at: u64) {
-    let topics = (symbol_short!("ACC_EXP"), patient.clone(), grantee.clone());
+    let topics = (topics::ACC_EXP, patient.clone(), grantee.clone());
+    let revision = grant_revision::get_revision(env, &patient, &grantee);
+    let epoch = grant_revision::get_epoch(env, &patient);
     let data = AccessExpiredEvent {
         patient,
         grantee,
         expired_at,
+        grant_revision: revision,
+        access_epoch: epoch,
         purged_at: env.ledger().timestamp(),
     };
     env.events().publish(topics, data);
@@ -382,7 +716,7 @@ pub struct ProviderUpdatedEvent {
 /// Publishes an event when a new provider is registered.
 /// This event includes the provider address, name, provider ID, and registration timestamp.
 pub fn publish_provider_registered(env: &Env, provider: Address, name: String, provider_id: u64) {
-    let topics = (symbol_short!("PROV_REG"), provider.clone());
+    let topics = (topics::PROV_REG, provider.clone());
     let data = ProviderRegisteredEvent {
         provider,
         name,
@@ -400,11 +734,7 @@ pub fn publish_provider_verified(
     verifier: Address,
     status: VerificationStatus,
 ) {
-    let topics = (
-        symbol_short!("PROV_VER"),
-        provider.clone(),
-        verifier.clone(),
-    );
+    let topics = (topics::PROV_VER, provider.clone(), verifier.clone());
     let data = ProviderVerifiedEvent {
         provider,
         verifier,
@@ -415,7 +745,7 @@ pub fn publish_provider_verified(
 }
 
 pub fn publish_batch_access_granted(env: &Env, patient: Address, count: u32) {
-    let topics = (symbol_short!("BATCH_A"), patient.clone());
+    let topics = (topics::BATCH_A, patient.clone());
     let data = BatchAccessGrantedEvent {
         patient,
         count,
@@ -427,7 +757,7 @@ pub fn publish_batch_access_granted(env: &Env, patient: Address, count: u32) {
 /// Publishes an event when an examination is added.
 /// This event includes the record ID.
 pub fn publish_examination_added(env: &Env, record_id: u64) {
-    let topics = (symbol_short!("EXAM_ADD"), record_id);
+    let topics = (topics::EXAM_ADD, record_id);
     let data = ExaminationAddedEvent {
         record_id,
         timestamp: env.ledger().timestamp(),
@@ -458,7 +788,7 @@ pub fn publish_meta_access_granted(
     expires_at: u64,
     nonce: u64,
 ) {
-    let topics = (symbol_short!("META_GRT"), patient.clone(), grantee.clone());
+    let topics = (topics::META_GRT, patient.clone(), grantee.clone());
     let data = MetaAccessGrantedEvent {
         patient,
         grantee,
@@ -479,6 +809,8 @@ pub struct ConsentGrantedEvent {
     pub grantee: Address,
     pub consent_type: crate::ConsentType,
     pub expires_at: u64,
+    pub grant_revision: u64,
+    pub access_epoch: u64,
     pub timestamp: u64,
 }
 
@@ -488,6 +820,8 @@ pub struct ConsentGrantedEvent {
 pub struct ConsentRevokedEvent {
     pub patient: Address,
     pub grantee: Address,
+    pub grant_revision: u64,
+    pub access_epoch: u64,
     pub timestamp: u64,
 }
 
@@ -499,12 +833,16 @@ pub fn publish_consent_granted(
     consent_type: crate::ConsentType,
     expires_at: u64,
 ) {
-    let topics = (symbol_short!("CST_GRT"), patient.clone(), grantee.clone());
+    let topics = (topics::CST_GRT, patient.clone(), grantee.clone());
+    let revision = grant_revision::get_revision(env, &patient, &grantee);
+    let epoch = grant_revision::get_epoch(env, &patient);
     let data = ConsentGrantedEvent {
         patient,
         grantee,
         consent_type,
         expires_at,
+        grant_revision: revision,
+        access_epoch: epoch,
         timestamp: env.ledger().timestamp(),
     };
     env.events().publish(topics, data);
@@ -512,10 +850,14 @@ pub fn publish_consent_granted(
 
 /// Publishes an event when consent is revoked.
 pub fn publish_consent_revoked(env: &Env, patient: Address, grantee: Address) {
-    let topics = (symbol_short!("CST_REV"), patient.clone(), grantee.clone());
+    let topics = (topics::CST_REV, patient.clone(), grantee.clone());
+    let revision = grant_revision::get_revision(env, &patient, &grantee);
+    let epoch = grant_revision::get_epoch(env, &patient);
     let data = ConsentRevokedEvent {
         patient,
         grantee,
+        grant_revision: revision,
+        access_epoch: epoch,
         timestamp: env.ledger().timestamp(),
     };
     env.events().publish(topics, data);
@@ -538,7 +880,7 @@ pub struct ProfileUpdatedEvent {
 }
 
 pub fn publish_profile_created(env: &Env, patient: Address) {
-    let topics = (symbol_short!("PROF_C"), patient.clone());
+    let topics = (topics::PROF_C, patient.clone());
     let data = ProfileCreatedEvent {
         patient,
         timestamp: env.ledger().timestamp(),
@@ -547,7 +889,7 @@ pub fn publish_profile_created(env: &Env, patient: Address) {
 }
 
 pub fn publish_profile_updated(env: &Env, patient: Address) {
-    let topics = (symbol_short!("PROF_U"), patient.clone());
+    let topics = (topics::PROF_U, patient.clone());
     let data = ProfileUpdatedEvent {
         patient,
         timestamp: env.ledger().timestamp(),
@@ -574,7 +916,7 @@ pub struct ErrorEvent {
 /// This event includes error code, category, severity, message, user, resource ID, retryable flag, and timestamp.
 pub fn publish_error(env: &Env, error_code: u32, context: ErrorContext) {
     let topics = (
-        symbol_short!("ERROR"),
+        topics::ERROR,
         context.category.clone(),
         context.severity.clone(),
     );
@@ -643,11 +985,7 @@ pub fn publish_emergency_access_granted(
     condition: EmergencyCondition,
     expires_at: u64,
 ) {
-    let topics = (
-        symbol_short!("EMRG_GRT"),
-        patient.clone(),
-        requester.clone(),
-    );
+    let topics = (topics::EMRG_GRT, patient.clone(), requester.clone());
     let data = EmergencyAccessGrantedEvent {
         access_id,
         patient,
@@ -666,7 +1004,7 @@ pub fn publish_emergency_access_revoked(
     patient: Address,
     revoker: Address,
 ) {
-    let topics = (symbol_short!("EMRG_REV"), patient.clone(), revoker.clone());
+    let topics = (topics::EMRG_REV, patient.clone(), revoker.clone());
     let data = EmergencyAccessRevokedEvent {
         access_id,
         patient,
@@ -683,7 +1021,7 @@ pub fn publish_emergency_contact_notified(
     patient: Address,
     contact: Address,
 ) {
-    let topics = (symbol_short!("EMRG_NOT"), patient.clone(), contact.clone());
+    let topics = (topics::EMRG_NOT, patient.clone(), contact.clone());
     let data = EmergencyContactNotifiedEvent {
         access_id,
         patient,
@@ -701,11 +1039,7 @@ pub fn publish_emergency_access_used(
     requester: Address,
     record_id: Option<u64>,
 ) {
-    let topics = (
-        symbol_short!("EMRG_USE"),
-        patient.clone(),
-        requester.clone(),
-    );
+    let topics = (topics::EMRG_USE, patient.clone(), requester.clone());
     let data = EmergencyAccessUsedEvent {
         access_id,
         patient,
@@ -805,7 +1139,7 @@ pub fn publish_appointment_scheduled(
     appointment_type: AppointmentType,
     scheduled_at: u64,
 ) {
-    let topics = (symbol_short!("APPT_SCH"), patient.clone(), provider.clone());
+    let topics = (topics::APPT_SCH, patient.clone(), provider.clone());
     let data = AppointmentScheduledEvent {
         appointment_id,
         patient,
@@ -825,7 +1159,7 @@ pub fn publish_appointment_confirmed(
     provider: Address,
     confirmed_by: Address,
 ) {
-    let topics = (symbol_short!("APPT_CFM"), patient.clone(), provider.clone());
+    let topics = (topics::APPT_CFM, patient.clone(), provider.clone());
     let data = AppointmentConfirmedEvent {
         appointment_id,
         patient,
@@ -844,7 +1178,7 @@ pub fn publish_appointment_cancelled(
     provider: Address,
     cancelled_by: Address,
 ) {
-    let topics = (symbol_short!("APPT_CNL"), patient.clone(), provider.clone());
+    let topics = (topics::APPT_CNL, patient.clone(), provider.clone());
     let data = AppointmentCancelledEvent {
         appointment_id,
         patient,
@@ -865,11 +1199,7 @@ pub fn publish_appointment_rescheduled(
     new_scheduled_at: u64,
     rescheduled_by: Address,
 ) {
-    let topics = (
-        symbol_short!("APPT_RSCH"),
-        patient.clone(),
-        provider.clone(),
-    );
+    let topics = (topics::APPT_RSCH, patient.clone(), provider.clone());
     let data = AppointmentRescheduledEvent {
         appointment_id,
         patient,
@@ -890,7 +1220,7 @@ pub fn publish_appointment_completed(
     provider: Address,
     completed_by: Address,
 ) {
-    let topics = (symbol_short!("APPT_CMP"), patient.clone(), provider.clone());
+    let topics = (topics::APPT_CMP, patient.clone(), provider.clone());
     let data = AppointmentCompletedEvent {
         appointment_id,
         patient,
@@ -909,7 +1239,7 @@ pub fn publish_appointment_reminder(
     provider: Address,
     scheduled_at: u64,
 ) {
-    let topics = (symbol_short!("APPT_RMD"), patient.clone(), provider.clone());
+    let topics = (topics::APPT_RMD, patient.clone(), provider.clone());
     let data = AppointmentReminderEvent {
         appointment_id,
         patient,
@@ -928,7 +1258,7 @@ pub fn publish_appointment_verified(
     provider: Address,
     verifier: Address,
 ) {
-    let topics = (symbol_short!("APPT_VER"), patient.clone(), provider.clone());
+    let topics = (topics::APPT_VER, patient.clone(), provider.clone());
     let data = AppointmentVerifiedEvent {
         appointment_id,
         patient,
@@ -955,11 +1285,7 @@ pub struct AuditLogEntryEvent {
 
 /// Publishes an audit log entry event.
 pub fn publish_audit_log_entry(env: &Env, entry: &AuditEntry) {
-    let topics = (
-        symbol_short!("AUDIT"),
-        entry.actor.clone(),
-        entry.patient.clone(),
-    );
+    let topics = (topics::AUDIT, entry.actor.clone(), entry.patient.clone());
     let data = AuditLogEntryEvent {
         entry_id: entry.id,
         actor: entry.actor.clone(),
@@ -1015,7 +1341,7 @@ pub fn publish_rate_limit_exceeded(
     max_requests: u32,
     reset_at: u64,
 ) {
-    let topics = (symbol_short!("RL_EXCD"), address.clone(), operation.clone());
+    let topics = (topics::RL_EXCD, address.clone(), operation.clone());
     let data = RateLimitExceededEvent {
         address: address.clone(),
         operation: operation.clone(),
@@ -1035,7 +1361,7 @@ pub fn publish_rate_limit_config_updated(
     window_seconds: u64,
     updated_by: Address,
 ) {
-    let topics = (symbol_short!("RL_CONFIG"), operation.clone());
+    let topics = (topics::RL_CONFIG, operation.clone());
     let data = RateLimitConfigUpdatedEvent {
         operation: operation.clone(),
         max_requests,
@@ -1053,7 +1379,7 @@ pub fn publish_rate_limit_bypass_updated(
     bypass_enabled: bool,
     updated_by: Address,
 ) {
-    let topics = (symbol_short!("RL_BYPASS"), address.clone());
+    let topics = (topics::RL_BYPASS, address.clone());
     let data = RateLimitBypassUpdatedEvent {
         address: address.clone(),
         bypass_enabled,
@@ -1094,7 +1420,7 @@ pub struct SensitivitySetEvent {
 
 /// Publishes an event when an access policy is created.
 pub fn publish_policy_created(env: &Env, policy_id: String, created_by: Address) {
-    let topics = (symbol_short!("POL_CRT"),);
+    let topics = (topics::POL_CRT,);
     let data = PolicyCreatedEvent {
         policy_id,
         created_by,
@@ -1110,7 +1436,7 @@ pub fn publish_credential_set(
     credential: crate::CredentialType,
     set_by: Address,
 ) {
-    let topics = (symbol_short!("CRED_SET"), user.clone());
+    let topics = (topics::CRED_SET, user.clone());
     let data = CredentialSetEvent {
         user,
         credential,
@@ -1127,7 +1453,7 @@ pub fn publish_sensitivity_set(
     sensitivity: crate::SensitivityLevel,
     set_by: Address,
 ) {
-    let topics = (symbol_short!("SENS_SET"), record_id);
+    let topics = (topics::SENS_SET, record_id);
     let data = SensitivitySetEvent {
         record_id,
         sensitivity,
@@ -1136,3 +1462,2133 @@ pub fn publish_sensitivity_set(
     };
     env.events().publish(topics, data);
 }
+
+/// Event published when a record's pinning priority changes.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordPriorityChangedEvent {
+    pub record_id: u64,
+    pub changed_by: Address,
+    pub previous: RecordPriority,
+    pub priority: RecordPriority,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a record's pinning priority changes.
+/// Indexed by the off-chain pinning daemon to reconcile retention.
+pub fn publish_record_priority_changed(
+    env: &Env,
+    record_id: u64,
+    changed_by: Address,
+    previous: RecordPriority,
+    priority: RecordPriority,
+) {
+    let topics = (topics::PRIO_CHG, record_id);
+    let data = RecordPriorityChangedEvent {
+        record_id,
+        changed_by,
+        previous,
+        priority,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when an admin sweeps a grantee's access grants.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GranteeAccessSweptEvent {
+    pub grantee: Address,
+    pub revoked_by: Address,
+    pub revoked: u32,
+    pub remaining: u32,
+    pub timestamp: u64,
+}
+
+/// Event published when a user is frozen by an admin.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserFrozenEvent {
+    pub user: Address,
+    pub frozen_by: Address,
+    pub timestamp: u64,
+}
+
+/// Event published when a user is unfrozen by an admin.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserUnfrozenEvent {
+    pub user: Address,
+    pub unfrozen_by: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes a summary event after one batch of a grantee revocation sweep.
+/// Individual revocations are published as `ACC_REV` events.
+pub fn publish_grantee_access_swept(
+    env: &Env,
+    grantee: Address,
+    revoked_by: Address,
+    revoked: u32,
+    remaining: u32,
+) {
+    let topics = (topics::ACC_SWEEP, grantee.clone());
+    let data = GranteeAccessSweptEvent {
+        grantee,
+        revoked_by,
+        revoked,
+        remaining,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Publishes an event when a user is frozen.
+pub fn publish_user_frozen(env: &Env, user: Address, frozen_by: Address) {
+    let topics = (topics::USR_FRZ, user.clone());
+    let data = UserFrozenEvent {
+        user,
+        frozen_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Publishes an event when a user is unfrozen.
+pub fn publish_user_unfrozen(env: &Env, user: Address, unfrozen_by: Address) {
+    let topics = (topics::USR_UNFRZ, user.clone());
+    let data = UserUnfrozenEvent {
+        user,
+        unfrozen_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a grant is queued for patient approval.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GrantPendingEvent {
+    pub patient: Address,
+    pub grantee: Address,
+    pub level: AccessLevel,
+    pub requested_by: Address,
+    pub approval_deadline: u64,
+    pub timestamp: u64,
+}
+
+/// Event published when a patient approves a pending grant.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GrantApprovedEvent {
+    pub patient: Address,
+    pub grantee: Address,
+    pub requested_by: Address,
+    pub timestamp: u64,
+}
+
+/// Event published when a SystemAdmin grants access despite the patient
+/// requiring approval, or grants itself access on the patient's behalf.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ForcedGrantEvent {
+    pub forced_by: Address,
+    pub patient: Address,
+    pub grantee: Address,
+    pub level: AccessLevel,
+    pub expires_at: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a grant is queued for patient approval.
+pub fn publish_grant_pending(env: &Env, pending: &PendingGrant) {
+    let topics = (
+        topics::GRT_PEND,
+        pending.patient.clone(),
+        pending.grantee.clone(),
+    );
+    let data = GrantPendingEvent {
+        patient: pending.patient.clone(),
+        grantee: pending.grantee.clone(),
+        level: pending.level.clone(),
+        requested_by: pending.requested_by.clone(),
+        approval_deadline: pending.expires_at,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Publishes an event when a patient approves a pending grant.
+pub fn publish_grant_approved(
+    env: &Env,
+    patient: Address,
+    grantee: Address,
+    requested_by: Address,
+) {
+    let topics = (topics::GRT_APPR, patient.clone(), grantee.clone());
+    let data = GrantApprovedEvent {
+        patient,
+        grantee,
+        requested_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Publishes an event when a SystemAdmin force-grants access.
+pub fn publish_forced_grant(
+    env: &Env,
+    forced_by: Address,
+    patient: Address,
+    grantee: Address,
+    level: AccessLevel,
+    expires_at: u64,
+) {
+    let topics = (topics::GRT_FORCE, patient.clone(), grantee.clone());
+    let data = ForcedGrantEvent {
+        forced_by,
+        patient,
+        grantee,
+        level,
+        expires_at,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a record is superseded by a corrected record.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordSupersededEvent {
+    pub old_record_id: u64,
+    pub new_record_id: u64,
+    pub patient: Address,
+    pub superseded_by: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a record is superseded by a corrected record.
+pub fn publish_record_superseded(
+    env: &Env,
+    old_record_id: u64,
+    new_record_id: u64,
+    patient: Address,
+    superseded_by: Address,
+) {
+    let topics = (topics::REC_SUPER, old_record_id, new_record_id);
+    let data = RecordSupersededEvent {
+        old_record_id,
+        new_record_id,
+        patient,
+        superseded_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a patient offers access that the grantee must accept.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessOfferedEvent {
+    pub patient: Address,
+    pub grantee: Address,
+    pub level: AccessLevel,
+    pub duration_seconds: u64,
+    pub offer_expires_at: u64,
+    pub timestamp: u64,
+}
+
+/// Event published when a grantee accepts an access offer.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessOfferAcceptedEvent {
+    pub patient: Address,
+    pub grantee: Address,
+    pub expires_at: u64,
+    pub timestamp: u64,
+}
+
+/// Event published when a grantee declines an access offer.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessOfferDeclinedEvent {
+    pub patient: Address,
+    pub grantee: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a patient offers access to a grantee.
+pub fn publish_access_offered(env: &Env, offer: &AccessOffer) {
+    let topics = (
+        topics::ACC_OFR,
+        offer.patient.clone(),
+        offer.grantee.clone(),
+    );
+    let data = AccessOfferedEvent {
+        patient: offer.patient.clone(),
+        grantee: offer.grantee.clone(),
+        level: offer.level.clone(),
+        duration_seconds: offer.duration_seconds,
+        offer_expires_at: offer.expires_at,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Publishes an event when a grantee accepts an access offer.
+/// `expires_at` is the expiry of the grant that acceptance activated.
+pub fn publish_access_offer_accepted(
+    env: &Env,
+    patient: Address,
+    grantee: Address,
+    expires_at: u64,
+) {
+    let topics = (topics::ACC_ACPT, patient.clone(), grantee.clone());
+    let data = AccessOfferAcceptedEvent {
+        patient,
+        grantee,
+        expires_at,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Publishes an event when a grantee declines an access offer.
+pub fn publish_access_offer_declined(env: &Env, patient: Address, grantee: Address) {
+    let topics = (topics::ACC_DECL, patient.clone(), grantee.clone());
+    let data = AccessOfferDeclinedEvent {
+        patient,
+        grantee,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when an admin adds a hash algorithm to the allowlist.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HashAlgorithmRegisteredEvent {
+    pub hash_alg: Symbol,
+    pub registered_by: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a hash algorithm is registered.
+pub fn publish_hash_algorithm_registered(env: &Env, hash_alg: Symbol, registered_by: Address) {
+    let topics = (topics::HASH_REG, hash_alg.clone());
+    let data = HashAlgorithmRegisteredEvent {
+        hash_alg,
+        registered_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a provider attests that a record supports a claim.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimAttestedEvent {
+    pub record_id: u64,
+    pub provider: Address,
+    pub claim_ref_hash: String,
+    pub amount_hint: i128,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a claim attestation is created.
+pub fn publish_claim_attested(env: &Env, attestation: &ClaimAttestation) {
+    let topics = (
+        topics::CLM_ATT,
+        attestation.record_id,
+        attestation.provider.clone(),
+    );
+    let data = ClaimAttestedEvent {
+        record_id: attestation.record_id,
+        provider: attestation.provider.clone(),
+        claim_ref_hash: attestation.claim_ref_hash.clone(),
+        amount_hint: attestation.amount_hint,
+        timestamp: attestation.attested_at,
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a claim attestation is voided.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimAttestationVoidedEvent {
+    pub record_id: u64,
+    pub claim_ref_hash: String,
+    pub voided_by: Address,
+    pub reason: String,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a claim attestation is voided.
+pub fn publish_claim_attestation_voided(
+    env: &Env,
+    record_id: u64,
+    claim_ref_hash: String,
+    voided_by: Address,
+    reason: String,
+) {
+    let topics = (topics::CLM_VOID, record_id);
+    let data = ClaimAttestationVoidedEvent {
+        record_id,
+        claim_ref_hash,
+        voided_by,
+        reason,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published by every bulk or maintenance endpoint, so off-chain
+/// reconciliation can check that each run touched what it reported.
+///
+/// `range_start..range_end` are the positions processed in the list the
+/// operation walks (see [`admin_ops`]); `touched` is the number of storage
+/// entries the run removed or rewrote.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminOpEvent {
+    pub operation: Symbol,
+    pub caller: Address,
+    /// Owner of the list that was processed.
+    pub target: Address,
+    pub range_start: u32,
+    pub range_end: u32,
+    pub touched: u32,
+    pub timestamp: u64,
+}
+
+/// Publishes an event describing one run of a bulk or maintenance endpoint.
+pub fn publish_admin_op(
+    env: &Env,
+    operation: Symbol,
+    caller: Address,
+    target: Address,
+    range_start: u32,
+    range_end: u32,
+    touched: u32,
+) {
+    let topics = (topics::ADMIN_OP, operation.clone());
+    let data = AdminOpEvent {
+        operation,
+        caller,
+        target,
+        range_start,
+        range_end,
+        touched,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a patient opens a read session for a session key.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionCreatedEvent {
+    pub patient: Address,
+    pub session_key: Address,
+    pub scope: AccessLevel,
+    pub expires_at: u64,
+    pub timestamp: u64,
+}
+
+/// Event published when a patient revokes a session before it expires.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionRevokedEvent {
+    pub patient: Address,
+    pub session_key: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a patient opens a read session.
+pub fn publish_session_created(env: &Env, session: &Session) {
+    let topics = (
+        topics::SESS_NEW,
+        session.patient.clone(),
+        session.session_key.clone(),
+    );
+    let data = SessionCreatedEvent {
+        patient: session.patient.clone(),
+        session_key: session.session_key.clone(),
+        scope: session.scope.clone(),
+        expires_at: session.expires_at,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Publishes an event when a patient revokes a session.
+pub fn publish_session_revoked(env: &Env, patient: Address, session_key: Address) {
+    let topics = (topics::SESS_REV, patient.clone(), session_key.clone());
+    let data = SessionRevokedEvent {
+        patient,
+        session_key,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a record's external reference is set or changed.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExternalRefSetEvent {
+    pub record_id: u64,
+    pub external_ref: String,
+    /// The reference this one replaced; only admins may replace one.
+    pub previous: Option<String>,
+    pub set_by: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a record's external reference is set.
+pub fn publish_external_ref_set(
+    env: &Env,
+    record_id: u64,
+    external_ref: String,
+    previous: Option<String>,
+    set_by: Address,
+) {
+    let topics = (topics::XREF_SET, record_id);
+    let data = ExternalRefSetEvent {
+        record_id,
+        external_ref,
+        previous,
+        set_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a patient claims records created under a
+/// provisional identity.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProvisionalClaimedEvent {
+    pub patient: Address,
+    pub provisional_hash: BytesN<32>,
+    pub record_count: u32,
+    /// The admin who co-signed the linkage.
+    pub approved_by: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when provisional records are claimed by a patient.
+pub fn publish_provisional_claimed(
+    env: &Env,
+    patient: Address,
+    provisional_hash: BytesN<32>,
+    record_count: u32,
+    approved_by: Address,
+) {
+    let topics = (topics::PROV_CLM, patient.clone());
+    let data = ProvisionalClaimedEvent {
+        patient,
+        provisional_hash,
+        record_count,
+        approved_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published for each patient flagged by a breach notification.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BreachFlaggedEvent {
+    pub patient: Address,
+    pub incident_ref_hash: String,
+    pub flagged_by: Address,
+    pub timestamp: u64,
+}
+
+/// Event published when a patient's breach flag is cleared.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BreachClearedEvent {
+    pub patient: Address,
+    pub cleared_by: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a patient is flagged as affected by a breach.
+pub fn publish_breach_flagged(env: &Env, flag: &BreachFlag) {
+    let topics = (topics::BRCH_SET, flag.patient.clone());
+    let data = BreachFlaggedEvent {
+        patient: flag.patient.clone(),
+        incident_ref_hash: flag.incident_ref_hash.clone(),
+        flagged_by: flag.flagged_by.clone(),
+        timestamp: flag.flagged_at,
+    };
+    env.events().publish(topics, data);
+}
+
+/// Publishes an event when a patient's breach flag is cleared.
+pub fn publish_breach_cleared(env: &Env, patient: Address, cleared_by: Address) {
+    let topics = (topics::BRCH_CLR, patient.clone());
+    let data = BreachClearedEvent {
+        patient,
+        cleared_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a patient registers a key for signing relayed
+/// grant approvals.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SigningKeyRegisteredEvent {
+    pub patient: Address,
+    pub pubkey: BytesN<32>,
+    pub timestamp: u64,
+}
+
+/// Event published alongside [`AccessGrantedEvent`] when the grant was
+/// submitted by a relayer under the patient's signature.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GrantRelayedEvent {
+    pub patient: Address,
+    pub grantee: Address,
+    pub relayer: Address,
+    pub nonce: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a patient registers a signing key.
+pub fn publish_signing_key_registered(env: &Env, patient: Address, pubkey: BytesN<32>) {
+    let topics = (topics::SIGN_KEY, patient.clone());
+    let data = SigningKeyRegisteredEvent {
+        patient,
+        pubkey,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Publishes an event when a relayer submits a patient-signed grant.
+pub fn publish_grant_relayed(
+    env: &Env,
+    patient: Address,
+    grantee: Address,
+    relayer: Address,
+    nonce: u64,
+) {
+    let topics = (topics::GRT_RLY, patient.clone(), grantee.clone());
+    let data = GrantRelayedEvent {
+        patient,
+        grantee,
+        relayer,
+        nonce,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published for each patient grant moved to a grantee's new address,
+/// so the patient can be told who now holds their grant.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GrantMigratedEvent {
+    pub patient: Address,
+    pub old_grantee: Address,
+    pub new_grantee: Address,
+    pub expires_at: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a grant moves to the grantee's new address.
+pub fn publish_grant_migrated(
+    env: &Env,
+    patient: Address,
+    old_grantee: Address,
+    new_grantee: Address,
+    expires_at: u64,
+) {
+    let topics = (topics::GRT_MIG, patient.clone(), old_grantee.clone());
+    let data = GrantMigratedEvent {
+        patient,
+        old_grantee,
+        new_grantee,
+        expires_at,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published alongside [`AccessGrantedEvent`] when the grant was
+/// created automatically for the provider who registered the patient.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AutoGrantEvent {
+    pub patient: Address,
+    pub grantee: Address,
+    pub level: AccessLevel,
+    pub expires_at: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when registering a patient grants the registering
+/// provider access.
+pub fn publish_auto_grant(
+    env: &Env,
+    patient: Address,
+    grantee: Address,
+    level: AccessLevel,
+    expires_at: u64,
+) {
+    let topics = (topics::GRT_AUTO, patient.clone(), grantee.clone());
+    let data = AutoGrantEvent {
+        patient,
+        grantee,
+        level,
+        expires_at,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a record version is pinned under a label.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionPinnedEvent {
+    pub record_id: u64,
+    pub version: u32,
+    pub label: Symbol,
+    pub pinned_by: Address,
+    pub timestamp: u64,
+}
+
+/// Event published when an admin removes a version pin.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionUnpinnedEvent {
+    pub record_id: u64,
+    pub version: u32,
+    pub label: Symbol,
+    pub unpinned_by: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a record version is pinned.
+pub fn publish_version_pinned(
+    env: &Env,
+    record_id: u64,
+    version: u32,
+    label: Symbol,
+    pinned_by: Address,
+) {
+    let topics = (topics::VER_PIN, record_id);
+    let data = VersionPinnedEvent {
+        record_id,
+        version,
+        label,
+        pinned_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Publishes an event when a version pin is removed.
+pub fn publish_version_unpinned(
+    env: &Env,
+    record_id: u64,
+    version: u32,
+    label: Symbol,
+    unpinned_by: Address,
+) {
+    let topics = (topics::VER_UNPIN, record_id);
+    let data = VersionUnpinnedEvent {
+        record_id,
+        version,
+        label,
+        unpinned_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a user is deactivated.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserDeactivatedEvent {
+    pub user: Address,
+    pub cascade: DeactivationCascade,
+    pub deactivated_by: Address,
+    pub timestamp: u64,
+}
+
+/// Event published when a deactivated user is reactivated.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserReactivatedEvent {
+    pub user: Address,
+    pub reactivated_by: Address,
+    pub timestamp: u64,
+}
+
+/// Event published when a deactivation call revokes delegations the user
+/// made.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DelegationsCleanedEvent {
+    pub user: Address,
+    pub revoked: u32,
+    pub revoked_by: Address,
+    pub timestamp: u64,
+}
+
+/// Event published when a deactivation call revokes grants the user
+/// received. Each grant also gets its own [`AccessRevokedEvent`].
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GrantsCleanedEvent {
+    pub user: Address,
+    pub revoked: u32,
+    pub revoked_by: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a user is deactivated.
+pub fn publish_user_deactivated(
+    env: &Env,
+    user: Address,
+    cascade: DeactivationCascade,
+    deactivated_by: Address,
+) {
+    let topics = (topics::USR_DEACT, user.clone());
+    let data = UserDeactivatedEvent {
+        user,
+        cascade,
+        deactivated_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Publishes an event when a user is reactivated.
+pub fn publish_user_reactivated(env: &Env, user: Address, reactivated_by: Address) {
+    let topics = (topics::USR_REACT, user.clone());
+    let data = UserReactivatedEvent {
+        user,
+        reactivated_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Publishes an event when deactivation revokes the user's delegations.
+pub fn publish_delegations_cleaned(env: &Env, user: Address, revoked: u32, revoked_by: Address) {
+    let topics = (topics::DLG_CLEAN, user.clone());
+    let data = DelegationsCleanedEvent {
+        user,
+        revoked,
+        revoked_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Publishes an event when deactivation revokes grants the user received.
+pub fn publish_grants_cleaned(env: &Env, user: Address, revoked: u32, revoked_by: Address) {
+    let topics = (topics::GRT_CLEAN, user.clone());
+    let data = GrantsCleanedEvent {
+        user,
+        revoked,
+        revoked_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a provider embargoes a record.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmbargoSetEvent {
+    pub record_id: u64,
+    pub patient: Address,
+    pub provider: Address,
+    pub release_at: u64,
+    pub timestamp: u64,
+}
+
+/// Event published when a provider lifts an embargo before its release date.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmbargoClearedEvent {
+    pub record_id: u64,
+    pub patient: Address,
+    pub provider: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a record is embargoed.
+pub fn publish_embargo_set(
+    env: &Env,
+    record_id: u64,
+    patient: Address,
+    provider: Address,
+    release_at: u64,
+) {
+    let topics = (topics::EMB_SET, record_id);
+    let data = EmbargoSetEvent {
+        record_id,
+        patient,
+        provider,
+        release_at,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Publishes an event when a record's embargo is lifted early.
+pub fn publish_embargo_cleared(env: &Env, record_id: u64, patient: Address, provider: Address) {
+    let topics = (topics::EMB_CLEAR, record_id);
+    let data = EmbargoClearedEvent {
+        record_id,
+        patient,
+        provider,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a SystemAdmin grants access for longer than the
+/// configured maximum grant duration.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GrantCapOverriddenEvent {
+    pub admin: Address,
+    pub patient: Address,
+    pub grantee: Address,
+    pub duration_seconds: u64,
+    pub max_duration_seconds: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a grant overrides the maximum grant duration.
+pub fn publish_grant_cap_overridden(
+    env: &Env,
+    admin: Address,
+    patient: Address,
+    grantee: Address,
+    duration_seconds: u64,
+    max_duration_seconds: u64,
+) {
+    let topics = (topics::GRT_OVCAP, patient.clone(), grantee.clone());
+    let data = GrantCapOverriddenEvent {
+        admin,
+        patient,
+        grantee,
+        duration_seconds,
+        max_duration_seconds,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a patient creates a share code.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShareCodeCreatedEvent {
+    pub patient: Address,
+    pub record_id: u64,
+    pub code_hash: BytesN<32>,
+    pub level: AccessLevel,
+    pub expires_at: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a share code is created.
+pub fn publish_share_code_created(env: &Env, code: &ShareCode) {
+    let topics = (topics::SHR_NEW, code.patient.clone(), code.record_id);
+    let data = ShareCodeCreatedEvent {
+        patient: code.patient.clone(),
+        record_id: code.record_id,
+        code_hash: code.code_hash.clone(),
+        level: code.level.clone(),
+        expires_at: code.expires_at,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a share code is redeemed.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShareCodeRedeemedEvent {
+    pub patient: Address,
+    pub record_id: u64,
+    pub redeemer: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a share code is redeemed.
+pub fn publish_share_code_redeemed(env: &Env, patient: Address, record_id: u64, redeemer: Address) {
+    let topics = (topics::SHR_RDM, patient.clone(), record_id);
+    let data = ShareCodeRedeemedEvent {
+        patient,
+        record_id,
+        redeemer,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a patient cancels an unredeemed share code.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShareCodeCancelledEvent {
+    pub patient: Address,
+    pub record_id: u64,
+    pub code_hash: BytesN<32>,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a share code is cancelled.
+pub fn publish_share_code_cancelled(
+    env: &Env,
+    patient: Address,
+    record_id: u64,
+    code_hash: BytesN<32>,
+) {
+    let topics = (topics::SHR_CNCL, patient.clone(), record_id);
+    let data = ShareCodeCancelledEvent {
+        patient,
+        record_id,
+        code_hash,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published after each `gc_pending` run.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingGcEvent {
+    pub category: PendingCategory,
+    pub caller: Address,
+    pub removed: u32,
+    pub next_cursor: Option<u32>,
+    pub timestamp: u64,
+}
+
+/// Publishes a summary of one run of the pending-entry collector.
+pub fn publish_pending_gc(
+    env: &Env,
+    category: PendingCategory,
+    caller: Address,
+    removed: u32,
+    next_cursor: Option<u32>,
+) {
+    let topics = (topics::PEND_GC,);
+    let data = PendingGcEvent {
+        category,
+        caller,
+        removed,
+        next_cursor,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a patient caps a grantee's access to one record.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordOverrideSetEvent {
+    pub patient: Address,
+    pub grantee: Address,
+    pub record_id: u64,
+    pub expires_at: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a per-record access override is set.
+pub fn publish_record_override_set(
+    env: &Env,
+    patient: Address,
+    grantee: Address,
+    record_id: u64,
+    expires_at: u64,
+) {
+    let topics = (topics::OVR_SET, patient.clone(), record_id);
+    let data = RecordOverrideSetEvent {
+        patient,
+        grantee,
+        record_id,
+        expires_at,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a per-record access override is removed.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordOverrideClearedEvent {
+    pub patient: Address,
+    pub grantee: Address,
+    pub record_id: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a per-record access override is removed.
+pub fn publish_record_override_cleared(
+    env: &Env,
+    patient: Address,
+    grantee: Address,
+    record_id: u64,
+) {
+    let topics = (topics::OVR_CLEAR, patient.clone(), record_id);
+    let data = RecordOverrideClearedEvent {
+        patient,
+        grantee,
+        record_id,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when an admin records a heartbeat.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HeartbeatEvent {
+    pub admin: Address,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when an admin records a heartbeat.
+pub fn publish_heartbeat(env: &Env, admin: Address, ledger: u32) {
+    let topics = (topics::HEARTBEAT, admin.clone());
+    let data = HeartbeatEvent {
+        admin,
+        ledger,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a revocation is scheduled to take effect after the
+/// revocation grace window.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RevocationScheduledEvent {
+    pub patient: Address,
+    pub grantee: Address,
+    pub effective_at: u64,
+    pub grant_revision: u64,
+    pub access_epoch: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a revocation is scheduled.
+pub fn publish_revocation_scheduled(
+    env: &Env,
+    patient: Address,
+    grantee: Address,
+    effective_at: u64,
+) {
+    let topics = (topics::REV_SCHED, patient.clone(), grantee.clone());
+    let revision = grant_revision::get_revision(env, &patient, &grantee);
+    let epoch = grant_revision::get_epoch(env, &patient);
+    let data = RevocationScheduledEvent {
+        patient,
+        grantee,
+        effective_at,
+        grant_revision: revision,
+        access_epoch: epoch,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a patient cancels a scheduled revocation.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RevocationCancelledEvent {
+    pub patient: Address,
+    pub grantee: Address,
+    /// The grant's restored expiry.
+    pub expires_at: u64,
+    pub grant_revision: u64,
+    pub access_epoch: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a scheduled revocation is cancelled.
+pub fn publish_revocation_cancelled(
+    env: &Env,
+    patient: Address,
+    grantee: Address,
+    expires_at: u64,
+) {
+    let topics = (topics::REV_CNCL, patient.clone(), grantee.clone());
+    let revision = grant_revision::get_revision(env, &patient, &grantee);
+    let epoch = grant_revision::get_epoch(env, &patient);
+    let data = RevocationCancelledEvent {
+        patient,
+        grantee,
+        expires_at,
+        grant_revision: revision,
+        access_epoch: epoch,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a patient revokes access without the grace window.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessRevokedImmediatelyEvent {
+    pub patient: Address,
+    pub grantee: Address,
+    pub grant_revision: u64,
+    pub access_epoch: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when access is revoked bypassing the grace window.
+pub fn publish_access_revoked_immediately(env: &Env, patient: Address, grantee: Address) {
+    let topics = (topics::REV_IMM, patient.clone(), grantee.clone());
+    let revision = grant_revision::get_revision(env, &patient, &grantee);
+    let epoch = grant_revision::get_epoch(env, &patient);
+    let data = AccessRevokedImmediatelyEvent {
+        patient,
+        grantee,
+        grant_revision: revision,
+        access_epoch: epoch,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when an admin starts recovering a patient's chart.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryInitiatedEvent {
+    pub old_patient: Address,
+    pub new_patient: Address,
+    pub initiated_by: Address,
+    pub unlocks_at: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a patient recovery is initiated.
+pub fn publish_recovery_initiated(
+    env: &Env,
+    old_patient: Address,
+    new_patient: Address,
+    initiated_by: Address,
+    unlocks_at: u64,
+) {
+    let topics = (topics::RCV_INIT, old_patient.clone(), new_patient.clone());
+    let data = RecoveryInitiatedEvent {
+        old_patient,
+        new_patient,
+        initiated_by,
+        unlocks_at,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when the old address vetoes a pending recovery.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryCancelledEvent {
+    pub old_patient: Address,
+    pub new_patient: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a patient recovery is cancelled.
+pub fn publish_recovery_cancelled(env: &Env, old_patient: Address, new_patient: Address) {
+    let topics = (topics::RCV_CNCL, old_patient.clone(), new_patient.clone());
+    let data = RecoveryCancelledEvent {
+        old_patient,
+        new_patient,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published for each record moved to the recovered address.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryRecordReboundEvent {
+    pub old_patient: Address,
+    pub new_patient: Address,
+    pub record_id: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a record is rebound during recovery.
+pub fn publish_recovery_record_rebound(
+    env: &Env,
+    old_patient: Address,
+    new_patient: Address,
+    record_id: u64,
+) {
+    let topics = (topics::RCV_REC, old_patient.clone(), new_patient.clone());
+    let data = RecoveryRecordReboundEvent {
+        old_patient,
+        new_patient,
+        record_id,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published for each grant moved to the recovered address.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryGrantReboundEvent {
+    pub old_patient: Address,
+    pub new_patient: Address,
+    pub grantee: Address,
+    pub expires_at: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a grant is rebound during recovery.
+pub fn publish_recovery_grant_rebound(
+    env: &Env,
+    old_patient: Address,
+    new_patient: Address,
+    grantee: Address,
+    expires_at: u64,
+) {
+    let topics = (topics::RCV_GRT, old_patient.clone(), new_patient.clone());
+    let data = RecoveryGrantReboundEvent {
+        old_patient,
+        new_patient,
+        grantee,
+        expires_at,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when the patient's user entry moves to the recovered
+/// address.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryUserReboundEvent {
+    pub old_patient: Address,
+    pub new_patient: Address,
+    pub role: Role,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when the user entry is rebound during recovery.
+pub fn publish_recovery_user_rebound(
+    env: &Env,
+    old_patient: Address,
+    new_patient: Address,
+    role: Role,
+) {
+    let topics = (topics::RCV_USER, old_patient.clone(), new_patient.clone());
+    let data = RecoveryUserReboundEvent {
+        old_patient,
+        new_patient,
+        role,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a patient recovery completes.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryFinalizedEvent {
+    pub old_patient: Address,
+    pub new_patient: Address,
+    pub finalized_by: Address,
+    pub records_rebound: u32,
+    pub grants_rebound: u32,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a patient recovery is finalized.
+pub fn publish_recovery_finalized(
+    env: &Env,
+    old_patient: Address,
+    new_patient: Address,
+    finalized_by: Address,
+    records_rebound: u32,
+    grants_rebound: u32,
+) {
+    let topics = (topics::RCV_FIN, old_patient.clone(), new_patient.clone());
+    let data = RecoveryFinalizedEvent {
+        old_patient,
+        new_patient,
+        finalized_by,
+        records_rebound,
+        grants_rebound,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a patient disputes one of their records.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeOpenedEvent {
+    pub patient: Address,
+    pub record_id: u64,
+    pub reason_hash: String,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a record dispute is opened.
+pub fn publish_dispute_opened(env: &Env, patient: Address, record_id: u64, reason_hash: String) {
+    let topics = (topics::DSP_OPEN, patient.clone(), record_id);
+    let data = DisputeOpenedEvent {
+        patient,
+        record_id,
+        reason_hash,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a provider answers a record dispute.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeRespondedEvent {
+    pub provider: Address,
+    pub record_id: u64,
+    pub response_hash: String,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a record dispute is responded to.
+pub fn publish_dispute_responded(
+    env: &Env,
+    provider: Address,
+    record_id: u64,
+    response_hash: String,
+) {
+    let topics = (topics::DSP_RESP, provider.clone(), record_id);
+    let data = DisputeRespondedEvent {
+        provider,
+        record_id,
+        response_hash,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a record dispute is resolved.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeResolvedEvent {
+    pub resolved_by: Address,
+    pub record_id: u64,
+    pub resolution: DisputeResolution,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a record dispute is resolved.
+pub fn publish_dispute_resolved(
+    env: &Env,
+    resolved_by: Address,
+    record_id: u64,
+    resolution: DisputeResolution,
+) {
+    let topics = (topics::DSP_RSLV, resolved_by.clone(), record_id);
+    let data = DisputeResolvedEvent {
+        resolved_by,
+        record_id,
+        resolution,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a patient freezes access to their records.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PatientAccessFrozenEvent {
+    pub patient: Address,
+    pub allow_emergency: bool,
+    /// The patient's access epoch after this change.
+    pub access_epoch: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a patient freezes access to their records.
+pub fn publish_patient_access_frozen(env: &Env, patient: Address, allow_emergency: bool) {
+    let topics = (topics::PAT_FRZ, patient.clone());
+    let epoch = grant_revision::get_epoch(env, &patient);
+    let data = PatientAccessFrozenEvent {
+        patient,
+        allow_emergency,
+        access_epoch: epoch,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a patient lifts their access freeze.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PatientAccessUnfrozenEvent {
+    pub patient: Address,
+    pub access_epoch: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a patient lifts their access freeze.
+pub fn publish_patient_access_unfrozen(env: &Env, patient: Address) {
+    let topics = (topics::PAT_UNFRZ, patient.clone());
+    let epoch = grant_revision::get_epoch(env, &patient);
+    let data = PatientAccessUnfrozenEvent {
+        patient,
+        access_epoch: epoch,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a record observer is registered or its
+/// subscriptions are replaced.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ObserverRegisteredEvent {
+    pub observer: Address,
+    pub events: Vec<Symbol>,
+    pub registered_by: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a record observer is registered.
+pub fn publish_observer_registered(
+    env: &Env,
+    observer: Address,
+    events: Vec<Symbol>,
+    registered_by: Address,
+) {
+    let topics = (topics::OBS_REG, observer.clone());
+    let data = ObserverRegisteredEvent {
+        observer,
+        events,
+        registered_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a record observer is removed.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ObserverUnregisteredEvent {
+    pub observer: Address,
+    pub unregistered_by: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a record observer is removed.
+pub fn publish_observer_unregistered(env: &Env, observer: Address, unregistered_by: Address) {
+    let topics = (topics::OBS_UNREG, observer.clone());
+    let data = ObserverUnregisteredEvent {
+        observer,
+        unregistered_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a record observer fails to handle a notification.
+/// The record write it was notified of still goes through.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ObserverFailedEvent {
+    pub observer: Address,
+    pub topic: Symbol,
+    pub record_id: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a record observer fails.
+pub fn publish_observer_failed(env: &Env, observer: Address, topic: Symbol, record_id: u64) {
+    let topics = (topics::OBS_FAIL, observer.clone(), record_id);
+    let data = ObserverFailedEvent {
+        observer,
+        topic,
+        record_id,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when an admin changes who may call the caller-less
+/// record-listing endpoints.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyReadModeChangedEvent {
+    pub mode: LegacyReadMode,
+    pub changed_by: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when the legacy read mode changes.
+pub fn publish_legacy_read_mode_changed(env: &Env, mode: LegacyReadMode, changed_by: Address) {
+    let topics = (topics::LEG_READ,);
+    let data = LegacyReadModeChangedEvent {
+        mode,
+        changed_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when an admin redacts a historical record version.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionRedactedEvent {
+    pub record_id: u64,
+    pub version: u32,
+    pub reason_hash: BytesN<32>,
+    pub redacted_by: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a record version is redacted.
+pub fn publish_version_redacted(
+    env: &Env,
+    record_id: u64,
+    version: u32,
+    reason_hash: BytesN<32>,
+    redacted_by: Address,
+) {
+    let topics = (topics::VER_RDCT, record_id, version);
+    let data = VersionRedactedEvent {
+        record_id,
+        version,
+        reason_hash,
+        redacted_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a patient grants an organization, or one of its
+/// units, access to their records.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrgAccessGrantedEvent {
+    pub grant: OrgGrant,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when organization access is granted.
+pub fn publish_org_access_granted(env: &Env, grant: OrgGrant) {
+    let topics = (topics::ORG_GRANT, grant.patient.clone(), grant.org.clone());
+    let data = OrgAccessGrantedEvent {
+        grant,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a patient revokes an organization's or unit's
+/// access.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrgAccessRevokedEvent {
+    pub patient: Address,
+    pub org: Symbol,
+    pub unit: Option<Symbol>,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when organization access is revoked.
+pub fn publish_org_access_revoked(env: &Env, patient: Address, org: Symbol, unit: Option<Symbol>) {
+    let topics = (topics::ORG_RVK, patient.clone(), org.clone());
+    let data = OrgAccessRevokedEvent {
+        patient,
+        org,
+        unit,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a provider opens a write session on a record.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WriteSessionOpenedEvent {
+    pub caller: Address,
+    pub record_id: u64,
+    pub expires_at: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a write session is opened.
+pub fn publish_write_session_opened(env: &Env, session: &WriteSession) {
+    let topics = (topics::WS_OPEN, session.caller.clone(), session.record_id);
+    let data = WriteSessionOpenedEvent {
+        caller: session.caller.clone(),
+        record_id: session.record_id,
+        expires_at: session.expires_at,
+        timestamp: session.opened_at,
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a provider closes a write session early.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WriteSessionClosedEvent {
+    pub caller: Address,
+    pub record_id: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a write session is closed.
+pub fn publish_write_session_closed(env: &Env, caller: Address, record_id: u64) {
+    let topics = (topics::WS_CLOSE, caller.clone(), record_id);
+    let data = WriteSessionClosedEvent {
+        caller,
+        record_id,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when an admin adds a record outside the provider's
+/// jurisdictions for the patient's residency region.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResidencyOverriddenEvent {
+    pub admin: Address,
+    pub patient: Address,
+    pub provider: Address,
+    pub record_id: u64,
+    pub region: Symbol,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a record is added despite a residency mismatch.
+pub fn publish_residency_overridden(
+    env: &Env,
+    admin: Address,
+    patient: Address,
+    provider: Address,
+    record_id: u64,
+    region: Symbol,
+) {
+    let topics = (topics::RES_OVRD, patient.clone(), provider.clone());
+    let data = ResidencyOverriddenEvent {
+        admin,
+        patient,
+        provider,
+        record_id,
+        region,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published by `emit_expiry_beacons` for a grant that has expired
+/// without being revoked or purged. The grant itself is left untouched.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExpiryBeaconEvent {
+    pub patient: Address,
+    pub grantee: Address,
+    pub expired_at: u64,
+    pub bucket: u64,
+    pub grant_revision: u64,
+    pub access_epoch: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a keeper beacons an expired grant.
+pub fn publish_expiry_beacon(
+    env: &Env,
+    patient: Address,
+    grantee: Address,
+    expired_at: u64,
+    bucket: u64,
+) {
+    let topics = (topics::EXP_BCN, patient.clone(), grantee.clone());
+    let revision = grant_revision::get_revision(env, &patient, &grantee);
+    let epoch = grant_revision::get_epoch(env, &patient);
+    let data = ExpiryBeaconEvent {
+        patient,
+        grantee,
+        expired_at,
+        bucket,
+        grant_revision: revision,
+        access_epoch: epoch,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a SystemAdmin reads a record with a stated reason.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminReadEvent {
+    pub admin: Address,
+    pub patient: Address,
+    pub record_id: u64,
+    pub reason_hash: String,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a SystemAdmin reads a record through
+/// `read_record_as_admin`.
+pub fn publish_admin_read(
+    env: &Env,
+    admin: Address,
+    patient: Address,
+    record_id: u64,
+    reason_hash: String,
+) {
+    let topics = (topics::ADM_READ, patient.clone(), admin.clone());
+    let data = AdminReadEvent {
+        admin,
+        patient,
+        record_id,
+        reason_hash,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a diff document is attached to a version pair.
+/// `from_version` is always the lower of the two.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionDiffAttachedEvent {
+    pub record_id: u64,
+    pub from_version: u32,
+    pub to_version: u32,
+    pub diff_hash: String,
+    /// The pointer this one replaced; only admins may replace one.
+    pub previous: Option<String>,
+    pub attached_by: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a version diff pointer is attached.
+pub fn publish_version_diff_attached(
+    env: &Env,
+    record_id: u64,
+    from_version: u32,
+    to_version: u32,
+    diff_hash: String,
+    previous: Option<String>,
+    attached_by: Address,
+) {
+    let topics = (topics::VER_DIFF, record_id);
+    let data = VersionDiffAttachedEvent {
+        record_id,
+        from_version,
+        to_version,
+        diff_hash,
+        previous,
+        attached_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a patient shares records with another registered
+/// user. Names are copied from both users' `User` entries so wallets can
+/// show who shared with whom; `patient_name` is `None` for patients who
+/// never registered.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserShareEvent {
+    pub patient: Address,
+    pub patient_name: Option<String>,
+    pub grantee: Address,
+    pub grantee_name: String,
+    pub record_ids: Vec<u64>,
+    pub level: AccessLevel,
+    pub expires_at: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a patient shares records through
+/// `share_with_user`.
+pub fn publish_user_share(
+    env: &Env,
+    share: &UserShare,
+    patient_name: Option<String>,
+    grantee_name: String,
+) {
+    let topics = (
+        topics::USR_SHR,
+        share.patient.clone(),
+        share.grantee.clone(),
+    );
+    let data = UserShareEvent {
+        patient: share.patient.clone(),
+        patient_name,
+        grantee: share.grantee.clone(),
+        grantee_name,
+        record_ids: share.record_ids.clone(),
+        level: share.level.clone(),
+        expires_at: share.expires_at,
+        timestamp: share.shared_at,
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a record ID is reserved by `begin_record`.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordReservedEvent {
+    pub record_id: u64,
+    pub patient: Address,
+    pub provider: Address,
+    pub record_type: RecordType,
+    pub reserved_by: Address,
+    pub expires_at: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a record ID is reserved for a pending upload.
+pub fn publish_record_reserved(env: &Env, pending: &PendingRecord) {
+    let topics = (
+        topics::REC_RSV,
+        pending.patient.clone(),
+        pending.provider.clone(),
+    );
+    let data = RecordReservedEvent {
+        record_id: pending.record_id,
+        patient: pending.patient.clone(),
+        provider: pending.provider.clone(),
+        record_type: pending.record_type.clone(),
+        reserved_by: pending.reserved_by.clone(),
+        expires_at: pending.expires_at,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a reserved record ID is given up. The ID is not
+/// reused.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordAbandonedEvent {
+    pub record_id: u64,
+    pub patient: Address,
+    pub provider: Address,
+    pub abandoned_by: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when `abandon_record` releases a reservation.
+pub fn publish_record_abandoned(env: &Env, pending: &PendingRecord) {
+    let topics = (
+        topics::REC_ABDN,
+        pending.patient.clone(),
+        pending.provider.clone(),
+    );
+    let data = RecordAbandonedEvent {
+        record_id: pending.record_id,
+        patient: pending.patient.clone(),
+        provider: pending.provider.clone(),
+        abandoned_by: pending.reserved_by.clone(),
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when an admin permission moves from one holder to
+/// another.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminPermissionTransferredEvent {
+    pub permission: Permission,
+    pub from: Address,
+    pub to: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when `transfer_admin_permission` hands over
+/// `InfraAdmin` or `ClinicalAdmin`.
+pub fn publish_admin_permission_transferred(
+    env: &Env,
+    permission: Permission,
+    from: Address,
+    to: Address,
+) {
+    let topics = (topics::ADM_XFER, from.clone(), to.clone());
+    let data = AdminPermissionTransferredEvent {
+        permission,
+        from,
+        to,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when the legacy SystemAdmin superset is turned on or
+/// off.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacySystemAdminChangedEvent {
+    pub enabled: bool,
+    pub changed_by: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when `set_legacy_system_admin` changes the flag.
+pub fn publish_legacy_system_admin_changed(env: &Env, enabled: bool, changed_by: Address) {
+    let topics = (topics::SYS_ADM,);
+    let data = LegacySystemAdminChangedEvent {
+        enabled,
+        changed_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a user's account is closed.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccountClosedEvent {
+    pub user: Address,
+    pub closed_by: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when `close_account` first closes an account.
+pub fn publish_account_closed(env: &Env, user: Address, closed_by: Address) {
+    let topics = (topics::ACCT_CLS, user.clone());
+    let data = AccountClosedEvent {
+        user,
+        closed_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published, once per patient, when a grant is removed because
+/// the grantee's account was closed.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClosedGranteeRemovedEvent {
+    pub patient: Address,
+    pub grantee: Address,
+    pub removed_by: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when account closure removes a patient's grant.
+pub fn publish_closed_grantee_removed(
+    env: &Env,
+    patient: Address,
+    grantee: Address,
+    removed_by: Address,
+) {
+    let topics = (topics::GRT_CLOSE, patient.clone(), grantee.clone());
+    let data = ClosedGranteeRemovedEvent {
+        patient,
+        grantee,
+        removed_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a revocation leaves the grantee a handoff note.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessRevokedWithNoteEvent {
+    pub patient: Address,
+    pub grantee: Address,
+    pub note_hash: String,
+    pub revoked_by: Address,
+    pub grant_revision: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes an event carrying the handoff note left by a revocation.
+pub fn publish_access_revoked_with_note(env: &Env, note: HandoffNote) {
+    let topics = (topics::REV_NOTE, note.patient.clone(), note.grantee.clone());
+    let revision = grant_revision::get_revision(env, &note.patient, &note.grantee);
+    let data = AccessRevokedWithNoteEvent {
+        patient: note.patient,
+        grantee: note.grantee,
+        note_hash: note.note_hash,
+        revoked_by: note.revoked_by,
+        grant_revision: revision,
+        timestamp: note.revoked_at,
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a maintenance job starts, runs or is cancelled.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JobUpdatedEvent {
+    pub job_id: u64,
+    pub kind: Symbol,
+    pub status: JobStatus,
+    pub cursor: u32,
+    pub processed: u32,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes the state of a maintenance job after `updated_by` changed it.
+pub fn publish_job_updated(env: &Env, job: &MaintenanceJob, updated_by: Address) {
+    let topics = (topics::JOB_UPD, job.kind.clone(), job.job_id);
+    let data = JobUpdatedEvent {
+        job_id: job.job_id,
+        kind: job.kind.clone(),
+        status: job.status.clone(),
+        cursor: job.cursor,
+        processed: job.processed,
+        updated_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
+
+/// Event published when a legal hold is placed on a record or released.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegalHoldEvent {
+    pub record_id: u64,
+    pub case_ref_hash: BytesN<32>,
+    pub changed_by: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a legal hold is placed.
+pub fn publish_legal_hold_placed(env: &Env, hold: &LegalHold) {
+    let topics = (topics::HOLD_SET, hold.record_id);
+    let data = LegalHoldEvent {
+        record_id: hold.record_id,
+        case_ref_hash: hold.case_ref_hash.clone(),
+        changed_by: hold.placed_by.clone(),
+        timestamp: hold.placed_at,
+    };
+    env.events().publish(topics, data);
+}
+
+/// Publishes an event when `released_by` releases a legal hold.
+pub fn publish_legal_hold_released(env: &Env, hold: &LegalHold, released_by: Address) {
+    let topics = (topics::HOLD_REL, hold.record_id);
+    let data = LegalHoldEvent {
+        record_id: hold.record_id,
+        case_ref_hash: hold.case_ref_hash.clone(),
+        changed_by: released_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(topics, data);
+}
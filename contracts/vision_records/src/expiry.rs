@@ -0,0 +1,90 @@
+use soroban_sdk::{symbol_short, Env, Symbol};
+
+use crate::instance_usage::InstanceKey;
+
+// ── Storage keys ──────────────────────────────────────────────
+const EXPIRY_GRACE: Symbol = symbol_short!("EXP_GRACE");
+const MAX_GRANT_DURATION: Symbol = symbol_short!("MAX_GRANT");
+
+/// Instance-storage keys written here.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] = &[
+    InstanceKey::number(EXPIRY_GRACE),
+    InstanceKey::number(MAX_GRANT_DURATION),
+];
+
+/// Longest grace window an admin may configure.
+pub const MAX_EXPIRY_GRACE_SECONDS: u64 = 600; // 10 minutes
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// Every expiry check in the contract goes through `is_expired`, so grants,
+// consents, delegations, sessions and prescriptions all lapse at the same
+// moment relative to their `expires_at`.
+
+/// Seconds past `expires_at` during which an entry is still honoured, to
+/// absorb clock skew between wallets and ledger close times. Defaults to 0.
+pub fn get_grace(env: &Env) -> u64 {
+    env.storage().instance().get(&EXPIRY_GRACE).unwrap_or(0)
+}
+
+pub fn set_grace(env: &Env, seconds: u64) {
+    env.storage().instance().set(&EXPIRY_GRACE, &seconds);
+}
+
+/// Longest duration, in seconds, a new access grant may run for. 0, the
+/// default, leaves grants bounded only by `validation::validate_duration`.
+pub fn get_max_grant_duration(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&MAX_GRANT_DURATION)
+        .unwrap_or(0)
+}
+
+pub fn set_max_grant_duration(env: &Env, seconds: u64) {
+    env.storage().instance().set(&MAX_GRANT_DURATION, &seconds);
+}
+
+/// Whether a grant running `duration_seconds` from now would exceed the
+/// configured maximum grant duration.
+pub fn exceeds_max_grant_duration(env: &Env, duration_seconds: u64) -> bool {
+    let max = get_max_grant_duration(env);
+    max != 0 && duration_seconds > max
+}
+
+/// Whether something expiring at `expires_at` has lapsed at `now`, given
+/// `grace` seconds of tolerance. With no grace an entry is live strictly
+/// before `expires_at` and expired from `expires_at` on.
+pub fn is_expired_at(expires_at: u64, now: u64, grace: u64) -> bool {
+    now >= expires_at.saturating_add(grace)
+}
+
+/// Whether something expiring at `expires_at` has lapsed at the current
+/// ledger time, under the configured grace window.
+pub fn is_expired(env: &Env, expires_at: u64) -> bool {
+    is_expired_at(expires_at, env.ledger().timestamp(), get_grace(env))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_expired_at_boundary_without_grace() {
+        assert!(!is_expired_at(1_000, 999, 0));
+        assert!(is_expired_at(1_000, 1_000, 0));
+        assert!(is_expired_at(1_000, 1_001, 0));
+    }
+
+    #[test]
+    fn test_is_expired_at_boundary_with_grace() {
+        assert!(!is_expired_at(1_000, 1_000, 30));
+        assert!(!is_expired_at(1_000, 1_029, 30));
+        assert!(is_expired_at(1_000, 1_030, 30));
+    }
+
+    #[test]
+    fn test_is_expired_at_saturates() {
+        assert!(!is_expired_at(u64::MAX, u64::MAX - 1, 30));
+        assert!(is_expired_at(u64::MAX, u64::MAX, 30));
+    }
+}
@@ -0,0 +1,162 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::instance_usage::InstanceKey;
+use crate::{events, expiry, AccessGrant};
+
+// ── Storage keys ──────────────────────────────────────────────
+const BEACONS_ENABLED: Symbol = symbol_short!("BCN_ON");
+const EXPIRY_BUCKET: Symbol = symbol_short!("EXP_BKT");
+
+/// Instance-storage keys written here.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] = &[InstanceKey::flag(BEACONS_ENABLED)];
+
+/// Width of one expiry bucket: grants are filed under `expires_at / 3600`.
+pub const BUCKET_SECONDS: u64 = 3_600;
+
+/// Most bucket entries `emit` examines in one call, keeping its grant
+/// reads and events within one invocation's limits.
+pub const MAX_BEACONS_PER_CALL: u32 = 30;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for an expiry bucket.
+fn extend_ttl_bucket_key(env: &Env, key: &(Symbol, u64)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A grant filed in an expiry bucket.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExpiryBeaconEntry {
+    pub patient: Address,
+    pub grantee: Address,
+}
+
+/// Outcome of one `emit_expiry_beacons` call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExpiryBeaconResult {
+    /// Beacons published by this call.
+    pub emitted: u32,
+    /// Entries still filed in the bucket; call again while non-zero.
+    pub remaining: u32,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// The bucket index only ever points at grants; it never changes them. An
+// entry leaves its bucket once beaconed, or once its grant is gone or now
+// expires in another bucket, which is what makes `emit` idempotent. A grant
+// whose expiry moves is filed again under its new bucket by the writer
+// that moved it.
+
+pub fn is_enabled(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&BEACONS_ENABLED)
+        .unwrap_or(false)
+}
+
+pub fn set_enabled(env: &Env, enabled: bool) {
+    env.storage().instance().set(&BEACONS_ENABLED, &enabled);
+}
+
+pub fn bucket_of(expires_at: u64) -> u64 {
+    expires_at / BUCKET_SECONDS
+}
+
+pub fn bucket_key(bucket: u64) -> (Symbol, u64) {
+    (EXPIRY_BUCKET, bucket)
+}
+
+pub fn get_bucket(env: &Env, bucket: u64) -> Vec<ExpiryBeaconEntry> {
+    env.storage()
+        .persistent()
+        .get(&bucket_key(bucket))
+        .unwrap_or(Vec::new(env))
+}
+
+fn write_bucket(env: &Env, bucket: u64, entries: &Vec<ExpiryBeaconEntry>) {
+    let key = bucket_key(bucket);
+    if entries.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, entries);
+        extend_ttl_bucket_key(env, &key);
+    }
+}
+
+/// Files the grant from `patient` to `grantee` under the bucket of
+/// `expires_at`. Does nothing while beacons are disabled.
+pub fn index(env: &Env, patient: &Address, grantee: &Address, expires_at: u64) {
+    if !is_enabled(env) {
+        return;
+    }
+    let bucket = bucket_of(expires_at);
+    let entry = ExpiryBeaconEntry {
+        patient: patient.clone(),
+        grantee: grantee.clone(),
+    };
+    let mut entries = get_bucket(env, bucket);
+    if !entries.contains(&entry) {
+        entries.push_back(entry);
+        write_bucket(env, bucket, &entries);
+    }
+}
+
+/// Whether every grant filed in `bucket` had its expiry before now.
+pub fn is_past(env: &Env, bucket: u64) -> bool {
+    bucket.saturating_add(1).saturating_mul(BUCKET_SECONDS) <= env.ledger().timestamp()
+}
+
+/// Publishes a beacon for up to `MAX_BEACONS_PER_CALL` entries of a past
+/// bucket whose grants have expired, and drops those and any stale entries
+/// from the bucket. Entries whose grant is still inside the expiry grace
+/// window stay for a later call.
+pub fn emit(env: &Env, bucket: u64) -> ExpiryBeaconResult {
+    let mut result = ExpiryBeaconResult {
+        emitted: 0,
+        remaining: 0,
+    };
+    let mut kept = Vec::new(env);
+    let mut examined: u32 = 0;
+    for entry in get_bucket(env, bucket).iter() {
+        if examined >= MAX_BEACONS_PER_CALL {
+            kept.push_back(entry);
+            continue;
+        }
+        examined = examined.saturating_add(1);
+
+        let grant: Option<AccessGrant> = env.storage().persistent().get(&(
+            symbol_short!("ACCESS"),
+            entry.patient.clone(),
+            entry.grantee.clone(),
+        ));
+        match grant {
+            Some(grant) if bucket_of(grant.expires_at) == bucket => {
+                if expiry::is_expired(env, grant.expires_at) {
+                    events::publish_expiry_beacon(
+                        env,
+                        entry.patient,
+                        entry.grantee,
+                        grant.expires_at,
+                        bucket,
+                    );
+                    result.emitted = result.emitted.saturating_add(1);
+                } else {
+                    kept.push_back(entry);
+                }
+            }
+            // Revoked, purged, or filed again under its new expiry
+            _ => {}
+        }
+    }
+    write_bucket(env, bucket, &kept);
+    result.remaining = kept.len();
+    result
+}
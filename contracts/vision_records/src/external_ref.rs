@@ -0,0 +1,91 @@
+use soroban_sdk::{symbol_short, Env, String, Symbol};
+
+use crate::errors::ContractError;
+
+// ── Storage keys ──────────────────────────────────────────────
+const RECORD_REF: Symbol = symbol_short!("REC_XREF");
+const REF_RECORD: Symbol = symbol_short!("XREF_REC");
+
+/// Longest external reference accepted, e.g. `DiagnosticReport/<id>`.
+pub const MAX_EXTERNAL_REF_LEN: u32 = 128;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a record's reference.
+fn extend_ttl_ref_key(env: &Env, key: &(Symbol, u64)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+/// Extends the time-to-live (TTL) for a reference's reverse index entry.
+fn extend_ttl_reverse_key(env: &Env, key: &(Symbol, String)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// A record's reference is kept beside the record, with a reverse index
+// from reference to record ID, so records stored before references
+// existed still decode and simply have none.
+
+pub fn record_ref_key(record_id: u64) -> (Symbol, u64) {
+    (RECORD_REF, record_id)
+}
+
+pub fn ref_record_key(external_ref: &String) -> (Symbol, String) {
+    (REF_RECORD, external_ref.clone())
+}
+
+/// Rejects empty references and references over the length cap.
+pub fn validate(external_ref: &String) -> Result<(), ContractError> {
+    if external_ref.is_empty() || external_ref.len() > MAX_EXTERNAL_REF_LEN {
+        return Err(ContractError::InvalidInput);
+    }
+    Ok(())
+}
+
+pub fn get_ref(env: &Env, record_id: u64) -> Option<String> {
+    env.storage().persistent().get(&record_ref_key(record_id))
+}
+
+pub fn find_record(env: &Env, external_ref: &String) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&ref_record_key(external_ref))
+}
+
+/// Fails with `DuplicateExternalRef` if `external_ref` already belongs to
+/// a record other than `record_id`.
+pub fn require_available(
+    env: &Env,
+    external_ref: &String,
+    record_id: Option<u64>,
+) -> Result<(), ContractError> {
+    match find_record(env, external_ref) {
+        Some(owner) if Some(owner) != record_id => Err(ContractError::DuplicateExternalRef),
+        _ => Ok(()),
+    }
+}
+
+/// Points `record_id` at `external_ref`, releasing its previous reference.
+/// Returns the previous reference, if any. Callers check availability.
+pub fn set_ref(env: &Env, record_id: u64, external_ref: &String) -> Option<String> {
+    let previous = get_ref(env, record_id);
+    if let Some(old) = &previous {
+        env.storage().persistent().remove(&ref_record_key(old));
+    }
+
+    let key = record_ref_key(record_id);
+    env.storage().persistent().set(&key, external_ref);
+    extend_ttl_ref_key(env, &key);
+
+    let reverse = ref_record_key(external_ref);
+    env.storage().persistent().set(&reverse, &record_id);
+    extend_ttl_reverse_key(env, &reverse);
+
+    previous
+}
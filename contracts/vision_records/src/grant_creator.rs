@@ -0,0 +1,49 @@
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+// ── Storage keys ──────────────────────────────────────────────
+const GRANT_CREATOR: Symbol = symbol_short!("GRT_CRTR");
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a grant creator key.
+fn extend_ttl_creator_key(env: &Env, key: &(Symbol, Address, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// Like the purpose, the creator is kept beside the grant so grants stored
+// before creators were tracked still decode. A missing entry reads as the
+// patient, and a patient-created grant stores nothing.
+
+pub fn creator_key(patient: &Address, grantee: &Address) -> (Symbol, Address, Address) {
+    (GRANT_CREATOR, patient.clone(), grantee.clone())
+}
+
+/// Who created the `patient` → `grantee` grant.
+pub fn get_creator(env: &Env, patient: &Address, grantee: &Address) -> Address {
+    env.storage()
+        .persistent()
+        .get(&creator_key(patient, grantee))
+        .unwrap_or_else(|| patient.clone())
+}
+
+/// Records who created the grant just stored for `patient` → `grantee`.
+pub fn set_creator(env: &Env, patient: &Address, grantee: &Address, created_by: &Address) {
+    let key = creator_key(patient, grantee);
+    if created_by == patient {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, created_by);
+        extend_ttl_creator_key(env, &key);
+    }
+}
+
+pub fn remove_creator(env: &Env, patient: &Address, grantee: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&creator_key(patient, grantee));
+}
@@ -0,0 +1,137 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::{get_grantee_index, grantee_index_key, ContractError};
+
+// ── Storage keys ──────────────────────────────────────────────
+
+/// Largest patient grantee list `compact` rebuilds in one call; past this
+/// the rebuild would need to be split across calls with a cursor.
+pub const MAX_COMPACT_INDEX_SIZE: u32 = 200;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a grant index.
+fn extend_ttl_index_key(env: &Env, key: &(Symbol, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Outcome of one `compact_grant_indexes` run.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GrantIndexCompaction {
+    /// Stale or duplicate grantees dropped from the patient's grantee list.
+    pub grantees_removed: u32,
+    /// Stale or duplicate entries for the patient dropped from grantees'
+    /// reverse indexes.
+    pub reverse_removed: u32,
+    /// Live grants the patient was missing from in a grantee's reverse index.
+    pub reverse_restored: u32,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// The `ACCESS` entries are authoritative. The patient's grantee list
+// (`ACC_LST`) and each grantee's reverse index (`ACC_RCV`) should name
+// exactly the pairs that still have one; revocation paths that skip either
+// index leave stale addresses behind. Reverse entries are reached through
+// the patient's list, so a grantee missing from that list is out of reach.
+
+fn list_key(patient: &Address) -> (Symbol, Address) {
+    (symbol_short!("ACC_LST"), patient.clone())
+}
+
+pub fn get_list(env: &Env, patient: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&list_key(patient))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn has_grant(env: &Env, patient: &Address, grantee: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&(symbol_short!("ACCESS"), patient.clone(), grantee.clone()))
+}
+
+pub fn count_of(patients: &Vec<Address>, patient: &Address) -> u32 {
+    patients.iter().filter(|p| p == patient).count() as u32
+}
+
+fn write_index(env: &Env, key: &(Symbol, Address), entries: &Vec<Address>) {
+    if entries.is_empty() {
+        env.storage().persistent().remove(key);
+    } else {
+        env.storage().persistent().set(key, entries);
+        extend_ttl_index_key(env, key);
+    }
+}
+
+/// Rebuilds the patient's grantee list and the patient's entries in each
+/// listed grantee's reverse index from the stored grants.
+pub fn compact(env: &Env, patient: &Address) -> Result<GrantIndexCompaction, ContractError> {
+    let grantees = get_list(env, patient);
+    if grantees.len() > MAX_COMPACT_INDEX_SIZE {
+        return Err(ContractError::IndexTooLarge);
+    }
+
+    let mut result = GrantIndexCompaction {
+        grantees_removed: 0,
+        reverse_removed: 0,
+        reverse_restored: 0,
+    };
+    let mut kept = Vec::new(env);
+    for grantee in grantees.iter() {
+        if kept.contains(&grantee) {
+            result.grantees_removed = result.grantees_removed.saturating_add(1);
+            continue;
+        }
+        let live = has_grant(env, patient, &grantee);
+        if live {
+            kept.push_back(grantee.clone());
+        } else {
+            result.grantees_removed = result.grantees_removed.saturating_add(1);
+        }
+
+        let reverse = get_grantee_index(env, &grantee);
+        let listed = count_of(&reverse, patient);
+        let wanted = u32::from(live);
+        if listed == wanted {
+            continue;
+        }
+        let mut rebuilt: Vec<Address> = Vec::new(env);
+        for p in reverse.iter().filter(|p| p != patient) {
+            rebuilt.push_back(p);
+        }
+        if live {
+            rebuilt.push_back(patient.clone());
+        }
+        if listed > wanted {
+            result.reverse_removed = result
+                .reverse_removed
+                .saturating_add(listed.saturating_sub(wanted));
+        } else {
+            result.reverse_restored = result.reverse_restored.saturating_add(1);
+        }
+        write_index(env, &grantee_index_key(&grantee), &rebuilt);
+    }
+
+    write_index(env, &list_key(patient), &kept);
+    Ok(result)
+}
+
+/// Whether the patient's grantee list names each granted grantee once and
+/// nothing else, and each listed grantee's reverse index names the patient
+/// exactly once.
+pub fn verify(env: &Env, patient: &Address) -> bool {
+    let grantees = get_list(env, patient);
+    grantees.iter().all(|grantee| {
+        count_of(&grantees, &grantee) == 1
+            && has_grant(env, patient, &grantee)
+            && count_of(&get_grantee_index(env, &grantee), patient) == 1
+    })
+}
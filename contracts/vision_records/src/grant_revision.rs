@@ -0,0 +1,69 @@
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+// ── Storage keys ──────────────────────────────────────────────
+const GRANT_REVISION: Symbol = symbol_short!("GRT_REV");
+const ACCESS_EPOCH: Symbol = symbol_short!("ACC_EPOCH");
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a grant revision key.
+fn extend_ttl_pair_key(env: &Env, key: &(Symbol, Address, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+/// Extends the time-to-live (TTL) for an access epoch key.
+fn extend_ttl_patient_key(env: &Env, key: &(Symbol, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// Off-chain gateways cache access decisions keyed on these two counters.
+// The grant revision moves whenever a grant, record grant, consent or
+// scheduled revocation between one patient and one grantee changes. The
+// access epoch moves when something changes the patient's access as a
+// whole, so a gateway must drop every cached decision for that patient.
+// Neither ever goes backwards, and reads never move them.
+
+pub fn revision_key(patient: &Address, grantee: &Address) -> (Symbol, Address, Address) {
+    (GRANT_REVISION, patient.clone(), grantee.clone())
+}
+
+pub fn epoch_key(patient: &Address) -> (Symbol, Address) {
+    (ACCESS_EPOCH, patient.clone())
+}
+
+pub fn get_revision(env: &Env, patient: &Address, grantee: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&revision_key(patient, grantee))
+        .unwrap_or(0)
+}
+
+/// Records a change to the access `patient` has given `grantee`.
+pub fn bump_revision(env: &Env, patient: &Address, grantee: &Address) {
+    let key = revision_key(patient, grantee);
+    let next = get_revision(env, patient, grantee).saturating_add(1);
+    env.storage().persistent().set(&key, &next);
+    extend_ttl_pair_key(env, &key);
+}
+
+pub fn get_epoch(env: &Env, patient: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&epoch_key(patient))
+        .unwrap_or(0)
+}
+
+/// Records a change to all access to `patient`'s records.
+pub fn bump_epoch(env: &Env, patient: &Address) {
+    let key = epoch_key(patient);
+    let next = get_epoch(env, patient).saturating_add(1);
+    env.storage().persistent().set(&key, &next);
+    extend_ttl_patient_key(env, &key);
+}
@@ -0,0 +1,60 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
+
+// ── Storage keys ──────────────────────────────────────────────
+const HANDOFF_NOTES: Symbol = symbol_short!("HANDOFF");
+
+/// Number of most recent handoff notes kept per grantee.
+pub const MAX_HANDOFF_NOTES: u32 = 50;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a grantee's note list.
+fn extend_ttl_grantee_key(env: &Env, key: &(Symbol, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A reference to an off-chain care summary left for a provider whose
+/// access was revoked. Only the hash is kept; the summary itself stays
+/// with the patient's systems.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HandoffNote {
+    pub patient: Address,
+    pub grantee: Address,
+    pub note_hash: String,
+    pub revoked_by: Address,
+    pub revoked_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// Notes are written once, as part of the revocation they belong to, and
+// never edited. Each grantee's list keeps the newest `MAX_HANDOFF_NOTES`;
+// adding one to a full list drops the oldest.
+
+pub fn notes_key(grantee: &Address) -> (Symbol, Address) {
+    (HANDOFF_NOTES, grantee.clone())
+}
+
+pub fn get_notes(env: &Env, grantee: &Address) -> Vec<HandoffNote> {
+    env.storage()
+        .persistent()
+        .get(&notes_key(grantee))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn add_note(env: &Env, note: &HandoffNote) {
+    let mut notes = get_notes(env, &note.grantee);
+    while notes.len() >= MAX_HANDOFF_NOTES {
+        notes.pop_front();
+    }
+    notes.push_back(note.clone());
+    let key = notes_key(&note.grantee);
+    env.storage().persistent().set(&key, &notes);
+    extend_ttl_grantee_key(env, &key);
+}
@@ -0,0 +1,64 @@
+use soroban_sdk::{symbol_short, Env, Symbol, Vec};
+
+use crate::errors::ContractError;
+use crate::instance_usage::{InstanceKey, SYMBOL_BYTES};
+
+// ── Storage keys ──────────────────────────────────────────────
+const HASH_ALGS: Symbol = symbol_short!("HASH_ALGS");
+
+/// Instance-storage keys written here.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] = &[InstanceKey::list(HASH_ALGS, SYMBOL_BYTES)];
+
+/// Reported for content written without an algorithm tag.
+pub const UNKNOWN: Symbol = symbol_short!("unknown");
+
+pub const SHA256: Symbol = symbol_short!("sha256");
+pub const BLAKE3: Symbol = symbol_short!("blake3");
+pub const CIDV0: Symbol = symbol_short!("cidv0");
+pub const CIDV1: Symbol = symbol_short!("cidv1");
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// The allowlist starts with the built-in algorithms and is stored once an
+// admin registers another one.
+
+fn default_algorithms(env: &Env) -> Vec<Symbol> {
+    Vec::from_array(env, [SHA256, BLAKE3, CIDV0, CIDV1])
+}
+
+/// Algorithms records may be tagged with.
+pub fn get_algorithms(env: &Env) -> Vec<Symbol> {
+    env.storage()
+        .instance()
+        .get(&HASH_ALGS)
+        .unwrap_or_else(|| default_algorithms(env))
+}
+
+pub fn is_supported(env: &Env, hash_alg: &Symbol) -> bool {
+    get_algorithms(env).contains(hash_alg)
+}
+
+/// Adds `hash_alg` to the allowlist. Returns false if it was already there.
+///
+/// `unknown` is reserved for untagged content and cannot be registered.
+pub fn register(env: &Env, hash_alg: &Symbol) -> Result<bool, ContractError> {
+    if *hash_alg == UNKNOWN {
+        return Err(ContractError::InvalidInput);
+    }
+    let mut algorithms = get_algorithms(env);
+    if algorithms.contains(hash_alg) {
+        return Ok(false);
+    }
+    algorithms.push_back(hash_alg.clone());
+    env.storage().instance().set(&HASH_ALGS, &algorithms);
+    Ok(true)
+}
+
+/// Rejects algorithms that are not on the allowlist.
+pub fn require_supported(env: &Env, hash_alg: &Symbol) -> Result<(), ContractError> {
+    if is_supported(env, hash_alg) {
+        Ok(())
+    } else {
+        Err(ContractError::UnsupportedHashAlgorithm)
+    }
+}
@@ -0,0 +1,107 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::instance_usage::InstanceKey;
+
+// ── Storage keys ──────────────────────────────────────────────
+const HEARTBEAT: Symbol = symbol_short!("HEARTBEAT");
+const HEARTBEAT_CONFIG: Symbol = symbol_short!("HB_CONFIG");
+
+/// Instance-storage keys written here.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] = &[
+    InstanceKey::value(HEARTBEAT, 64),
+    InstanceKey::value(HEARTBEAT_CONFIG, 24),
+];
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// The most recent admin heartbeat.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Heartbeat {
+    pub admin: Address,
+    pub timestamp: u64,
+    pub ledger: u32,
+}
+
+/// The last heartbeat as reported to monitoring.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HeartbeatInfo {
+    pub admin: Address,
+    pub timestamp: u64,
+    /// Ledgers closed since the heartbeat was recorded.
+    pub ledgers_since: u32,
+}
+
+/// How long maintenance may go without a heartbeat, and what happens
+/// once it has.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HeartbeatConfig {
+    /// Seconds after the last heartbeat at which maintenance counts as
+    /// stale; 0 disables the check.
+    pub stale_threshold: u64,
+    /// While stale, refuse new grants made on a patient's behalf by anyone
+    /// other than the patient.
+    pub lock_grants_when_stale: bool,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// Both entries live in instance storage: they are read on every grant
+// while the lockout is enabled, and stay small.
+
+pub fn get(env: &Env) -> Option<Heartbeat> {
+    env.storage().instance().get(&HEARTBEAT)
+}
+
+/// Records a heartbeat from `admin` at the current ledger.
+pub fn beat(env: &Env, admin: &Address) -> Heartbeat {
+    let heartbeat = Heartbeat {
+        admin: admin.clone(),
+        timestamp: env.ledger().timestamp(),
+        ledger: env.ledger().sequence(),
+    };
+    env.storage().instance().set(&HEARTBEAT, &heartbeat);
+    heartbeat
+}
+
+pub fn get_info(env: &Env) -> Option<HeartbeatInfo> {
+    get(env).map(|heartbeat| HeartbeatInfo {
+        admin: heartbeat.admin,
+        timestamp: heartbeat.timestamp,
+        ledgers_since: env.ledger().sequence().saturating_sub(heartbeat.ledger),
+    })
+}
+
+pub fn get_config(env: &Env) -> HeartbeatConfig {
+    env.storage()
+        .instance()
+        .get(&HEARTBEAT_CONFIG)
+        .unwrap_or(HeartbeatConfig {
+            stale_threshold: 0,
+            lock_grants_when_stale: false,
+        })
+}
+
+pub fn set_config(env: &Env, config: &HeartbeatConfig) {
+    env.storage().instance().set(&HEARTBEAT_CONFIG, config);
+}
+
+/// Whether the last heartbeat is older than the configured threshold. With
+/// a threshold set and no heartbeat ever recorded, maintenance is stale.
+pub fn is_stale(env: &Env) -> bool {
+    let threshold = get_config(env).stale_threshold;
+    if threshold == 0 {
+        return false;
+    }
+    match get(env) {
+        Some(heartbeat) => env.ledger().timestamp().saturating_sub(heartbeat.timestamp) > threshold,
+        None => true,
+    }
+}
+
+/// Whether grants from anyone but the patient are currently refused.
+pub fn grants_locked(env: &Env) -> bool {
+    get_config(env).lock_grants_when_stale && is_stale(env)
+}
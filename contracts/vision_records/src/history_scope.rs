@@ -0,0 +1,42 @@
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+// ── Storage keys ──────────────────────────────────────────────
+const HISTORY_HIDDEN: Symbol = symbol_short!("GRT_NOHST");
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a hidden-history flag.
+fn extend_ttl_flag_key(env: &Env, key: &(Symbol, Address, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// A grant normally lets the grantee read both a record and its earlier
+// versions. A patient may share only the current record by hiding the
+// history from the grantee. Like the append-only flag, only grants that
+// hide history have an entry, so existing grants keep including it.
+
+pub fn history_hidden_key(patient: &Address, grantee: &Address) -> (Symbol, Address, Address) {
+    (HISTORY_HIDDEN, patient.clone(), grantee.clone())
+}
+
+pub fn is_history_hidden(env: &Env, patient: &Address, grantee: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&history_hidden_key(patient, grantee))
+}
+
+/// Sets or clears the flag on the grant just stored for `patient` → `grantee`.
+pub fn set_history_hidden(env: &Env, patient: &Address, grantee: &Address, hidden: bool) {
+    let key = history_hidden_key(patient, grantee);
+    if hidden {
+        env.storage().persistent().set(&key, &true);
+        extend_ttl_flag_key(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
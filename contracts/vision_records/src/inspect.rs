@@ -0,0 +1,125 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::expiry;
+use crate::versioning::RecordVersion;
+use crate::{AccessGrant, VisionRecord};
+
+// ── Storage keys ──────────────────────────────────────────────
+const RECORD_GRANTEES: Symbol = symbol_short!("REC_GRTS");
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a record's grantee list.
+fn extend_ttl_record_key(env: &Env, key: &(Symbol, u64)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// How much state a patient holds, for support tooling.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StorageFootprint {
+    pub patient: Address,
+    pub records: u32,
+    /// Versions across all of the patient's records.
+    pub versions: u64,
+    /// Unexpired patient-wide grants.
+    pub grants: u32,
+    /// Active delegations the patient has made.
+    pub delegations: u32,
+    /// Grants awaiting the patient's approval.
+    pub pending_requests: u32,
+}
+
+/// Everything support tooling needs about one record.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RecordDebug {
+    pub record: VisionRecord,
+    pub version_count: u32,
+    /// The latest version, or nothing for a record without version
+    /// history. A list of at most one because a contract type cannot hold
+    /// an optional struct.
+    pub last_version: Vec<RecordVersion>,
+    /// Unexpired grants covering the record: the patient's patient-wide
+    /// grants plus grants on this record alone.
+    pub active_grant_count: u32,
+    /// Set once the record is superseded; no further versions may follow.
+    pub locked: bool,
+    /// Set when the record's priority marks it for cold storage.
+    pub archived: bool,
+    /// Set while the record has an unresolved dispute.
+    pub disputed: bool,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn record_grantees_key(record_id: u64) -> (Symbol, u64) {
+    (RECORD_GRANTEES, record_id)
+}
+
+/// Grantees holding (or having held) a record-level grant on the record.
+pub fn get_record_grantees(env: &Env, record_id: u64) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&record_grantees_key(record_id))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn add_record_grantee(env: &Env, record_id: u64, grantee: &Address) {
+    let key = record_grantees_key(record_id);
+    let mut grantees = get_record_grantees(env, record_id);
+    if !grantees.contains(grantee) {
+        grantees.push_back(grantee.clone());
+        env.storage().persistent().set(&key, &grantees);
+    }
+    extend_ttl_record_key(env, &key);
+}
+
+pub fn remove_record_grantee(env: &Env, record_id: u64, grantee: &Address) {
+    let mut grantees = get_record_grantees(env, record_id);
+    if let Some(index) = grantees.first_index_of(grantee) {
+        grantees.remove(index);
+        env.storage()
+            .persistent()
+            .set(&record_grantees_key(record_id), &grantees);
+    }
+}
+
+fn is_active(env: &Env, grant: Option<AccessGrant>) -> bool {
+    grant.is_some_and(|grant| !expiry::is_expired(env, grant.expires_at))
+}
+
+/// Counts the patient's unexpired patient-wide grants.
+pub fn count_active_grants(env: &Env, patient: &Address) -> u32 {
+    let grantees: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&(symbol_short!("ACC_LST"), patient.clone()))
+        .unwrap_or(Vec::new(env));
+    let mut count: u32 = 0;
+    for grantee in grantees.iter() {
+        let key = (symbol_short!("ACCESS"), patient.clone(), grantee);
+        if is_active(env, env.storage().persistent().get(&key)) {
+            count = count.saturating_add(1);
+        }
+    }
+    count
+}
+
+/// Counts the unexpired grants on the record alone. Record grants made
+/// before grantees were listed per record are not counted.
+pub fn count_active_record_grants(env: &Env, record_id: u64) -> u32 {
+    let mut count: u32 = 0;
+    for grantee in get_record_grantees(env, record_id).iter() {
+        let key = (symbol_short!("REC_ACC"), record_id, grantee);
+        if is_active(env, env.storage().persistent().get(&key)) {
+            count = count.saturating_add(1);
+        }
+    }
+    count
+}
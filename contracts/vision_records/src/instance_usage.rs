@@ -0,0 +1,189 @@
+use soroban_sdk::{contracttype, symbol_short, Env, Symbol, Val, Vec};
+
+// ── Storage keys ──────────────────────────────────────────────
+const WARN_THRESHOLD: Symbol = symbol_short!("INST_WARN");
+
+/// Instance-storage keys written here.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] = &[InstanceKey::number(WARN_THRESHOLD)];
+
+/// Estimated instance size above which `InstanceUsage::warning` is set,
+/// until an admin configures another: half of a 64 KiB ledger entry.
+pub const DEFAULT_WARN_THRESHOLD_BYTES: u32 = 32_768;
+
+/// Approximate encoded sizes used by the estimate. They only need to be
+/// close enough to show a trend, not to match the XDR byte for byte.
+pub const KEY_BYTES: u32 = 16;
+pub const FLAG_BYTES: u32 = 4;
+pub const NUMBER_BYTES: u32 = 12;
+pub const SYMBOL_BYTES: u32 = 12;
+pub const ADDRESS_BYTES: u32 = 44;
+pub const LIST_BYTES: u32 = 8;
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// How the size of a registered instance entry is estimated.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InstanceSize {
+    /// One value of about this many bytes.
+    Fixed(u32),
+    /// A `Vec` of about this many bytes per element.
+    List(u32),
+    /// A family of keys sharing this symbol as their first element, one
+    /// entry of about this many bytes each. The family cannot be listed,
+    /// so it is registered but left out of the estimate.
+    Keyed(u32),
+}
+
+/// An instance-storage key a module writes, and how large its entry gets.
+#[derive(Clone, Debug)]
+pub struct InstanceKey {
+    pub key: Symbol,
+    pub size: InstanceSize,
+}
+
+impl InstanceKey {
+    pub const fn flag(key: Symbol) -> Self {
+        Self::value(key, FLAG_BYTES)
+    }
+
+    pub const fn number(key: Symbol) -> Self {
+        Self::value(key, NUMBER_BYTES)
+    }
+
+    pub const fn value(key: Symbol, bytes: u32) -> Self {
+        Self {
+            key,
+            size: InstanceSize::Fixed(bytes),
+        }
+    }
+
+    pub const fn list(key: Symbol, item_bytes: u32) -> Self {
+        Self {
+            key,
+            size: InstanceSize::List(item_bytes),
+        }
+    }
+
+    pub const fn keyed(key: Symbol, entry_bytes: u32) -> Self {
+        Self {
+            key,
+            size: InstanceSize::Keyed(entry_bytes),
+        }
+    }
+}
+
+/// What the contract currently holds in instance storage. The whole
+/// instance is one ledger entry, loaded by every call, so it must stay
+/// well under the network's entry size limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InstanceUsage {
+    /// Registered keys currently set.
+    pub key_count: u32,
+    /// Estimated size of those keys and their values.
+    pub estimated_bytes: u32,
+    pub warn_threshold_bytes: u32,
+    /// `estimated_bytes` is above `warn_threshold_bytes`.
+    pub warning: bool,
+}
+
+// ── Registry ─────────────────────────────────────────────────
+//
+// Every module that writes instance storage lists its keys in its own
+// `INSTANCE_KEYS` table, next to the key constants, and the table is
+// added here. A key missing from the registry is invisible to
+// `get_instance_usage`; `test_instance_usage` checks the registry against
+// the full list of keys so a new one cannot be left out unnoticed.
+
+/// Keys written by `teye_common` on this contract's behalf.
+const SHARED_INSTANCE_KEYS: &[InstanceKey] = &[
+    InstanceKey::value(symbol_short!("S_ADMIN"), ADDRESS_BYTES),
+    InstanceKey::value(symbol_short!("MSIG_CFG"), 160),
+    InstanceKey::number(symbol_short!("MSIG_CTR")),
+    InstanceKey::flag(symbol_short!("WL_EN")),
+    InstanceKey::flag(symbol_short!("REN_LOCK")),
+];
+
+const REGISTRY: &[&[InstanceKey]] = &[
+    crate::INSTANCE_KEYS,
+    crate::appointment::INSTANCE_KEYS,
+    crate::audit::INSTANCE_KEYS,
+    crate::circuit_breaker::INSTANCE_KEYS,
+    crate::compliance::INSTANCE_KEYS,
+    crate::contract_grantee::INSTANCE_KEYS,
+    crate::delegation_cooldown::INSTANCE_KEYS,
+    crate::emergency::INSTANCE_KEYS,
+    crate::errors::INSTANCE_KEYS,
+    crate::expiry::INSTANCE_KEYS,
+    crate::expiry_beacon::INSTANCE_KEYS,
+    crate::hash_alg::INSTANCE_KEYS,
+    crate::heartbeat::INSTANCE_KEYS,
+    INSTANCE_KEYS,
+    crate::jobs::INSTANCE_KEYS,
+    crate::legacy_read::INSTANCE_KEYS,
+    crate::observer::INSTANCE_KEYS,
+    crate::offer::INSTANCE_KEYS,
+    crate::period_stats::INSTANCE_KEYS,
+    crate::priority::INSTANCE_KEYS,
+    crate::provider::INSTANCE_KEYS,
+    crate::quota::INSTANCE_KEYS,
+    crate::rbac::INSTANCE_KEYS,
+    crate::record_counter::INSTANCE_KEYS,
+    crate::record_preset::INSTANCE_KEYS,
+    crate::registration::INSTANCE_KEYS,
+    crate::residency::INSTANCE_KEYS,
+    crate::revocation::INSTANCE_KEYS,
+    SHARED_INSTANCE_KEYS,
+];
+
+/// Every registered instance key.
+pub fn registered_keys() -> impl Iterator<Item = &'static InstanceKey> {
+    REGISTRY.iter().flat_map(|keys| keys.iter())
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn get_warn_threshold(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&WARN_THRESHOLD)
+        .unwrap_or(DEFAULT_WARN_THRESHOLD_BYTES)
+}
+
+pub fn set_warn_threshold(env: &Env, bytes: u32) {
+    env.storage().instance().set(&WARN_THRESHOLD, &bytes);
+}
+
+/// Estimated size of `key`'s entry, or `None` if it is not set or is a
+/// keyed family.
+fn entry_bytes(env: &Env, key: &InstanceKey) -> Option<u32> {
+    let storage = env.storage().instance();
+    let value_bytes = match key.size {
+        InstanceSize::Fixed(bytes) => storage.has(&key.key).then_some(bytes)?,
+        InstanceSize::List(item_bytes) => {
+            let items: Vec<Val> = storage.get(&key.key)?;
+            LIST_BYTES.saturating_add(item_bytes.saturating_mul(items.len()))
+        }
+        InstanceSize::Keyed(_) => return None,
+    };
+    Some(KEY_BYTES.saturating_add(value_bytes))
+}
+
+/// Sums the registered keys that are currently set.
+pub fn measure(env: &Env) -> InstanceUsage {
+    let mut key_count = 0u32;
+    let mut estimated_bytes = 0u32;
+    for key in registered_keys() {
+        if let Some(bytes) = entry_bytes(env, key) {
+            key_count = key_count.saturating_add(1);
+            estimated_bytes = estimated_bytes.saturating_add(bytes);
+        }
+    }
+    let warn_threshold_bytes = get_warn_threshold(env);
+    InstanceUsage {
+        key_count,
+        estimated_bytes,
+        warn_threshold_bytes,
+        warning: estimated_bytes > warn_threshold_bytes,
+    }
+}
@@ -0,0 +1,177 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::{
+    decrypt_data_hash, get_grantee_index, grant_index, patient_index, provisional, record_counter,
+    versioning, ContractError, VisionRecord,
+};
+
+// ── Invariants ────────────────────────────────────────────────
+
+/// A record ID in the patient's index has no stored record.
+pub const IDX_DANGLING: Symbol = symbol_short!("IDX_DANGL");
+/// A record ID in the patient's index belongs to another patient.
+pub const IDX_FOREIGN: Symbol = symbol_short!("IDX_FORGN");
+/// The patient's index is out of creation order or repeats an ID.
+pub const IDX_ORDER: Symbol = symbol_short!("IDX_ORDER");
+/// The record is missing from its patient's index.
+pub const REC_UNINDEXED: Symbol = symbol_short!("REC_UNIDX");
+/// The record stored under an ID carries a different `id`.
+pub const REC_ID: Symbol = symbol_short!("REC_ID");
+/// The record has no versions.
+pub const VER_NONE: Symbol = symbol_short!("VER_NONE");
+/// The version named by the record's version count is missing.
+pub const VER_MISSING: Symbol = symbol_short!("VER_MISS");
+/// A version exists beyond the record's version count.
+pub const VER_OVERRUN: Symbol = symbol_short!("VER_OVER");
+/// The latest version's content differs from the record's.
+pub const VER_HASH: Symbol = symbol_short!("VER_HASH");
+/// A record ID is above its counter shard's sequence.
+pub const COUNTER_BEHIND: Symbol = symbol_short!("CTR_BHND");
+/// The patient's grantee list repeats a grantee or names one without a
+/// grant.
+pub const GRANT_LIST: Symbol = symbol_short!("GRT_LIST");
+/// A listed grantee's reverse index does not name the patient exactly once.
+pub const GRANT_REVERSE: Symbol = symbol_short!("GRT_REV");
+/// The patient's own reverse index, as a grantee, names a patient with no
+/// matching grant or list entry.
+pub const GRANT_RECEIVED: Symbol = symbol_short!("GRT_RCV");
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// What a `check_invariants` call examines.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InvariantScope {
+    /// The patient's record index and both directions of their grant
+    /// indexes.
+    Patient(Address),
+    /// One record, its place in its patient's index, and its versions.
+    Record(u64),
+}
+
+// ── Checks ───────────────────────────────────────────────────
+//
+// Every check reads only what hangs off the given scope, and the lists it
+// walks are capped by the same limits the repair endpoints use, so a call
+// costs about as much as repairing that scope would. Violations are
+// reported once each, in the order the checks run.
+
+/// Returns the invariants violated within `scope`; empty when consistent.
+pub fn check(env: &Env, scope: &InvariantScope) -> Result<Vec<Symbol>, ContractError> {
+    let mut violations = Vec::new(env);
+    match scope {
+        InvariantScope::Patient(patient) => check_patient(env, patient, &mut violations)?,
+        InvariantScope::Record(record_id) => check_record(env, *record_id, &mut violations)?,
+    }
+    Ok(violations)
+}
+
+fn report(violations: &mut Vec<Symbol>, invariant: Symbol) {
+    if !violations.contains(&invariant) {
+        violations.push_back(invariant);
+    }
+}
+
+fn get_record(env: &Env, record_id: u64) -> Option<VisionRecord> {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("RECORD"), record_id))
+}
+
+fn check_patient(
+    env: &Env,
+    patient: &Address,
+    violations: &mut Vec<Symbol>,
+) -> Result<(), ContractError> {
+    let record_ids = patient_index::get_records(env, patient);
+    let grantees = grant_index::get_list(env, patient);
+    let received = get_grantee_index(env, patient);
+    if record_ids.len() > patient_index::MAX_REPAIR_INDEX_SIZE
+        || grantees.len() > grant_index::MAX_COMPACT_INDEX_SIZE
+        || received.len() > grant_index::MAX_COMPACT_INDEX_SIZE
+    {
+        return Err(ContractError::IndexTooLarge);
+    }
+
+    // Order is checked from the records already loaded here, keyed as the
+    // index sorts them, rather than reloading them through the index
+    let mut previous: Option<(u64, u64)> = None;
+    for record_id in record_ids.iter() {
+        let record = get_record(env, record_id);
+        match &record {
+            None => report(violations, IDX_DANGLING),
+            Some(record) if record.patient != *patient => report(violations, IDX_FOREIGN),
+            Some(_) => {}
+        }
+        if !record_counter::is_allocated(env, record_id) {
+            report(violations, COUNTER_BEHIND);
+        }
+        let key = (record.map_or(0, |record| record.created_at), record_id);
+        if previous.is_some_and(|previous| previous >= key) {
+            report(violations, IDX_ORDER);
+        }
+        previous = Some(key);
+    }
+
+    for grantee in grantees.iter() {
+        let live = grant_index::has_grant(env, patient, &grantee);
+        if grant_index::count_of(&grantees, &grantee) != 1 || !live {
+            report(violations, GRANT_LIST);
+        }
+        let reverse = get_grantee_index(env, &grantee);
+        if grant_index::count_of(&reverse, patient) != u32::from(live) {
+            report(violations, GRANT_REVERSE);
+        }
+    }
+
+    for grantor in received.iter() {
+        if grant_index::count_of(&received, &grantor) != 1
+            || !grant_index::has_grant(env, &grantor, patient)
+            || !grant_index::get_list(env, &grantor).contains(patient)
+        {
+            report(violations, GRANT_RECEIVED);
+        }
+    }
+    Ok(())
+}
+
+fn check_record(
+    env: &Env,
+    record_id: u64,
+    violations: &mut Vec<Symbol>,
+) -> Result<(), ContractError> {
+    let record = get_record(env, record_id).ok_or(ContractError::RecordNotFound)?;
+    if record.id != record_id {
+        report(violations, REC_ID);
+    }
+    if !record_counter::is_allocated(env, record_id) {
+        report(violations, COUNTER_BEHIND);
+    }
+    // Unclaimed provisional records join an index only when claimed
+    if !provisional::is_placeholder(env, &record.patient)
+        && !patient_index::get_records(env, &record.patient).contains(record_id)
+    {
+        report(violations, REC_UNINDEXED);
+    }
+
+    let count = versioning::get_version_count(env, record_id);
+    if count == 0 {
+        report(violations, VER_NONE);
+    } else {
+        match versioning::get_version(env, record_id, count) {
+            None => report(violations, VER_MISSING),
+            Some(latest) if !latest.redacted => {
+                let latest_hash = decrypt_data_hash(env, &latest.data_hash, &latest.key_version);
+                let record_hash = decrypt_data_hash(env, &record.data_hash, &record.key_version);
+                if latest_hash != record_hash {
+                    report(violations, VER_HASH);
+                }
+            }
+            Some(_) => {}
+        }
+    }
+    if versioning::get_version(env, record_id, count.saturating_add(1)).is_some() {
+        report(violations, VER_OVERRUN);
+    }
+    Ok(())
+}
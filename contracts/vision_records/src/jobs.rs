@@ -0,0 +1,158 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::events::admin_ops;
+use crate::instance_usage::InstanceKey;
+use crate::pending_gc::PendingCategory;
+use crate::ContractError;
+
+// ── Storage keys ──────────────────────────────────────────────
+const JOB: Symbol = symbol_short!("JOB");
+const JOB_SEQ: Symbol = symbol_short!("JOB_SEQ");
+const ACTIVE_JOB: Symbol = symbol_short!("JOB_ACT");
+
+/// Instance-storage keys written here.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] = &[InstanceKey::number(JOB_SEQ)];
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a job.
+fn extend_ttl_job_key(env: &Env, key: &(Symbol, u64)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+/// Extends the time-to-live (TTL) for a kind's active-job marker.
+fn extend_ttl_kind_key(env: &Env, key: &(Symbol, Symbol)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// What a maintenance job works through. Each variant is one job kind,
+/// named by the matching [`admin_ops`] symbol.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum JobParams {
+    /// `gc_pending` over one category's pending-entry index.
+    PendingGc(PendingCategory),
+    /// `revoke_all_access_for_grantee` for one grantee.
+    GranteeSweep(Address),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum JobStatus {
+    Running,
+    Completed,
+    Cancelled,
+}
+
+/// A maintenance job and how far it has got. `cursor` is where the next
+/// run resumes; jobs whose runs drain their index from the front keep it
+/// at 0.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaintenanceJob {
+    pub job_id: u64,
+    pub kind: Symbol,
+    pub params: JobParams,
+    pub cursor: u32,
+    pub processed: u32,
+    pub status: JobStatus,
+    pub started_by: Address,
+    pub started_at: u64,
+    pub updated_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// Jobs keep their cursor here rather than with the caller, so a run that
+// fails can simply be retried. At most one job per kind is running at a
+// time: two walks of the same index from different cursors would skip or
+// repeat entries as each compacts it under the other.
+
+pub fn job_key(job_id: u64) -> (Symbol, u64) {
+    (JOB, job_id)
+}
+
+pub fn active_key(kind: &Symbol) -> (Symbol, Symbol) {
+    (ACTIVE_JOB, kind.clone())
+}
+
+/// The kind a job over `params` belongs to.
+pub fn kind_of(params: &JobParams) -> Symbol {
+    match params {
+        JobParams::PendingGc(_) => admin_ops::PENDING_GC,
+        JobParams::GranteeSweep(_) => admin_ops::GRANTEE_SWEEP,
+    }
+}
+
+pub fn get_job(env: &Env, job_id: u64) -> Option<MaintenanceJob> {
+    env.storage().persistent().get(&job_key(job_id))
+}
+
+/// The running job of `kind`, if any.
+pub fn get_active(env: &Env, kind: &Symbol) -> Option<u64> {
+    env.storage().persistent().get(&active_key(kind))
+}
+
+/// Registers a running job over `params`. Fails with `JobAlreadyRunning`
+/// while another job of the same kind is running.
+pub fn start(
+    env: &Env,
+    params: &JobParams,
+    started_by: &Address,
+) -> Result<MaintenanceJob, ContractError> {
+    let kind = kind_of(params);
+    if get_active(env, &kind).is_some() {
+        return Err(ContractError::JobAlreadyRunning);
+    }
+    let job_id = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&JOB_SEQ)
+        .unwrap_or(0)
+        .saturating_add(1);
+    env.storage().instance().set(&JOB_SEQ, &job_id);
+
+    let now = env.ledger().timestamp();
+    let job = MaintenanceJob {
+        job_id,
+        kind: kind.clone(),
+        params: params.clone(),
+        cursor: 0,
+        processed: 0,
+        status: JobStatus::Running,
+        started_by: started_by.clone(),
+        started_at: now,
+        updated_at: now,
+    };
+    let key = active_key(&kind);
+    env.storage().persistent().set(&key, &job_id);
+    extend_ttl_kind_key(env, &key);
+    save(env, &job);
+    Ok(job)
+}
+
+/// Stores `job`, releasing its kind once it is no longer running.
+pub fn save(env: &Env, job: &MaintenanceJob) {
+    let key = job_key(job.job_id);
+    env.storage().persistent().set(&key, job);
+    extend_ttl_job_key(env, &key);
+    if job.status != JobStatus::Running {
+        env.storage().persistent().remove(&active_key(&job.kind));
+    }
+}
+
+/// The running job `job_id`, or `JobNotFound` / `JobNotRunning`.
+pub fn get_running(env: &Env, job_id: u64) -> Result<MaintenanceJob, ContractError> {
+    let job = get_job(env, job_id).ok_or(ContractError::JobNotFound)?;
+    if job.status != JobStatus::Running {
+        return Err(ContractError::JobNotRunning);
+    }
+    Ok(job)
+}
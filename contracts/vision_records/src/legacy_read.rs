@@ -0,0 +1,39 @@
+use soroban_sdk::{contracttype, symbol_short, Env, Symbol};
+
+use crate::instance_usage::InstanceKey;
+
+// ── Storage keys ──────────────────────────────────────────────
+const LEGACY_READ_MODE: Symbol = symbol_short!("LEG_READ");
+
+/// Instance-storage keys written here.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] = &[InstanceKey::value(LEGACY_READ_MODE, 12)];
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Who may call the record-listing endpoints that take no caller, such as
+/// `get_patient_records`. Each has an authenticated `_as` variant that is
+/// unaffected.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LegacyReadMode {
+    /// Anyone may call them, as before.
+    Open,
+    /// The contract admin must authorize the call.
+    AdminOnly,
+    /// Every call fails with `LegacyEndpointDisabled`.
+    Disabled,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+/// The current mode, `Open` until an admin changes it.
+pub fn get_mode(env: &Env) -> LegacyReadMode {
+    env.storage()
+        .instance()
+        .get(&LEGACY_READ_MODE)
+        .unwrap_or(LegacyReadMode::Open)
+}
+
+pub fn set_mode(env: &Env, mode: &LegacyReadMode) {
+    env.storage().instance().set(&LEGACY_READ_MODE, mode);
+}
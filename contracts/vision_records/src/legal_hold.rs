@@ -0,0 +1,121 @@
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Symbol, Vec};
+
+use crate::ContractError;
+
+// ── Storage keys ──────────────────────────────────────────────
+const LEGAL_HOLD: Symbol = symbol_short!("LGL_HOLD");
+const HOLD_INDEX: Symbol = symbol_short!("LGL_IDX");
+
+/// Most records that may be under a legal hold at once.
+pub const MAX_LEGAL_HOLDS: u32 = 200;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a record's legal hold.
+fn extend_ttl_hold_key(env: &Env, key: &(Symbol, u64)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A hold keeping a record intact for litigation. `case_ref_hash` is the
+/// hash of the off-chain case reference.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegalHold {
+    pub record_id: u64,
+    pub case_ref_hash: BytesN<32>,
+    pub placed_by: Address,
+    pub placed_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// A held record can still be read and updated, since new versions keep
+// the old ones, but nothing may remove, rewrite or retire its history.
+// Anything that redacts, archives or supersedes a record must call
+// `require_not_held` first.
+
+pub fn hold_key(record_id: u64) -> (Symbol, u64) {
+    (LEGAL_HOLD, record_id)
+}
+
+pub fn get_hold(env: &Env, record_id: u64) -> Option<LegalHold> {
+    env.storage().persistent().get(&hold_key(record_id))
+}
+
+pub fn is_held(env: &Env, record_id: u64) -> bool {
+    env.storage().persistent().has(&hold_key(record_id))
+}
+
+/// Fails with `LegalHold` if the record is under a legal hold.
+pub fn require_not_held(env: &Env, record_id: u64) -> Result<(), ContractError> {
+    if is_held(env, record_id) {
+        return Err(ContractError::LegalHold);
+    }
+    Ok(())
+}
+
+fn get_index(env: &Env) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&HOLD_INDEX)
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_index(env: &Env, index: &Vec<u64>) {
+    if index.is_empty() {
+        env.storage().persistent().remove(&HOLD_INDEX);
+    } else {
+        env.storage().persistent().set(&HOLD_INDEX, index);
+        env.storage()
+            .persistent()
+            .extend_ttl(&HOLD_INDEX, TTL_THRESHOLD, TTL_EXTEND_TO);
+    }
+}
+
+/// Places `hold`. Fails with `AlreadyExists` if the record is already
+/// held, or `InvalidInput` once `MAX_LEGAL_HOLDS` records are.
+pub fn place(env: &Env, hold: &LegalHold) -> Result<(), ContractError> {
+    if is_held(env, hold.record_id) {
+        return Err(ContractError::AlreadyExists);
+    }
+    let mut index = get_index(env);
+    if index.len() >= MAX_LEGAL_HOLDS {
+        return Err(ContractError::InvalidInput);
+    }
+    index.push_back(hold.record_id);
+    set_index(env, &index);
+
+    let key = hold_key(hold.record_id);
+    env.storage().persistent().set(&key, hold);
+    extend_ttl_hold_key(env, &key);
+    Ok(())
+}
+
+/// Releases the record's hold and returns it, or fails with
+/// `LegalHoldNotFound` if the record is not held.
+pub fn release(env: &Env, record_id: u64) -> Result<LegalHold, ContractError> {
+    let hold = get_hold(env, record_id).ok_or(ContractError::LegalHoldNotFound)?;
+    env.storage().persistent().remove(&hold_key(record_id));
+    let mut index = get_index(env);
+    if let Some(pos) = index.first_index_of(record_id) {
+        index.remove(pos);
+        set_index(env, &index);
+    }
+    Ok(hold)
+}
+
+/// Every active hold, oldest first.
+pub fn get_holds(env: &Env) -> Vec<LegalHold> {
+    let mut holds = Vec::new(env);
+    for record_id in get_index(env).iter() {
+        if let Some(hold) = get_hold(env, record_id) {
+            holds.push_back(hold);
+        }
+    }
+    holds
+}
@@ -1,27 +1,109 @@
 #![no_std]
 #![allow(clippy::too_many_arguments)]
 extern crate alloc;
+pub mod access;
+pub mod access_export;
+pub mod access_freeze;
+pub mod access_template;
+pub mod activity;
 pub mod appointment;
+pub mod approval;
 pub mod audit;
+pub mod auth;
+pub mod bounded;
+pub mod breach;
 pub mod circuit_breaker;
+pub mod claim;
+pub mod coauthor;
+pub mod commitment;
+pub mod compliance;
+pub mod confirmation;
+pub mod consent_proof;
+pub mod contract_grantee;
+pub mod counters;
+pub mod cursor;
+pub mod deactivation;
+pub mod delegation_cooldown;
+pub mod digest;
+pub mod dispute;
+pub mod embargo;
 pub mod emergency;
 pub mod errors;
 pub mod events;
 pub mod examination;
+pub mod expiry;
+pub mod expiry_beacon;
+pub mod external_ref;
+pub mod grant_creator;
+pub mod grant_index;
+pub mod grant_revision;
+pub mod handoff_note;
+pub mod hash_alg;
+pub mod heartbeat;
+pub mod history_scope;
+pub mod inspect;
+pub mod instance_usage;
+pub mod invariants;
+pub mod jobs;
+pub mod legacy_read;
+pub mod legal_hold;
+pub mod observer;
+pub mod offer;
+pub mod organization;
+pub mod patient_index;
+pub mod patient_label;
 pub mod patient_profile;
+pub mod patient_summary;
+pub mod pending_gc;
+pub mod pending_record;
+pub mod period_stats;
 pub mod prescription;
+pub mod priority;
 pub mod provider;
+pub mod provider_stats;
+pub mod provider_template;
+pub mod provisional;
+pub mod purpose;
+pub mod quota;
 pub mod rate_limit;
 pub mod rbac;
+pub mod receipt;
+pub mod record_counter;
+pub mod record_order;
+pub mod record_override;
+pub mod record_policy;
+pub mod record_preset;
+pub mod recovery;
+pub mod redaction;
+pub mod registration;
+pub mod residency;
+pub mod revocation;
+pub mod role_history;
+pub mod session;
+pub mod share_code;
+pub mod signed_grant;
+pub mod staleness;
+pub mod temp_storage;
+#[cfg(any(test, feature = "testutils"))]
+pub mod testutils;
+pub mod user_share;
 pub mod validation;
+pub mod version_diff;
+pub mod version_pin;
+pub mod versioning;
+pub mod visibility;
+pub mod write_cost;
+pub mod write_scope;
+pub mod write_session;
 
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, Address, BytesN, Env, String, Symbol, Vec,
+    contract, contractimpl, contracttype, symbol_short, Address, Bytes, BytesN, Env, Map, String,
+    Symbol, Vec,
 };
 
-extern crate alloc;
 use alloc::string::ToString;
 use teye_common::{multisig, whitelist, KeyManager, StdString, StdVec, admin_tiers, AdminTier};
+use instance_usage::InstanceKey;
 
 /// Re-export the contract-specific error type at the crate root.
 pub use errors::ContractError;
@@ -33,16 +115,73 @@ pub use provider::VerificationStatus;
 pub use errors::{create_error_context, log_error};
 
 /// Re-export types from submodules used directly in the contract impl.
+pub use access_export::{AccessGrantPage, AccessStateExport, AccessStateGap};
+pub use access_freeze::AccessFreeze;
+pub use access_template::{AccessTemplate, AccessTemplateEntry};
+pub use activity::ActivityEntry;
+pub use approval::PendingGrant;
 pub use audit::{AccessAction, AccessResult};
+pub use auth::{AuthAction, AuthDecision};
+pub use bounded::{BoundedAuditEntries, BoundedGrants, BoundedVersions};
+pub use breach::BreachFlag;
+pub use claim::ClaimAttestation;
+pub use coauthor::ProviderRecord;
+pub use commitment::{CommitmentEntry, PatientCommitment};
+pub use compliance::{ComplianceEntry, CompliancePage};
+pub use consent_proof::{ConsentProof, ConsentProofFields};
+pub use cursor::{Cursor, RecordIdPage, VersionPage};
+pub use deactivation::{CleanupProgress, DeactivationCascade, DeactivationSummary};
+pub use digest::DigestScope;
+pub use dispute::{Dispute, DisputeResolution, DisputeStatus};
+pub use embargo::RecordEmbargo;
 pub use examination::{
     EyeExamination, IntraocularPressure, OptFundusPhotography, OptRetinalImaging, OptVisualField,
     SlitLampFindings, VisualAcuity,
 };
+pub use expiry_beacon::{ExpiryBeaconEntry, ExpiryBeaconResult};
+pub use grant_index::GrantIndexCompaction;
+pub use handoff_note::HandoffNote;
+pub use heartbeat::{HeartbeatConfig, HeartbeatInfo};
+pub use inspect::{RecordDebug, StorageFootprint};
+pub use instance_usage::InstanceUsage;
+pub use invariants::InvariantScope;
+pub use jobs::{JobParams, JobStatus, MaintenanceJob};
+pub use legacy_read::LegacyReadMode;
+pub use legal_hold::LegalHold;
+pub use observer::Observer;
+pub use offer::AccessOffer;
+pub use organization::{OrgGrant, OrgMembership, Organization};
+pub use patient_index::{PatientIndexCheck, PatientIndexRepair};
 pub use patient_profile::{
     EmergencyContact, InsuranceInfo, OptionalEmergencyContact, OptionalInsuranceInfo,
     PatientProfile,
 };
+pub use patient_summary::{PatientProfileSummary, RecordTypeCount, SummaryVisibility};
+pub use pending_gc::{PendingCategory, PendingGcResult};
+pub use pending_record::PendingRecord;
 pub use prescription::{LensType, OptionalContactLensData, Prescription, PrescriptionData};
+pub use priority::{PriorityRecords, RecordPriority};
+pub use provider_stats::ProviderStats;
+pub use provider_template::ProviderAccessTemplate;
+pub use purpose::{GrantPurpose, GrantStats, PurposeCount};
+pub use quota::QuotaUsage;
+pub use receipt::{AddRecordReceipt, GrantReceipt, UpdateReceipt};
+pub use record_order::RecordOrder;
+pub use record_override::{EffectiveAccess, RecordAccessOverride};
+pub use record_policy::{RecordTypePolicy, RecordTypeRequirement};
+pub use record_preset::RecordPreset;
+pub use recovery::PatientRecovery;
+pub use redaction::RedactedOriginal;
+pub use revocation::PendingRevocation;
+pub use role_history::{RoleAction, RoleHistoryEntry};
+pub use session::Session;
+pub use share_code::ShareCode;
+pub use staleness::StalenessPolicy;
+pub use user_share::UserShare;
+pub use versioning::{RecordComparison, RecordSummary, RecordVersion};
+pub use visibility::RoleVisibility;
+pub use write_cost::{WriteCostAction, WriteCostEstimate};
+pub use write_session::WriteSession;
 
 /// Storage keys for the contract
 const ADMIN: Symbol = symbol_short!("ADMIN");
@@ -57,6 +196,23 @@ const TTL_EXTEND_TO: u32 = 10368000;
 const ENC_CUR: Symbol = symbol_short!("ENC_CUR");
 const ENC_KEY: Symbol = symbol_short!("ENC_KEY");
 
+/// Instance-storage keys written by the contract entry points.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] = &[
+    InstanceKey::value(ADMIN, instance_usage::ADDRESS_BYTES),
+    InstanceKey::value(PENDING_ADMIN, instance_usage::ADDRESS_BYTES),
+    InstanceKey::flag(INITIALIZED),
+    InstanceKey::value(RATE_CFG, 24),
+    InstanceKey::value(ENC_CUR, 40),
+    InstanceKey::number(symbol_short!("RX_CTR")),
+];
+
+/// Maximum number of record IDs accepted by `get_records`. Each ID costs
+/// about a dozen reads and an audit write, so batches stay small.
+pub const MAX_GET_RECORDS: u32 = 10;
+
+/// Maximum number of records changed together by `update_records_atomic`.
+pub const MAX_ATOMIC_UPDATES: u32 = 5;
+
 /// Extends the time-to-live (TTL) for a storage key containing an Address.
 /// This ensures the data remains accessible for the extended period.
 fn extend_ttl_address_key(env: &Env, key: &(Symbol, Address)) {
@@ -94,12 +250,133 @@ fn consent_key(patient: &Address, grantee: &Address) -> (Symbol, Address, Addres
 fn has_active_consent(env: &Env, patient: &Address, grantee: &Address) -> bool {
     let key = consent_key(patient, grantee);
     if let Some(consent) = env.storage().persistent().get::<_, ConsentGrant>(&key) {
-        !consent.revoked && consent.expires_at > env.ledger().timestamp()
+        !consent.revoked && !expiry::is_expired(env, consent.expires_at)
     } else {
         false
     }
 }
 
+fn grantee_index_key(grantee: &Address) -> (Symbol, Address) {
+    (symbol_short!("ACC_RCV"), grantee.clone())
+}
+
+/// Returns the patients that have granted `grantee` patient-level access.
+fn get_grantee_index(env: &Env, grantee: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&grantee_index_key(grantee))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Records `patient` in the grantee's reverse index (no duplicates).
+fn add_to_grantee_index(env: &Env, grantee: &Address, patient: &Address) {
+    let key = grantee_index_key(grantee);
+    let mut patients = get_grantee_index(env, grantee);
+    if !patients.contains(patient) {
+        patients.push_back(patient.clone());
+        env.storage().persistent().set(&key, &patients);
+        extend_ttl_address_key(env, &key);
+    }
+}
+
+/// Drops `patient` from the grantee's reverse index.
+fn remove_from_grantee_index(env: &Env, grantee: &Address, patient: &Address) {
+    let key = grantee_index_key(grantee);
+    let patients = get_grantee_index(env, grantee);
+    if let Some(pos) = patients.first_index_of(patient) {
+        let mut patients = patients;
+        patients.remove(pos);
+        if patients.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &patients);
+        }
+    }
+}
+
+/// Drops `grantee` from the patient's grantee list.
+fn remove_from_grantee_list(env: &Env, patient: &Address, grantee: &Address) {
+    let key = (symbol_short!("ACC_LST"), patient.clone());
+    let grantees: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+    if let Some(pos) = grantees.first_index_of(grantee) {
+        let mut grantees = grantees;
+        grantees.remove(pos);
+        if grantees.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &grantees);
+        }
+    }
+}
+
+/// Encrypts a plaintext data hash under the currently active encryption key.
+/// Returns the stored ciphertext and the key version used, if any.
+fn encrypt_data_hash(env: &Env, data_hash: &String) -> (String, Option<String>) {
+    let current_version: Option<String> = env.storage().instance().get(&ENC_CUR);
+    let mut master_bytes: StdVec<u8> = StdVec::new();
+    if let Some(ver) = current_version.clone() {
+        if let Some(sv) = env
+            .storage()
+            .persistent()
+            .get::<(Symbol, String), String>(&(ENC_KEY, ver.clone()))
+        {
+            let hex = sv.to_string();
+            if let Some(bytes) = teye_common::hex_to_bytes(&hex) {
+                master_bytes = bytes;
+            }
+        }
+    }
+
+    let km = KeyManager::new(master_bytes);
+    let plaintext: StdString = data_hash.to_string();
+    let ciphertext = km.encrypt(None, &plaintext);
+    (String::from_str(env, &ciphertext), current_version)
+}
+
+/// Decrypts a stored data hash. Prefers the given key version and falls back
+/// to the current instance version; returns the input unchanged if it cannot
+/// be decrypted.
+fn decrypt_data_hash(env: &Env, data_hash: &String, key_version: &Option<String>) -> String {
+    let key_ver = key_version
+        .clone()
+        .or_else(|| env.storage().instance().get(&ENC_CUR));
+    let mut master_bytes: StdVec<u8> = StdVec::new();
+    if let Some(ver) = key_ver {
+        if let Some(sv) = env
+            .storage()
+            .persistent()
+            .get::<(Symbol, String), String>(&(ENC_KEY, ver.clone()))
+        {
+            let hex = sv.to_string();
+            if let Some(bytes) = teye_common::hex_to_bytes(&hex) {
+                master_bytes = bytes;
+            }
+        }
+    }
+
+    if !master_bytes.is_empty() || key_version.is_none() {
+        let km = KeyManager::new(master_bytes);
+        let ciphertext_std: StdString = data_hash.to_string();
+        if let Some(plain) = km.decrypt(None, &ciphertext_std) {
+            return String::from_str(env, &plain);
+        }
+    }
+    data_hash.clone()
+}
+
+/// The data hash of a stored version as readers see it. A redacted
+/// version's tombstone is returned as is rather than decrypted.
+fn version_data_hash(env: &Env, entry: &RecordVersion) -> String {
+    if entry.redacted {
+        return entry.data_hash.clone();
+    }
+    decrypt_data_hash(env, &entry.data_hash, &entry.key_version)
+}
+
 pub use rbac::{
     create_access_policy, evaluate_access_policies, set_record_sensitivity, set_user_credential,
     AccessPolicy, CredentialType, Permission, PolicyContext, Role, SensitivityLevel,
@@ -124,7 +401,7 @@ pub enum AccessLevel {
     Read,
     /// Write access to the record
     Write,
-    /// Full access including read, write, and delete
+    /// Write access, plus sharing `Read` access on the patient's behalf
     Full,
 }
 
@@ -144,6 +421,9 @@ pub enum RecordType {
     Surgery,
     /// Laboratory result record
     LabResult,
+    /// Note written by the patient about their own condition. The patient
+    /// is both patient and provider; notes are append-only.
+    PatientNote,
 }
 
 /// Status for emergency access grants
@@ -176,8 +456,53 @@ pub struct VisionRecord {
     pub record_type: RecordType,
     pub data_hash: String,
     pub key_version: Option<String>,
+    /// Algorithm that produced `data_hash`, or `unknown` if untagged.
+    pub hash_alg: Symbol,
+    /// When the record was made; for an imported record, the original
+    /// exam date rather than the import date.
     pub created_at: u64,
     pub updated_at: u64,
+    /// When a backdated record was actually written on-chain, or `None`
+    /// for records created live.
+    pub imported_at: Option<u64>,
+    /// Providers credited alongside `provider`. Coauthorship grants no
+    /// rights over the record.
+    pub coauthors: Vec<Address>,
+}
+
+/// One entry of a `get_records` batch, in the position of the requested ID.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub enum RecordFetchResult {
+    Found(VisionRecord, RecordSummary),
+    NotFound(u64),
+    AccessDenied(u64),
+}
+
+/// Outcome of `update_records_atomic`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AtomicUpdateResult {
+    /// Every entry was written; the new version of each record, in the
+    /// order of the request.
+    Applied(Vec<u32>),
+    /// Nothing was written. Holds the index of the first entry that failed
+    /// and the `ContractError` code it failed with.
+    Rejected(u32, u32),
+}
+
+/// A record together with what list views show next to it. `is_stale` is
+/// worked out from `updated_at` and the record type's staleness policy
+/// when the record is read.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RecordWithMetadata {
+    pub record: VisionRecord,
+    pub priority: RecordPriority,
+    pub is_stale: bool,
+    /// The patient's own label for the record. Only ever filled in for the
+    /// patient and their guardians.
+    pub patient_label: Option<String>,
 }
 
 /// Access grant structure
@@ -191,9 +516,49 @@ pub struct AccessGrant {
     pub expires_at: u64,
 }
 
+/// An unexpired access grant together with its declared purpose.
+///
+/// `level` is the effective level from `check_access`, which may be `None`
+/// while the stored grant is still unexpired (e.g. consent lapsed).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessGrantDetail {
+    pub patient: Address,
+    pub grantee: Address,
+    pub level: AccessLevel,
+    pub purpose: GrantPurpose,
+    /// The patient, or whoever granted on the patient's behalf.
+    pub created_by: Address,
+    pub granted_at: u64,
+    pub expires_at: u64,
+    /// Whether `grantee` is a marked integration contract.
+    pub is_contract_grantee: bool,
+}
+
+/// Progress of an admin revocation sweep over a grantee's grants.
+/// Call again while `remaining` is non-zero to resume.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessSweepResult {
+    pub revoked: u32,
+    pub remaining: u32,
+}
+
+/// Progress of moving a grantee's grants to a new address.
+/// Call again while `remaining` is non-zero to resume.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GranteeMigrationResult {
+    pub migrated: u32,
+    /// Grants dropped instead of moved: expired, missing, or the patient
+    /// already grants the new address.
+    pub skipped: u32,
+    pub remaining: u32,
+}
+
 /// Consent grant structure for patient-to-provider consent tracking
 #[contracttype]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ConsentGrant {
     pub patient: Address,
     pub grantee: Address,
@@ -237,12 +602,26 @@ impl VisionRecordsContract {
         );
     }
 
+    /// Fails with `NotInitialized` until `initialize` has run. Every
+    /// endpoint that returns a `Result` calls this first, so a fresh
+    /// deployment reports the same error whichever endpoint is hit.
+    fn require_initialized(env: &Env) -> Result<(), ContractError> {
+        if !env.storage().instance().has(&INITIALIZED) {
+            return Err(ContractError::NotInitialized);
+        }
+        Ok(())
+    }
+
     fn unauthorized<T>(
         env: &Env,
         caller: &Address,
         action: &str,
         required_permission: &str,
     ) -> Result<T, ContractError> {
+        // Before initialization no caller can hold a role or tier, so a
+        // failed check means the contract is not set up, not that the
+        // caller lacks rights.
+        Self::require_initialized(env)?;
         Self::emit_access_violation(env, caller, action, required_permission);
         Err(ContractError::Unauthorized)
     }
@@ -253,10 +632,130 @@ impl VisionRecordsContract {
         action: &str,
         required_permission: &str,
     ) -> Result<T, ContractError> {
+        Self::require_initialized(env)?;
         Self::emit_access_violation(env, caller, action, required_permission);
         Err(ContractError::AccessDenied)
     }
 
+    /// Fails with `DurationTooLong` if a grant of `duration_seconds` would
+    /// exceed the configured maximum grant duration.
+    fn require_within_grant_cap(env: &Env, duration_seconds: u64) -> Result<(), ContractError> {
+        if expiry::exceeds_max_grant_duration(env, duration_seconds) {
+            return Err(ContractError::DurationTooLong);
+        }
+        Ok(())
+    }
+
+    /// Stores a record-level grant running until `expires_at` and indexes
+    /// it under the record.
+    fn store_record_access_grant(
+        env: &Env,
+        patient: &Address,
+        grantee: &Address,
+        record_id: u64,
+        level: &AccessLevel,
+        expires_at: u64,
+        granted_by: &Address,
+    ) {
+        let grant = AccessGrant {
+            patient: patient.clone(),
+            grantee: grantee.clone(),
+            level: level.clone(),
+            granted_at: env.ledger().timestamp(),
+            expires_at,
+        };
+        let key = (symbol_short!("REC_ACC"), record_id, grantee.clone());
+        env.storage().persistent().set(&key, &grant);
+        extend_ttl_record_access_key(env, &key);
+        inspect::add_record_grantee(env, record_id, grantee);
+        grant_revision::bump_revision(env, patient, grantee);
+        compliance::log(
+            env,
+            compliance::GRANT,
+            granted_by,
+            patient,
+            Some(grantee),
+            record_id,
+        );
+    }
+
+    /// Rejects grants that could never be used: a `None` level, a patient
+    /// granting to themselves, or a grantee who is frozen or deactivated.
+    fn require_grantable(
+        env: &Env,
+        patient: &Address,
+        grantee: &Address,
+        level: &AccessLevel,
+    ) -> Result<(), ContractError> {
+        if *level == AccessLevel::None
+            || grantee == patient
+            || rbac::is_frozen(env, grantee)
+            || deactivation::is_deactivated(env, grantee)
+        {
+            return Err(ContractError::InvalidInput);
+        }
+        Ok(())
+    }
+
+    /// Stores an active patient-level grant and indexes it for purge and
+    /// sweep iteration. Returns the receipt the grant was stored from.
+    fn store_access_grant(
+        env: &Env,
+        patient: &Address,
+        grantee: &Address,
+        level: &AccessLevel,
+        purpose: &GrantPurpose,
+        duration_seconds: u64,
+        created_by: &Address,
+    ) -> GrantReceipt {
+        let now = env.ledger().timestamp();
+        let receipt = GrantReceipt {
+            granted_at: now,
+            expires_at: now.saturating_add(duration_seconds),
+        };
+        let grant = AccessGrant {
+            patient: patient.clone(),
+            grantee: grantee.clone(),
+            level: level.clone(),
+            granted_at: receipt.granted_at,
+            expires_at: receipt.expires_at,
+        };
+
+        let key = (symbol_short!("ACCESS"), patient.clone(), grantee.clone());
+        env.storage().persistent().set(&key, &grant);
+        extend_ttl_access_key(env, &key);
+        purpose::set_purpose(env, patient, grantee, purpose);
+        grant_creator::set_creator(env, patient, grantee, created_by);
+        write_scope::set_append_only(env, patient, grantee, false);
+        history_scope::set_history_hidden(env, patient, grantee, false);
+        revocation::clear_pending(env, patient, grantee);
+        grant_revision::bump_revision(env, patient, grantee);
+
+        // Track the grantee address in the patient's grantee list for purge iteration.
+        let list_key = (symbol_short!("ACC_LST"), patient.clone());
+        let mut grantees: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&list_key)
+            .unwrap_or(Vec::new(env));
+        if !grantees.contains(grantee) {
+            grantees.push_back(grantee.clone());
+            env.storage().persistent().set(&list_key, &grantees);
+        }
+        add_to_grantee_index(env, grantee, patient);
+        expiry_beacon::index(env, patient, grantee, receipt.expires_at);
+        compliance::log(
+            env,
+            compliance::GRANT,
+            created_by,
+            patient,
+            Some(grantee),
+            0,
+        );
+
+        receipt
+    }
+
     fn enforce_rate_limit(env: &Env, caller: &Address) -> Result<(), ContractError> {
         let cfg: Option<(u64, u64)> = env.storage().instance().get(&RATE_CFG);
         let (max_requests_per_window, window_duration_seconds) = match cfg {
@@ -291,8 +790,18 @@ impl VisionRecordsContract {
         Ok(())
     }
 
-    /// Initialize the contract with an admin address
-    pub fn initialize(env: Env, admin: Address) -> Result<(), ContractError> {
+    /// Initialize the contract with an admin address.
+    ///
+    /// `admin` gets `InfraAdmin`. With no `clinical_admin` it also gets
+    /// `ClinicalAdmin` and the legacy `SystemAdmin` superset stays on, as
+    /// before the split. Given a separate `clinical_admin`, that address
+    /// gets `ClinicalAdmin` alone, `admin` loses its record permissions and
+    /// the legacy superset is turned off.
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        clinical_admin: Option<Address>,
+    ) -> Result<(), ContractError> {
         if env.storage().instance().has(&INITIALIZED) {
             let context = create_error_context(
                 &env,
@@ -315,18 +824,36 @@ impl VisionRecordsContract {
 
         env.storage().instance().set(&ADMIN, &admin);
         env.storage().instance().set(&INITIALIZED, &true);
-        rbac::assign_role(&env, admin.clone(), Role::Admin, 0);
-
-        // Bootstrap the admin with the Admin role so they can register other users
-        rbac::assign_role(&env, admin.clone(), Role::Admin, 0);
 
-        // Assign the Admin RBAC role so the admin has permissions
-        rbac::assign_role(&env, admin.clone(), Role::Admin, 0);
+        // Bootstrap the admin with the Admin role so they can register other
+        // users; the admin permissions below are custom grants on top of it
+        rbac::assign_role(&env, admin.clone(), Role::Admin, 0, &admin);
 
         // Bootstrap the initializing admin as SuperAdmin in the tier system
         admin_tiers::set_super_admin(&env, &admin);
         admin_tiers::track_admin(&env, &admin);
 
+        let _ = rbac::grant_custom_permission(&env, admin.clone(), Permission::InfraAdmin);
+        match clinical_admin {
+            Some(clinical_admin) if clinical_admin != admin => {
+                rbac::assign_role(&env, clinical_admin.clone(), Role::Admin, 0, &admin);
+                let _ =
+                    rbac::grant_custom_permission(&env, clinical_admin, Permission::ClinicalAdmin);
+                for permission in [
+                    Permission::ReadAnyRecord,
+                    Permission::WriteRecord,
+                    Permission::ManageAccess,
+                ] {
+                    let _ = rbac::revoke_custom_permission(&env, admin.clone(), permission);
+                }
+                rbac::set_legacy_system_admin_enabled(&env, false);
+            }
+            _ => {
+                let _ =
+                    rbac::grant_custom_permission(&env, admin.clone(), Permission::ClinicalAdmin);
+            }
+        }
+
         events::publish_initialized(&env, admin);
 
         Ok(())
@@ -362,6 +889,7 @@ impl VisionRecordsContract {
         current_admin: Address,
         new_admin: Address,
     ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
         current_admin.require_auth();
 
         let admin = Self::get_admin(env.clone())?;
@@ -379,6 +907,7 @@ impl VisionRecordsContract {
     /// Accept the pending admin transfer. Only the proposed new admin can call this.
     /// Completes the two-step admin transfer process.
     pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
         new_admin.require_auth();
 
         let pending: Address = env
@@ -403,6 +932,7 @@ impl VisionRecordsContract {
 
     /// Cancel a pending admin transfer. Only the current admin can call this.
     pub fn cancel_admin_transfer(env: Env, current_admin: Address) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
         current_admin.require_auth();
 
         let admin = Self::get_admin(env.clone())?;
@@ -433,6 +963,90 @@ impl VisionRecordsContract {
         env.storage().instance().get(&PENDING_ADMIN)
     }
 
+    // ── Infra / clinical admin split ─────────────────────────────────────────
+
+    /// Hand `permission` (`InfraAdmin` or `ClinicalAdmin`) from `caller` to
+    /// `new_holder`, a registered user. `caller` loses it explicitly, though
+    /// a `SystemAdmin` caller keeps it through the legacy superset until
+    /// that is turned off.
+    pub fn transfer_admin_permission(
+        env: Env,
+        caller: Address,
+        new_holder: Address,
+        permission: Permission,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+
+        if !matches!(
+            permission,
+            Permission::InfraAdmin | Permission::ClinicalAdmin
+        ) || new_holder == caller
+        {
+            return Err(ContractError::InvalidInput);
+        }
+        if !rbac::has_admin_permission(&env, &caller, &permission) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "transfer_admin_permission",
+                "permission:transferred",
+            );
+        }
+
+        rbac::grant_custom_permission(&env, new_holder.clone(), permission.clone())
+            .map_err(|_| ContractError::UserNotFound)?;
+        let _ = rbac::revoke_custom_permission(&env, caller.clone(), permission.clone());
+
+        events::publish_admin_permission_transferred(&env, permission, caller, new_holder);
+
+        Ok(())
+    }
+
+    /// Turn the deprecated `SystemAdmin` superset on or off. While on,
+    /// `SystemAdmin` counts as both `InfraAdmin` and `ClinicalAdmin`, and
+    /// tier admins pass clinical checks. Requires ContractAdmin.
+    pub fn set_legacy_system_admin(
+        env: Env,
+        caller: Address,
+        enabled: bool,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_legacy_system_admin",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        rbac::set_legacy_system_admin_enabled(&env, enabled);
+        events::publish_legacy_system_admin_changed(&env, enabled, caller);
+        Ok(())
+    }
+
+    /// Whether `SystemAdmin` still stands in for the split admin permissions.
+    pub fn is_legacy_system_admin_enabled(env: Env) -> bool {
+        rbac::is_legacy_system_admin_enabled(&env)
+    }
+
+    /// Replace the contract code with the uploaded Wasm `new_wasm_hash`.
+    /// Requires ContractAdmin.
+    pub fn upgrade(
+        env: Env,
+        caller: Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(&env, &caller, "upgrade", "admin_tier:ContractAdmin");
+        }
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ok(())
+    }
+
     // ── Multisig management ──────────────────────────────────────────────────
 
     /// Configure M-of-N multisig for admin operations.
@@ -442,9 +1056,7 @@ impl VisionRecordsContract {
         signers: soroban_sdk::Vec<Address>,
         threshold: u32,
     ) -> Result<(), ContractError> {
-        if !Self::is_initialized(env.clone()) {
-            return Err(ContractError::NotInitialized);
-        }
+        Self::require_initialized(&env)?;
         caller.require_auth();
         
         let admin = Self::get_admin(env.clone())?;
@@ -462,9 +1074,7 @@ impl VisionRecordsContract {
         action: Symbol,
         data_hash: BytesN<32>,
     ) -> Result<u64, ContractError> {
-        if !Self::is_initialized(env.clone()) {
-            return Err(ContractError::NotInitialized);
-        }
+        Self::require_initialized(&env)?;
         proposer.require_auth();
 
         multisig::propose(&env, &proposer, action, data_hash)
@@ -476,9 +1086,7 @@ impl VisionRecordsContract {
         approver: Address,
         proposal_id: u64,
     ) -> Result<(), ContractError> {
-        if !Self::is_initialized(env.clone()) {
-            return Err(ContractError::NotInitialized);
-        }
+        Self::require_initialized(&env)?;
         approver.require_auth();
 
         multisig::approve(&env, &approver, proposal_id)
@@ -497,7 +1105,7 @@ impl VisionRecordsContract {
 
     /// Configure per-address rate limiting for this contract.
     ///
-    /// Requires at least `ContractAdmin` tier, or legacy admin/SystemAdmin.
+    /// Requires at least `ContractAdmin` tier, or legacy admin/InfraAdmin.
     /// Uses multisig if configured.
     pub fn set_rate_limit_config(
         env: Env,
@@ -506,6 +1114,7 @@ impl VisionRecordsContract {
         window_duration_seconds: u64,
         proposal_id: u64,
     ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
         caller.require_auth();
 
         if max_requests_per_window == 0 || window_duration_seconds == 0 {
@@ -538,16 +1147,16 @@ impl VisionRecordsContract {
         key: String,
         proposal_id: u64,
     ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
         caller.require_auth();
 
         let admin = Self::get_admin(env.clone())?;
-        let has_system_admin = rbac::has_permission(&env, &caller, &Permission::SystemAdmin);
-        if caller != admin && !has_system_admin {
+        if caller != admin && !rbac::has_infra_admin(&env, &caller) {
             return Self::unauthorized(
                 &env,
                 &caller,
                 "set_encryption_key",
-                "admin_or_system_admin",
+                "admin_or_permission:InfraAdmin",
             );
         }
 
@@ -568,12 +1177,13 @@ impl VisionRecordsContract {
 
     /// Enables or disables whitelist enforcement globally.
     ///
-    /// Requires at least `ContractAdmin` tier, or legacy admin/SystemAdmin.
+    /// Requires at least `ContractAdmin` tier, or legacy admin/InfraAdmin.
     pub fn set_whitelist_enabled(
         env: Env,
         caller: Address,
         enabled: bool,
     ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
         caller.require_auth();
         if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
             return Self::unauthorized(
@@ -589,8 +1199,9 @@ impl VisionRecordsContract {
 
     /// Adds an address to the whitelist.
     ///
-    /// Requires at least `ContractAdmin` tier, or legacy admin/SystemAdmin.
+    /// Requires at least `ContractAdmin` tier, or legacy admin/InfraAdmin.
     pub fn add_to_whitelist(env: Env, caller: Address, user: Address) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
         caller.require_auth();
         if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
             return Self::unauthorized(
@@ -606,12 +1217,13 @@ impl VisionRecordsContract {
 
     /// Removes an address from the whitelist.
     ///
-    /// Requires at least `ContractAdmin` tier, or legacy admin/SystemAdmin.
+    /// Requires at least `ContractAdmin` tier, or legacy admin/InfraAdmin.
     pub fn remove_from_whitelist(
         env: Env,
         caller: Address,
         user: Address,
     ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
         caller.require_auth();
         if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
             return Self::unauthorized(
@@ -641,6 +1253,7 @@ impl VisionRecordsContract {
         role: Role,
         name: String,
     ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
         circuit_breaker::require_not_paused(
             &env,
             &circuit_breaker::PauseScope::Function(symbol_short!("REG_USR")),
@@ -681,673 +1294,8018 @@ impl VisionRecordsContract {
             is_active: true,
         };
 
+        // Assigned before the profile is overwritten, so a re-registered
+        // user's backfilled role history keeps their first registration time
+        rbac::assign_role(&env, user.clone(), role.clone(), 0, &caller);
         let key = (symbol_short!("USER"), user.clone());
         env.storage().persistent().set(&key, &user_data);
         extend_ttl_address_key(&env, &key);
-        rbac::assign_role(&env, user.clone(), role.clone(), 0);
 
-        rbac::assign_role(&env, user.clone(), role.clone(), 0);
+        rbac::assign_role(&env, user.clone(), role.clone(), 0, &caller);
 
         // Assign the role in the RBAC system
-        rbac::assign_role(&env, user.clone(), role.clone(), 0);
+        rbac::assign_role(&env, user.clone(), role.clone(), 0, &caller);
 
         // Create the RBAC role assignment so has_permission works
-        rbac::assign_role(&env, user.clone(), role.clone(), 0);
+        rbac::assign_role(&env, user.clone(), role.clone(), 0, &caller);
 
-        events::publish_user_registered(&env, user, role, name);
+        events::publish_user_registered(&env, user.clone(), role.clone(), name, false);
+        if role == Role::Patient {
+            Self::auto_grant_registering_provider(&env, &caller, &user);
+        }
 
         Ok(())
     }
 
-    /// Get user information
-    pub fn get_user(env: Env, user: Address) -> Result<User, ContractError> {
-        let key = (symbol_short!("USER"), user.clone());
-        match env.storage().persistent().get(&key) {
-            Some(user_data) => Ok(user_data),
-            None => {
-                let resource_id = String::from_str(&env, "get_user");
-                let context = create_error_context(
-                    &env,
-                    ContractError::UserNotFound,
-                    Some(user.clone()),
-                    Some(resource_id.clone()),
-                );
-                log_error(
-                    &env,
-                    ContractError::UserNotFound,
-                    Some(user),
-                    Some(resource_id),
-                    None,
-                );
-                events::publish_error(&env, ContractError::UserNotFound as u32, context);
-                Err(ContractError::UserNotFound)
-            }
+    /// Grants the configured registration access from a newly registered
+    /// patient to the clinician who registered them. Admins and staff
+    /// registering patients receive nothing.
+    fn auto_grant_registering_provider(env: &Env, caller: &Address, patient: &Address) {
+        let (level, duration_seconds) = match registration::get_auto_grant(env) {
+            Some(auto_grant) => auto_grant,
+            None => return,
+        };
+        let is_clinician = rbac::get_active_assignment(env, caller).is_some_and(|assignment| {
+            assignment.role == Role::Optometrist || assignment.role == Role::Ophthalmologist
+        });
+        // A cap lowered after the auto-grant was configured still applies,
+        // as does a stale-maintenance lockout
+        if !is_clinician
+            || expiry::exceeds_max_grant_duration(env, duration_seconds)
+            || heartbeat::grants_locked(env)
+        {
+            return;
         }
+
+        let purpose = GrantPurpose::Treatment;
+        let expires_at = Self::store_access_grant(
+            env,
+            patient,
+            caller,
+            &level,
+            &purpose,
+            duration_seconds,
+            patient,
+        )
+        .expires_at;
+        activity::log(env, patient, activity::ACCESS_GRANTED, caller, 0);
+        events::publish_auto_grant(
+            env,
+            patient.clone(),
+            caller.clone(),
+            level.clone(),
+            expires_at,
+        );
+        events::publish_access_granted(
+            env,
+            patient.clone(),
+            caller.clone(),
+            level,
+            purpose,
+            duration_seconds,
+            expires_at,
+        );
     }
 
-    /// Add a vision record
-    #[allow(clippy::arithmetic_side_effects)]
-    pub fn add_record(
+    /// Register the caller as a patient without going through an admin.
+    /// Only available while self-registration is enabled, and limited to a
+    /// daily cap across all callers.
+    pub fn self_register_patient(
         env: Env,
         caller: Address,
-        patient: Address,
-        provider: Address,
-        record_type: RecordType,
-        data_hash: String,
-    ) -> Result<u64, ContractError> {
-        let _guard = teye_common::ReentrancyGuard::new(&env);
+        name: String,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
         circuit_breaker::require_not_paused(
             &env,
-            &circuit_breaker::PauseScope::Function(symbol_short!("ADD_REC")),
+            &circuit_breaker::PauseScope::Function(symbol_short!("REG_USR")),
+        )?;
+        caller.require_auth();
+
+        if !registration::is_self_registration_enabled(&env) {
+            return Self::access_denied(
+                &env,
+                &caller,
+                "self_register_patient",
+                "self_registration_enabled",
+            );
+        }
+
+        validation::validate_name(&name)?;
+
+        let key = (symbol_short!("USER"), caller.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(ContractError::AlreadyExists);
+        }
+
+        let now = env.ledger().timestamp();
+        if !registration::try_consume_daily_slot(&env, now) {
+            return Err(ContractError::RateLimitExceeded);
+        }
+
+        let user_data = User {
+            address: caller.clone(),
+            role: Role::Patient,
+            name: name.clone(),
+            registered_at: now,
+            is_active: true,
+        };
+        env.storage().persistent().set(&key, &user_data);
+        extend_ttl_address_key(&env, &key);
+        rbac::assign_role(&env, caller.clone(), Role::Patient, 0, &caller);
+
+        events::publish_user_registered(&env, caller, Role::Patient, name, true);
+
+        Ok(())
+    }
+
+    /// Enable or disable patient self-registration. Requires ContractAdmin.
+    pub fn set_patient_self_registration(
+        env: Env,
+        caller: Address,
+        allowed: bool,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_patient_self_registration",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        registration::set_self_registration_enabled(&env, allowed);
+        Ok(())
+    }
+
+    /// Whether patients may currently register themselves.
+    pub fn self_registration_allowed(env: Env) -> bool {
+        registration::is_self_registration_enabled(&env)
+    }
+
+    /// Hide or show a record type for every user holding `role`. Hidden
+    /// types stay hidden whatever grants the user holds, except from the
+    /// record's patient and authoring provider. Requires ContractAdmin.
+    pub fn set_role_visibility(
+        env: Env,
+        caller: Address,
+        role: Role,
+        record_type: RecordType,
+        visible: bool,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_role_visibility",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        visibility::set_visible(&env, &role, &record_type, visible);
+        Ok(())
+    }
+
+    /// Which record types `role` may see, one entry per record type.
+    pub fn get_role_visibility(env: Env, role: Role) -> Vec<RoleVisibility> {
+        visibility::get_matrix(&env, &role)
+    }
+
+    /// Restrict who may author records of `record_type`: from now on the
+    /// record's provider must also meet `requirement`, unless the caller
+    /// adding the record is a ClinicalAdmin. `None` lifts the restriction.
+    /// Requires ContractAdmin.
+    pub fn set_record_type_policy(
+        env: Env,
+        caller: Address,
+        record_type: RecordType,
+        requirement: Option<RecordTypeRequirement>,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_record_type_policy",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        record_policy::set_requirement(&env, &record_type, &requirement);
+        Ok(())
+    }
+
+    /// Every restricted record type and what its providers must hold.
+    pub fn get_record_type_policies(env: Env) -> Vec<RecordTypePolicy> {
+        record_policy::get_policies(&env)
+    }
+
+    /// Flag records of `record_type` as stale once `max_age_seconds` have
+    /// passed since their last update. 0 removes the policy, so the type
+    /// never goes stale. Requires ContractAdmin.
+    pub fn set_staleness_policy(
+        env: Env,
+        caller: Address,
+        record_type: RecordType,
+        max_age_seconds: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_staleness_policy",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        staleness::set_max_age(&env, &record_type, max_age_seconds);
+        Ok(())
+    }
+
+    /// Every record type with a staleness policy and its window.
+    pub fn get_staleness_policies(env: Env) -> Vec<StalenessPolicy> {
+        staleness::get_policies(&env)
+    }
+
+    /// Mark `address` as a known integration contract. Grants to it must
+    /// then name a purpose and end within the contract grant cap.
+    /// Requires ContractAdmin.
+    pub fn mark_contract_grantee(
+        env: Env,
+        caller: Address,
+        address: Address,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "mark_contract_grantee",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        contract_grantee::mark(&env, &address)
+    }
+
+    /// Remove the integration-contract mark from `address`. Grants it
+    /// already holds keep their terms. Requires ContractAdmin.
+    pub fn unmark_contract_grantee(
+        env: Env,
+        caller: Address,
+        address: Address,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "unmark_contract_grantee",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        if !contract_grantee::unmark(&env, &address) {
+            return Err(ContractError::ContractGranteeNotFound);
+        }
+        Ok(())
+    }
+
+    /// Every address marked as an integration contract.
+    pub fn get_contract_grantees(env: Env) -> Vec<Address> {
+        contract_grantee::get_marked(&env)
+    }
+
+    pub fn is_contract_grantee(env: Env, address: Address) -> bool {
+        contract_grantee::is_marked(&env, &address)
+    }
+
+    /// Set the longest grant a marked integration contract may receive.
+    /// Requires ContractAdmin.
+    pub fn set_contract_grant_max_duration(
+        env: Env,
+        caller: Address,
+        seconds: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_contract_grant_max_duration",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        if seconds == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+        contract_grantee::set_max_duration(&env, seconds);
+        Ok(())
+    }
+
+    pub fn get_contract_grant_max_duration(env: Env) -> u64 {
+        contract_grantee::get_max_duration(&env)
+    }
+
+    /// Turn residency enforcement on or off. While on, a record may only be
+    /// added for a patient with a declared region by a provider whose
+    /// jurisdictions include it. Requires ContractAdmin.
+    pub fn set_residency_enforcement(
+        env: Env,
+        caller: Address,
+        enforced: bool,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_residency_enforcement",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        residency::set_enforced(&env, enforced);
+        Ok(())
+    }
+
+    pub fn is_residency_enforced(env: Env) -> bool {
+        residency::is_enforced(&env)
+    }
+
+    /// Declare the region `patient`'s data must stay in, e.g. `EU`. Set by
+    /// the patient or a ContractAdmin. A patient with no region is
+    /// unrestricted.
+    pub fn set_patient_residency(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        region: Symbol,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if caller != patient && !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_patient_residency",
+                "patient_or_admin_tier:ContractAdmin",
+            );
+        }
+        residency::set_patient_region(&env, &patient, &region);
+        Ok(())
+    }
+
+    pub fn get_patient_residency(env: Env, patient: Address) -> Option<Symbol> {
+        residency::get_patient_region(&env, &patient)
+    }
+
+    /// Set the regions `provider` is registered to practise in, replacing
+    /// any previous set; an empty list clears it. At most
+    /// `residency::MAX_JURISDICTIONS`. Requires ContractAdmin.
+    pub fn set_provider_jurisdictions(
+        env: Env,
+        caller: Address,
+        provider: Address,
+        regions: Vec<Symbol>,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_provider_jurisdictions",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        if regions.len() > residency::MAX_JURISDICTIONS {
+            return Err(ContractError::InvalidInput);
+        }
+        residency::set_jurisdictions(&env, &provider, &regions);
+        Ok(())
+    }
+
+    pub fn get_provider_jurisdictions(env: Env, provider: Address) -> Vec<Symbol> {
+        residency::get_jurisdictions(&env, &provider)
+    }
+
+    /// Fails with `ResidencyMismatch` if `provider` may not write
+    /// `patient`'s data. A ClinicalAdmin `caller` may override the mismatch;
+    /// the patient's region is then returned so the override can be
+    /// published once the record exists.
+    fn check_residency(
+        env: &Env,
+        caller: &Address,
+        provider: &Address,
+        patient: &Address,
+    ) -> Result<Option<Symbol>, ContractError> {
+        match residency::mismatch(env, provider, patient) {
+            None => Ok(None),
+            Some(region) if rbac::has_clinical_admin(env, caller) => Ok(Some(region)),
+            Some(_) => Err(ContractError::ResidencyMismatch),
+        }
+    }
+
+    /// Register `observer` to be called on the record events in `events`
+    /// (`REC_ADD`, `REC_UPD`), replacing its subscriptions if it is already
+    /// registered. At most `MAX_OBSERVERS` may be registered. An observer
+    /// that panics or returns an error does not block the record write,
+    /// but one that exhausts the transaction budget aborts it. Observers
+    /// are only called while `set_observers_enabled` has turned them on.
+    /// Requires ContractAdmin.
+    pub fn register_observer(
+        env: Env,
+        caller: Address,
+        observer: Address,
+        events: Vec<Symbol>,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "register_observer",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        observer::register(
+            &env,
+            &Observer {
+                address: observer.clone(),
+                events: events.clone(),
+            },
         )?;
+        events::publish_observer_registered(&env, observer, events, caller);
+        Ok(())
+    }
+
+    /// Stop notifying `observer`. Requires ContractAdmin.
+    pub fn unregister_observer(
+        env: Env,
+        caller: Address,
+        observer: Address,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "unregister_observer",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        if observer::unregister(&env, &observer) {
+            events::publish_observer_unregistered(&env, observer, caller);
+        }
+        Ok(())
+    }
+
+    /// Every registered observer and the events it receives.
+    pub fn get_observers(env: Env) -> Vec<Observer> {
+        observer::get_observers(&env)
+    }
+
+    /// Turn notification of registered observers on or off. It is off
+    /// until enabled, because an observer that exhausts the budget aborts
+    /// the record write it was called from. Requires ContractAdmin.
+    pub fn set_observers_enabled(
+        env: Env,
+        caller: Address,
+        enabled: bool,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_observers_enabled",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        observer::set_enabled(&env, enabled);
+        Ok(())
+    }
+
+    /// Whether registered observers are currently notified.
+    pub fn are_observers_enabled(env: Env) -> bool {
+        observer::is_enabled(&env)
+    }
+
+    /// Set the maximum number of self-registrations accepted per day.
+    /// Requires ContractAdmin.
+    pub fn set_self_registration_daily_cap(
+        env: Env,
+        caller: Address,
+        cap: u32,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_self_registration_daily_cap",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        registration::set_daily_cap(&env, cap);
+        Ok(())
+    }
+
+    /// Set the access a patient automatically grants the optometrist or
+    /// ophthalmologist who registers them, as `(level, duration_seconds)`.
+    /// `None` turns the auto-grant off. Requires ContractAdmin.
+    pub fn set_auto_grant_on_registration(
+        env: Env,
+        caller: Address,
+        auto_grant: Option<(AccessLevel, u64)>,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_auto_grant_on_registration",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        if let Some((level, duration_seconds)) = &auto_grant {
+            if *level == AccessLevel::None {
+                return Err(ContractError::InvalidInput);
+            }
+            validation::validate_duration(*duration_seconds)?;
+            Self::require_within_grant_cap(&env, *duration_seconds)?;
+        }
+        registration::set_auto_grant(&env, &auto_grant);
+        Ok(())
+    }
+
+    /// The access granted on registration, if the auto-grant is on.
+    pub fn get_auto_grant_on_registration(env: Env) -> Option<(AccessLevel, u64)> {
+        registration::get_auto_grant(&env)
+    }
+
+    /// Get user information
+    pub fn get_user(env: Env, user: Address) -> Result<User, ContractError> {
+        Self::require_initialized(&env)?;
+        let key = (symbol_short!("USER"), user.clone());
+        match env.storage().persistent().get(&key) {
+            Some(user_data) => Ok(user_data),
+            None => {
+                let resource_id = String::from_str(&env, "get_user");
+                let context = create_error_context(
+                    &env,
+                    ContractError::UserNotFound,
+                    Some(user.clone()),
+                    Some(resource_id.clone()),
+                );
+                log_error(
+                    &env,
+                    ContractError::UserNotFound,
+                    Some(user),
+                    Some(resource_id),
+                    None,
+                );
+                events::publish_error(&env, ContractError::UserNotFound as u32, context);
+                Err(ContractError::UserNotFound)
+            }
+        }
+    }
+
+    /// Add a vision record
+    pub fn add_record(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        provider: Address,
+        record_type: RecordType,
+        data_hash: String,
+    ) -> Result<u64, ContractError> {
+        Self::require_initialized(&env)?;
+        Self::insert_record(
+            env,
+            caller,
+            patient,
+            provider,
+            record_type,
+            data_hash,
+            hash_alg::UNKNOWN,
+            None,
+            None,
+            None,
+        )
+        .map(|receipt| receipt.record_id)
+    }
+
+    /// Add a vision record like `add_record`, returning a receipt of what
+    /// was stored instead of the bare record ID.
+    pub fn add_record_v2(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        provider: Address,
+        record_type: RecordType,
+        data_hash: String,
+    ) -> Result<AddRecordReceipt, ContractError> {
+        Self::require_initialized(&env)?;
+        Self::insert_record(
+            env,
+            caller,
+            patient,
+            provider,
+            record_type,
+            data_hash,
+            hash_alg::UNKNOWN,
+            None,
+            None,
+            None,
+        )
+    }
+
+    /// Add a vision record whose data hash was produced by `hash_alg`,
+    /// which must be a registered algorithm.
+    pub fn add_record_tagged(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        provider: Address,
+        record_type: RecordType,
+        data_hash: String,
+        hash_alg: Symbol,
+    ) -> Result<u64, ContractError> {
+        Self::require_initialized(&env)?;
+        hash_alg::require_supported(&env, &hash_alg)?;
+        Self::insert_record(
+            env,
+            caller,
+            patient,
+            provider,
+            record_type,
+            data_hash,
+            hash_alg,
+            None,
+            None,
+            None,
+        )
+        .map(|receipt| receipt.record_id)
+    }
+
+    /// Add a record imported from historical data, dated `original_created_at`
+    /// (the original exam date) instead of now. The import time is kept in
+    /// the record's `imported_at`, and the first version is dated to the
+    /// original date too. The caller needs `ImportRecords` on top of the
+    /// usual rights to add the record.
+    pub fn add_record_backdated(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        provider: Address,
+        record_type: RecordType,
+        data_hash: String,
+        original_created_at: u64,
+    ) -> Result<u64, ContractError> {
+        Self::require_initialized(&env)?;
+        if original_created_at == 0 || original_created_at > env.ledger().timestamp() {
+            return Err(ContractError::InvalidTimestamp);
+        }
+        Self::insert_record(
+            env,
+            caller,
+            patient,
+            provider,
+            record_type,
+            data_hash,
+            hash_alg::UNKNOWN,
+            None,
+            Some(original_created_at),
+            None,
+        )
+        .map(|receipt| receipt.record_id)
+    }
+
+    /// Add a record produced jointly by `provider` and up to
+    /// `coauthor::MAX_COAUTHORS` other providers, e.g. a technician who
+    /// took the measurements. Each coauthor must hold `WriteRecord` or
+    /// `AppendRecord`; the record is also listed under each of them by
+    /// `get_provider_records`. Coauthors gain no write rights over it.
+    pub fn add_record_with_coauthors(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        provider: Address,
+        coauthors: Vec<Address>,
+        record_type: RecordType,
+        data_hash: String,
+    ) -> Result<u64, ContractError> {
+        Self::require_initialized(&env)?;
+        Self::insert_record(
+            env,
+            caller,
+            patient,
+            provider,
+            record_type,
+            data_hash,
+            hash_alg::UNKNOWN,
+            None,
+            None,
+            Some(coauthors),
+        )
+        .map(|receipt| receipt.record_id)
+    }
+
+    /// Add a vision record carrying an external reference such as
+    /// `DiagnosticReport/abc-123`. The reference must not already belong
+    /// to another record.
+    pub fn add_record_with_ref(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        provider: Address,
+        record_type: RecordType,
+        data_hash: String,
+        external_ref: String,
+    ) -> Result<u64, ContractError> {
+        Self::require_initialized(&env)?;
+        external_ref::validate(&external_ref)?;
+        external_ref::require_available(&env, &external_ref, None)?;
+
+        let record_id = Self::insert_record(
+            env.clone(),
+            caller.clone(),
+            patient,
+            provider,
+            record_type,
+            data_hash,
+            hash_alg::UNKNOWN,
+            None,
+            None,
+            None,
+        )?
+        .record_id;
+        external_ref::set_ref(&env, record_id, &external_ref);
+        events::publish_external_ref_set(&env, record_id, external_ref, None, caller);
+
+        Ok(record_id)
+    }
+
+    /// Set a record's external reference. Callers with write access to the
+    /// record may set it once; only an OperatorAdmin may change it after.
+    pub fn set_external_ref(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        external_ref: String,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        external_ref::validate(&external_ref)?;
+
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+
+        let is_admin = Self::has_clinical_admin_access(&env, &caller, &AdminTier::OperatorAdmin);
+        if !is_admin && !auth::write_record(&env, &caller, &record).allowed {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_external_ref",
+                "permission:WriteRecord_or_OperatorAdmin",
+            );
+        }
+
+        let current = external_ref::get_ref(&env, record_id);
+        if current.as_ref() == Some(&external_ref) {
+            return Ok(());
+        }
+        if current.is_some() && !is_admin {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_external_ref",
+                "admin_tier:OperatorAdmin",
+            );
+        }
+        external_ref::require_available(&env, &external_ref, Some(record_id))?;
+
+        let previous = external_ref::set_ref(&env, record_id, &external_ref);
+        events::publish_external_ref_set(&env, record_id, external_ref, previous, caller);
+        Ok(())
+    }
+
+    /// Get a record's external reference, if it has one.
+    pub fn get_external_ref(env: Env, record_id: u64) -> Option<String> {
+        external_ref::get_ref(&env, record_id)
+    }
+
+    /// Look up a record by its external reference. The caller needs read
+    /// access to the record, as for `get_record`.
+    pub fn get_record_by_external_ref(
+        env: Env,
+        caller: Address,
+        external_ref: String,
+    ) -> Result<VisionRecord, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        let record_id =
+            external_ref::find_record(&env, &external_ref).ok_or(ContractError::RecordNotFound)?;
+        Self::load_readable_record(env, caller, record_id)
+    }
+
+    /// Define a record preset, or replace the preset of the same name.
+    /// Records created with it get `record_type` and start at
+    /// `default_priority`. `requires_details` makes the eye examination
+    /// details mandatory at creation and is only allowed for Examination
+    /// presets; `requires_cosign` makes at least one coauthor mandatory.
+    /// Requires ContractAdmin.
+    #[allow(clippy::too_many_arguments)]
+    pub fn define_record_preset(
+        env: Env,
+        caller: Address,
+        preset: Symbol,
+        record_type: RecordType,
+        requires_details: bool,
+        requires_cosign: bool,
+        default_priority: RecordPriority,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "define_record_preset",
+                "admin_tier:ContractAdmin",
+            );
+        }
+
+        // Notes are written by patients, who never go through presets
+        if record_type == RecordType::PatientNote
+            || (requires_details && record_type != RecordType::Examination)
+        {
+            return Err(ContractError::InvalidInput);
+        }
+        let names = record_preset::get_preset_names(&env);
+        if !names.contains(&preset) && names.len() >= record_preset::MAX_PRESETS {
+            return Err(ContractError::InvalidInput);
+        }
+
+        record_preset::set_preset(
+            &env,
+            &RecordPreset {
+                name: preset,
+                record_type,
+                requires_details,
+                requires_cosign,
+                default_priority,
+                updated_at: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Every record preset, in the order they were first defined.
+    pub fn get_record_presets(env: Env) -> Vec<RecordPreset> {
+        record_preset::get_presets(&env)
+    }
+
+    /// Add a record using a preset, which sets its type and starting
+    /// priority. `coauthors` and `examination` are checked against the
+    /// preset's requirements before anything is stored; the examination's
+    /// `record_id` is replaced by the new record's. Fails with
+    /// `PresetNotFound` for an unknown preset and `PresetRequirementUnmet`
+    /// when a required part is missing. The preset name stays on the
+    /// record, see `get_record_preset`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_record_with_preset(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        provider: Address,
+        preset: Symbol,
+        data_hash: String,
+        coauthors: Vec<Address>,
+        examination: Option<EyeExamination>,
+    ) -> Result<u64, ContractError> {
+        Self::require_initialized(&env)?;
+        let preset =
+            record_preset::get_preset(&env, &preset).ok_or(ContractError::PresetNotFound)?;
+        if (preset.requires_cosign && coauthors.is_empty())
+            || (preset.requires_details && examination.is_none())
+        {
+            return Err(ContractError::PresetRequirementUnmet);
+        }
+        if examination.is_some() && preset.record_type != RecordType::Examination {
+            return Err(ContractError::InvalidRecordType);
+        }
+
+        let record_id = Self::insert_record(
+            env.clone(),
+            caller,
+            patient,
+            provider,
+            preset.record_type,
+            data_hash,
+            hash_alg::UNKNOWN,
+            None,
+            None,
+            Some(coauthors),
+        )?
+        .record_id;
+
+        if let Some(mut exam) = examination {
+            exam.record_id = record_id;
+            examination::set_examination(&env, &exam);
+            events::publish_examination_added(&env, record_id);
+        }
+        priority::set_priority(&env, record_id, &preset.default_priority);
+        record_preset::stamp_record(&env, record_id, &preset.name);
+
+        Ok(record_id)
+    }
+
+    /// The preset a record was created with, if any.
+    pub fn get_record_preset(env: Env, record_id: u64) -> Option<Symbol> {
+        record_preset::get_record_preset(&env, record_id)
+    }
+
+    /// Add a note the patient writes about their own condition. Only the
+    /// patient, holding the Patient role, may add one; no write permission
+    /// is needed. Notes can never be updated or superseded.
+    pub fn add_patient_note(
+        env: Env,
+        patient: Address,
+        data_hash: String,
+    ) -> Result<u64, ContractError> {
+        Self::require_initialized(&env)?;
+        Self::insert_record(
+            env,
+            patient.clone(),
+            patient.clone(),
+            patient,
+            RecordType::PatientNote,
+            data_hash,
+            hash_alg::UNKNOWN,
+            None,
+            None,
+            None,
+        )
+        .map(|receipt| receipt.record_id)
+    }
+
+    /// Add a record for a walk-in patient who has no wallet yet. The record
+    /// is held against `provisional_patient_hash` and, until a patient
+    /// claims it, is readable only by its provider and admins.
+    pub fn add_record_provisional(
+        env: Env,
+        provider: Address,
+        provisional_patient_hash: BytesN<32>,
+        record_type: RecordType,
+        data_hash: String,
+    ) -> Result<u64, ContractError> {
+        Self::require_initialized(&env)?;
+        if provisional::get_claimant(&env, &provisional_patient_hash).is_some() {
+            return Err(ContractError::ProvisionalAlreadyClaimed);
+        }
+        Self::insert_record(
+            env.clone(),
+            provider.clone(),
+            provisional::placeholder_patient(&env),
+            provider,
+            record_type,
+            data_hash,
+            hash_alg::UNKNOWN,
+            Some(provisional_patient_hash),
+            None,
+            None,
+        )
+        .map(|receipt| receipt.record_id)
+    }
+
+    /// Move every record held against `provisional_patient_hash` to
+    /// `patient`. An OperatorAdmin co-signs the linkage; each record gets a
+    /// version by that admin noting the change of patient. A provisional
+    /// identity can be claimed once. Returns the claimed record IDs.
+    pub fn claim_provisional_records(
+        env: Env,
+        patient: Address,
+        provisional_patient_hash: BytesN<32>,
+        proof_admin: Address,
+    ) -> Result<Vec<u64>, ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+        proof_admin.require_auth();
+
+        if !Self::has_clinical_admin_access(&env, &proof_admin, &AdminTier::OperatorAdmin) {
+            return Self::unauthorized(
+                &env,
+                &proof_admin,
+                "claim_provisional_records",
+                "admin_tier:OperatorAdmin",
+            );
+        }
+        if provisional::get_claimant(&env, &provisional_patient_hash).is_some() {
+            return Err(ContractError::ProvisionalAlreadyClaimed);
+        }
+
+        let record_ids = provisional::get_records(&env, &provisional_patient_hash);
+        if record_ids.is_empty() {
+            return Err(ContractError::RecordNotFound);
+        }
+        let used = Self::patient_record_ids(&env, &patient).len();
+        if used.saturating_add(record_ids.len()) > quota::limit_for(&env, &patient) {
+            return Err(ContractError::QuotaExceeded);
+        }
+
+        let now = env.ledger().timestamp();
+        for record_id in record_ids.iter() {
+            let key = (symbol_short!("RECORD"), record_id);
+            let mut record: VisionRecord = env
+                .storage()
+                .persistent()
+                .get(&key)
+                .ok_or(ContractError::RecordNotFound)?;
+
+            versioning::seed_initial_version(&env, &record)?;
+            versioning::move_patient_versions(
+                &env,
+                &record.patient,
+                &patient,
+                versioning::get_version_count(&env, record_id),
+            );
+
+            // Content is unchanged; the version notes who linked the patient
+            if !versioning::is_superseded(&env, record_id) {
+                let version = versioning::append_version(
+                    &env,
+                    record_id,
+                    &patient,
+                    record.data_hash.clone(),
+                    record.key_version.clone(),
+                    record.hash_alg.clone(),
+                    proof_admin.clone(),
+                    now,
+                )?;
+                record.updated_at = version.modified_at;
+            }
+            record.patient = patient.clone();
+            env.storage().persistent().set(&key, &record);
+            extend_ttl_u64_key(&env, &key);
+
+            provider_stats::record_created(&env, &record.provider, &patient, record.created_at);
+            coauthor::index_record(&env, &record.provider, &record.coauthors, record_id);
+            patient_index::insert(&env, &patient, record_id);
+            patient_summary::record_added(&env, &patient, &record.record_type, record.created_at);
+            activity::log(
+                &env,
+                &patient,
+                activity::RECORD_ADDED,
+                &proof_admin,
+                record_id,
+            );
+            observer::notify(&env, &activity::RECORD_ADDED, record_id, &patient);
+        }
+
+        provisional::mark_claimed(&env, &provisional_patient_hash, &patient);
+        events::publish_provisional_claimed(
+            &env,
+            patient,
+            provisional_patient_hash,
+            record_ids.len(),
+            proof_admin,
+        );
+
+        Ok(record_ids)
+    }
+
+    /// The provisional identity a record was created under, if any. Kept
+    /// after the record is claimed.
+    pub fn get_provisional_origin(env: Env, record_id: u64) -> Option<BytesN<32>> {
+        provisional::get_origin(&env, record_id)
+    }
+
+    /// Reserve a record ID for a document still uploading, so other
+    /// systems can reference it right away. Runs the same checks as
+    /// `add_record` except for the data hash. The record is written once
+    /// `commit_record` supplies the hash within
+    /// `pending_record::PENDING_RECORD_SECONDS`; until then reads fail with
+    /// `RecordPending` and it is in no patient index.
+    pub fn begin_record(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        provider: Address,
+        record_type: RecordType,
+    ) -> Result<u64, ContractError> {
+        Self::require_initialized(&env)?;
+        let _guard = teye_common::ReentrancyGuard::new(&env);
+        Self::authorize_record_insert(
+            &env,
+            &caller,
+            &patient,
+            &provider,
+            &record_type,
+            None,
+            false,
+        )?;
+
+        let residency_override = if record_type == RecordType::PatientNote {
+            None
+        } else {
+            Self::check_residency(&env, &caller, &provider, &patient)?
+        };
+        let used = Self::patient_record_ids(&env, &patient).len();
+        quota::require_capacity(&env, &patient, used)?;
+
+        let record_id = record_counter::next_id(&env, &provider)?;
+        let now = env.ledger().timestamp();
+        let pending = PendingRecord {
+            record_id,
+            patient,
+            provider,
+            record_type,
+            reserved_by: caller,
+            reserved_at: now,
+            expires_at: now.saturating_add(pending_record::PENDING_RECORD_SECONDS),
+            residency_override,
+        };
+        pending_record::set(&env, &pending);
+        events::publish_record_reserved(&env, &pending);
+        Ok(record_id)
+    }
+
+    /// Write a record reserved by `begin_record` with its data hash, as
+    /// version 1. Only whoever reserved it may commit, and only once. Fails
+    /// with `AlreadyExists` if it was already committed and
+    /// `RecordNotFound` once the reservation was abandoned or lapsed.
+    pub fn commit_record(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        data_hash: String,
+    ) -> Result<AddRecordReceipt, ContractError> {
+        Self::require_initialized(&env)?;
+        let _guard = teye_common::ReentrancyGuard::new(&env);
+        caller.require_auth();
+        let pending = Self::open_reservation(&env, record_id)?;
+        if pending.reserved_by != caller {
+            return Self::unauthorized(&env, &caller, "commit_record", "record_reserver");
+        }
+        validation::validate_data_hash(&data_hash)?;
+        let used = Self::patient_record_ids(&env, &pending.patient).len();
+        quota::require_capacity(&env, &pending.patient, used)?;
+
+        pending_record::remove(&env, record_id);
+        Self::store_new_record(
+            &env,
+            &caller,
+            record_id,
+            pending.patient,
+            pending.provider,
+            pending.record_type,
+            data_hash,
+            hash_alg::UNKNOWN,
+            None,
+            None,
+            Vec::new(&env),
+            pending.residency_override,
+        )
+    }
+
+    /// Give up a reservation made by `begin_record`. The ID is burned and
+    /// never handed out again. Only whoever reserved it may abandon it.
+    pub fn abandon_record(env: Env, caller: Address, record_id: u64) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        let pending = Self::open_reservation(&env, record_id)?;
+        if pending.reserved_by != caller {
+            return Self::unauthorized(&env, &caller, "abandon_record", "record_reserver");
+        }
+        pending_record::remove(&env, record_id);
+        events::publish_record_abandoned(&env, &pending);
+        Ok(())
+    }
+
+    /// Get an open reservation made by `begin_record`.
+    pub fn get_pending_record(env: Env, record_id: u64) -> Option<PendingRecord> {
+        pending_record::get(&env, record_id)
+    }
+
+    /// The open reservation for `record_id`, failing with `AlreadyExists`
+    /// for a committed record and `RecordNotFound` otherwise.
+    fn open_reservation(env: &Env, record_id: u64) -> Result<PendingRecord, ContractError> {
+        if let Some(pending) = pending_record::get(env, record_id) {
+            return Ok(pending);
+        }
+        if env
+            .storage()
+            .persistent()
+            .has(&(symbol_short!("RECORD"), record_id))
+        {
+            return Err(ContractError::AlreadyExists);
+        }
+        Err(ContractError::RecordNotFound)
+    }
+
+    #[allow(clippy::arithmetic_side_effects)]
+    fn insert_record(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        provider: Address,
+        record_type: RecordType,
+        data_hash: String,
+        hash_alg: Symbol,
+        provisional: Option<BytesN<32>>,
+        original_created_at: Option<u64>,
+        coauthors: Option<Vec<Address>>,
+    ) -> Result<AddRecordReceipt, ContractError> {
+        let _guard = teye_common::ReentrancyGuard::new(&env);
+        Self::authorize_record_insert(
+            &env,
+            &caller,
+            &patient,
+            &provider,
+            &record_type,
+            Some(&data_hash),
+            original_created_at.is_some(),
+        )?;
+
+        let coauthors = coauthors.unwrap_or(Vec::new(&env));
+        coauthor::validate(&env, &provider, &coauthors)?;
+
+        // Notes are the patient's own, and provisional records have no
+        // patient to declare a region yet
+        let residency_override = if record_type == RecordType::PatientNote || provisional.is_some()
+        {
+            None
+        } else {
+            Self::check_residency(&env, &caller, &provider, &patient)?
+        };
+
+        if provisional.is_none() {
+            let used = Self::patient_record_ids(&env, &patient).len();
+            quota::require_capacity(&env, &patient, used)?;
+        }
+
+        // Generate record ID from the provider's counter shard
+        let record_id = record_counter::next_id(&env, &provider)?;
+        Self::store_new_record(
+            &env,
+            &caller,
+            record_id,
+            patient,
+            provider,
+            record_type,
+            data_hash,
+            hash_alg,
+            provisional,
+            original_created_at,
+            coauthors,
+            residency_override,
+        )
+    }
+
+    /// Checks that `caller` may add a record of `record_type` for
+    /// `patient`: pause state, the import permission for backdated
+    /// records, the provider whitelist, the rate limit, `data_hash` when
+    /// already known, and the write policy. Denials are audited.
+    fn authorize_record_insert(
+        env: &Env,
+        caller: &Address,
+        patient: &Address,
+        provider: &Address,
+        record_type: &RecordType,
+        data_hash: Option<&String>,
+        backdated: bool,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(
+            env,
+            &circuit_breaker::PauseScope::Function(symbol_short!("ADD_REC")),
+        )?;
+        caller.require_auth();
+
+        if backdated && !rbac::has_permission(env, caller, &Permission::ImportRecords) {
+            return Self::unauthorized(
+                env,
+                caller,
+                "add_record_backdated",
+                "permission:ImportRecords",
+            );
+        }
+
+        // Patients writing notes are not subject to the provider whitelist
+        let is_note = *record_type == RecordType::PatientNote;
+        if !is_note && !whitelist::check_whitelist_access(env, caller) {
+            return Self::unauthorized(env, caller, "add_record", "whitelisted_caller");
+        }
+
+        Self::enforce_rate_limit(env, caller)?;
+
+        if let Some(data_hash) = data_hash {
+            validation::validate_data_hash(data_hash)?;
+        }
+
+        let decision = if is_note {
+            auth::append_note(env, caller, patient, provider)
+        } else {
+            auth::append_record(env, caller, patient, provider, record_type)
+        };
+        if !decision.allowed {
+            // Log failed write attempt
+            let audit_entry = audit::create_audit_entry(
+                env,
+                caller.clone(),
+                patient.clone(),
+                None,
+                AccessAction::Write,
+                AccessResult::Denied,
+                Some(String::from_str(env, "Insufficient permissions")),
+            )?;
+            audit::add_audit_entry(env, &audit_entry);
+            events::publish_audit_log_entry(env, &audit_entry);
+
+            let context = create_error_context(
+                env,
+                ContractError::Unauthorized,
+                Some(caller.clone()),
+                Some(String::from_str(env, "add_record")),
+            );
+            log_error(
+                env,
+                ContractError::Unauthorized,
+                Some(caller.clone()),
+                None,
+                None,
+            );
+            events::publish_error(env, ContractError::Unauthorized as u32, context);
+            let requirement = if decision.denied_for(env, auth::TYPE_RESTRICTED) {
+                "record_type_policy"
+            } else {
+                "permission:AppendRecord_or_ClinicalAdmin"
+            };
+            return Self::unauthorized(env, caller, "add_record", requirement);
+        }
+        Ok(())
+    }
+
+    /// Writes an authorized record under an ID already allocated for it,
+    /// with version 1, and indexes it unless it is provisional.
+    fn store_new_record(
+        env: &Env,
+        caller: &Address,
+        record_id: u64,
+        patient: Address,
+        provider: Address,
+        record_type: RecordType,
+        data_hash: String,
+        hash_alg: Symbol,
+        provisional: Option<BytesN<32>>,
+        original_created_at: Option<u64>,
+        coauthors: Vec<Address>,
+        residency_override: Option<Symbol>,
+    ) -> Result<AddRecordReceipt, ContractError> {
+        // Encrypt the provided data_hash under the current key version (if any)
+        let (stored_hash, current_version) = encrypt_data_hash(env, &data_hash);
+
+        let now = env.ledger().timestamp();
+        let created_at = original_created_at.unwrap_or(now);
+        let record = VisionRecord {
+            id: record_id,
+            patient: patient.clone(),
+            provider: provider.clone(),
+            record_type: record_type.clone(),
+            data_hash: stored_hash,
+            key_version: current_version.clone(),
+            hash_alg,
+            created_at,
+            updated_at: created_at,
+            imported_at: original_created_at.map(|_| now),
+            coauthors,
+        };
+
+        let key = (symbol_short!("RECORD"), record_id);
+        env.storage().persistent().set(&key, &record);
+        extend_ttl_u64_key(env, &key);
+
+        priority::init_priority(
+            env,
+            record_id,
+            &priority::default_priority_for(env, &record_type),
+        );
+        period_stats::record_created(env, &record_type, created_at);
+
+        let version = versioning::append_version(
+            env,
+            record_id,
+            &record.patient,
+            record.data_hash.clone(),
+            record.key_version.clone(),
+            record.hash_alg.clone(),
+            caller.clone(),
+            record.created_at,
+        )?;
+        let receipt = AddRecordReceipt {
+            record_id,
+            version: version.version,
+            created_at: record.created_at,
+        };
+
+        match &provisional {
+            // Stats and the patient index are updated when the record is claimed
+            Some(identity) => provisional::add_record(env, identity, record_id),
+            None => {
+                provider_stats::record_created(env, &provider, &patient, now);
+                coauthor::index_record(env, &provider, &record.coauthors, record_id);
+                patient_index::insert(env, &patient, record_id);
+                patient_summary::record_added(env, &patient, &record.record_type, created_at);
+                activity::log(env, &patient, activity::RECORD_ADDED, caller, record_id);
+                observer::notify(env, &activity::RECORD_ADDED, record_id, &patient);
+            }
+        }
+        if let Some(region) = residency_override {
+            events::publish_residency_overridden(
+                env,
+                caller.clone(),
+                patient.clone(),
+                provider.clone(),
+                record_id,
+                region,
+            );
+        }
+        events::publish_record_added(
+            env,
+            record_id,
+            patient,
+            provider,
+            record_type,
+            record.coauthors,
+        );
+
+        Ok(receipt)
+    }
+
+    /// Add multiple vision records in a single transaction.
+    /// Validates provider permission once, then creates all records atomically.
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn add_records(
+        env: Env,
+        provider: Address,
+        records: Vec<BatchRecordInput>,
+    ) -> Result<Vec<u64>, ContractError> {
+        Self::require_initialized(&env)?;
+        provider.require_auth();
+
+        if records.is_empty() {
+            return Err(ContractError::InvalidInput);
+        }
+
+        if !whitelist::check_whitelist_access(&env, &provider) {
+            return Self::unauthorized(&env, &provider, "add_records", "whitelisted_provider");
+        }
+
+        // Check provider may append records once for the whole batch
+        if auth::record_permission(&env, &provider, &provider, &Permission::WriteRecord)
+            .or_else(|| {
+                auth::record_permission(&env, &provider, &provider, &Permission::AppendRecord)
+            })
+            .is_none()
+        {
+            return Self::unauthorized(
+                &env,
+                &provider,
+                "add_records",
+                "permission:AppendRecord_or_ClinicalAdmin",
+            );
+        }
+
+        let mut record_ids = Vec::new(&env);
+
+        // Load current encryption key/version once for the batch
+        let current_version: Option<String> = env.storage().instance().get(&ENC_CUR);
+        let mut master_bytes_batch: StdVec<u8> = StdVec::new();
+        if let Some(ver) = current_version.clone() {
+            if let Some(sv) = env
+                .storage()
+                .persistent()
+                .get::<(Symbol, String), String>(&(ENC_KEY, ver.clone()))
+            {
+                let hex = sv.to_string();
+                if let Some(bytes) = teye_common::hex_to_bytes(&hex) {
+                    master_bytes_batch = bytes;
+                }
+            }
+        }
+
+        for input in records.iter() {
+            // Notes are only ever written by the patient
+            if input.record_type == RecordType::PatientNote {
+                return Err(ContractError::InvalidRecordType);
+            }
+            if !auth::record_type_allowed(&env, &provider, &provider, &input.record_type) {
+                return Self::unauthorized(&env, &provider, "add_records", "record_type_policy");
+            }
+            let residency_override =
+                Self::check_residency(&env, &provider, &provider, &input.patient)?;
+
+            // Earlier records in this batch are already in the patient's list.
+            let used = Self::patient_record_ids(&env, &input.patient).len();
+            quota::require_capacity(&env, &input.patient, used)?;
+
+            let current_id = record_counter::next_id(&env, &provider)?;
+
+            // Encrypt input.data_hash with batch master
+            let km = KeyManager::new(master_bytes_batch.clone());
+            let plaintext: StdString = input.data_hash.to_string();
+            let ciphertext = km.encrypt(None, &plaintext);
+            let stored_hash = String::from_str(&env, &ciphertext);
+
+            let record = VisionRecord {
+                id: current_id,
+                patient: input.patient.clone(),
+                provider: provider.clone(),
+                record_type: input.record_type.clone(),
+                data_hash: stored_hash,
+                key_version: current_version.clone(),
+                hash_alg: hash_alg::UNKNOWN,
+                created_at: env.ledger().timestamp(),
+                updated_at: env.ledger().timestamp(),
+                imported_at: None,
+                coauthors: Vec::new(&env),
+            };
+
+            let key = (symbol_short!("RECORD"), current_id);
+            env.storage().persistent().set(&key, &record);
+
+            priority::init_priority(
+                &env,
+                current_id,
+                &priority::default_priority_for(&env, &input.record_type),
+            );
+            period_stats::record_created(&env, &input.record_type, record.created_at);
+
+            versioning::append_version(
+                &env,
+                current_id,
+                &input.patient,
+                record.data_hash.clone(),
+                record.key_version.clone(),
+                record.hash_alg.clone(),
+                provider.clone(),
+                record.created_at,
+            )?;
+
+            provider_stats::record_created(&env, &provider, &input.patient, record.created_at);
+            coauthor::index_record(&env, &provider, &record.coauthors, current_id);
+
+            patient_index::insert(&env, &input.patient, current_id);
+            patient_summary::record_added(
+                &env,
+                &input.patient,
+                &input.record_type,
+                record.created_at,
+            );
+
+            activity::log(
+                &env,
+                &input.patient,
+                activity::RECORD_ADDED,
+                &provider,
+                current_id,
+            );
+            if let Some(region) = residency_override {
+                events::publish_residency_overridden(
+                    &env,
+                    provider.clone(),
+                    input.patient.clone(),
+                    provider.clone(),
+                    current_id,
+                    region,
+                );
+            }
+            events::publish_record_added(
+                &env,
+                current_id,
+                input.patient.clone(),
+                provider.clone(),
+                input.record_type.clone(),
+                record.coauthors.clone(),
+            );
+            observer::notify(&env, &activity::RECORD_ADDED, current_id, &input.patient);
+
+            record_ids.push_back(current_id);
+        }
+
+        events::publish_batch_records_added(&env, provider, record_ids.len());
+
+        Ok(record_ids)
+    }
+
+    /// Get a vision record by ID. A ClinicalAdmin with no other way in gets
+    /// `AdminReasonRequired` and must use `read_record_as_admin`.
+    pub fn get_record(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+    ) -> Result<VisionRecord, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        Self::load_readable_record(env, caller, record_id)
+    }
+
+    /// Loads a record for a caller who has already authenticated, checking
+    /// read access and auditing the attempt like `get_record`.
+    fn load_readable_record(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+    ) -> Result<VisionRecord, ContractError> {
+        let key = (symbol_short!("RECORD"), record_id);
+        match env.storage().persistent().get::<_, VisionRecord>(&key) {
+            Some(record) => {
+                let decision = auth::read_record(&env, &caller, &record);
+                if !decision.allowed {
+                    // Log failed access attempt
+                    let audit_entry = audit::create_audit_entry(
+                        &env,
+                        caller.clone(),
+                        record.patient.clone(),
+                        Some(record_id),
+                        AccessAction::Read,
+                        AccessResult::Denied,
+                        Some(String::from_str(&env, "Insufficient permissions")),
+                    )?;
+                    audit::add_audit_entry(&env, &audit_entry);
+                    events::publish_audit_log_entry(&env, &audit_entry);
+
+                    if decision.denied_for(&env, auth::EMBARGOED) {
+                        return Err(ContractError::Embargoed);
+                    }
+                    if decision.denied_for(&env, auth::ADMIN_REASON_REQUIRED) {
+                        return Err(ContractError::AdminReasonRequired);
+                    }
+                    return Self::unauthorized(&env, &caller, "get_record", "record_read_access");
+                }
+
+                // Log successful access
+                let audit_entry = audit::create_audit_entry(
+                    &env,
+                    caller.clone(),
+                    record.patient.clone(),
+                    Some(record_id),
+                    AccessAction::Read,
+                    AccessResult::Success,
+                    None,
+                )?;
+                audit::add_audit_entry(&env, &audit_entry);
+                events::publish_audit_log_entry(&env, &audit_entry);
+
+                // Decrypt data_hash for authorized caller before returning
+                let mut out_record = record.clone();
+                out_record.data_hash =
+                    decrypt_data_hash(&env, &record.data_hash, &record.key_version);
+
+                Ok(out_record)
+            }
+            None if pending_record::is_pending(&env, record_id) => {
+                Err(ContractError::RecordPending)
+            }
+            None => {
+                // Log failed access attempt (record not found)
+                // We don't know the patient, so we'll use caller as placeholder
+                let audit_entry = audit::create_audit_entry(
+                    &env,
+                    caller.clone(),
+                    caller.clone(), // Placeholder since we don't know patient
+                    Some(record_id),
+                    AccessAction::Read,
+                    AccessResult::NotFound,
+                    Some(String::from_str(&env, "Record not found")),
+                )?;
+                audit::add_audit_entry(&env, &audit_entry);
+                events::publish_audit_log_entry(&env, &audit_entry);
+
+                let resource_id = String::from_str(&env, "get_record");
+                let context = create_error_context(
+                    &env,
+                    ContractError::RecordNotFound,
+                    None,
+                    Some(resource_id.clone()),
+                );
+                log_error(
+                    &env,
+                    ContractError::RecordNotFound,
+                    None,
+                    Some(resource_id),
+                    None,
+                );
+                events::publish_error(&env, ContractError::RecordNotFound as u32, context);
+                Err(ContractError::RecordNotFound)
+            }
+        }
+    }
+
+    /// Read a record as a ClinicalAdmin. `reason_hash` points at the admin's
+    /// off-chain justification; it is kept in the patient's audit trail and
+    /// published in an `ADM_READ` event.
+    pub fn read_record_as_admin(
+        env: Env,
+        admin: Address,
+        record_id: u64,
+        reason_hash: String,
+    ) -> Result<VisionRecord, ContractError> {
+        Self::require_initialized(&env)?;
+        admin.require_auth();
+        validation::validate_data_hash(&reason_hash)?;
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if !auth::read_record_as_admin(&env, &admin, &record).allowed {
+            return Self::unauthorized(
+                &env,
+                &admin,
+                "read_record_as_admin",
+                "permission:ClinicalAdmin",
+            );
+        }
+
+        let audit_entry = audit::create_audit_entry(
+            &env,
+            admin.clone(),
+            record.patient.clone(),
+            Some(record_id),
+            AccessAction::Read,
+            AccessResult::Success,
+            Some(reason_hash.clone()),
+        )?;
+        audit::add_audit_entry(&env, &audit_entry);
+        events::publish_audit_log_entry(&env, &audit_entry);
+        compliance::log(
+            &env,
+            compliance::ADMIN_READ,
+            &admin,
+            &record.patient,
+            None,
+            record_id,
+        );
+        events::publish_admin_read(&env, admin, record.patient.clone(), record_id, reason_hash);
+
+        let mut out_record = record.clone();
+        out_record.data_hash = decrypt_data_hash(&env, &record.data_hash, &record.key_version);
+        Ok(out_record)
+    }
+
+    /// Update the data hash of an existing record, appending a new version
+    /// to its history. Returns the new version number.
+    ///
+    /// Timestamps are kept monotonic: if the ledger clock reads at or before
+    /// the record's last modification, the new `updated_at` and version
+    /// `modified_at` are clamped to one second after it instead of rejecting
+    /// the update.
+    pub fn update_record(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        data_hash: String,
+    ) -> Result<u32, ContractError> {
+        Self::require_initialized(&env)?;
+        Self::write_record_version(env, caller, record_id, data_hash, hash_alg::UNKNOWN)
+            .map(|receipt| receipt.new_version)
+    }
+
+    /// Update a record like `update_record`, returning a receipt of what was
+    /// written instead of the bare version number.
+    pub fn update_record_v2(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        data_hash: String,
+    ) -> Result<UpdateReceipt, ContractError> {
+        Self::require_initialized(&env)?;
+        Self::write_record_version(env, caller, record_id, data_hash, hash_alg::UNKNOWN)
+    }
+
+    /// Update a record with a data hash produced by `hash_alg`, which must
+    /// be a registered algorithm. Otherwise behaves like `update_record`.
+    pub fn update_record_tagged(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        data_hash: String,
+        hash_alg: Symbol,
+    ) -> Result<u32, ContractError> {
+        Self::require_initialized(&env)?;
+        hash_alg::require_supported(&env, &hash_alg)?;
+        Self::write_record_version(env, caller, record_id, data_hash, hash_alg)
+            .map(|receipt| receipt.new_version)
+    }
+
+    /// Update up to `MAX_ATOMIC_UPDATES` records together, e.g. an
+    /// examination and the prescription derived from it. Every entry is
+    /// checked as `update_record` would check it before any is written, so
+    /// the records never disagree: either all get a new version, or none
+    /// does and the result names the entry that failed. A record may appear
+    /// only once per call.
+    pub fn update_records_atomic(
+        env: Env,
+        caller: Address,
+        updates: Vec<(u64, String)>,
+    ) -> Result<AtomicUpdateResult, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if updates.is_empty() || updates.len() > MAX_ATOMIC_UPDATES {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let mut records: Vec<VisionRecord> = Vec::new(&env);
+        for index in 0..updates.len() {
+            let (record_id, data_hash) = updates.get_unchecked(index);
+            match Self::check_atomic_update(&env, &caller, record_id, &data_hash, &records) {
+                Ok(record) => records.push_back(record),
+                Err(err) => {
+                    if err == ContractError::Unauthorized {
+                        Self::emit_access_violation(
+                            &env,
+                            &caller,
+                            "update_records_atomic",
+                            "permission:WriteRecord_or_ClinicalAdmin",
+                        );
+                    }
+                    return Ok(AtomicUpdateResult::Rejected(index, err as u32));
+                }
+            }
+        }
+
+        let mut versions = Vec::new(&env);
+        for (record, (_, data_hash)) in records.iter().zip(updates.iter()) {
+            let receipt = Self::store_record_update(
+                &env,
+                caller.clone(),
+                record,
+                data_hash,
+                hash_alg::UNKNOWN,
+            )?;
+            versions.push_back(receipt.new_version);
+        }
+        Ok(AtomicUpdateResult::Applied(versions))
+    }
+
+    /// Runs the `update_record` checks for one entry of an atomic update
+    /// without writing anything. `checked` holds the records of the
+    /// entries before it.
+    fn check_atomic_update(
+        env: &Env,
+        caller: &Address,
+        record_id: u64,
+        data_hash: &String,
+        checked: &Vec<VisionRecord>,
+    ) -> Result<VisionRecord, ContractError> {
+        validation::validate_data_hash(data_hash)?;
+        if checked.iter().any(|record| record.id == record_id) {
+            return Err(ContractError::InvalidInput);
+        }
+        let record = Self::load_writable_record(env, record_id)?;
+        if !auth::write_record(env, caller, &record).allowed {
+            return Err(ContractError::Unauthorized);
+        }
+        Ok(record)
+    }
+
+    fn write_record_version(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        data_hash: String,
+        hash_alg: Symbol,
+    ) -> Result<UpdateReceipt, ContractError> {
+        caller.require_auth();
+
+        validation::validate_data_hash(&data_hash)?;
+
+        let record = Self::load_writable_record(&env, record_id)?;
+
+        if !auth::write_record(&env, &caller, &record).allowed {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "update_record",
+                "permission:WriteRecord_or_ClinicalAdmin",
+            );
+        }
+
+        Self::store_record_update(&env, caller, record, data_hash, hash_alg)
+    }
+
+    /// Writes `data_hash` as the next version of `record` once the caller
+    /// has been authorized.
+    fn store_record_update(
+        env: &Env,
+        caller: Address,
+        mut record: VisionRecord,
+        data_hash: String,
+        hash_alg: Symbol,
+    ) -> Result<UpdateReceipt, ContractError> {
+        let env = env.clone();
+        let record_id = record.id;
+        let key = (symbol_short!("RECORD"), record_id);
+        let (stored_hash, key_version) = encrypt_data_hash(&env, &data_hash);
+        let modified_at =
+            versioning::monotonic_timestamp(record.updated_at, env.ledger().timestamp());
+        versioning::seed_initial_version(&env, &record)?;
+        let version = versioning::append_version(
+            &env,
+            record_id,
+            &record.patient,
+            stored_hash.clone(),
+            key_version.clone(),
+            hash_alg.clone(),
+            caller.clone(),
+            modified_at,
+        )?;
+
+        let receipt = UpdateReceipt {
+            record_id,
+            new_version: version.version,
+            updated_at: version.modified_at,
+            previous_hash: decrypt_data_hash(&env, &record.data_hash, &record.key_version),
+        };
+
+        record.data_hash = stored_hash;
+        record.key_version = key_version;
+        record.hash_alg = hash_alg;
+        record.updated_at = receipt.updated_at;
+        env.storage().persistent().set(&key, &record);
+        extend_ttl_u64_key(&env, &key);
+
+        provider_stats::record_updated(&env, &record.provider, receipt.updated_at);
+        activity::log(
+            &env,
+            &record.patient,
+            activity::RECORD_UPDATED,
+            &caller,
+            record_id,
+        );
+        observer::notify(&env, &activity::RECORD_UPDATED, record_id, &record.patient);
+
+        let audit_entry = audit::create_audit_entry(
+            &env,
+            caller,
+            record.patient,
+            Some(record_id),
+            AccessAction::Write,
+            AccessResult::Success,
+            None,
+        )?;
+        audit::add_audit_entry(&env, &audit_entry);
+        events::publish_audit_log_entry(&env, &audit_entry);
+
+        Ok(receipt)
+    }
+
+    /// Loads a record that may still take new versions.
+    fn load_writable_record(env: &Env, record_id: u64) -> Result<VisionRecord, ContractError> {
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+
+        if versioning::is_superseded(env, record_id) {
+            return Err(ContractError::RecordSuperseded);
+        }
+        if record.record_type == RecordType::PatientNote {
+            return Err(ContractError::RecordImmutable);
+        }
+        Ok(record)
+    }
+
+    /// Open a write session on a record for up to
+    /// `write_session::MAX_WRITE_SESSION_TTL_SECONDS`, e.g. while
+    /// documenting a long procedure. The full write check runs once here;
+    /// `update_record_in_session` then only checks that the session is
+    /// still live. A session opened through a delegation ends when the
+    /// delegation does. Opening again replaces the caller's session.
+    pub fn open_write_session(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        ttl_seconds: u64,
+    ) -> Result<WriteSession, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if ttl_seconds == 0 || ttl_seconds > write_session::MAX_WRITE_SESSION_TTL_SECONDS {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let record = Self::load_writable_record(&env, record_id)?;
+        if priority::get_priority(&env, record_id) == RecordPriority::Archivable {
+            return Err(ContractError::RecordImmutable);
+        }
+        let decision = auth::write_record(&env, &caller, &record);
+        if !decision.allowed {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "open_write_session",
+                "permission:WriteRecord_or_ClinicalAdmin",
+            );
+        }
+
+        let now = env.ledger().timestamp();
+        let mut expires_at = now.saturating_add(ttl_seconds);
+        if decision.reason == Symbol::new(&env, auth::DELEGATED) {
+            if let Some(until) = write_session::delegation_expiry(&env, &record.provider, &caller) {
+                expires_at = expires_at.min(until);
+            }
+        }
+        let session = WriteSession {
+            caller: caller.clone(),
+            record_id,
+            patient: record.patient.clone(),
+            opened_at: now,
+            expires_at,
+            grant_revision: grant_revision::get_revision(&env, &record.patient, &caller),
+            access_epoch: grant_revision::get_epoch(&env, &record.patient),
+        };
+        write_session::set_session(&env, &session);
+        events::publish_write_session_opened(&env, &session);
+        Ok(session)
+    }
+
+    /// Update a record under the caller's open write session, without
+    /// repeating the full write check. Fails with `SessionNotFound` once
+    /// the session has expired, been closed, or been invalidated by a
+    /// change to the patient's grants or access epoch. A record that was
+    /// superseded or archived meanwhile can no longer be written.
+    pub fn update_record_in_session(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        data_hash: String,
+    ) -> Result<u32, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        validation::validate_data_hash(&data_hash)?;
+
+        if write_session::get_live(&env, &caller, record_id).is_none() {
+            return Err(ContractError::SessionNotFound);
+        }
+        let record = Self::load_writable_record(&env, record_id)?;
+        if priority::get_priority(&env, record_id) == RecordPriority::Archivable {
+            return Err(ContractError::RecordImmutable);
+        }
+
+        Self::store_record_update(&env, caller, record, data_hash, hash_alg::UNKNOWN)
+            .map(|receipt| receipt.new_version)
+    }
+
+    /// End the caller's write session on a record before it expires.
+    pub fn close_write_session(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+
+        let key = write_session::session_key(&caller, record_id);
+        if !temp_storage::has_temp(&env, &key) {
+            return Err(ContractError::SessionNotFound);
+        }
+        write_session::remove_session(&env, &caller, record_id);
+        events::publish_write_session_closed(&env, caller, record_id);
+        Ok(())
+    }
+
+    /// Get the caller's write session on a record, if it is still usable.
+    pub fn get_write_session(env: Env, caller: Address, record_id: u64) -> Option<WriteSession> {
+        write_session::get_live(&env, &caller, record_id)
+    }
+
+    /// `get_record`, additionally requiring that the patient has not
+    /// hidden the record's history from `caller`.
+    fn get_record_for_history(
+        env: &Env,
+        caller: &Address,
+        record_id: u64,
+        action: &str,
+    ) -> Result<VisionRecord, ContractError> {
+        let record = Self::load_readable_record(env.clone(), caller.clone(), record_id)?;
+        if !auth::read_history(env, caller, &record).allowed {
+            return Self::access_denied(env, caller, action, "record_history_access");
+        }
+        Ok(record)
+    }
+
+    /// Get the number of versions stored for a record.
+    pub fn get_record_version_count(env: Env, record_id: u64) -> u32 {
+        versioning::get_version_count(&env, record_id)
+    }
+
+    /// Get a record's version count and who last changed it, without
+    /// fetching its history. The caller needs read access to the record.
+    pub fn get_record_summary(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+    ) -> Result<RecordSummary, ContractError> {
+        Self::require_initialized(&env)?;
+        Self::get_record(env.clone(), caller, record_id)?;
+        versioning::get_summary(&env, record_id).ok_or(ContractError::RecordNotFound)
+    }
+
+    /// Get a record with its priority and whether it is stale under its
+    /// type's staleness policy. The caller needs read access to the record.
+    /// The patient and their guardians also get the patient's label.
+    pub fn get_record_with_metadata(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+    ) -> Result<RecordWithMetadata, ContractError> {
+        Self::require_initialized(&env)?;
+        let record = Self::get_record(env.clone(), caller.clone(), record_id)?;
+        Ok(Self::with_metadata(&env, &caller, record))
+    }
+
+    /// Get a page of the patient's records with their metadata, as
+    /// `get_record_with_metadata` would return each. The caller needs read
+    /// access to every record on the page.
+    pub fn get_patient_records_meta(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<RecordWithMetadata>, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+
+        if limit == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let record_ids = Self::patient_record_ids(&env, &patient);
+        let mut page = Vec::new(&env);
+        let end = offset.saturating_add(limit).min(record_ids.len());
+        for i in offset..end {
+            let record = Self::load_readable_record(
+                env.clone(),
+                caller.clone(),
+                record_ids.get_unchecked(i),
+            )?;
+            page.push_back(Self::with_metadata(&env, &caller, record));
+        }
+        Ok(page)
+    }
+
+    /// Label one of the patient's own records with a name of their choosing,
+    /// up to `patient_label::MAX_LABEL_LEN` bytes, replacing any earlier
+    /// label. Archived records may be labeled too. The label is shown only
+    /// to the patient and their guardians, and no event carries it.
+    pub fn set_patient_label(
+        env: Env,
+        patient: Address,
+        record_id: u64,
+        label: String,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+        Self::require_own_record(&env, &patient, record_id, "set_patient_label")?;
+        patient_label::set_label(&env, &patient, record_id, &label)
+    }
+
+    /// Remove the patient's label from one of their records.
+    pub fn remove_patient_label(
+        env: Env,
+        patient: Address,
+        record_id: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+        Self::require_own_record(&env, &patient, record_id, "remove_patient_label")?;
+        if !patient_label::remove_label(&env, &patient, record_id) {
+            return Err(ContractError::PatientLabelNotFound);
+        }
+        Ok(())
+    }
+
+    /// Get a specific version of a record. The caller needs read access to
+    /// the record and its history.
+    pub fn get_record_version(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        version: u32,
+    ) -> Result<RecordVersion, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        Self::get_record_for_history(&env, &caller, record_id, "get_record_version")?;
+
+        let mut entry = versioning::get_version(&env, record_id, version)
+            .ok_or(ContractError::RecordNotFound)?;
+        entry.data_hash = version_data_hash(&env, &entry);
+        Ok(entry)
+    }
+
+    /// Redact one historical version of a record, e.g. when another
+    /// patient's data was pasted into it. The version's data hash is
+    /// replaced by `redaction::TOMBSTONE` for every reader, and the
+    /// original is sealed for `get_redacted_original`. The latest version
+    /// cannot be redacted. Requires ClinicalAdmin.
+    pub fn redact_version(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        version: u32,
+        reason_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !rbac::has_clinical_admin(&env, &caller) {
+            return Self::unauthorized(&env, &caller, "redact_version", "permission:ClinicalAdmin");
+        }
+
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        legal_hold::require_not_held(&env, record_id)?;
+        let original = redaction::redact(&env, record_id, version, reason_hash, caller.clone())?;
+        compliance::log(
+            &env,
+            compliance::ERASURE,
+            &caller,
+            &record.patient,
+            None,
+            record_id,
+        );
+        events::publish_version_redacted(&env, record_id, version, original.reason_hash, caller);
+        Ok(())
+    }
+
+    /// Get what a redacted version held before redaction, with its data
+    /// hash decrypted. Requires ClinicalAdmin.
+    pub fn get_redacted_original(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        version: u32,
+    ) -> Result<RedactedOriginal, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !rbac::has_clinical_admin(&env, &caller) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "get_redacted_original",
+                "permission:ClinicalAdmin",
+            );
+        }
+
+        let mut original = redaction::get_original(&env, record_id, version)
+            .ok_or(ContractError::RecordNotFound)?;
+        original.data_hash = decrypt_data_hash(&env, &original.data_hash, &original.key_version);
+        Ok(original)
+    }
+
+    /// Place a legal hold on a record for litigation. While held, the
+    /// record cannot be redacted, archived or superseded; reads and
+    /// updates carry on as normal. `case_ref_hash` is the hash of the
+    /// off-chain case reference. Requires ClinicalAdmin.
+    pub fn place_legal_hold(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        case_ref_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !rbac::has_clinical_admin(&env, &caller) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "place_legal_hold",
+                "permission:ClinicalAdmin",
+            );
+        }
+        if !env
+            .storage()
+            .persistent()
+            .has(&(symbol_short!("RECORD"), record_id))
+        {
+            return Err(ContractError::RecordNotFound);
+        }
+
+        let hold = LegalHold {
+            record_id,
+            case_ref_hash,
+            placed_by: caller,
+            placed_at: env.ledger().timestamp(),
+        };
+        legal_hold::place(&env, &hold)?;
+        events::publish_legal_hold_placed(&env, &hold);
+        Ok(())
+    }
+
+    /// Release a record's legal hold. Requires ClinicalAdmin.
+    pub fn release_legal_hold(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !rbac::has_clinical_admin(&env, &caller) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "release_legal_hold",
+                "permission:ClinicalAdmin",
+            );
+        }
+
+        let hold = legal_hold::release(&env, record_id)?;
+        events::publish_legal_hold_released(&env, &hold, caller);
+        Ok(())
+    }
+
+    /// Every active legal hold, oldest first. Requires ClinicalAdmin.
+    pub fn get_legal_holds(env: Env, caller: Address) -> Result<Vec<LegalHold>, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !rbac::has_clinical_admin(&env, &caller) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "get_legal_holds",
+                "permission:ClinicalAdmin",
+            );
+        }
+        Ok(legal_hold::get_holds(&env))
+    }
+
+    /// Pin a version of a record under `label`, so outside parties such as
+    /// insurers can cite it however the record changes later. Anyone with
+    /// read access to the record may pin; labels are unique per record and
+    /// at most `version_pin::MAX_PINS_PER_RECORD` pins are kept. Pins can
+    /// only be removed by a ClinicalAdmin.
+    pub fn pin_version(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        version: u32,
+        label: Symbol,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        Self::load_readable_record(env.clone(), caller.clone(), record_id)?;
+        if versioning::get_version(&env, record_id, version).is_none() {
+            return Err(ContractError::RecordNotFound);
+        }
+
+        version_pin::pin(&env, record_id, version, &label)?;
+        events::publish_version_pinned(&env, record_id, version, label, caller);
+        Ok(())
+    }
+
+    /// Resolve a pin label to the record version it names.
+    pub fn get_pinned_version(env: Env, record_id: u64, label: Symbol) -> Option<u32> {
+        version_pin::get_pinned_version(&env, record_id, &label)
+    }
+
+    /// Get every pin on a record, label → version.
+    pub fn get_version_pins(env: Env, record_id: u64) -> Map<Symbol, u32> {
+        version_pin::get_pins(&env, record_id)
+    }
+
+    /// Remove a pin. ClinicalAdmin only.
+    pub fn unpin_version(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        label: Symbol,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !rbac::has_clinical_admin(&env, &caller) {
+            return Self::unauthorized(&env, &caller, "unpin_version", "permission:ClinicalAdmin");
+        }
+
+        let version = version_pin::unpin(&env, record_id, &label)?;
+        events::publish_version_unpinned(&env, record_id, version, label, caller);
+        Ok(())
+    }
+
+    /// Withhold a record from its patient until `release_at`, e.g. while
+    /// results await provider review. Only the authoring provider may set
+    /// an embargo, within `embargo::EMBARGO_SET_WINDOW` of creating the
+    /// record. Until release, the patient, their sessions and anyone
+    /// reading through the patient's grants get `Embargoed`; the provider
+    /// and role-based readers are unaffected.
+    pub fn set_record_embargo(
+        env: Env,
+        provider: Address,
+        record_id: u64,
+        release_at: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        provider.require_auth();
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if provider != record.provider {
+            return Self::unauthorized(&env, &provider, "set_record_embargo", "record_provider");
+        }
+
+        let now = env.ledger().timestamp();
+        // An imported record's window runs from its import, not its exam date
+        let window_closes = record
+            .imported_at
+            .unwrap_or(record.created_at)
+            .saturating_add(embargo::EMBARGO_SET_WINDOW);
+        if now > window_closes
+            || release_at <= now
+            || release_at > now.saturating_add(embargo::MAX_EMBARGO_DURATION)
+        {
+            return Err(ContractError::InvalidInput);
+        }
+
+        embargo::set(
+            &env,
+            &RecordEmbargo {
+                record_id,
+                provider: provider.clone(),
+                release_at,
+                set_at: now,
+            },
+        );
+        events::publish_embargo_set(&env, record_id, record.patient, provider, release_at);
+        Ok(())
+    }
+
+    /// Lift a record's embargo before its release date. Authoring provider
+    /// only; fails with `RecordNotFound` if no embargo is in force.
+    pub fn clear_embargo(env: Env, provider: Address, record_id: u64) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        provider.require_auth();
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if provider != record.provider {
+            return Self::unauthorized(&env, &provider, "clear_embargo", "record_provider");
+        }
+        if !embargo::is_embargoed(&env, record_id) {
+            return Err(ContractError::RecordNotFound);
+        }
+
+        embargo::clear(&env, record_id);
+        events::publish_embargo_cleared(&env, record_id, record.patient, provider);
+        Ok(())
+    }
+
+    /// Get the embargo in force on a record, if any. Open to everyone who
+    /// could read the record once it is released, so a patient can see
+    /// that a record exists and when it becomes readable without seeing
+    /// its contents.
+    pub fn get_record_embargo(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+    ) -> Result<Option<RecordEmbargo>, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        let decision = auth::read_record(&env, &caller, &record);
+        if !decision.allowed && !decision.denied_for(&env, auth::EMBARGOED) {
+            return Self::unauthorized(&env, &caller, "get_record_embargo", "record_read_access");
+        }
+        Ok(embargo::get_active(&env, record_id))
+    }
+
+    /// Dispute one of the patient's own records. `reason_hash` points at
+    /// the patient's off-chain statement. A record has at most one
+    /// unresolved dispute at a time, and cannot be rolled back while it
+    /// has one.
+    pub fn flag_record(
+        env: Env,
+        patient: Address,
+        record_id: u64,
+        reason_hash: String,
+    ) -> Result<Dispute, ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if patient != record.patient {
+            return Self::unauthorized(&env, &patient, "flag_record", "record_patient");
+        }
+        validation::validate_data_hash(&reason_hash)?;
+        if dispute::is_open(&env, record_id) {
+            return Err(ContractError::AlreadyExists);
+        }
+
+        let opened = Dispute {
+            record_id,
+            patient: patient.clone(),
+            reason_hash: reason_hash.clone(),
+            status: DisputeStatus::Open,
+            opened_at: env.ledger().timestamp(),
+            response_hash: None,
+            responded_at: None,
+            resolution: DisputeResolution::Pending,
+            resolved_by: None,
+            resolved_at: None,
+        };
+        dispute::set(&env, &opened);
+        events::publish_dispute_opened(&env, patient, record_id, reason_hash);
+        Ok(opened)
+    }
+
+    /// Answer an open dispute on a record. Authoring provider only, once
+    /// per dispute.
+    pub fn respond_to_dispute(
+        env: Env,
+        provider: Address,
+        record_id: u64,
+        response_hash: String,
+    ) -> Result<Dispute, ContractError> {
+        Self::require_initialized(&env)?;
+        provider.require_auth();
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if provider != record.provider {
+            return Self::unauthorized(&env, &provider, "respond_to_dispute", "record_provider");
+        }
+        validation::validate_data_hash(&response_hash)?;
+        let mut responded = dispute::get(&env, record_id).ok_or(ContractError::DisputeNotFound)?;
+        if responded.status != DisputeStatus::Open {
+            return Err(ContractError::InvalidDisputeStatus);
+        }
+
+        responded.status = DisputeStatus::Responded;
+        responded.response_hash = Some(response_hash.clone());
+        responded.responded_at = Some(env.ledger().timestamp());
+        dispute::set(&env, &responded);
+        events::publish_dispute_responded(&env, provider, record_id, response_hash);
+        Ok(responded)
+    }
+
+    /// Settle an unresolved dispute, whether or not the provider has
+    /// responded. The record's authoring provider or an OperatorAdmin may
+    /// resolve it. `Pending` is not a resolution and fails with
+    /// `InvalidInput`.
+    pub fn resolve_dispute(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        resolution: DisputeResolution,
+    ) -> Result<Dispute, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if resolution == DisputeResolution::Pending {
+            return Err(ContractError::InvalidInput);
+        }
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if caller != record.provider
+            && !Self::has_clinical_admin_access(&env, &caller, &AdminTier::OperatorAdmin)
+        {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "resolve_dispute",
+                "record_provider_or_admin_tier:OperatorAdmin",
+            );
+        }
+        let mut resolved = dispute::get(&env, record_id).ok_or(ContractError::DisputeNotFound)?;
+        if resolved.status == DisputeStatus::Resolved {
+            return Err(ContractError::InvalidDisputeStatus);
+        }
+
+        resolved.status = DisputeStatus::Resolved;
+        resolved.resolution = resolution.clone();
+        resolved.resolved_by = Some(caller.clone());
+        resolved.resolved_at = Some(env.ledger().timestamp());
+        dispute::set(&env, &resolved);
+        events::publish_dispute_resolved(&env, caller, record_id, resolution);
+        Ok(resolved)
+    }
+
+    /// The record's latest dispute, resolved or not. Open to everyone who
+    /// can read the record, and to its patient while it is embargoed.
+    pub fn get_dispute(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+    ) -> Result<Option<Dispute>, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        let decision = auth::read_record(&env, &caller, &record);
+        if !decision.allowed && !decision.denied_for(&env, auth::EMBARGOED) {
+            return Self::unauthorized(&env, &caller, "get_dispute", "record_read_access");
+        }
+        Ok(dispute::get(&env, record_id))
+    }
+
+    /// Get the version of a record that was current at `timestamp`.
+    /// The caller needs read access to the record and its history.
+    pub fn get_record_at(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        timestamp: u64,
+    ) -> Result<RecordVersion, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        Self::get_record_for_history(&env, &caller, record_id, "get_record_at")?;
+
+        let mut entry = versioning::get_version_at(&env, record_id, timestamp)
+            .ok_or(ContractError::RecordNotFound)?;
+        entry.data_hash = version_data_hash(&env, &entry);
+        Ok(entry)
+    }
+
+    /// Compare two versions of a record. The versions may be given in either
+    /// order; at most `versioning::MAX_COMPARE_SPAN` versions are walked.
+    /// The caller needs read access to the record and its history.
+    pub fn compare_record_versions(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        from_version: u32,
+        to_version: u32,
+    ) -> Result<RecordComparison, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        Self::get_record_for_history(&env, &caller, record_id, "compare_record_versions")?;
+
+        versioning::compare_versions(&env, record_id, from_version, to_version, |entry| {
+            version_data_hash(&env, entry)
+        })
+    }
+
+    /// Attach the hash of a rendered diff between two versions of a record.
+    /// The pair is unordered, so `(2, 5)` and `(5, 2)` name the same diff.
+    /// Callers with write access to the record may attach one once; only an
+    /// OperatorAdmin may replace it. Both versions must exist.
+    pub fn attach_version_diff(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        from_version: u32,
+        to_version: u32,
+        diff_hash: String,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        validation::validate_data_hash(&diff_hash)?;
+        if from_version == to_version {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+
+        let is_admin = Self::has_clinical_admin_access(&env, &caller, &AdminTier::OperatorAdmin);
+        if !is_admin && !auth::write_record(&env, &caller, &record).allowed {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "attach_version_diff",
+                "permission:WriteRecord_or_OperatorAdmin",
+            );
+        }
+        let (low, high) = version_diff::pair(from_version, to_version);
+        for version in [low, high] {
+            if versioning::get_version(&env, record_id, version).is_none() {
+                return Err(ContractError::RecordNotFound);
+            }
+        }
+
+        let current = version_diff::get(&env, record_id, low, high);
+        if current.as_ref() == Some(&diff_hash) {
+            return Ok(());
+        }
+        if current.is_some() && !is_admin {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "attach_version_diff",
+                "admin_tier:OperatorAdmin",
+            );
+        }
+
+        let previous = version_diff::set(&env, record_id, low, high, &diff_hash);
+        events::publish_version_diff_attached(
+            &env, record_id, low, high, diff_hash, previous, caller,
+        );
+        Ok(())
+    }
+
+    /// Get the diff attached to a pair of a record's versions, in either
+    /// order. The caller needs read access to the record and its history.
+    pub fn get_version_diff(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        from_version: u32,
+        to_version: u32,
+    ) -> Result<Option<String>, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        Self::get_record_for_history(&env, &caller, record_id, "get_version_diff")?;
+        Ok(version_diff::get(&env, record_id, from_version, to_version))
+    }
+
+    /// Get a page of a record's versions, oldest first, starting where
+    /// `cursor` left off or at version 1 without one. At most
+    /// `cursor::MAX_CURSOR_PAGE` versions are returned per call. A cursor
+    /// issued before the history was restructured fails with
+    /// `StaleCursor`, and iteration should restart. The caller needs read
+    /// access to the record and its history.
+    pub fn get_record_history_page(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        cursor: Option<Cursor>,
+        limit: u32,
+    ) -> Result<VersionPage, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+
+        if limit == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+        Self::get_record_for_history(&env, &caller, record_id, "get_record_history_page")?;
+
+        let generation = cursor::history_generation(&env, record_id);
+        let start = cursor::resume(&cursor, generation)?;
+        let count = versioning::get_version_count(&env, record_id);
+        let end = start
+            .saturating_add(limit.min(cursor::MAX_CURSOR_PAGE))
+            .min(count);
+
+        let mut versions = Vec::new(&env);
+        for index in start..end {
+            let version = index.saturating_add(1);
+            if let Some(mut entry) = versioning::get_version(&env, record_id, version) {
+                entry.data_hash = version_data_hash(&env, &entry);
+                versions.push_back(entry);
+            }
+        }
+        Ok(VersionPage {
+            versions,
+            has_more: end < count,
+            next: cursor::next(end, generation),
+        })
+    }
+
+    /// Get up to `bounded::MAX_BOUNDED_ITEMS` of a record's versions,
+    /// oldest first, skipping the first `offset`. `truncated` is set when
+    /// later versions were left out. The caller needs read access to the
+    /// record and its history.
+    pub fn get_record_history_bounded(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        offset: u32,
+    ) -> Result<BoundedVersions, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        Self::get_record_for_history(&env, &caller, record_id, "get_record_history_bounded")?;
+
+        let count = versioning::get_version_count(&env, record_id);
+        let (end, truncated) = bounded::window(offset, count, bounded::MAX_BOUNDED_ITEMS);
+        let mut items = Vec::new(&env);
+        for index in offset..end {
+            let version = index.saturating_add(1);
+            if let Some(mut entry) = versioning::get_version(&env, record_id, version) {
+                entry.data_hash = version_data_hash(&env, &entry);
+                items.push_back(entry);
+            }
+        }
+        Ok(BoundedVersions {
+            items,
+            truncated,
+            next_offset: end,
+        })
+    }
+
+    /// Get the unexpired grants on a patient's records, walking at most
+    /// `bounded::MAX_BOUNDED_GRANTS` grantee list entries from `offset`.
+    /// `truncated` is set when list entries remain. Readable by the
+    /// patient, by a guardian the patient delegated `ManageAccess` to, and
+    /// by admins.
+    pub fn get_patient_grants_bounded(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        offset: u32,
+    ) -> Result<BoundedGrants, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+
+        if caller != patient
+            && !rbac::has_delegated_permission(&env, &patient, &caller, &Permission::ManageAccess)
+            && !Self::has_clinical_admin_access(&env, &caller, &AdminTier::OperatorAdmin)
+        {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "get_patient_grants_bounded",
+                "patient_or_guardian_or_admin",
+            );
+        }
+
+        let grantees = grant_index::get_list(&env, &patient);
+        let (end, truncated) = bounded::window(offset, grantees.len(), bounded::MAX_BOUNDED_GRANTS);
+        let mut items = Vec::new(&env);
+        for index in offset..end {
+            let grantee = grantees.get_unchecked(index);
+            let key = (symbol_short!("ACCESS"), patient.clone(), grantee.clone());
+            if let Some(grant) = env.storage().persistent().get::<_, AccessGrant>(&key) {
+                if !expiry::is_expired(&env, grant.expires_at) {
+                    items.push_back(AccessGrantDetail {
+                        patient: patient.clone(),
+                        grantee: grantee.clone(),
+                        level: Self::check_access(env.clone(), patient.clone(), grantee.clone()),
+                        purpose: purpose::get_purpose(&env, &patient, &grantee),
+                        created_by: grant_creator::get_creator(&env, &patient, &grantee),
+                        granted_at: grant.granted_at,
+                        expires_at: grant.expires_at,
+                        is_contract_grantee: contract_grantee::is_marked(&env, &grantee),
+                    });
+                }
+            }
+        }
+        Ok(BoundedGrants {
+            items,
+            truncated,
+            next_offset: end,
+        })
+    }
+
+    /// Get up to `bounded::MAX_BOUNDED_AUDIT` of a patient's audit entries,
+    /// oldest first, skipping the first `offset`. Entries whose storage has
+    /// lapsed are left out of the page. `truncated` is set when later entries were left
+    /// out. Readable by the patient and by admins.
+    pub fn get_audit_trail_bounded(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        offset: u32,
+    ) -> Result<BoundedAuditEntries, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+
+        if caller != patient
+            && !Self::has_clinical_admin_access(&env, &caller, &AdminTier::OperatorAdmin)
+        {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "get_audit_trail_bounded",
+                "patient_or_admin",
+            );
+        }
+
+        let count = audit::patient_audit_count(&env, &patient);
+        let (end, truncated) = bounded::window(offset, count, bounded::MAX_BOUNDED_AUDIT);
+        Ok(BoundedAuditEntries {
+            items: audit::get_patient_audit_slice(&env, &patient, offset, end),
+            truncated,
+            next_offset: end,
+        })
+    }
+
+    /// Check whether `data_hash`, produced by `hash_alg`, matches the
+    /// record's current content. Both the hash and the algorithm must match,
+    /// so untagged records only match when `hash_alg` is `unknown`. The
+    /// caller needs read access to the record.
+    pub fn verify_record_hash(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        data_hash: String,
+        hash_alg: Symbol,
+    ) -> Result<bool, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        let record = Self::load_readable_record(env, caller, record_id)?;
+        Ok(record.hash_alg == hash_alg && record.data_hash == data_hash)
+    }
+
+    /// Add a hash algorithm to the allowlist used by the tagged record
+    /// entry points. Requires ContractAdmin.
+    pub fn register_hash_algorithm(
+        env: Env,
+        caller: Address,
+        hash_alg: Symbol,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "register_hash_algorithm",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        if hash_alg::register(&env, &hash_alg)? {
+            events::publish_hash_algorithm_registered(&env, hash_alg, caller);
+        }
+        Ok(())
+    }
+
+    /// Hash algorithms records may be tagged with.
+    pub fn get_hash_algorithms(env: Env) -> Vec<Symbol> {
+        hash_alg::get_algorithms(&env)
+    }
+
+    /// Attest that a record supports the insurance claim identified by
+    /// `claim_ref_hash`. Only the record's provider may attest, and a claim
+    /// can have one active attestation per record.
+    pub fn create_claim_attestation(
+        env: Env,
+        provider: Address,
+        record_id: u64,
+        claim_ref_hash: String,
+        amount_hint: i128,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        provider.require_auth();
+
+        validation::validate_data_hash(&claim_ref_hash)?;
+        if amount_hint < 0 {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if record.provider != provider {
+            return Self::unauthorized(
+                &env,
+                &provider,
+                "create_claim_attestation",
+                "record_provider",
+            );
+        }
+
+        let attestation = ClaimAttestation {
+            record_id,
+            provider,
+            claim_ref_hash,
+            amount_hint,
+            attested_at: env.ledger().timestamp(),
+            voided_by: None,
+            void_reason: None,
+            voided_at: None,
+        };
+        claim::add_attestation(&env, attestation.clone())?;
+        events::publish_claim_attested(&env, &attestation);
+        Ok(())
+    }
+
+    /// Get every attestation made for a record, including voided ones.
+    /// Readable by the record's patient and provider and by grantees with
+    /// at least Read access to the patient or the record.
+    pub fn get_claim_attestations(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+    ) -> Result<Vec<ClaimAttestation>, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+
+        let allowed = caller == record.patient
+            || caller == record.provider
+            || session::can_read(&env, &record.patient, &caller)
+            || access::level_satisfies(
+                &AccessLevel::Read,
+                &Self::check_access(env.clone(), record.patient.clone(), caller.clone()),
+            )
+            || access::level_satisfies(
+                &AccessLevel::Read,
+                &Self::check_record_access(env.clone(), record_id, caller.clone()),
+            );
+        if !allowed {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "get_claim_attestations",
+                "patient_or_provider_or_reader",
+            );
+        }
+
+        Ok(claim::get_attestations(&env, record_id))
+    }
+
+    /// Whether the record has an active (not voided) attestation for the
+    /// claim. Public so an insurer can check a claim without reading the
+    /// record.
+    pub fn verify_claim_attestation(env: Env, record_id: u64, claim_ref_hash: String) -> bool {
+        claim::is_attested(&env, record_id, &claim_ref_hash)
+    }
+
+    /// Void the active attestation for a claim, keeping it on record with
+    /// the reason. Callable by the attesting provider or an OperatorAdmin.
+    pub fn void_claim_attestation(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        claim_ref_hash: String,
+        reason: String,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+
+        if reason.is_empty() {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let attestation = claim::get_attestations(&env, record_id)
+            .iter()
+            .find(|a| !a.is_voided() && a.claim_ref_hash == claim_ref_hash)
+            .ok_or(ContractError::ClaimAttestationNotFound)?;
+        if caller != attestation.provider
+            && !Self::has_clinical_admin_access(&env, &caller, &AdminTier::OperatorAdmin)
+        {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "void_claim_attestation",
+                "attesting_provider_or_admin",
+            );
+        }
+
+        claim::void_attestation(&env, record_id, &claim_ref_hash, &caller, &reason)?;
+        events::publish_claim_attestation_voided(&env, record_id, claim_ref_hash, caller, reason);
+        Ok(())
+    }
+
+    /// Get workload and activity counters for a provider. Readable by the
+    /// provider, ManageUsers holders, and admins.
+    pub fn get_provider_stats(
+        env: Env,
+        caller: Address,
+        provider: Address,
+    ) -> Result<ProviderStats, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+
+        if caller != provider
+            && !rbac::has_permission(&env, &caller, &Permission::ManageUsers)
+            && !Self::has_admin_access(&env, &caller, &AdminTier::OperatorAdmin)
+        {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "get_provider_stats",
+                "provider_or_permission:ManageUsers_or_admin",
+            );
+        }
+
+        Ok(provider_stats::get_stats(
+            &env,
+            &provider,
+            env.ledger().timestamp(),
+        ))
+    }
+
+    /// Get the number of records a provider created in a `YYYYMM` month
+    /// bucket. Same access rules as `get_provider_stats`.
+    pub fn get_provider_monthly_created(
+        env: Env,
+        caller: Address,
+        provider: Address,
+        period: u32,
+    ) -> Result<u32, ContractError> {
+        Self::require_initialized(&env)?;
+        Self::get_provider_stats(env.clone(), caller, provider.clone())?;
+        Ok(provider_stats::get_monthly_created(&env, &provider, period))
+    }
+
+    /// Add eye examination details for an existing record
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_eye_examination(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        visual_acuity: VisualAcuity,
+        iop: IntraocularPressure,
+        slit_lamp: SlitLampFindings,
+        visual_field: OptVisualField,
+        retina_imaging: OptRetinalImaging,
+        fundus_photo: OptFundusPhotography,
+        clinical_notes: String,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+
+        let record = Self::load_readable_record(env.clone(), caller.clone(), record_id)?;
+
+        if !auth::write_record(&env, &caller, &record).allowed {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "add_eye_examination",
+                "permission:WriteRecord_or_ClinicalAdmin",
+            );
+        }
+
+        if record.record_type != RecordType::Examination {
+            return Err(ContractError::InvalidRecordType);
+        }
+
+        let exam = EyeExamination {
+            record_id,
+            visual_acuity,
+            iop,
+            slit_lamp,
+            visual_field,
+            retina_imaging,
+            fundus_photo,
+            clinical_notes,
+        };
+
+        examination::set_examination(&env, &exam);
+        events::publish_examination_added(&env, record_id);
+
+        Ok(())
+    }
+
+    /// Retrieve eye examination details for a record
+    pub fn get_eye_examination(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+    ) -> Result<EyeExamination, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        let record = Self::load_readable_record(env.clone(), caller.clone(), record_id)?;
+
+        let has_perm = if caller == record.patient
+            || caller == record.provider
+            || session::can_read(&env, &record.patient, &caller)
+        {
+            true
+        } else {
+            let access = Self::check_access(env.clone(), record.patient.clone(), caller.clone());
+            let record_access = Self::check_record_access(env.clone(), record_id, caller.clone());
+            access::level_satisfies(&AccessLevel::Read, &access)
+                || access::level_satisfies(&AccessLevel::Read, &record_access)
+                || rbac::has_clinical_admin(&env, &caller)
+        };
+
+        if !has_perm {
+            return Self::access_denied(&env, &caller, "get_eye_examination", "record_read_access");
+        }
+
+        examination::get_examination(&env, record_id).ok_or(ContractError::RecordNotFound)
+    }
+
+    /// Get all records for a patient.
+    ///
+    /// Takes no caller, so it is subject to the legacy read mode; see
+    /// `set_legacy_read_mode`. New integrations should use
+    /// `get_patient_records_as`.
+    pub fn get_patient_records(env: Env, patient: Address) -> Result<Vec<u64>, ContractError> {
+        Self::require_initialized(&env)?;
+        Self::require_legacy_read(&env)?;
+        Ok(Self::patient_record_ids(&env, &patient))
+    }
+
+    /// Get all records for a patient on behalf of `caller`, who must be the
+    /// patient, hold read access to the patient's records, or be a
+    /// role-based reader or system admin.
+    pub fn get_patient_records_as(
+        env: Env,
+        caller: Address,
+        patient: Address,
+    ) -> Result<Vec<u64>, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+
+        let blocked =
+            rbac::is_frozen(&env, &caller) || access_freeze::blocks(&env, &patient, &caller);
+        let allowed = caller == patient
+            || session::can_read(&env, &patient, &caller)
+            || rbac::has_permission(&env, &caller, &Permission::ReadAnyRecord)
+            || rbac::has_clinical_admin(&env, &caller)
+            || access::level_satisfies(
+                &AccessLevel::Read,
+                &Self::check_access(env.clone(), patient.clone(), caller.clone()),
+            );
+        if blocked || !allowed {
+            return Self::access_denied(
+                &env,
+                &caller,
+                "get_patient_records_as",
+                "patient_read_access",
+            );
+        }
+
+        Ok(Self::patient_record_ids(&env, &patient))
+    }
+
+    /// The patient's record IDs in creation order, without any checks.
+    fn patient_record_ids(env: &Env, patient: &Address) -> Vec<u64> {
+        patient_index::get_records(env, patient)
+    }
+
+    /// Wraps a record `caller` has already been allowed to read with its
+    /// metadata. The patient's label is left out unless `caller` is the
+    /// patient or a guardian holding `ManageAccess`.
+    fn with_metadata(env: &Env, caller: &Address, record: VisionRecord) -> RecordWithMetadata {
+        let patient_label = if *caller == record.patient
+            || rbac::has_delegated_permission(
+                env,
+                &record.patient,
+                caller,
+                &Permission::ManageAccess,
+            ) {
+            patient_label::get_label(env, &record.patient, record.id)
+        } else {
+            None
+        };
+        RecordWithMetadata {
+            priority: priority::get_priority(env, record.id),
+            is_stale: staleness::is_stale(env, &record.record_type, record.updated_at),
+            patient_label,
+            record,
+        }
+    }
+
+    /// Fails with `RecordNotFound` if the record does not exist, or
+    /// `Unauthorized` if it belongs to another patient.
+    fn require_own_record(
+        env: &Env,
+        patient: &Address,
+        record_id: u64,
+        action: &str,
+    ) -> Result<(), ContractError> {
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if record.patient != *patient {
+            return Self::unauthorized(env, patient, action, "record_patient");
+        }
+        Ok(())
+    }
+
+    /// Applies the legacy read mode to an endpoint that takes no caller.
+    /// Under `AdminOnly` the stored admin must have authorized the call.
+    fn require_legacy_read(env: &Env) -> Result<(), ContractError> {
+        match legacy_read::get_mode(env) {
+            LegacyReadMode::Open => Ok(()),
+            LegacyReadMode::AdminOnly => {
+                let admin: Address = env
+                    .storage()
+                    .instance()
+                    .get(&ADMIN)
+                    .ok_or(ContractError::NotInitialized)?;
+                admin.require_auth();
+                Ok(())
+            }
+            LegacyReadMode::Disabled => Err(ContractError::LegacyEndpointDisabled),
+        }
+    }
+
+    /// Set who may call the record-listing endpoints that take no caller:
+    /// `get_patient_records`, `get_patient_records_filtered` and
+    /// `get_patient_records_page`. Requires ContractAdmin.
+    pub fn set_legacy_read_mode(
+        env: Env,
+        caller: Address,
+        mode: LegacyReadMode,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_legacy_read_mode",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        legacy_read::set_mode(&env, &mode);
+        events::publish_legacy_read_mode_changed(&env, mode, caller);
+        Ok(())
+    }
+
+    /// The current legacy read mode, `Open` unless an admin changed it.
+    pub fn get_legacy_read_mode(env: Env) -> LegacyReadMode {
+        legacy_read::get_mode(&env)
+    }
+
+    /// Get a page of the patient's record IDs in `get_patient_records`
+    /// order, starting where `cursor` left off or at the start without one.
+    /// At most `cursor::MAX_CURSOR_PAGE` IDs are returned per call. Records
+    /// added meanwhile show up on later pages; a cursor issued before
+    /// records were inserted ahead of it or moved away fails with
+    /// `StaleCursor`, and iteration should restart.
+    pub fn get_patient_records_page(
+        env: Env,
+        patient: Address,
+        cursor: Option<Cursor>,
+        limit: u32,
+    ) -> Result<RecordIdPage, ContractError> {
+        Self::require_initialized(&env)?;
+        Self::require_legacy_read(&env)?;
+        if limit == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let generation = cursor::records_generation(&env, &patient);
+        let start = cursor::resume(&cursor, generation)?;
+        let ids = Self::patient_record_ids(&env, &patient);
+        let end = start
+            .saturating_add(limit.min(cursor::MAX_CURSOR_PAGE))
+            .min(ids.len());
+
+        let mut record_ids = Vec::new(&env);
+        for index in start..end {
+            record_ids.push_back(ids.get_unchecked(index));
+        }
+        Ok(RecordIdPage {
+            record_ids,
+            has_more: end < ids.len(),
+            next: cursor::next(end, generation),
+        })
+    }
+
+    /// The current head of the patient's record commitment chain. A wallet
+    /// can hand it to a verifier along with the log from
+    /// `get_commitment_log` to prove a record ID is in the patient's index.
+    pub fn get_patient_commitment(env: Env, patient: Address) -> PatientCommitment {
+        commitment::get(&env, &patient)
+    }
+
+    /// The appends folded into the patient's commitment, oldest first,
+    /// paged by `offset` and `limit` (at most
+    /// `commitment::MAX_COMMITMENT_PAGE` per call).
+    pub fn get_commitment_log(
+        env: Env,
+        patient: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<CommitmentEntry> {
+        commitment::get_log(&env, &patient, offset, limit)
+    }
+
+    /// Get the records a provider is credited on, as the record's provider
+    /// or flagged as a coauthor, in creation order.
+    pub fn get_provider_records(env: Env, provider: Address) -> Vec<ProviderRecord> {
+        coauthor::get_provider_records(&env, &provider)
+    }
+
+    /// Get a patient's records, optionally leaving out records that have
+    /// been superseded by a corrected record. Subject to the legacy read
+    /// mode, like `get_patient_records`.
+    pub fn get_patient_records_filtered(
+        env: Env,
+        patient: Address,
+        exclude_superseded: bool,
+    ) -> Result<Vec<u64>, ContractError> {
+        Self::require_initialized(&env)?;
+        Self::require_legacy_read(&env)?;
+        let records = Self::patient_record_ids(&env, &patient);
+        if !exclude_superseded {
+            return Ok(records);
+        }
+
+        let mut current = Vec::new(&env);
+        for record_id in records.iter() {
+            if !versioning::is_superseded(&env, record_id) {
+                current.push_back(record_id);
+            }
+        }
+        Ok(current)
+    }
+
+    /// Get up to `limit` of the patient's current records of `record_type`
+    /// that are stale under the type's staleness policy, in
+    /// `get_patient_records` order, for renewal campaigns. Superseded
+    /// records are left out.
+    /// At most `staleness::MAX_STALE_RESULTS` IDs are returned. Readable by
+    /// the patient, holders of the patient's grant, and admins.
+    pub fn get_stale_records(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        record_type: RecordType,
+        limit: u32,
+    ) -> Result<Vec<u64>, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+
+        if caller != patient
+            && Self::check_access(env.clone(), patient.clone(), caller.clone()) == AccessLevel::None
+            && !Self::has_clinical_admin_access(&env, &caller, &AdminTier::OperatorAdmin)
+        {
+            return Self::access_denied(&env, &caller, "get_stale_records", "patient_access");
+        }
+        if limit == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let limit = limit.min(staleness::MAX_STALE_RESULTS);
+        let mut stale = Vec::new(&env);
+        if staleness::get_max_age(&env, &record_type).is_none() {
+            return Ok(stale);
+        }
+        for record_id in Self::patient_record_ids(&env, &patient).iter() {
+            if stale.len() >= limit {
+                break;
+            }
+            let key = (symbol_short!("RECORD"), record_id);
+            if let Some(record) = env.storage().persistent().get::<_, VisionRecord>(&key) {
+                if record.record_type == record_type
+                    && staleness::is_stale(&env, &record_type, record.updated_at)
+                    && !versioning::is_superseded(&env, record_id)
+                {
+                    stale.push_back(record_id);
+                }
+            }
+        }
+        Ok(stale)
+    }
+
+    /// Pin one of the patient's records so it is listed first by
+    /// `RecordOrder::PinnedFirst`. At most
+    /// `record_order::MAX_PINS_PER_PATIENT` records may be pinned.
+    pub fn pin_record(env: Env, patient: Address, record_id: u64) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if record.patient != patient {
+            return Self::unauthorized(&env, &patient, "pin_record", "record_owner");
+        }
+
+        record_order::pin(&env, &patient, record_id)
+    }
+
+    /// Remove a record from the patient's pins.
+    pub fn unpin_record(env: Env, patient: Address, record_id: u64) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+        record_order::unpin(&env, &patient, record_id)
+    }
+
+    /// Get the patient's pinned record IDs, in the order they were pinned.
+    pub fn get_pinned_records(env: Env, patient: Address) -> Vec<u64> {
+        record_order::get_pins(&env, &patient)
+    }
+
+    /// Get a page of the patient's records in the requested order.
+    ///
+    /// The caller needs read access to every record on the page. Ordering
+    /// by `UpdatedDesc` only covers the patient's newest
+    /// `record_order::MAX_SORTABLE_RECORDS` records.
+    pub fn get_patient_records_ordered(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        order: RecordOrder,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<VisionRecord>, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+
+        if limit == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let record_ids = Self::patient_record_ids(&env, &patient);
+        let ordered = record_order::order_ids(&env, &patient, &record_ids, &order);
+
+        let mut page = Vec::new(&env);
+        let end = offset.saturating_add(limit).min(ordered.len());
+        for i in offset..end {
+            let record_id = ordered.get_unchecked(i);
+            page.push_back(Self::load_readable_record(
+                env.clone(),
+                caller.clone(),
+                record_id,
+            )?);
+        }
+        Ok(page)
+    }
+
+    /// Get a page of the patient's activity feed, oldest retained entry
+    /// first, at most `activity::MAX_FEED_PAGE` entries long. Only the most
+    /// recent `activity::MAX_ACTIVITY_ENTRIES` entries are kept.
+    ///
+    /// Readable by the patient, by a guardian the patient delegated
+    /// `ManageAccess` to, and by admins.
+    pub fn get_activity_feed(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<ActivityEntry>, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+
+        if caller != patient
+            && !rbac::has_delegated_permission(&env, &patient, &caller, &Permission::ManageAccess)
+            && !Self::has_clinical_admin_access(&env, &caller, &AdminTier::OperatorAdmin)
+        {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "get_activity_feed",
+                "patient_or_guardian_or_admin",
+            );
+        }
+
+        if limit == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+
+        Ok(activity::get_feed(&env, &patient, offset, limit))
+    }
+
+    /// Get page `page_no` of the compliance log: grants, revocations, admin
+    /// reads and erasures in sequence order, `compliance::COMPLIANCE_PAGE_SIZE`
+    /// to a page. Entries are never changed or removed. Admin only.
+    pub fn get_compliance_page(
+        env: Env,
+        caller: Address,
+        page_no: u64,
+    ) -> Result<CompliancePage, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+
+        if !Self::has_clinical_admin_access(&env, &caller, &AdminTier::OperatorAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "get_compliance_page",
+                "admin_tier:OperatorAdmin",
+            );
+        }
+
+        Ok(CompliancePage {
+            page_no,
+            entries: compliance::get_page(&env, page_no),
+            total: compliance::total_logged(&env),
+        })
+    }
+
+    /// Replace a record with a corrected one. A new record is created for
+    /// the same patient and provider, and the old record is marked
+    /// superseded with a terminal version linking to the new one. The old
+    /// record stays readable but can no longer be updated.
+    pub fn supersede_record(
+        env: Env,
+        caller: Address,
+        old_record_id: u64,
+        new_data_hash: String,
+        record_type: RecordType,
+    ) -> Result<u64, ContractError> {
+        Self::require_initialized(&env)?;
+        // The caller is authenticated by `add_record` below; a second
+        // `require_auth` in the same call would be rejected.
+
+        let old_key = (symbol_short!("RECORD"), old_record_id);
+        let mut old_record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&old_key)
+            .ok_or(ContractError::RecordNotFound)?;
+
+        if versioning::is_superseded(&env, old_record_id) {
+            return Err(ContractError::RecordSuperseded);
+        }
+        if old_record.record_type == RecordType::PatientNote {
+            return Err(ContractError::RecordImmutable);
+        }
+
+        // Superseding modifies the old record, so appending alone is not enough.
+        if !auth::write_record(&env, &caller, &old_record).allowed {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "supersede_record",
+                "permission:WriteRecord_or_ClinicalAdmin",
+            );
+        }
+        legal_hold::require_not_held(&env, old_record_id)?;
+
+        let new_record_id = Self::add_record(
+            env.clone(),
+            caller.clone(),
+            old_record.patient.clone(),
+            old_record.provider.clone(),
+            record_type,
+            new_data_hash,
+        )?;
+
+        let modified_at =
+            versioning::monotonic_timestamp(old_record.updated_at, env.ledger().timestamp());
+        versioning::seed_initial_version(&env, &old_record)?;
+        let version = versioning::mark_superseded(
+            &env,
+            old_record_id,
+            &old_record.patient,
+            new_record_id,
+            caller.clone(),
+            modified_at,
+        )?;
+        old_record.updated_at = version.modified_at;
+        env.storage().persistent().set(&old_key, &old_record);
+        extend_ttl_u64_key(&env, &old_key);
+        patient_label::copy_to_record(&env, &old_record.patient, old_record_id, new_record_id);
+
+        activity::log(
+            &env,
+            &old_record.patient,
+            activity::RECORD_SUPERSEDED,
+            &caller,
+            old_record_id,
+        );
+        events::publish_record_superseded(
+            &env,
+            old_record_id,
+            new_record_id,
+            old_record.patient,
+            caller,
+        );
+
+        Ok(new_record_id)
+    }
+
+    /// Get the record that replaced `record_id`, if it was superseded.
+    pub fn get_superseded_by(env: Env, record_id: u64) -> Option<u64> {
+        versioning::get_superseded_by(&env, record_id)
+    }
+
+    /// Grant access to a user
+    pub fn grant_access(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        grantee: Address,
+        level: AccessLevel,
+        duration_seconds: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        Self::grant_access_for_purpose(env, caller, patient, grantee, level, None, duration_seconds)
+            .map(|_| ())
+    }
+
+    /// Grant access like `grant_access`, returning a receipt of the stored
+    /// grant, or `None` when the grant was left pending patient approval.
+    pub fn grant_access_v2(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        grantee: Address,
+        level: AccessLevel,
+        duration_seconds: u64,
+    ) -> Result<Option<GrantReceipt>, ContractError> {
+        Self::require_initialized(&env)?;
+        Self::grant_access_for_purpose(env, caller, patient, grantee, level, None, duration_seconds)
+    }
+
+    /// Grant access to a user for a declared purpose. Otherwise behaves
+    /// like `grant_access`, except that `Research` grants must be made by
+    /// the patient directly rather than by a delegate or admin.
+    pub fn grant_access_with_purpose(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        grantee: Address,
+        level: AccessLevel,
+        purpose: GrantPurpose,
+        duration_seconds: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        if purpose == GrantPurpose::Research && caller != patient {
+            caller.require_auth();
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "grant_access_with_purpose",
+                "patient_for_research_purpose",
+            );
+        }
+        Self::grant_access_for_purpose(
+            env,
+            caller,
+            patient,
+            grantee,
+            level,
+            Some(purpose),
+            duration_seconds,
+        )
+        .map(|_| ())
+    }
+
+    /// Grant `grantee` write access that only lets it add new records for
+    /// the patient, never modify existing ones. Made by the patient
+    /// directly; a later `grant_access` for the same grantee replaces it.
+    pub fn grant_append_access(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+        duration_seconds: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        Self::grant_access_for_purpose(
+            env.clone(),
+            patient.clone(),
+            patient.clone(),
+            grantee.clone(),
+            AccessLevel::Write,
+            None,
+            duration_seconds,
+        )?;
+        write_scope::set_append_only(&env, &patient, &grantee, true);
+        Ok(())
+    }
+
+    /// Grant access like `grant_access`. With `override_duration_cap` set,
+    /// a ClinicalAdmin may exceed the maximum grant duration; the grant then
+    /// also emits a `GRT_OVCAP` event. Anyone else, or an admin without the
+    /// flag, gets `DurationTooLong` for a duration over the cap.
+    pub fn grant_access_with_override(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        grantee: Address,
+        level: AccessLevel,
+        duration_seconds: u64,
+        override_duration_cap: bool,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        Self::apply_access_grant(
+            env,
+            caller,
+            patient,
+            grantee,
+            level,
+            None,
+            duration_seconds,
+            override_duration_cap,
+        )
+        .map(|_| ())
+    }
+
+    /// Whether the grant `patient` made to `grantee` is append-only.
+    pub fn is_append_only_grant(env: Env, patient: Address, grantee: Address) -> bool {
+        write_scope::is_append_only(&env, &patient, &grantee)
+    }
+
+    /// Grant access like `grant_access`, choosing whether the grantee may
+    /// also read earlier versions of the patient's records. Without history
+    /// the grantee still reads each record as it stands now. Made by the
+    /// patient directly; a later `grant_access` for the same grantee
+    /// includes history again.
+    pub fn grant_access_with_history(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+        level: AccessLevel,
+        duration_seconds: u64,
+        include_history: bool,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        Self::grant_access_for_purpose(
+            env.clone(),
+            patient.clone(),
+            patient.clone(),
+            grantee.clone(),
+            level,
+            None,
+            duration_seconds,
+        )?;
+        history_scope::set_history_hidden(&env, &patient, &grantee, !include_history);
+        Ok(())
+    }
+
+    /// Whether the grant `patient` made to `grantee` lets it read record
+    /// history.
+    pub fn grant_includes_history(env: Env, patient: Address, grantee: Address) -> bool {
+        !history_scope::is_history_hidden(&env, &patient, &grantee)
+    }
+
+    /// Register the ed25519 key the patient signs relayed grant approvals
+    /// with, replacing any earlier key.
+    pub fn register_signing_key(
+        env: Env,
+        patient: Address,
+        pubkey: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+        signed_grant::set_signing_key(&env, &patient, &pubkey);
+        events::publish_signing_key_registered(&env, patient, pubkey);
+        Ok(())
+    }
+
+    /// The patient's registered signing key, if any.
+    pub fn get_signing_key(env: Env, patient: Address) -> Option<BytesN<32>> {
+        signed_grant::get_signing_key(&env, &patient)
+    }
+
+    /// The nonce the patient's next signed approval must carry.
+    pub fn get_signing_nonce(env: Env, patient: Address) -> u64 {
+        signed_grant::get_nonce(&env, &patient)
+    }
+
+    /// Grant access on the patient's behalf, submitted by `relayer` under
+    /// the patient's signature over a [`signed_grant::GrantApproval`].
+    ///
+    /// `nonce` must be the patient's current nonce; each accepted approval
+    /// spends it. The grant is then made exactly as if the patient had
+    /// called `grant_access`. An invalid signature aborts the call.
+    pub fn approve_grant_with_signature(
+        env: Env,
+        relayer: Address,
+        patient: Address,
+        grantee: Address,
+        level: AccessLevel,
+        duration_seconds: u64,
+        nonce: u64,
+        signature: BytesN<64>,
+        pubkey: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        relayer.require_auth();
+
+        let registered = signed_grant::get_signing_key(&env, &patient)
+            .ok_or(ContractError::SigningKeyNotFound)?;
+        if registered != pubkey {
+            return Self::unauthorized(
+                &env,
+                &relayer,
+                "approve_grant_with_signature",
+                "patient_signing_key",
+            );
+        }
+
+        let expected = signed_grant::get_nonce(&env, &patient);
+        if nonce < expected {
+            return Err(ContractError::NonceAlreadyUsed);
+        }
+        if nonce > expected {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let approval = signed_grant::GrantApproval {
+            contract: env.current_contract_address(),
+            patient: patient.clone(),
+            grantee: grantee.clone(),
+            level: level.clone(),
+            duration_seconds,
+            nonce,
+        };
+        let message = signed_grant::approval_message(&env, &approval);
+        teye_common::verify_meta_signature(&env, &pubkey, &message, &signature);
+        signed_grant::advance_nonce(&env, &patient);
+
+        Self::apply_access_grant(
+            env.clone(),
+            patient.clone(),
+            patient.clone(),
+            grantee.clone(),
+            level,
+            None,
+            duration_seconds,
+            false,
+        )?;
+        events::publish_grant_relayed(&env, patient, grantee, relayer, nonce);
+        Ok(())
+    }
+
+    fn grant_access_for_purpose(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        grantee: Address,
+        level: AccessLevel,
+        purpose: Option<GrantPurpose>,
+        duration_seconds: u64,
+    ) -> Result<Option<GrantReceipt>, ContractError> {
+        caller.require_auth();
+        Self::apply_access_grant(
+            env,
+            caller,
+            patient,
+            grantee,
+            level,
+            purpose,
+            duration_seconds,
+            false,
+        )
+    }
+
+    /// The body of `grant_access_for_purpose`, for a `caller` whose
+    /// authorization has already been established. `override_cap` lets a
+    /// ClinicalAdmin exceed the maximum grant duration. Returns `None` when
+    /// the grant is left pending patient approval.
+    #[allow(clippy::arithmetic_side_effects, clippy::too_many_arguments)]
+    fn apply_access_grant(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        grantee: Address,
+        level: AccessLevel,
+        purpose: Option<GrantPurpose>,
+        duration_seconds: u64,
+        override_cap: bool,
+    ) -> Result<Option<GrantReceipt>, ContractError> {
+        let _guard = teye_common::ReentrancyGuard::new(&env);
+        circuit_breaker::require_not_paused(
+            &env,
+            &circuit_breaker::PauseScope::Function(symbol_short!("GRT_ACC")),
+        )?;
+
+        Self::enforce_rate_limit(&env, &caller)?;
+
+        validation::validate_duration(duration_seconds)?;
+        Self::require_grantable(&env, &patient, &grantee, &level)?;
+        contract_grantee::check_grant(&env, &grantee, purpose.as_ref(), duration_seconds)?;
+        let purpose = purpose.unwrap_or(GrantPurpose::Treatment);
+        let over_cap = expiry::exceeds_max_grant_duration(&env, duration_seconds);
+        if over_cap && !override_cap {
+            return Err(ContractError::DurationTooLong);
+        }
+        if over_cap && !rbac::has_clinical_admin(&env, &caller) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "grant_access_with_override",
+                "permission:ClinicalAdmin",
+            );
+        }
+
+        let decision = auth::grant_access(&env, &caller, &patient);
+        if decision.denied_for(&env, auth::LOCKDOWN) {
+            return Err(ContractError::BreachLockdown);
+        }
+        if !decision.allowed {
+            // Log failed access grant attempt
+            let audit_entry = audit::create_audit_entry(
+                &env,
+                caller.clone(),
+                patient.clone(),
+                None,
+                AccessAction::GrantAccess,
+                AccessResult::Denied,
+                Some(String::from_str(&env, "Insufficient permissions")),
+            )?;
+            audit::add_audit_entry(&env, &audit_entry);
+            events::publish_audit_log_entry(&env, &audit_entry);
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "grant_access",
+                "patient_or_permission:ManageAccess_or_ClinicalAdmin",
+            );
+        }
+        // A delegate managing the patient's access may not use that to
+        // grant itself access; only a ClinicalAdmin may, and it is flagged
+        let self_grant = caller != patient && grantee == caller;
+        if self_grant && !rbac::has_clinical_admin(&env, &caller) {
+            Self::emit_access_violation(&env, &caller, "grant_access", "grantee_not_caller");
+            return Err(ContractError::SelfGrantViaDelegation);
+        }
+        if decision.reason == Symbol::new(&env, auth::FULL_GRANTEE) {
+            Self::require_full_grantee_grant(
+                &env,
+                &caller,
+                &patient,
+                &grantee,
+                &level,
+                duration_seconds,
+            )?;
+        }
+        if caller != patient && heartbeat::grants_locked(&env) {
+            return Err(ContractError::MaintenanceStale);
+        }
+        if over_cap {
+            // Only a ClinicalAdmin gets here, so the grant is stored below
+            // rather than left pending
+            events::publish_grant_cap_overridden(
+                &env,
+                caller.clone(),
+                patient.clone(),
+                grantee.clone(),
+                duration_seconds,
+                expiry::get_max_grant_duration(&env),
+            );
+        }
+
+        if caller != patient && approval::is_approval_required(&env, &patient) {
+            if rbac::has_clinical_admin(&env, &caller) {
+                // ClinicalAdmin may bypass patient approval, but it is flagged
+                let receipt = Self::store_access_grant(
+                    &env,
+                    &patient,
+                    &grantee,
+                    &level,
+                    &purpose,
+                    duration_seconds,
+                    &caller,
+                );
+                activity::log(&env, &patient, activity::ACCESS_GRANTED, &caller, 0);
+                events::publish_forced_grant(
+                    &env,
+                    caller,
+                    patient.clone(),
+                    grantee.clone(),
+                    level.clone(),
+                    receipt.expires_at,
+                );
+                events::publish_access_granted(
+                    &env,
+                    patient,
+                    grantee,
+                    level,
+                    purpose,
+                    duration_seconds,
+                    receipt.expires_at,
+                );
+                return Ok(Some(receipt));
+            }
+
+            let now = env.ledger().timestamp();
+            let pending = PendingGrant {
+                patient,
+                grantee,
+                level,
+                purpose,
+                duration_seconds,
+                requested_by: caller,
+                requested_at: now,
+                expires_at: now.saturating_add(approval::PENDING_GRANT_WINDOW_SECONDS),
+            };
+            approval::set_pending_grant(&env, &pending);
+            events::publish_grant_pending(&env, &pending);
+            return Ok(None);
+        }
+
+        let receipt = Self::store_access_grant(
+            &env,
+            &patient,
+            &grantee,
+            &level,
+            &purpose,
+            duration_seconds,
+            &caller,
+        );
+        activity::log(&env, &patient, activity::ACCESS_GRANTED, &caller, 0);
+        if self_grant {
+            events::publish_forced_grant(
+                &env,
+                caller,
+                patient.clone(),
+                grantee.clone(),
+                level.clone(),
+                receipt.expires_at,
+            );
+        }
+
+        events::publish_access_granted(
+            &env,
+            patient,
+            grantee,
+            level,
+            purpose,
+            duration_seconds,
+            receipt.expires_at,
+        );
+
+        Ok(Some(receipt))
+    }
+
+    /// The limits on a grant made by a holder of the patient's `Full` grant:
+    /// `Read` only, to someone else, ending no later than the caller's own
+    /// grant, and never replacing a live grant the caller did not create.
+    fn require_full_grantee_grant(
+        env: &Env,
+        caller: &Address,
+        patient: &Address,
+        grantee: &Address,
+        level: &AccessLevel,
+        duration_seconds: u64,
+    ) -> Result<(), ContractError> {
+        if *level != AccessLevel::Read {
+            return Self::unauthorized(env, caller, "grant_access", "access:Full_grants_Read_only");
+        }
+        if grantee == caller {
+            return Err(ContractError::InvalidInput);
+        }
+        let key = (symbol_short!("ACCESS"), patient.clone(), grantee.clone());
+        let existing: Option<AccessGrant> = env.storage().persistent().get(&key);
+        if existing.is_some_and(|grant| !expiry::is_expired(env, grant.expires_at))
+            && grant_creator::get_creator(env, patient, grantee) != *caller
+        {
+            return Self::unauthorized(env, caller, "grant_access", "grant_creator");
+        }
+        let own_expiry = auth::full_grant_expiry(env, patient, caller).unwrap_or(0);
+        let expires_at = env.ledger().timestamp().saturating_add(duration_seconds);
+        if expires_at > own_expiry {
+            return Err(ContractError::DurationTooLong);
+        }
+        Ok(())
+    }
+
+    /// Require (or stop requiring) patient approval for grants made on the
+    /// patient's behalf by delegates. Only the patient may set this.
+    pub fn set_require_patient_approval(
+        env: Env,
+        patient: Address,
+        required: bool,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+        approval::set_approval_required(&env, &patient, required);
+        Ok(())
+    }
+
+    /// Whether grants on behalf of `patient` need the patient's approval.
+    pub fn requires_patient_approval(env: Env, patient: Address) -> bool {
+        approval::is_approval_required(&env, &patient)
+    }
+
+    /// Approve a pending grant, activating it for its requested duration
+    /// starting now. Fails with `ExpiredAccess` once the approval window has
+    /// lapsed.
+    pub fn approve_grant(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+
+        let pending = approval::get_pending_grant(&env, &patient, &grantee)
+            .ok_or(ContractError::PendingGrantNotFound)?;
+
+        if expiry::is_expired(&env, pending.expires_at) {
+            return Err(ContractError::ExpiredAccess);
+        }
+        approval::remove_pending_grant(&env, &patient, &grantee);
+
+        let expires_at = Self::store_access_grant(
+            &env,
+            &patient,
+            &grantee,
+            &pending.level,
+            &pending.purpose,
+            pending.duration_seconds,
+            &pending.requested_by,
+        )
+        .expires_at;
+        activity::log(
+            &env,
+            &patient,
+            activity::ACCESS_GRANTED,
+            &pending.requested_by,
+            0,
+        );
+        events::publish_grant_approved(
+            &env,
+            patient.clone(),
+            grantee.clone(),
+            pending.requested_by,
+        );
+        events::publish_access_granted(
+            &env,
+            patient,
+            grantee,
+            pending.level,
+            pending.purpose,
+            pending.duration_seconds,
+            expires_at,
+        );
+
+        Ok(())
+    }
+
+    /// Get the grant awaiting the patient's approval for `grantee`, if any.
+    pub fn get_pending_grant(env: Env, patient: Address, grantee: Address) -> Option<PendingGrant> {
+        approval::get_pending_grant(&env, &patient, &grantee)
+    }
+
+    /// Offer access that only becomes active once `grantee` accepts it.
+    /// The offer lapses if not accepted within the offer window; the grant's
+    /// duration starts at acceptance.
+    pub fn grant_access_pending_acceptance(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+        level: AccessLevel,
+        duration_seconds: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+        validation::validate_duration(duration_seconds)?;
+        Self::require_within_grant_cap(&env, duration_seconds)?;
+        Self::require_grantable(&env, &patient, &grantee, &level)?;
+        contract_grantee::check_grant(&env, &grantee, None, duration_seconds)?;
+
+        let now = env.ledger().timestamp();
+        let offer = AccessOffer {
+            patient,
+            grantee,
+            level,
+            duration_seconds,
+            offered_at: now,
+            expires_at: now.saturating_add(offer::get_offer_window(&env)),
+        };
+        offer::set_offer(&env, &offer);
+        events::publish_access_offered(&env, &offer);
+        Ok(())
+    }
+
+    /// Accept an access offer, activating the grant from now. Fails with
+    /// `ExpiredAccess` once the offer window has lapsed.
+    pub fn accept_access(
+        env: Env,
+        grantee: Address,
+        patient: Address,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        grantee.require_auth();
+
+        let offer =
+            offer::get_offer(&env, &patient, &grantee).ok_or(ContractError::AccessOfferNotFound)?;
+
+        if expiry::is_expired(&env, offer.expires_at) {
+            return Err(ContractError::ExpiredAccess);
+        }
+        // The grantee may have been frozen or deactivated since the offer
+        Self::require_grantable(&env, &patient, &grantee, &offer.level)?;
+        offer::remove_offer(&env, &patient, &grantee);
+
+        let expires_at = Self::store_access_grant(
+            &env,
+            &patient,
+            &grantee,
+            &offer.level,
+            &GrantPurpose::Treatment,
+            offer.duration_seconds,
+            &patient,
+        )
+        .expires_at;
+        activity::log(&env, &patient, activity::ACCESS_GRANTED, &grantee, 0);
+        events::publish_access_offer_accepted(&env, patient.clone(), grantee.clone(), expires_at);
+        events::publish_access_granted(
+            &env,
+            patient,
+            grantee,
+            offer.level,
+            GrantPurpose::Treatment,
+            offer.duration_seconds,
+            expires_at,
+        );
+
+        Ok(())
+    }
+
+    /// Decline an access offer. Nothing is granted.
+    pub fn decline_access(
+        env: Env,
+        grantee: Address,
+        patient: Address,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        grantee.require_auth();
+
+        if offer::get_offer(&env, &patient, &grantee).is_none() {
+            return Err(ContractError::AccessOfferNotFound);
+        }
+        offer::remove_offer(&env, &patient, &grantee);
+        events::publish_access_offer_declined(&env, patient, grantee);
+        Ok(())
+    }
+
+    /// Get the offer awaiting `grantee`'s acceptance, if any.
+    pub fn get_access_offer(env: Env, patient: Address, grantee: Address) -> Option<AccessOffer> {
+        offer::get_offer(&env, &patient, &grantee)
+    }
+
+    /// Set how long new access offers stay open, at most about 180 days.
+    /// Requires ContractAdmin.
+    pub fn set_access_offer_window(
+        env: Env,
+        caller: Address,
+        seconds: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_access_offer_window",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        // Offers are temporary entries, which cannot outlast the max TTL
+        if seconds == 0 || !temp_storage::fits_max_ttl(seconds) {
+            return Err(ContractError::InvalidInput);
+        }
+        offer::set_offer_window(&env, seconds);
+        Ok(())
+    }
+
+    /// How long new access offers stay open.
+    pub fn get_access_offer_window(env: Env) -> u64 {
+        offer::get_offer_window(&env)
+    }
+
+    /// Set how many seconds past `expires_at` grants, consents, delegations,
+    /// sessions and prescriptions are still honoured, to absorb clock skew
+    /// between wallets and the ledger. Requires ContractAdmin.
+    pub fn set_expiry_grace(env: Env, caller: Address, seconds: u64) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_expiry_grace",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        if seconds > expiry::MAX_EXPIRY_GRACE_SECONDS {
+            return Err(ContractError::InvalidInput);
+        }
+        expiry::set_grace(&env, seconds);
+        Ok(())
+    }
+
+    /// The configured expiry grace window, in seconds.
+    pub fn get_expiry_grace(env: Env) -> u64 {
+        expiry::get_grace(&env)
+    }
+
+    /// Set how many seconds `revoke_access` waits before a revocation takes
+    /// effect, up to `revocation::MAX_REVOCATION_GRACE_SECONDS`. 0, the
+    /// default, revokes immediately. Requires ContractAdmin.
+    pub fn set_revocation_grace(
+        env: Env,
+        caller: Address,
+        seconds: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_revocation_grace",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        if seconds > revocation::MAX_REVOCATION_GRACE_SECONDS {
+            return Err(ContractError::InvalidInput);
+        }
+        revocation::set_grace(&env, seconds);
+        Ok(())
+    }
+
+    /// The configured revocation grace window, in seconds.
+    pub fn get_revocation_grace(env: Env) -> u64 {
+        revocation::get_grace(&env)
+    }
+
+    /// Set the longest duration a new access grant may run for, or 0 for
+    /// no limit. Applies to every way of granting patient-wide or
+    /// record-level access, including offers, batches and templates;
+    /// existing grants are left alone. Requires ContractAdmin.
+    pub fn set_max_grant_duration(
+        env: Env,
+        caller: Address,
+        seconds: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_max_grant_duration",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        if seconds != 0 {
+            validation::validate_duration(seconds)?;
+        }
+        expiry::set_max_grant_duration(&env, seconds);
+        Ok(())
+    }
+
+    /// The maximum grant duration in seconds, or 0 if unlimited.
+    pub fn get_max_grant_duration(env: Env) -> u64 {
+        expiry::get_max_grant_duration(&env)
+    }
+
+    /// Record that an admin is alive and maintenance is running. Requires
+    /// OperatorAdmin.
+    pub fn heartbeat(env: Env, caller: Address) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::OperatorAdmin) {
+            return Self::unauthorized(&env, &caller, "heartbeat", "admin_tier:OperatorAdmin");
+        }
+        let heartbeat = heartbeat::beat(&env, &caller);
+        events::publish_heartbeat(&env, caller, heartbeat.ledger);
+        Ok(())
+    }
+
+    /// The last heartbeat, with the number of ledgers closed since.
+    pub fn get_last_heartbeat(env: Env) -> Option<HeartbeatInfo> {
+        heartbeat::get_info(&env)
+    }
+
+    /// Set how long maintenance may go without a heartbeat before it counts
+    /// as stale (0 disables the check), and whether grants made on a
+    /// patient's behalf by anyone but the patient are refused while stale.
+    /// Requires ContractAdmin.
+    pub fn set_heartbeat_config(
+        env: Env,
+        caller: Address,
+        config: HeartbeatConfig,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_heartbeat_config",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        if config.stale_threshold == 0 && config.lock_grants_when_stale {
+            return Err(ContractError::InvalidInput);
+        }
+        heartbeat::set_config(&env, &config);
+        Ok(())
+    }
+
+    /// The heartbeat staleness settings.
+    pub fn get_heartbeat_config(env: Env) -> HeartbeatConfig {
+        heartbeat::get_config(&env)
+    }
+
+    /// Whether the last heartbeat is older than the configured threshold.
+    /// Always false while no threshold is set; true if a threshold is set
+    /// but no heartbeat was ever recorded.
+    pub fn is_maintenance_stale(env: Env) -> bool {
+        heartbeat::is_stale(&env)
+    }
+
+    /// Open a read session letting `session_key` read the patient's records
+    /// for `ttl_seconds`, e.g. for a portal backend that should not hold the
+    /// patient's signing key.
+    ///
+    /// The session reads with the patient's own rights, capped at `scope`.
+    /// Sessions never authorize writes, even with a `Write` or `Full` scope.
+    /// Opening a session for a key that already has one replaces it.
+    pub fn create_session(
+        env: Env,
+        patient: Address,
+        session_key: Address,
+        scope: AccessLevel,
+        ttl_seconds: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+
+        if session_key == patient
+            || scope == AccessLevel::None
+            || ttl_seconds == 0
+            || ttl_seconds > session::MAX_SESSION_TTL_SECONDS
+        {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let now = env.ledger().timestamp();
+        let session = Session {
+            patient,
+            session_key,
+            scope,
+            created_at: now,
+            expires_at: now.saturating_add(ttl_seconds),
+        };
+        session::set_session(&env, &session);
+        events::publish_session_created(&env, &session);
+        Ok(())
+    }
+
+    /// End a session before it expires.
+    pub fn revoke_session(
+        env: Env,
+        patient: Address,
+        session_key: Address,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+
+        if session::get_session(&env, &patient, &session_key).is_none() {
+            return Err(ContractError::SessionNotFound);
+        }
+        session::remove_session(&env, &patient, &session_key);
+        events::publish_session_revoked(&env, patient, session_key);
+        Ok(())
+    }
+
+    /// Get the live session for `session_key`, if any.
+    pub fn get_session(env: Env, patient: Address, session_key: Address) -> Option<Session> {
+        session::get_session(&env, &patient, &session_key)
+    }
+
+    /// Grant access to multiple users in a single transaction.
+    /// Patient authorizes once for the entire batch.
+    pub fn grant_access_batch(
+        env: Env,
+        patient: Address,
+        grants: Vec<BatchGrantInput>,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+
+        if grants.is_empty() {
+            return Err(ContractError::InvalidInput);
+        }
+
+        for grant in grants.iter() {
+            Self::require_within_grant_cap(&env, grant.duration_seconds)?;
+            Self::require_grantable(&env, &patient, &grant.grantee, &grant.level)?;
+            contract_grantee::check_grant(&env, &grant.grantee, None, grant.duration_seconds)?;
+        }
+
+        for grant in grants.iter() {
+            let receipt = Self::store_access_grant(
+                &env,
+                &patient,
+                &grant.grantee,
+                &grant.level,
+                &GrantPurpose::Treatment,
+                grant.duration_seconds,
+                &patient,
+            );
+            activity::log(&env, &patient, activity::ACCESS_GRANTED, &patient, 0);
+
+            events::publish_access_granted(
+                &env,
+                patient.clone(),
+                grant.grantee.clone(),
+                grant.level.clone(),
+                GrantPurpose::Treatment,
+                grant.duration_seconds,
+                receipt.expires_at,
+            );
+        }
+
+        events::publish_batch_access_granted(&env, patient, grants.len());
+
+        Ok(())
+    }
+
+    /// Create or replace an access template owned by `owner`. Templates
+    /// owned by the contract admin are shared with every patient.
+    pub fn create_access_template(
+        env: Env,
+        owner: Address,
+        name: Symbol,
+        entries: Vec<AccessTemplateEntry>,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        owner.require_auth();
+
+        if entries.is_empty() || entries.len() > access_template::MAX_TEMPLATE_ENTRIES {
+            return Err(ContractError::InvalidInput);
+        }
+        for entry in entries.iter() {
+            if entry.level == AccessLevel::None {
+                return Err(ContractError::InvalidInput);
+            }
+            validation::validate_duration(entry.duration_seconds)?;
+        }
+
+        let names = access_template::get_template_names(&env, &owner);
+        if !names.contains(&name) && names.len() >= access_template::MAX_TEMPLATES_PER_OWNER {
+            return Err(ContractError::InvalidInput);
+        }
+
+        access_template::set_template(
+            &env,
+            &AccessTemplate {
+                owner,
+                name,
+                entries,
+                created_at: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Grant access to `grantees` using one of the patient's templates, or
+    /// a shared admin template of the same name. A single-entry template
+    /// applies to every grantee; otherwise grantees are matched to entries
+    /// by position and the counts must be equal.
+    pub fn apply_access_template(
+        env: Env,
+        patient: Address,
+        template_name: Symbol,
+        grantees: Vec<Address>,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+
+        let template = access_template::get_template(&env, &patient, &template_name)
+            .or_else(|| {
+                env.storage()
+                    .instance()
+                    .get::<Symbol, Address>(&ADMIN)
+                    .and_then(|admin| access_template::get_template(&env, &admin, &template_name))
+            })
+            .ok_or(ContractError::TemplateNotFound)?;
+
+        let entries = template.entries;
+        if grantees.is_empty() || (entries.len() != 1 && entries.len() != grantees.len()) {
+            return Err(ContractError::InvalidInput);
+        }
+        for entry in entries.iter() {
+            Self::require_within_grant_cap(&env, entry.duration_seconds)?;
+        }
+
+        for (i, grantee) in grantees.iter().enumerate() {
+            let index = if entries.len() == 1 { 0 } else { i as u32 };
+            let entry = entries.get(index).ok_or(ContractError::InvalidInput)?;
+            Self::require_grantable(&env, &patient, &grantee, &entry.level)?;
+            contract_grantee::check_grant(&env, &grantee, None, entry.duration_seconds)?;
+            let expires_at = Self::store_access_grant(
+                &env,
+                &patient,
+                &grantee,
+                &entry.level,
+                &GrantPurpose::Treatment,
+                entry.duration_seconds,
+                &patient,
+            )
+            .expires_at;
+            activity::log(&env, &patient, activity::ACCESS_GRANTED, &patient, 0);
+            events::publish_access_granted(
+                &env,
+                patient.clone(),
+                grantee,
+                entry.level,
+                GrantPurpose::Treatment,
+                entry.duration_seconds,
+                expires_at,
+            );
+        }
+
+        events::publish_batch_access_granted(&env, patient, grantees.len());
+
+        Ok(())
+    }
+
+    /// List the templates owned by `owner`.
+    pub fn get_templates(env: Env, owner: Address) -> Vec<AccessTemplate> {
+        let mut templates = Vec::new(&env);
+        for name in access_template::get_template_names(&env, &owner).iter() {
+            if let Some(template) = access_template::get_template(&env, &owner, &name) {
+                templates.push_back(template);
+            }
+        }
+        templates
+    }
+
+    /// Delete one of `owner`'s templates. Grants already made from it are
+    /// not affected.
+    pub fn delete_access_template(
+        env: Env,
+        owner: Address,
+        name: Symbol,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        owner.require_auth();
+        if !access_template::remove_template(&env, &owner, &name) {
+            return Err(ContractError::TemplateNotFound);
+        }
+        Ok(())
+    }
+
+    /// Set the access `provider` asks new patients for, replacing any
+    /// earlier template. Patients accept it in one call with
+    /// `accept_provider_template`; grants already made from an earlier
+    /// template are not affected. Requires an active Optometrist or
+    /// Ophthalmologist role.
+    pub fn set_provider_access_template(
+        env: Env,
+        provider: Address,
+        level: AccessLevel,
+        duration_seconds: u64,
+        purpose: GrantPurpose,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        provider.require_auth();
+
+        let is_clinician = rbac::get_active_assignment(&env, &provider).is_some_and(|assignment| {
+            assignment.role == Role::Optometrist || assignment.role == Role::Ophthalmologist
+        });
+        if !is_clinician {
+            return Self::unauthorized(
+                &env,
+                &provider,
+                "set_provider_access_template",
+                "role:Optometrist_or_Ophthalmologist",
+            );
+        }
+        if level == AccessLevel::None {
+            return Err(ContractError::InvalidInput);
+        }
+        validation::validate_duration(duration_seconds)?;
+
+        provider_template::set_template(
+            &env,
+            &ProviderAccessTemplate {
+                provider,
+                level,
+                duration_seconds,
+                purpose,
+                updated_at: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Get the access `provider` asks new patients for, so a wallet can
+    /// show it before the patient accepts.
+    pub fn get_provider_access_template(
+        env: Env,
+        provider: Address,
+    ) -> Option<ProviderAccessTemplate> {
+        provider_template::get_template(&env, &provider)
+    }
+
+    /// Withdraw `provider`'s access template. Grants already made from it
+    /// are not affected.
+    pub fn remove_provider_access_template(
+        env: Env,
+        provider: Address,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        provider.require_auth();
+        if !provider_template::remove_template(&env, &provider) {
+            return Err(ContractError::TemplateNotFound);
+        }
+        Ok(())
+    }
+
+    /// Grant `provider` the access in its template as it stands now, like
+    /// `grant_access_with_purpose` made by the patient. Fails with
+    /// `TemplateNotFound` if the provider has no template.
+    pub fn accept_provider_template(
+        env: Env,
+        patient: Address,
+        provider: Address,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        let template = provider_template::get_template(&env, &provider)
+            .ok_or(ContractError::TemplateNotFound)?;
+        Self::grant_access_for_purpose(
+            env,
+            patient.clone(),
+            patient,
+            provider,
+            template.level,
+            Some(template.purpose),
+            template.duration_seconds,
+        )
+        .map(|_| ())
+    }
+
+    /// Register an organization, such as a hospital system, run by
+    /// `admin`. Requires ContractAdmin.
+    pub fn create_organization(
+        env: Env,
+        caller: Address,
+        org: Symbol,
+        admin: Address,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "create_organization",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        if organization::get_org(&env, &org).is_some() {
+            return Err(ContractError::AlreadyExists);
+        }
+
+        organization::set_org(
+            &env,
+            &Organization {
+                id: org,
+                admin,
+                units: Vec::new(&env),
+                created_at: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Loads `org`, failing unless `caller` is its admin.
+    fn require_org_admin(
+        env: &Env,
+        caller: &Address,
+        org: &Symbol,
+        action: &str,
+    ) -> Result<Organization, ContractError> {
+        let organization =
+            organization::get_org(env, org).ok_or(ContractError::OrganizationNotFound)?;
+        if organization.admin != *caller {
+            return Self::unauthorized(env, caller, action, "org_admin");
+        }
+        Ok(organization)
+    }
+
+    /// Add a unit (department) to an organization. At most
+    /// `organization::MAX_UNITS_PER_ORG` units may be created.
+    pub fn create_unit(
+        env: Env,
+        org_admin: Address,
+        org: Symbol,
+        unit_name: Symbol,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        org_admin.require_auth();
+        let mut organization = Self::require_org_admin(&env, &org_admin, &org, "create_unit")?;
+        if organization::has_unit(&organization, &unit_name) {
+            return Err(ContractError::AlreadyExists);
+        }
+        if organization.units.len() >= organization::MAX_UNITS_PER_ORG {
+            return Err(ContractError::InvalidInput);
+        }
+
+        organization.units.push_back(unit_name);
+        organization::set_org(&env, &organization);
+        Ok(())
+    }
+
+    /// Add a user to an organization directly, so they hold whatever
+    /// access patients grant the organization as a whole. A user belongs
+    /// to at most one organization.
+    pub fn add_org_member(
+        env: Env,
+        org_admin: Address,
+        org: Symbol,
+        member: Address,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        org_admin.require_auth();
+        Self::require_org_admin(&env, &org_admin, &org, "add_org_member")?;
+
+        let mut membership = Self::membership_in(&env, &org, &member)?;
+        membership.direct = true;
+        organization::set_membership(&env, &member, &membership);
+        Ok(())
+    }
+
+    /// Remove a user's direct membership of an organization. Access they
+    /// hold through a unit is unaffected.
+    pub fn remove_org_member(
+        env: Env,
+        org_admin: Address,
+        org: Symbol,
+        member: Address,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        org_admin.require_auth();
+        Self::require_org_admin(&env, &org_admin, &org, "remove_org_member")?;
+
+        let mut membership = organization::get_membership(&env, &member)
+            .filter(|membership| membership.org == org && membership.direct)
+            .ok_or(ContractError::UserNotFound)?;
+        membership.direct = false;
+        organization::set_membership(&env, &member, &membership);
+        Ok(())
+    }
+
+    /// Place a user in one of an organization's units, replacing any unit
+    /// they were in. Unit members also hold the organization's own grants.
+    pub fn add_member_to_unit(
+        env: Env,
+        org_admin: Address,
+        org: Symbol,
+        unit: Symbol,
+        member: Address,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        org_admin.require_auth();
+        let organization = Self::require_org_admin(&env, &org_admin, &org, "add_member_to_unit")?;
+        if !organization::has_unit(&organization, &unit) {
+            return Err(ContractError::OrganizationNotFound);
+        }
+
+        let mut membership = Self::membership_in(&env, &org, &member)?;
+        membership.unit = Some(unit);
+        organization::set_membership(&env, &member, &membership);
+        Ok(())
+    }
+
+    /// Take a user out of a unit. Access they held through the unit ends
+    /// at once; organization grants still apply if they are a direct
+    /// member.
+    pub fn remove_member_from_unit(
+        env: Env,
+        org_admin: Address,
+        org: Symbol,
+        unit: Symbol,
+        member: Address,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        org_admin.require_auth();
+        Self::require_org_admin(&env, &org_admin, &org, "remove_member_from_unit")?;
+
+        let mut membership = organization::get_membership(&env, &member)
+            .filter(|membership| membership.org == org && membership.unit == Some(unit.clone()))
+            .ok_or(ContractError::UserNotFound)?;
+        membership.unit = None;
+        organization::set_membership(&env, &member, &membership);
+        Ok(())
+    }
+
+    /// `member`'s membership of `org`, or an empty one if they have none.
+    /// Fails if they already belong to another organization.
+    fn membership_in(
+        env: &Env,
+        org: &Symbol,
+        member: &Address,
+    ) -> Result<OrgMembership, ContractError> {
+        match organization::get_membership(env, member) {
+            Some(membership) if membership.org != *org => Err(ContractError::AlreadyExists),
+            Some(membership) => Ok(membership),
+            None => Ok(OrgMembership {
+                org: org.clone(),
+                direct: false,
+                unit: None,
+            }),
+        }
+    }
+
+    /// Grant an organization, or one of its units when `unit` is set,
+    /// access to the patient's records. Members resolve to their unit's
+    /// grant first and fall back to the organization's; see
+    /// `check_access`. Replaces any earlier grant to the same target.
+    pub fn grant_org_access(
+        env: Env,
+        patient: Address,
+        org: Symbol,
+        unit: Option<Symbol>,
+        level: AccessLevel,
+        duration_seconds: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+        if level == AccessLevel::None || duration_seconds == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+        Self::require_within_grant_cap(&env, duration_seconds)?;
+        let organization =
+            organization::get_org(&env, &org).ok_or(ContractError::OrganizationNotFound)?;
+        if let Some(unit) = &unit {
+            if !organization::has_unit(&organization, unit) {
+                return Err(ContractError::OrganizationNotFound);
+            }
+        }
+
+        let now = env.ledger().timestamp();
+        let grant = OrgGrant {
+            patient: patient.clone(),
+            org,
+            unit,
+            level,
+            granted_at: now,
+            expires_at: now.saturating_add(duration_seconds),
+        };
+        organization::set_grant(&env, &grant);
+        // Every member's access may have changed
+        grant_revision::bump_epoch(&env, &patient);
+        activity::log(&env, &patient, activity::ACCESS_GRANTED, &patient, 0);
+        events::publish_org_access_granted(&env, grant);
+        Ok(())
+    }
+
+    /// Revoke the patient's grant to an organization or one of its units.
+    pub fn revoke_org_access(
+        env: Env,
+        patient: Address,
+        org: Symbol,
+        unit: Option<Symbol>,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+        if !organization::remove_grant(&env, &patient, &org, &unit) {
+            return Err(ContractError::OrganizationNotFound);
+        }
+
+        grant_revision::bump_epoch(&env, &patient);
+        activity::log(&env, &patient, activity::ACCESS_REVOKED, &patient, 0);
+        events::publish_org_access_revoked(&env, patient, org, unit);
+        Ok(())
+    }
+
+    /// Get an organization and its units.
+    pub fn get_organization(env: Env, org: Symbol) -> Option<Organization> {
+        organization::get_org(&env, &org)
+    }
+
+    /// Get the organization and unit a user belongs to, if any.
+    pub fn get_org_membership(env: Env, member: Address) -> Option<OrgMembership> {
+        organization::get_membership(&env, &member)
+    }
+
+    /// Get the patient's grant to an organization or one of its units,
+    /// including expired grants that have not been revoked.
+    pub fn get_org_grant(
+        env: Env,
+        patient: Address,
+        org: Symbol,
+        unit: Option<Symbol>,
+    ) -> Option<OrgGrant> {
+        organization::get_grant(&env, &patient, &org, &unit)
+    }
+
+    /// Report whether `caller` may perform `action`, and which rule decided
+    /// it. Runs the same checks as the endpoints themselves; changes
+    /// nothing and needs no authorization.
+    pub fn authorize(env: Env, caller: Address, action: AuthAction) -> AuthDecision {
+        auth::authorize(&env, &caller, &action)
+    }
+
+    /// Check access level with ABAC policy evaluation
+    pub fn check_access(env: Env, patient: Address, grantee: Address) -> AccessLevel {
+        if rbac::is_frozen(&env, &grantee) {
+            return AccessLevel::None;
+        }
+
+        if access_freeze::blocks(&env, &patient, &grantee) {
+            return AccessLevel::None;
+        }
+
+        // A personal grant is the most specific and is checked first; only
+        // without one does the grantee fall back to their organization
+        if let Some(level) = Self::personal_access(&env, &patient, &grantee) {
+            return level;
+        }
+        match organization::resolve(&env, &patient, &grantee) {
+            Some(level) if evaluate_access_policies(&env, &grantee, None, Some(patient)) => level,
+            _ => AccessLevel::None,
+        }
+    }
+
+    /// The level of `grantee`'s own unexpired grant, if they also hold
+    /// active consent and ABAC policies allow it.
+    fn personal_access(env: &Env, patient: &Address, grantee: &Address) -> Option<AccessLevel> {
+        // First check traditional consent-based access
+        if !has_active_consent(env, patient, grantee) {
+            return None;
+        }
+
+        let key = (symbol_short!("ACCESS"), patient.clone(), grantee.clone());
+        let grant = env.storage().persistent().get::<_, AccessGrant>(&key)?;
+        if expiry::is_expired(env, grant.expires_at) {
+            return None;
+        }
+        // Check if ABAC policies also allow this access
+        evaluate_access_policies(env, grantee, None, Some(patient.clone())).then_some(grant.level)
+    }
+
+    /// The revision of the access `patient` has given `grantee`. It moves on
+    /// every change to their grants, record grants, consent or scheduled
+    /// revocations, so a cached `check_access` result is stale once it
+    /// differs.
+    pub fn get_grant_revision(env: Env, patient: Address, grantee: Address) -> u64 {
+        grant_revision::get_revision(&env, &patient, &grantee)
+    }
+
+    /// The patient's access epoch. It moves when the patient's access
+    /// changes as a whole (freezes and chart recovery), which invalidates
+    /// every cached decision for the patient.
+    pub fn get_access_epoch(env: Env, patient: Address) -> u64 {
+        grant_revision::get_epoch(&env, &patient)
+    }
+
+    /// Returns true if `grantee` currently holds at least `required` access
+    /// to `patient`'s records, as evaluated by `check_access`.
+    pub fn meets_access_level(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+        required: AccessLevel,
+    ) -> bool {
+        let actual = Self::check_access(env, patient, grantee);
+        access::level_satisfies(&required, &actual)
+    }
+
+    /// Like `check_access`, but also reports the grant's purpose, creator
+    /// and validity window. Returns `None` if there is no unexpired grant.
+    /// Grants made before purposes were recorded report `Treatment`, and
+    /// those made before creators were recorded report the patient.
+    pub fn check_access_detailed(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+    ) -> Option<AccessGrantDetail> {
+        let key = (symbol_short!("ACCESS"), patient.clone(), grantee.clone());
+        let grant: AccessGrant = env.storage().persistent().get(&key)?;
+        if expiry::is_expired(&env, grant.expires_at) {
+            return None;
+        }
+
+        Some(AccessGrantDetail {
+            level: Self::check_access(env.clone(), patient.clone(), grantee.clone()),
+            purpose: purpose::get_purpose(&env, &patient, &grantee),
+            created_by: grant_creator::get_creator(&env, &patient, &grantee),
+            is_contract_grantee: contract_grantee::is_marked(&env, &grantee),
+            patient,
+            grantee,
+            granted_at: grant.granted_at,
+            expires_at: grant.expires_at,
+        })
+    }
+
+    /// Count the unexpired access grants on a patient's records, in total
+    /// and per purpose. Readable by the patient, by a guardian the patient
+    /// delegated `ManageAccess` to, and by admins.
+    pub fn get_grant_stats(
+        env: Env,
+        caller: Address,
+        patient: Address,
+    ) -> Result<GrantStats, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+
+        if caller != patient
+            && !rbac::has_delegated_permission(&env, &patient, &caller, &Permission::ManageAccess)
+            && !Self::has_admin_access(&env, &caller, &AdminTier::OperatorAdmin)
+        {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "get_grant_stats",
+                "patient_or_guardian_or_admin",
+            );
+        }
+
+        let grantees: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("ACC_LST"), patient.clone()))
+            .unwrap_or(Vec::new(&env));
+
+        let mut purposes = Vec::new(&env);
+        for grantee in grantees.iter() {
+            let key = (symbol_short!("ACCESS"), patient.clone(), grantee.clone());
+            if let Some(grant) = env.storage().persistent().get::<_, AccessGrant>(&key) {
+                if !expiry::is_expired(&env, grant.expires_at) {
+                    purposes.push_back(purpose::get_purpose(&env, &patient, &grantee));
+                }
+            }
+        }
+
+        Ok(GrantStats {
+            grants_active: purposes.len(),
+            grants_active_by_purpose: purpose::count_by_purpose(&env, &purposes),
+            patient,
+        })
+    }
+
+    /// Grant record-level access to a specific record.
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn grant_record_access(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+        record_id: u64,
+        level: AccessLevel,
+        duration_seconds: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+        validation::validate_duration(duration_seconds)?;
+        Self::require_within_grant_cap(&env, duration_seconds)?;
+        contract_grantee::check_grant(&env, &grantee, None, duration_seconds)?;
+
+        let record_key = (symbol_short!("RECORD"), record_id);
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&record_key)
+            .ok_or(ContractError::RecordNotFound)?;
+        if record.patient != patient {
+            return Self::unauthorized(&env, &patient, "grant_record_access", "record_owner");
+        }
+        Self::require_grantable(&env, &patient, &grantee, &level)?;
+
+        let expires_at = env.ledger().timestamp() + duration_seconds;
+        Self::store_record_access_grant(
+            &env, &patient, &grantee, record_id, &level, expires_at, &patient,
+        );
+        activity::log(
+            &env,
+            &patient,
+            activity::RECORD_ACCESS_GRANTED,
+            &patient,
+            record_id,
+        );
+
+        events::publish_record_access_granted(
+            &env,
+            patient,
+            grantee,
+            record_id,
+            level,
+            duration_seconds,
+            expires_at,
+        );
+        Ok(())
+    }
+
+    /// Check record-level access for a specific grantee.
+    pub fn check_record_access(env: Env, record_id: u64, grantee: Address) -> AccessLevel {
+        if rbac::is_frozen(&env, &grantee) {
+            return AccessLevel::None;
+        }
+        let key = (symbol_short!("REC_ACC"), record_id, grantee.clone());
+        if let Some(grant) = env.storage().persistent().get::<_, AccessGrant>(&key) {
+            if !expiry::is_expired(&env, grant.expires_at)
+                && !access_freeze::blocks(&env, &grant.patient, &grantee)
+            {
+                return grant.level;
+            }
+        }
+        AccessLevel::None
+    }
+
+    /// Create a single-use code sharing one of the patient's records with
+    /// whoever redeems it, for recipients whose address is not yet known.
+    /// The code itself stays off-chain; only its SHA-256 is stored. The
+    /// code can be redeemed until `expires_at`, and the record-level grant
+    /// it creates also ends then; codes are kept in temporary storage, so
+    /// `expires_at` must fall within about 180 days (`DurationTooLong`
+    /// otherwise). A patient may hold at most
+    /// `MAX_LIVE_SHARE_CODES` (20) unredeemed, unexpired codes; beyond that
+    /// this fails with `ShareCodeLimitReached`.
+    pub fn create_share_code(
+        env: Env,
+        patient: Address,
+        record_id: u64,
+        code_hash: BytesN<32>,
+        level: AccessLevel,
+        expires_at: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if record.patient != patient {
+            return Self::unauthorized(&env, &patient, "create_share_code", "record_owner");
+        }
+        if level == AccessLevel::None {
+            return Err(ContractError::InvalidInput);
+        }
+        let now = env.ledger().timestamp();
+        let duration_seconds = expires_at.saturating_sub(now);
+        validation::validate_duration(duration_seconds)?;
+        Self::require_within_grant_cap(&env, duration_seconds)?;
+        // Codes live in temporary storage, which cannot outlast the max TTL
+        if !temp_storage::fits_max_ttl(duration_seconds) {
+            return Err(ContractError::DurationTooLong);
+        }
+        if share_code::get_code(&env, record_id, &code_hash).is_some() {
+            return Err(ContractError::AlreadyExists);
+        }
+        if share_code::prune_live(&env, &patient) >= share_code::MAX_LIVE_SHARE_CODES {
+            return Err(ContractError::ShareCodeLimitReached);
+        }
+
+        let code = ShareCode {
+            patient,
+            record_id,
+            code_hash,
+            level,
+            created_at: now,
+            expires_at,
+        };
+        share_code::add_live_code(&env, &code);
+        events::publish_share_code_created(&env, &code);
+        Ok(())
+    }
+
+    /// Redeem a share code by presenting its preimage, granting `redeemer`
+    /// access to the record until the code's expiry. Each code works once.
+    /// Fails with `ShareCodeNotFound` for a wrong, cancelled or already
+    /// redeemed code and `ExpiredAccess` for a lapsed one.
+    pub fn redeem_share_code(
+        env: Env,
+        redeemer: Address,
+        record_id: u64,
+        code_preimage: Bytes,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        redeemer.require_auth();
+        let code_hash: BytesN<32> = env.crypto().sha256(&code_preimage).to_bytes();
+        let code = share_code::get_code(&env, record_id, &code_hash)
+            .ok_or(ContractError::ShareCodeNotFound)?;
+        if expiry::is_expired(&env, code.expires_at) {
+            return Err(ContractError::ExpiredAccess);
+        }
+        if redeemer == code.patient {
+            return Err(ContractError::InvalidInput);
+        }
+        share_code::retire_code(&env, &code);
+
+        Self::store_record_access_grant(
+            &env,
+            &code.patient,
+            &redeemer,
+            record_id,
+            &code.level,
+            code.expires_at,
+            &redeemer,
+        );
+        activity::log(
+            &env,
+            &code.patient,
+            activity::RECORD_ACCESS_GRANTED,
+            &redeemer,
+            record_id,
+        );
+        events::publish_share_code_redeemed(
+            &env,
+            code.patient.clone(),
+            record_id,
+            redeemer.clone(),
+        );
+        events::publish_record_access_granted(
+            &env,
+            code.patient,
+            redeemer,
+            record_id,
+            code.level,
+            code.expires_at.saturating_sub(env.ledger().timestamp()),
+            code.expires_at,
+        );
+        Ok(())
+    }
+
+    /// Cancel a share code before it is redeemed. Patient only.
+    pub fn cancel_share_code(
+        env: Env,
+        patient: Address,
+        record_id: u64,
+        code_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+        let code = share_code::get_code(&env, record_id, &code_hash)
+            .ok_or(ContractError::ShareCodeNotFound)?;
+        if code.patient != patient {
+            return Self::unauthorized(&env, &patient, "cancel_share_code", "record_owner");
+        }
+        share_code::retire_code(&env, &code);
+        events::publish_share_code_cancelled(&env, patient, record_id, code_hash);
+        Ok(())
+    }
+
+    /// Get an unredeemed share code by its hash, if it still exists.
+    pub fn get_share_code(env: Env, record_id: u64, code_hash: BytesN<32>) -> Option<ShareCode> {
+        share_code::get_code(&env, record_id, &code_hash)
+    }
+
+    /// Share some of the patient's records with another registered user,
+    /// such as a family member coordinating their care, through a
+    /// record-level grant on each. The grantee must be an active user of
+    /// any role other than the patient. Fails with `UserNotFound` for an
+    /// unregistered grantee and `InvalidInput` for an inactive one, an
+    /// empty or repeated record list, or more than
+    /// `user_share::MAX_SHARE_RECORDS` records.
+    pub fn share_with_user(
+        env: Env,
+        patient: Address,
+        grantee_user: Address,
+        record_ids: Vec<u64>,
+        level: AccessLevel,
+        duration_seconds: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+        validation::validate_duration(duration_seconds)?;
+        Self::require_within_grant_cap(&env, duration_seconds)?;
+
+        let grantee: User = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("USER"), grantee_user.clone()))
+            .ok_or(ContractError::UserNotFound)?;
+        if !grantee.is_active {
+            return Err(ContractError::InvalidInput);
+        }
+        Self::require_grantable(&env, &patient, &grantee_user, &level)?;
+        if record_ids.is_empty() || record_ids.len() > user_share::MAX_SHARE_RECORDS {
+            return Err(ContractError::InvalidInput);
+        }
+        let mut seen = Vec::new(&env);
+        for record_id in record_ids.iter() {
+            if seen.contains(record_id) {
+                return Err(ContractError::InvalidInput);
+            }
+            seen.push_back(record_id);
+            let record: VisionRecord = env
+                .storage()
+                .persistent()
+                .get(&(symbol_short!("RECORD"), record_id))
+                .ok_or(ContractError::RecordNotFound)?;
+            if record.patient != patient {
+                return Self::unauthorized(&env, &patient, "share_with_user", "record_owner");
+            }
+        }
+
+        let now = env.ledger().timestamp();
+        let share = UserShare {
+            patient: patient.clone(),
+            grantee: grantee_user.clone(),
+            record_ids: record_ids.clone(),
+            level: level.clone(),
+            shared_at: now,
+            expires_at: now.saturating_add(duration_seconds),
+        };
+        user_share::add(&env, &share)?;
+        for record_id in record_ids.iter() {
+            Self::store_record_access_grant(
+                &env,
+                &patient,
+                &grantee_user,
+                record_id,
+                &level,
+                share.expires_at,
+                &patient,
+            );
+            activity::log(
+                &env,
+                &patient,
+                activity::RECORD_ACCESS_GRANTED,
+                &patient,
+                record_id,
+            );
+            events::publish_record_access_granted(
+                &env,
+                patient.clone(),
+                grantee_user.clone(),
+                record_id,
+                level.clone(),
+                duration_seconds,
+                share.expires_at,
+            );
+        }
+
+        let patient_name = env
+            .storage()
+            .persistent()
+            .get::<_, User>(&(symbol_short!("USER"), patient.clone()))
+            .map(|user| user.name);
+        events::publish_user_share(&env, &share, patient_name, grantee.name);
+        Ok(())
+    }
+
+    /// Get the unexpired shares the patient has given through
+    /// `share_with_user`. Records whose grant has since been revoked are
+    /// left out, as are shares with none left. Patient only.
+    pub fn get_shares_given(env: Env, patient: Address) -> Result<Vec<UserShare>, ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+
+        let mut shares = Vec::new(&env);
+        for share in user_share::get_given(&env, &patient).iter() {
+            if let Some(share) = Self::active_share(&env, share) {
+                shares.push_back(share);
+            }
+        }
+        Ok(shares)
+    }
+
+    /// Get the unexpired shares other patients have given `user`, filtered
+    /// like `get_shares_given`. The user only.
+    pub fn get_shares_received(env: Env, user: Address) -> Result<Vec<UserShare>, ContractError> {
+        Self::require_initialized(&env)?;
+        user.require_auth();
+
+        let mut shares = Vec::new(&env);
+        for sharer in user_share::get_sharers(&env, &user).iter() {
+            for share in user_share::get_given(&env, &sharer).iter() {
+                if share.grantee != user {
+                    continue;
+                }
+                if let Some(share) = Self::active_share(&env, share) {
+                    shares.push_back(share);
+                }
+            }
+        }
+        Ok(shares)
+    }
+
+    /// Revoke record-level access for a specific record.
+    pub fn revoke_record_access(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+        record_id: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+        let record_key = (symbol_short!("RECORD"), record_id);
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&record_key)
+            .ok_or(ContractError::RecordNotFound)?;
+        if record.patient != patient {
+            return Self::unauthorized(&env, &patient, "revoke_record_access", "record_owner");
+        }
+
+        inspect::remove_record_grantee(&env, record_id, &grantee);
+        grant_revision::bump_revision(&env, &patient, &grantee);
+        compliance::log(
+            &env,
+            compliance::REVOKE,
+            &patient,
+            &patient,
+            Some(&grantee),
+            record_id,
+        );
+        let key = (symbol_short!("REC_ACC"), record_id, grantee);
+        env.storage().persistent().remove(&key);
+        activity::log(
+            &env,
+            &patient,
+            activity::ACCESS_REVOKED,
+            &patient,
+            record_id,
+        );
+        Ok(())
+    }
+
+    /// Cap how long `grantee` may reach one of the patient's records
+    /// through their grants, whatever those grants allow. An `expires_at`
+    /// already in the past excludes the record outright. Patient only.
+    pub fn set_record_access_override(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+        record_id: u64,
+        expires_at: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if record.patient != patient {
+            return Self::unauthorized(
+                &env,
+                &patient,
+                "set_record_access_override",
+                "record_owner",
+            );
+        }
+        if grantee == patient {
+            return Err(ContractError::InvalidInput);
+        }
+
+        record_override::set(
+            &env,
+            &RecordAccessOverride {
+                patient: patient.clone(),
+                grantee: grantee.clone(),
+                record_id,
+                expires_at,
+                set_at: env.ledger().timestamp(),
+            },
+        );
+        grant_revision::bump_revision(&env, &patient, &grantee);
+        events::publish_record_override_set(&env, patient, grantee, record_id, expires_at);
+        Ok(())
+    }
+
+    /// Remove a per-record access override, so the grantee's grants apply
+    /// to the record in full again. Patient only.
+    pub fn clear_record_access_override(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+        record_id: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+        let record_override = record_override::get(&env, record_id, &grantee)
+            .ok_or(ContractError::AccessOverrideNotFound)?;
+        if record_override.patient != patient {
+            return Self::unauthorized(
+                &env,
+                &patient,
+                "clear_record_access_override",
+                "record_owner",
+            );
+        }
+
+        record_override::clear(&env, &patient, record_id, &grantee);
+        grant_revision::bump_revision(&env, &patient, &grantee);
+        events::publish_record_override_cleared(&env, patient, grantee, record_id);
+        Ok(())
+    }
+
+    /// Resolve the access `grantee` holds to one of `patient`'s records
+    /// through grants: the stronger of the patient-wide and record-level
+    /// grant, capped by any per-record override. For debugging access
+    /// questions; readable by the patient, the grantee, a guardian the
+    /// patient delegated `ManageAccess` to, and admins.
+    pub fn get_effective_access(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        grantee: Address,
+        record_id: u64,
+    ) -> Result<EffectiveAccess, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if caller != patient
+            && caller != grantee
+            && !rbac::has_delegated_permission(&env, &patient, &caller, &Permission::ManageAccess)
+            && !Self::has_clinical_admin_access(&env, &caller, &AdminTier::OperatorAdmin)
+        {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "get_effective_access",
+                "patient_or_grantee_or_guardian_or_admin",
+            );
+        }
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if record.patient != patient {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let detail = Self::check_access_detailed(env.clone(), patient.clone(), grantee.clone());
+        let mut resolved = detail
+            .filter(|detail| detail.level != AccessLevel::None)
+            .map(|detail| (detail.level, detail.expires_at));
+        let record_level = Self::check_record_access(env.clone(), record_id, grantee.clone());
+        let record_grant = env
+            .storage()
+            .persistent()
+            .get::<_, AccessGrant>(&(symbol_short!("REC_ACC"), record_id, grantee.clone()))
+            .filter(|_| record_level != AccessLevel::None);
+        if let Some(grant) = record_grant {
+            let stronger = match &resolved {
+                Some((level, expires_at)) => {
+                    !access::level_satisfies(&record_level, level)
+                        || (record_level == *level && grant.expires_at > *expires_at)
+                }
+                None => true,
+            };
+            if stronger {
+                resolved = Some((record_level, grant.expires_at));
+            }
+        }
+
+        let override_expires_at = record_override::get(&env, record_id, &grantee)
+            .map(|record_override| record_override.expires_at);
+        let (level, expires_at) = match resolved {
+            Some(_) if record_override::excludes(&env, record_id, &grantee) => {
+                (AccessLevel::None, None)
+            }
+            Some((level, expires_at)) => {
+                let capped = override_expires_at.map_or(expires_at, |cap| expires_at.min(cap));
+                (level, Some(capped))
+            }
+            None => (AccessLevel::None, None),
+        };
+        Ok(EffectiveAccess {
+            level,
+            expires_at,
+            override_expires_at,
+        })
+    }
+
+    /// Grant consent for a grantee.
+    pub fn grant_consent(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+        consent_type: ConsentType,
+        duration_seconds: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+        if duration_seconds == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+        let now = env.ledger().timestamp();
+        let consent = ConsentGrant {
+            patient: patient.clone(),
+            grantee: grantee.clone(),
+            consent_type: consent_type.clone(),
+            granted_at: now,
+            expires_at: now.saturating_add(duration_seconds),
+            revoked: false,
+        };
+        let key = consent_key(&patient, &grantee);
+        env.storage().persistent().set(&key, &consent);
+        extend_ttl_access_key(&env, &key);
+        consent_proof::clear_withdrawn_at(&env, &patient, &grantee);
+        grant_revision::bump_revision(&env, &patient, &grantee);
+        events::publish_consent_granted(&env, patient, grantee, consent_type, consent.expires_at);
+        Ok(())
+    }
+
+    /// Revoke previously granted consent.
+    pub fn revoke_consent(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+        Self::withdraw_consent(&env, &patient, &grantee);
+        events::publish_consent_revoked(&env, patient, grantee);
+        Ok(())
+    }
+
+    /// Flags the patient's consent to `grantee` revoked. The entry is kept
+    /// so the consent can still be proven. Returns false if there was no
+    /// unrevoked consent.
+    fn withdraw_consent(env: &Env, patient: &Address, grantee: &Address) -> bool {
+        let key = consent_key(patient, grantee);
+        let mut consent = match env.storage().persistent().get::<_, ConsentGrant>(&key) {
+            Some(consent) if !consent.revoked => consent,
+            _ => return false,
+        };
+        consent.revoked = true;
+        env.storage().persistent().set(&key, &consent);
+        let now = env.ledger().timestamp();
+        consent_proof::set_withdrawn_at(env, patient, grantee, now);
+        grant_revision::bump_revision(env, patient, grantee);
+        true
+    }
+
+    /// Get a proof that `patient` consented to `provider`: the consent's
+    /// scope, when it was given and, if so, withdrawn, with a digest an
+    /// off-chain verifier can recompute from those fields (see
+    /// [`ConsentProofFields`]). Readable by the patient and admins.
+    pub fn get_consent_proof(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        provider: Address,
+    ) -> Result<ConsentProof, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if caller != patient
+            && !Self::has_clinical_admin_access(&env, &caller, &AdminTier::OperatorAdmin)
+        {
+            return Self::unauthorized(&env, &caller, "get_consent_proof", "patient_or_admin");
+        }
+        let consent: ConsentGrant = env
+            .storage()
+            .persistent()
+            .get(&consent_key(&patient, &provider))
+            .ok_or(ContractError::ConsentNotFound)?;
+        Ok(consent_proof::prove(&env, &consent))
+    }
+
+    /// Export the patient's access-control configuration for a wallet
+    /// backup: grants with their purposes, consents, pending grants,
+    /// guardians, emergency contact, freeze and approval setting. Records
+    /// are not included. When the patient's grantee list is longer than
+    /// `access_export::MAX_EXPORT_GRANTS`, `next_grant` is set and the
+    /// remaining grants come from `export_access_grants_page`.
+    ///
+    /// Readable by the patient and by a guardian the patient delegated
+    /// `ManageAccess` to.
+    pub fn export_access_state(
+        env: Env,
+        caller: Address,
+        patient: Address,
+    ) -> Result<AccessStateExport, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        Self::require_patient_or_guardian(&env, &caller, &patient, "export_access_state")?;
+
+        let page = Self::access_grant_page(&env, &patient, 0);
+        let mut guardians = Vec::new(&env);
+        for delegatee in rbac::get_delegatees(&env, &patient).iter() {
+            if rbac::has_delegated_permission(&env, &patient, &delegatee, &Permission::ManageAccess)
+            {
+                guardians.push_back(delegatee);
+            }
+        }
+
+        let freeze = access_freeze::get(&env, &patient);
+        Ok(AccessStateExport {
+            exported_at: env.ledger().timestamp(),
+            grants: page.grants,
+            consents: page.consents,
+            pending_grants: approval::get_pending_grants(&env, &patient),
+            guardians,
+            emergency_contact: Self::emergency_contact_of(&env, &patient),
+            frozen: freeze.is_some(),
+            freeze_allows_emergency: freeze.as_ref().is_some_and(|freeze| freeze.allow_emergency),
+            frozen_at: freeze.map_or(0, |freeze| freeze.frozen_at),
+            approval_required: approval::is_approval_required(&env, &patient),
+            next_grant: page.next,
+            patient,
+        })
+    }
+
+    /// The grants and consents of an `AccessStateExport` from grantee list
+    /// position `start` on, for patients with more grantees than one export
+    /// holds. Same access rules as `export_access_state`.
+    pub fn export_access_grants_page(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        start: u32,
+    ) -> Result<AccessGrantPage, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        Self::require_patient_or_guardian(&env, &caller, &patient, "export_access_grants_page")?;
+        Ok(Self::access_grant_page(&env, &patient, start))
+    }
+
+    /// Compare an export against `patient`'s current state, typically the
+    /// new wallet after a migration, and list the entries that would need
+    /// re-creating. Grants count as present only at the exported level.
+    /// Grants and consents from later pages can be appended to the
+    /// export's lists before calling.
+    pub fn validate_access_state(
+        env: Env,
+        patient: Address,
+        export: AccessStateExport,
+    ) -> Vec<AccessStateGap> {
+        let mut gaps = Vec::new(&env);
+
+        for detail in export.grants.iter() {
+            let key = (
+                symbol_short!("ACCESS"),
+                patient.clone(),
+                detail.grantee.clone(),
+            );
+            let present = env
+                .storage()
+                .persistent()
+                .get::<_, AccessGrant>(&key)
+                .is_some_and(|grant| {
+                    !expiry::is_expired(&env, grant.expires_at) && grant.level == detail.level
+                });
+            if !present {
+                gaps.push_back(AccessStateGap::Grant(detail.grantee));
+            }
+        }
+        for consent in export.consents.iter() {
+            if !has_active_consent(&env, &patient, &consent.grantee) {
+                gaps.push_back(AccessStateGap::Consent(consent.grantee));
+            }
+        }
+        for pending in export.pending_grants.iter() {
+            let present = approval::get_pending_grant(&env, &patient, &pending.grantee)
+                .is_some_and(|grant| !expiry::is_expired(&env, grant.expires_at));
+            if !present {
+                gaps.push_back(AccessStateGap::PendingGrant(pending.grantee));
+            }
+        }
+        for guardian in export.guardians.iter() {
+            if !rbac::has_delegated_permission(&env, &patient, &guardian, &Permission::ManageAccess)
+            {
+                gaps.push_back(AccessStateGap::Guardian(guardian));
+            }
+        }
+        if export.emergency_contact != OptionalEmergencyContact::None
+            && export.emergency_contact != Self::emergency_contact_of(&env, &patient)
+        {
+            gaps.push_back(AccessStateGap::EmergencyContact);
+        }
+        if export.frozen && !access_freeze::is_frozen(&env, &patient) {
+            gaps.push_back(AccessStateGap::Freeze);
+        }
+        if export.approval_required && !approval::is_approval_required(&env, &patient) {
+            gaps.push_back(AccessStateGap::ApprovalRequired);
+        }
+        gaps
+    }
+
+    /// Fails unless `caller` is the patient or holds `ManageAccess` by the
+    /// patient's delegation.
+    fn require_patient_or_guardian(
+        env: &Env,
+        caller: &Address,
+        patient: &Address,
+        action: &str,
+    ) -> Result<(), ContractError> {
+        if caller != patient
+            && !rbac::has_delegated_permission(env, patient, caller, &Permission::ManageAccess)
+        {
+            return Self::unauthorized(env, caller, action, "patient_or_guardian");
+        }
+        Ok(())
+    }
+
+    /// Unexpired grants and active consents for up to
+    /// `access_export::MAX_EXPORT_GRANTS` entries of the patient's grantee
+    /// list from position `start`. Grants report their stored level, not
+    /// the effective one, so a freeze does not hide them.
+    fn access_grant_page(env: &Env, patient: &Address, start: u32) -> AccessGrantPage {
+        let grantees = grant_index::get_list(env, patient);
+        let end = start
+            .saturating_add(access_export::MAX_EXPORT_GRANTS)
+            .min(grantees.len());
+
+        let mut grants = Vec::new(env);
+        let mut consents = Vec::new(env);
+        for index in start..end {
+            let grantee = grantees.get_unchecked(index);
+            let key = (symbol_short!("ACCESS"), patient.clone(), grantee.clone());
+            if let Some(grant) = env.storage().persistent().get::<_, AccessGrant>(&key) {
+                if !expiry::is_expired(env, grant.expires_at) {
+                    grants.push_back(AccessGrantDetail {
+                        patient: patient.clone(),
+                        grantee: grantee.clone(),
+                        level: grant.level,
+                        purpose: purpose::get_purpose(env, patient, &grantee),
+                        created_by: grant_creator::get_creator(env, patient, &grantee),
+                        granted_at: grant.granted_at,
+                        expires_at: grant.expires_at,
+                        is_contract_grantee: contract_grantee::is_marked(env, &grantee),
+                    });
+                }
+            }
+            if let Some(consent) = env
+                .storage()
+                .persistent()
+                .get::<_, ConsentGrant>(&consent_key(patient, &grantee))
+            {
+                if !consent.revoked && !expiry::is_expired(env, consent.expires_at) {
+                    consents.push_back(consent);
+                }
+            }
+        }
+
+        AccessGrantPage {
+            grants,
+            consents,
+            next: (end < grantees.len()).then_some(end),
+        }
+    }
+
+    /// `share` narrowed to the records its grantee can still reach through
+    /// the grant it created, or `None` once it has lapsed or none remain.
+    fn active_share(env: &Env, mut share: UserShare) -> Option<UserShare> {
+        if expiry::is_expired(env, share.expires_at) {
+            return None;
+        }
+        let mut record_ids = Vec::new(env);
+        for record_id in share.record_ids.iter() {
+            let key = (symbol_short!("REC_ACC"), record_id, share.grantee.clone());
+            if let Some(grant) = env.storage().persistent().get::<_, AccessGrant>(&key) {
+                if grant.patient == share.patient && !expiry::is_expired(env, grant.expires_at) {
+                    record_ids.push_back(record_id);
+                }
+            }
+        }
+        if record_ids.is_empty() {
+            return None;
+        }
+        share.record_ids = record_ids;
+        Some(share)
+    }
+
+    /// The emergency contact on the patient's profile, if any.
+    fn emergency_contact_of(env: &Env, patient: &Address) -> OptionalEmergencyContact {
+        env.storage()
+            .persistent()
+            .get::<_, PatientProfile>(&(symbol_short!("PAT_PROF"), patient.clone()))
+            .map(|profile| profile.emergency_contact)
+            .unwrap_or(OptionalEmergencyContact::None)
+    }
+
+    /// Revoke access. While a revocation grace window is configured, the
+    /// grant keeps working until the window ends and the patient may call
+    /// `cancel_revocation` in the meantime; otherwise it is revoked at once.
+    pub fn revoke_access(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+
+        let grace = revocation::get_grace(&env);
+        let scheduled = if grace > 0 {
+            revocation::schedule(&env, &patient, &grantee, grace)
+        } else {
+            None
+        };
+        let pending = match scheduled {
+            Some(pending) => pending,
+            None => return Self::revoke_access_now(&env, &patient, &grantee, &patient, None),
+        };
+        grant_revision::bump_revision(&env, &patient, &grantee);
+        expiry_beacon::index(&env, &patient, &grantee, pending.effective_at);
+        compliance::log(
+            &env,
+            compliance::REVOKE,
+            &patient,
+            &patient,
+            Some(&grantee),
+            0,
+        );
+
+        let audit_entry = audit::create_audit_entry(
+            &env,
+            patient.clone(),
+            patient.clone(),
+            None,
+            AccessAction::RevokeAccess,
+            AccessResult::Success,
+            Some(String::from_str(&env, "Revocation scheduled")),
+        )?;
+        audit::add_audit_entry(&env, &audit_entry);
+        events::publish_audit_log_entry(&env, &audit_entry);
+
+        activity::log(&env, &patient, activity::ACCESS_REVOKED, &patient, 0);
+        events::publish_revocation_scheduled(&env, patient, grantee, pending.effective_at);
+
+        Ok(())
+    }
+
+    /// Revoke access at once, bypassing any revocation grace window, e.g.
+    /// when the grantee poses a risk to the patient.
+    pub fn revoke_access_immediate(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+
+        Self::revoke_access_now(&env, &patient, &grantee, &patient, None)?;
+        events::publish_access_revoked_immediately(&env, patient, grantee);
+        Ok(())
+    }
+
+    /// Revoke a grant the caller made on the patient's behalf as a holder
+    /// of the patient's `Full` grant. The caller must still hold `Full`.
+    /// Takes effect at once, without the patient's revocation grace window.
+    pub fn revoke_created_grant(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        grantee: Address,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+
+        if auth::full_grant_expiry(&env, &patient, &caller).is_none() {
+            return Self::unauthorized(&env, &caller, "revoke_created_grant", "access:Full");
+        }
+        let key = (symbol_short!("ACCESS"), patient.clone(), grantee.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(ContractError::AccessGrantNotFound);
+        }
+        if grant_creator::get_creator(&env, &patient, &grantee) != caller {
+            return Self::unauthorized(&env, &caller, "revoke_created_grant", "grant_creator");
+        }
+        Self::revoke_access_now(&env, &patient, &grantee, &caller, None)
+    }
+
+    /// Revoke a grant at once and leave the grantee a handoff note: the
+    /// hash of an off-chain care summary. The hash goes into the audit
+    /// entry and the revocation events, and stays listed for the grantee
+    /// under `get_handoff_notes` after its access to the records is gone.
+    /// Callable by the patient or a guardian the patient delegated
+    /// `ManageAccess` to. Takes effect without the revocation grace window.
+    pub fn revoke_access_with_note(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        grantee: Address,
+        note_hash: String,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+
+        if caller != patient
+            && !rbac::has_delegated_permission(&env, &patient, &caller, &Permission::ManageAccess)
+        {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "revoke_access_with_note",
+                "patient_or_permission:ManageAccess",
+            );
+        }
+        validation::validate_data_hash(&note_hash)?;
+        let key = (symbol_short!("ACCESS"), patient.clone(), grantee.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(ContractError::AccessGrantNotFound);
+        }
+
+        Self::revoke_access_now(&env, &patient, &grantee, &caller, Some(&note_hash))?;
+        let note = HandoffNote {
+            patient,
+            grantee,
+            note_hash,
+            revoked_by: caller,
+            revoked_at: env.ledger().timestamp(),
+        };
+        handoff_note::add_note(&env, &note);
+        events::publish_access_revoked_with_note(&env, note);
+        Ok(())
+    }
+
+    /// Revoke every grant the patient holds with a marked integration
+    /// contract, leaving grants to people in place. Takes effect without
+    /// the revocation grace window. Returns how many grants were revoked.
+    pub fn revoke_all_contract_grants(env: Env, patient: Address) -> Result<u32, ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+
+        let mut revoked = 0u32;
+        for grantee in grant_index::get_list(&env, &patient).iter() {
+            if !contract_grantee::is_marked(&env, &grantee)
+                || !grant_index::has_grant(&env, &patient, &grantee)
+            {
+                continue;
+            }
+            Self::revoke_access_now(&env, &patient, &grantee, &patient, None)?;
+            revoked = revoked.saturating_add(1);
+        }
+        Ok(revoked)
+    }
+
+    /// Handoff notes left for `grantee` when patients revoked its access,
+    /// oldest first. Only the grantee may list them.
+    pub fn get_handoff_notes(
+        env: Env,
+        grantee: Address,
+    ) -> Result<Vec<HandoffNote>, ContractError> {
+        Self::require_initialized(&env)?;
+        grantee.require_auth();
+        Ok(handoff_note::get_notes(&env, &grantee))
+    }
+
+    fn revoke_access_now(
+        env: &Env,
+        patient: &Address,
+        grantee: &Address,
+        revoked_by: &Address,
+        note_hash: Option<&String>,
+    ) -> Result<(), ContractError> {
+        let key = (symbol_short!("ACCESS"), patient.clone(), grantee.clone());
+        env.storage().persistent().remove(&key);
+        purpose::remove_purpose(env, patient, grantee);
+        grant_creator::remove_creator(env, patient, grantee);
+        write_scope::set_append_only(env, patient, grantee, false);
+        history_scope::set_history_hidden(env, patient, grantee, false);
+        revocation::clear_pending(env, patient, grantee);
+        grant_revision::bump_revision(env, patient, grantee);
+        remove_from_grantee_index(env, grantee, patient);
+        compliance::log(
+            env,
+            compliance::REVOKE,
+            revoked_by,
+            patient,
+            Some(grantee),
+            0,
+        );
+
+        // Log successful access revoke
+        let audit_entry = audit::create_audit_entry(
+            env,
+            revoked_by.clone(),
+            patient.clone(),
+            None,
+            AccessAction::RevokeAccess,
+            AccessResult::Success,
+            note_hash.cloned(),
+        )?;
+        audit::add_audit_entry(env, &audit_entry);
+        events::publish_audit_log_entry(env, &audit_entry);
+
+        activity::log(env, patient, activity::ACCESS_REVOKED, revoked_by, 0);
+        events::publish_access_revoked(env, patient.clone(), grantee.clone());
+
+        Ok(())
+    }
+
+    /// Cancel a revocation still inside its grace window, restoring the
+    /// grant's original expiry.
+    pub fn cancel_revocation(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+
+        let pending = revocation::cancel(&env, &patient, &grantee)?;
+        grant_revision::bump_revision(&env, &patient, &grantee);
+        events::publish_revocation_cancelled(&env, patient, grantee, pending.original_expires_at);
+        Ok(())
+    }
+
+    /// The revocation scheduled for a grant, if one is still pending or has
+    /// taken effect without the grant being purged yet.
+    pub fn get_pending_revocation(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+    ) -> Option<PendingRevocation> {
+        revocation::get_pending(&env, &patient, &grantee)
+    }
+
+    /// Delete up to `limit` entries of `category` whose deadline has passed,
+    /// walking that category's expiry-day buckets from day `cursor` (0 for
+    /// the oldest). An entry is collected once the UTC day its deadline
+    /// falls on, plus the expiry grace window, has ended; live entries are
+    /// left alone. Call again with the returned `next_cursor` until it is
+    /// `None`. Requires OperatorAdmin. Fails with `JobAlreadyRunning` while
+    /// a `PEND_GC` maintenance job is walking the indexes.
+    pub fn gc_pending(
+        env: Env,
+        caller: Address,
+        category: PendingCategory,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<PendingGcResult, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::OperatorAdmin) {
+            return Self::unauthorized(&env, &caller, "gc_pending", "admin_tier:OperatorAdmin");
+        }
+        if limit == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+        if jobs::get_active(&env, &events::admin_ops::PENDING_GC).is_some() {
+            return Err(ContractError::JobAlreadyRunning);
+        }
+
+        Ok(Self::collect_pending(
+            &env, &caller, &category, cursor, limit,
+        ))
+    }
+
+    /// The body of `gc_pending`, once the caller is authorized.
+    fn collect_pending(
+        env: &Env,
+        caller: &Address,
+        category: &PendingCategory,
+        cursor: u32,
+        limit: u32,
+    ) -> PendingGcResult {
+        let open = pending_gc::first_open_day(env);
+        let result = pending_gc::collect(env, category, cursor, limit);
+        events::publish_pending_gc(
+            env,
+            category.clone(),
+            caller.clone(),
+            result.removed,
+            result.next_cursor,
+        );
+        events::publish_admin_op(
+            env,
+            events::admin_ops::PENDING_GC,
+            caller.clone(),
+            env.current_contract_address(),
+            cursor.min(open),
+            result.next_cursor.unwrap_or(open),
+            result.removed,
+        );
+        result
+    }
+
+    /// Whether `caller` may drive jobs over `params`: the tier the matching
+    /// maintenance endpoint requires.
+    fn has_job_access(env: &Env, caller: &Address, params: &JobParams) -> bool {
+        match params {
+            JobParams::PendingGc(_) => {
+                Self::has_admin_access(env, caller, &AdminTier::OperatorAdmin)
+            }
+            JobParams::GranteeSweep(_) => {
+                Self::has_clinical_admin_access(env, caller, &AdminTier::ContractAdmin)
+            }
+        }
+    }
+
+    /// Start a maintenance job over `params` and return its ID; drive it
+    /// with `run_job`. `kind` must be the `admin_ops` symbol for `params`.
+    /// Requires the tier of the matching maintenance endpoint. Fails with
+    /// `JobAlreadyRunning` while another job of the same kind is running.
+    pub fn start_job(
+        env: Env,
+        caller: Address,
+        kind: Symbol,
+        params: JobParams,
+    ) -> Result<u64, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_job_access(&env, &caller, &params) {
+            return Self::unauthorized(&env, &caller, "start_job", "job_admin_tier");
+        }
+        if kind != jobs::kind_of(&params) {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let job = jobs::start(&env, &params, &caller)?;
+        events::publish_job_updated(&env, &job, caller);
+        Ok(job.job_id)
+    }
+
+    /// Process up to `limit` more items of a running job, resuming from
+    /// its stored cursor. The job is `Completed` once its work runs out. A
+    /// run that fails leaves the job where it was, so it can be retried.
+    pub fn run_job(
+        env: Env,
+        caller: Address,
+        job_id: u64,
+        limit: u32,
+    ) -> Result<MaintenanceJob, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        let mut job = jobs::get_running(&env, job_id)?;
+        if !Self::has_job_access(&env, &caller, &job.params) {
+            return Self::unauthorized(&env, &caller, "run_job", "job_admin_tier");
+        }
+        if limit == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let finished = match &job.params {
+            JobParams::PendingGc(category) => {
+                let result = Self::collect_pending(&env, &caller, category, job.cursor, limit);
+                job.processed = job.processed.saturating_add(result.removed);
+                match result.next_cursor {
+                    Some(cursor) => {
+                        job.cursor = cursor;
+                        false
+                    }
+                    None => true,
+                }
+            }
+            JobParams::GranteeSweep(grantee) => {
+                let result = Self::sweep_grantee(&env, &caller, grantee, limit)?;
+                job.processed = job.processed.saturating_add(result.revoked);
+                result.remaining == 0
+            }
+        };
+        if finished {
+            job.status = JobStatus::Completed;
+        }
+        job.updated_at = env.ledger().timestamp();
+        jobs::save(&env, &job);
+        events::publish_job_updated(&env, &job, caller);
+        Ok(job)
+    }
+
+    /// Stop a running job where it is, freeing its kind for a new job.
+    /// Work already done is kept.
+    pub fn cancel_job(env: Env, caller: Address, job_id: u64) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        let mut job = jobs::get_running(&env, job_id)?;
+        if !Self::has_job_access(&env, &caller, &job.params) {
+            return Self::unauthorized(&env, &caller, "cancel_job", "job_admin_tier");
+        }
+
+        job.status = JobStatus::Cancelled;
+        job.updated_at = env.ledger().timestamp();
+        jobs::save(&env, &job);
+        events::publish_job_updated(&env, &job, caller);
+        Ok(())
+    }
+
+    /// A maintenance job and its progress, if it exists.
+    pub fn get_job(env: Env, job_id: u64) -> Option<MaintenanceJob> {
+        jobs::get_job(&env, job_id)
+    }
+
+    /// The ID of the running job of `kind`, if any.
+    pub fn get_active_job(env: Env, kind: Symbol) -> Option<u64> {
+        jobs::get_active(&env, &kind)
+    }
+
+    /// Purge all expired access grants for a given patient, along with any
+    /// stored with a `None` level.
+    ///
+    /// Only the patient themselves or a InfraAdmin may call this.
+    /// Returns the number of grants removed.
+    pub fn purge_expired_grants(
+        env: Env,
+        caller: Address,
+        patient: Address,
+    ) -> Result<u32, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+
+        let is_patient = caller == patient;
+        let is_admin = rbac::has_infra_admin(&env, &caller);
+        if !is_patient && !is_admin {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "purge_expired_grants",
+                "patient_or_permission:InfraAdmin",
+            );
+        }
+
+        let list_key = (symbol_short!("ACC_LST"), patient.clone());
+        let grantees: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&list_key)
+            .unwrap_or(Vec::new(&env));
+
+        let mut remaining = Vec::new(&env);
+        let mut purged: u32 = 0;
+
+        for i in 0..grantees.len() {
+            if let Some(grantee) = grantees.get(i) {
+                let access_key = (symbol_short!("ACCESS"), patient.clone(), grantee.clone());
+
+                match env
+                    .storage()
+                    .persistent()
+                    .get::<_, AccessGrant>(&access_key)
+                {
+                    // Grants with a `None` level are no longer accepted, but
+                    // may predate that check
+                    Some(grant)
+                        if expiry::is_expired(&env, grant.expires_at)
+                            || grant.level == AccessLevel::None =>
+                    {
+                        env.storage().persistent().remove(&access_key);
+                        purpose::remove_purpose(&env, &patient, &grantee);
+                        grant_creator::remove_creator(&env, &patient, &grantee);
+                        write_scope::set_append_only(&env, &patient, &grantee, false);
+                        history_scope::set_history_hidden(&env, &patient, &grantee, false);
+                        revocation::clear_pending(&env, &patient, &grantee);
+                        grant_revision::bump_revision(&env, &patient, &grantee);
+                        remove_from_grantee_index(&env, &grantee, &patient);
+                        if expiry::is_expired(&env, grant.expires_at) {
+                            events::publish_access_expired(
+                                &env,
+                                patient.clone(),
+                                grantee,
+                                grant.expires_at,
+                            );
+                        }
+                        purged += 1;
+                    }
+                    Some(_) => {
+                        // Grant still active — keep in the list.
+                        remaining.push_back(grantee);
+                    }
+                    None => {
+                        // Already removed — nothing to purge, drop from list.
+                        remove_from_grantee_index(&env, &grantee, &patient);
+                    }
+                }
+            }
+        }
+
+        // Update the grantee list to only keep active entries.
+        if remaining.is_empty() {
+            env.storage().persistent().remove(&list_key);
+        } else {
+            env.storage().persistent().set(&list_key, &remaining);
+        }
+
+        events::publish_admin_op(
+            &env,
+            events::admin_ops::PURGE_GRANTS,
+            caller,
+            patient,
+            0,
+            grantees.len(),
+            purged,
+        );
+
+        Ok(purged)
+    }
+
+    /// Turn expiry beacons on or off. While on, every new grant is filed
+    /// under its hourly expiry bucket so `emit_expiry_beacons` can announce
+    /// it once it lapses. Requires ContractAdmin. Off by default.
+    pub fn set_expiry_beacons(
+        env: Env,
+        caller: Address,
+        enabled: bool,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_expiry_beacons",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        expiry_beacon::set_enabled(&env, enabled);
+        Ok(())
+    }
+
+    pub fn is_expiry_beacons_enabled(env: Env) -> bool {
+        expiry_beacon::is_enabled(&env)
+    }
+
+    /// Turn the anonymized monthly record counts on or off. While off, new
+    /// records are not counted and `get_period_counts` fails with
+    /// `FeatureDisabled`; months already counted keep their totals.
+    /// Requires ContractAdmin. On by default.
+    pub fn set_period_stats_enabled(
+        env: Env,
+        caller: Address,
+        enabled: bool,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_period_stats_enabled",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        period_stats::set_enabled(&env, enabled);
+        Ok(())
+    }
+
+    pub fn is_period_stats_enabled(env: Env) -> bool {
+        period_stats::is_enabled(&env)
+    }
+
+    /// Count the records of `record_type` created in each month from
+    /// `from_bucket` to `to_bucket`, both `YYYYMM` and inclusive, as
+    /// `(bucket, count)` pairs. Imported records count toward their
+    /// original month. Holds no patient or provider identifiers, so it is
+    /// public. At most `period_stats::MAX_PERIOD_BUCKETS` months per call.
+    pub fn get_period_counts(
+        env: Env,
+        record_type: RecordType,
+        from_bucket: u32,
+        to_bucket: u32,
+    ) -> Result<Vec<(u32, u64)>, ContractError> {
+        Self::require_initialized(&env)?;
+        if !period_stats::is_enabled(&env) {
+            return Err(ContractError::FeatureDisabled);
+        }
+        period_stats::get_counts(&env, &record_type, from_bucket, to_bucket)
+    }
+
+    /// Set how many counter shards new record IDs are spread over, from 1
+    /// to `record_counter::MAX_COUNTER_SHARDS`. Existing IDs are
+    /// unaffected. Requires ContractAdmin. Defaults to 8.
+    pub fn set_counter_shards(env: Env, caller: Address, shards: u32) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
         caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_counter_shards",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        if shards == 0 || shards > record_counter::MAX_COUNTER_SHARDS {
+            return Err(ContractError::InvalidInput);
+        }
+        record_counter::set_shards(&env, shards);
+        Ok(())
+    }
 
-        if !whitelist::check_whitelist_access(&env, &caller) {
-            return Self::unauthorized(&env, &caller, "add_record", "whitelisted_caller");
+    pub fn get_counter_shards(env: Env) -> u32 {
+        record_counter::get_shards(&env)
+    }
+
+    /// The grants filed under expiry `bucket` (`expires_at / 3600`) that
+    /// have not been beaconed yet.
+    pub fn get_expiry_bucket(env: Env, bucket: u64) -> Vec<ExpiryBeaconEntry> {
+        expiry_beacon::get_bucket(&env, bucket)
+    }
+
+    /// Publish an `EXP_BCN` event for each expired grant filed under a past
+    /// expiry `bucket`, at most `expiry_beacon::MAX_BEACONS_PER_CALL`
+    /// entries per call. Each grant is beaconed once; grants themselves are
+    /// never changed. Anyone may call this, e.g. a keeper; fails with
+    /// `InvalidInput` for a bucket that has not ended yet.
+    pub fn emit_expiry_beacons(
+        env: Env,
+        caller: Address,
+        bucket: u64,
+    ) -> Result<ExpiryBeaconResult, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !expiry_beacon::is_past(&env, bucket) {
+            return Err(ContractError::InvalidInput);
         }
+        Ok(expiry_beacon::emit(&env, bucket))
+    }
 
-        Self::enforce_rate_limit(&env, &caller)?;
+    /// Rebuild the patient's grantee list and the reverse index entries of
+    /// each listed grantee from the stored grants, dropping stale and
+    /// duplicate addresses. Lists longer than
+    /// `grant_index::MAX_COMPACT_INDEX_SIZE` are refused with `IndexTooLarge`.
+    ///
+    /// Only the patient or an OperatorAdmin may call this.
+    pub fn compact_grant_indexes(
+        env: Env,
+        caller: Address,
+        patient: Address,
+    ) -> Result<GrantIndexCompaction, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
 
-        validation::validate_data_hash(&data_hash)?;
+        if caller != patient && !Self::has_admin_access(&env, &caller, &AdminTier::OperatorAdmin) {
+            return Self::unauthorized(&env, &caller, "compact_grant_indexes", "patient_or_admin");
+        }
 
-        // If caller is the provider, unified check covers direct + delegated WriteRecord.
-        // Otherwise, check if this specific provider delegated to the caller.
-        let has_perm = if caller == provider {
-            rbac::has_permission(&env, &caller, &Permission::WriteRecord)
-        } else {
-            rbac::has_delegated_permission(&env, &provider, &caller, &Permission::WriteRecord)
-        };
+        let indexed = grant_index::get_list(&env, &patient).len();
+        let result = grant_index::compact(&env, &patient)?;
+        events::publish_admin_op(
+            &env,
+            events::admin_ops::COMPACT_INDEX,
+            caller,
+            patient,
+            0,
+            indexed,
+            result
+                .grantees_removed
+                .saturating_add(result.reverse_removed)
+                .saturating_add(result.reverse_restored),
+        );
+        Ok(result)
+    }
 
-        // Fall back to SystemAdmin (unified: direct role + any delegation)
-        if !has_perm && !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
-            // Log failed write attempt
-            let audit_entry = audit::create_audit_entry(
-                &env,
-                caller.clone(),
-                patient.clone(),
-                None,
-                AccessAction::Write,
-                AccessResult::Denied,
-                Some(String::from_str(&env, "Insufficient permissions")),
-            );
-            audit::add_audit_entry(&env, &audit_entry);
-            events::publish_audit_log_entry(&env, &audit_entry);
+    /// Whether the patient's grantee list and the matching reverse index
+    /// entries agree with the stored grants.
+    pub fn verify_grant_indexes(env: Env, patient: Address) -> bool {
+        grant_index::verify(&env, &patient)
+    }
 
-            let context = create_error_context(
+    /// Drop duplicate IDs from the patient's record list and restore its
+    /// creation order. Lists longer than
+    /// `patient_index::MAX_REPAIR_INDEX_SIZE` are refused with
+    /// `IndexTooLarge`. Requires OperatorAdmin.
+    pub fn repair_patient_index(
+        env: Env,
+        caller: Address,
+        patient: Address,
+    ) -> Result<PatientIndexRepair, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::OperatorAdmin) {
+            return Self::unauthorized(
                 &env,
-                ContractError::Unauthorized,
-                Some(caller.clone()),
-                Some(String::from_str(&env, "add_record")),
+                &caller,
+                "repair_patient_index",
+                "admin_tier:OperatorAdmin",
             );
-            log_error(
+        }
+
+        let indexed = Self::patient_record_ids(&env, &patient).len();
+        let result = patient_index::repair(&env, &patient)?;
+        events::publish_admin_op(
+            &env,
+            events::admin_ops::REPAIR_INDEX,
+            caller,
+            patient,
+            0,
+            indexed,
+            result
+                .duplicates_removed
+                .saturating_add(result.entries_moved),
+        );
+        Ok(result)
+    }
+
+    /// Check up to `MAX_BOUNDED_ITEMS` entries of the patient's record list,
+    /// from `offset`, against creation order. The list holds each ID once,
+    /// in order, when every window reports `ordered`.
+    pub fn verify_patient_index(env: Env, patient: Address, offset: u32) -> PatientIndexCheck {
+        patient_index::verify(&env, &patient, offset)
+    }
+
+    /// Check the stored state within `scope` against the contract's
+    /// invariants and return the `invariants` symbols of those violated;
+    /// empty when consistent. Meant for operators after migrations and bulk
+    /// operations. Read-only; lists longer than the repair limits are
+    /// refused with `IndexTooLarge`. Requires OperatorAdmin.
+    pub fn check_invariants(
+        env: Env,
+        caller: Address,
+        scope: InvariantScope,
+    ) -> Result<Vec<Symbol>, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::OperatorAdmin) {
+            return Self::unauthorized(
                 &env,
-                ContractError::Unauthorized,
-                Some(caller.clone()),
-                None,
-                None,
+                &caller,
+                "check_invariants",
+                "admin_tier:OperatorAdmin",
             );
-            events::publish_error(&env, ContractError::Unauthorized as u32, context);
+        }
+
+        invariants::check(&env, &scope)
+    }
+
+    /// Get the patients that have granted `grantee` patient-level access.
+    pub fn get_grantee_patients(env: Env, grantee: Address) -> Vec<Address> {
+        get_grantee_index(&env, &grantee)
+    }
+
+    /// Revoke every patient-level grant held by `grantee`, e.g. after a
+    /// provider's key is compromised.
+    ///
+    /// Each grant is revoked as `revoke_access_immediate` would; the
+    /// grantee also leaves each patient's grantee list and the patient's
+    /// consent to it is withdrawn. Processes at most
+    /// `limit` grants per call and emits a revocation event for each. Call
+    /// again while `remaining` is non-zero to finish the sweep.
+    /// Requires at least `ContractAdmin` tier, or ClinicalAdmin.
+    pub fn revoke_all_access_for_grantee(
+        env: Env,
+        caller: Address,
+        grantee: Address,
+        limit: u32,
+    ) -> Result<AccessSweepResult, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+
+        if !Self::has_clinical_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
             return Self::unauthorized(
                 &env,
                 &caller,
-                "add_record",
-                "permission:WriteRecord_or_SystemAdmin",
+                "revoke_all_access_for_grantee",
+                "admin_tier:ContractAdmin",
             );
         }
 
-        // Generate record ID
-        let counter_key = symbol_short!("REC_CTR");
-        let record_id: u64 = env.storage().instance().get(&counter_key).unwrap_or(0) + 1;
-        env.storage().instance().set(&counter_key, &record_id);
+        if limit == 0 {
+            return Err(ContractError::InvalidInput);
+        }
 
-        // Determine current encryption key version (if any) and load master bytes
-        let current_version: Option<String> = env.storage().instance().get(&ENC_CUR);
-        let mut master_bytes: StdVec<u8> = StdVec::new();
-        if let Some(ver) = current_version.clone() {
-            if let Some(sv) = env
+        Self::sweep_grantee(&env, &caller, &grantee, limit)
+    }
+
+    /// The body of `revoke_all_access_for_grantee`, once the caller is
+    /// authorized.
+    fn sweep_grantee(
+        env: &Env,
+        caller: &Address,
+        grantee: &Address,
+        limit: u32,
+    ) -> Result<AccessSweepResult, ContractError> {
+        let patients = get_grantee_index(env, grantee);
+        let reason = String::from_str(env, "Admin revocation sweep");
+        let mut revoked: u32 = 0;
+        let mut remaining = Vec::new(env);
+
+        // Each revocation drops the patient from the grantee's index
+        for patient in patients.iter() {
+            if revoked >= limit {
+                remaining.push_back(patient);
+                continue;
+            }
+
+            Self::revoke_access_now(env, &patient, grantee, caller, Some(&reason))?;
+            remove_from_grantee_list(env, &patient, grantee);
+            Self::withdraw_consent(env, &patient, grantee);
+            revoked = revoked.saturating_add(1);
+        }
+
+        let result = AccessSweepResult {
+            revoked,
+            remaining: remaining.len(),
+        };
+        events::publish_grantee_access_swept(
+            env,
+            grantee.clone(),
+            caller.clone(),
+            revoked,
+            result.remaining,
+        );
+        // Revoked entries leave the index, so each run starts from the front.
+        events::publish_admin_op(
+            env,
+            events::admin_ops::GRANTEE_SWEEP,
+            caller.clone(),
+            grantee.clone(),
+            0,
+            revoked,
+            revoked,
+        );
+
+        Ok(result)
+    }
+
+    /// Move every patient-level grant held by `old_grantee` to
+    /// `new_grantee`, e.g. when a provider rotates their wallet. Both
+    /// addresses must authorize.
+    ///
+    /// Each grant keeps its level, purpose, write scope, per-record
+    /// overrides, `granted_at` and `expires_at`; the patient's consent for
+    /// the old address moves with it. Expired grants, and grants from patients who already grant the
+    /// new address, are dropped and counted in `skipped`. Processes at most
+    /// `limit` grants per call; call again while `remaining` is non-zero.
+    ///
+    /// `confirm` must be `compute_confirmation(MIG_GRNT, old_grantee)`, so
+    /// swapped addresses fail with `ConfirmationMismatch`.
+    pub fn migrate_grantee(
+        env: Env,
+        old_grantee: Address,
+        new_grantee: Address,
+        limit: u32,
+        confirm: BytesN<32>,
+    ) -> Result<GranteeMigrationResult, ContractError> {
+        Self::require_initialized(&env)?;
+        // Checked before authenticating, since the same address cannot
+        // authorize one call twice
+        if limit == 0 || old_grantee == new_grantee {
+            return Err(ContractError::InvalidInput);
+        }
+        old_grantee.require_auth();
+        new_grantee.require_auth();
+        confirmation::require(&env, &confirmation::MIGRATE_GRANTEE, &old_grantee, &confirm)?;
+
+        let patients = get_grantee_index(&env, &old_grantee);
+        let mut migrated: u32 = 0;
+        let mut skipped: u32 = 0;
+        let mut remaining = Vec::new(&env);
+
+        for patient in patients.iter() {
+            if migrated.saturating_add(skipped) >= limit {
+                remaining.push_back(patient);
+                continue;
+            }
+
+            let old_key = (
+                symbol_short!("ACCESS"),
+                patient.clone(),
+                old_grantee.clone(),
+            );
+            let new_key = (
+                symbol_short!("ACCESS"),
+                patient.clone(),
+                new_grantee.clone(),
+            );
+            let grant: Option<AccessGrant> = env.storage().persistent().get(&old_key);
+            let purpose = purpose::get_purpose(&env, &patient, &old_grantee);
+            let created_by = grant_creator::get_creator(&env, &patient, &old_grantee);
+            let append_only = write_scope::is_append_only(&env, &patient, &old_grantee);
+            let history_hidden = history_scope::is_history_hidden(&env, &patient, &old_grantee);
+            env.storage().persistent().remove(&old_key);
+            purpose::remove_purpose(&env, &patient, &old_grantee);
+            grant_creator::remove_creator(&env, &patient, &old_grantee);
+            write_scope::set_append_only(&env, &patient, &old_grantee, false);
+            history_scope::set_history_hidden(&env, &patient, &old_grantee, false);
+            grant_revision::bump_revision(&env, &patient, &old_grantee);
+
+            let list_key = (symbol_short!("ACC_LST"), patient.clone());
+            let mut grantees: Vec<Address> = env
                 .storage()
                 .persistent()
-                .get::<(Symbol, String), String>(&(ENC_KEY, ver.clone()))
+                .get(&list_key)
+                .unwrap_or(Vec::new(&env));
+            if let Some(pos) = grantees.first_index_of(&old_grantee) {
+                grantees.remove(pos);
+            }
+
+            let movable = grant.filter(|grant| {
+                !expiry::is_expired(&env, grant.expires_at)
+                    && !env.storage().persistent().has(&new_key)
+            });
+            let mut grant = match movable {
+                Some(grant) => grant,
+                None => {
+                    env.storage().persistent().set(&list_key, &grantees);
+                    skipped = skipped.saturating_add(1);
+                    continue;
+                }
+            };
+
+            Self::require_grantable(&env, &patient, &new_grantee, &grant.level)?;
+            grant.grantee = new_grantee.clone();
+            env.storage().persistent().set(&new_key, &grant);
+            extend_ttl_access_key(&env, &new_key);
+            purpose::set_purpose(&env, &patient, &new_grantee, &purpose);
+            grant_creator::set_creator(&env, &patient, &new_grantee, &created_by);
+            write_scope::set_append_only(&env, &patient, &new_grantee, append_only);
+            history_scope::set_history_hidden(&env, &patient, &new_grantee, history_hidden);
+            record_override::migrate(&env, &patient, &old_grantee, &new_grantee);
+            revocation::migrate(&env, &patient, &old_grantee, &new_grantee);
+            grant_revision::bump_revision(&env, &patient, &new_grantee);
+            if !grantees.contains(&new_grantee) {
+                grantees.push_back(new_grantee.clone());
+            }
+            env.storage().persistent().set(&list_key, &grantees);
+            add_to_grantee_index(&env, &new_grantee, &patient);
+            expiry_beacon::index(&env, &patient, &new_grantee, grant.expires_at);
+
+            let old_consent = consent_key(&patient, &old_grantee);
+            if let Some(mut consent) = env
+                .storage()
+                .persistent()
+                .get::<_, ConsentGrant>(&old_consent)
             {
-                let hex = sv.to_string();
-                if let Some(bytes) = teye_common::hex_to_bytes(&hex) {
-                    master_bytes = bytes;
+                consent.grantee = new_grantee.clone();
+                env.storage().persistent().remove(&old_consent);
+                let new_consent = consent_key(&patient, &new_grantee);
+                env.storage().persistent().set(&new_consent, &consent);
+                extend_ttl_access_key(&env, &new_consent);
+                consent_proof::clear_withdrawn_at(&env, &patient, &new_grantee);
+                if let Some(withdrawn_at) =
+                    consent_proof::get_withdrawn_at(&env, &patient, &old_grantee)
+                {
+                    consent_proof::clear_withdrawn_at(&env, &patient, &old_grantee);
+                    consent_proof::set_withdrawn_at(&env, &patient, &new_grantee, withdrawn_at);
                 }
             }
-        }
 
-        // Build KeyManager and encrypt the provided data_hash
-        let km = KeyManager::new(master_bytes);
-        let plaintext: StdString = data_hash.to_string();
-        let ciphertext = km.encrypt(None, &plaintext);
-        let stored_hash = String::from_str(&env, &ciphertext);
+            events::publish_grant_migrated(
+                &env,
+                patient,
+                old_grantee.clone(),
+                new_grantee.clone(),
+                grant.expires_at,
+            );
+            migrated = migrated.saturating_add(1);
+        }
 
-        let record = VisionRecord {
-            id: record_id,
-            patient: patient.clone(),
-            provider: provider.clone(),
-            record_type: record_type.clone(),
-            data_hash: stored_hash,
-            key_version: current_version.clone(),
-            created_at: env.ledger().timestamp(),
-            updated_at: env.ledger().timestamp(),
-        };
+        // Processed entries leave the index, so each run starts from the front.
+        let index_key = grantee_index_key(&old_grantee);
+        if remaining.is_empty() {
+            env.storage().persistent().remove(&index_key);
+        } else {
+            env.storage().persistent().set(&index_key, &remaining);
+        }
 
-        let key = (symbol_short!("RECORD"), record_id);
-        env.storage().persistent().set(&key, &record);
-        extend_ttl_u64_key(&env, &key);
+        Ok(GranteeMigrationResult {
+            migrated,
+            skipped,
+            remaining: remaining.len(),
+        })
+    }
 
-        // Add to patient's record list
-        let patient_key = (symbol_short!("PAT_REC"), patient.clone());
-        let mut patient_records: Vec<u64> = env
+    /// Start moving `old_patient`'s chart to `new_patient` after the
+    /// patient lost their wallet. The patient is given a secret out of
+    /// band; `challenge_hash` is its SHA-256.
+    ///
+    /// The recovery can be finalized once `RECOVERY_TIMELOCK_SECONDS` have
+    /// passed, and until then the old address may veto it with
+    /// `cancel_recovery`. `new_patient` must be an unused address. Requires
+    /// ContractAdmin.
+    pub fn initiate_recovery(
+        env: Env,
+        caller: Address,
+        old_patient: Address,
+        new_patient: Address,
+        challenge_hash: BytesN<32>,
+    ) -> Result<PatientRecovery, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_clinical_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "initiate_recovery",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        if old_patient == new_patient {
+            return Err(ContractError::InvalidInput);
+        }
+        if !env
             .storage()
             .persistent()
-            .get(&patient_key)
-            .unwrap_or(Vec::new(&env));
-        patient_records.push_back(record_id);
-        env.storage()
-            .persistent()
-            .set(&patient_key, &patient_records);
+            .has(&(symbol_short!("USER"), old_patient.clone()))
+        {
+            return Err(ContractError::UserNotFound);
+        }
+        if recovery::get(&env, &old_patient).is_some()
+            || Self::is_address_in_use(&env, &new_patient)
+        {
+            return Err(ContractError::AlreadyExists);
+        }
 
-        Ok(record_id)
+        let now = env.ledger().timestamp();
+        let pending = PatientRecovery {
+            old_patient: old_patient.clone(),
+            new_patient: new_patient.clone(),
+            challenge_hash,
+            initiated_by: caller.clone(),
+            initiated_at: now,
+            unlocks_at: now.saturating_add(recovery::RECOVERY_TIMELOCK_SECONDS),
+            started: false,
+            records_rebound: 0,
+            grants_rebound: 0,
+            remaining: 0,
+        };
+        recovery::set(&env, &pending);
+        events::publish_recovery_initiated(
+            &env,
+            old_patient,
+            new_patient,
+            caller,
+            pending.unlocks_at,
+        );
+        Ok(pending)
     }
 
-    /// Add multiple vision records in a single transaction.
-    /// Validates provider permission once, then creates all records atomically.
-    #[allow(clippy::arithmetic_side_effects)]
-    pub fn add_records(
-        env: Env,
-        provider: Address,
-        records: Vec<BatchRecordInput>,
-    ) -> Result<Vec<u64>, ContractError> {
-        provider.require_auth();
+    /// Veto a pending recovery of the caller's chart. Fails with
+    /// `RecoveryInProgress` once `finalize_recovery` has started moving it.
+    pub fn cancel_recovery(env: Env, old_patient: Address) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        old_patient.require_auth();
+        let pending = recovery::get(&env, &old_patient).ok_or(ContractError::RecoveryNotFound)?;
+        if pending.started {
+            return Err(ContractError::RecoveryInProgress);
+        }
+        recovery::clear(&env, &old_patient);
+        events::publish_recovery_cancelled(&env, old_patient, pending.new_patient);
+        Ok(())
+    }
 
-        if records.is_empty() {
+    /// The recovery pending for `old_patient`, if any.
+    pub fn get_recovery(env: Env, old_patient: Address) -> Option<PatientRecovery> {
+        recovery::get(&env, &old_patient)
+    }
+
+    /// The `confirm` value an irreversible operation expects when acting
+    /// on `target`: the SHA-256 of the contract address, `operation` and
+    /// `target`. Operations that take one are `FIN_RECOV`
+    /// (`finalize_recovery`, target the old patient address) and
+    /// `MIG_GRNT` (`migrate_grantee`, target the old grantee address).
+    pub fn compute_confirmation(env: Env, operation: Symbol, target: Address) -> BytesN<32> {
+        confirmation::compute(&env, &operation, &target)
+    }
+
+    /// Complete a recovery once its timelock has passed. `preimage` must
+    /// hash to the challenge the recovery was started with.
+    ///
+    /// The first call deactivates the old address. Each call then moves up
+    /// to `limit` of the patient's records and patient-wide grants, with
+    /// the matching consents, to the new address, publishing an event per
+    /// item; expired grants are dropped. Call again while `remaining` is
+    /// non-zero. Once everything has moved, the user entry follows and the
+    /// recovery is cleared. When more than one admin is registered, the
+    /// recovery must be finalized by an admin other than the one who
+    /// initiated it. Requires ContractAdmin.
+    ///
+    /// `confirm` must be `compute_confirmation(FIN_RECOV, old_patient)`,
+    /// so a pasted-over address fails with `ConfirmationMismatch`.
+    pub fn finalize_recovery(
+        env: Env,
+        caller: Address,
+        old_patient: Address,
+        preimage: Bytes,
+        confirm: BytesN<32>,
+        limit: u32,
+    ) -> Result<PatientRecovery, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_clinical_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "finalize_recovery",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        confirmation::require(
+            &env,
+            &confirmation::FINALIZE_RECOVERY,
+            &old_patient,
+            &confirm,
+        )?;
+        if limit == 0 {
             return Err(ContractError::InvalidInput);
         }
+        let mut pending =
+            recovery::get(&env, &old_patient).ok_or(ContractError::RecoveryNotFound)?;
+        if caller == pending.initiated_by && recovery::requires_second_admin(&env) {
+            return Self::unauthorized(&env, &caller, "finalize_recovery", "second_admin");
+        }
+        if env.ledger().timestamp() < pending.unlocks_at {
+            return Err(ContractError::RecoveryLocked);
+        }
+        if !recovery::proves(&env, &pending, &preimage) {
+            return Err(ContractError::InvalidRecoveryProof);
+        }
+        let new_patient = pending.new_patient.clone();
+        if !pending.started {
+            // The address may have been put to use since the recovery started
+            if Self::is_address_in_use(&env, &new_patient) {
+                return Err(ContractError::AlreadyExists);
+            }
+            // Whoever holds the lost key keeps nothing
+            deactivation::set_deactivated(&env, &old_patient, true);
+            pending.started = true;
+        }
 
-        if !whitelist::check_whitelist_access(&env, &provider) {
-            return Self::unauthorized(&env, &provider, "add_records", "whitelisted_provider");
+        let records = Self::rebind_patient_records(&env, &old_patient, &new_patient, limit)?;
+        let grants = Self::rebind_patient_grants(
+            &env,
+            &old_patient,
+            &new_patient,
+            limit.saturating_sub(records),
+        );
+        pending.records_rebound = pending.records_rebound.saturating_add(records);
+        pending.grants_rebound = pending.grants_rebound.saturating_add(grants);
+        pending.remaining = Self::patient_record_ids(&env, &old_patient)
+            .len()
+            .saturating_add(grant_index::get_list(&env, &old_patient).len());
+        if pending.remaining > 0 {
+            recovery::set(&env, &pending);
+            return Ok(pending);
         }
 
-        // Check provider has WriteRecord permission once for the whole batch
-        if !rbac::has_permission(&env, &provider, &Permission::WriteRecord)
-            && !rbac::has_permission(&env, &provider, &Permission::SystemAdmin)
-        {
-            return Self::unauthorized(
+        let old_user_key = (symbol_short!("USER"), old_patient.clone());
+        if let Some(mut user) = env.storage().persistent().get::<_, User>(&old_user_key) {
+            user.address = new_patient.clone();
+            let new_user_key = (symbol_short!("USER"), new_patient.clone());
+            env.storage().persistent().remove(&old_user_key);
+            env.storage().persistent().set(&new_user_key, &user);
+            extend_ttl_address_key(&env, &new_user_key);
+            rbac::assign_role(&env, new_patient.clone(), user.role.clone(), 0, &caller);
+            events::publish_recovery_user_rebound(
                 &env,
-                &provider,
-                "add_records",
-                "permission:WriteRecord_or_SystemAdmin",
+                old_patient.clone(),
+                new_patient.clone(),
+                user.role,
             );
         }
 
-        let counter_key = symbol_short!("REC_CTR");
-        let mut current_id: u64 = env.storage().instance().get(&counter_key).unwrap_or(0);
-        let mut record_ids = Vec::new(&env);
+        recovery::clear(&env, &old_patient);
+        events::publish_recovery_finalized(
+            &env,
+            old_patient,
+            new_patient,
+            caller,
+            pending.records_rebound,
+            pending.grants_rebound,
+        );
+        Ok(pending)
+    }
 
-        // Load current encryption key/version once for the batch
-        let current_version: Option<String> = env.storage().instance().get(&ENC_CUR);
-        let mut master_bytes_batch: StdVec<u8> = StdVec::new();
-        if let Some(ver) = current_version.clone() {
-            if let Some(sv) = env
+    /// Whether `address` already has a user entry, records or grants.
+    fn is_address_in_use(env: &Env, address: &Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&(symbol_short!("USER"), address.clone()))
+            || !Self::patient_record_ids(env, address).is_empty()
+            || !grant_index::get_list(env, address).is_empty()
+    }
+
+    /// Moves up to `limit` records from the front of `old_patient`'s index
+    /// to `new_patient`, and the patient summary along with the last of
+    /// them. Returns how many moved.
+    fn rebind_patient_records(
+        env: &Env,
+        old_patient: &Address,
+        new_patient: &Address,
+        limit: u32,
+    ) -> Result<u32, ContractError> {
+        let record_ids = Self::patient_record_ids(env, old_patient);
+        let moving = record_ids.len().min(limit);
+        for record_id in record_ids.slice(..moving).iter() {
+            let key = (symbol_short!("RECORD"), record_id);
+            let mut record: VisionRecord = env
                 .storage()
                 .persistent()
-                .get::<(Symbol, String), String>(&(ENC_KEY, ver.clone()))
-            {
-                let hex = sv.to_string();
-                if let Some(bytes) = teye_common::hex_to_bytes(&hex) {
-                    master_bytes_batch = bytes;
-                }
-            }
+                .get(&key)
+                .ok_or(ContractError::RecordNotFound)?;
+            versioning::move_patient_versions(
+                env,
+                old_patient,
+                new_patient,
+                versioning::get_version_count(env, record_id),
+            );
+            record.patient = new_patient.clone();
+            env.storage().persistent().set(&key, &record);
+            extend_ttl_u64_key(env, &key);
+
+            patient_index::insert(env, new_patient, record_id);
+            patient_label::move_to_patient(env, old_patient, new_patient, record_id);
+            events::publish_recovery_record_rebound(
+                env,
+                old_patient.clone(),
+                new_patient.clone(),
+                record_id,
+            );
         }
+        patient_index::remove_first(env, old_patient, moving);
+        if moving == record_ids.len() {
+            patient_summary::move_summary(env, old_patient, new_patient);
+        }
+        Ok(moving)
+    }
 
-        for input in records.iter() {
-            current_id += 1;
-
-            // Encrypt input.data_hash with batch master
-            let km = KeyManager::new(master_bytes_batch.clone());
-            let plaintext: StdString = input.data_hash.to_string();
-            let ciphertext = km.encrypt(None, &plaintext);
-            let stored_hash = String::from_str(&env, &ciphertext);
-
-            let record = VisionRecord {
-                id: current_id,
-                patient: input.patient.clone(),
-                provider: provider.clone(),
-                record_type: input.record_type.clone(),
-                data_hash: stored_hash,
-                key_version: current_version.clone(),
-                created_at: env.ledger().timestamp(),
-                updated_at: env.ledger().timestamp(),
+    /// Moves up to `limit` patient-wide grants from the front of
+    /// `old_patient`'s list, and the consents behind them, to
+    /// `new_patient`. Expired grants are dropped. Returns how many live
+    /// grants moved.
+    fn rebind_patient_grants(
+        env: &Env,
+        old_patient: &Address,
+        new_patient: &Address,
+        limit: u32,
+    ) -> u32 {
+        let listed = grant_index::get_list(env, old_patient);
+        let moving = listed.len().min(limit);
+        if moving == 0 {
+            return 0;
+        }
+        let mut grantees = grant_index::get_list(env, new_patient);
+        let mut rebound: u32 = 0;
+        for grantee in listed.slice(..moving).iter() {
+            let old_key = (
+                symbol_short!("ACCESS"),
+                old_patient.clone(),
+                grantee.clone(),
+            );
+            let grant: Option<AccessGrant> = env.storage().persistent().get(&old_key);
+            let purpose = purpose::get_purpose(env, old_patient, &grantee);
+            let mut created_by = grant_creator::get_creator(env, old_patient, &grantee);
+            if created_by == *old_patient {
+                created_by = new_patient.clone();
+            }
+            let append_only = write_scope::is_append_only(env, old_patient, &grantee);
+            let history_hidden = history_scope::is_history_hidden(env, old_patient, &grantee);
+            env.storage().persistent().remove(&old_key);
+            purpose::remove_purpose(env, old_patient, &grantee);
+            grant_creator::remove_creator(env, old_patient, &grantee);
+            write_scope::set_append_only(env, old_patient, &grantee, false);
+            history_scope::set_history_hidden(env, old_patient, &grantee, false);
+            // A scheduled revocation has already capped the grant's expiry
+            revocation::clear_pending(env, old_patient, &grantee);
+            remove_from_grantee_index(env, &grantee, old_patient);
+
+            let old_consent = consent_key(old_patient, &grantee);
+            let consent: Option<ConsentGrant> = env.storage().persistent().get(&old_consent);
+            env.storage().persistent().remove(&old_consent);
+            let withdrawn_at = consent_proof::get_withdrawn_at(env, old_patient, &grantee);
+            consent_proof::clear_withdrawn_at(env, old_patient, &grantee);
+
+            let mut grant = match grant.filter(|g| !expiry::is_expired(env, g.expires_at)) {
+                Some(grant) => grant,
+                None => continue,
             };
+            grant.patient = new_patient.clone();
+            let new_key = (
+                symbol_short!("ACCESS"),
+                new_patient.clone(),
+                grantee.clone(),
+            );
+            env.storage().persistent().set(&new_key, &grant);
+            extend_ttl_access_key(env, &new_key);
+            purpose::set_purpose(env, new_patient, &grantee, &purpose);
+            grant_creator::set_creator(env, new_patient, &grantee, &created_by);
+            write_scope::set_append_only(env, new_patient, &grantee, append_only);
+            history_scope::set_history_hidden(env, new_patient, &grantee, history_hidden);
+            add_to_grantee_index(env, &grantee, new_patient);
+            expiry_beacon::index(env, new_patient, &grantee, grant.expires_at);
+            if let Some(mut consent) = consent {
+                consent.patient = new_patient.clone();
+                let new_consent = consent_key(new_patient, &grantee);
+                env.storage().persistent().set(&new_consent, &consent);
+                extend_ttl_access_key(env, &new_consent);
+                if let Some(withdrawn_at) = withdrawn_at {
+                    consent_proof::set_withdrawn_at(env, new_patient, &grantee, withdrawn_at);
+                }
+            }
 
-            let key = (symbol_short!("RECORD"), current_id);
-            env.storage().persistent().set(&key, &record);
+            events::publish_recovery_grant_rebound(
+                env,
+                old_patient.clone(),
+                new_patient.clone(),
+                grantee.clone(),
+                grant.expires_at,
+            );
+            grantees.push_back(grantee);
+            rebound = rebound.saturating_add(1);
+        }
 
-            let patient_key = (symbol_short!("PAT_REC"), input.patient.clone());
-            let mut patient_records: Vec<u64> = env
-                .storage()
-                .persistent()
-                .get(&patient_key)
-                .unwrap_or(Vec::new(&env));
-            patient_records.push_back(current_id);
+        let old_list_key = (symbol_short!("ACC_LST"), old_patient.clone());
+        if moving == listed.len() {
+            env.storage().persistent().remove(&old_list_key);
+        } else {
             env.storage()
                 .persistent()
-                .set(&patient_key, &patient_records);
+                .set(&old_list_key, &listed.slice(moving..));
+        }
+        if !grantees.is_empty() {
+            let list_key = (symbol_short!("ACC_LST"), new_patient.clone());
+            env.storage().persistent().set(&list_key, &grantees);
+            extend_ttl_address_key(env, &list_key);
+        }
+        grant_revision::bump_epoch(env, old_patient);
+        grant_revision::bump_epoch(env, new_patient);
+        rebound
+    }
 
-            events::publish_record_added(
-                &env,
-                current_id,
-                input.patient.clone(),
-                provider.clone(),
-                input.record_type.clone(),
-            );
+    /// Freeze a user: every permission and access check for them fails
+    /// immediately, without touching their individual grants.
+    ///
+    /// Requires at least `OperatorAdmin` tier, or ClinicalAdmin.
+    pub fn freeze_user(env: Env, caller: Address, user: Address) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_clinical_admin_access(&env, &caller, &AdminTier::OperatorAdmin) {
+            return Self::unauthorized(&env, &caller, "freeze_user", "admin_tier:OperatorAdmin");
+        }
+        rbac::set_frozen(&env, &user, true);
+        events::publish_user_frozen(&env, user, caller);
+        Ok(())
+    }
 
-            record_ids.push_back(current_id);
+    /// Lift a freeze placed by `freeze_user`. Existing grants apply again.
+    ///
+    /// Requires at least `OperatorAdmin` tier, or ClinicalAdmin.
+    pub fn unfreeze_user(env: Env, caller: Address, user: Address) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_clinical_admin_access(&env, &caller, &AdminTier::OperatorAdmin) {
+            return Self::unauthorized(&env, &caller, "unfreeze_user", "admin_tier:OperatorAdmin");
         }
+        rbac::set_frozen(&env, &user, false);
+        events::publish_user_unfrozen(&env, user, caller);
+        Ok(())
+    }
 
-        env.storage().instance().set(&counter_key, &current_id);
+    /// Check whether a user is currently frozen.
+    pub fn is_user_frozen(env: Env, user: Address) -> bool {
+        rbac::is_frozen(&env, &user)
+    }
 
-        events::publish_batch_records_added(&env, provider, record_ids.len());
+    /// Freeze all access to the patient's records by anyone but the
+    /// patient: grants, record grants, sessions and consents stop counting,
+    /// and providers can no longer add to or modify the patient's records.
+    /// With `allow_emergency` set, callers holding active emergency access
+    /// are still let through. Nothing is deleted, so `unfreeze_my_access`
+    /// restores access exactly as it was. Freezing again replaces the
+    /// `allow_emergency` choice.
+    pub fn freeze_my_access(
+        env: Env,
+        patient: Address,
+        allow_emergency: bool,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+        access_freeze::set(
+            &env,
+            &AccessFreeze {
+                patient: patient.clone(),
+                allow_emergency,
+                frozen_at: env.ledger().timestamp(),
+            },
+        );
+        grant_revision::bump_epoch(&env, &patient);
+        events::publish_patient_access_frozen(&env, patient, allow_emergency);
+        Ok(())
+    }
 
-        Ok(record_ids)
+    /// Lift a freeze placed by `freeze_my_access`.
+    pub fn unfreeze_my_access(env: Env, patient: Address) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+        if access_freeze::remove(&env, &patient) {
+            grant_revision::bump_epoch(&env, &patient);
+            events::publish_patient_access_unfrozen(&env, patient);
+        }
+        Ok(())
     }
 
-    /// Get a vision record by ID.
-    pub fn get_record(
+    /// Whether the patient has frozen access to their records.
+    pub fn is_access_frozen(env: Env, patient: Address) -> bool {
+        access_freeze::is_frozen(&env, &patient)
+    }
+
+    /// Deactivate a user: they hold no permissions until reactivated.
+    ///
+    /// `cascade` chooses what else is cleaned up. `RevokeOutgoingDelegations`
+    /// revokes every delegation the user made; `RevokeAll` also revokes the
+    /// patient-wide grants the user received. At most `limit` delegations
+    /// and grants are revoked per call, delegations first; call again with
+    /// the same cascade while `remaining` is non-zero. Reactivation does not
+    /// restore anything revoked here.
+    ///
+    /// Requires at least `OperatorAdmin` tier, or ClinicalAdmin.
+    pub fn deactivate_user(
         env: Env,
         caller: Address,
-        record_id: u64,
-    ) -> Result<VisionRecord, ContractError> {
+        user: Address,
+        cascade: DeactivationCascade,
+        limit: u32,
+    ) -> Result<DeactivationSummary, ContractError> {
+        Self::require_initialized(&env)?;
         caller.require_auth();
-        let key = (symbol_short!("RECORD"), record_id);
-        match env.storage().persistent().get::<_, VisionRecord>(&key) {
-            Some(record) => {
-                // Check access permissions
-                let has_access = if caller == record.patient || caller == record.provider {
-                    // Patient can always read their own records
-                    // Provider can read records they created
-                    true
-                } else {
-                    // Check if caller has broad read permissions, active consent, or explicit grant
-                    rbac::has_permission(&env, &caller, &Permission::ReadAnyRecord)
-                        || rbac::has_permission(&env, &caller, &Permission::SystemAdmin)
-                        || has_active_consent(&env, &record.patient, &caller)
-                        || {
-                            let access_level = Self::check_access(
-                                env.clone(),
-                                record.patient.clone(),
-                                caller.clone(),
-                            );
-                            access_level != AccessLevel::None
-                        }
-                        || Self::check_record_access(env.clone(), record_id, caller.clone())
-                            != AccessLevel::None
-                };
-
-                if !has_access {
-                    // Log failed access attempt
-                    let audit_entry = audit::create_audit_entry(
-                        &env,
-                        caller.clone(),
-                        record.patient.clone(),
-                        Some(record_id),
-                        AccessAction::Read,
-                        AccessResult::Denied,
-                        Some(String::from_str(&env, "Insufficient permissions")),
-                    );
-                    audit::add_audit_entry(&env, &audit_entry);
-                    events::publish_audit_log_entry(&env, &audit_entry);
-
-                    return Self::unauthorized(&env, &caller, "get_record", "record_read_access");
-                }
+        if !Self::has_clinical_admin_access(&env, &caller, &AdminTier::OperatorAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "deactivate_user",
+                "admin_tier:OperatorAdmin",
+            );
+        }
+        if limit == 0 {
+            return Err(ContractError::InvalidInput);
+        }
 
-                // Log successful access
-                let audit_entry = audit::create_audit_entry(
-                    &env,
-                    caller.clone(),
-                    record.patient.clone(),
-                    Some(record_id),
-                    AccessAction::Read,
-                    AccessResult::Success,
-                    None,
-                );
-                audit::add_audit_entry(&env, &audit_entry);
-                events::publish_audit_log_entry(&env, &audit_entry);
+        let key = (symbol_short!("USER"), user.clone());
+        let mut user_data: User = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(ContractError::UserNotFound)?;
+        if user_data.is_active {
+            user_data.is_active = false;
+            env.storage().persistent().set(&key, &user_data);
+            extend_ttl_address_key(&env, &key);
+            deactivation::set_deactivated(&env, &user, true);
+            events::publish_user_deactivated(&env, user.clone(), cascade.clone(), caller.clone());
+        }
 
-                // Decrypt data_hash for authorized caller before returning
-                let mut out_record = record.clone();
-                // Prefer record's key_version, fall back to current instance version
-                let key_ver = out_record
-                    .key_version
-                    .clone()
-                    .or_else(|| env.storage().instance().get(&ENC_CUR));
-                let mut master_bytes: StdVec<u8> = StdVec::new();
-                if let Some(ver) = key_ver {
-                    if let Some(sv) = env
-                        .storage()
-                        .persistent()
-                        .get::<(Symbol, String), String>(&(ENC_KEY, ver.clone()))
-                    {
-                        let hex = sv.to_string();
-                        if let Some(bytes) = teye_common::hex_to_bytes(&hex) {
-                            master_bytes = bytes;
-                        }
-                    }
-                }
+        let mut budget = limit;
+        let mut delegations_revoked: u32 = 0;
+        let mut grants_revoked: u32 = 0;
+        let mut remaining: u32 = 0;
 
-                if !master_bytes.is_empty() || out_record.key_version.is_none() {
-                    let km = KeyManager::new(master_bytes);
-                    let ciphertext_std: StdString = out_record.data_hash.to_string();
-                    if let Some(plain) = km.decrypt(None, &ciphertext_std) {
-                        out_record.data_hash = String::from_str(&env, &plain);
-                    }
+        if cascade != DeactivationCascade::None {
+            let delegatees = rbac::get_delegatees(&env, &user);
+            for delegatee in delegatees.iter() {
+                if budget == 0 {
+                    break;
                 }
-
-                Ok(out_record)
+                rbac::revoke_delegation(&env, &user, &delegatee, &caller);
+                delegations_revoked = delegations_revoked.saturating_add(1);
+                budget = budget.saturating_sub(1);
             }
-            None => {
-                // Log failed access attempt (record not found)
-                // We don't know the patient, so we'll use caller as placeholder
-                let audit_entry = audit::create_audit_entry(
+            remaining = delegatees.len().saturating_sub(delegations_revoked);
+            if delegations_revoked > 0 {
+                events::publish_delegations_cleaned(
                     &env,
+                    user.clone(),
+                    delegations_revoked,
                     caller.clone(),
-                    caller.clone(), // Placeholder since we don't know patient
-                    Some(record_id),
-                    AccessAction::Read,
-                    AccessResult::NotFound,
-                    Some(String::from_str(&env, "Record not found")),
                 );
-                audit::add_audit_entry(&env, &audit_entry);
-                events::publish_audit_log_entry(&env, &audit_entry);
+            }
+        }
 
-                let resource_id = String::from_str(&env, "get_record");
-                let context = create_error_context(
-                    &env,
-                    ContractError::RecordNotFound,
-                    None,
-                    Some(resource_id.clone()),
-                );
-                log_error(
-                    &env,
-                    ContractError::RecordNotFound,
-                    None,
-                    Some(resource_id),
-                    None,
-                );
-                events::publish_error(&env, ContractError::RecordNotFound as u32, context);
-                Err(ContractError::RecordNotFound)
+        if cascade == DeactivationCascade::RevokeAll {
+            let patients = get_grantee_index(&env, &user);
+            let mut kept = Vec::new(&env);
+            for patient in patients.iter() {
+                if budget == 0 {
+                    kept.push_back(patient);
+                    continue;
+                }
+                let grant_key = (symbol_short!("ACCESS"), patient.clone(), user.clone());
+                env.storage().persistent().remove(&grant_key);
+                purpose::remove_purpose(&env, &patient, &user);
+                grant_creator::remove_creator(&env, &patient, &user);
+                write_scope::set_append_only(&env, &patient, &user, false);
+                history_scope::set_history_hidden(&env, &patient, &user, false);
+                grant_revision::bump_revision(&env, &patient, &user);
+                activity::log(&env, &patient, activity::ACCESS_REVOKED, &caller, 0);
+                compliance::log(&env, compliance::REVOKE, &caller, &patient, Some(&user), 0);
+                events::publish_access_revoked(&env, patient, user.clone());
+                grants_revoked = grants_revoked.saturating_add(1);
+                budget = budget.saturating_sub(1);
+            }
+            let index_key = grantee_index_key(&user);
+            if kept.is_empty() {
+                env.storage().persistent().remove(&index_key);
+            } else {
+                env.storage().persistent().set(&index_key, &kept);
+            }
+            remaining = remaining.saturating_add(kept.len());
+            if grants_revoked > 0 {
+                events::publish_grants_cleaned(&env, user.clone(), grants_revoked, caller);
             }
         }
+
+        Ok(DeactivationSummary {
+            user,
+            cascade,
+            delegations_revoked,
+            grants_revoked,
+            remaining,
+        })
     }
 
-    /// Add eye examination details for an existing record
-    #[allow(clippy::too_many_arguments)]
-    pub fn add_eye_examination(
-        env: Env,
-        caller: Address,
-        record_id: u64,
-        visual_acuity: VisualAcuity,
-        iop: IntraocularPressure,
-        slit_lamp: SlitLampFindings,
-        visual_field: OptVisualField,
-        retina_imaging: OptRetinalImaging,
-        fundus_photo: OptFundusPhotography,
-        clinical_notes: String,
-    ) -> Result<(), ContractError> {
+    /// Reactivate a deactivated user. Delegations and grants revoked on
+    /// deactivation stay revoked. Closed accounts fail with
+    /// `AccountClosed`.
+    ///
+    /// Requires at least `OperatorAdmin` tier, or ClinicalAdmin.
+    pub fn reactivate_user(env: Env, caller: Address, user: Address) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
         caller.require_auth();
-
-        let record = Self::get_record(env.clone(), caller.clone(), record_id)?;
-
-        let has_perm = if caller == record.provider {
-            rbac::has_permission(&env, &caller, &Permission::WriteRecord)
-        } else {
-            rbac::has_delegated_permission(
-                &env,
-                &record.provider,
-                &caller,
-                &Permission::WriteRecord,
-            )
-        };
-
-        if !has_perm && !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+        if !Self::has_clinical_admin_access(&env, &caller, &AdminTier::OperatorAdmin) {
             return Self::unauthorized(
                 &env,
                 &caller,
-                "add_eye_examination",
-                "permission:WriteRecord_or_SystemAdmin",
+                "reactivate_user",
+                "admin_tier:OperatorAdmin",
             );
         }
 
-        if record.record_type != RecordType::Examination {
-            return Err(ContractError::InvalidRecordType);
+        let key = (symbol_short!("USER"), user.clone());
+        let mut user_data: User = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(ContractError::UserNotFound)?;
+        if deactivation::is_closed(&env, &user) {
+            return Err(ContractError::AccountClosed);
         }
-
-        let exam = EyeExamination {
-            record_id,
-            visual_acuity,
-            iop,
-            slit_lamp,
-            visual_field,
-            retina_imaging,
-            fundus_photo,
-            clinical_notes,
-        };
-
-        examination::set_examination(&env, &exam);
-        events::publish_examination_added(&env, record_id);
-
+        if user_data.is_active {
+            return Ok(());
+        }
+        user_data.is_active = true;
+        env.storage().persistent().set(&key, &user_data);
+        extend_ttl_address_key(&env, &key);
+        deactivation::set_deactivated(&env, &user, false);
+        events::publish_user_reactivated(&env, user, caller);
         Ok(())
     }
 
-    /// Retrieve eye examination details for a record
-    pub fn get_eye_examination(
+    /// Close a user's account for good, e.g. on a right-to-be-forgotten
+    /// request. The user is deactivated and can never be reactivated, and
+    /// everything pointing at them is removed: delegations they made or
+    /// received and the patient-wide grants they hold. Each affected
+    /// patient's activity feed gets a `GRANTEE_CLOSED` entry and a
+    /// `GRT_CLOSE` event is published per patient.
+    ///
+    /// At most `limit` items are removed per call; call again while
+    /// `remaining` is non-zero, or check `get_cleanup_progress`.
+    ///
+    /// Requires at least `OperatorAdmin` tier, or ClinicalAdmin.
+    pub fn close_account(
         env: Env,
         caller: Address,
-        record_id: u64,
-    ) -> Result<EyeExamination, ContractError> {
+        user: Address,
+        limit: u32,
+    ) -> Result<CleanupProgress, ContractError> {
+        Self::require_initialized(&env)?;
         caller.require_auth();
-        let record = Self::get_record(env.clone(), caller.clone(), record_id)?;
+        if !Self::has_clinical_admin_access(&env, &caller, &AdminTier::OperatorAdmin) {
+            return Self::unauthorized(&env, &caller, "close_account", "admin_tier:OperatorAdmin");
+        }
+        if limit == 0 {
+            return Err(ContractError::InvalidInput);
+        }
 
-        let has_perm = if caller == record.patient || caller == record.provider {
-            true
-        } else {
-            let access = Self::check_access(env.clone(), record.patient.clone(), caller.clone());
-            let record_access = Self::check_record_access(env.clone(), record_id, caller.clone());
-            access == AccessLevel::Read
-                || access == AccessLevel::Write
-                || access == AccessLevel::Full
-                || record_access != AccessLevel::None
-                || rbac::has_permission(&env, &caller, &Permission::SystemAdmin)
+        let key = (symbol_short!("USER"), user.clone());
+        let mut user_data: User = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(ContractError::UserNotFound)?;
+        let mut progress = match deactivation::get_cleanup_progress(&env, &user) {
+            Some(progress) => progress,
+            None => {
+                user_data.is_active = false;
+                env.storage().persistent().set(&key, &user_data);
+                extend_ttl_address_key(&env, &key);
+                deactivation::set_deactivated(&env, &user, true);
+                events::publish_account_closed(&env, user.clone(), caller.clone());
+                CleanupProgress {
+                    user: user.clone(),
+                    closed_by: caller.clone(),
+                    closed_at: env.ledger().timestamp(),
+                    delegations_removed: 0,
+                    grants_removed: 0,
+                    remaining: 0,
+                }
+            }
         };
 
-        if !has_perm {
-            return Self::access_denied(&env, &caller, "get_eye_examination", "record_read_access");
+        let mut budget = limit;
+        for delegatee in rbac::get_delegatees(&env, &user).iter() {
+            if budget == 0 {
+                break;
+            }
+            rbac::revoke_delegation(&env, &user, &delegatee, &caller);
+            progress.delegations_removed = progress.delegations_removed.saturating_add(1);
+            budget = budget.saturating_sub(1);
+        }
+        for delegator in rbac::get_delegators(&env, &user).iter() {
+            if budget == 0 {
+                break;
+            }
+            rbac::revoke_delegation(&env, &delegator, &user, &caller);
+            progress.delegations_removed = progress.delegations_removed.saturating_add(1);
+            budget = budget.saturating_sub(1);
+        }
+
+        let patients = get_grantee_index(&env, &user);
+        let mut kept = Vec::new(&env);
+        for patient in patients.iter() {
+            if budget == 0 {
+                kept.push_back(patient);
+                continue;
+            }
+            let grant_key = (symbol_short!("ACCESS"), patient.clone(), user.clone());
+            env.storage().persistent().remove(&grant_key);
+            purpose::remove_purpose(&env, &patient, &user);
+            grant_creator::remove_creator(&env, &patient, &user);
+            write_scope::set_append_only(&env, &patient, &user, false);
+            history_scope::set_history_hidden(&env, &patient, &user, false);
+            revocation::clear_pending(&env, &patient, &user);
+            grant_revision::bump_revision(&env, &patient, &user);
+            activity::log(&env, &patient, activity::GRANTEE_CLOSED, &caller, 0);
+            compliance::log(&env, compliance::REVOKE, &caller, &patient, Some(&user), 0);
+            events::publish_closed_grantee_removed(&env, patient, user.clone(), caller.clone());
+            progress.grants_removed = progress.grants_removed.saturating_add(1);
+            budget = budget.saturating_sub(1);
+        }
+        let index_key = grantee_index_key(&user);
+        if kept.is_empty() {
+            env.storage().persistent().remove(&index_key);
+        } else {
+            env.storage().persistent().set(&index_key, &kept);
         }
 
-        examination::get_examination(&env, record_id).ok_or(ContractError::RecordNotFound)
-    }
-
-    /// Get all records for a patient
-    pub fn get_patient_records(env: Env, patient: Address) -> Vec<u64> {
-        let key = (symbol_short!("PAT_REC"), patient);
-        env.storage()
-            .persistent()
-            .get(&key)
-            .unwrap_or(Vec::new(&env))
+        progress.remaining = rbac::get_delegatees(&env, &user)
+            .len()
+            .saturating_add(rbac::get_delegators(&env, &user).len())
+            .saturating_add(kept.len());
+        deactivation::set_cleanup_progress(&env, &progress);
+        Ok(progress)
     }
 
-    /// Grant access to a user
-    #[allow(clippy::arithmetic_side_effects)]
-    pub fn grant_access(
+    /// How far the cleanup after `close_account` has got. Fails with
+    /// `UserNotFound` if the user's account was never closed.
+    ///
+    /// Requires at least `OperatorAdmin` tier, or ClinicalAdmin.
+    pub fn get_cleanup_progress(
         env: Env,
         caller: Address,
-        patient: Address,
-        grantee: Address,
-        level: AccessLevel,
-        duration_seconds: u64,
-    ) -> Result<(), ContractError> {
-        let _guard = teye_common::ReentrancyGuard::new(&env);
-        circuit_breaker::require_not_paused(
-            &env,
-            &circuit_breaker::PauseScope::Function(symbol_short!("GRT_ACC")),
-        )?;
+        user: Address,
+    ) -> Result<CleanupProgress, ContractError> {
+        Self::require_initialized(&env)?;
         caller.require_auth();
-
-        Self::enforce_rate_limit(&env, &caller)?;
-
-        validation::validate_duration(duration_seconds)?;
-
-        let has_perm = if caller == patient {
-            true // Patient manages own access
-        } else {
-            // Specific patient→caller delegation for ManageAccess
-            rbac::has_delegated_permission(&env, &patient, &caller, &Permission::ManageAccess)
-                // Or caller has SystemAdmin (unified: direct + any delegation)
-                || rbac::has_permission(&env, &caller, &Permission::SystemAdmin)
-        };
-
-        if !has_perm {
-            // Log failed access grant attempt
-            let audit_entry = audit::create_audit_entry(
-                &env,
-                caller.clone(),
-                patient.clone(),
-                None,
-                AccessAction::GrantAccess,
-                AccessResult::Denied,
-                Some(String::from_str(&env, "Insufficient permissions")),
-            );
-            audit::add_audit_entry(&env, &audit_entry);
-            events::publish_audit_log_entry(&env, &audit_entry);
+        if !Self::has_clinical_admin_access(&env, &caller, &AdminTier::OperatorAdmin) {
             return Self::unauthorized(
                 &env,
                 &caller,
-                "grant_access",
-                "patient_or_permission:ManageAccess_or_SystemAdmin",
+                "get_cleanup_progress",
+                "admin_tier:OperatorAdmin",
             );
         }
+        deactivation::get_cleanup_progress(&env, &user).ok_or(ContractError::UserNotFound)
+    }
 
-        let expires_at = env.ledger().timestamp() + duration_seconds;
-        let grant = AccessGrant {
-            patient: patient.clone(),
-            grantee: grantee.clone(),
-            level: level.clone(),
-            granted_at: env.ledger().timestamp(),
-            expires_at,
-        };
-
-        let key = (symbol_short!("ACCESS"), patient.clone(), grantee.clone());
-        env.storage().persistent().set(&key, &grant);
-        extend_ttl_access_key(&env, &key);
+    /// Get the total number of record IDs handed out across all counter
+    /// shards
+    pub fn get_record_count(env: Env) -> Result<u64, ContractError> {
+        Self::require_initialized(&env)?;
+        Ok(record_counter::count(&env))
+    }
 
-        // Track the grantee address in the patient's grantee list for purge iteration.
-        let list_key = (symbol_short!("ACC_LST"), patient.clone());
-        let mut grantees: Vec<Address> = env
-            .storage()
-            .persistent()
-            .get(&list_key)
-            .unwrap_or(Vec::new(&env));
-        // Avoid duplicates: only append if not already present.
-        let mut found = false;
-        for i in 0..grantees.len() {
-            if grantees.get(i) == Some(grantee.clone()) {
-                found = true;
-                break;
+    /// SHA-256 digest of the state in `scope`, for comparing state across
+    /// environments or before and after an upgrade. Stable while the
+    /// covered entries are unchanged. `Global` covers only counters and
+    /// configuration; see [`digest::GlobalState`].
+    pub fn get_state_digest(env: Env, scope: DigestScope) -> Result<BytesN<32>, ContractError> {
+        Self::require_initialized(&env)?;
+        match scope {
+            DigestScope::Record(record_id) => {
+                let state =
+                    digest::record_state(&env, record_id).ok_or(ContractError::RecordNotFound)?;
+                Ok(digest::record_digest(&env, &state))
+            }
+            DigestScope::Patient(patient) => {
+                let record_ids = Self::patient_record_ids(&env, &patient);
+                let state = digest::patient_state(&env, &patient, &record_ids);
+                Ok(digest::patient_digest(&env, &state))
             }
+            DigestScope::Global => Ok(digest::global_digest(&env, &Self::global_state(&env))),
         }
-        if !found {
-            grantees.push_back(grantee.clone());
-            env.storage().persistent().set(&list_key, &grantees);
+    }
+
+    /// Counts of the state held for `patient`, for support tooling.
+    /// InfraAdmin only.
+    pub fn get_storage_footprint(
+        env: Env,
+        caller: Address,
+        patient: Address,
+    ) -> Result<StorageFootprint, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !rbac::has_infra_admin(&env, &caller) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "get_storage_footprint",
+                "permission:InfraAdmin",
+            );
         }
 
-        events::publish_access_granted(&env, patient, grantee, level, duration_seconds, expires_at);
+        Ok(StorageFootprint {
+            records: Self::patient_record_ids(&env, &patient).len(),
+            versions: versioning::get_patient_version_total(&env, &patient),
+            grants: inspect::count_active_grants(&env, &patient),
+            delegations: rbac::count_active_delegations_from(&env, &patient),
+            pending_requests: approval::count_pending(&env, &patient),
+            patient,
+        })
+    }
 
-        Ok(())
+    /// How full the contract's instance storage is: the number of
+    /// registered keys set and an estimate of their size, flagged once it
+    /// passes the warning threshold. Every call loads the whole instance,
+    /// so this is the early warning before it nears the ledger entry size
+    /// limit. Keys written per caller or per function, such as function
+    /// pause flags, are not counted.
+    pub fn get_instance_usage(env: Env) -> InstanceUsage {
+        instance_usage::measure(&env)
     }
 
-    /// Grant access to multiple users in a single transaction.
-    /// Patient authorizes once for the entire batch.
-    #[allow(clippy::arithmetic_side_effects)]
-    pub fn grant_access_batch(
+    /// Set the estimated instance size, in bytes, above which
+    /// `get_instance_usage` raises its warning. Requires ContractAdmin.
+    pub fn set_instance_usage_threshold(
         env: Env,
-        patient: Address,
-        grants: Vec<BatchGrantInput>,
+        caller: Address,
+        bytes: u32,
     ) -> Result<(), ContractError> {
-        patient.require_auth();
-
-        if grants.is_empty() {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_instance_usage_threshold",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        if bytes == 0 {
             return Err(ContractError::InvalidInput);
         }
+        instance_usage::set_warn_threshold(&env, bytes);
+        Ok(())
+    }
 
-        let now = env.ledger().timestamp();
-        for grant in grants.iter() {
-            let expires_at = now + grant.duration_seconds;
-            let access_grant = AccessGrant {
-                patient: patient.clone(),
-                grantee: grant.grantee.clone(),
-                level: grant.level.clone(),
-                granted_at: now,
-                expires_at,
-            };
-            let key = (
-                symbol_short!("ACCESS"),
-                patient.clone(),
-                grant.grantee.clone(),
-            );
-            env.storage().persistent().set(&key, &access_grant);
-
-            events::publish_access_granted(
+    /// A record together with its version history summary, grant count
+    /// and flags, for support tooling. ClinicalAdmin only.
+    pub fn get_record_debug(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+    ) -> Result<RecordDebug, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !rbac::has_clinical_admin(&env, &caller) {
+            return Self::unauthorized(
                 &env,
-                patient.clone(),
-                grant.grantee.clone(),
-                grant.level.clone(),
-                grant.duration_seconds,
-                expires_at,
+                &caller,
+                "get_record_debug",
+                "permission:ClinicalAdmin",
             );
         }
 
-        events::publish_batch_access_granted(&env, patient, grants.len());
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        let version_count = versioning::get_version_count(&env, record_id);
+        let active_grant_count = inspect::count_active_grants(&env, &record.patient)
+            .saturating_add(inspect::count_active_record_grants(&env, record_id));
+        let mut last_version = Vec::new(&env);
+        if let Some(version) = versioning::get_version(&env, record_id, version_count) {
+            last_version.push_back(version);
+        }
+        Ok(RecordDebug {
+            version_count,
+            last_version,
+            active_grant_count,
+            locked: versioning::is_superseded(&env, record_id),
+            archived: priority::get_priority(&env, record_id) == RecordPriority::Archivable,
+            disputed: dispute::is_open(&env, record_id),
+            record,
+        })
+    }
 
-        Ok(())
+    /// Estimates the ledger entries and bytes `action` writes when its
+    /// payload (the data hash, or the user's name) is about
+    /// `approx_payload_len` bytes long. Counts assume the parties involved
+    /// already hold data in the contract; see `write_cost` for the layout.
+    pub fn estimate_write_cost(
+        _env: Env,
+        action: WriteCostAction,
+        approx_payload_len: u32,
+    ) -> WriteCostEstimate {
+        write_cost::estimate(action, approx_payload_len)
     }
 
-    /// Check access level with ABAC policy evaluation
-    pub fn check_access(env: Env, patient: Address, grantee: Address) -> AccessLevel {
-        // First check traditional consent-based access
-        if !has_active_consent(&env, &patient, &grantee) {
-            return AccessLevel::None;
+    fn global_state(env: &Env) -> digest::GlobalState {
+        let instance = env.storage().instance();
+        let auto_grant = registration::get_auto_grant(env);
+        let (auto_grant_level, auto_grant_duration) =
+            auto_grant.clone().unwrap_or((AccessLevel::None, 0));
+        digest::GlobalState {
+            admin: instance.get(&ADMIN),
+            record_counter: record_counter::count(env),
+            prescription_counter: counters::current_id(env, &symbol_short!("RX_CTR")),
+            provider_counter: counters::current_id(env, &symbol_short!("PROV_CTR")),
+            appointment_counter: counters::current_id(env, &appointment::APPT_CTR),
+            emergency_counter: counters::current_id(env, &emergency::EMRG_CTR),
+            rate_limit: instance.get(&RATE_CFG).unwrap_or((0, 0)),
+            encryption_key_version: instance.get(&ENC_CUR),
+            default_patient_quota: quota::get_default_quota(env),
+            expiry_grace: expiry::get_grace(env),
+            offer_window: offer::get_offer_window(env),
+            max_grant_duration: expiry::get_max_grant_duration(env),
+            auto_critical: priority::is_auto_critical_enabled(env),
+            self_registration: registration::is_self_registration_enabled(env),
+            self_registration_daily_cap: registration::get_daily_cap(env),
+            auto_grant_enabled: auto_grant.is_some(),
+            auto_grant_level,
+            auto_grant_duration,
+            hash_algorithms: hash_alg::get_algorithms(env),
+            paused: instance
+                .get(&circuit_breaker::global_pause_key())
+                .unwrap_or(false),
         }
+    }
 
-        let key = (symbol_short!("ACCESS"), patient.clone(), grantee.clone());
+    /// Get up to `MAX_GET_RECORDS` records by ID, applying the same read
+    /// check as `get_record` to each. Results line up with `record_ids`;
+    /// a missing or unreadable ID yields an error entry in its position
+    /// instead of failing the batch. The batch is audited as one `Read`
+    /// entry, `Denied` if any ID was unreadable.
+    pub fn get_records(
+        env: Env,
+        caller: Address,
+        record_ids: Vec<u64>,
+    ) -> Result<Vec<RecordFetchResult>, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if record_ids.is_empty() || record_ids.len() > MAX_GET_RECORDS {
+            return Err(ContractError::InvalidInput);
+        }
 
-        if let Some(grant) = env.storage().persistent().get::<_, AccessGrant>(&key) {
-            if grant.expires_at > env.ledger().timestamp() {
-                // Check if ABAC policies also allow this access
-                let abac_allowed =
-                    evaluate_access_policies(&env, &grantee, None, Some(patient.clone()));
-                if abac_allowed {
-                    return grant.level;
+        let mut results = Vec::new(&env);
+        let mut audited_patient: Option<Address> = None;
+        let mut any_denied = false;
+        for record_id in record_ids.iter() {
+            let key = (symbol_short!("RECORD"), record_id);
+            let record = match env.storage().persistent().get::<_, VisionRecord>(&key) {
+                Some(record) => record,
+                None => {
+                    results.push_back(RecordFetchResult::NotFound(record_id));
+                    continue;
                 }
+            };
+
+            audited_patient.get_or_insert_with(|| record.patient.clone());
+            if !auth::read_record(&env, &caller, &record).allowed {
+                any_denied = true;
+                results.push_back(RecordFetchResult::AccessDenied(record_id));
+                continue;
             }
+            let summary =
+                versioning::get_summary(&env, record_id).ok_or(ContractError::RecordNotFound)?;
+            let mut out_record = record.clone();
+            out_record.data_hash = decrypt_data_hash(&env, &record.data_hash, &record.key_version);
+            results.push_back(RecordFetchResult::Found(out_record, summary));
         }
 
-        AccessLevel::None
+        // One entry covers the batch, filed under the first stored record's
+        // patient, so the per-ID checks above write nothing
+        if let Some(patient) = audited_patient {
+            let audit_entry = audit::create_audit_entry(
+                &env,
+                caller,
+                patient,
+                None,
+                AccessAction::Read,
+                if any_denied {
+                    AccessResult::Denied
+                } else {
+                    AccessResult::Success
+                },
+                None,
+            )?;
+            audit::add_audit_entry(&env, &audit_entry);
+            events::publish_audit_log_entry(&env, &audit_entry);
+        }
+        Ok(results)
     }
 
-    /// Grant record-level access to a specific record.
-    #[allow(clippy::arithmetic_side_effects)]
-    pub fn grant_record_access(
+    /// Set the pinning priority of a record.
+    ///
+    /// Only the record's patient or provider may call this. Emits a
+    /// `PRIO_CHG` event for the off-chain pinning daemon.
+    pub fn set_record_priority(
         env: Env,
-        patient: Address,
-        grantee: Address,
+        caller: Address,
         record_id: u64,
-        level: AccessLevel,
-        duration_seconds: u64,
+        priority: RecordPriority,
     ) -> Result<(), ContractError> {
-        patient.require_auth();
-        validation::validate_duration(duration_seconds)?;
+        Self::require_initialized(&env)?;
+        caller.require_auth();
 
         let record_key = (symbol_short!("RECORD"), record_id);
         let record: VisionRecord = env
@@ -1355,238 +9313,237 @@ impl VisionRecordsContract {
             .persistent()
             .get(&record_key)
             .ok_or(ContractError::RecordNotFound)?;
-        if record.patient != patient {
-            return Self::unauthorized(&env, &patient, "grant_record_access", "record_owner");
-        }
 
-        let now = env.ledger().timestamp();
-        let expires_at = now + duration_seconds;
-        let grant = AccessGrant {
-            patient: patient.clone(),
-            grantee: grantee.clone(),
-            level: level.clone(),
-            granted_at: now,
-            expires_at,
-        };
+        if caller != record.patient && caller != record.provider {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_record_priority",
+                "record_patient_or_provider",
+            );
+        }
+        if priority == RecordPriority::Archivable {
+            legal_hold::require_not_held(&env, record_id)?;
+        }
 
-        let key = (symbol_short!("REC_ACC"), record_id, grantee.clone());
-        env.storage().persistent().set(&key, &grant);
-        extend_ttl_record_access_key(&env, &key);
+        let previous = priority::set_priority(&env, record_id, &priority);
+        events::publish_record_priority_changed(&env, record_id, caller, previous, priority);
 
-        events::publish_record_access_granted(
-            &env,
-            patient,
-            grantee,
-            record_id,
-            level,
-            duration_seconds,
-            expires_at,
-        );
         Ok(())
     }
 
-    /// Check record-level access for a specific grantee.
-    pub fn check_record_access(env: Env, record_id: u64, grantee: Address) -> AccessLevel {
-        let key = (symbol_short!("REC_ACC"), record_id, grantee);
-        if let Some(grant) = env.storage().persistent().get::<_, AccessGrant>(&key) {
-            if grant.expires_at > env.ledger().timestamp() {
-                return grant.level;
-            }
+    /// Get the pinning priority of a record. Records without a stored
+    /// priority report `Standard`.
+    pub fn get_record_priority(env: Env, record_id: u64) -> Result<RecordPriority, ContractError> {
+        Self::require_initialized(&env)?;
+        let record_key = (symbol_short!("RECORD"), record_id);
+        if !env.storage().persistent().has(&record_key) {
+            return Err(ContractError::RecordNotFound);
         }
-        AccessLevel::None
+        Ok(priority::get_priority(&env, record_id))
     }
 
-    /// Revoke record-level access for a specific record.
-    pub fn revoke_record_access(
+    /// List record IDs with the given priority, for bulk pinning reconciliation.
+    ///
+    /// Requires at least `OperatorAdmin` tier, or ClinicalAdmin.
+    /// `Critical` and `Archivable` are read from their index: `cursor` is a
+    /// position and at most `bounded::MAX_BOUNDED_ITEMS` positions are
+    /// walked. Positions never move, so paging neither skips nor repeats
+    /// IDs. `Standard` is not indexed: `cursor` is the record ID to resume
+    /// from and at most `priority::STANDARD_SCAN_SIZE` IDs are checked.
+    pub fn get_records_by_priority(
         env: Env,
-        patient: Address,
-        grantee: Address,
-        record_id: u64,
-    ) -> Result<(), ContractError> {
-        patient.require_auth();
-        let record_key = (symbol_short!("RECORD"), record_id);
-        let record: VisionRecord = env
-            .storage()
-            .persistent()
-            .get(&record_key)
-            .ok_or(ContractError::RecordNotFound)?;
-        if record.patient != patient {
-            return Self::unauthorized(&env, &patient, "revoke_record_access", "record_owner");
+        caller: Address,
+        priority: RecordPriority,
+        cursor: u64,
+        limit: u32,
+    ) -> Result<PriorityRecords, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+
+        if !Self::has_clinical_admin_access(&env, &caller, &AdminTier::OperatorAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "get_records_by_priority",
+                "admin_tier:OperatorAdmin",
+            );
         }
 
-        let key = (symbol_short!("REC_ACC"), record_id, grantee);
-        env.storage().persistent().remove(&key);
+        if limit == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+
+        if priority == RecordPriority::Standard {
+            return Ok(priority::scan_standard(
+                &env,
+                cursor,
+                limit.min(priority::STANDARD_SCAN_SIZE),
+            ));
+        }
+        Ok(priority::get_range(
+            &env,
+            &priority,
+            u32::try_from(cursor).unwrap_or(u32::MAX),
+            limit.min(bounded::MAX_BOUNDED_ITEMS),
+        ))
+    }
+
+    /// Enable or disable defaulting new Surgery and Diagnosis records to
+    /// `Critical` priority.
+    ///
+    /// Requires at least `ContractAdmin` tier, or legacy admin/InfraAdmin.
+    pub fn set_priority_auto_critical(
+        env: Env,
+        caller: Address,
+        enabled: bool,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_priority_auto_critical",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        priority::set_auto_critical_enabled(&env, enabled);
         Ok(())
     }
 
-    /// Grant consent for a grantee.
-    pub fn grant_consent(
+    /// Whether new Surgery and Diagnosis records default to `Critical` priority.
+    pub fn is_priority_auto_critical(env: Env) -> bool {
+        priority::is_auto_critical_enabled(&env)
+    }
+
+    /// Set the number of records a patient may hold unless they have their
+    /// own quota. Requires ContractAdmin.
+    pub fn set_default_patient_quota(
         env: Env,
-        patient: Address,
-        grantee: Address,
-        consent_type: ConsentType,
-        duration_seconds: u64,
+        caller: Address,
+        quota: u32,
     ) -> Result<(), ContractError> {
-        patient.require_auth();
-        if duration_seconds == 0 {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_default_patient_quota",
+                "admin_tier:ContractAdmin",
+            );
+        }
+        if quota == 0 {
             return Err(ContractError::InvalidInput);
         }
-        let now = env.ledger().timestamp();
-        let consent = ConsentGrant {
-            patient: patient.clone(),
-            grantee: grantee.clone(),
-            consent_type: consent_type.clone(),
-            granted_at: now,
-            expires_at: now.saturating_add(duration_seconds),
-            revoked: false,
-        };
-        let key = consent_key(&patient, &grantee);
-        env.storage().persistent().set(&key, &consent);
-        extend_ttl_access_key(&env, &key);
-        events::publish_consent_granted(&env, patient, grantee, consent_type, consent.expires_at);
+        quota::set_default_quota(&env, quota);
         Ok(())
     }
 
-    /// Revoke previously granted consent.
-    pub fn revoke_consent(
+    /// Override the record quota for one patient, taking precedence over
+    /// the default. A `quota` of 0 removes the override. A quota below the
+    /// patient's current usage blocks new records without touching existing
+    /// ones. Requires OperatorAdmin.
+    pub fn set_patient_quota(
         env: Env,
+        caller: Address,
         patient: Address,
-        grantee: Address,
+        quota: u32,
     ) -> Result<(), ContractError> {
-        patient.require_auth();
-        let key = consent_key(&patient, &grantee);
-        if let Some(mut consent) = env.storage().persistent().get::<_, ConsentGrant>(&key) {
-            consent.revoked = true;
-            env.storage().persistent().set(&key, &consent);
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_admin_access(&env, &caller, &AdminTier::OperatorAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_patient_quota",
+                "admin_tier:OperatorAdmin",
+            );
         }
-        events::publish_consent_revoked(&env, patient, grantee);
+        let quota = if quota == 0 { None } else { Some(quota) };
+        quota::set_override(&env, &patient, quota);
         Ok(())
     }
 
-    /// Revoke access
-    pub fn revoke_access(
+    /// How many records the patient holds and how many they may hold.
+    pub fn get_patient_quota_usage(env: Env, patient: Address) -> QuotaUsage {
+        QuotaUsage {
+            used: Self::patient_record_ids(&env, &patient).len(),
+            limit: quota::limit_for(&env, &patient),
+        }
+    }
+
+    /// Flag patients affected by a breach of the off-chain store, so
+    /// wallets can warn them and their grants can be reviewed. While
+    /// flagged, only the patient may grant new access. At most
+    /// `MAX_BREACH_BATCH` patients per call.
+    pub fn flag_breach(
         env: Env,
-        patient: Address,
-        grantee: Address,
+        caller: Address,
+        patients: Vec<Address>,
+        incident_ref_hash: String,
     ) -> Result<(), ContractError> {
-        patient.require_auth();
-
-        let key = (symbol_short!("ACCESS"), patient.clone(), grantee.clone());
-        env.storage().persistent().remove(&key);
-
-        // Log successful access revoke
-        let audit_entry = audit::create_audit_entry(
-            &env,
-            patient.clone(),
-            patient.clone(),
-            None,
-            AccessAction::RevokeAccess,
-            AccessResult::Success,
-            None,
-        );
-        audit::add_audit_entry(&env, &audit_entry);
-        events::publish_audit_log_entry(&env, &audit_entry);
-
-        events::publish_access_revoked(&env, patient, grantee);
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_clinical_admin_access(&env, &caller, &AdminTier::OperatorAdmin) {
+            return Self::unauthorized(&env, &caller, "flag_breach", "admin_tier:OperatorAdmin");
+        }
+        if patients.is_empty()
+            || patients.len() > breach::MAX_BREACH_BATCH
+            || incident_ref_hash.is_empty()
+        {
+            return Err(ContractError::InvalidInput);
+        }
 
+        let now = env.ledger().timestamp();
+        for patient in patients.iter() {
+            let flag = BreachFlag {
+                patient,
+                incident_ref_hash: incident_ref_hash.clone(),
+                flagged_by: caller.clone(),
+                flagged_at: now,
+            };
+            breach::set_flag(&env, &flag);
+            events::publish_breach_flagged(&env, &flag);
+        }
         Ok(())
     }
 
-    /// Purge all expired access grants for a given patient.
-    ///
-    /// Only the patient themselves or a SystemAdmin may call this.
-    /// Returns the number of grants removed.
-    pub fn purge_expired_grants(
+    /// The patient's breach flag, if any. Readable by the patient and
+    /// admins.
+    pub fn get_breach_status(
         env: Env,
         caller: Address,
         patient: Address,
-    ) -> Result<u32, ContractError> {
+    ) -> Result<Option<BreachFlag>, ContractError> {
+        Self::require_initialized(&env)?;
         caller.require_auth();
-
-        let is_patient = caller == patient;
-        let is_admin = rbac::has_permission(&env, &caller, &Permission::SystemAdmin);
-        if !is_patient && !is_admin {
+        if caller != patient
+            && !Self::has_clinical_admin_access(&env, &caller, &AdminTier::OperatorAdmin)
+        {
             return Self::unauthorized(
                 &env,
                 &caller,
-                "purge_expired_grants",
-                "patient_or_permission:SystemAdmin",
+                "get_breach_status",
+                "patient_or_admin_tier:OperatorAdmin",
             );
         }
-
-        let list_key = (symbol_short!("ACC_LST"), patient.clone());
-        let grantees: Vec<Address> = env
-            .storage()
-            .persistent()
-            .get(&list_key)
-            .unwrap_or(Vec::new(&env));
-
-        let now = env.ledger().timestamp();
-        let mut remaining = Vec::new(&env);
-        let mut purged: u32 = 0;
-
-        for i in 0..grantees.len() {
-            if let Some(grantee) = grantees.get(i) {
-                let access_key = (symbol_short!("ACCESS"), patient.clone(), grantee.clone());
-
-                match env
-                    .storage()
-                    .persistent()
-                    .get::<_, AccessGrant>(&access_key)
-                {
-                    Some(grant) if grant.expires_at <= now => {
-                        env.storage().persistent().remove(&access_key);
-                        events::publish_access_expired(
-                            &env,
-                            patient.clone(),
-                            grantee,
-                            grant.expires_at,
-                        );
-                        purged += 1;
-                    }
-                    Some(_) => {
-                        // Grant still active — keep in the list.
-                        remaining.push_back(grantee);
-                    }
-                    None => {
-                        // Already removed — nothing to purge, drop from list.
-                    }
-                }
-            }
-        }
-
-        // Update the grantee list to only keep active entries.
-        if remaining.is_empty() {
-            env.storage().persistent().remove(&list_key);
-        } else {
-            env.storage().persistent().set(&list_key, &remaining);
-        }
-
-        Ok(purged)
-    }
-
-    /// Get the total number of records
-    pub fn get_record_count(env: Env) -> u64 {
-        let counter_key = symbol_short!("REC_CTR");
-        env.storage().instance().get(&counter_key).unwrap_or(0)
+        Ok(breach::get_flag(&env, &patient))
     }
 
-    /// Get multiple records by IDs.
-    pub fn get_records(env: Env, record_ids: Vec<u64>) -> Result<Vec<VisionRecord>, ContractError> {
-        if record_ids.is_empty() {
-            return Err(ContractError::InvalidInput);
+    /// Clear a patient's breach flag, lifting the grant lockdown. Clearing
+    /// a patient who is not flagged does nothing.
+    pub fn clear_breach(env: Env, caller: Address, patient: Address) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if !Self::has_clinical_admin_access(&env, &caller, &AdminTier::OperatorAdmin) {
+            return Self::unauthorized(&env, &caller, "clear_breach", "admin_tier:OperatorAdmin");
         }
-        let mut records = Vec::new(&env);
-        for record_id in record_ids.iter() {
-            let key = (symbol_short!("RECORD"), record_id);
-            let record = env
-                .storage()
-                .persistent()
-                .get::<_, VisionRecord>(&key)
-                .ok_or(ContractError::RecordNotFound)?;
-            records.push_back(record);
+        if breach::remove_flag(&env, &patient) {
+            events::publish_breach_cleared(&env, patient, caller);
         }
-        Ok(records)
+        Ok(())
     }
 
     /// Add a new prescription
@@ -1602,6 +9559,7 @@ impl VisionRecordsContract {
         duration_seconds: u64,
         metadata_hash: String,
     ) -> Result<u64, ContractError> {
+        Self::require_initialized(&env)?;
         provider.require_auth();
 
         // Check if provider is authorized (role check)
@@ -1616,9 +9574,7 @@ impl VisionRecordsContract {
         }
 
         // Generate ID
-        let counter_key = symbol_short!("RX_CTR");
-        let rx_id: u64 = env.storage().instance().get(&counter_key).unwrap_or(0) + 1;
-        env.storage().instance().set(&counter_key, &rx_id);
+        let rx_id = counters::next_id(&env, &symbol_short!("RX_CTR"))?;
 
         let rx = Prescription {
             id: rx_id,
@@ -1641,6 +9597,7 @@ impl VisionRecordsContract {
 
     /// Get a prescription by ID
     pub fn get_prescription(env: Env, rx_id: u64) -> Result<Prescription, ContractError> {
+        Self::require_initialized(&env)?;
         prescription::get_prescription(&env, rx_id).ok_or(ContractError::RecordNotFound)
     }
 
@@ -1655,6 +9612,7 @@ impl VisionRecordsContract {
         rx_id: u64,
         verifier: Address,
     ) -> Result<bool, ContractError> {
+        Self::require_initialized(&env)?;
         // Ensure verifier exists
         VisionRecordsContract::get_user(env.clone(), verifier.clone())?;
 
@@ -1677,10 +9635,11 @@ impl VisionRecordsContract {
         gender_hash: String,
         blood_type_hash: String,
     ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
         caller.require_auth();
 
         // Only patient or authorized user can create profile
-        if caller != patient && !rbac::has_permission(&env, &caller, &Permission::ManageUsers) {
+        if !auth::manage_user(&env, &caller, &patient).allowed {
             return Self::unauthorized(
                 &env,
                 &caller,
@@ -1723,6 +9682,7 @@ impl VisionRecordsContract {
         gender_hash: String,
         blood_type_hash: String,
     ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
         caller.require_auth();
 
         // Only profile owner can update
@@ -1755,6 +9715,7 @@ impl VisionRecordsContract {
         patient: Address,
         contact: Option<EmergencyContact>,
     ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
         caller.require_auth();
 
         // Only profile owner can update
@@ -1788,6 +9749,7 @@ impl VisionRecordsContract {
         patient: Address,
         insurance_info: Option<InsuranceInfo>,
     ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
         caller.require_auth();
 
         // Only profile owner can update
@@ -1821,6 +9783,7 @@ impl VisionRecordsContract {
         patient: Address,
         reference: String,
     ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
         caller.require_auth();
 
         // Only profile owner can update
@@ -1851,6 +9814,7 @@ impl VisionRecordsContract {
 
     /// Get patient profile
     pub fn get_profile(env: Env, patient: Address) -> Result<PatientProfile, ContractError> {
+        Self::require_initialized(&env)?;
         let profile_key = (symbol_short!("PAT_PROF"), patient);
         env.storage()
             .persistent()
@@ -1864,72 +9828,305 @@ impl VisionRecordsContract {
         env.storage().persistent().has(&profile_key)
     }
 
-    /// Grants a custom permission to a user.
-    /// Requires the caller to have ManageUsers permission.
-    pub fn grant_custom_permission(
+    /// Choose who may read the patient's record summary. Only the patient
+    /// may set this; it defaults to `Private`.
+    pub fn set_summary_visibility(
+        env: Env,
+        patient: Address,
+        mode: SummaryVisibility,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        patient.require_auth();
+        patient_summary::set_visibility(&env, &patient, &mode);
+        Ok(())
+    }
+
+    /// Who may read the patient's record summary.
+    pub fn get_summary_visibility(env: Env, patient: Address) -> SummaryVisibility {
+        patient_summary::get_visibility(&env, &patient)
+    }
+
+    /// How many records of each type the patient has and when the newest
+    /// of each was created, without any record content, so a provider can
+    /// decide whether to request access. Readable by the patient and by
+    /// OperatorAdmins or ClinicalAdmins, and by others as the patient's
+    /// `SummaryVisibility` allows.
+    pub fn get_patient_profile_summary(
+        env: Env,
+        caller: Address,
+        patient: Address,
+    ) -> Result<PatientProfileSummary, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+
+        let is_grantee = || {
+            Self::check_access(env.clone(), patient.clone(), caller.clone()) != AccessLevel::None
+        };
+        let allowed = caller == patient
+            || Self::has_clinical_admin_access(&env, &caller, &AdminTier::OperatorAdmin)
+            || match patient_summary::get_visibility(&env, &patient) {
+                SummaryVisibility::Public => true,
+                SummaryVisibility::RegisteredProvidersOnly => {
+                    Self::is_active_clinician(&env, &caller) || is_grantee()
+                }
+                SummaryVisibility::GranteesOnly => is_grantee(),
+                SummaryVisibility::Private => false,
+            };
+        if !allowed {
+            return Self::access_denied(
+                &env,
+                &caller,
+                "get_patient_profile_summary",
+                "summary_visibility",
+            );
+        }
+
+        Ok(patient_summary::get_summary(&env, &patient))
+    }
+
+    /// Whether `user` holds an active Optometrist or Ophthalmologist role.
+    fn is_active_clinician(env: &Env, user: &Address) -> bool {
+        rbac::get_active_assignment(env, user).is_some_and(|assignment| {
+            assignment.role == Role::Optometrist || assignment.role == Role::Ophthalmologist
+        })
+    }
+
+    /// Grants a custom permission to a user.
+    /// Requires the caller to have ManageUsers permission.
+    pub fn grant_custom_permission(
+        env: Env,
+        caller: Address,
+        user: Address,
+        permission: Permission,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        // Unified check: covers direct role, custom grants, and delegated roles
+        if !rbac::has_permission(&env, &caller, &Permission::ManageUsers) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "grant_custom_permission",
+                "permission:ManageUsers",
+            );
+        }
+        // Admin permissions only come from someone who holds them
+        if rbac::is_admin_permission(&permission)
+            && !rbac::has_admin_permission(&env, &caller, &permission)
+        {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "grant_custom_permission",
+                "permission:granted",
+            );
+        }
+        rbac::grant_custom_permission(&env, user, permission)
+            .map_err(|_| ContractError::UserNotFound)?;
+        Ok(())
+    }
+
+    /// Revokes a custom permission from a user.
+    /// Requires the caller to have ManageUsers permission.
+    pub fn revoke_custom_permission(
+        env: Env,
+        caller: Address,
+        user: Address,
+        permission: Permission,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        // Unified check: covers direct role, custom grants, and delegated roles
+        if !rbac::has_permission(&env, &caller, &Permission::ManageUsers) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "revoke_custom_permission",
+                "permission:ManageUsers",
+            );
+        }
+        // Admin permissions only come from someone who holds them
+        if rbac::is_admin_permission(&permission)
+            && !rbac::has_admin_permission(&env, &caller, &permission)
+        {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "revoke_custom_permission",
+                "permission:granted",
+            );
+        }
+        rbac::revoke_custom_permission(&env, user, permission)
+            .map_err(|_| ContractError::UserNotFound)?;
+        Ok(())
+    }
+
+    /// Delegates a role to another user with an expiration timestamp.
+    /// The delegator must authenticate the transaction. Fails with
+    /// `DelegationCooldown` while the same delegation, recently revoked,
+    /// is still cooling down.
+    pub fn delegate_role(
+        env: Env,
+        delegator: Address,
+        delegatee: Address,
+        role: Role,
+        expires_at: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        delegator.require_auth();
+        if delegation_cooldown::active_until(&env, &delegator, &delegatee, &role).is_some() {
+            return Err(ContractError::DelegationCooldown);
+        }
+        rbac::delegate_role(&env, delegator, delegatee, role, expires_at);
+        Ok(())
+    }
+
+    /// Revoke the role and scoped delegations from `delegator` to
+    /// `delegatee`. Callable by the delegator, or by an OperatorAdmin or
+    /// ClinicalAdmin to force it. A revoked role delegation starts its
+    /// cooldown, see `set_delegation_cooldown`.
+    pub fn revoke_delegation(
+        env: Env,
+        caller: Address,
+        delegator: Address,
+        delegatee: Address,
+    ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if caller != delegator
+            && !Self::has_clinical_admin_access(&env, &caller, &AdminTier::OperatorAdmin)
+        {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "revoke_delegation",
+                "delegator_or_admin_tier:OperatorAdmin",
+            );
+        }
+        rbac::revoke_delegation(&env, &delegator, &delegatee, &caller);
+        Ok(())
+    }
+
+    /// Set how long a revoked role delegation is blocked from being made
+    /// again for the same delegator, delegatee and role. 0 turns cooldowns
+    /// off; cooldowns already running keep their end time. Cooldowns are
+    /// temporary entries, so `seconds` beyond about 180 days fails with
+    /// `InvalidInput`. Requires ContractAdmin.
+    pub fn set_delegation_cooldown(
         env: Env,
         caller: Address,
-        user: Address,
-        permission: Permission,
+        seconds: u64,
     ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
         caller.require_auth();
-        // Unified check: covers direct role, custom grants, and delegated roles
-        if !rbac::has_permission(&env, &caller, &Permission::ManageUsers) {
+        if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
             return Self::unauthorized(
                 &env,
                 &caller,
-                "grant_custom_permission",
-                "permission:ManageUsers",
+                "set_delegation_cooldown",
+                "admin_tier:ContractAdmin",
             );
         }
-        rbac::grant_custom_permission(&env, user, permission)
-            .map_err(|_| ContractError::UserNotFound)?;
+        if !temp_storage::fits_max_ttl(seconds) {
+            return Err(ContractError::InvalidInput);
+        }
+        delegation_cooldown::set_cooldown_seconds(&env, seconds);
         Ok(())
     }
 
-    /// Revokes a custom permission from a user.
-    /// Requires the caller to have ManageUsers permission.
-    pub fn revoke_custom_permission(
+    /// Seconds a revoked role delegation stays blocked; 0 when off.
+    pub fn get_delegation_cooldown(env: Env) -> u64 {
+        delegation_cooldown::get_cooldown_seconds(&env)
+    }
+
+    /// When the cooldown on re-delegating `role` from `delegator` to
+    /// `delegatee` ends, or `None` if none is running.
+    pub fn get_delegation_cooldown_until(
+        env: Env,
+        delegator: Address,
+        delegatee: Address,
+        role: Role,
+    ) -> Option<u64> {
+        delegation_cooldown::active_until(&env, &delegator, &delegatee, &role)
+    }
+
+    /// End a delegation cooldown early so the delegation can be made
+    /// again. Requires at least `OperatorAdmin` tier, or ClinicalAdmin.
+    pub fn clear_delegation_cooldown(
         env: Env,
         caller: Address,
-        user: Address,
-        permission: Permission,
+        delegator: Address,
+        delegatee: Address,
+        role: Role,
     ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
         caller.require_auth();
-        // Unified check: covers direct role, custom grants, and delegated roles
-        if !rbac::has_permission(&env, &caller, &Permission::ManageUsers) {
+        if !Self::has_clinical_admin_access(&env, &caller, &AdminTier::OperatorAdmin) {
             return Self::unauthorized(
                 &env,
                 &caller,
-                "revoke_custom_permission",
-                "permission:ManageUsers",
+                "clear_delegation_cooldown",
+                "admin_tier:OperatorAdmin",
             );
         }
-        rbac::revoke_custom_permission(&env, user, permission)
-            .map_err(|_| ContractError::UserNotFound)?;
+        delegation_cooldown::clear(&env, &delegator, &delegatee, &role);
         Ok(())
     }
 
-    /// Delegates a role to another user with an expiration timestamp.
-    /// The delegator must authenticate the transaction.
-    pub fn delegate_role(
+    /// Delegates only the listed permissions, rather than a whole role, to
+    /// another user until `expires_at` (0 = never). For example, a provider
+    /// can delegate `AppendRecord` to staff who should add records but not
+    /// modify them.
+    pub fn delegate_permissions(
         env: Env,
         delegator: Address,
         delegatee: Address,
-        role: Role,
+        permissions: Vec<Permission>,
         expires_at: u64,
     ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
         delegator.require_auth();
-        rbac::delegate_role(&env, delegator, delegatee, role, expires_at);
+        if permissions.is_empty() {
+            return Err(ContractError::InvalidInput);
+        }
+        rbac::delegate_permissions(&env, delegator, delegatee, permissions, expires_at);
         Ok(())
     }
 
+    /// Get the roles `user` has held: assignments, changes, expiries and
+    /// role delegations to them, oldest first. Only the latest
+    /// `role_history::MAX_ROLE_HISTORY` entries are kept. Readable by the
+    /// user, by holders of `ManageUsers` and by admins.
+    pub fn get_role_history(
+        env: Env,
+        caller: Address,
+        user: Address,
+    ) -> Result<Vec<RoleHistoryEntry>, ContractError> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+
+        if caller != user
+            && !rbac::has_permission(&env, &caller, &Permission::ManageUsers)
+            && !Self::has_clinical_admin_access(&env, &caller, &AdminTier::OperatorAdmin)
+        {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "get_role_history",
+                "self_or_permission:ManageUsers_or_admin",
+            );
+        }
+
+        Ok(role_history::get_history(&env, &user))
+    }
+
     /// Pauses contract operations for a given scope.
     pub fn pause_contract(
         env: Env,
         caller: Address,
         scope: circuit_breaker::PauseScope,
     ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
         caller.require_auth();
         circuit_breaker::pause_contract(&env, &caller, scope)
     }
@@ -1940,6 +10137,7 @@ impl VisionRecordsContract {
         caller: Address,
         scope: circuit_breaker::PauseScope,
     ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
         caller.require_auth();
         circuit_breaker::resume_contract(&env, &caller, scope)
     }
@@ -1951,6 +10149,7 @@ impl VisionRecordsContract {
         group_name: String,
         permissions: Vec<Permission>,
     ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
         caller.require_auth();
         if !rbac::has_permission(&env, &caller, &Permission::ManageUsers) {
             return Self::unauthorized(&env, &caller, "create_acl_group", "permission:ManageUsers");
@@ -1966,6 +10165,7 @@ impl VisionRecordsContract {
         user: Address,
         group_name: String,
     ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
         caller.require_auth();
         if !rbac::has_permission(&env, &caller, &Permission::ManageUsers) {
             return Self::unauthorized(
@@ -1985,6 +10185,7 @@ impl VisionRecordsContract {
         user: Address,
         group_name: String,
     ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
         caller.require_auth();
         if !rbac::has_permission(&env, &caller, &Permission::ManageUsers) {
             return Self::unauthorized(
@@ -2023,6 +10224,7 @@ impl VisionRecordsContract {
         target: Address,
         tier: AdminTier,
     ) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
         caller.require_auth();
         if !admin_tiers::promote_admin(&env, &caller, &target, tier) {
             return Self::unauthorized(&env, &caller, "promote_admin", "admin_tier:SuperAdmin");
@@ -2035,6 +10237,7 @@ impl VisionRecordsContract {
     ///
     /// Only a `SuperAdmin` may call this.
     pub fn demote_admin(env: Env, caller: Address, target: Address) -> Result<(), ContractError> {
+        Self::require_initialized(&env)?;
         caller.require_auth();
         if !admin_tiers::demote_admin(&env, &caller, &target) {
             return Self::unauthorized(&env, &caller, "demote_admin", "admin_tier:SuperAdmin");
@@ -2050,21 +10253,45 @@ impl VisionRecordsContract {
 
     // ======================== Internal Helpers ========================
 
+    /// Whether the caller's role may see records of `record_type`. Callers
+    /// without an active role are not restricted.
+    fn role_can_see(env: &Env, caller: &Address, record_type: &RecordType) -> bool {
+        match rbac::get_active_assignment(env, caller) {
+            Some(assignment) => visibility::is_visible(env, &assignment.role, record_type),
+            None => true,
+        }
+    }
+
     /// Unified check: returns true if caller has at least the specified admin
-    /// tier, OR is the legacy ADMIN address, OR has SystemAdmin RBAC permission.
+    /// tier, OR is the legacy ADMIN address, OR has InfraAdmin RBAC permission.
     fn has_admin_access(env: &Env, caller: &Address, min_tier: &AdminTier) -> bool {
+        Self::is_tier_admin(env, caller, min_tier) || rbac::has_infra_admin(env, caller)
+    }
+
+    /// Like `has_admin_access`, for the clinical side: records and user
+    /// overrides. Tier admins and the ADMIN address only pass while the
+    /// legacy SystemAdmin flag is on; otherwise ClinicalAdmin is required.
+    fn has_clinical_admin_access(env: &Env, caller: &Address, min_tier: &AdminTier) -> bool {
+        rbac::has_clinical_admin(env, caller)
+            || (rbac::is_legacy_system_admin_enabled(env)
+                && Self::is_tier_admin(env, caller, min_tier))
+    }
+
+    /// Whether caller has at least the specified admin tier or is the
+    /// legacy ADMIN address. A frozen caller has neither.
+    fn is_tier_admin(env: &Env, caller: &Address, min_tier: &AdminTier) -> bool {
+        if rbac::is_frozen(env, caller) {
+            return false;
+        }
         // 1. Check tiered admin system
         if admin_tiers::require_tier(env, caller, min_tier) {
             return true;
         }
         // 2. Fall back to legacy admin address
-        if let Some(admin) = env.storage().instance().get::<Symbol, Address>(&ADMIN) {
-            if *caller == admin {
-                return true;
-            }
-        }
-        // 3. Fall back to RBAC SystemAdmin
-        rbac::has_permission(env, caller, &Permission::SystemAdmin)
+        env.storage()
+            .instance()
+            .get::<Symbol, Address>(&ADMIN)
+            .is_some_and(|admin| *caller == admin)
     }
 }
 
@@ -2081,3 +10308,282 @@ mod test_batch;
 
 #[cfg(test)]
 mod test_admin_tiers;
+
+#[cfg(test)]
+mod test_priority;
+
+#[cfg(test)]
+mod test_versioning;
+
+#[cfg(test)]
+mod test_freeze;
+
+#[cfg(test)]
+mod test_approval;
+
+#[cfg(test)]
+mod test_provider_stats;
+
+#[cfg(test)]
+mod test_access;
+
+#[cfg(test)]
+mod test_temp_storage;
+
+#[cfg(test)]
+mod test_registration;
+
+#[cfg(test)]
+mod test_supersede;
+
+#[cfg(test)]
+mod test_counters;
+
+#[cfg(test)]
+mod test_access_template;
+
+#[cfg(test)]
+mod test_activity;
+
+#[cfg(test)]
+mod test_visibility;
+
+#[cfg(test)]
+mod test_offer;
+
+#[cfg(test)]
+mod test_hash_alg;
+
+#[cfg(test)]
+mod test_record_order;
+
+#[cfg(test)]
+mod test_claim;
+
+#[cfg(test)]
+mod test_purpose;
+
+#[cfg(test)]
+mod test_session;
+
+#[cfg(test)]
+mod test_uninitialized;
+
+#[cfg(test)]
+mod test_quota;
+
+#[cfg(test)]
+mod test_external_ref;
+
+#[cfg(test)]
+mod test_expiry;
+
+#[cfg(test)]
+mod test_provisional;
+
+#[cfg(test)]
+mod test_write_scope;
+
+#[cfg(test)]
+mod test_digest;
+
+#[cfg(test)]
+mod test_breach;
+
+#[cfg(test)]
+mod test_auth;
+
+#[cfg(test)]
+mod test_signed_grant;
+
+#[cfg(test)]
+mod test_inspect;
+
+#[cfg(test)]
+mod test_patient_note;
+
+#[cfg(test)]
+mod test_grantee_migration;
+
+#[cfg(test)]
+mod test_auto_grant;
+
+#[cfg(test)]
+mod test_version_pin;
+
+#[cfg(test)]
+mod test_deactivation;
+
+#[cfg(test)]
+mod test_embargo;
+
+#[cfg(test)]
+mod test_grant_cap;
+
+#[cfg(test)]
+mod test_share_code;
+
+#[cfg(test)]
+mod test_pending_gc;
+
+#[cfg(test)]
+mod test_record_override;
+
+#[cfg(test)]
+mod test_backdate;
+
+#[cfg(test)]
+mod test_heartbeat;
+
+#[cfg(test)]
+mod test_consent_proof;
+
+#[cfg(test)]
+mod test_grant_index;
+
+#[cfg(test)]
+mod test_coauthor;
+
+#[cfg(test)]
+mod test_revocation;
+
+#[cfg(test)]
+mod test_commitment;
+
+#[cfg(test)]
+mod test_receipt;
+
+#[cfg(test)]
+mod test_record_policy;
+
+#[cfg(test)]
+mod test_recovery;
+
+#[cfg(test)]
+mod test_dispute;
+
+#[cfg(test)]
+mod test_history_scope;
+
+#[cfg(test)]
+mod test_cursor;
+
+#[cfg(test)]
+mod test_access_freeze;
+
+#[cfg(test)]
+mod test_observer;
+
+#[cfg(test)]
+mod test_grant_validation;
+
+#[cfg(test)]
+mod test_record_summary;
+
+#[cfg(test)]
+mod test_grant_revision;
+
+#[cfg(test)]
+mod test_legacy_read;
+
+#[cfg(test)]
+mod test_redaction;
+
+#[cfg(test)]
+mod test_organization;
+
+#[cfg(test)]
+mod test_write_session;
+
+#[cfg(test)]
+mod test_full_grant;
+
+#[cfg(test)]
+mod test_write_cost;
+
+#[cfg(test)]
+mod test_residency;
+
+#[cfg(test)]
+mod test_patient_index;
+
+#[cfg(test)]
+mod test_expiry_beacon;
+
+#[cfg(test)]
+mod test_admin_read;
+
+#[cfg(test)]
+mod test_version_diff;
+
+#[cfg(test)]
+mod test_invariants;
+
+#[cfg(test)]
+mod test_provider_template;
+
+#[cfg(test)]
+mod test_record_counter;
+
+#[cfg(test)]
+mod test_access_export;
+
+#[cfg(test)]
+mod test_role_history;
+
+#[cfg(test)]
+mod test_bounded;
+
+#[cfg(test)]
+mod test_user_share;
+
+#[cfg(test)]
+mod test_pending_record;
+
+#[cfg(test)]
+mod test_period_stats;
+
+#[cfg(test)]
+mod test_admin_split;
+
+#[cfg(test)]
+mod test_compliance;
+
+#[cfg(test)]
+mod test_record_preset;
+
+#[cfg(test)]
+mod test_delegation_cooldown;
+
+#[cfg(test)]
+mod test_patient_summary;
+
+#[cfg(test)]
+mod test_account_closure;
+
+#[cfg(test)]
+mod test_handoff_note;
+
+#[cfg(test)]
+mod test_staleness;
+
+#[cfg(test)]
+mod test_self_grant;
+
+#[cfg(test)]
+mod test_jobs;
+
+#[cfg(test)]
+mod test_contract_grantee;
+
+#[cfg(test)]
+mod test_atomic_update;
+
+#[cfg(test)]
+mod test_legal_hold;
+
+#[cfg(test)]
+mod test_instance_usage;
+
+#[cfg(test)]
+mod test_patient_label;
@@ -0,0 +1,134 @@
+use soroban_sdk::{contractclient, contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::activity;
+use crate::errors::ContractError;
+use crate::events;
+use crate::instance_usage::InstanceKey;
+
+// ── Storage keys ──────────────────────────────────────────────
+const OBSERVERS: Symbol = symbol_short!("OBSERVERS");
+const OBSERVERS_ENABLED: Symbol = symbol_short!("OBS_ON");
+
+/// Instance-storage keys written here.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] = &[
+    InstanceKey::list(OBSERVERS, 96),
+    InstanceKey::flag(OBSERVERS_ENABLED),
+];
+
+/// Most observers that may be registered at once. Every one of them is
+/// invoked inside the record write, so this bounds what a write costs.
+pub const MAX_OBSERVERS: u32 = 3;
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// The interface an observer contract implements.
+#[contractclient(name = "RecordObserverClient")]
+pub trait RecordObserver {
+    /// Called after a record event the observer subscribed to. `topic` is
+    /// `REC_ADD` or `REC_UPD`.
+    fn on_record_event(env: Env, topic: Symbol, record_id: u64, patient: Address);
+}
+
+/// A registered observer and the record events it is notified of.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Observer {
+    pub address: Address,
+    pub events: Vec<Symbol>,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// Observers are invoked with try-call semantics: an observer that panics
+// or returns an error has its own changes rolled back and is reported in
+// an `OBS_FAIL` event, while the record write goes on. Running out of
+// budget is different. The budget belongs to the whole transaction, so an
+// observer that exhausts it aborts the record write along with it. That
+// is why notification is off until an admin turns it on. The record write
+// holds the reentrancy guard, so an observer cannot call back into this
+// contract's guarded entry points.
+
+/// Record events observers may subscribe to.
+pub fn is_observable(topic: &Symbol) -> bool {
+    *topic == activity::RECORD_ADDED || *topic == activity::RECORD_UPDATED
+}
+
+/// Whether registered observers are notified at all.
+pub fn is_enabled(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&OBSERVERS_ENABLED)
+        .unwrap_or(false)
+}
+
+pub fn set_enabled(env: &Env, enabled: bool) {
+    env.storage().instance().set(&OBSERVERS_ENABLED, &enabled);
+}
+
+pub fn get_observers(env: &Env) -> Vec<Observer> {
+    env.storage()
+        .instance()
+        .get(&OBSERVERS)
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_observers(env: &Env, observers: &Vec<Observer>) {
+    env.storage().instance().set(&OBSERVERS, observers);
+}
+
+/// Registers `observer`, or replaces its subscriptions if it is already
+/// registered.
+pub fn register(env: &Env, observer: &Observer) -> Result<(), ContractError> {
+    if observer.events.is_empty() || !observer.events.iter().all(|topic| is_observable(&topic)) {
+        return Err(ContractError::InvalidInput);
+    }
+    let mut observers = get_observers(env);
+    match observers
+        .iter()
+        .position(|existing| existing.address == observer.address)
+    {
+        Some(index) => observers.set(index as u32, observer.clone()),
+        None if observers.len() >= MAX_OBSERVERS => {
+            return Err(ContractError::ObserverLimitReached)
+        }
+        None => observers.push_back(observer.clone()),
+    }
+    set_observers(env, &observers);
+    Ok(())
+}
+
+/// Removes `address` from the observers. Returns whether it was registered.
+pub fn unregister(env: &Env, address: &Address) -> bool {
+    let mut observers = get_observers(env);
+    match observers
+        .iter()
+        .position(|existing| existing.address == *address)
+    {
+        Some(index) => {
+            observers.remove(index as u32);
+            set_observers(env, &observers);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Invokes every observer subscribed to `topic`, unless notification is
+/// turned off.
+pub fn notify(env: &Env, topic: &Symbol, record_id: u64, patient: &Address) {
+    if !is_enabled(env) {
+        return;
+    }
+    for observer in get_observers(env).iter() {
+        if !observer.events.contains(topic) {
+            continue;
+        }
+        let client = RecordObserverClient::new(env, &observer.address);
+        if !matches!(
+            client.try_on_record_event(topic, &record_id, patient),
+            Ok(Ok(()))
+        ) {
+            events::publish_observer_failed(env, observer.address, topic.clone(), record_id);
+        }
+    }
+}
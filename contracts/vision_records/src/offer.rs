@@ -0,0 +1,75 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::instance_usage::InstanceKey;
+use crate::pending_gc::{self, PendingCategory, PendingEntry};
+use crate::temp_storage;
+use crate::AccessLevel;
+
+// ── Storage keys ──────────────────────────────────────────────
+const ACCESS_OFFER: Symbol = symbol_short!("ACC_OFR");
+const OFFER_WINDOW: Symbol = symbol_short!("OFR_WIN");
+
+/// Instance-storage keys written here.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] = &[InstanceKey::number(OFFER_WINDOW)];
+
+/// How long an offer waits for the grantee by default.
+pub const DEFAULT_OFFER_WINDOW_SECONDS: u64 = 604_800; // 7 days
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A grant the patient has offered but the grantee has not yet accepted.
+///
+/// Offers are not access grants: they grant nothing until accepted, at
+/// which point the grant's duration starts. Held in temporary storage, so
+/// an unanswered offer is dropped by the network shortly after it lapses.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessOffer {
+    pub patient: Address,
+    pub grantee: Address,
+    pub level: AccessLevel,
+    pub duration_seconds: u64,
+    pub offered_at: u64,
+    /// Acceptance deadline; the offer is discarded if not accepted by then.
+    pub expires_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn offer_key(patient: &Address, grantee: &Address) -> (Symbol, Address, Address) {
+    (ACCESS_OFFER, patient.clone(), grantee.clone())
+}
+
+/// How long new offers stay open.
+pub fn get_offer_window(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&OFFER_WINDOW)
+        .unwrap_or(DEFAULT_OFFER_WINDOW_SECONDS)
+}
+
+pub fn set_offer_window(env: &Env, seconds: u64) {
+    env.storage().instance().set(&OFFER_WINDOW, &seconds);
+}
+
+pub fn get_offer(env: &Env, patient: &Address, grantee: &Address) -> Option<AccessOffer> {
+    temp_storage::get_temp(env, &offer_key(patient, grantee))
+}
+
+/// Stores an offer until slightly past its acceptance deadline.
+pub fn set_offer(env: &Env, offer: &AccessOffer) {
+    let key = offer_key(&offer.patient, &offer.grantee);
+    let window = offer.expires_at.saturating_sub(offer.offered_at);
+    let ttl = temp_storage::ledgers_for_seconds(window).saturating_add(temp_storage::TEMP_TTL_DAY);
+    temp_storage::set_temp(env, &key, offer, ttl);
+    pending_gc::register(
+        env,
+        &PendingCategory::AccessOffer,
+        &PendingEntry::Grant(offer.patient.clone(), offer.grantee.clone()),
+        offer.expires_at,
+    );
+}
+
+pub fn remove_offer(env: &Env, patient: &Address, grantee: &Address) {
+    temp_storage::remove_temp(env, &offer_key(patient, grantee));
+}
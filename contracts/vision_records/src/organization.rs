@@ -0,0 +1,174 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::{expiry, AccessLevel};
+
+// ── Storage keys ──────────────────────────────────────────────
+const ORGANIZATION: Symbol = symbol_short!("ORG");
+const ORG_MEMBER: Symbol = symbol_short!("ORG_MEM");
+const ORG_GRANT: Symbol = symbol_short!("ORG_GRT");
+
+/// Maximum number of units a single organization may hold.
+pub const MAX_UNITS_PER_ORG: u32 = 20;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for an organization key.
+fn extend_ttl_org_key(env: &Env, key: &(Symbol, Symbol)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+/// Extends the time-to-live (TTL) for a membership key.
+fn extend_ttl_member_key(env: &Env, key: &(Symbol, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+/// Extends the time-to-live (TTL) for an organization grant key.
+fn extend_ttl_grant_key(env: &Env, key: &(Symbol, Address, Symbol, Option<Symbol>)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A provider organization, such as a hospital system, and the units
+/// (departments) it is divided into.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Organization {
+    pub id: Symbol,
+    pub admin: Address,
+    pub units: Vec<Symbol>,
+    pub created_at: u64,
+}
+
+/// Where a user sits in an organization. A user belongs to at most one
+/// organization and at most one of its units.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrgMembership {
+    pub org: Symbol,
+    /// Whether the user was added to the organization itself, rather than
+    /// only through a unit.
+    pub direct: bool,
+    pub unit: Option<Symbol>,
+}
+
+/// Access a patient has given an organization, or one of its units when
+/// `unit` is set.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrgGrant {
+    pub patient: Address,
+    pub org: Symbol,
+    pub unit: Option<Symbol>,
+    pub level: AccessLevel,
+    pub granted_at: u64,
+    pub expires_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// Access resolves from the member to their unit, then to the
+// organization, and stops at the first unexpired grant: a unit grant
+// wins over the organization's even when it is lower or expires sooner.
+// Grants never stack, so once the unit grant lapses the organization's
+// applies again. A membership names one organization and at most one
+// unit, so resolution reads at most two grants.
+
+pub fn org_key(org: &Symbol) -> (Symbol, Symbol) {
+    (ORGANIZATION, org.clone())
+}
+
+pub fn member_key(member: &Address) -> (Symbol, Address) {
+    (ORG_MEMBER, member.clone())
+}
+
+pub fn grant_key(
+    patient: &Address,
+    org: &Symbol,
+    unit: &Option<Symbol>,
+) -> (Symbol, Address, Symbol, Option<Symbol>) {
+    (ORG_GRANT, patient.clone(), org.clone(), unit.clone())
+}
+
+pub fn get_org(env: &Env, org: &Symbol) -> Option<Organization> {
+    env.storage().persistent().get(&org_key(org))
+}
+
+pub fn set_org(env: &Env, organization: &Organization) {
+    let key = org_key(&organization.id);
+    env.storage().persistent().set(&key, organization);
+    extend_ttl_org_key(env, &key);
+}
+
+/// Whether `unit` is one of `org`'s units.
+pub fn has_unit(organization: &Organization, unit: &Symbol) -> bool {
+    organization.units.contains(unit)
+}
+
+pub fn get_membership(env: &Env, member: &Address) -> Option<OrgMembership> {
+    env.storage().persistent().get(&member_key(member))
+}
+
+/// Stores a membership, or removes it once it places the user nowhere.
+pub fn set_membership(env: &Env, member: &Address, membership: &OrgMembership) {
+    let key = member_key(member);
+    if !membership.direct && membership.unit.is_none() {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, membership);
+    extend_ttl_member_key(env, &key);
+}
+
+pub fn get_grant(
+    env: &Env,
+    patient: &Address,
+    org: &Symbol,
+    unit: &Option<Symbol>,
+) -> Option<OrgGrant> {
+    env.storage()
+        .persistent()
+        .get(&grant_key(patient, org, unit))
+}
+
+pub fn set_grant(env: &Env, grant: &OrgGrant) {
+    let key = grant_key(&grant.patient, &grant.org, &grant.unit);
+    env.storage().persistent().set(&key, grant);
+    extend_ttl_grant_key(env, &key);
+}
+
+/// Removes a grant, returning whether there was one.
+pub fn remove_grant(env: &Env, patient: &Address, org: &Symbol, unit: &Option<Symbol>) -> bool {
+    let key = grant_key(patient, org, unit);
+    let existed = env.storage().persistent().has(&key);
+    env.storage().persistent().remove(&key);
+    existed
+}
+
+fn active_grant(
+    env: &Env,
+    patient: &Address,
+    org: &Symbol,
+    unit: &Option<Symbol>,
+) -> Option<OrgGrant> {
+    get_grant(env, patient, org, unit).filter(|grant| !expiry::is_expired(env, grant.expires_at))
+}
+
+/// The access `member` holds to `patient`'s records through their
+/// organization, if any.
+pub fn resolve(env: &Env, patient: &Address, member: &Address) -> Option<AccessLevel> {
+    let membership = get_membership(env, member)?;
+    if membership.unit.is_some() {
+        if let Some(grant) = active_grant(env, patient, &membership.org, &membership.unit) {
+            return Some(grant.level);
+        }
+    }
+    active_grant(env, patient, &membership.org, &None).map(|grant| grant.level)
+}
@@ -0,0 +1,203 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::bounded::{self, MAX_BOUNDED_ITEMS};
+use crate::{commitment, cursor, ContractError, VisionRecord};
+
+// ── Storage keys ──────────────────────────────────────────────
+const PATIENT_RECORDS: Symbol = symbol_short!("PAT_REC");
+
+/// Largest patient record list `repair` rebuilds in one call.
+pub const MAX_REPAIR_INDEX_SIZE: u32 = 500;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a patient's record list.
+fn extend_ttl_records_key(env: &Env, key: &(Symbol, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Outcome of one `repair_patient_index` run.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PatientIndexRepair {
+    /// Repeated record IDs dropped from the list.
+    pub duplicates_removed: u32,
+    /// Positions of the de-duplicated list that now hold a different ID.
+    pub entries_moved: u32,
+}
+
+/// Outcome of checking one window of a patient's record list; pass
+/// `next_offset` back as `offset` while `truncated` to check the rest.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PatientIndexCheck {
+    /// Whether every entry in the window sorts strictly after the one
+    /// before it.
+    pub ordered: bool,
+    pub truncated: bool,
+    pub next_offset: u32,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// The list holds each of the patient's record IDs once, in creation order
+// with ties broken by ID, so a backdated import sits among the records
+// created around its original date. Cursors rely on that order: anything
+// that shifts existing entries bumps the patient's records generation.
+
+pub fn records_key(patient: &Address) -> (Symbol, Address) {
+    (PATIENT_RECORDS, patient.clone())
+}
+
+/// The patient's record IDs in creation order.
+pub fn get_records(env: &Env, patient: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&records_key(patient))
+        .unwrap_or(Vec::new(env))
+}
+
+fn write_records(env: &Env, patient: &Address, record_ids: &Vec<u64>) {
+    let key = records_key(patient);
+    if record_ids.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, record_ids);
+        extend_ttl_records_key(env, &key);
+    }
+}
+
+/// Drops the first `count` records from the patient's list, e.g. once
+/// they have moved to another address.
+pub fn remove_first(env: &Env, patient: &Address, count: u32) {
+    if count == 0 {
+        return;
+    }
+    let record_ids = get_records(env, patient);
+    let count = count.min(record_ids.len());
+    write_records(env, patient, &record_ids.slice(count..));
+    cursor::bump_records_generation(env, patient);
+}
+
+/// Where `record_id` belongs in a list: by creation time, then by ID. A
+/// record that no longer exists sorts first.
+fn sort_key(env: &Env, record_id: u64) -> (u64, u64) {
+    let created_at = env
+        .storage()
+        .persistent()
+        .get::<_, VisionRecord>(&(symbol_short!("RECORD"), record_id))
+        .map(|record| record.created_at)
+        .unwrap_or(0);
+    (created_at, record_id)
+}
+
+/// Inserts a stored record into the patient's list at its place in
+/// creation order and appends it to the patient's commitment log. A record
+/// already listed is left alone; returns whether it was inserted.
+pub fn insert(env: &Env, patient: &Address, record_id: u64) -> bool {
+    let mut record_ids = get_records(env, patient);
+    if record_ids.contains(record_id) {
+        return false;
+    }
+
+    // New records almost always belong at the end, so check the last entry
+    // before binary-searching; either way only O(log n) records are loaded
+    let key = sort_key(env, record_id);
+    let len = record_ids.len();
+    let mut at = len;
+    if len > 0 && sort_key(env, record_ids.get_unchecked(len.saturating_sub(1))) > key {
+        let (mut low, mut high) = (0, len.saturating_sub(1));
+        while low < high {
+            let mid = low.saturating_add(high.saturating_sub(low) / 2);
+            if sort_key(env, record_ids.get_unchecked(mid)) > key {
+                high = mid;
+            } else {
+                low = mid.saturating_add(1);
+            }
+        }
+        at = low;
+    }
+    // Inserting ahead of existing records shifts their positions
+    if at < record_ids.len() {
+        cursor::bump_records_generation(env, patient);
+    }
+    record_ids.insert(at, record_id);
+    write_records(env, patient, &record_ids);
+    commitment::append(env, patient, record_id);
+    true
+}
+
+/// Checks that up to `MAX_BOUNDED_ITEMS` entries from `offset` are each
+/// strictly after the entry before them, which also rules out adjacent
+/// duplicates. A list is in order when every window reports `ordered`.
+pub fn verify(env: &Env, patient: &Address, offset: u32) -> PatientIndexCheck {
+    let record_ids = get_records(env, patient);
+    let offset = offset.min(record_ids.len());
+    let (end, truncated) = bounded::window(offset, record_ids.len(), MAX_BOUNDED_ITEMS);
+    let mut previous = offset
+        .checked_sub(1)
+        .map(|at| sort_key(env, record_ids.get_unchecked(at)));
+    let mut ordered = true;
+    for at in offset..end {
+        let key = sort_key(env, record_ids.get_unchecked(at));
+        if previous.is_some_and(|previous| previous >= key) {
+            ordered = false;
+            break;
+        }
+        previous = Some(key);
+    }
+    PatientIndexCheck {
+        ordered,
+        truncated,
+        next_offset: end,
+    }
+}
+
+/// De-duplicates and re-sorts the patient's list in place. The commitment
+/// log is a history of insertions and is left as it is.
+pub fn repair(env: &Env, patient: &Address) -> Result<PatientIndexRepair, ContractError> {
+    let record_ids = get_records(env, patient);
+    if record_ids.len() > MAX_REPAIR_INDEX_SIZE {
+        return Err(ContractError::IndexTooLarge);
+    }
+
+    let mut result = PatientIndexRepair {
+        duplicates_removed: 0,
+        entries_moved: 0,
+    };
+    // Insertion sort, keeping the first-seen order alongside to count moves
+    let mut kept: Vec<u64> = Vec::new(env);
+    let mut sorted: Vec<(u64, u64)> = Vec::new(env);
+    for record_id in record_ids.iter() {
+        let key = sort_key(env, record_id);
+        let mut at = sorted.len();
+        while at > 0 && sorted.get_unchecked(at.saturating_sub(1)) > key {
+            at = at.saturating_sub(1);
+        }
+        if at > 0 && sorted.get_unchecked(at.saturating_sub(1)) == key {
+            result.duplicates_removed = result.duplicates_removed.saturating_add(1);
+            continue;
+        }
+        sorted.insert(at, key);
+        kept.push_back(record_id);
+    }
+
+    let mut repaired: Vec<u64> = Vec::new(env);
+    for (index, (_, record_id)) in sorted.iter().enumerate() {
+        if kept.get_unchecked(index as u32) != record_id {
+            result.entries_moved = result.entries_moved.saturating_add(1);
+        }
+        repaired.push_back(record_id);
+    }
+
+    if result.duplicates_removed > 0 || result.entries_moved > 0 {
+        write_records(env, patient, &repaired);
+        cursor::bump_records_generation(env, patient);
+    }
+    Ok(result)
+}
@@ -0,0 +1,88 @@
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::ContractError;
+
+// ── Storage keys ──────────────────────────────────────────────
+const PATIENT_LABEL: Symbol = symbol_short!("PAT_LBL");
+
+/// Longest label a patient may give a record, in bytes.
+pub const MAX_LABEL_LEN: u32 = 48;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a patient's record label.
+fn extend_ttl_label_key(env: &Env, key: &(Symbol, Address, u64)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// A label is the patient's own name for a record ("my LASIK surgery"). It
+// is keyed by patient rather than stored on the record, so providers'
+// metadata is never touched and a provider change leaves it in place.
+// Only the patient and their guardians are ever shown it.
+
+pub fn label_key(patient: &Address, record_id: u64) -> (Symbol, Address, u64) {
+    (PATIENT_LABEL, patient.clone(), record_id)
+}
+
+pub fn get_label(env: &Env, patient: &Address, record_id: u64) -> Option<String> {
+    env.storage()
+        .persistent()
+        .get(&label_key(patient, record_id))
+}
+
+/// Stores `label`, failing with `InvalidInput` when it is empty or longer
+/// than `MAX_LABEL_LEN`.
+pub fn set_label(
+    env: &Env,
+    patient: &Address,
+    record_id: u64,
+    label: &String,
+) -> Result<(), ContractError> {
+    if label.is_empty() || label.len() > MAX_LABEL_LEN {
+        return Err(ContractError::InvalidInput);
+    }
+    let key = label_key(patient, record_id);
+    env.storage().persistent().set(&key, label);
+    extend_ttl_label_key(env, &key);
+    Ok(())
+}
+
+/// Removes the label. Returns whether there was one.
+pub fn remove_label(env: &Env, patient: &Address, record_id: u64) -> bool {
+    let key = label_key(patient, record_id);
+    if !env.storage().persistent().has(&key) {
+        return false;
+    }
+    env.storage().persistent().remove(&key);
+    true
+}
+
+/// Gives the record that supersedes `old_record_id` the old record's
+/// label, unless the patient has already labeled it.
+pub fn copy_to_record(env: &Env, patient: &Address, old_record_id: u64, new_record_id: u64) {
+    if let Some(label) = get_label(env, patient, old_record_id) {
+        if get_label(env, patient, new_record_id).is_none() {
+            let key = label_key(patient, new_record_id);
+            env.storage().persistent().set(&key, &label);
+            extend_ttl_label_key(env, &key);
+        }
+    }
+}
+
+/// Moves a record's label along with the record to a recovered patient
+/// address.
+pub fn move_to_patient(env: &Env, old_patient: &Address, new_patient: &Address, record_id: u64) {
+    if let Some(label) = get_label(env, old_patient, record_id) {
+        env.storage()
+            .persistent()
+            .remove(&label_key(old_patient, record_id));
+        let key = label_key(new_patient, record_id);
+        env.storage().persistent().set(&key, &label);
+        extend_ttl_label_key(env, &key);
+    }
+}
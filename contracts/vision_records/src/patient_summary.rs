@@ -0,0 +1,152 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::{staleness, RecordType};
+
+// ── Storage keys ──────────────────────────────────────────────
+const PATIENT_SUMMARY: Symbol = symbol_short!("PAT_SUM");
+const SUMMARY_VISIBILITY: Symbol = symbol_short!("SUM_VIS");
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a patient's summary or setting.
+fn extend_ttl_patient_key(env: &Env, key: &(Symbol, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Who besides the patient and admins may read a patient's summary.
+/// Each mode admits everyone the next one does.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SummaryVisibility {
+    /// Any caller
+    Public,
+    /// Active optometrists and ophthalmologists, and grantees
+    RegisteredProvidersOnly,
+    /// Holders of a live patient-wide grant
+    GranteesOnly,
+    /// Only the patient and admins (default)
+    Private,
+}
+
+/// How many records of one type a patient has and when the newest was
+/// created. Backdated imports count toward their original date.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordTypeCount {
+    pub record_type: RecordType,
+    pub count: u32,
+    pub latest_created_at: u64,
+}
+
+/// Counts of a patient's records by type, without any content. Types
+/// with no records are left out.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PatientProfileSummary {
+    pub patient: Address,
+    pub total: u32,
+    pub by_type: Vec<RecordTypeCount>,
+    /// Types whose newest record is stale under the type's staleness
+    /// policy, judged by its creation date.
+    pub stale_types: Vec<RecordType>,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// Counts are kept up to date as records join a patient's index, so
+// reading a summary never scans records. Records indexed before summaries
+// were kept are not counted.
+
+pub fn summary_key(patient: &Address) -> (Symbol, Address) {
+    (PATIENT_SUMMARY, patient.clone())
+}
+
+pub fn visibility_key(patient: &Address) -> (Symbol, Address) {
+    (SUMMARY_VISIBILITY, patient.clone())
+}
+
+pub fn get_counts(env: &Env, patient: &Address) -> Vec<RecordTypeCount> {
+    env.storage()
+        .persistent()
+        .get(&summary_key(patient))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn get_summary(env: &Env, patient: &Address) -> PatientProfileSummary {
+    let by_type = get_counts(env, patient);
+    let mut total: u32 = 0;
+    let mut stale_types = Vec::new(env);
+    for entry in by_type.iter() {
+        total = total.saturating_add(entry.count);
+        if staleness::is_stale(env, &entry.record_type, entry.latest_created_at) {
+            stale_types.push_back(entry.record_type);
+        }
+    }
+    PatientProfileSummary {
+        patient: patient.clone(),
+        total,
+        by_type,
+        stale_types,
+    }
+}
+
+/// Counts one record of `record_type` created at `created_at`.
+pub fn record_added(env: &Env, patient: &Address, record_type: &RecordType, created_at: u64) {
+    let mut counts = get_counts(env, patient);
+    let mut found = false;
+    for i in 0..counts.len() {
+        if let Some(mut entry) = counts.get(i) {
+            if entry.record_type == *record_type {
+                entry.count = entry.count.saturating_add(1);
+                entry.latest_created_at = entry.latest_created_at.max(created_at);
+                counts.set(i, entry);
+                found = true;
+                break;
+            }
+        }
+    }
+    if !found {
+        counts.push_back(RecordTypeCount {
+            record_type: record_type.clone(),
+            count: 1,
+            latest_created_at: created_at,
+        });
+    }
+    let key = summary_key(patient);
+    env.storage().persistent().set(&key, &counts);
+    extend_ttl_patient_key(env, &key);
+}
+
+/// Moves a summary to another address along with the records it counts.
+pub fn move_summary(env: &Env, from: &Address, to: &Address) {
+    let from_key = summary_key(from);
+    let counts: Option<Vec<RecordTypeCount>> = env.storage().persistent().get(&from_key);
+    if let Some(counts) = counts {
+        let to_key = summary_key(to);
+        env.storage().persistent().set(&to_key, &counts);
+        extend_ttl_patient_key(env, &to_key);
+        env.storage().persistent().remove(&from_key);
+    }
+}
+
+pub fn get_visibility(env: &Env, patient: &Address) -> SummaryVisibility {
+    env.storage()
+        .persistent()
+        .get(&visibility_key(patient))
+        .unwrap_or(SummaryVisibility::Private)
+}
+
+pub fn set_visibility(env: &Env, patient: &Address, mode: &SummaryVisibility) {
+    let key = visibility_key(patient);
+    if *mode == SummaryVisibility::Private {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, mode);
+        extend_ttl_patient_key(env, &key);
+    }
+}
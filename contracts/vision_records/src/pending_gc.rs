@@ -0,0 +1,272 @@
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Symbol, Vec};
+
+use crate::{approval, expiry, offer, share_code};
+
+// ── Storage keys ──────────────────────────────────────────────
+const PENDING_PAGE: Symbol = symbol_short!("PEND_IDX");
+const PENDING_COUNT: Symbol = symbol_short!("PEND_CNT");
+const PENDING_OLDEST: Symbol = symbol_short!("PEND_OLD");
+
+/// Entries per stored page of one day's bucket.
+pub const PENDING_PAGE_SIZE: u32 = 50;
+
+const DAY_SECONDS: u64 = 86_400;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a bucket page.
+fn extend_ttl_page_key(env: &Env, key: &(Symbol, PendingCategory, u32, u32)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+/// Extends the time-to-live (TTL) for a bucket's entry count.
+fn extend_ttl_count_key(env: &Env, key: &(Symbol, PendingCategory, u32)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+/// Extends the time-to-live (TTL) for a category's oldest bucket day.
+fn extend_ttl_oldest_key(env: &Env, key: &(Symbol, PendingCategory)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A kind of deadline-bound entry that `gc_pending` can clean up.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PendingCategory {
+    /// Grants waiting on patient approval (`approval::PendingGrant`).
+    AccessRequest,
+    /// Grants offered but not yet accepted (`offer::AccessOffer`).
+    AccessOffer,
+    /// Share codes not yet redeemed (`share_code::ShareCode`).
+    ShareCode,
+}
+
+/// Where a pending entry is stored.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PendingEntry {
+    /// A request or offer, by `(patient, grantee)`.
+    Grant(Address, Address),
+    /// A share code, by `(record_id, code_hash)`.
+    ShareCode(u64, BytesN<32>),
+}
+
+/// Outcome of one `gc_pending` run.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingGcResult {
+    /// Entries whose deadline had passed and were deleted.
+    pub removed: u32,
+    /// The expiry day to resume from, or `None` once every bucket that
+    /// was due has been emptied.
+    pub next_cursor: Option<u32>,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// Each category's entries are bucketed by the UTC day (days since the Unix
+// epoch) their deadline falls on, and each bucket is split into pages of
+// `PENDING_PAGE_SIZE`, so registering an entry touches one page and one
+// count however much is pending. Collection walks only buckets whose day,
+// plus the expiry grace window, has fully passed. Every entry still listed
+// there is expired, gone (accepted, approved, redeemed, cancelled, or
+// dropped by the network), or live under a later deadline it was
+// registered again for, so nothing in a walked bucket is kept. Buckets
+// are drained from their last page, and a run that stops early leaves the
+// rest of the bucket in place. An entry may be listed more than once;
+// later copies find it gone.
+
+pub fn page_key(
+    category: &PendingCategory,
+    day: u32,
+    page_no: u32,
+) -> (Symbol, PendingCategory, u32, u32) {
+    (PENDING_PAGE, category.clone(), day, page_no)
+}
+
+fn count_key(category: &PendingCategory, day: u32) -> (Symbol, PendingCategory, u32) {
+    (PENDING_COUNT, category.clone(), day)
+}
+
+fn oldest_key(category: &PendingCategory) -> (Symbol, PendingCategory) {
+    (PENDING_OLDEST, category.clone())
+}
+
+/// The bucket a deadline falls in.
+pub fn day_of(timestamp: u64) -> u32 {
+    u32::try_from(timestamp / DAY_SECONDS).unwrap_or(u32::MAX)
+}
+
+/// The first bucket that is not yet due: every deadline before it has
+/// passed, grace window included.
+pub fn first_open_day(env: &Env) -> u32 {
+    day_of(
+        env.ledger()
+            .timestamp()
+            .saturating_sub(expiry::get_grace(env)),
+    )
+}
+
+/// Number of entries listed in one day's bucket.
+pub fn bucket_len(env: &Env, category: &PendingCategory, day: u32) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&count_key(category, day))
+        .unwrap_or(0)
+}
+
+fn set_bucket_len(env: &Env, category: &PendingCategory, day: u32, len: u32) {
+    let key = count_key(category, day);
+    if len == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &len);
+        extend_ttl_count_key(env, &key);
+    }
+}
+
+fn get_page(env: &Env, category: &PendingCategory, day: u32, page_no: u32) -> Vec<PendingEntry> {
+    env.storage()
+        .persistent()
+        .get(&page_key(category, day, page_no))
+        .unwrap_or(Vec::new(env))
+}
+
+fn put_page(
+    env: &Env,
+    category: &PendingCategory,
+    day: u32,
+    page_no: u32,
+    entries: &Vec<PendingEntry>,
+) {
+    let key = page_key(category, day, page_no);
+    if entries.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, entries);
+        extend_ttl_page_key(env, &key);
+    }
+}
+
+/// The earliest day that may still have a bucket.
+fn oldest_day(env: &Env, category: &PendingCategory) -> Option<u32> {
+    env.storage().persistent().get(&oldest_key(category))
+}
+
+fn set_oldest_day(env: &Env, category: &PendingCategory, day: u32) {
+    let key = oldest_key(category);
+    env.storage().persistent().set(&key, &day);
+    extend_ttl_oldest_key(env, &key);
+}
+
+/// Lists an entry in the bucket for its deadline.
+#[allow(clippy::arithmetic_side_effects)]
+pub fn register(env: &Env, category: &PendingCategory, entry: &PendingEntry, expires_at: u64) {
+    let day = day_of(expires_at);
+    let len = bucket_len(env, category, day);
+    let page_no = len / PENDING_PAGE_SIZE;
+    let mut page = get_page(env, category, day, page_no);
+    page.push_back(entry.clone());
+    put_page(env, category, day, page_no, &page);
+    set_bucket_len(env, category, day, len + 1);
+    if oldest_day(env, category).map_or(true, |oldest| day < oldest) {
+        set_oldest_day(env, category, day);
+    }
+}
+
+/// The entry's deadline, or `None` if it no longer exists.
+fn deadline(env: &Env, category: &PendingCategory, entry: &PendingEntry) -> Option<u64> {
+    match (category, entry) {
+        (PendingCategory::AccessRequest, PendingEntry::Grant(patient, grantee)) => {
+            approval::get_pending_grant(env, patient, grantee).map(|pending| pending.expires_at)
+        }
+        (PendingCategory::AccessOffer, PendingEntry::Grant(patient, grantee)) => {
+            offer::get_offer(env, patient, grantee).map(|offer| offer.expires_at)
+        }
+        (PendingCategory::ShareCode, PendingEntry::ShareCode(record_id, code_hash)) => {
+            share_code::get_code(env, *record_id, code_hash).map(|code| code.expires_at)
+        }
+        _ => None,
+    }
+}
+
+fn remove(env: &Env, category: &PendingCategory, entry: &PendingEntry) {
+    match (category, entry) {
+        (PendingCategory::AccessRequest, PendingEntry::Grant(patient, grantee)) => {
+            approval::remove_pending_grant(env, patient, grantee)
+        }
+        (PendingCategory::AccessOffer, PendingEntry::Grant(patient, grantee)) => {
+            offer::remove_offer(env, patient, grantee)
+        }
+        (PendingCategory::ShareCode, PendingEntry::ShareCode(record_id, code_hash)) => {
+            share_code::remove_code(env, *record_id, code_hash)
+        }
+        _ => {}
+    }
+}
+
+/// Examines up to `limit` listed entries or empty days in the buckets due
+/// for collection, starting at day `cursor` (0 for the oldest), and
+/// deletes the entries past their deadline. References to live or vanished
+/// entries are dropped.
+#[allow(clippy::arithmetic_side_effects)]
+pub fn collect(env: &Env, category: &PendingCategory, cursor: u32, limit: u32) -> PendingGcResult {
+    let open = first_open_day(env);
+    let Some(oldest) = oldest_day(env, category) else {
+        return PendingGcResult {
+            removed: 0,
+            next_cursor: None,
+        };
+    };
+
+    let mut day = cursor.max(oldest);
+    let mut examined: u32 = 0;
+    let mut removed: u32 = 0;
+    while day < open && examined < limit {
+        let mut left = bucket_len(env, category, day);
+        if left == 0 {
+            day += 1;
+            examined += 1;
+            continue;
+        }
+        let page_no = (left - 1) / PENDING_PAGE_SIZE;
+        let mut page = get_page(env, category, day, page_no);
+        while examined < limit {
+            let Some(entry) = page.pop_back() else {
+                break;
+            };
+            examined += 1;
+            left -= 1;
+            if let Some(expires_at) = deadline(env, category, &entry) {
+                if expiry::is_expired(env, expires_at) {
+                    remove(env, category, &entry);
+                    removed += 1;
+                }
+            }
+        }
+        put_page(env, category, day, page_no, &page);
+        set_bucket_len(env, category, day, left);
+        if left == 0 {
+            day += 1;
+        }
+    }
+
+    // Every bucket from the oldest up to `day` is now empty
+    if cursor <= oldest && day > oldest {
+        set_oldest_day(env, category, day);
+    }
+
+    PendingGcResult {
+        removed,
+        next_cursor: (day < open).then_some(day),
+    }
+}
@@ -0,0 +1,61 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::{expiry, temp_storage, RecordType};
+
+// ── Storage keys ──────────────────────────────────────────────
+const PENDING_RECORD: Symbol = symbol_short!("REC_PEND");
+
+/// How long a reserved record ID waits for its data hash.
+pub const PENDING_RECORD_SECONDS: u64 = 86_400;
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A record ID reserved by `begin_record` while its document is still
+/// uploading. The record itself is only written by `commit_record`; until
+/// then reads fail with `RecordPending` and the record is in no patient
+/// index.
+///
+/// Held in temporary storage. Once abandoned or past `expires_at` the ID
+/// is burned: the counter has moved on and nothing can commit it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingRecord {
+    pub record_id: u64,
+    pub patient: Address,
+    pub provider: Address,
+    pub record_type: RecordType,
+    /// Who reserved the ID; only they may commit or abandon it.
+    pub reserved_by: Address,
+    pub reserved_at: u64,
+    pub expires_at: u64,
+    /// Region the reserver's residency check was overridden for, reported
+    /// when the record is committed.
+    pub residency_override: Option<Symbol>,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn pending_key(record_id: u64) -> (Symbol, u64) {
+    (PENDING_RECORD, record_id)
+}
+
+/// The reservation for `record_id`, if it is still open.
+pub fn get(env: &Env, record_id: u64) -> Option<PendingRecord> {
+    temp_storage::get_temp::<_, PendingRecord>(env, &pending_key(record_id))
+        .filter(|pending| !expiry::is_expired(env, pending.expires_at))
+}
+
+pub fn is_pending(env: &Env, record_id: u64) -> bool {
+    get(env, record_id).is_some()
+}
+
+/// Stores a reservation until slightly past its expiry.
+pub fn set(env: &Env, pending: &PendingRecord) {
+    let ttl = temp_storage::ledgers_for_seconds(PENDING_RECORD_SECONDS)
+        .saturating_add(temp_storage::TEMP_TTL_DAY);
+    temp_storage::set_temp(env, &pending_key(pending.record_id), pending, ttl);
+}
+
+pub fn remove(env: &Env, record_id: u64) {
+    temp_storage::remove_temp(env, &pending_key(record_id));
+}
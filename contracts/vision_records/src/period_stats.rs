@@ -0,0 +1,162 @@
+use soroban_sdk::{symbol_short, Env, Symbol, Vec};
+
+use crate::errors::ContractError;
+use crate::instance_usage::InstanceKey;
+use crate::RecordType;
+
+// ── Storage keys ──────────────────────────────────────────────
+const PERIOD_STATS_DISABLED: Symbol = symbol_short!("PRD_OFF");
+const PERIOD_COUNT: Symbol = symbol_short!("PRD_CNT");
+
+/// Instance-storage keys written here.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] = &[InstanceKey::flag(PERIOD_STATS_DISABLED)];
+
+/// Most monthly buckets one `get_period_counts` call covers (five years),
+/// each a separate storage read.
+pub const MAX_PERIOD_BUCKETS: u32 = 60;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a period counter.
+fn extend_ttl_count_key(env: &Env, key: &(Symbol, RecordType, u32)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Buckets ───────────────────────────────────────────────────
+//
+// Records are counted per record type and calendar month (UTC), with the
+// month written as `YYYYMM`, e.g. `202610`. Only counts are kept: nothing
+// about which patient or provider a record belongs to.
+
+/// The `YYYYMM` bucket of a Unix timestamp.
+#[allow(clippy::arithmetic_side_effects)]
+pub fn month_bucket(timestamp: u64) -> u32 {
+    // Days-to-civil conversion from Howard Hinnant's date algorithms,
+    // restricted to dates from 1970 on.
+    let z = timestamp / 86_400 + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    u32::try_from(year * 100 + month).unwrap_or(u32::MAX)
+}
+
+/// Whether `bucket` names a real month.
+pub fn is_valid_bucket(bucket: u32) -> bool {
+    (1..=12).contains(&(bucket % 100))
+}
+
+/// The bucket of the month after `bucket`.
+#[allow(clippy::arithmetic_side_effects)]
+pub fn next_bucket(bucket: u32) -> u32 {
+    if bucket % 100 == 12 {
+        (bucket / 100 + 1) * 100 + 1
+    } else {
+        bucket + 1
+    }
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn is_enabled(env: &Env) -> bool {
+    !env.storage().instance().has(&PERIOD_STATS_DISABLED)
+}
+
+pub fn set_enabled(env: &Env, enabled: bool) {
+    if enabled {
+        env.storage().instance().remove(&PERIOD_STATS_DISABLED);
+    } else {
+        env.storage().instance().set(&PERIOD_STATS_DISABLED, &true);
+    }
+}
+
+pub fn count_key(record_type: &RecordType, bucket: u32) -> (Symbol, RecordType, u32) {
+    (PERIOD_COUNT, record_type.clone(), bucket)
+}
+
+pub fn get_count(env: &Env, record_type: &RecordType, bucket: u32) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&count_key(record_type, bucket))
+        .unwrap_or(0)
+}
+
+/// Counts a new record under the month it was created in, which for
+/// imported records is their original creation date. Does nothing while
+/// the feature is disabled.
+pub fn record_created(env: &Env, record_type: &RecordType, created_at: u64) {
+    if !is_enabled(env) {
+        return;
+    }
+    let bucket = month_bucket(created_at);
+    let key = count_key(record_type, bucket);
+    let count = get_count(env, record_type, bucket).saturating_add(1);
+    env.storage().persistent().set(&key, &count);
+    extend_ttl_count_key(env, &key);
+}
+
+/// `(bucket, count)` for every month from `from_bucket` to `to_bucket`
+/// inclusive, months without records included as zero. Fails with
+/// `InvalidInput` for a malformed or reversed range or one longer than
+/// `MAX_PERIOD_BUCKETS`.
+pub fn get_counts(
+    env: &Env,
+    record_type: &RecordType,
+    from_bucket: u32,
+    to_bucket: u32,
+) -> Result<Vec<(u32, u64)>, ContractError> {
+    if !is_valid_bucket(from_bucket) || !is_valid_bucket(to_bucket) || from_bucket > to_bucket {
+        return Err(ContractError::InvalidInput);
+    }
+    let mut counts = Vec::new(env);
+    let mut bucket = from_bucket;
+    while bucket <= to_bucket {
+        if counts.len() >= MAX_PERIOD_BUCKETS {
+            return Err(ContractError::InvalidInput);
+        }
+        counts.push_back((bucket, get_count(env, record_type, bucket)));
+        bucket = next_bucket(bucket);
+    }
+    Ok(counts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2024-01-31T23:59:59Z and 2024-02-01T00:00:00Z
+    const END_OF_JANUARY: u64 = 1_706_745_599;
+    const START_OF_FEBRUARY: u64 = 1_706_745_600;
+
+    #[test]
+    fn test_month_bucket_at_month_boundaries() {
+        assert_eq!(month_bucket(0), 197001);
+        assert_eq!(month_bucket(END_OF_JANUARY), 202401);
+        assert_eq!(month_bucket(START_OF_FEBRUARY), 202402);
+        // 2024-02-29T12:00:00Z, a leap day
+        assert_eq!(month_bucket(1_709_208_000), 202402);
+        // 2024-12-31T23:59:59Z and 2025-01-01T00:00:00Z
+        assert_eq!(month_bucket(1_735_689_599), 202412);
+        assert_eq!(month_bucket(1_735_689_600), 202501);
+    }
+
+    #[test]
+    fn test_next_bucket_rolls_over_years() {
+        assert_eq!(next_bucket(202411), 202412);
+        assert_eq!(next_bucket(202412), 202501);
+    }
+
+    #[test]
+    fn test_bucket_validation() {
+        assert!(is_valid_bucket(202401));
+        assert!(is_valid_bucket(202412));
+        assert!(!is_valid_bucket(202400));
+        assert!(!is_valid_bucket(202413));
+    }
+}
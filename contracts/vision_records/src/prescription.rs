@@ -1,5 +1,7 @@
 use soroban_sdk::{contracttype, Address, Env, String, Vec};
 
+use crate::expiry;
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum LensType {
@@ -79,9 +81,13 @@ pub fn get_patient_history(env: &Env, patient: Address) -> Vec<u64> {
         .unwrap_or(Vec::new(env))
 }
 
+/// Marks a prescription verified. Expired prescriptions are not verified.
 pub fn verify_prescription(env: &Env, id: u64, verifier: Address) -> bool {
     if let Some(mut rx) = get_prescription(env, id) {
         verifier.require_auth();
+        if expiry::is_expired(env, rx.expires_at) {
+            return false;
+        }
         rx.verified = true;
         let key = (soroban_sdk::symbol_short!("RX"), id);
         env.storage().persistent().set(&key, &rx);
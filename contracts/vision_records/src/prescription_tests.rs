@@ -10,7 +10,7 @@ fn test_prescription_workflow() {
     let client = VisionRecordsContractClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
-    client.initialize(&admin);
+    client.initialize(&admin, &None);
 
     let patient = Address::generate(&env);
     let doctor = Address::generate(&env);
@@ -86,7 +86,7 @@ fn test_contact_lens_workflow() {
     let client = VisionRecordsContractClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
-    client.initialize(&admin);
+    client.initialize(&admin, &None);
 
     let patient = Address::generate(&env);
     let doctor = Address::generate(&env);
@@ -0,0 +1,317 @@
+use soroban_sdk::{contracttype, symbol_short, Env, Symbol, Vec};
+
+use crate::instance_usage::InstanceKey;
+use crate::{record_counter, RecordType};
+
+// ── Storage keys ──────────────────────────────────────────────
+const REC_PRIORITY: Symbol = symbol_short!("REC_PRIO");
+const PRIORITY_INDEX: Symbol = symbol_short!("PRIO_IDX");
+const PRIORITY_INDEX_LEN: Symbol = symbol_short!("PRIO_LEN");
+const AUTO_CRITICAL: Symbol = symbol_short!("PRIO_AUTO");
+
+/// Instance-storage keys written here.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] = &[InstanceKey::flag(AUTO_CRITICAL)];
+
+/// Record IDs per stored page of a priority index.
+pub const PRIORITY_PAGE_SIZE: u32 = 50;
+
+/// Most record IDs one `Standard` listing checks. Each costs two reads (its
+/// priority slot and the record), so the scan is half the bounded cap.
+pub const STANDARD_SCAN_SIZE: u32 = 25;
+
+/// Marks a position left by a record that changed priority. Record ID 0 is
+/// never allocated.
+const HOLE: u64 = 0;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a record priority key.
+fn extend_ttl_priority_key(env: &Env, key: &(Symbol, u64)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+/// Extends the time-to-live (TTL) for a priority index page.
+fn extend_ttl_page_key(env: &Env, key: &(Symbol, RecordPriority, u32)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+/// Extends the time-to-live (TTL) for a priority index length.
+fn extend_ttl_len_key(env: &Env, key: &(Symbol, RecordPriority)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Retention hint consumed by off-chain pinning services.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum RecordPriority {
+    /// Must always remain pinned
+    Critical = 1,
+    /// Default retention
+    Standard = 2,
+    /// May be moved to cold storage
+    Archivable = 3,
+}
+
+/// One page of record IDs at a priority. Pass `next_cursor` back as
+/// `cursor` while `truncated` to continue; `items` may be shorter than the
+/// limit, since removed and `Standard` positions are skipped.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriorityRecords {
+    pub items: Vec<u64>,
+    pub truncated: bool,
+    pub next_cursor: u64,
+}
+
+/// A record's non-default priority and its position in that priority's
+/// index.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrioritySlot {
+    pub priority: RecordPriority,
+    pub slot: u32,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// Only `Critical` and `Archivable` are stored. A record with no entry is
+// `Standard`, so creating a record at the default priority writes nothing
+// here. Each stored priority has an index of record IDs split into pages of
+// `PRIORITY_PAGE_SIZE`; position `n` is item `n % PRIORITY_PAGE_SIZE` of
+// page `n / PRIORITY_PAGE_SIZE`. A record keeps its position next to its
+// priority. Leaving an index leaves a hole at that position rather than
+// moving another ID into it, so positions never change and paging by
+// position neither skips nor repeats IDs; every change touches at most two
+// pages. `Standard` has no index and is listed by scanning record IDs.
+
+pub fn priority_key(record_id: u64) -> (Symbol, u64) {
+    (REC_PRIORITY, record_id)
+}
+
+pub fn index_page_key(priority: &RecordPriority, page_no: u32) -> (Symbol, RecordPriority, u32) {
+    (PRIORITY_INDEX, priority.clone(), page_no)
+}
+
+fn index_len_key(priority: &RecordPriority) -> (Symbol, RecordPriority) {
+    (PRIORITY_INDEX_LEN, priority.clone())
+}
+
+fn get_slot(env: &Env, record_id: u64) -> Option<PrioritySlot> {
+    env.storage().persistent().get(&priority_key(record_id))
+}
+
+fn put_slot(env: &Env, record_id: u64, slot: &PrioritySlot) {
+    let key = priority_key(record_id);
+    env.storage().persistent().set(&key, slot);
+    extend_ttl_priority_key(env, &key);
+}
+
+/// Returns the stored priority for a record, defaulting to `Standard`.
+pub fn get_priority(env: &Env, record_id: u64) -> RecordPriority {
+    get_slot(env, record_id)
+        .map(|slot| slot.priority)
+        .unwrap_or(RecordPriority::Standard)
+}
+
+/// Positions used in the index under `priority`, holes included; always 0
+/// for `Standard`.
+pub fn index_len(env: &Env, priority: &RecordPriority) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&index_len_key(priority))
+        .unwrap_or(0)
+}
+
+fn set_index_len(env: &Env, priority: &RecordPriority, len: u32) {
+    let key = index_len_key(priority);
+    if len == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &len);
+        extend_ttl_len_key(env, &key);
+    }
+}
+
+fn get_page(env: &Env, priority: &RecordPriority, page_no: u32) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&index_page_key(priority, page_no))
+        .unwrap_or(Vec::new(env))
+}
+
+fn put_page(env: &Env, priority: &RecordPriority, page_no: u32, ids: &Vec<u64>) {
+    let key = index_page_key(priority, page_no);
+    if ids.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, ids);
+        extend_ttl_page_key(env, &key);
+    }
+}
+
+/// Record IDs at `priority` from up to `limit` index positions starting at
+/// `offset`, skipping holes.
+#[allow(clippy::arithmetic_side_effects)]
+pub fn get_range(env: &Env, priority: &RecordPriority, offset: u32, limit: u32) -> PriorityRecords {
+    let len = index_len(env, priority);
+    let end = offset.saturating_add(limit).min(len);
+    let mut ids = Vec::new(env);
+    let mut position = offset;
+    while position < end {
+        let page_no = position / PRIORITY_PAGE_SIZE;
+        let page = get_page(env, priority, page_no);
+        let page_end = end.min((page_no + 1) * PRIORITY_PAGE_SIZE);
+        for i in (position % PRIORITY_PAGE_SIZE)..(page_end - page_no * PRIORITY_PAGE_SIZE) {
+            if let Some(id) = page.get(i).filter(|id| *id != HOLE) {
+                ids.push_back(id);
+            }
+        }
+        position = page_end;
+    }
+    PriorityRecords {
+        items: ids,
+        truncated: end < len,
+        next_cursor: u64::from(end),
+    }
+}
+
+/// `Standard` record IDs among up to `limit` allocated IDs, walked shard by
+/// shard from `cursor`. Each exhausted shard counts against `limit` too.
+pub fn scan_standard(env: &Env, cursor: u64, limit: u32) -> PriorityRecords {
+    let shards = record_counter::shards_in_use(env);
+    let (mut shard, mut sequence) = record_counter::split(cursor);
+    sequence = sequence.max(1);
+    let mut ids = Vec::new(env);
+    let mut steps = 0;
+    let mut last_sequence = None;
+    while steps < limit && shard < shards {
+        steps = steps.saturating_add(1);
+        let last =
+            *last_sequence.get_or_insert_with(|| record_counter::current_sequence(env, shard));
+        if sequence > last {
+            shard = shard.saturating_add(1);
+            sequence = 1;
+            last_sequence = None;
+            continue;
+        }
+        let record_id = record_counter::compose(shard, sequence);
+        if get_slot(env, record_id).is_none()
+            && env
+                .storage()
+                .persistent()
+                .has(&(symbol_short!("RECORD"), record_id))
+        {
+            ids.push_back(record_id);
+        }
+        sequence = sequence.saturating_add(1);
+    }
+    PriorityRecords {
+        items: ids,
+        truncated: shard < shards,
+        next_cursor: record_counter::compose(shard, sequence),
+    }
+}
+
+/// Appends `record_id` to the index and returns its position.
+#[allow(clippy::arithmetic_side_effects)]
+fn add_to_index(env: &Env, priority: &RecordPriority, record_id: u64) -> u32 {
+    let position = index_len(env, priority);
+    let page_no = position / PRIORITY_PAGE_SIZE;
+    let mut page = get_page(env, priority, page_no);
+    page.push_back(record_id);
+    put_page(env, priority, page_no, &page);
+    set_index_len(env, priority, position + 1);
+    position
+}
+
+/// Removes the ID at `position`, leaving a hole unless it was the last.
+#[allow(clippy::arithmetic_side_effects)]
+fn remove_from_index(env: &Env, priority: &RecordPriority, position: u32) {
+    let len = index_len(env, priority);
+    if position >= len {
+        return;
+    }
+    let page_no = position / PRIORITY_PAGE_SIZE;
+    let mut page = get_page(env, priority, page_no);
+    if position == len - 1 {
+        page.pop_back();
+        set_index_len(env, priority, position);
+    } else {
+        page.set(position % PRIORITY_PAGE_SIZE, HOLE);
+    }
+    put_page(env, priority, page_no, &page);
+}
+
+/// Stores the priority for a record and moves it between priority indexes.
+/// Returns the previous priority.
+pub fn set_priority(env: &Env, record_id: u64, priority: &RecordPriority) -> RecordPriority {
+    let previous = get_slot(env, record_id);
+    if let Some(prev) = &previous {
+        if prev.priority == *priority {
+            return prev.priority.clone();
+        }
+        remove_from_index(env, &prev.priority, prev.slot);
+    }
+
+    if *priority == RecordPriority::Standard {
+        if previous.is_some() {
+            env.storage().persistent().remove(&priority_key(record_id));
+        }
+    } else {
+        init_priority(env, record_id, priority);
+    }
+
+    previous
+        .map(|prev| prev.priority)
+        .unwrap_or(RecordPriority::Standard)
+}
+
+/// Stores a priority for a record with none stored, such as one just
+/// created. `Standard` writes nothing.
+pub fn init_priority(env: &Env, record_id: u64, priority: &RecordPriority) {
+    if *priority == RecordPriority::Standard {
+        return;
+    }
+    let slot = add_to_index(env, priority, record_id);
+    put_slot(
+        env,
+        record_id,
+        &PrioritySlot {
+            priority: priority.clone(),
+            slot,
+        },
+    );
+}
+
+/// Whether Surgery and Diagnosis records default to `Critical` on creation.
+pub fn is_auto_critical_enabled(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&AUTO_CRITICAL)
+        .unwrap_or(false)
+}
+
+pub fn set_auto_critical_enabled(env: &Env, enabled: bool) {
+    env.storage().instance().set(&AUTO_CRITICAL, &enabled);
+}
+
+/// Returns the priority a newly created record of `record_type` starts with.
+pub fn default_priority_for(env: &Env, record_type: &RecordType) -> RecordPriority {
+    if is_auto_critical_enabled(env)
+        && (*record_type == RecordType::Surgery || *record_type == RecordType::Diagnosis)
+    {
+        RecordPriority::Critical
+    } else {
+        RecordPriority::Standard
+    }
+}
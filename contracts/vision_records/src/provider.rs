@@ -1,6 +1,10 @@
 #![allow(clippy::arithmetic_side_effects)]
 use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Vec};
 
+use crate::counters;
+use crate::errors::ContractError;
+use crate::instance_usage::{InstanceKey, NUMBER_BYTES};
+
 const TTL_THRESHOLD: u32 = 5184000;
 const TTL_EXTEND_TO: u32 = 10368000;
 
@@ -218,16 +222,18 @@ pub fn get_providers_by_status(env: &Env, status: &VerificationStatus) -> Vec<Ad
         .unwrap_or(Vec::new(env))
 }
 
+/// Instance-storage keys written here.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] = &[
+    InstanceKey::number(symbol_short!("PROV_CTR")),
+    InstanceKey::list(symbol_short!("PROV_IDS"), NUMBER_BYTES),
+];
+
 pub fn get_provider_counter(env: &Env) -> u64 {
-    let counter_key = symbol_short!("PROV_CTR");
-    env.storage().instance().get(&counter_key).unwrap_or(0)
+    counters::current_id(env, &symbol_short!("PROV_CTR"))
 }
 
-pub fn increment_provider_counter(env: &Env) -> u64 {
-    let counter_key = symbol_short!("PROV_CTR");
-    let count = get_provider_counter(env) + 1;
-    env.storage().instance().set(&counter_key, &count);
-    count
+pub fn increment_provider_counter(env: &Env) -> Result<u64, ContractError> {
+    counters::next_id(env, &symbol_short!("PROV_CTR"))
 }
 
 pub fn get_all_provider_ids(env: &Env) -> Vec<u64> {
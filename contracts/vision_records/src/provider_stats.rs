@@ -0,0 +1,153 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+// ── Storage keys ──────────────────────────────────────────────
+const PROVIDER_STATS: Symbol = symbol_short!("PRV_STAT");
+const PROVIDER_PATIENTS: Symbol = symbol_short!("PRV_PAT");
+const PROVIDER_MONTHLY: Symbol = symbol_short!("PRV_MON");
+
+/// Maximum number of patients tracked exactly per provider.
+pub const MAX_TRACKED_PATIENTS: u32 = 100;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for provider-keyed stats storage.
+fn extend_ttl_provider_key(env: &Env, key: &(Symbol, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+/// Extends the time-to-live (TTL) for monthly counter keys.
+fn extend_ttl_monthly_key(env: &Env, key: &(Symbol, Address, u32)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Workload and activity counters for a single provider.
+///
+/// `distinct_patients` is exact for the first `MAX_TRACKED_PATIENTS`
+/// patients. Past that, any patient outside the tracked set is counted
+/// as new on every record created for them, so the figure becomes an
+/// upper bound and `patients_approximate` is set.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProviderStats {
+    pub provider: Address,
+    pub records_created: u64,
+    pub records_updated: u64,
+    pub distinct_patients: u32,
+    pub patients_approximate: bool,
+    pub last_activity: u64,
+    /// Month bucket (`YYYYMM`, UTC) of the ledger time the stats were read.
+    pub current_period: u32,
+    pub created_this_period: u32,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn stats_key(provider: &Address) -> (Symbol, Address) {
+    (PROVIDER_STATS, provider.clone())
+}
+
+pub fn seen_patients_key(provider: &Address) -> (Symbol, Address) {
+    (PROVIDER_PATIENTS, provider.clone())
+}
+
+pub fn monthly_key(provider: &Address, period: u32) -> (Symbol, Address, u32) {
+    (PROVIDER_MONTHLY, provider.clone(), period)
+}
+
+/// Converts a Unix timestamp into a `YYYYMM` month bucket (UTC).
+#[allow(clippy::arithmetic_side_effects)]
+pub fn month_bucket(timestamp: u64) -> u32 {
+    // Civil-from-days conversion (proleptic Gregorian calendar).
+    let z = timestamp / 86_400 + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    (year * 100 + month) as u32
+}
+
+/// Number of records a provider created within the given month bucket.
+pub fn get_monthly_created(env: &Env, provider: &Address, period: u32) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&monthly_key(provider, period))
+        .unwrap_or(0)
+}
+
+/// Returns the provider's stats with the period counters set for `now`.
+pub fn get_stats(env: &Env, provider: &Address, now: u64) -> ProviderStats {
+    let mut stats = env
+        .storage()
+        .persistent()
+        .get(&stats_key(provider))
+        .unwrap_or(ProviderStats {
+            provider: provider.clone(),
+            records_created: 0,
+            records_updated: 0,
+            distinct_patients: 0,
+            patients_approximate: false,
+            last_activity: 0,
+            current_period: 0,
+            created_this_period: 0,
+        });
+    stats.current_period = month_bucket(now);
+    stats.created_this_period = get_monthly_created(env, provider, stats.current_period);
+    stats
+}
+
+fn set_stats(env: &Env, stats: &ProviderStats) {
+    let key = stats_key(&stats.provider);
+    env.storage().persistent().set(&key, stats);
+    extend_ttl_provider_key(env, &key);
+}
+
+/// Records that `provider` created a record for `patient` at `now`.
+pub fn record_created(env: &Env, provider: &Address, patient: &Address, now: u64) {
+    let mut stats = get_stats(env, provider, now);
+    stats.records_created = stats.records_created.saturating_add(1);
+    stats.last_activity = stats.last_activity.max(now);
+
+    let seen_key = seen_patients_key(provider);
+    let mut seen: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&seen_key)
+        .unwrap_or(Vec::new(env));
+    if !seen.contains(patient) {
+        stats.distinct_patients = stats.distinct_patients.saturating_add(1);
+        if seen.len() < MAX_TRACKED_PATIENTS {
+            seen.push_back(patient.clone());
+            env.storage().persistent().set(&seen_key, &seen);
+            extend_ttl_provider_key(env, &seen_key);
+        } else {
+            stats.patients_approximate = true;
+        }
+    }
+
+    stats.created_this_period = stats.created_this_period.saturating_add(1);
+    let month_key = monthly_key(provider, stats.current_period);
+    env.storage()
+        .persistent()
+        .set(&month_key, &stats.created_this_period);
+    extend_ttl_monthly_key(env, &month_key);
+
+    set_stats(env, &stats);
+}
+
+/// Records that one of `provider`'s records was updated at `now`.
+pub fn record_updated(env: &Env, provider: &Address, now: u64) {
+    let mut stats = get_stats(env, provider, now);
+    stats.records_updated = stats.records_updated.saturating_add(1);
+    stats.last_activity = stats.last_activity.max(now);
+    set_stats(env, &stats);
+}
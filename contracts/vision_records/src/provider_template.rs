@@ -0,0 +1,59 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::purpose::GrantPurpose;
+use crate::AccessLevel;
+
+// ── Storage keys ──────────────────────────────────────────────
+const PROVIDER_TEMPLATE: Symbol = symbol_short!("PRV_TPL");
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a provider's access template.
+fn extend_ttl_template_key(env: &Env, key: &(Symbol, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// The access a provider asks every new patient for. A patient accepting
+/// it gets a grant built from the template as it stands at acceptance;
+/// later changes leave grants already made alone.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProviderAccessTemplate {
+    pub provider: Address,
+    pub level: AccessLevel,
+    pub duration_seconds: u64,
+    pub purpose: GrantPurpose,
+    pub updated_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn template_key(provider: &Address) -> (Symbol, Address) {
+    (PROVIDER_TEMPLATE, provider.clone())
+}
+
+pub fn get_template(env: &Env, provider: &Address) -> Option<ProviderAccessTemplate> {
+    env.storage().persistent().get(&template_key(provider))
+}
+
+/// Stores a provider's template, replacing any earlier one.
+pub fn set_template(env: &Env, template: &ProviderAccessTemplate) {
+    let key = template_key(&template.provider);
+    env.storage().persistent().set(&key, template);
+    extend_ttl_template_key(env, &key);
+}
+
+/// Removes a provider's template. Returns false if there was none.
+pub fn remove_template(env: &Env, provider: &Address) -> bool {
+    let key = template_key(provider);
+    if !env.storage().persistent().has(&key) {
+        return false;
+    }
+    env.storage().persistent().remove(&key);
+    true
+}
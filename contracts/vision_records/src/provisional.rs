@@ -0,0 +1,92 @@
+use soroban_sdk::{symbol_short, Address, BytesN, Env, Symbol, Vec};
+
+// ── Storage keys ──────────────────────────────────────────────
+const PROVISIONAL_RECORDS: Symbol = symbol_short!("PROV_REC");
+const RECORD_ORIGIN: Symbol = symbol_short!("PROV_OF");
+const CLAIMED_BY: Symbol = symbol_short!("PROV_CLM");
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for provisional-identity keyed entries.
+fn extend_ttl_identity_key(env: &Env, key: &(Symbol, BytesN<32>)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+/// Extends the time-to-live (TTL) for a record's origin entry.
+fn extend_ttl_origin_key(env: &Env, key: &(Symbol, u64)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// A walk-in patient without a wallet is identified by a hash the clinic
+// derives off-chain. Their records are stored with the contract's own
+// address as `patient` and indexed under the hash until a patient claims
+// them. The record-to-hash origin is kept after the claim as provenance.
+
+pub fn records_key(identity: &BytesN<32>) -> (Symbol, BytesN<32>) {
+    (PROVISIONAL_RECORDS, identity.clone())
+}
+
+pub fn origin_key(record_id: u64) -> (Symbol, u64) {
+    (RECORD_ORIGIN, record_id)
+}
+
+pub fn claimed_by_key(identity: &BytesN<32>) -> (Symbol, BytesN<32>) {
+    (CLAIMED_BY, identity.clone())
+}
+
+/// The `patient` stored on records that have not been claimed yet.
+pub fn placeholder_patient(env: &Env) -> Address {
+    env.current_contract_address()
+}
+
+/// Whether `patient` is the placeholder of an unclaimed provisional record.
+pub fn is_placeholder(env: &Env, patient: &Address) -> bool {
+    *patient == placeholder_patient(env)
+}
+
+/// Unclaimed record IDs stored against `identity`, oldest first.
+pub fn get_records(env: &Env, identity: &BytesN<32>) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&records_key(identity))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn add_record(env: &Env, identity: &BytesN<32>, record_id: u64) {
+    let key = records_key(identity);
+    let mut records = get_records(env, identity);
+    records.push_back(record_id);
+    env.storage().persistent().set(&key, &records);
+    extend_ttl_identity_key(env, &key);
+
+    let origin = origin_key(record_id);
+    env.storage().persistent().set(&origin, identity);
+    extend_ttl_origin_key(env, &origin);
+}
+
+/// The provisional identity a record was created under, if any.
+pub fn get_origin(env: &Env, record_id: u64) -> Option<BytesN<32>> {
+    env.storage().persistent().get(&origin_key(record_id))
+}
+
+/// The patient who claimed `identity`, if it has been claimed.
+pub fn get_claimant(env: &Env, identity: &BytesN<32>) -> Option<Address> {
+    env.storage().persistent().get(&claimed_by_key(identity))
+}
+
+/// Records `patient` as the owner of `identity` and drops its unclaimed
+/// index. Claims are final.
+pub fn mark_claimed(env: &Env, identity: &BytesN<32>, patient: &Address) {
+    env.storage().persistent().remove(&records_key(identity));
+
+    let key = claimed_by_key(identity);
+    env.storage().persistent().set(&key, patient);
+    extend_ttl_identity_key(env, &key);
+}
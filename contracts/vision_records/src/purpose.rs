@@ -0,0 +1,109 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+// ── Storage keys ──────────────────────────────────────────────
+const GRANT_PURPOSE: Symbol = symbol_short!("GRT_PURP");
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a grant purpose key.
+fn extend_ttl_purpose_key(env: &Env, key: &(Symbol, Address, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Declared reason an access grant was made, for compliance reporting.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GrantPurpose {
+    Treatment,
+    Payment,
+    Research,
+    Personal,
+}
+
+/// Number of grants held for one purpose.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PurposeCount {
+    pub purpose: GrantPurpose,
+    pub count: u32,
+}
+
+/// Active access grants on a patient's records.
+///
+/// Computed on read from the patient's grantee list, so grants that have
+/// expired but not yet been purged are not counted.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GrantStats {
+    pub patient: Address,
+    pub grants_active: u32,
+    /// One entry per purpose, in declaration order.
+    pub grants_active_by_purpose: Vec<PurposeCount>,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// The purpose is kept beside the grant rather than inside `AccessGrant` so
+// grants stored before purposes existed still decode. A missing entry
+// reads as `Treatment`, and `Treatment` is never stored.
+
+pub fn purpose_key(patient: &Address, grantee: &Address) -> (Symbol, Address, Address) {
+    (GRANT_PURPOSE, patient.clone(), grantee.clone())
+}
+
+/// Every purpose, in declaration order.
+pub fn all_purposes(env: &Env) -> Vec<GrantPurpose> {
+    Vec::from_array(
+        env,
+        [
+            GrantPurpose::Treatment,
+            GrantPurpose::Payment,
+            GrantPurpose::Research,
+            GrantPurpose::Personal,
+        ],
+    )
+}
+
+pub fn get_purpose(env: &Env, patient: &Address, grantee: &Address) -> GrantPurpose {
+    env.storage()
+        .persistent()
+        .get(&purpose_key(patient, grantee))
+        .unwrap_or(GrantPurpose::Treatment)
+}
+
+/// Records the purpose of the grant just stored for `patient` → `grantee`.
+pub fn set_purpose(env: &Env, patient: &Address, grantee: &Address, purpose: &GrantPurpose) {
+    let key = purpose_key(patient, grantee);
+    if *purpose == GrantPurpose::Treatment {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, purpose);
+        extend_ttl_purpose_key(env, &key);
+    }
+}
+
+pub fn remove_purpose(env: &Env, patient: &Address, grantee: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&purpose_key(patient, grantee));
+}
+
+/// Tallies `purposes` into one count per purpose.
+pub fn count_by_purpose(env: &Env, purposes: &Vec<GrantPurpose>) -> Vec<PurposeCount> {
+    let mut counts = Vec::new(env);
+    for purpose in all_purposes(env).iter() {
+        let mut count: u32 = 0;
+        for p in purposes.iter() {
+            if p == purpose {
+                count = count.saturating_add(1);
+            }
+        }
+        counts.push_back(PurposeCount { purpose, count });
+    }
+    counts
+}
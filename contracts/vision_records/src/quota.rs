@@ -0,0 +1,87 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::errors::ContractError;
+use crate::instance_usage::InstanceKey;
+
+// ── Storage keys ──────────────────────────────────────────────
+const DEFAULT_QUOTA: Symbol = symbol_short!("QUOTA_DEF");
+const PATIENT_QUOTA: Symbol = symbol_short!("PAT_QUOTA");
+
+/// Instance-storage keys written here.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] = &[InstanceKey::number(DEFAULT_QUOTA)];
+
+/// Records a patient may hold when neither a contract-wide default nor a
+/// per-patient override has been set.
+pub const DEFAULT_PATIENT_RECORD_QUOTA: u32 = 5_000;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a patient quota key.
+fn extend_ttl_quota_key(env: &Env, key: &(Symbol, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// How much of a patient's record quota is in use.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuotaUsage {
+    pub used: u32,
+    pub limit: u32,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// Usage is the length of the patient's record list, so every record the
+// patient has ever been given counts, including superseded ones.
+
+pub fn patient_quota_key(patient: &Address) -> (Symbol, Address) {
+    (PATIENT_QUOTA, patient.clone())
+}
+
+pub fn get_default_quota(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DEFAULT_QUOTA)
+        .unwrap_or(DEFAULT_PATIENT_RECORD_QUOTA)
+}
+
+pub fn set_default_quota(env: &Env, quota: u32) {
+    env.storage().instance().set(&DEFAULT_QUOTA, &quota);
+}
+
+pub fn get_override(env: &Env, patient: &Address) -> Option<u32> {
+    env.storage().persistent().get(&patient_quota_key(patient))
+}
+
+/// Sets a per-patient quota, or clears it when `quota` is `None` so the
+/// patient falls back to the contract-wide default.
+pub fn set_override(env: &Env, patient: &Address, quota: Option<u32>) {
+    let key = patient_quota_key(patient);
+    match quota {
+        Some(quota) => {
+            env.storage().persistent().set(&key, &quota);
+            extend_ttl_quota_key(env, &key);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// The quota that applies to `patient`: their override if set, otherwise
+/// the contract-wide default.
+pub fn limit_for(env: &Env, patient: &Address) -> u32 {
+    get_override(env, patient).unwrap_or_else(|| get_default_quota(env))
+}
+
+/// Fails with `QuotaExceeded` if a patient holding `used` records may not
+/// be given another one.
+pub fn require_capacity(env: &Env, patient: &Address, used: u32) -> Result<(), ContractError> {
+    if used >= limit_for(env, patient) {
+        return Err(ContractError::QuotaExceeded);
+    }
+    Ok(())
+}
@@ -111,7 +111,7 @@ pub fn get_rate_limit_count(env: &Env, address: &Address, operation: &String) ->
 pub fn increment_rate_limit_count(env: &Env, address: &Address, operation: &String) -> u32 {
     let key = (RATE_LIMIT_COUNT, address.clone(), operation.clone());
     let current: u32 = env.storage().persistent().get(&key).unwrap_or(0);
-    let new_count = current + 1;
+    let new_count = current.saturating_add(1);
     env.storage().persistent().set(&key, &new_count);
     extend_ttl_count_key(env, &key);
     new_count
@@ -1,5 +1,9 @@
 use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
 
+use crate::instance_usage::InstanceKey;
+use crate::role_history::{self, RoleAction};
+use crate::{deactivation, delegation_cooldown, expiry};
+
 const TTL_THRESHOLD: u32 = 5184000;
 const TTL_EXTEND_TO: u32 = 10368000;
 
@@ -79,7 +83,23 @@ pub enum Permission {
     WriteRecord = 2,
     ManageAccess = 3,
     ManageUsers = 4,
+    /// Deprecated superset of `InfraAdmin` and `ClinicalAdmin`, honoured
+    /// only while the legacy SystemAdmin flag is on; see
+    /// `set_legacy_system_admin`.
     SystemAdmin = 5,
+    /// Add new records, but not modify existing ones. `WriteRecord`
+    /// implies it.
+    AppendRecord = 6,
+    /// Add records dated before they reached the chain, e.g. when
+    /// importing paper charts. No base role carries it.
+    ImportRecords = 7,
+    /// Run the contract itself: upgrades, pausing, configuration and
+    /// maintenance jobs. Grants no access to records.
+    InfraAdmin = 8,
+    /// Act on records and users past the normal rules: admin reads and
+    /// writes, rollbacks, redaction, access overrides, and freezing,
+    /// deactivating or recovering users.
+    ClinicalAdmin = 9,
 }
 
 #[contracttype]
@@ -92,6 +112,7 @@ pub enum Role {
     Optometrist = 3,
     Ophthalmologist = 4,
     Admin = 5,
+    Pharmacist = 6,
 }
 
 pub fn get_base_permissions(env: &Env, role: &Role) -> Vec<Permission> {
@@ -186,6 +207,10 @@ pub fn delegatee_index_key(delegatee: &Address) -> (Symbol, Address) {
     (symbol_short!("DELEG_IDX"), delegatee.clone())
 }
 
+pub fn delegator_index_key(delegator: &Address) -> (Symbol, Address) {
+    (symbol_short!("DELEG_OUT"), delegator.clone())
+}
+
 pub fn acl_group_key(name: &String) -> (Symbol, String) {
     (symbol_short!("ACL_GRP"), name.clone())
 }
@@ -194,6 +219,30 @@ pub fn user_groups_key(user: &Address) -> (Symbol, Address) {
     (symbol_short!("USR_GRPS"), user.clone())
 }
 
+pub fn frozen_user_key(user: &Address) -> (Symbol, Address) {
+    (symbol_short!("FROZEN"), user.clone())
+}
+
+/// Returns true if the user has been frozen by an admin.
+pub fn is_frozen(env: &Env, user: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&frozen_user_key(user))
+        .unwrap_or(false)
+}
+
+/// Freeze or unfreeze a user. A frozen user fails every permission check
+/// without any of their grants or assignments being touched.
+pub fn set_frozen(env: &Env, user: &Address, frozen: bool) {
+    let key = frozen_user_key(user);
+    if frozen {
+        env.storage().persistent().set(&key, &true);
+        extend_ttl_address_key(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
 
 pub fn access_policy_key(id: &String) -> (Symbol, String) {
     (symbol_short!("ACC_POL"), id.clone())
@@ -209,7 +258,13 @@ pub fn record_sensitivity_key(record_id: &u64) -> (Symbol, u64) {
 
 // ======================== Core RBAC Engine ========================
 
-pub fn assign_role(env: &Env, user: Address, role: Role, expires_at: u64) {
+/// Assigns `role` to `user`, replacing any earlier assignment, and logs the
+/// transition in the user's role history as made `by` the given address.
+pub fn assign_role(env: &Env, user: Address, role: Role, expires_at: u64, by: &Address) {
+    let previous: Option<RoleAssignment> =
+        env.storage().persistent().get(&user_assignment_key(&user));
+    role_history::record_assignment(env, &user, previous.as_ref(), &role, by);
+
     let assignment = RoleAssignment {
         role,
         custom_grants: Vec::new(env),
@@ -229,13 +284,63 @@ pub fn get_active_assignment(env: &Env, user: &Address) -> Option<RoleAssignment
         .persistent()
         .get::<_, RoleAssignment>(&user_assignment_key(user))
     {
-        if assignment.expires_at == 0 || assignment.expires_at > env.ledger().timestamp() {
+        if assignment.expires_at == 0 || !expiry::is_expired(env, assignment.expires_at) {
             return Some(assignment);
         }
     }
     None
 }
 
+// ── Admin permissions ─────────────────────────────────────────
+
+const LEGACY_SYSTEM_ADMIN_OFF: Symbol = symbol_short!("SYSADM_OF");
+
+/// Instance-storage keys written here.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] = &[InstanceKey::flag(LEGACY_SYSTEM_ADMIN_OFF)];
+
+/// Whether `SystemAdmin` still stands in for `InfraAdmin` and
+/// `ClinicalAdmin`. On unless the contract was initialized with a separate
+/// clinical admin or an admin turned it off.
+pub fn is_legacy_system_admin_enabled(env: &Env) -> bool {
+    !env.storage().instance().has(&LEGACY_SYSTEM_ADMIN_OFF)
+}
+
+pub fn set_legacy_system_admin_enabled(env: &Env, enabled: bool) {
+    if enabled {
+        env.storage().instance().remove(&LEGACY_SYSTEM_ADMIN_OFF);
+    } else {
+        env.storage()
+            .instance()
+            .set(&LEGACY_SYSTEM_ADMIN_OFF, &true);
+    }
+}
+
+/// Whether `permission` is one of the admin permissions, which only their
+/// holders may hand out.
+pub fn is_admin_permission(permission: &Permission) -> bool {
+    matches!(
+        permission,
+        Permission::SystemAdmin | Permission::InfraAdmin | Permission::ClinicalAdmin
+    )
+}
+
+/// Whether `user` holds the admin `permission`, counting `SystemAdmin`
+/// while the legacy flag is on.
+pub fn has_admin_permission(env: &Env, user: &Address, permission: &Permission) -> bool {
+    if *permission != Permission::SystemAdmin && has_permission(env, user, permission) {
+        return true;
+    }
+    is_legacy_system_admin_enabled(env) && has_permission(env, user, &Permission::SystemAdmin)
+}
+
+pub fn has_infra_admin(env: &Env, user: &Address) -> bool {
+    has_admin_permission(env, user, &Permission::InfraAdmin)
+}
+
+pub fn has_clinical_admin(env: &Env, user: &Address) -> bool {
+    has_admin_permission(env, user, &Permission::ClinicalAdmin)
+}
+
 /// Set custom permissions for an existing assignment
 pub fn grant_custom_permission(env: &Env, user: Address, permission: Permission) -> Result<(), ()> {
     let mut assignment = get_active_assignment(env, &user).ok_or(())?;
@@ -291,7 +396,8 @@ pub fn revoke_custom_permission(
 /// Create a delegation from `delegator` to `delegatee`.
 ///
 /// Also updates the delegatee's delegation index so that `has_permission`
-/// can discover all active delegations when evaluating permissions.
+/// can discover all active delegations when evaluating permissions, and
+/// logs the delegated role in the delegatee's role history.
 pub fn delegate_role(
     env: &Env,
     delegator: Address,
@@ -323,6 +429,100 @@ pub fn delegate_role(
     }
     env.storage().persistent().set(&idx_key, &delegators);
     extend_ttl_address_key(env, &idx_key);
+
+    add_to_delegator_index(env, &del.delegator, &del.delegatee);
+    role_history::record(
+        env,
+        &del.delegatee,
+        &del.role,
+        RoleAction::Delegated,
+        &del.delegator,
+    );
+}
+
+/// Records `delegatee` in the delegator's index of outgoing delegations.
+fn add_to_delegator_index(env: &Env, delegator: &Address, delegatee: &Address) {
+    let key = delegator_index_key(delegator);
+    let mut delegatees: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+    if !delegatees.contains(delegatee) {
+        delegatees.push_back(delegatee.clone());
+        env.storage().persistent().set(&key, &delegatees);
+    }
+    extend_ttl_address_key(env, &key);
+}
+
+/// Addresses `delegator` has delegated to, whether or not the delegation
+/// is still active.
+pub fn get_delegatees(env: &Env, delegator: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&delegator_index_key(delegator))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Addresses that have delegated a role or permissions to `delegatee`,
+/// whether or not the delegation is still active.
+pub fn get_delegators(env: &Env, delegatee: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&delegatee_index_key(delegatee))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Removes both the role and the scoped delegation from `delegator` to
+/// `delegatee`, and drops the pair from both indexes. A removed role
+/// delegation is logged in the delegatee's role history as revoked `by`
+/// the given address, and starts its re-delegation cooldown.
+pub fn revoke_delegation(env: &Env, delegator: &Address, delegatee: &Address, by: &Address) {
+    let key = delegation_key(delegator, delegatee);
+    if let Some(delegation) = env.storage().persistent().get::<_, Delegation>(&key) {
+        role_history::record(env, delegatee, &delegation.role, RoleAction::Revoked, by);
+        delegation_cooldown::start(env, delegator, delegatee, &delegation.role);
+    }
+    env.storage().persistent().remove(&key);
+    env.storage()
+        .persistent()
+        .remove(&scoped_delegation_key(delegator, delegatee));
+
+    let idx_key = delegatee_index_key(delegatee);
+    let mut delegators: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&idx_key)
+        .unwrap_or(Vec::new(env));
+    if let Some(pos) = delegators.first_index_of(delegator) {
+        delegators.remove(pos);
+        env.storage().persistent().set(&idx_key, &delegators);
+    }
+
+    let out_key = delegator_index_key(delegator);
+    let mut delegatees = get_delegatees(env, delegator);
+    if let Some(pos) = delegatees.first_index_of(delegatee) {
+        delegatees.remove(pos);
+        if delegatees.is_empty() {
+            env.storage().persistent().remove(&out_key);
+        } else {
+            env.storage().persistent().set(&out_key, &delegatees);
+        }
+    }
+}
+
+/// Counts the addresses holding an active role or scoped delegation from
+/// `delegator`. Delegations made before the index was kept are not counted.
+pub fn count_active_delegations_from(env: &Env, delegator: &Address) -> u32 {
+    let mut count: u32 = 0;
+    for delegatee in get_delegatees(env, delegator).iter() {
+        if get_active_delegation(env, delegator, &delegatee).is_some()
+            || get_active_scoped_delegation(env, delegator, &delegatee).is_some()
+        {
+            count = count.saturating_add(1);
+        }
+    }
+    count
 }
 
 /// Retrieve the active delegations for a particular `delegatee` representing `delegator`
@@ -336,7 +536,7 @@ pub fn get_active_delegation(
         .persistent()
         .get::<_, Delegation>(&delegation_key(delegator, delegatee))
     {
-        if del.expires_at == 0 || del.expires_at > env.ledger().timestamp() {
+        if del.expires_at == 0 || !expiry::is_expired(env, del.expires_at) {
             return Some(del);
         }
     }
@@ -380,6 +580,8 @@ pub fn delegate_permissions(
     }
     env.storage().persistent().set(&idx_key, &delegators);
     extend_ttl_address_key(env, &idx_key);
+
+    add_to_delegator_index(env, &del.delegator, &del.delegatee);
 }
 
 /// Retrieve the active scoped delegation for a particular delegator→delegatee pair.
@@ -393,7 +595,7 @@ pub fn get_active_scoped_delegation(
         .persistent()
         .get::<_, ScopedDelegation>(&scoped_delegation_key(delegator, delegatee))
     {
-        if del.expires_at == 0 || del.expires_at > env.ledger().timestamp() {
+        if del.expires_at == 0 || !expiry::is_expired(env, del.expires_at) {
             return Some(del);
         }
     }
@@ -471,6 +673,11 @@ pub fn get_group_permissions(env: &Env, name: &String) -> Vec<Permission> {
 /// This function merges Base Role inherited permissions, Custom Grants, Custom Revokes,
 /// and currently active delegated Roles.
 pub fn has_permission(env: &Env, user: &Address, permission: &Permission) -> bool {
+    // Frozen and deactivated users hold no permissions at all
+    if is_frozen(env, user) || deactivation::is_deactivated(env, user) {
+        return false;
+    }
+
     // Step 1: Check direct role assignment
     if let Some(assignment) = get_active_assignment(env, user) {
         // Explicit revoke takes highest priority — overrides grants,
@@ -524,6 +731,10 @@ pub fn has_delegated_permission(
     delegatee: &Address,
     permission: &Permission,
 ) -> bool {
+    if is_frozen(env, delegatee) || deactivation::is_deactivated(env, delegatee) {
+        return false;
+    }
+
     // Full role delegation: delegatee gets all permissions of the role
     if let Some(delegation) = get_active_delegation(env, delegator, delegatee) {
         if get_base_permissions(env, &delegation.role).contains(permission) {
@@ -651,7 +862,10 @@ pub fn evaluate_policy(env: &Env, policy: &AccessPolicy, context: &PolicyContext
                 .persistent()
                 .get::<_, ConsentGrant>(&consent_key)
             {
-                if consent.revoked || consent.expires_at <= context.current_time {
+                let grace = expiry::get_grace(env);
+                if consent.revoked
+                    || expiry::is_expired_at(consent.expires_at, context.current_time, grace)
+                {
                     return false;
                 }
             } else {
@@ -0,0 +1,36 @@
+use soroban_sdk::{contracttype, String};
+
+// ── Types ─────────────────────────────────────────────────────
+//
+// Returned by the `_v2` write endpoints. Each receipt is built from the
+// values the call persisted, so it always agrees with a read made right
+// after the write.
+
+/// What `add_record_v2` stored.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddRecordReceipt {
+    pub record_id: u64,
+    /// Version number of the record's first version.
+    pub version: u32,
+    pub created_at: u64,
+}
+
+/// What `update_record_v2` stored.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpdateReceipt {
+    pub record_id: u64,
+    pub new_version: u32,
+    pub updated_at: u64,
+    /// The record's data hash before this update.
+    pub previous_hash: String,
+}
+
+/// The stored grant behind a `grant_access_v2` call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GrantReceipt {
+    pub granted_at: u64,
+    pub expires_at: u64,
+}
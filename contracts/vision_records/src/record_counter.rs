@@ -0,0 +1,152 @@
+use soroban_sdk::{symbol_short, xdr::ToXdr, Address, Env, Symbol};
+
+use crate::counters;
+use crate::errors::ContractError;
+use crate::instance_usage::InstanceKey;
+
+// ── Storage keys ──────────────────────────────────────────────
+
+/// Instance-storage keys written here.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] = &[
+    InstanceKey::number(LEGACY_COUNTER),
+    InstanceKey::number(COUNTER_SHARDS),
+    InstanceKey::number(SHARDS_HIGH_WATER),
+];
+/// The single instance counter every record ID came from before sharding.
+const LEGACY_COUNTER: Symbol = symbol_short!("REC_CTR");
+const COUNTER_SHARDS: Symbol = symbol_short!("CTR_SHDS");
+const SHARDS_HIGH_WATER: Symbol = symbol_short!("CTR_SHHW");
+const SHARD_SEQUENCE: Symbol = symbol_short!("REC_SHD");
+
+/// Shards used until an admin configures otherwise.
+pub const DEFAULT_COUNTER_SHARDS: u32 = 8;
+
+/// Most shards that may be configured.
+pub const MAX_COUNTER_SHARDS: u32 = 64;
+
+/// Bits of a record ID below the shard number. IDs from any shard but 0
+/// are above 2^53, so clients that parse numbers as doubles (JavaScript,
+/// most JSON decoders) must read record IDs as strings or big integers.
+pub const SHARD_SHIFT: u32 = 56;
+
+/// Largest sequence a shard can hand out.
+pub const MAX_SEQUENCE: u64 = (1u64 << SHARD_SHIFT) - 1;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a shard's sequence.
+fn extend_ttl_shard_key(env: &Env, key: &(Symbol, u32)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// A record ID is `(shard << 56) | sequence`, where each shard keeps its own
+// persistent sequence and a provider always allocates from the shard its
+// address hashes to. Record creation by providers in different shards
+// therefore writes no common entry. Shard 0 IDs are plain sequence numbers,
+// so shard 0 continues from the legacy counter and IDs handed out before
+// sharding stay unique and valid. Changing the shard count only moves
+// providers between shards; every shard's sequence stays where it was.
+//
+// No running total is kept, since it would be one entry every record
+// creation writes. `count` sums the shards instead, at most
+// `MAX_COUNTER_SHARDS` reads.
+
+pub fn get_shards(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&COUNTER_SHARDS)
+        .unwrap_or(DEFAULT_COUNTER_SHARDS)
+}
+
+/// Sets the shard count. The caller validates it against
+/// `MAX_COUNTER_SHARDS`.
+pub fn set_shards(env: &Env, shards: u32) {
+    env.storage().instance().set(&COUNTER_SHARDS, &shards);
+    if shards > high_water(env) {
+        env.storage().instance().set(&SHARDS_HIGH_WATER, &shards);
+    }
+}
+
+/// The highest shard count ever in effect.
+fn high_water(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&SHARDS_HIGH_WATER)
+        .unwrap_or(DEFAULT_COUNTER_SHARDS)
+        .max(get_shards(env))
+}
+
+/// How many shards may hold record IDs: every shard ever in use.
+pub fn shards_in_use(env: &Env) -> u32 {
+    high_water(env).min(MAX_COUNTER_SHARDS)
+}
+
+pub fn shard_key(shard: u32) -> (Symbol, u32) {
+    (SHARD_SEQUENCE, shard)
+}
+
+/// The shard `provider` allocates record IDs from.
+pub fn shard_of(env: &Env, provider: &Address) -> u32 {
+    let hash = env
+        .crypto()
+        .sha256(&provider.clone().to_xdr(env))
+        .to_array();
+    u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]]) % get_shards(env).max(1)
+}
+
+pub fn compose(shard: u32, sequence: u64) -> u64 {
+    (u64::from(shard) << SHARD_SHIFT) | sequence
+}
+
+/// Splits a record ID into its shard and sequence.
+pub fn split(record_id: u64) -> (u32, u64) {
+    ((record_id >> SHARD_SHIFT) as u32, record_id & MAX_SEQUENCE)
+}
+
+/// The last sequence `shard` handed out, or 0 if none.
+pub fn current_sequence(env: &Env, shard: u32) -> u64 {
+    let sequence: u64 = env
+        .storage()
+        .persistent()
+        .get(&shard_key(shard))
+        .unwrap_or(0);
+    if shard == 0 {
+        sequence.max(counters::current_id(env, &LEGACY_COUNTER))
+    } else {
+        sequence
+    }
+}
+
+/// Allocates the next record ID for a record written by `provider`. The
+/// shard's sequence is left untouched if it would overflow.
+pub fn next_id(env: &Env, provider: &Address) -> Result<u64, ContractError> {
+    let shard = shard_of(env, provider);
+    let sequence = counters::checked_next_u64(current_sequence(env, shard))?;
+    if sequence > MAX_SEQUENCE {
+        return Err(ContractError::CounterOverflow);
+    }
+    let key = shard_key(shard);
+    env.storage().persistent().set(&key, &sequence);
+    extend_ttl_shard_key(env, &key);
+    Ok(compose(shard, sequence))
+}
+
+/// Whether `record_id` has been handed out by its shard.
+pub fn is_allocated(env: &Env, record_id: u64) -> bool {
+    let (shard, sequence) = split(record_id);
+    sequence != 0 && sequence <= current_sequence(env, shard)
+}
+
+/// Record IDs handed out across all shards, including legacy IDs. Every
+/// shard that was ever in use is summed, so records in shards dropped by
+/// a lower shard count still count.
+pub fn count(env: &Env) -> u64 {
+    (0..shards_in_use(env)).fold(0u64, |total, shard| {
+        total.saturating_add(current_sequence(env, shard))
+    })
+}
@@ -0,0 +1,145 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::errors::ContractError;
+use crate::VisionRecord;
+
+// ── Storage keys ──────────────────────────────────────────────
+const PATIENT_PINS: Symbol = symbol_short!("PAT_PIN");
+
+/// Maximum number of records a patient may pin.
+pub const MAX_PINS_PER_PATIENT: u32 = 5;
+
+/// `UpdatedDesc` only sorts the patient's most recently created records,
+/// up to this many; older records are left out of that ordering.
+pub const MAX_SORTABLE_RECORDS: u32 = 100;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a patient's pin list.
+fn extend_ttl_pins_key(env: &Env, key: &(Symbol, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Order in which a patient's records are listed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RecordOrder {
+    /// Newest first.
+    CreatedDesc,
+    /// Oldest first, with backdated imports placed by their original date.
+    CreatedAsc,
+    /// Most recently modified first, over at most `MAX_SORTABLE_RECORDS`
+    /// of the newest records.
+    UpdatedDesc,
+    /// Pinned records in the order they were pinned, then the rest newest
+    /// first.
+    PinnedFirst,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn pins_key(patient: &Address) -> (Symbol, Address) {
+    (PATIENT_PINS, patient.clone())
+}
+
+/// The patient's pinned record IDs, in the order they were pinned.
+pub fn get_pins(env: &Env, patient: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&pins_key(patient))
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_pins(env: &Env, patient: &Address, pins: &Vec<u64>) {
+    let key = pins_key(patient);
+    if pins.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, pins);
+        extend_ttl_pins_key(env, &key);
+    }
+}
+
+pub fn pin(env: &Env, patient: &Address, record_id: u64) -> Result<(), ContractError> {
+    let mut pins = get_pins(env, patient);
+    if pins.contains(record_id) {
+        return Err(ContractError::AlreadyExists);
+    }
+    if pins.len() >= MAX_PINS_PER_PATIENT {
+        return Err(ContractError::PinLimitReached);
+    }
+    pins.push_back(record_id);
+    set_pins(env, patient, &pins);
+    Ok(())
+}
+
+pub fn unpin(env: &Env, patient: &Address, record_id: u64) -> Result<(), ContractError> {
+    let mut pins = get_pins(env, patient);
+    let index = pins
+        .first_index_of(record_id)
+        .ok_or(ContractError::RecordNotFound)?;
+    pins.remove(index);
+    set_pins(env, patient, &pins);
+    Ok(())
+}
+
+fn updated_at(env: &Env, record_id: u64) -> u64 {
+    env.storage()
+        .persistent()
+        .get::<_, VisionRecord>(&(symbol_short!("RECORD"), record_id))
+        .map(|record| record.updated_at)
+        .unwrap_or(0)
+}
+
+/// Orders `record_ids`, which must be in creation order, as requested.
+///
+/// Only `UpdatedDesc` reads records, and it reads at most
+/// `MAX_SORTABLE_RECORDS` of them.
+pub fn order_ids(
+    env: &Env,
+    patient: &Address,
+    record_ids: &Vec<u64>,
+    order: &RecordOrder,
+) -> Vec<u64> {
+    let mut newest_first = Vec::new(env);
+    for record_id in record_ids.iter().rev() {
+        newest_first.push_back(record_id);
+    }
+
+    match order {
+        RecordOrder::CreatedAsc => record_ids.clone(),
+        RecordOrder::CreatedDesc => newest_first,
+        RecordOrder::UpdatedDesc => {
+            // Insertion sort; stable, so equal timestamps stay newest first.
+            let mut sorted: Vec<(u64, u64)> = Vec::new(env);
+            for record_id in newest_first.iter().take(MAX_SORTABLE_RECORDS as usize) {
+                let entry = (updated_at(env, record_id), record_id);
+                let mut at = sorted.len();
+                while at > 0 && sorted.get_unchecked(at.saturating_sub(1)).0 < entry.0 {
+                    at = at.saturating_sub(1);
+                }
+                sorted.insert(at, entry);
+            }
+            let mut ordered = Vec::new(env);
+            for (_, record_id) in sorted.iter() {
+                ordered.push_back(record_id);
+            }
+            ordered
+        }
+        RecordOrder::PinnedFirst => {
+            let pins = get_pins(env, patient);
+            let mut ordered = pins.clone();
+            for record_id in newest_first.iter() {
+                if !pins.contains(record_id) {
+                    ordered.push_back(record_id);
+                }
+            }
+            ordered
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::AccessLevel;
+
+// ── Storage keys ──────────────────────────────────────────────
+const RECORD_OVERRIDE: Symbol = symbol_short!("REC_OVR");
+const OVERRIDE_INDEX: Symbol = symbol_short!("OVR_IDX");
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a per-record access override.
+fn extend_ttl_override_key(env: &Env, key: &(Symbol, u64, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+/// Extends the time-to-live (TTL) for a grantee's override index.
+fn extend_ttl_index_key(env: &Env, key: &(Symbol, Address, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A patient's cap on how long one grantee may reach one record through
+/// their grants, whatever the grants themselves allow. An `expires_at`
+/// already in the past excludes the record outright.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordAccessOverride {
+    pub patient: Address,
+    pub grantee: Address,
+    pub record_id: u64,
+    pub expires_at: u64,
+    pub set_at: u64,
+}
+
+/// How a grantee's access to one record resolves once grants and any
+/// override are combined.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EffectiveAccess {
+    /// `None` if no grant applies or the override has passed.
+    pub level: AccessLevel,
+    /// When `level` stops applying, or `None` if there is no access.
+    pub expires_at: Option<u64>,
+    /// The override's expiry, if one is set, whether or not it has passed.
+    pub override_expires_at: Option<u64>,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// An override is kept after it passes; from then on it keeps the record
+// excluded until the patient clears it. Each patient/grantee pair also
+// indexes its overridden records so the overrides can follow the grantee
+// when grants are migrated.
+
+pub fn override_key(record_id: u64, grantee: &Address) -> (Symbol, u64, Address) {
+    (RECORD_OVERRIDE, record_id, grantee.clone())
+}
+
+pub fn index_key(patient: &Address, grantee: &Address) -> (Symbol, Address, Address) {
+    (OVERRIDE_INDEX, patient.clone(), grantee.clone())
+}
+
+pub fn get(env: &Env, record_id: u64, grantee: &Address) -> Option<RecordAccessOverride> {
+    env.storage()
+        .persistent()
+        .get(&override_key(record_id, grantee))
+}
+
+/// Record ids `patient` has set overrides on for `grantee`.
+pub fn get_index(env: &Env, patient: &Address, grantee: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&index_key(patient, grantee))
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_index(env: &Env, patient: &Address, grantee: &Address, index: &Vec<u64>) {
+    let key = index_key(patient, grantee);
+    if index.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, index);
+        extend_ttl_index_key(env, &key);
+    }
+}
+
+pub fn set(env: &Env, record_override: &RecordAccessOverride) {
+    let key = override_key(record_override.record_id, &record_override.grantee);
+    env.storage().persistent().set(&key, record_override);
+    extend_ttl_override_key(env, &key);
+
+    let mut index = get_index(env, &record_override.patient, &record_override.grantee);
+    if !index.contains(record_override.record_id) {
+        index.push_back(record_override.record_id);
+        set_index(
+            env,
+            &record_override.patient,
+            &record_override.grantee,
+            &index,
+        );
+    }
+}
+
+pub fn clear(env: &Env, patient: &Address, record_id: u64, grantee: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&override_key(record_id, grantee));
+    let mut index = get_index(env, patient, grantee);
+    if let Some(pos) = index.first_index_of(record_id) {
+        index.remove(pos);
+        set_index(env, patient, grantee, &index);
+    }
+}
+
+/// Moves every override `patient` set for `old_grantee` onto
+/// `new_grantee`, replacing any the new address already had.
+pub fn migrate(env: &Env, patient: &Address, old_grantee: &Address, new_grantee: &Address) {
+    for record_id in get_index(env, patient, old_grantee).iter() {
+        if let Some(mut record_override) = get(env, record_id, old_grantee) {
+            clear(env, patient, record_id, old_grantee);
+            record_override.grantee = new_grantee.clone();
+            set(env, &record_override);
+        }
+    }
+    set_index(env, patient, old_grantee, &Vec::new(env));
+}
+
+/// Whether an override has cut `grantee` off from the record. Unlike
+/// grants, an override gets no grace window.
+pub fn excludes(env: &Env, record_id: u64, grantee: &Address) -> bool {
+    get(env, record_id, grantee)
+        .is_some_and(|record_override| env.ledger().timestamp() >= record_override.expires_at)
+}
@@ -0,0 +1,106 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::rbac::{self, Permission, Role};
+use crate::visibility::all_record_types;
+use crate::RecordType;
+
+// ── Storage keys ──────────────────────────────────────────────
+const RECORD_TYPE_POLICY: Symbol = symbol_short!("RT_POLICY");
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a record type's policy.
+fn extend_ttl_type_key(env: &Env, key: &(Symbol, RecordType)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// What a provider must hold to author records of one type, on top of
+/// the usual write check.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RecordTypeRequirement {
+    /// An active role ranked at least this high. See [`role_rank`].
+    MinRole(Role),
+    Permission(Permission),
+}
+
+/// One restricted record type.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordTypePolicy {
+    pub record_type: RecordType,
+    pub requirement: RecordTypeRequirement,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// Only restricted record types are stored, so any provider who may write
+// records may author every type until an admin restricts one.
+
+pub fn policy_key(record_type: &RecordType) -> (Symbol, RecordType) {
+    (RECORD_TYPE_POLICY, record_type.clone())
+}
+
+/// Clinical seniority of a role. Roles outside the clinical ladder rank
+/// with `Staff`.
+pub fn role_rank(role: &Role) -> u32 {
+    match role {
+        Role::None | Role::Patient => 0,
+        Role::Staff | Role::Pharmacist => 1,
+        Role::Optometrist => 2,
+        Role::Ophthalmologist => 3,
+        Role::Admin => 4,
+    }
+}
+
+pub fn get_requirement(env: &Env, record_type: &RecordType) -> Option<RecordTypeRequirement> {
+    env.storage().persistent().get(&policy_key(record_type))
+}
+
+/// Restricts `record_type` to `requirement`, or lifts the restriction
+/// when it is `None`.
+pub fn set_requirement(
+    env: &Env,
+    record_type: &RecordType,
+    requirement: &Option<RecordTypeRequirement>,
+) {
+    let key = policy_key(record_type);
+    match requirement {
+        Some(requirement) => {
+            env.storage().persistent().set(&key, requirement);
+            extend_ttl_type_key(env, &key);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Whether `provider` meets the policy for `record_type`.
+pub fn allows(env: &Env, provider: &Address, record_type: &RecordType) -> bool {
+    match get_requirement(env, record_type) {
+        None => true,
+        Some(RecordTypeRequirement::MinRole(role)) => rbac::get_active_assignment(env, provider)
+            .is_some_and(|assignment| role_rank(&assignment.role) >= role_rank(&role)),
+        Some(RecordTypeRequirement::Permission(permission)) => {
+            rbac::has_permission(env, provider, &permission)
+        }
+    }
+}
+
+/// Every restricted record type, in declaration order.
+pub fn get_policies(env: &Env) -> Vec<RecordTypePolicy> {
+    let mut policies = Vec::new(env);
+    for record_type in all_record_types(env).iter() {
+        if let Some(requirement) = get_requirement(env, &record_type) {
+            policies.push_back(RecordTypePolicy {
+                record_type,
+                requirement,
+            });
+        }
+    }
+    policies
+}
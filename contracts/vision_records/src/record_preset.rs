@@ -0,0 +1,108 @@
+use soroban_sdk::{contracttype, symbol_short, Env, Symbol, Vec};
+
+use crate::instance_usage::{InstanceKey, SYMBOL_BYTES};
+use crate::priority::RecordPriority;
+use crate::RecordType;
+
+// ── Storage keys ──────────────────────────────────────────────
+const RECORD_PRESET: Symbol = symbol_short!("REC_PRST");
+const RECORD_PRESET_NAMES: Symbol = symbol_short!("REC_PRSTS");
+const PRESET_OF_RECORD: Symbol = symbol_short!("PRST_OF");
+
+/// Instance-storage keys written here.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] =
+    &[InstanceKey::list(RECORD_PRESET_NAMES, SYMBOL_BYTES)];
+
+/// Maximum number of presets the contract keeps.
+pub const MAX_PRESETS: u32 = 32;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a preset key.
+fn extend_ttl_preset_key(env: &Env, key: &(Symbol, Symbol)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+/// Extends the time-to-live (TTL) for a record's preset stamp.
+fn extend_ttl_stamp_key(env: &Env, key: &(Symbol, u64)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A named kind of record with what must come with it at creation.
+///
+/// `requires_details` needs the eye examination details in the creating
+/// call, so it only applies to `Examination` presets. `requires_cosign`
+/// needs at least one coauthor.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordPreset {
+    pub name: Symbol,
+    pub record_type: RecordType,
+    pub requires_details: bool,
+    pub requires_cosign: bool,
+    pub default_priority: RecordPriority,
+    pub updated_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn preset_key(name: &Symbol) -> (Symbol, Symbol) {
+    (RECORD_PRESET, name.clone())
+}
+
+pub fn stamp_key(record_id: u64) -> (Symbol, u64) {
+    (PRESET_OF_RECORD, record_id)
+}
+
+pub fn get_preset(env: &Env, name: &Symbol) -> Option<RecordPreset> {
+    env.storage().persistent().get(&preset_key(name))
+}
+
+pub fn get_preset_names(env: &Env) -> Vec<Symbol> {
+    env.storage()
+        .instance()
+        .get(&RECORD_PRESET_NAMES)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Every preset, in the order they were first defined.
+pub fn get_presets(env: &Env) -> Vec<RecordPreset> {
+    let mut presets = Vec::new(env);
+    for name in get_preset_names(env).iter() {
+        if let Some(preset) = get_preset(env, &name) {
+            presets.push_back(preset);
+        }
+    }
+    presets
+}
+
+/// Stores a preset, replacing any existing preset of the same name.
+pub fn set_preset(env: &Env, preset: &RecordPreset) {
+    let key = preset_key(&preset.name);
+    env.storage().persistent().set(&key, preset);
+    extend_ttl_preset_key(env, &key);
+
+    let mut names = get_preset_names(env);
+    if !names.contains(&preset.name) {
+        names.push_back(preset.name.clone());
+        env.storage().instance().set(&RECORD_PRESET_NAMES, &names);
+    }
+}
+
+/// The preset a record was created with, if any.
+pub fn get_record_preset(env: &Env, record_id: u64) -> Option<Symbol> {
+    env.storage().persistent().get(&stamp_key(record_id))
+}
+
+pub fn stamp_record(env: &Env, record_id: u64, name: &Symbol) {
+    let key = stamp_key(record_id);
+    env.storage().persistent().set(&key, name);
+    extend_ttl_stamp_key(env, &key);
+}
@@ -0,0 +1,84 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Bytes, BytesN, Env, Symbol};
+
+use teye_common::admin_tiers;
+
+// ── Storage keys ──────────────────────────────────────────────
+const PATIENT_RECOVERY: Symbol = symbol_short!("RECOVERY");
+
+/// How long a recovery waits before it can be finalized, giving the old
+/// address time to veto it.
+pub const RECOVERY_TIMELOCK_SECONDS: u64 = 604_800; // 7 days
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a pending recovery.
+fn extend_ttl_recovery_key(env: &Env, key: &(Symbol, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A pending move of a patient's chart from a lost address to a new one.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PatientRecovery {
+    pub old_patient: Address,
+    pub new_patient: Address,
+    /// SHA-256 of the secret the patient was given out of band.
+    pub challenge_hash: BytesN<32>,
+    pub initiated_by: Address,
+    pub initiated_at: u64,
+    /// Earliest time the recovery can be finalized.
+    pub unlocks_at: u64,
+    /// Whether `finalize_recovery` has started moving the chart. A started
+    /// recovery can no longer be cancelled.
+    pub started: bool,
+    pub records_rebound: u32,
+    /// Live grants moved; expired ones are dropped uncounted.
+    pub grants_rebound: u32,
+    /// Records and grants still to move, as of the last
+    /// `finalize_recovery` call.
+    pub remaining: u32,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// At most one recovery is pending per old address. Finalizing it
+// deactivates the old address, then moves the record index and the
+// patient-wide grants to the new address a batch per call, draining the
+// old lists from the front, and last the user entry. The recovery is
+// cleared once nothing is left to move.
+
+pub fn recovery_key(old_patient: &Address) -> (Symbol, Address) {
+    (PATIENT_RECOVERY, old_patient.clone())
+}
+
+pub fn get(env: &Env, old_patient: &Address) -> Option<PatientRecovery> {
+    env.storage().persistent().get(&recovery_key(old_patient))
+}
+
+pub fn set(env: &Env, recovery: &PatientRecovery) {
+    let key = recovery_key(&recovery.old_patient);
+    env.storage().persistent().set(&key, recovery);
+    extend_ttl_recovery_key(env, &key);
+}
+
+pub fn clear(env: &Env, old_patient: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&recovery_key(old_patient));
+}
+
+/// Whether `preimage` is the secret behind the recovery's challenge.
+pub fn proves(env: &Env, recovery: &PatientRecovery, preimage: &Bytes) -> bool {
+    env.crypto().sha256(preimage).to_bytes() == recovery.challenge_hash
+}
+
+/// Whether finalizing needs an admin other than the one who initiated,
+/// which is the case whenever more than one admin is registered.
+pub fn requires_second_admin(env: &Env) -> bool {
+    admin_tiers::list_admins(env).len() > 1
+}
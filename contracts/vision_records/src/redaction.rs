@@ -0,0 +1,124 @@
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String, Symbol, Vec};
+
+use crate::errors::ContractError;
+use crate::versioning::{self, RecordVersion};
+
+// ── Storage keys ──────────────────────────────────────────────
+const REDACTED_ORIGINAL: Symbol = symbol_short!("RED_ORIG");
+const REDACTED_VERSIONS: Symbol = symbol_short!("RED_VERS");
+
+/// Stored in place of a redacted version's data hash.
+pub const TOMBSTONE: &str = "REDACTED";
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a sealed original.
+fn extend_ttl_original_key(env: &Env, key: &(Symbol, u64, u32)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+/// Extends the time-to-live (TTL) for a record's redacted version list.
+fn extend_ttl_record_key(env: &Env, key: &(Symbol, u64)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// What a redacted version held before its data hash was tombstoned.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RedactedOriginal {
+    pub record_id: u64,
+    pub version: u32,
+    /// The data hash as it was stored, still encrypted under `key_version`.
+    pub data_hash: String,
+    pub key_version: Option<String>,
+    /// Hash of the off-chain justification for the redaction.
+    pub reason_hash: BytesN<32>,
+    pub redacted_by: Address,
+    pub redacted_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// Redaction rewrites one historical version in place: its data hash
+// becomes `TOMBSTONE`, its key version is cleared so readers never try to
+// decrypt the tombstone, and `redacted` is set. Version numbers, authors
+// and timestamps are left alone, so history stays ordered and cursors stay
+// valid. The original goes to a sealed entry only admins can read.
+
+pub fn original_key(record_id: u64, version: u32) -> (Symbol, u64, u32) {
+    (REDACTED_ORIGINAL, record_id, version)
+}
+
+pub fn versions_key(record_id: u64) -> (Symbol, u64) {
+    (REDACTED_VERSIONS, record_id)
+}
+
+pub fn get_original(env: &Env, record_id: u64, version: u32) -> Option<RedactedOriginal> {
+    env.storage()
+        .persistent()
+        .get(&original_key(record_id, version))
+}
+
+/// The record's redacted version numbers, in the order they were redacted.
+pub fn get_redacted_versions(env: &Env, record_id: u64) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(&versions_key(record_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Tombstones `version` of a record and seals its original.
+///
+/// The latest version cannot be redacted, since it is the record's
+/// current content; correct the record with an update first. A version
+/// can only be redacted once.
+pub fn redact(
+    env: &Env,
+    record_id: u64,
+    version: u32,
+    reason_hash: BytesN<32>,
+    redacted_by: Address,
+) -> Result<RedactedOriginal, ContractError> {
+    let entry: RecordVersion =
+        versioning::get_version(env, record_id, version).ok_or(ContractError::RecordNotFound)?;
+    if entry.redacted || version >= versioning::get_version_count(env, record_id) {
+        return Err(ContractError::InvalidInput);
+    }
+
+    let original = RedactedOriginal {
+        record_id,
+        version,
+        data_hash: entry.data_hash.clone(),
+        key_version: entry.key_version.clone(),
+        reason_hash,
+        redacted_by,
+        redacted_at: env.ledger().timestamp(),
+    };
+    let key = original_key(record_id, version);
+    env.storage().persistent().set(&key, &original);
+    extend_ttl_original_key(env, &key);
+
+    let mut versions = get_redacted_versions(env, record_id);
+    versions.push_back(version);
+    let list_key = versions_key(record_id);
+    env.storage().persistent().set(&list_key, &versions);
+    extend_ttl_record_key(env, &list_key);
+
+    versioning::replace_version(
+        env,
+        &RecordVersion {
+            data_hash: String::from_str(env, TOMBSTONE),
+            key_version: None,
+            redacted: true,
+            ..entry
+        },
+    );
+    Ok(original)
+}
@@ -0,0 +1,93 @@
+use soroban_sdk::{symbol_short, Env, Symbol};
+
+use crate::instance_usage::InstanceKey;
+use crate::{temp_storage, AccessLevel};
+
+// ── Storage keys ──────────────────────────────────────────────
+const SELF_REG_ENABLED: Symbol = symbol_short!("SREG_ON");
+const SELF_REG_DAILY_CAP: Symbol = symbol_short!("SREG_CAP");
+const SELF_REG_COUNT: Symbol = symbol_short!("SREG_CNT");
+const AUTO_GRANT: Symbol = symbol_short!("REG_AUTO");
+
+/// Instance-storage keys written here.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] = &[
+    InstanceKey::flag(SELF_REG_ENABLED),
+    InstanceKey::number(SELF_REG_DAILY_CAP),
+    InstanceKey::value(AUTO_GRANT, 24),
+];
+
+/// Daily self-registration cap used until an admin configures one.
+pub const DEFAULT_DAILY_CAP: u32 = 100;
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+// ── Storage Functions ────────────────────────────────────────
+
+/// Whether patients may register themselves without an admin.
+pub fn is_self_registration_enabled(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&SELF_REG_ENABLED)
+        .unwrap_or(false)
+}
+
+pub fn set_self_registration_enabled(env: &Env, enabled: bool) {
+    env.storage().instance().set(&SELF_REG_ENABLED, &enabled);
+}
+
+/// Maximum number of self-registrations accepted per UTC day.
+pub fn get_daily_cap(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&SELF_REG_DAILY_CAP)
+        .unwrap_or(DEFAULT_DAILY_CAP)
+}
+
+pub fn set_daily_cap(env: &Env, cap: u32) {
+    env.storage().instance().set(&SELF_REG_DAILY_CAP, &cap);
+}
+
+pub fn day_bucket(timestamp: u64) -> u64 {
+    timestamp / SECONDS_PER_DAY
+}
+
+pub fn daily_count_key(day: u64) -> (Symbol, u64) {
+    (SELF_REG_COUNT, day)
+}
+
+/// Number of self-registrations accepted on the day containing `timestamp`.
+pub fn get_daily_count(env: &Env, timestamp: u64) -> u32 {
+    temp_storage::get_temp(env, &daily_count_key(day_bucket(timestamp))).unwrap_or(0)
+}
+
+/// Counts one self-registration against the daily cap.
+///
+/// Returns false without counting if the cap for the day is already
+/// reached. Counters only matter for the day they cover, so they live in
+/// temporary storage.
+pub fn try_consume_daily_slot(env: &Env, timestamp: u64) -> bool {
+    let count = get_daily_count(env, timestamp);
+    if count >= get_daily_cap(env) {
+        return false;
+    }
+    temp_storage::set_temp(
+        env,
+        &daily_count_key(day_bucket(timestamp)),
+        &count.saturating_add(1),
+        temp_storage::TEMP_TTL_DAY.saturating_mul(2),
+    );
+    true
+}
+
+/// Access a provider receives from a patient they register, as
+/// `(level, duration_seconds)`. `None` disables the auto-grant.
+pub fn get_auto_grant(env: &Env) -> Option<(AccessLevel, u64)> {
+    env.storage().instance().get(&AUTO_GRANT)
+}
+
+pub fn set_auto_grant(env: &Env, auto_grant: &Option<(AccessLevel, u64)>) {
+    match auto_grant {
+        Some(value) => env.storage().instance().set(&AUTO_GRANT, value),
+        None => env.storage().instance().remove(&AUTO_GRANT),
+    }
+}
@@ -0,0 +1,92 @@
+use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
+
+use crate::instance_usage::InstanceKey;
+
+// ── Storage keys ──────────────────────────────────────────────
+const RESIDENCY_ENFORCED: Symbol = symbol_short!("RES_ON");
+const PATIENT_REGION: Symbol = symbol_short!("PAT_REGN");
+const PROVIDER_JURISDICTIONS: Symbol = symbol_short!("PROV_JUR");
+
+/// Instance-storage keys written here.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] = &[InstanceKey::flag(RESIDENCY_ENFORCED)];
+
+/// Most regions a provider may be registered in.
+pub const MAX_JURISDICTIONS: u32 = 16;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a residency key.
+fn extend_ttl_residency_key(env: &Env, key: &(Symbol, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// A patient without a declared region is unrestricted, and a provider
+// without jurisdictions may only write for such patients once enforcement
+// is on.
+
+pub fn is_enforced(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&RESIDENCY_ENFORCED)
+        .unwrap_or(false)
+}
+
+pub fn set_enforced(env: &Env, enforced: bool) {
+    env.storage().instance().set(&RESIDENCY_ENFORCED, &enforced);
+}
+
+pub fn patient_region_key(patient: &Address) -> (Symbol, Address) {
+    (PATIENT_REGION, patient.clone())
+}
+
+pub fn provider_jurisdictions_key(provider: &Address) -> (Symbol, Address) {
+    (PROVIDER_JURISDICTIONS, provider.clone())
+}
+
+pub fn get_patient_region(env: &Env, patient: &Address) -> Option<Symbol> {
+    env.storage().persistent().get(&patient_region_key(patient))
+}
+
+pub fn set_patient_region(env: &Env, patient: &Address, region: &Symbol) {
+    let key = patient_region_key(patient);
+    env.storage().persistent().set(&key, region);
+    extend_ttl_residency_key(env, &key);
+}
+
+pub fn get_jurisdictions(env: &Env, provider: &Address) -> Vec<Symbol> {
+    env.storage()
+        .persistent()
+        .get(&provider_jurisdictions_key(provider))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Replaces the provider's jurisdictions; an empty list clears them.
+pub fn set_jurisdictions(env: &Env, provider: &Address, regions: &Vec<Symbol>) {
+    let key = provider_jurisdictions_key(provider);
+    if regions.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, regions);
+        extend_ttl_residency_key(env, &key);
+    }
+}
+
+/// The patient's region when `provider` may not write their data, or
+/// `None` when residency allows the write. Always `None` while enforcement
+/// is off.
+pub fn mismatch(env: &Env, provider: &Address, patient: &Address) -> Option<Symbol> {
+    if !is_enforced(env) {
+        return None;
+    }
+    let region = get_patient_region(env, patient)?;
+    if get_jurisdictions(env, provider).contains(&region) {
+        None
+    } else {
+        Some(region)
+    }
+}
@@ -0,0 +1,146 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::instance_usage::InstanceKey;
+use crate::{expiry, AccessGrant, ContractError};
+
+// ── Storage keys ──────────────────────────────────────────────
+const REVOCATION_GRACE: Symbol = symbol_short!("REV_GRACE");
+const PENDING_REVOCATION: Symbol = symbol_short!("REV_PEND");
+
+/// Instance-storage keys written here.
+pub(crate) const INSTANCE_KEYS: &[InstanceKey] = &[InstanceKey::number(REVOCATION_GRACE)];
+
+/// Longest revocation grace window an admin may configure.
+pub const MAX_REVOCATION_GRACE_SECONDS: u64 = 604_800; // 7 days
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a pending revocation.
+fn extend_ttl_pending_key(env: &Env, key: &(Symbol, Address, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A revocation the patient can still cancel. Until `effective_at` the
+/// grant keeps working; from then on it is expired.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingRevocation {
+    pub patient: Address,
+    pub grantee: Address,
+    pub requested_at: u64,
+    pub effective_at: u64,
+    /// The grant's expiry before the revocation capped it, restored on
+    /// cancellation.
+    pub original_expires_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// A pending revocation caps the grant's `expires_at` at `effective_at`, so
+// every existing expiry check honours the grant until then and treats it
+// as expired (and purgeable) afterwards. A new grant to the same grantee
+// replaces the pending revocation.
+
+fn access_key(patient: &Address, grantee: &Address) -> (Symbol, Address, Address) {
+    (symbol_short!("ACCESS"), patient.clone(), grantee.clone())
+}
+
+pub fn pending_key(patient: &Address, grantee: &Address) -> (Symbol, Address, Address) {
+    (PENDING_REVOCATION, patient.clone(), grantee.clone())
+}
+
+/// Seconds a revocation waits before taking effect. Defaults to 0, which
+/// revokes immediately.
+pub fn get_grace(env: &Env) -> u64 {
+    env.storage().instance().get(&REVOCATION_GRACE).unwrap_or(0)
+}
+
+pub fn set_grace(env: &Env, seconds: u64) {
+    env.storage().instance().set(&REVOCATION_GRACE, &seconds);
+}
+
+pub fn get_pending(env: &Env, patient: &Address, grantee: &Address) -> Option<PendingRevocation> {
+    env.storage()
+        .persistent()
+        .get(&pending_key(patient, grantee))
+}
+
+fn set_pending(env: &Env, pending: &PendingRevocation) {
+    let key = pending_key(&pending.patient, &pending.grantee);
+    env.storage().persistent().set(&key, pending);
+    extend_ttl_pending_key(env, &key);
+}
+
+pub fn clear_pending(env: &Env, patient: &Address, grantee: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&pending_key(patient, grantee));
+}
+
+/// Schedules the revocation of a live grant `grace` seconds from now. An
+/// already scheduled revocation is returned unchanged. Returns `None` when
+/// there is no live grant to wind down.
+pub fn schedule(
+    env: &Env,
+    patient: &Address,
+    grantee: &Address,
+    grace: u64,
+) -> Option<PendingRevocation> {
+    let key = access_key(patient, grantee);
+    let mut grant: AccessGrant = env.storage().persistent().get(&key)?;
+    if expiry::is_expired(env, grant.expires_at) {
+        return None;
+    }
+    if let Some(pending) = get_pending(env, patient, grantee) {
+        return Some(pending);
+    }
+
+    let now = env.ledger().timestamp();
+    let pending = PendingRevocation {
+        patient: patient.clone(),
+        grantee: grantee.clone(),
+        requested_at: now,
+        effective_at: now.saturating_add(grace).min(grant.expires_at),
+        original_expires_at: grant.expires_at,
+    };
+    grant.expires_at = pending.effective_at;
+    env.storage().persistent().set(&key, &grant);
+    set_pending(env, &pending);
+    Some(pending)
+}
+
+/// Cancels a revocation that has not yet taken effect, restoring the
+/// grant's original expiry.
+pub fn cancel(
+    env: &Env,
+    patient: &Address,
+    grantee: &Address,
+) -> Result<PendingRevocation, ContractError> {
+    let pending = get_pending(env, patient, grantee).ok_or(ContractError::RevocationNotPending)?;
+    let key = access_key(patient, grantee);
+    let mut grant: AccessGrant = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .filter(|_| env.ledger().timestamp() < pending.effective_at)
+        .ok_or(ContractError::RevocationNotPending)?;
+    grant.expires_at = pending.original_expires_at;
+    env.storage().persistent().set(&key, &grant);
+    clear_pending(env, patient, grantee);
+    Ok(pending)
+}
+
+/// Moves a pending revocation along with a grant migrated to a new
+/// grantee.
+pub fn migrate(env: &Env, patient: &Address, old_grantee: &Address, new_grantee: &Address) {
+    if let Some(mut pending) = get_pending(env, patient, old_grantee) {
+        clear_pending(env, patient, old_grantee);
+        pending.grantee = new_grantee.clone();
+        set_pending(env, &pending);
+    }
+}
@@ -0,0 +1,167 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::expiry;
+use crate::rbac::{Role, RoleAssignment};
+use crate::User;
+
+// ── Storage keys ──────────────────────────────────────────────
+const ROLE_HISTORY: Symbol = symbol_short!("ROLE_HST");
+
+/// Number of most recent entries kept per user.
+pub const MAX_ROLE_HISTORY: u32 = 50;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a user's role history.
+fn extend_ttl_history_key(env: &Env, key: &(Symbol, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// What happened to a user's role.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RoleAction {
+    /// The user was given a role while holding none.
+    Assigned,
+    /// The user's role was replaced by a different one.
+    Changed,
+    /// The user's role lapsed. Noticed, and logged at its expiry time,
+    /// when the user is next assigned a role.
+    Expired,
+    /// Someone delegated a role to the user.
+    Delegated,
+    /// A role delegated to the user was withdrawn.
+    Revoked,
+}
+
+/// One change in the roles a user held.
+///
+/// `by` is whoever made the change, or the contract itself for expiries
+/// and for the initial role of users registered before history was kept.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleHistoryEntry {
+    pub role: Role,
+    pub action: RoleAction,
+    pub by: Address,
+    pub at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn history_key(user: &Address) -> (Symbol, Address) {
+    (ROLE_HISTORY, user.clone())
+}
+
+/// The user's retained role history, oldest first.
+pub fn get_history(env: &Env, user: &Address) -> Vec<RoleHistoryEntry> {
+    env.storage()
+        .persistent()
+        .get(&history_key(user))
+        .unwrap_or(Vec::new(env))
+}
+
+fn push(history: &mut Vec<RoleHistoryEntry>, entry: RoleHistoryEntry) {
+    if history.len() >= MAX_ROLE_HISTORY {
+        history.pop_front();
+    }
+    history.push_back(entry);
+}
+
+fn save(env: &Env, user: &Address, history: &Vec<RoleHistoryEntry>) {
+    let key = history_key(user);
+    env.storage().persistent().set(&key, history);
+    extend_ttl_history_key(env, &key);
+}
+
+/// When the user was registered, or 0 if they never were.
+fn registered_at(env: &Env, user: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get::<_, User>(&(symbol_short!("USER"), user.clone()))
+        .map_or(0, |user| user.registered_at)
+}
+
+/// Appends a single entry to the user's history.
+pub fn record(env: &Env, user: &Address, role: &Role, action: RoleAction, by: &Address) {
+    let mut history = get_history(env, user);
+    push(
+        &mut history,
+        RoleHistoryEntry {
+            role: role.clone(),
+            action,
+            by: by.clone(),
+            at: env.ledger().timestamp(),
+        },
+    );
+    save(env, user, &history);
+}
+
+/// Logs the transition from `previous`, the user's stored assignment if
+/// any, to `role`. Reassigning the role the user already holds logs
+/// nothing. A user with an assignment but no history was registered
+/// before history was kept, and first gets an entry for that assignment.
+pub fn record_assignment(
+    env: &Env,
+    user: &Address,
+    previous: Option<&RoleAssignment>,
+    role: &Role,
+    by: &Address,
+) {
+    let now = env.ledger().timestamp();
+    let contract = env.current_contract_address();
+    let mut history = get_history(env, user);
+    let backfilled = previous.is_some() && history.is_empty();
+    if let Some(previous) = previous.filter(|_| backfilled) {
+        push(
+            &mut history,
+            RoleHistoryEntry {
+                role: previous.role.clone(),
+                action: RoleAction::Assigned,
+                by: contract.clone(),
+                at: registered_at(env, user),
+            },
+        );
+    }
+
+    let action = match previous {
+        None => Some(RoleAction::Assigned),
+        Some(previous)
+            if previous.expires_at != 0 && expiry::is_expired(env, previous.expires_at) =>
+        {
+            push(
+                &mut history,
+                RoleHistoryEntry {
+                    role: previous.role.clone(),
+                    action: RoleAction::Expired,
+                    by: contract,
+                    at: previous.expires_at,
+                },
+            );
+            Some(RoleAction::Assigned)
+        }
+        Some(previous) if previous.role != *role => Some(RoleAction::Changed),
+        Some(_) => None,
+    };
+
+    let changed = action.is_some();
+    if let Some(action) = action {
+        push(
+            &mut history,
+            RoleHistoryEntry {
+                role: role.clone(),
+                action,
+                by: by.clone(),
+                at: now,
+            },
+        );
+    }
+    if changed || backfilled {
+        save(env, user, &history);
+    }
+}
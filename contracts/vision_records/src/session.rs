@@ -0,0 +1,67 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::access;
+use crate::expiry;
+use crate::temp_storage;
+use crate::AccessLevel;
+
+// ── Storage keys ──────────────────────────────────────────────
+const SESSION: Symbol = symbol_short!("SESSION");
+
+/// Longest session a patient can open in one call.
+pub const MAX_SESSION_TTL_SECONDS: u64 = 86_400; // 1 day
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A short-lived binding that lets `session_key` read on the patient's
+/// behalf.
+///
+/// Sessions are read-only: `scope` caps what the session may see, but a
+/// `Write` or `Full` scope never authorizes a write. Held in temporary
+/// storage, so the network drops the binding shortly after it expires.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Session {
+    pub patient: Address,
+    pub session_key: Address,
+    pub scope: AccessLevel,
+    pub created_at: u64,
+    pub expires_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn session_key(patient: &Address, session_key: &Address) -> (Symbol, Address, Address) {
+    (SESSION, patient.clone(), session_key.clone())
+}
+
+/// Returns the session, or `None` if it was never opened, was revoked, or
+/// has expired.
+pub fn get_session(env: &Env, patient: &Address, key: &Address) -> Option<Session> {
+    let session: Session = temp_storage::get_temp(env, &session_key(patient, key))?;
+    if expiry::is_expired(env, session.expires_at) {
+        return None;
+    }
+    Some(session)
+}
+
+/// Stores a session until slightly past its expiry.
+pub fn set_session(env: &Env, session: &Session) {
+    let key = session_key(&session.patient, &session.session_key);
+    let lifetime = session.expires_at.saturating_sub(session.created_at);
+    let ttl =
+        temp_storage::ledgers_for_seconds(lifetime).saturating_add(temp_storage::TEMP_TTL_SHORT);
+    temp_storage::set_temp(env, &key, session, ttl);
+}
+
+pub fn remove_session(env: &Env, patient: &Address, key: &Address) {
+    temp_storage::remove_temp(env, &session_key(patient, key));
+}
+
+/// Whether `key` holds a live session that may read `patient`'s records.
+pub fn can_read(env: &Env, patient: &Address, key: &Address) -> bool {
+    match get_session(env, patient, key) {
+        Some(session) => access::level_satisfies(&AccessLevel::Read, &session.scope),
+        None => false,
+    }
+}
@@ -0,0 +1,140 @@
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Symbol, Vec};
+
+use crate::expiry;
+use crate::pending_gc::{self, PendingCategory, PendingEntry};
+use crate::temp_storage;
+use crate::AccessLevel;
+
+// ── Storage keys ──────────────────────────────────────────────
+const SHARE_CODE: Symbol = symbol_short!("SHR_CODE");
+const LIVE_CODES: Symbol = symbol_short!("SHR_LIVE");
+
+/// Most unredeemed, unexpired codes a patient may hold at once.
+pub const MAX_LIVE_SHARE_CODES: u32 = 20;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a patient's live-code list.
+fn extend_ttl_live_key(env: &Env, key: &(Symbol, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A single-use code a patient hands out off-chain to share one record
+/// with someone whose address they do not know yet. Only the SHA-256 of
+/// the code is stored; whoever presents the preimage first is granted
+/// access until `expires_at`.
+///
+/// Held in temporary storage, so an unredeemed code is dropped by the
+/// network shortly after it lapses.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShareCode {
+    pub patient: Address,
+    pub record_id: u64,
+    pub code_hash: BytesN<32>,
+    pub level: AccessLevel,
+    pub created_at: u64,
+    /// Last moment the code can be redeemed, and when the grant it
+    /// creates ends.
+    pub expires_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn share_code_key(record_id: u64, code_hash: &BytesN<32>) -> (Symbol, u64, BytesN<32>) {
+    (SHARE_CODE, record_id, code_hash.clone())
+}
+
+pub fn get_code(env: &Env, record_id: u64, code_hash: &BytesN<32>) -> Option<ShareCode> {
+    temp_storage::get_temp(env, &share_code_key(record_id, code_hash))
+}
+
+/// Stores a code until slightly past its expiry.
+pub fn set_code(env: &Env, code: &ShareCode) {
+    let key = share_code_key(code.record_id, &code.code_hash);
+    let lifetime = code.expires_at.saturating_sub(code.created_at);
+    let ttl =
+        temp_storage::ledgers_for_seconds(lifetime).saturating_add(temp_storage::TEMP_TTL_DAY);
+    temp_storage::set_temp(env, &key, code, ttl);
+    pending_gc::register(
+        env,
+        &PendingCategory::ShareCode,
+        &PendingEntry::ShareCode(code.record_id, code.code_hash.clone()),
+        code.expires_at,
+    );
+}
+
+pub fn remove_code(env: &Env, record_id: u64, code_hash: &BytesN<32>) {
+    temp_storage::remove_temp(env, &share_code_key(record_id, code_hash));
+}
+
+// ── Live codes per patient ───────────────────────────────────
+//
+// Each patient's outstanding codes are listed so `create_share_code` can
+// cap them. Redeeming or cancelling a code drops it from the list; codes
+// that lapse or are collected by `gc_pending` stay listed until the
+// patient's next `create_share_code` prunes them.
+
+fn live_key(patient: &Address) -> (Symbol, Address) {
+    (LIVE_CODES, patient.clone())
+}
+
+fn get_live(env: &Env, patient: &Address) -> Vec<(u64, BytesN<32>)> {
+    env.storage()
+        .persistent()
+        .get(&live_key(patient))
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_live(env: &Env, patient: &Address, codes: &Vec<(u64, BytesN<32>)>) {
+    let key = live_key(patient);
+    if codes.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, codes);
+        extend_ttl_live_key(env, &key);
+    }
+}
+
+/// Drops the patient's listed codes that are gone or expired and returns
+/// how many remain.
+pub fn prune_live(env: &Env, patient: &Address) -> u32 {
+    let listed = get_live(env, patient);
+    let mut live = Vec::new(env);
+    for (record_id, code_hash) in listed.iter() {
+        let open = get_code(env, record_id, &code_hash)
+            .is_some_and(|code| !expiry::is_expired(env, code.expires_at));
+        if open {
+            live.push_back((record_id, code_hash));
+        }
+    }
+    if live.len() != listed.len() {
+        set_live(env, patient, &live);
+    }
+    live.len()
+}
+
+/// Stores a new code and lists it against its patient.
+pub fn add_live_code(env: &Env, code: &ShareCode) {
+    set_code(env, code);
+    let mut live = get_live(env, &code.patient);
+    live.push_back((code.record_id, code.code_hash.clone()));
+    set_live(env, &code.patient, &live);
+}
+
+/// Removes a redeemed or cancelled code and drops it from its patient's
+/// list.
+pub fn retire_code(env: &Env, code: &ShareCode) {
+    remove_code(env, code.record_id, &code.code_hash);
+    let mut live = get_live(env, &code.patient);
+    let entry = (code.record_id, code.code_hash.clone());
+    if let Some(position) = live.first_index_of(&entry) {
+        live.remove(position);
+        set_live(env, &code.patient, &live);
+    }
+}
@@ -0,0 +1,78 @@
+use soroban_sdk::{contracttype, symbol_short, xdr::ToXdr, Address, Bytes, BytesN, Env, Symbol};
+
+use crate::AccessLevel;
+
+// ── Storage keys ──────────────────────────────────────────────
+const SIG_KEY: Symbol = symbol_short!("SIG_KEY");
+const SIG_NONCE: Symbol = symbol_short!("SIG_NONCE");
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a patient's signing key or nonce.
+fn extend_ttl_signing_key(env: &Env, key: &(Symbol, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// The message a patient signs to approve a grant relayed by someone else.
+///
+/// The signed bytes are the XDR encoding of this struct. Binding the
+/// contract address keeps a signature from being replayed against another
+/// deployment; the nonce keeps it from being replayed against this one.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GrantApproval {
+    pub contract: Address,
+    pub patient: Address,
+    pub grantee: Address,
+    pub level: AccessLevel,
+    pub duration_seconds: u64,
+    pub nonce: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn signing_key_key(patient: &Address) -> (Symbol, Address) {
+    (SIG_KEY, patient.clone())
+}
+
+pub fn nonce_key(patient: &Address) -> (Symbol, Address) {
+    (SIG_NONCE, patient.clone())
+}
+
+pub fn get_signing_key(env: &Env, patient: &Address) -> Option<BytesN<32>> {
+    env.storage().persistent().get(&signing_key_key(patient))
+}
+
+/// Registers `pubkey` for the patient, replacing any earlier key. The
+/// nonce counter carries on, so approvals signed with an old key stay
+/// spent.
+pub fn set_signing_key(env: &Env, patient: &Address, pubkey: &BytesN<32>) {
+    let key = signing_key_key(patient);
+    env.storage().persistent().set(&key, pubkey);
+    extend_ttl_signing_key(env, &key);
+}
+
+/// The nonce the patient's next signed approval must carry.
+pub fn get_nonce(env: &Env, patient: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&nonce_key(patient))
+        .unwrap_or(0)
+}
+
+/// Spends the patient's current nonce.
+pub fn advance_nonce(env: &Env, patient: &Address) {
+    let key = nonce_key(patient);
+    let next = get_nonce(env, patient).saturating_add(1);
+    env.storage().persistent().set(&key, &next);
+    extend_ttl_signing_key(env, &key);
+}
+
+pub fn approval_message(env: &Env, approval: &GrantApproval) -> Bytes {
+    approval.clone().to_xdr(env)
+}
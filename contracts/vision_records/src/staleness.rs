@@ -0,0 +1,81 @@
+use soroban_sdk::{contracttype, symbol_short, Env, Symbol, Vec};
+
+use crate::visibility::all_record_types;
+use crate::RecordType;
+
+// ── Storage keys ──────────────────────────────────────────────
+const STALENESS_POLICY: Symbol = symbol_short!("STALE_POL");
+
+/// Most record IDs `get_stale_records` returns per call.
+pub const MAX_STALE_RESULTS: u32 = 50;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a record type's staleness policy.
+fn extend_ttl_type_key(env: &Env, key: &(Symbol, RecordType)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// How long records of one type stay current after their last update.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StalenessPolicy {
+    pub record_type: RecordType,
+    pub max_age_seconds: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// Staleness is worked out at read time from a record's `updated_at`, so
+// changing a policy takes effect on every existing record at once and
+// nothing is written when a record goes stale. Types without a policy
+// never go stale.
+
+pub fn policy_key(record_type: &RecordType) -> (Symbol, RecordType) {
+    (STALENESS_POLICY, record_type.clone())
+}
+
+pub fn get_max_age(env: &Env, record_type: &RecordType) -> Option<u64> {
+    env.storage().persistent().get(&policy_key(record_type))
+}
+
+/// Sets the staleness window for `record_type`, or removes it when
+/// `max_age_seconds` is 0.
+pub fn set_max_age(env: &Env, record_type: &RecordType, max_age_seconds: u64) {
+    let key = policy_key(record_type);
+    if max_age_seconds == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &max_age_seconds);
+        extend_ttl_type_key(env, &key);
+    }
+}
+
+/// Whether a record of `record_type` last updated at `updated_at` is
+/// older than its type's window. A record exactly `max_age_seconds` old
+/// is still current.
+pub fn is_stale(env: &Env, record_type: &RecordType, updated_at: u64) -> bool {
+    match get_max_age(env, record_type) {
+        Some(max_age) => env.ledger().timestamp().saturating_sub(updated_at) > max_age,
+        None => false,
+    }
+}
+
+/// Every record type with a staleness window, in declaration order.
+pub fn get_policies(env: &Env) -> Vec<StalenessPolicy> {
+    let mut policies = Vec::new(env);
+    for record_type in all_record_types(env).iter() {
+        if let Some(max_age_seconds) = get_max_age(env, &record_type) {
+            policies.push_back(StalenessPolicy {
+                record_type,
+                max_age_seconds,
+            });
+        }
+    }
+    policies
+}
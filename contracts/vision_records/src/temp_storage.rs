@@ -0,0 +1,73 @@
+use soroban_sdk::{Env, IntoVal, TryFromVal, Val};
+
+// ── TTL constants ─────────────────────────────────────────────
+//
+// Temporary entries are deleted by the network once their TTL lapses, so
+// they never need to be cleaned up and never pay rent past their lifetime.
+// Only use this module for state that is safe to lose: anything a user
+// would expect to still exist later belongs in persistent storage.
+
+/// Assumed average ledger close time, used to convert seconds into ledgers.
+pub const LEDGER_CLOSE_SECONDS: u64 = 5;
+
+/// About one hour; for single-use tokens and idempotency keys.
+pub const TEMP_TTL_SHORT: u32 = 720;
+
+/// About one day.
+pub const TEMP_TTL_DAY: u32 = 17_280;
+
+/// About seven days; for requests that wait on another party.
+pub const TEMP_TTL_WEEK: u32 = 120_960;
+
+/// Longest TTL the network lets an entry be extended to, about 180 days.
+/// Extending past it is a host error, so it traps the whole call.
+pub const MAX_TEMP_TTL: u32 = 3_110_400;
+
+/// Whether an entry kept for `seconds` plus a day of slack, the TTL most
+/// callers here set, stays within `MAX_TEMP_TTL`.
+pub fn fits_max_ttl(seconds: u64) -> bool {
+    ledgers_for_seconds(seconds).saturating_add(TEMP_TTL_DAY) <= MAX_TEMP_TTL
+}
+
+/// Converts a duration in seconds into a ledger count, rounding up.
+pub fn ledgers_for_seconds(seconds: u64) -> u32 {
+    let ledgers = seconds.div_ceil(LEDGER_CLOSE_SECONDS);
+    u32::try_from(ledgers).unwrap_or(u32::MAX)
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+/// Stores `value` in temporary storage, live for at least `ttl_ledgers`.
+pub fn set_temp<K, V>(env: &Env, key: &K, value: &V, ttl_ledgers: u32)
+where
+    K: IntoVal<Env, Val>,
+    V: IntoVal<Env, Val>,
+{
+    env.storage().temporary().set(key, value);
+    env.storage()
+        .temporary()
+        .extend_ttl(key, ttl_ledgers, ttl_ledgers);
+}
+
+/// Reads a temporary entry. Returns `None` once the entry has expired.
+pub fn get_temp<K, V>(env: &Env, key: &K) -> Option<V>
+where
+    K: IntoVal<Env, Val>,
+    V: TryFromVal<Env, Val>,
+{
+    env.storage().temporary().get(key)
+}
+
+pub fn has_temp<K>(env: &Env, key: &K) -> bool
+where
+    K: IntoVal<Env, Val>,
+{
+    env.storage().temporary().has(key)
+}
+
+pub fn remove_temp<K>(env: &Env, key: &K)
+where
+    K: IntoVal<Env, Val>,
+{
+    env.storage().temporary().remove(key);
+}
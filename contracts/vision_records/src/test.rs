@@ -6,6 +6,7 @@
 )]
 
 use super::*;
+use crate::testutils::install_default_policy;
 use soroban_sdk::testutils::{Address as _, Events, Ledger};
 use soroban_sdk::{symbol_short, Env, IntoVal, TryIntoVal};
 
@@ -16,16 +17,13 @@ fn test_initialize() {
     let client = VisionRecordsContractClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
-    client.initialize(&admin);
+    client.initialize(&admin, &None);
+    // Captured right away: each later call replaces the recorded events
     let events = env.events().all();
 
     assert!(client.is_initialized());
     assert_eq!(client.get_admin(), admin);
-
-    let our_events = env.events().all();
-    let events_vec = our_events.events();
-
-    assert!(!events_vec.is_empty());
+    assert!(!events.events().is_empty());
 }
 
 #[test]
@@ -37,7 +35,7 @@ fn test_whitelist_register_user_and_add_record_enforcement() {
     let client = VisionRecordsContractClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
-    client.initialize(&admin);
+    client.initialize(&admin, &None);
 
     let user = Address::generate(&env);
     let patient = Address::generate(&env);
@@ -116,7 +114,7 @@ fn test_whitelist_enforced_for_add_records_batch() {
     let admin = Address::generate(&env);
     let provider = Address::generate(&env);
     let patient = Address::generate(&env);
-    client.initialize(&admin);
+    client.initialize(&admin, &None);
     client.register_user(
         &admin,
         &provider,
@@ -157,7 +155,7 @@ fn test_whitelist_admin_only_management() {
     let admin = Address::generate(&env);
     let non_admin = Address::generate(&env);
     let target = Address::generate(&env);
-    client.initialize(&admin);
+    client.initialize(&admin, &None);
 
     let add_res = client.try_add_to_whitelist(&non_admin, &target);
     assert!(add_res.is_err());
@@ -190,10 +188,10 @@ fn test_rate_limit_add_record_and_grant_access() {
     let client = VisionRecordsContractClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
-    client.initialize(&admin);
+    client.initialize(&admin, &None);
 
     // Configure a small window for testing
-    client.set_rate_limit_config(&admin, &2, &60);
+    client.set_rate_limit_config(&admin, &2, &60, &0);
 
     let patient = Address::generate(&env);
     let provider = Address::generate(&env);
@@ -259,7 +257,7 @@ fn test_permission_without_consent_denied() {
     let client = VisionRecordsContractClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
-    client.initialize(&admin);
+    client.initialize(&admin, &None);
 
     let patient = Address::generate(&env);
     let doctor = Address::generate(&env);
@@ -278,9 +276,10 @@ fn test_consent_and_permission_grants_access() {
 
     let contract_id = env.register(VisionRecordsContract, ());
     let client = VisionRecordsContractClient::new(&env, &contract_id);
+    install_default_policy(&env, &contract_id);
 
     let admin = Address::generate(&env);
-    client.initialize(&admin);
+    client.initialize(&admin, &None);
 
     let patient = Address::generate(&env);
     let doctor = Address::generate(&env);
@@ -299,9 +298,10 @@ fn test_revoked_consent_blocks_access() {
 
     let contract_id = env.register(VisionRecordsContract, ());
     let client = VisionRecordsContractClient::new(&env, &contract_id);
+    install_default_policy(&env, &contract_id);
 
     let admin = Address::generate(&env);
-    client.initialize(&admin);
+    client.initialize(&admin, &None);
 
     let patient = Address::generate(&env);
     let doctor = Address::generate(&env);
@@ -324,9 +324,10 @@ fn test_expired_consent_blocks_access() {
 
     let contract_id = env.register(VisionRecordsContract, ());
     let client = VisionRecordsContractClient::new(&env, &contract_id);
+    install_default_policy(&env, &contract_id);
 
     let admin = Address::generate(&env);
-    client.initialize(&admin);
+    client.initialize(&admin, &None);
 
     let patient = Address::generate(&env);
     let doctor = Address::generate(&env);
@@ -353,7 +354,7 @@ fn test_get_record_consent_required() {
     let client = VisionRecordsContractClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
-    client.initialize(&admin);
+    client.initialize(&admin, &None);
 
     let patient = Address::generate(&env);
     let provider = Address::generate(&env);
@@ -0,0 +1,62 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{access, AccessLevel, VisionRecordsContract, VisionRecordsContractClient};
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+const LEVELS: [AccessLevel; 4] = [
+    AccessLevel::None,
+    AccessLevel::Read,
+    AccessLevel::Write,
+    AccessLevel::Full,
+];
+
+#[test]
+fn test_level_satisfies_full_matrix() {
+    // expected[required][actual]
+    let expected = [
+        [true, true, true, true],
+        [false, true, true, true],
+        [false, false, true, true],
+        [false, false, false, true],
+    ];
+
+    for (r, required) in LEVELS.iter().enumerate() {
+        for (a, actual) in LEVELS.iter().enumerate() {
+            assert_eq!(
+                access::level_satisfies(required, actual),
+                expected[r][a],
+                "required {:?}, actual {:?}",
+                required,
+                actual
+            );
+        }
+    }
+}
+
+#[test]
+fn test_level_rank_is_strictly_increasing() {
+    for pair in LEVELS.windows(2) {
+        assert!(access::level_rank(&pair[0]) < access::level_rank(&pair[1]));
+    }
+}
+
+#[test]
+fn test_meets_access_level_without_grant() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(VisionRecordsContract, ());
+    let client = VisionRecordsContractClient::new(&env, &contract_id);
+    client.initialize(&Address::generate(&env), &None);
+
+    let patient = Address::generate(&env);
+    let grantee = Address::generate(&env);
+
+    assert!(client.meets_access_level(&patient, &grantee, &AccessLevel::None));
+    assert!(!client.meets_access_level(&patient, &grantee, &AccessLevel::Read));
+    assert!(!client.meets_access_level(&patient, &grantee, &AccessLevel::Full));
+}
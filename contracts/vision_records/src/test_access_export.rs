@@ -0,0 +1,216 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{
+    access_export::MAX_EXPORT_GRANTS, AccessLevel, AccessStateGap, ConsentType, ContractError,
+    EmergencyContact, GrantPurpose, OptionalEmergencyContact, Role,
+};
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+const DAY: u64 = 86_400;
+
+struct RichState {
+    fx: TestContract,
+    guardian: Address,
+    requested: Address,
+}
+
+fn contact(fx: &TestContract) -> EmergencyContact {
+    EmergencyContact {
+        name: String::from_str(&fx.env, "Ada"),
+        relationship: String::from_str(&fx.env, "sibling"),
+        phone: String::from_str(&fx.env, "555-0100"),
+        email: String::from_str(&fx.env, "ada@example.com"),
+    }
+}
+
+/// A patient with a purposed grant and consent, a guardian, a pending
+/// grant, an emergency contact, a freeze and approval required.
+fn rich_state() -> RichState {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .build();
+    let (dr_a, pat_a) = (fx.provider("dr_a"), fx.patient("pat_a"));
+    let guardian = Address::generate(&fx.env);
+    let requested = Address::generate(&fx.env);
+
+    fx.client.grant_access_with_purpose(
+        &pat_a,
+        &pat_a,
+        &dr_a,
+        &AccessLevel::Write,
+        &GrantPurpose::Payment,
+        &DAY,
+    );
+    fx.client
+        .grant_consent(&pat_a, &dr_a, &ConsentType::Treatment, &DAY);
+    fx.client
+        .delegate_role(&pat_a, &guardian, &Role::Optometrist, &0);
+    fx.client.set_require_patient_approval(&pat_a, &true);
+    fx.client
+        .grant_access(&guardian, &pat_a, &requested, &AccessLevel::Read, &DAY);
+
+    let hash = String::from_str(&fx.env, "hash");
+    fx.client
+        .create_profile(&pat_a, &pat_a, &hash, &hash, &hash);
+    fx.client
+        .update_emergency_contact(&pat_a, &pat_a, &Some(contact(&fx)));
+    fx.client.freeze_my_access(&pat_a, &true);
+
+    RichState {
+        fx,
+        guardian,
+        requested,
+    }
+}
+
+#[test]
+fn test_export_captures_every_category() {
+    let state = rich_state();
+    let fx = &state.fx;
+    let (dr_a, pat_a) = (fx.provider("dr_a"), fx.patient("pat_a"));
+
+    let export = fx.client.export_access_state(&pat_a, &pat_a);
+    assert_eq!(export.patient, pat_a);
+    assert_eq!(export.exported_at, FIXTURE_START_TIME);
+
+    // The freeze does not hide the grant's stored level
+    assert_eq!(export.grants.len(), 1);
+    let grant = export.grants.get(0).unwrap();
+    assert_eq!(grant.grantee, dr_a);
+    assert_eq!(grant.level, AccessLevel::Write);
+    assert_eq!(grant.purpose, GrantPurpose::Payment);
+    assert_eq!(grant.created_by, pat_a);
+    assert_eq!(grant.expires_at, FIXTURE_START_TIME + DAY);
+
+    assert_eq!(export.consents.len(), 1);
+    let consent = export.consents.get(0).unwrap();
+    assert_eq!(consent.grantee, dr_a);
+    assert_eq!(consent.consent_type, ConsentType::Treatment);
+
+    assert_eq!(export.pending_grants.len(), 1);
+    let pending = export.pending_grants.get(0).unwrap();
+    assert_eq!(pending.grantee, state.requested);
+    assert_eq!(pending.requested_by, state.guardian);
+
+    assert_eq!(export.guardians.len(), 1);
+    assert_eq!(export.guardians.get(0).unwrap(), state.guardian);
+    assert_eq!(
+        export.emergency_contact,
+        OptionalEmergencyContact::Some(contact(fx))
+    );
+    assert!(export.frozen);
+    assert!(export.freeze_allows_emergency);
+    assert!(export.approval_required);
+    assert_eq!(export.next_grant, None);
+
+    // A guardian gets the same export
+    assert_eq!(
+        fx.client.export_access_state(&state.guardian, &pat_a),
+        fx.client.export_access_state(&pat_a, &pat_a)
+    );
+}
+
+#[test]
+fn test_export_leaves_out_lapsed_entries() {
+    let state = rich_state();
+    let fx = &state.fx;
+    let pat_a = fx.patient("pat_a");
+
+    fx.client.revoke_consent(&pat_a, &fx.provider("dr_a"));
+    fx.advance_time(2 * DAY);
+
+    let export = fx.client.export_access_state(&pat_a, &pat_a);
+    assert!(export.grants.is_empty());
+    assert!(export.consents.is_empty());
+    // The pending grant's seven-day approval window is still open
+    assert_eq!(export.pending_grants.len(), 1);
+}
+
+#[test]
+fn test_export_rejects_other_callers() {
+    let state = rich_state();
+    let fx = &state.fx;
+    let pat_a = fx.patient("pat_a");
+
+    assert_err(
+        fx.client
+            .try_export_access_state(&fx.provider("dr_a"), &pat_a),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client
+            .try_export_access_grants_page(&fx.admin(), &pat_a, &0),
+        ContractError::Unauthorized,
+    );
+}
+
+#[test]
+fn test_long_grantee_lists_continue_on_pages() {
+    let fx = Fixture::new().with_admin().with_patient("pat_a").build();
+    let pat_a = fx.patient("pat_a");
+    let total = MAX_EXPORT_GRANTS + 3;
+    for _ in 0..total {
+        let grantee = Address::generate(&fx.env);
+        fx.client
+            .grant_access(&pat_a, &pat_a, &grantee, &AccessLevel::Read, &DAY);
+    }
+
+    let export = fx.client.export_access_state(&pat_a, &pat_a);
+    assert_eq!(export.grants.len(), MAX_EXPORT_GRANTS);
+    assert_eq!(export.next_grant, Some(MAX_EXPORT_GRANTS));
+
+    let page = fx
+        .client
+        .export_access_grants_page(&pat_a, &pat_a, &MAX_EXPORT_GRANTS);
+    assert_eq!(page.grants.len(), 3);
+    assert_eq!(page.next, None);
+    for grant in page.grants.iter() {
+        assert!(!export.grants.contains(&grant));
+    }
+}
+
+#[test]
+fn test_validate_reports_entries_missing_from_new_wallet() {
+    let state = rich_state();
+    let fx = &state.fx;
+    let (dr_a, pat_a) = (fx.provider("dr_a"), fx.patient("pat_a"));
+    let export = fx.client.export_access_state(&pat_a, &pat_a);
+
+    // The old wallet matches its own export
+    assert!(fx.client.validate_access_state(&pat_a, &export).is_empty());
+
+    let new_wallet = Address::generate(&fx.env);
+    let gaps = fx.client.validate_access_state(&new_wallet, &export);
+    assert_eq!(gaps.len(), 7);
+    assert!(gaps.contains(&AccessStateGap::Grant(dr_a.clone())));
+    assert!(gaps.contains(&AccessStateGap::Consent(dr_a.clone())));
+    assert!(gaps.contains(&AccessStateGap::PendingGrant(state.requested.clone())));
+    assert!(gaps.contains(&AccessStateGap::Guardian(state.guardian.clone())));
+    assert!(gaps.contains(&AccessStateGap::EmergencyContact));
+    assert!(gaps.contains(&AccessStateGap::Freeze));
+    assert!(gaps.contains(&AccessStateGap::ApprovalRequired));
+
+    // A grant at a lower level than exported still needs re-creating
+    fx.client
+        .grant_access(&new_wallet, &new_wallet, &dr_a, &AccessLevel::Read, &DAY);
+    fx.client
+        .grant_consent(&new_wallet, &dr_a, &ConsentType::Treatment, &DAY);
+    fx.client
+        .delegate_role(&new_wallet, &state.guardian, &Role::Optometrist, &0);
+    let gaps = fx.client.validate_access_state(&new_wallet, &export);
+    assert_eq!(gaps.len(), 5);
+    assert!(gaps.contains(&AccessStateGap::Grant(dr_a.clone())));
+
+    fx.client
+        .grant_access(&new_wallet, &new_wallet, &dr_a, &AccessLevel::Write, &DAY);
+    let gaps = fx.client.validate_access_state(&new_wallet, &export);
+    assert!(!gaps.contains(&AccessStateGap::Grant(dr_a)));
+}
@@ -0,0 +1,199 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{auth, AccessLevel, AuthAction, ConsentType, ContractError, RecordType, Role};
+use crate::emergency::{self, EmergencyAccess, EmergencyCondition, EmergencyStatus};
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{Address, String, Symbol, Vec};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+const DAY: u64 = 86_400;
+
+/// `dr_a` wrote a record for `pat_a`, who has consented to and granted
+/// `staff` read access for 30 days.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider_role("staff", Role::Staff)
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .build();
+    let patient = fx.patient("pat_a");
+    let staff = fx.provider("staff");
+    fx.client
+        .grant_consent(&patient, &staff, &ConsentType::Treatment, &(30 * DAY));
+    fx.client
+        .grant_access(&patient, &patient, &staff, &AccessLevel::Read, &(30 * DAY));
+    fx
+}
+
+fn text(fx: &TestContract, value: &str) -> String {
+    String::from_str(&fx.env, value)
+}
+
+/// Stores active emergency access for `requester`; there is no endpoint
+/// that grants it.
+fn grant_emergency(fx: &TestContract, requester: &Address) {
+    let env = &fx.env;
+    env.as_contract(&fx.contract_id, || {
+        let id = emergency::increment_emergency_counter(env).unwrap();
+        emergency::set_emergency_access(
+            env,
+            &EmergencyAccess {
+                id,
+                patient: fx.patient("pat_a"),
+                requester: requester.clone(),
+                condition: EmergencyCondition::Unconscious,
+                attestation: text(fx, "unresponsive on arrival"),
+                granted_at: FIXTURE_START_TIME,
+                expires_at: FIXTURE_START_TIME + DAY,
+                status: EmergencyStatus::Active,
+                notified_contacts: Vec::new(env),
+            },
+        );
+    });
+}
+
+#[test]
+fn test_grant_denied_during_freeze_and_restored_after() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let staff = fx.provider("staff");
+    let before = fx.client.check_access_detailed(&patient, &staff).unwrap();
+    assert_eq!(before.level, AccessLevel::Read);
+
+    fx.client.freeze_my_access(&patient, &false);
+    assert!(fx.client.is_access_frozen(&patient));
+    assert_eq!(fx.client.check_access(&patient, &staff), AccessLevel::None);
+    assert_err(
+        fx.client.try_get_record(&staff, &fx.record(0)),
+        ContractError::Unauthorized,
+    );
+    let decision = fx
+        .client
+        .authorize(&staff, &AuthAction::ReadRecord(fx.record(0)));
+    assert_eq!(decision.reason, Symbol::new(&fx.env, auth::PATIENT_FROZEN));
+    // The grant itself is untouched
+    let during = fx.client.check_access_detailed(&patient, &staff).unwrap();
+    assert_eq!(during.level, AccessLevel::None);
+    assert_eq!(during.expires_at, before.expires_at);
+
+    fx.advance_time(DAY);
+    fx.client.unfreeze_my_access(&patient);
+    assert!(!fx.client.is_access_frozen(&patient));
+    assert_eq!(
+        fx.client.check_access_detailed(&patient, &staff),
+        Some(before)
+    );
+    fx.client.get_record(&staff, &fx.record(0));
+}
+
+#[test]
+fn test_freeze_covers_record_grants() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let staff = fx.provider("staff");
+    fx.client.revoke_access_immediate(&patient, &staff);
+    fx.client.grant_record_access(
+        &patient,
+        &staff,
+        &fx.record(0),
+        &AccessLevel::Read,
+        &(30 * DAY),
+    );
+    fx.client.get_record(&staff, &fx.record(0));
+
+    fx.client.freeze_my_access(&patient, &false);
+    assert_eq!(
+        fx.client.check_record_access(&fx.record(0), &staff),
+        AccessLevel::None
+    );
+    assert_err(
+        fx.client.try_get_record(&staff, &fx.record(0)),
+        ContractError::Unauthorized,
+    );
+}
+
+#[test]
+fn test_freeze_blocks_provider_writes_and_reads() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let provider = fx.provider("dr_a");
+    fx.client.freeze_my_access(&patient, &false);
+
+    assert_err(
+        fx.client.try_get_record(&provider, &fx.record(0)),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client
+            .try_update_record(&provider, &fx.record(0), &text(&fx, HASH_B)),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_add_record(
+            &provider,
+            &patient,
+            &provider,
+            &RecordType::Examination,
+            &text(&fx, HASH_B),
+        ),
+        ContractError::Unauthorized,
+    );
+
+    fx.client.unfreeze_my_access(&patient);
+    fx.client
+        .update_record(&provider, &fx.record(0), &text(&fx, HASH_B));
+}
+
+#[test]
+fn test_patient_keeps_own_access() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    fx.client.freeze_my_access(&patient, &false);
+    assert_eq!(
+        fx.client.get_record(&patient, &fx.record(0)).data_hash,
+        text(&fx, HASH_A)
+    );
+}
+
+#[test]
+fn test_emergency_access_per_flag() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let provider = fx.provider("dr_a");
+    grant_emergency(&fx, &provider);
+
+    fx.client.freeze_my_access(&patient, &false);
+    assert_err(
+        fx.client
+            .try_update_record(&provider, &fx.record(0), &text(&fx, HASH_B)),
+        ContractError::Unauthorized,
+    );
+
+    // Freezing again replaces the emergency choice
+    fx.client.freeze_my_access(&patient, &true);
+    fx.client.get_record(&provider, &fx.record(0));
+    fx.client
+        .update_record(&provider, &fx.record(0), &text(&fx, HASH_B));
+    assert_err(
+        fx.client
+            .try_get_record(&fx.provider("staff"), &fx.record(0)),
+        ContractError::Unauthorized,
+    );
+}
+
+#[test]
+fn test_unfreeze_without_freeze_is_noop() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    fx.client.unfreeze_my_access(&patient);
+    assert!(!fx.client.is_access_frozen(&patient));
+    fx.client.get_record(&fx.provider("staff"), &fx.record(0));
+}
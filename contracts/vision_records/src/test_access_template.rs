@@ -0,0 +1,225 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{
+    AccessGrant, AccessLevel, AccessTemplateEntry, ContractError, VisionRecordsContract,
+    VisionRecordsContractClient,
+};
+use soroban_sdk::{
+    symbol_short, testutils::Address as _, testutils::Ledger, vec, Address, Env, Vec,
+};
+
+const YEAR: u64 = 31_536_000;
+const NINETY_DAYS: u64 = 7_776_000;
+
+fn setup() -> (Env, VisionRecordsContractClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register(VisionRecordsContract, ());
+    let client = VisionRecordsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &None);
+
+    (env, client, admin)
+}
+
+fn entry(level: AccessLevel, duration_seconds: u64) -> AccessTemplateEntry {
+    AccessTemplateEntry {
+        level,
+        duration_seconds,
+    }
+}
+
+fn stored_grant(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    patient: &Address,
+    grantee: &Address,
+) -> AccessGrant {
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("ACCESS"), patient.clone(), grantee.clone()))
+            .unwrap()
+    })
+}
+
+#[test]
+fn test_apply_template_zips_entries_with_grantees() {
+    let (env, client, _admin) = setup();
+    let patient = Address::generate(&env);
+    let optometrist = Address::generate(&env);
+    let family = Address::generate(&env);
+
+    let name = symbol_short!("usual");
+    client.create_access_template(
+        &patient,
+        &name,
+        &vec![
+            &env,
+            entry(AccessLevel::Read, YEAR),
+            entry(AccessLevel::Read, NINETY_DAYS),
+        ],
+    );
+    client.apply_access_template(
+        &patient,
+        &name,
+        &vec![&env, optometrist.clone(), family.clone()],
+    );
+
+    let grant = stored_grant(&env, &client, &patient, &optometrist);
+    assert_eq!(grant.level, AccessLevel::Read);
+    assert_eq!(grant.expires_at, 1_000 + YEAR);
+
+    let grant = stored_grant(&env, &client, &patient, &family);
+    assert_eq!(grant.level, AccessLevel::Read);
+    assert_eq!(grant.expires_at, 1_000 + NINETY_DAYS);
+
+    assert_eq!(client.get_grantee_patients(&family), vec![&env, patient]);
+}
+
+#[test]
+fn test_single_entry_template_applies_to_all_grantees() {
+    let (env, client, _admin) = setup();
+    let patient = Address::generate(&env);
+    let grantees = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+
+    let name = symbol_short!("clinic");
+    client.create_access_template(
+        &patient,
+        &name,
+        &vec![&env, entry(AccessLevel::Write, YEAR)],
+    );
+    client.apply_access_template(&patient, &name, &grantees);
+
+    for grantee in grantees.iter() {
+        let grant = stored_grant(&env, &client, &patient, &grantee);
+        assert_eq!(grant.level, AccessLevel::Write);
+        assert_eq!(grant.expires_at, 1_000 + YEAR);
+    }
+}
+
+#[test]
+fn test_apply_template_rejects_mismatched_grantee_count() {
+    let (env, client, _admin) = setup();
+    let patient = Address::generate(&env);
+
+    let name = symbol_short!("usual");
+    client.create_access_template(
+        &patient,
+        &name,
+        &vec![
+            &env,
+            entry(AccessLevel::Read, YEAR),
+            entry(AccessLevel::Read, NINETY_DAYS),
+        ],
+    );
+    let res =
+        client.try_apply_access_template(&patient, &name, &vec![&env, Address::generate(&env)]);
+    assert_eq!(res, Err(Ok(ContractError::InvalidInput)));
+}
+
+#[test]
+fn test_template_entries_are_bounded_and_validated() {
+    let (env, client, _admin) = setup();
+    let patient = Address::generate(&env);
+
+    let mut entries = Vec::new(&env);
+    for _ in 0..6 {
+        entries.push_back(entry(AccessLevel::Read, YEAR));
+    }
+    let res = client.try_create_access_template(&patient, &symbol_short!("big"), &entries);
+    assert_eq!(res, Err(Ok(ContractError::InvalidInput)));
+
+    let res = client.try_create_access_template(
+        &patient,
+        &symbol_short!("none"),
+        &vec![&env, entry(AccessLevel::None, YEAR)],
+    );
+    assert_eq!(res, Err(Ok(ContractError::InvalidInput)));
+
+    let res = client.try_create_access_template(
+        &patient,
+        &symbol_short!("short"),
+        &vec![&env, entry(AccessLevel::Read, 60)],
+    );
+    assert_eq!(res, Err(Ok(ContractError::InvalidInput)));
+    assert_eq!(client.get_templates(&patient).len(), 0);
+}
+
+#[test]
+fn test_admin_templates_are_shared() {
+    let (env, client, admin) = setup();
+    let patient = Address::generate(&env);
+    let grantee = Address::generate(&env);
+
+    let name = symbol_short!("standard");
+    client.create_access_template(&admin, &name, &vec![&env, entry(AccessLevel::Read, YEAR)]);
+    client.apply_access_template(&patient, &name, &vec![&env, grantee.clone()]);
+    assert_eq!(
+        stored_grant(&env, &client, &patient, &grantee).level,
+        AccessLevel::Read
+    );
+
+    // A patient's own template takes precedence over the shared one.
+    client.create_access_template(&patient, &name, &vec![&env, entry(AccessLevel::Full, YEAR)]);
+    client.apply_access_template(&patient, &name, &vec![&env, grantee.clone()]);
+    assert_eq!(
+        stored_grant(&env, &client, &patient, &grantee).level,
+        AccessLevel::Full
+    );
+}
+
+#[test]
+fn test_list_and_delete_templates() {
+    let (env, client, _admin) = setup();
+    let patient = Address::generate(&env);
+
+    client.create_access_template(
+        &patient,
+        &symbol_short!("a"),
+        &vec![&env, entry(AccessLevel::Read, YEAR)],
+    );
+    client.create_access_template(
+        &patient,
+        &symbol_short!("b"),
+        &vec![&env, entry(AccessLevel::Write, YEAR)],
+    );
+    // Replacing a template does not duplicate it in the listing.
+    client.create_access_template(
+        &patient,
+        &symbol_short!("a"),
+        &vec![&env, entry(AccessLevel::Full, YEAR)],
+    );
+
+    let templates = client.get_templates(&patient);
+    assert_eq!(templates.len(), 2);
+    assert_eq!(
+        templates.get(0).unwrap().entries,
+        vec![&env, entry(AccessLevel::Full, YEAR)]
+    );
+
+    client.delete_access_template(&patient, &symbol_short!("a"));
+    assert_eq!(client.get_templates(&patient).len(), 1);
+
+    let res = client.try_apply_access_template(
+        &patient,
+        &symbol_short!("a"),
+        &vec![&env, Address::generate(&env)],
+    );
+    assert_eq!(res, Err(Ok(ContractError::TemplateNotFound)));
+    let res = client.try_delete_access_template(&patient, &symbol_short!("a"));
+    assert_eq!(res, Err(Ok(ContractError::TemplateNotFound)));
+}
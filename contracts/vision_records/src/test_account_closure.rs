@@ -0,0 +1,119 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{activity, AccessLevel, ContractError, Role};
+use crate::rbac;
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{testutils::Address as _, Address};
+
+const PATIENTS: [&str; 3] = ["pat_a", "pat_b", "pat_c"];
+
+/// `dr_x` holds grants from three patients, a role delegation from
+/// `dr_a`, and has delegated its own role to a deputy.
+fn setup() -> (TestContract, Address) {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_provider("dr_x")
+        .with_patient("pat_a")
+        .with_patient("pat_b")
+        .with_patient("pat_c")
+        .build();
+    let dr_x = fx.provider("dr_x");
+    for name in PATIENTS {
+        let patient = fx.patient(name);
+        fx.client
+            .grant_access(&patient, &patient, &dr_x, &AccessLevel::Read, &3_600);
+    }
+    fx.client
+        .delegate_role(&fx.provider("dr_a"), &dr_x, &Role::Optometrist, &0);
+    let deputy = Address::generate(&fx.env);
+    fx.client
+        .delegate_role(&dr_x, &deputy, &Role::Optometrist, &0);
+    (fx, deputy)
+}
+
+fn delegation_count(fx: &TestContract, user: &Address) -> (u32, u32) {
+    fx.env.as_contract(&fx.contract_id, || {
+        (
+            rbac::get_delegatees(&fx.env, user).len(),
+            rbac::get_delegators(&fx.env, user).len(),
+        )
+    })
+}
+
+#[test]
+fn test_closure_removes_grants_and_delegations() {
+    let (fx, deputy) = setup();
+    let (admin, dr_x) = (fx.admin(), fx.provider("dr_x"));
+    assert_eq!(fx.client.get_grantee_patients(&dr_x).len(), 3);
+    assert_eq!(delegation_count(&fx, &dr_x), (1, 1));
+
+    let first = fx.client.close_account(&admin, &dr_x, &3);
+    assert_eq!(first.delegations_removed, 2);
+    assert_eq!(first.grants_removed, 1);
+    assert_eq!(first.remaining, 2);
+    assert_eq!(fx.client.get_cleanup_progress(&admin, &dr_x), first);
+    assert!(!fx.client.get_user(&dr_x).is_active);
+
+    let done = fx.client.close_account(&admin, &dr_x, &10);
+    assert_eq!(done.delegations_removed, 2);
+    assert_eq!(done.grants_removed, 3);
+    assert_eq!(done.remaining, 0);
+    assert_eq!(done.closed_at, first.closed_at);
+
+    assert!(fx.client.get_grantee_patients(&dr_x).is_empty());
+    assert_eq!(delegation_count(&fx, &dr_x), (0, 0));
+    assert_eq!(delegation_count(&fx, &deputy), (0, 0));
+    for name in PATIENTS {
+        let patient = fx.patient(name);
+        assert_eq!(fx.client.check_access(&patient, &dr_x), AccessLevel::None);
+        let feed = fx.client.get_activity_feed(&patient, &patient, &0, &10);
+        let tombstone = feed.last().unwrap();
+        assert_eq!(tombstone.action, activity::GRANTEE_CLOSED);
+        assert_eq!(tombstone.actor, admin);
+    }
+}
+
+#[test]
+fn test_closed_account_stays_closed() {
+    let (fx, _) = setup();
+    let (admin, dr_x) = (fx.admin(), fx.provider("dr_x"));
+    fx.client.close_account(&admin, &dr_x, &10);
+
+    assert_err(
+        fx.client.try_reactivate_user(&admin, &dr_x),
+        ContractError::AccountClosed,
+    );
+    let patient = fx.patient("pat_a");
+    assert_err(
+        fx.client
+            .try_grant_access(&patient, &patient, &dr_x, &AccessLevel::Read, &3_600),
+        ContractError::InvalidInput,
+    );
+    // Closing again is a no-op that reports the finished cleanup
+    assert_eq!(fx.client.close_account(&admin, &dr_x, &1).remaining, 0);
+}
+
+#[test]
+fn test_closure_needs_admin() {
+    let (fx, _) = setup();
+    let dr_x = fx.provider("dr_x");
+    assert_err(
+        fx.client
+            .try_close_account(&fx.provider("dr_a"), &dr_x, &10),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_get_cleanup_progress(&fx.admin(), &dr_x),
+        ContractError::UserNotFound,
+    );
+    assert_err(
+        fx.client.try_close_account(&fx.admin(), &dr_x, &0),
+        ContractError::InvalidInput,
+    );
+}
@@ -0,0 +1,171 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+extern crate std;
+
+use super::{
+    activity, AccessLevel, ContractError, RecordType, Role, VisionRecordsContract,
+    VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger, Address, Env, String};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+
+fn setup() -> (
+    Env,
+    VisionRecordsContractClient<'static>,
+    Address,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register(VisionRecordsContract, ());
+    let client = VisionRecordsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &None);
+
+    let provider = Address::generate(&env);
+    client.register_user(
+        &admin,
+        &provider,
+        &Role::Optometrist,
+        &String::from_str(&env, "Dr. Feed"),
+    );
+    let patient = Address::generate(&env);
+
+    (env, client, admin, provider, patient)
+}
+
+fn add_record(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    provider: &Address,
+    patient: &Address,
+) -> u64 {
+    client.add_record(
+        provider,
+        patient,
+        provider,
+        &RecordType::Examination,
+        &String::from_str(env, HASH_A),
+    )
+}
+
+#[test]
+fn test_feed_records_adds_updates_and_grants() {
+    let (env, client, _admin, provider, patient) = setup();
+    let grantee = Address::generate(&env);
+
+    let record_id = add_record(&env, &client, &provider, &patient);
+    env.ledger().set_timestamp(1_010);
+    client.update_record(&provider, &record_id, &String::from_str(&env, HASH_B));
+    client.grant_access(&patient, &patient, &grantee, &AccessLevel::Read, &3_600);
+    client.revoke_access(&patient, &grantee);
+
+    let feed = client.get_activity_feed(&patient, &patient, &0, &10);
+    assert_eq!(feed.len(), 4);
+
+    let added = feed.get(0).unwrap();
+    assert_eq!(added.action, activity::RECORD_ADDED);
+    assert_eq!(added.actor, provider);
+    assert_eq!(added.subject_id, record_id);
+    assert_eq!(added.timestamp, 1_000);
+
+    let updated = feed.get(1).unwrap();
+    assert_eq!(updated.action, activity::RECORD_UPDATED);
+    assert_eq!(updated.subject_id, record_id);
+    assert_eq!(updated.timestamp, 1_010);
+
+    assert_eq!(feed.get(2).unwrap().action, activity::ACCESS_GRANTED);
+    assert_eq!(feed.get(2).unwrap().actor, patient);
+    assert_eq!(feed.get(3).unwrap().action, activity::ACCESS_REVOKED);
+}
+
+#[test]
+fn test_feed_evicts_oldest_entries_in_order() {
+    let (env, client, _admin, provider, patient) = setup();
+
+    let overflow = 5;
+    let mut ids = std::vec::Vec::new();
+    for _ in 0..activity::MAX_ACTIVITY_ENTRIES + overflow {
+        ids.push(add_record(&env, &client, &provider, &patient));
+    }
+
+    let mut feed = client.get_activity_feed(&patient, &patient, &0, &1_000);
+    assert_eq!(feed.len(), activity::MAX_FEED_PAGE);
+    feed.append(&client.get_activity_feed(
+        &patient,
+        &patient,
+        &activity::MAX_FEED_PAGE,
+        &activity::MAX_FEED_PAGE,
+    ));
+    assert_eq!(feed.len(), activity::MAX_ACTIVITY_ENTRIES);
+
+    // The first `overflow` records were evicted; the rest remain in order.
+    for (i, entry) in feed.iter().enumerate() {
+        assert_eq!(entry.subject_id, ids[i + overflow as usize]);
+    }
+
+    // One more entry evicts the next-oldest.
+    ids.push(add_record(&env, &client, &provider, &patient));
+    let feed = client.get_activity_feed(&patient, &patient, &0, &1);
+    assert_eq!(feed.get(0).unwrap().subject_id, ids[overflow as usize + 1]);
+}
+
+#[test]
+fn test_feed_pagination() {
+    let (env, client, _admin, provider, patient) = setup();
+
+    let mut ids = std::vec::Vec::new();
+    for _ in 0..5 {
+        ids.push(add_record(&env, &client, &provider, &patient));
+    }
+
+    let page = client.get_activity_feed(&patient, &patient, &1, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().subject_id, ids[1]);
+    assert_eq!(page.get(1).unwrap().subject_id, ids[2]);
+
+    let tail = client.get_activity_feed(&patient, &patient, &4, &10);
+    assert_eq!(tail.len(), 1);
+    assert_eq!(tail.get(0).unwrap().subject_id, ids[4]);
+
+    assert!(client
+        .get_activity_feed(&patient, &patient, &10, &10)
+        .is_empty());
+
+    let res = client.try_get_activity_feed(&patient, &patient, &0, &0);
+    assert_eq!(res, Err(Ok(ContractError::InvalidInput)));
+}
+
+#[test]
+fn test_feed_access_control() {
+    let (env, client, admin, provider, patient) = setup();
+    add_record(&env, &client, &provider, &patient);
+
+    let guardian = Address::generate(&env);
+    client.delegate_role(&patient, &guardian, &Role::Optometrist, &0);
+    let outsider = Address::generate(&env);
+
+    assert_eq!(
+        client.get_activity_feed(&guardian, &patient, &0, &10).len(),
+        1
+    );
+    assert_eq!(client.get_activity_feed(&admin, &patient, &0, &10).len(), 1);
+
+    // A provider who wrote to the record is not a guardian.
+    let res = client.try_get_activity_feed(&provider, &patient, &0, &10);
+    assert_eq!(res, Err(Ok(ContractError::Unauthorized)));
+
+    let res = client.try_get_activity_feed(&outsider, &patient, &0, &10);
+    assert_eq!(res, Err(Ok(ContractError::Unauthorized)));
+}
@@ -0,0 +1,109 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessLevel, ConsentType, ContractError, RecordType};
+use crate::audit::{self, AccessAction, AccessResult};
+use crate::events::topics;
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::String;
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const REASON: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_provider("dr_b")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .build()
+}
+
+fn reason(fx: &TestContract) -> String {
+    String::from_str(&fx.env, REASON)
+}
+
+#[test]
+fn test_plain_read_rejects_admin_without_other_rights() {
+    let fx = setup();
+    assert_err(
+        fx.client.try_get_record(&fx.admin(), &fx.record(0)),
+        ContractError::AdminReasonRequired,
+    );
+
+    // A grant from the patient lets the admin in like anyone else
+    let patient = fx.patient("pat_a");
+    fx.client
+        .grant_consent(&patient, &fx.admin(), &ConsentType::Treatment, &3_600);
+    fx.client
+        .grant_access(&patient, &patient, &fx.admin(), &AccessLevel::Read, &3_600);
+    assert_eq!(
+        fx.client.get_record(&fx.admin(), &fx.record(0)).id,
+        fx.record(0)
+    );
+}
+
+#[test]
+fn test_plain_read_unchanged_for_other_roles() {
+    let fx = setup();
+    // Providers' roles still carry ReadAnyRecord
+    assert_eq!(
+        fx.client.get_record(&fx.provider("dr_b"), &fx.record(0)).id,
+        fx.record(0)
+    );
+}
+
+#[test]
+fn test_reasoned_read_is_logged() {
+    let fx = setup();
+    let admin = fx.admin();
+    let patient = fx.patient("pat_a");
+    let record_id = fx.record(0);
+
+    let record = fx
+        .client
+        .read_record_as_admin(&admin, &record_id, &reason(&fx));
+    assert_eq!(record.id, record_id);
+    assert_eq!(record.data_hash, String::from_str(&fx.env, HASH_A));
+
+    assert!(fx.published((topics::ADM_READ, patient.clone(), admin.clone())));
+
+    let entry = fx
+        .env
+        .as_contract(&fx.contract_id, || {
+            audit::get_patient_audit_log(&fx.env, &patient)
+        })
+        .last()
+        .unwrap();
+    assert_eq!(entry.actor, admin);
+    assert_eq!(entry.record_id, Some(record_id));
+    assert_eq!(entry.action, AccessAction::Read);
+    assert_eq!(entry.result, AccessResult::Success);
+    assert_eq!(entry.reason, Some(reason(&fx)));
+}
+
+#[test]
+fn test_reasoned_read_requires_admin_and_reason() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    assert_err(
+        fx.client
+            .try_read_record_as_admin(&fx.admin(), &record_id, &String::from_str(&fx.env, "")),
+        ContractError::InvalidInput,
+    );
+    assert_err(
+        fx.client
+            .try_read_record_as_admin(&fx.provider("dr_b"), &record_id, &reason(&fx)),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client
+            .try_read_record_as_admin(&fx.admin(), &(record_id + 100), &reason(&fx)),
+        ContractError::RecordNotFound,
+    );
+}
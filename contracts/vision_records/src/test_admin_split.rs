@@ -0,0 +1,207 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{circuit_breaker::PauseScope, AuthAction, ContractError, Permission, RecordType, Role};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{testutils::Address as _, Address, BytesN, String};
+
+const HASH: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+
+/// `infra` and `clinic` are staff holding one admin permission each.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH)
+        .with_provider_role("infra", Role::Staff)
+        .with_provider_role("clinic", Role::Staff)
+        .build();
+    let admin = fx.admin();
+    fx.client
+        .grant_custom_permission(&admin, &fx.provider("infra"), &Permission::InfraAdmin);
+    fx.client
+        .grant_custom_permission(&admin, &fx.provider("clinic"), &Permission::ClinicalAdmin);
+    fx
+}
+
+fn reason(fx: &TestContract) -> String {
+    String::from_str(&fx.env, HASH)
+}
+
+#[test]
+fn test_infra_admin_cannot_roll_back() {
+    let fx = setup();
+    let infra = fx.provider("infra");
+    let record_id = fx.record(0);
+
+    assert!(
+        !fx.client
+            .authorize(&infra, &AuthAction::Rollback(record_id))
+            .allowed
+    );
+    assert_err(
+        fx.client
+            .try_read_record_as_admin(&infra, &record_id, &reason(&fx)),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_freeze_user(&infra, &fx.patient("pat_a")),
+        ContractError::Unauthorized,
+    );
+
+    fx.client.pause_contract(&infra, &PauseScope::Global);
+    fx.client.resume_contract(&infra, &PauseScope::Global);
+}
+
+#[test]
+fn test_clinical_admin_cannot_upgrade() {
+    let fx = setup();
+    let clinic = fx.provider("clinic");
+    let record_id = fx.record(0);
+
+    assert!(
+        fx.client
+            .authorize(&clinic, &AuthAction::Rollback(record_id))
+            .allowed
+    );
+    fx.client
+        .read_record_as_admin(&clinic, &record_id, &reason(&fx));
+
+    assert_err(
+        fx.client
+            .try_upgrade(&clinic, &BytesN::from_array(&fx.env, &[0; 32])),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_pause_contract(&clinic, &PauseScope::Global),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_set_legacy_system_admin(&clinic, &true),
+        ContractError::Unauthorized,
+    );
+}
+
+#[test]
+fn test_admin_permissions_only_come_from_holders() {
+    let fx = setup();
+    let dr_a = fx.provider("dr_a");
+
+    // ManageUsers alone does not let a provider promote itself
+    assert_err(
+        fx.client
+            .try_grant_custom_permission(&dr_a, &dr_a, &Permission::InfraAdmin),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_grant_custom_permission(
+            &fx.provider("infra"),
+            &dr_a,
+            &Permission::ClinicalAdmin,
+        ),
+        ContractError::Unauthorized,
+    );
+    assert!(!fx.client.check_permission(&dr_a, &Permission::InfraAdmin));
+}
+
+#[test]
+fn test_transfer_and_legacy_flag() {
+    let fx = setup();
+    let (admin, dr_a) = (fx.admin(), fx.provider("dr_a"));
+    let record_id = fx.record(0);
+
+    fx.client
+        .transfer_admin_permission(&admin, &dr_a, &Permission::ClinicalAdmin);
+    assert!(fx
+        .client
+        .check_permission(&dr_a, &Permission::ClinicalAdmin));
+    assert!(!fx
+        .client
+        .check_permission(&admin, &Permission::ClinicalAdmin));
+
+    // SystemAdmin still covers the admin until the flag goes off
+    assert!(fx.client.is_legacy_system_admin_enabled());
+    fx.client
+        .read_record_as_admin(&admin, &record_id, &reason(&fx));
+
+    fx.client.set_legacy_system_admin(&admin, &false);
+    assert!(!fx.client.is_legacy_system_admin_enabled());
+    assert_err(
+        fx.client
+            .try_read_record_as_admin(&admin, &record_id, &reason(&fx)),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_freeze_user(&admin, &fx.patient("pat_a")),
+        ContractError::Unauthorized,
+    );
+    fx.client
+        .read_record_as_admin(&dr_a, &record_id, &reason(&fx));
+
+    let stranger = Address::generate(&fx.env);
+    assert_err(
+        fx.client
+            .try_transfer_admin_permission(&dr_a, &stranger, &Permission::ClinicalAdmin),
+        ContractError::UserNotFound,
+    );
+    assert_err(
+        fx.client
+            .try_transfer_admin_permission(&dr_a, &admin, &Permission::SystemAdmin),
+        ContractError::InvalidInput,
+    );
+
+    // The admin keeps its explicit InfraAdmin
+    fx.client.pause_contract(&admin, &PauseScope::Global);
+}
+
+#[test]
+fn test_initialize_with_separate_clinical_admin() {
+    let fx = Fixture::new().build();
+    let (infra, clinic) = (Address::generate(&fx.env), Address::generate(&fx.env));
+    fx.client.initialize(&infra, &Some(clinic.clone()));
+    assert!(!fx.client.is_legacy_system_admin_enabled());
+
+    let name = |name: &str| String::from_str(&fx.env, name);
+    let (dr_a, pat_a) = (Address::generate(&fx.env), Address::generate(&fx.env));
+    fx.client
+        .register_user(&infra, &dr_a, &Role::Optometrist, &name("dr_a"));
+    fx.client
+        .register_user(&clinic, &pat_a, &Role::Patient, &name("pat_a"));
+    let record_id =
+        fx.client
+            .add_record(&dr_a, &pat_a, &dr_a, &RecordType::Examination, &name(HASH));
+
+    assert!(
+        !fx.client
+            .authorize(&infra, &AuthAction::Rollback(record_id))
+            .allowed
+    );
+    assert!(
+        !fx.client
+            .authorize(&infra, &AuthAction::ReadRecord(record_id))
+            .allowed
+    );
+    assert_err(
+        fx.client.try_freeze_user(&infra, &pat_a),
+        ContractError::Unauthorized,
+    );
+    fx.client.pause_contract(&infra, &PauseScope::Global);
+    fx.client.resume_contract(&infra, &PauseScope::Global);
+
+    assert!(
+        fx.client
+            .authorize(&clinic, &AuthAction::Rollback(record_id))
+            .allowed
+    );
+    fx.client.freeze_user(&clinic, &pat_a);
+    assert_err(
+        fx.client
+            .try_upgrade(&clinic, &BytesN::from_array(&fx.env, &[0; 32])),
+        ContractError::Unauthorized,
+    );
+}
@@ -0,0 +1,138 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{
+    approval, AccessLevel, ContractError, Role, VisionRecordsContract, VisionRecordsContractClient,
+};
+use soroban_sdk::{symbol_short, testutils::Address as _, testutils::Ledger, Address, Env};
+
+fn setup() -> (Env, VisionRecordsContractClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register(VisionRecordsContract, ());
+    let client = VisionRecordsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &None);
+
+    (env, client, admin)
+}
+
+/// Creates a patient with a delegate holding ManageAccess on their behalf.
+fn patient_with_delegate(env: &Env, client: &VisionRecordsContractClient) -> (Address, Address) {
+    let patient = Address::generate(env);
+    let delegate = Address::generate(env);
+    client.delegate_role(&patient, &delegate, &Role::Optometrist, &0);
+    (patient, delegate)
+}
+
+fn has_access_grant(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    patient: &Address,
+    grantee: &Address,
+) -> bool {
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .has(&(symbol_short!("ACCESS"), patient.clone(), grantee.clone()))
+    })
+}
+
+#[test]
+fn test_delegate_grant_is_active_without_policy() {
+    let (env, client, _admin) = setup();
+    let (patient, delegate) = patient_with_delegate(&env, &client);
+    let grantee = Address::generate(&env);
+
+    assert!(!client.requires_patient_approval(&patient));
+    client.grant_access(&delegate, &patient, &grantee, &AccessLevel::Read, &86400);
+
+    assert!(has_access_grant(&env, &client, &patient, &grantee));
+    assert!(client.get_pending_grant(&patient, &grantee).is_none());
+}
+
+#[test]
+fn test_delegate_grant_is_pending_with_policy() {
+    let (env, client, _admin) = setup();
+    let (patient, delegate) = patient_with_delegate(&env, &client);
+    let grantee = Address::generate(&env);
+
+    client.set_require_patient_approval(&patient, &true);
+    client.grant_access(&delegate, &patient, &grantee, &AccessLevel::Write, &86400);
+
+    assert!(!has_access_grant(&env, &client, &patient, &grantee));
+    assert_eq!(client.check_access(&patient, &grantee), AccessLevel::None);
+
+    let pending = client.get_pending_grant(&patient, &grantee).unwrap();
+    assert_eq!(pending.level, AccessLevel::Write);
+    assert_eq!(pending.requested_by, delegate);
+    assert_eq!(
+        pending.expires_at,
+        1_000 + approval::PENDING_GRANT_WINDOW_SECONDS
+    );
+
+    env.ledger().set_timestamp(5_000);
+    client.approve_grant(&patient, &grantee);
+
+    assert!(has_access_grant(&env, &client, &patient, &grantee));
+    assert!(client.get_pending_grant(&patient, &grantee).is_none());
+    assert_eq!(client.get_grantee_patients(&grantee).len(), 1);
+}
+
+#[test]
+fn test_patient_own_grant_skips_approval() {
+    let (env, client, _admin) = setup();
+    let patient = Address::generate(&env);
+    let grantee = Address::generate(&env);
+
+    client.set_require_patient_approval(&patient, &true);
+    client.grant_access(&patient, &patient, &grantee, &AccessLevel::Read, &86400);
+
+    assert!(has_access_grant(&env, &client, &patient, &grantee));
+}
+
+#[test]
+fn test_system_admin_force_grants_despite_policy() {
+    let (env, client, admin) = setup();
+    let patient = Address::generate(&env);
+    let grantee = Address::generate(&env);
+
+    client.set_require_patient_approval(&patient, &true);
+    client.grant_access(&admin, &patient, &grantee, &AccessLevel::Read, &86400);
+
+    assert!(has_access_grant(&env, &client, &patient, &grantee));
+    assert!(client.get_pending_grant(&patient, &grantee).is_none());
+}
+
+#[test]
+fn test_unapproved_pending_grant_expires() {
+    let (env, client, _admin) = setup();
+    let (patient, delegate) = patient_with_delegate(&env, &client);
+    let grantee = Address::generate(&env);
+
+    client.set_require_patient_approval(&patient, &true);
+    client.grant_access(&delegate, &patient, &grantee, &AccessLevel::Read, &86400);
+
+    env.ledger()
+        .set_timestamp(1_000 + approval::PENDING_GRANT_WINDOW_SECONDS);
+    let res = client.try_approve_grant(&patient, &grantee);
+    assert_eq!(res, Err(Ok(ContractError::ExpiredAccess)));
+    assert!(!has_access_grant(&env, &client, &patient, &grantee));
+}
+
+#[test]
+fn test_approve_without_pending_grant_fails() {
+    let (env, client, _admin) = setup();
+    let patient = Address::generate(&env);
+    let grantee = Address::generate(&env);
+
+    let res = client.try_approve_grant(&patient, &grantee);
+    assert_eq!(res, Err(Ok(ContractError::PendingGrantNotFound)));
+}
@@ -0,0 +1,129 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AtomicUpdateResult, ContractError, RecordType};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{vec, String, Vec};
+
+const HASH: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const FIXED: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+
+/// `dr_a` wrote an examination and the prescription derived from it;
+/// `dr_b` wrote a diagnosis. All belong to `pat_a`.
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH)
+        .with_record(RecordType::Prescription, HASH)
+        .with_provider("dr_b")
+        .with_record(RecordType::Diagnosis, HASH)
+        .build()
+}
+
+fn fixed(fx: &TestContract) -> String {
+    String::from_str(&fx.env, FIXED)
+}
+
+fn version_counts(fx: &TestContract) -> Vec<u32> {
+    vec![
+        &fx.env,
+        fx.client.get_record_version_count(&fx.record(0)),
+        fx.client.get_record_version_count(&fx.record(1)),
+        fx.client.get_record_version_count(&fx.record(2)),
+    ]
+}
+
+#[test]
+fn test_linked_records_update_together() {
+    let fx = setup();
+    let dr_a = fx.provider("dr_a");
+    let updates = vec![
+        &fx.env,
+        (fx.record(0), fixed(&fx)),
+        (fx.record(1), fixed(&fx)),
+    ];
+    assert_eq!(
+        fx.client.update_records_atomic(&dr_a, &updates),
+        AtomicUpdateResult::Applied(vec![&fx.env, 2, 2])
+    );
+    for index in 0..2 {
+        let record = fx.client.get_record(&dr_a, &fx.record(index));
+        assert_eq!(record.data_hash, fixed(&fx));
+    }
+    assert_eq!(version_counts(&fx), vec![&fx.env, 2, 2, 1]);
+}
+
+#[test]
+fn test_failing_entry_writes_nothing() {
+    let fx = setup();
+    let updates = vec![
+        &fx.env,
+        (fx.record(0), fixed(&fx)),
+        (fx.record(1), String::from_str(&fx.env, "")),
+        (fx.record(2), fixed(&fx)),
+    ];
+    assert_eq!(
+        fx.client
+            .update_records_atomic(&fx.provider("dr_a"), &updates),
+        AtomicUpdateResult::Rejected(1, ContractError::InvalidInput as u32)
+    );
+    assert_eq!(version_counts(&fx), vec![&fx.env, 1, 1, 1]);
+}
+
+#[test]
+fn test_permission_checked_per_record() {
+    let fx = setup();
+    // dr_a may write its own records but not dr_b's diagnosis
+    let updates = vec![
+        &fx.env,
+        (fx.record(0), fixed(&fx)),
+        (fx.record(2), fixed(&fx)),
+    ];
+    assert_eq!(
+        fx.client
+            .update_records_atomic(&fx.provider("dr_a"), &updates),
+        AtomicUpdateResult::Rejected(1, ContractError::Unauthorized as u32)
+    );
+    assert_eq!(version_counts(&fx), vec![&fx.env, 1, 1, 1]);
+
+    // A clinical admin may write both
+    assert_eq!(
+        fx.client.update_records_atomic(&fx.admin(), &updates),
+        AtomicUpdateResult::Applied(vec![&fx.env, 2, 2])
+    );
+}
+
+#[test]
+fn test_atomic_update_limits() {
+    let fx = setup();
+    let dr_a = fx.provider("dr_a");
+    assert_err(
+        fx.client
+            .try_update_records_atomic(&dr_a, &Vec::new(&fx.env)),
+        ContractError::InvalidInput,
+    );
+    let mut too_many = Vec::new(&fx.env);
+    for _ in 0..6 {
+        too_many.push_back((fx.record(0), fixed(&fx)));
+    }
+    assert_err(
+        fx.client.try_update_records_atomic(&dr_a, &too_many),
+        ContractError::InvalidInput,
+    );
+    // The same record twice is refused at its second entry
+    let repeated = vec![
+        &fx.env,
+        (fx.record(0), fixed(&fx)),
+        (fx.record(0), fixed(&fx)),
+    ];
+    assert_eq!(
+        fx.client.update_records_atomic(&dr_a, &repeated),
+        AtomicUpdateResult::Rejected(1, ContractError::InvalidInput as u32)
+    );
+}
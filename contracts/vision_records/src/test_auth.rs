@@ -0,0 +1,258 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{
+    AccessLevel, AuthAction, AuthDecision, ConsentType, ContractError, Permission, RecordType, Role,
+};
+use crate::auth;
+use crate::testutils::{assert_err, Fixture, TestContract};
+use core::fmt::Debug;
+use soroban_sdk::{testutils::Address as _, Address, String, Symbol, Vec};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider_role("staff", Role::Staff)
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .build()
+}
+
+fn text(fx: &TestContract, value: &str) -> String {
+    String::from_str(&fx.env, value)
+}
+
+/// Asserts that `authorize` allows exactly when the endpoint succeeds, and
+/// that it reports `reason`.
+fn assert_parity<T: Debug, E: Debug>(
+    fx: &TestContract,
+    decision: AuthDecision,
+    result: Result<T, E>,
+    reason: &str,
+) {
+    assert_eq!(
+        decision.allowed,
+        result.is_ok(),
+        "decision {:?} disagrees with endpoint {:?}",
+        decision,
+        result
+    );
+    assert_eq!(decision.reason, Symbol::new(&fx.env, reason));
+}
+
+fn check_read(fx: &TestContract, caller: &Address, record_id: u64, reason: &str) {
+    let decision = fx
+        .client
+        .authorize(caller, &AuthAction::ReadRecord(record_id));
+    assert_eq!(
+        fx.client
+            .authorize(caller, &AuthAction::ReadHistory(record_id)),
+        decision
+    );
+    assert_parity(
+        fx,
+        decision,
+        fx.client.try_get_record(caller, &record_id),
+        reason,
+    );
+}
+
+fn check_write(fx: &TestContract, caller: &Address, record_id: u64, reason: &str) {
+    let decision = fx
+        .client
+        .authorize(caller, &AuthAction::WriteRecord(record_id));
+    assert_eq!(
+        fx.client
+            .authorize(caller, &AuthAction::Rollback(record_id)),
+        decision
+    );
+    assert_parity(
+        fx,
+        decision,
+        fx.client
+            .try_update_record(caller, &record_id, &text(fx, HASH_B)),
+        reason,
+    );
+}
+
+fn check_grant(fx: &TestContract, caller: &Address, patient: &Address, reason: &str) {
+    let decision = fx
+        .client
+        .authorize(caller, &AuthAction::GrantAccess(patient.clone()));
+    assert_parity(
+        fx,
+        decision,
+        fx.client.try_grant_access(
+            caller,
+            patient,
+            &Address::generate(&fx.env),
+            &AccessLevel::Read,
+            &3_600,
+        ),
+        reason,
+    );
+}
+
+fn check_manage(fx: &TestContract, caller: &Address, user: &Address, reason: &str) {
+    let decision = fx
+        .client
+        .authorize(caller, &AuthAction::ManageUser(user.clone()));
+    assert_parity(
+        fx,
+        decision,
+        fx.client.try_create_profile(
+            caller,
+            user,
+            &text(fx, "hash_dob"),
+            &text(fx, "hash_gender"),
+            &text(fx, "hash_blood"),
+        ),
+        reason,
+    );
+}
+
+#[test]
+fn test_read_parity() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    let patient = fx.patient("pat_a");
+
+    check_read(&fx, &patient, record_id, auth::PATIENT_SELF);
+    check_read(&fx, &fx.provider("dr_a"), record_id, auth::PROVIDER_SELF);
+    check_read(&fx, &fx.admin(), record_id, auth::ADMIN_REASON_REQUIRED);
+    check_read(&fx, &Address::generate(&fx.env), record_id, auth::NO_ACCESS);
+
+    let consenting = Address::generate(&fx.env);
+    fx.client
+        .grant_consent(&patient, &consenting, &ConsentType::Treatment, &3_600);
+    check_read(&fx, &consenting, record_id, auth::CONSENT);
+
+    let granted = Address::generate(&fx.env);
+    fx.client
+        .grant_consent(&patient, &granted, &ConsentType::Treatment, &3_600);
+    fx.client
+        .grant_access(&patient, &patient, &granted, &AccessLevel::Read, &3_600);
+    check_read(&fx, &granted, record_id, auth::GRANT_READ);
+
+    let record_only = Address::generate(&fx.env);
+    fx.client.grant_record_access(
+        &patient,
+        &record_only,
+        &record_id,
+        &AccessLevel::Read,
+        &3_600,
+    );
+    check_read(&fx, &record_only, record_id, auth::RECORD_GRANT);
+
+    fx.client.freeze_user(&fx.admin(), &patient);
+    check_read(&fx, &patient, record_id, auth::FROZEN);
+}
+
+#[test]
+fn test_read_and_write_unknown_record() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    assert_eq!(
+        fx.client.authorize(&patient, &AuthAction::ReadRecord(999)),
+        AuthDecision {
+            allowed: false,
+            reason: Symbol::new(&fx.env, auth::NOT_FOUND),
+        }
+    );
+    assert_err(
+        fx.client.try_get_record(&patient, &999),
+        ContractError::RecordNotFound,
+    );
+    assert!(
+        !fx.client
+            .authorize(&patient, &AuthAction::Rollback(999))
+            .allowed
+    );
+}
+
+#[test]
+fn test_write_parity() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    let patient = fx.patient("pat_a");
+    let provider = fx.provider("dr_a");
+
+    check_write(&fx, &patient, record_id, auth::NO_ACCESS);
+    check_write(&fx, &provider, record_id, auth::PROVIDER_SELF);
+    check_write(&fx, &fx.admin(), record_id, auth::ADMIN);
+
+    let writer = Address::generate(&fx.env);
+    let appender = Address::generate(&fx.env);
+    for (delegate, permission) in [
+        (&writer, Permission::WriteRecord),
+        (&appender, Permission::AppendRecord),
+    ] {
+        fx.client.delegate_permissions(
+            &provider,
+            delegate,
+            &Vec::from_array(&fx.env, [permission]),
+            &0,
+        );
+    }
+    check_write(&fx, &writer, record_id, auth::DELEGATED);
+    check_write(&fx, &appender, record_id, auth::NO_ACCESS);
+
+    let clinic = Address::generate(&fx.env);
+    fx.client
+        .grant_consent(&patient, &clinic, &ConsentType::Treatment, &3_600);
+    fx.client.grant_append_access(&patient, &clinic, &3_600);
+    check_write(&fx, &clinic, record_id, auth::NO_ACCESS);
+    fx.client
+        .grant_access(&patient, &patient, &clinic, &AccessLevel::Write, &3_600);
+    check_write(&fx, &clinic, record_id, auth::GRANT_WRITE);
+
+    fx.client.freeze_user(&fx.admin(), &provider);
+    check_write(&fx, &provider, record_id, auth::FROZEN);
+}
+
+#[test]
+fn test_grant_parity() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let guardian = Address::generate(&fx.env);
+    fx.client.delegate_permissions(
+        &patient,
+        &guardian,
+        &Vec::from_array(&fx.env, [Permission::ManageAccess]),
+        &0,
+    );
+
+    check_grant(&fx, &patient, &patient, auth::PATIENT_SELF);
+    check_grant(&fx, &guardian, &patient, auth::DELEGATED);
+    check_grant(&fx, &fx.admin(), &patient, auth::ADMIN);
+    check_grant(&fx, &fx.provider("dr_a"), &patient, auth::NO_ACCESS);
+
+    fx.client.flag_breach(
+        &fx.admin(),
+        &Vec::from_array(&fx.env, [patient.clone()]),
+        &text(&fx, HASH_B),
+    );
+    check_grant(&fx, &guardian, &patient, auth::LOCKDOWN);
+    check_grant(&fx, &patient, &patient, auth::PATIENT_SELF);
+    // Callers without any right are still told only that they lack one.
+    check_grant(&fx, &fx.provider("dr_a"), &patient, auth::NO_ACCESS);
+}
+
+#[test]
+fn test_manage_user_parity() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let other = Address::generate(&fx.env);
+
+    check_manage(&fx, &patient, &other, auth::NO_ACCESS);
+    check_manage(&fx, &fx.provider("staff"), &other, auth::ROLE);
+    check_manage(&fx, &patient, &patient, auth::USER_SELF);
+}
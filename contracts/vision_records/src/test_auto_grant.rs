@@ -0,0 +1,120 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessLevel, ContractError, GrantPurpose, Role};
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("optom")
+        .with_provider_role("ophth", Role::Ophthalmologist)
+        .with_provider_role("staff", Role::Staff)
+        .with_provider_role("admin_user", Role::Admin)
+        .build()
+}
+
+fn enable(fx: &TestContract) {
+    fx.client
+        .set_auto_grant_on_registration(&fx.admin(), &Some((AccessLevel::Read, 86_400)));
+}
+
+fn register(fx: &TestContract, caller: &Address, role: Role) -> Address {
+    let user = Address::generate(&fx.env);
+    fx.client
+        .register_user(caller, &user, &role, &String::from_str(&fx.env, "New User"));
+    user
+}
+
+#[test]
+fn test_clinicians_registering_patients_get_access() {
+    let fx = setup();
+    enable(&fx);
+
+    for name in ["optom", "ophth"] {
+        let provider = fx.provider(name);
+        let patient = register(&fx, &provider, Role::Patient);
+
+        let grant = fx
+            .client
+            .check_access_detailed(&patient, &provider)
+            .unwrap();
+        assert_eq!(grant.purpose, GrantPurpose::Treatment);
+        assert_eq!(grant.granted_at, FIXTURE_START_TIME);
+        assert_eq!(grant.expires_at, FIXTURE_START_TIME + 86_400);
+        assert!(fx.client.get_grantee_patients(&provider).contains(&patient));
+
+        // Patients revoke it like any other grant.
+        fx.client.revoke_access(&patient, &provider);
+        assert_eq!(fx.client.check_access_detailed(&patient, &provider), None);
+    }
+}
+
+#[test]
+fn test_no_auto_grant_for_other_callers_or_roles() {
+    let fx = setup();
+    enable(&fx);
+
+    for caller in [fx.admin(), fx.provider("admin_user"), fx.provider("staff")] {
+        let patient = register(&fx, &caller, Role::Patient);
+        assert_eq!(fx.client.check_access_detailed(&patient, &caller), None);
+    }
+
+    // Only patients grant access on registration.
+    let optom = fx.provider("optom");
+    let colleague = register(&fx, &optom, Role::Staff);
+    assert_eq!(fx.client.check_access_detailed(&colleague, &optom), None);
+    assert_eq!(fx.client.get_grantee_patients(&optom).len(), 0);
+}
+
+#[test]
+fn test_no_auto_grant_when_disabled() {
+    let fx = setup();
+    let optom = fx.provider("optom");
+    assert_eq!(fx.client.get_auto_grant_on_registration(), None);
+
+    let patient = register(&fx, &optom, Role::Patient);
+    assert_eq!(fx.client.check_access_detailed(&patient, &optom), None);
+
+    enable(&fx);
+    fx.client.set_auto_grant_on_registration(&fx.admin(), &None);
+    let patient = register(&fx, &optom, Role::Patient);
+    assert_eq!(fx.client.check_access_detailed(&patient, &optom), None);
+}
+
+#[test]
+fn test_auto_grant_config_validation() {
+    let fx = setup();
+    enable(&fx);
+    assert_eq!(
+        fx.client.get_auto_grant_on_registration(),
+        Some((AccessLevel::Read, 86_400))
+    );
+
+    assert_err(
+        fx.client.try_set_auto_grant_on_registration(
+            &fx.provider("optom"),
+            &Some((AccessLevel::Full, 86_400)),
+        ),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client
+            .try_set_auto_grant_on_registration(&fx.admin(), &Some((AccessLevel::None, 86_400))),
+        ContractError::InvalidInput,
+    );
+    assert_err(
+        fx.client
+            .try_set_auto_grant_on_registration(&fx.admin(), &Some((AccessLevel::Read, 0))),
+        ContractError::InvalidInput,
+    );
+    assert_eq!(
+        fx.client.get_auto_grant_on_registration(),
+        Some((AccessLevel::Read, 86_400))
+    );
+}
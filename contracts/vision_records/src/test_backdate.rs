@@ -0,0 +1,197 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{ContractError, Permission, RecordOrder, RecordType};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{String, Vec};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+const YEAR: u64 = 31_536_000;
+const IMPORT_TIME: u64 = 10 * YEAR;
+
+/// `dr_a` may import historical records; `dr_b` may not. The clock is set
+/// ten years in, so there is a past to import into.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_b")
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .build();
+    fx.client.grant_custom_permission(
+        &fx.admin(),
+        &fx.provider("dr_a"),
+        &Permission::ImportRecords,
+    );
+    fx.set_time(IMPORT_TIME);
+    fx
+}
+
+fn import(fx: &TestContract, original_created_at: u64) -> u64 {
+    let provider = fx.provider("dr_a");
+    fx.client.add_record_backdated(
+        &provider,
+        &fx.patient("pat_a"),
+        &provider,
+        &RecordType::Examination,
+        &String::from_str(&fx.env, HASH_A),
+        &original_created_at,
+    )
+}
+
+fn add_live(fx: &TestContract) -> u64 {
+    let provider = fx.provider("dr_a");
+    fx.client.add_record(
+        &provider,
+        &fx.patient("pat_a"),
+        &provider,
+        &RecordType::Examination,
+        &String::from_str(&fx.env, HASH_A),
+    )
+}
+
+fn ordered(fx: &TestContract, order: RecordOrder) -> Vec<u64> {
+    let patient = fx.patient("pat_a");
+    let mut ids = Vec::new(&fx.env);
+    for record in fx
+        .client
+        .get_patient_records_ordered(&patient, &patient, &order, &0, &10)
+        .iter()
+    {
+        ids.push_back(record.id);
+    }
+    ids
+}
+
+#[test]
+fn test_backdated_record_keeps_original_date() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    let exam_date = 2 * YEAR;
+    let record_id = import(&fx, exam_date);
+
+    let record = fx.client.get_record(&provider, &record_id);
+    assert_eq!(record.created_at, exam_date);
+    assert_eq!(record.updated_at, exam_date);
+    assert_eq!(record.imported_at, Some(IMPORT_TIME));
+
+    let first = fx.client.get_record_at(&provider, &record_id, &exam_date);
+    assert_eq!(first.version, 1);
+    assert_eq!(first.modified_at, exam_date);
+    assert_err(
+        fx.client
+            .try_get_record_at(&provider, &record_id, &(exam_date - 1)),
+        ContractError::RecordNotFound,
+    );
+
+    // Live records still carry the ledger time and no import stamp.
+    let live = fx.client.get_record(&provider, &add_live(&fx));
+    assert_eq!(live.created_at, IMPORT_TIME);
+    assert_eq!(live.imported_at, None);
+}
+
+#[test]
+fn test_backdating_requires_import_permission() {
+    let fx = setup();
+    let other = fx.provider("dr_b");
+    assert_err(
+        fx.client.try_add_record_backdated(
+            &other,
+            &fx.patient("pat_a"),
+            &other,
+            &RecordType::Examination,
+            &String::from_str(&fx.env, HASH_A),
+            &YEAR,
+        ),
+        ContractError::Unauthorized,
+    );
+    // Without backdating the same provider adds records as usual.
+    fx.client.add_record(
+        &other,
+        &fx.patient("pat_a"),
+        &other,
+        &RecordType::Examination,
+        &String::from_str(&fx.env, HASH_A),
+    );
+}
+
+#[test]
+fn test_original_date_cannot_be_in_the_future() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    for original_created_at in [0, IMPORT_TIME + 1] {
+        assert_err(
+            fx.client.try_add_record_backdated(
+                &provider,
+                &fx.patient("pat_a"),
+                &provider,
+                &RecordType::Examination,
+                &String::from_str(&fx.env, HASH_A),
+                &original_created_at,
+            ),
+            ContractError::InvalidTimestamp,
+        );
+    }
+    // Backdating to the current ledger time is allowed.
+    let record_id = import(&fx, IMPORT_TIME);
+    assert_eq!(
+        fx.client.get_record(&provider, &record_id).created_at,
+        IMPORT_TIME
+    );
+}
+
+#[test]
+fn test_live_and_backdated_records_order_by_creation_date() {
+    let fx = setup();
+    let live_first = add_live(&fx);
+    let older = import(&fx, YEAR);
+    let newer = import(&fx, 5 * YEAR);
+    fx.advance_time(60);
+    let live_second = add_live(&fx);
+    let oldest = import(&fx, 1);
+
+    let by_date = Vec::from_array(&fx.env, [oldest, older, newer, live_first, live_second]);
+    assert_eq!(fx.client.get_patient_records(&fx.patient("pat_a")), by_date);
+    assert_eq!(ordered(&fx, RecordOrder::CreatedAsc), by_date);
+    assert_eq!(
+        ordered(&fx, RecordOrder::CreatedDesc),
+        Vec::from_array(&fx.env, [live_second, live_first, newer, older, oldest])
+    );
+    assert_eq!(
+        ordered(&fx, RecordOrder::UpdatedDesc),
+        Vec::from_array(&fx.env, [live_second, live_first, newer, older, oldest])
+    );
+}
+
+#[test]
+fn test_updating_a_backdated_record_dates_the_new_version_now() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    let exam_date = 3 * YEAR;
+    let record_id = import(&fx, exam_date);
+
+    fx.advance_time(60);
+    fx.client
+        .update_record(&provider, &record_id, &String::from_str(&fx.env, HASH_B));
+    let record = fx.client.get_record(&provider, &record_id);
+    assert_eq!(record.created_at, exam_date);
+    assert_eq!(record.updated_at, IMPORT_TIME + 60);
+
+    assert_eq!(
+        fx.client
+            .get_record_at(&provider, &record_id, &(IMPORT_TIME - 1))
+            .version,
+        1
+    );
+    assert_eq!(
+        fx.client
+            .get_record_at(&provider, &record_id, &(IMPORT_TIME + 60))
+            .version,
+        2
+    );
+}
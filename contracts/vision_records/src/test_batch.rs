@@ -6,9 +6,11 @@
 )]
 
 use super::{
-    AccessLevel, BatchGrantInput, BatchRecordInput, ContractError, RecordType, Role,
-    VisionRecordsContract, VisionRecordsContractClient,
+    audit, record_counter, AccessLevel, BatchGrantInput, BatchRecordInput, ContractError,
+    RecordFetchResult, RecordType, Role, VisionRecord, VisionRecordsContract,
+    VisionRecordsContractClient, MAX_GET_RECORDS,
 };
+use crate::testutils::install_default_policy;
 use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, Env, String, Vec};
 
 // ── Helpers ──────────────────────────────────────────────────────
@@ -19,9 +21,10 @@ fn setup() -> (Env, VisionRecordsContractClient<'static>, Address) {
 
     let contract_id = env.register(VisionRecordsContract, ());
     let client = VisionRecordsContractClient::new(&env, &contract_id);
+    install_default_policy(&env, &contract_id);
 
     let admin = Address::generate(&env);
-    client.initialize(&admin);
+    client.initialize(&admin, &None);
 
     (env, client, admin)
 }
@@ -53,6 +56,13 @@ fn register_patient(
     patient
 }
 
+fn found(result: Option<RecordFetchResult>) -> VisionRecord {
+    match result {
+        Some(RecordFetchResult::Found(record, _)) => record,
+        other => panic!("expected a record, got {:?}", other),
+    }
+}
+
 // ======================== Batch Record Creation ========================
 
 #[test]
@@ -70,9 +80,10 @@ fn test_batch_add_records_single() {
 
     let ids = client.add_records(&provider, &inputs);
     assert_eq!(ids.len(), 1);
-    assert_eq!(ids.get(0).unwrap(), 1);
+    let record_id = ids.get(0).unwrap();
+    assert_eq!(record_counter::split(record_id).1, 1);
 
-    let record = client.get_record(&provider, &1);
+    let record = client.get_record(&provider, &record_id);
     assert_eq!(record.patient, patient);
     assert_eq!(record.provider, provider);
     assert_eq!(record.record_type, RecordType::Examination);
@@ -105,32 +116,38 @@ fn test_batch_add_records_multiple() {
 
     let ids = client.add_records(&provider, &inputs);
     assert_eq!(ids.len(), 3);
-    assert_eq!(ids.get(0).unwrap(), 1);
-    assert_eq!(ids.get(1).unwrap(), 2);
-    assert_eq!(ids.get(2).unwrap(), 3);
+    // One provider, so one counter shard handing out consecutive IDs
+    let (id1, id2, id3) = (
+        ids.get(0).unwrap(),
+        ids.get(1).unwrap(),
+        ids.get(2).unwrap(),
+    );
+    assert_eq!(record_counter::split(id1).1, 1);
+    assert_eq!(id2, id1 + 1);
+    assert_eq!(id3, id1 + 2);
 
     // Verify records stored correctly
-    let rec1 = client.get_record(&provider, &1);
+    let rec1 = client.get_record(&provider, &id1);
     assert_eq!(rec1.patient, patient_a);
     assert_eq!(rec1.record_type, RecordType::Examination);
 
-    let rec2 = client.get_record(&provider, &2);
+    let rec2 = client.get_record(&provider, &id2);
     assert_eq!(rec2.patient, patient_b);
     assert_eq!(rec2.record_type, RecordType::Prescription);
 
-    let rec3 = client.get_record(&provider, &3);
+    let rec3 = client.get_record(&provider, &id3);
     assert_eq!(rec3.patient, patient_a);
     assert_eq!(rec3.record_type, RecordType::LabResult);
 
     // Verify patient record indices
     let a_recs = client.get_patient_records(&patient_a);
     assert_eq!(a_recs.len(), 2);
-    assert_eq!(a_recs.get(0).unwrap(), 1);
-    assert_eq!(a_recs.get(1).unwrap(), 3);
+    assert_eq!(a_recs.get(0).unwrap(), id1);
+    assert_eq!(a_recs.get(1).unwrap(), id3);
 
     let b_recs = client.get_patient_records(&patient_b);
     assert_eq!(b_recs.len(), 1);
-    assert_eq!(b_recs.get(0).unwrap(), 2);
+    assert_eq!(b_recs.get(0).unwrap(), id2);
 
     assert_eq!(client.get_record_count(), 3);
 }
@@ -188,7 +205,7 @@ fn test_batch_add_records_counter_continuity() {
     let patient = register_patient(&env, &client, &admin, "Alice");
 
     // First add a single record via add_record
-    client.add_record(
+    let first = client.add_record(
         &provider,
         &patient,
         &provider,
@@ -197,7 +214,7 @@ fn test_batch_add_records_counter_continuity() {
     );
     assert_eq!(client.get_record_count(), 1);
 
-    // Now batch-add more records — IDs should continue from 2
+    // Now batch-add more records — IDs should continue after it
     let mut inputs = Vec::new(&env);
     inputs.push_back(BatchRecordInput {
         patient: patient.clone(),
@@ -211,8 +228,8 @@ fn test_batch_add_records_counter_continuity() {
     });
 
     let ids = client.add_records(&provider, &inputs);
-    assert_eq!(ids.get(0).unwrap(), 2);
-    assert_eq!(ids.get(1).unwrap(), 3);
+    assert_eq!(ids.get(0).unwrap(), first + 1);
+    assert_eq!(ids.get(1).unwrap(), first + 2);
     assert_eq!(client.get_record_count(), 3);
 }
 
@@ -245,53 +262,115 @@ fn test_batch_get_records() {
 
     // Retrieve a subset
     let mut subset = Vec::new(&env);
-    subset.push_back(1u64);
-    subset.push_back(3u64);
+    subset.push_back(ids.get(0).unwrap());
+    subset.push_back(ids.get(2).unwrap());
 
-    let records = client.get_records(&subset);
+    let records = client.get_records(&provider, &subset);
     assert_eq!(records.len(), 2);
-    assert_eq!(records.get(0).unwrap().id, 1);
-    assert_eq!(records.get(1).unwrap().id, 3);
+    assert_eq!(found(records.get(0)).id, ids.get(0).unwrap());
+    assert_eq!(found(records.get(1)).id, ids.get(2).unwrap());
 }
 
 #[test]
 fn test_batch_get_records_not_found() {
-    let (env, client, _admin) = setup();
+    let (env, client, admin) = setup();
+    let provider = register_provider(&env, &client, &admin);
 
     let mut ids = Vec::new(&env);
     ids.push_back(999u64);
 
-    let result = client.try_get_records(&ids);
-    assert_eq!(
-        result.err().unwrap().unwrap(),
-        ContractError::RecordNotFound
-    );
+    let results = client.get_records(&provider, &ids);
+    assert_eq!(results.len(), 1);
+    assert!(matches!(
+        results.get(0),
+        Some(RecordFetchResult::NotFound(999))
+    ));
 }
 
 #[test]
-fn test_batch_get_records_partial_not_found() {
+fn test_batch_get_records_mixed_results_keep_positions() {
     let (env, client, admin) = setup();
     let provider = register_provider(&env, &client, &admin);
-    let patient = register_patient(&env, &client, &admin, "Alice");
+    let alice = register_patient(&env, &client, &admin, "Alice");
+    let bob = register_patient(&env, &client, &admin, "Bob");
 
     let mut inputs = Vec::new(&env);
-    inputs.push_back(BatchRecordInput {
-        patient: patient.clone(),
-        record_type: RecordType::Examination,
-        data_hash: String::from_str(&env, "hash_1"),
-    });
-    client.add_records(&provider, &inputs);
+    for patient in [&alice, &bob, &alice] {
+        inputs.push_back(BatchRecordInput {
+            patient: patient.clone(),
+            record_type: RecordType::Examination,
+            data_hash: String::from_str(&env, "hash_1"),
+        });
+    }
+    let created = client.add_records(&provider, &inputs);
+    let (r1, r2, r3) = (
+        created.get(0).unwrap(),
+        created.get(1).unwrap(),
+        created.get(2).unwrap(),
+    );
 
-    // Try to get existing + non-existing record
-    let mut ids = Vec::new(&env);
-    ids.push_back(1u64);
-    ids.push_back(999u64);
+    // Alice may read her own records but not Bob's.
+    let ids = Vec::from_array(&env, [r2, 999, r1, r3, r2]);
+    let results = client.get_records(&alice, &ids);
+    assert_eq!(results.len(), ids.len());
+    assert!(matches!(
+        results.get(0),
+        Some(RecordFetchResult::AccessDenied(id)) if id == r2
+    ));
+    assert!(matches!(
+        results.get(1),
+        Some(RecordFetchResult::NotFound(999))
+    ));
+    assert_eq!(found(results.get(2)).id, r1);
+    assert_eq!(found(results.get(3)).id, r3);
+    assert!(matches!(
+        results.get(4),
+        Some(RecordFetchResult::AccessDenied(id)) if id == r2
+    ));
+    assert_eq!(found(results.get(2)).patient, alice);
+
+    // A stranger sees nothing but still gets one entry per ID.
+    let stranger = Address::generate(&env);
+    let results = client.get_records(&stranger, &ids);
+    assert_eq!(results.len(), ids.len());
+    assert!(results
+        .iter()
+        .all(|result| !matches!(result, RecordFetchResult::Found(..))));
+}
 
-    let result = client.try_get_records(&ids);
-    assert_eq!(
-        result.err().unwrap().unwrap(),
-        ContractError::RecordNotFound
-    );
+#[test]
+fn test_batch_get_records_size_limits() {
+    let (env, client, admin) = setup();
+    let provider = register_provider(&env, &client, &admin);
+
+    let result = client.try_get_records(&provider, &Vec::new(&env));
+    assert_eq!(result.err().unwrap().unwrap(), ContractError::InvalidInput);
+
+    // A full batch of stored records fits in one invocation and is
+    // audited once
+    let patient = register_patient(&env, &client, &admin, "Alice");
+    let mut ids = Vec::new(&env);
+    for _ in 0..MAX_GET_RECORDS {
+        ids.push_back(client.add_record(
+            &provider,
+            &patient,
+            &provider,
+            &RecordType::Examination,
+            &String::from_str(&env, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"),
+        ));
+    }
+    let audited = || {
+        env.as_contract(&client.address, || {
+            audit::patient_audit_count(&env, &patient)
+        })
+    };
+    let before = audited();
+    assert_eq!(client.get_records(&provider, &ids).len(), MAX_GET_RECORDS);
+    assert_eq!(audited(), before + 1);
+
+    ids.push_back(u64::from(MAX_GET_RECORDS) + 1);
+    let result = client.try_get_records(&provider, &ids);
+    assert_eq!(result.err().unwrap().unwrap(), ContractError::InvalidInput);
 }
 
 // ======================== Batch Access Grants ========================
@@ -414,9 +493,9 @@ fn test_batch_records_atomic_counter() {
     // Counter should reflect all 5 records
     assert_eq!(client.get_record_count(), 5);
 
-    // All IDs are sequential
+    // All IDs are sequential within the provider's shard
     for (i, id) in ids.iter().enumerate() {
-        assert_eq!(id, (i as u64) + 1);
+        assert_eq!(record_counter::split(id).1, (i as u64) + 1);
     }
 }
 
@@ -442,14 +521,11 @@ fn test_batch_add_and_retrieve_round_trip() {
     let ids = client.add_records(&provider, &inputs);
 
     // Retrieve all via batch
-    let records = client.get_records(&ids);
+    let records = client.get_records(&provider, &ids);
     assert_eq!(records.len(), 2);
 
-    assert_eq!(records.get(0).unwrap().record_type, RecordType::Examination);
-    assert_eq!(
-        records.get(1).unwrap().record_type,
-        RecordType::Prescription
-    );
-    assert_eq!(records.get(0).unwrap().provider, provider);
-    assert_eq!(records.get(1).unwrap().provider, provider);
+    assert_eq!(found(records.get(0)).record_type, RecordType::Examination);
+    assert_eq!(found(records.get(1)).record_type, RecordType::Prescription);
+    assert_eq!(found(records.get(0)).provider, provider);
+    assert_eq!(found(records.get(1)).provider, provider);
 }
@@ -0,0 +1,203 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{
+    audit,
+    bounded::{MAX_BOUNDED_AUDIT, MAX_BOUNDED_GRANTS, MAX_BOUNDED_ITEMS},
+    hash_alg, versioning, AccessAction, AccessLevel, AccessResult, ContractError, RecordType,
+};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+const HASH: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const DAY: u64 = 86_400;
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .build()
+}
+
+/// A record with `versions` versions in its history.
+fn record_with_versions(fx: &TestContract, versions: u32) -> u64 {
+    let (dr_a, pat_a) = (fx.provider("dr_a"), fx.patient("pat_a"));
+    let record_id = fx.client.add_record(
+        &dr_a,
+        &pat_a,
+        &dr_a,
+        &RecordType::Examination,
+        &String::from_str(&fx.env, HASH),
+    );
+    // One invocation per version keeps each within the write limit
+    for _ in 1..versions {
+        fx.env.as_contract(&fx.contract_id, || {
+            versioning::append_version(
+                &fx.env,
+                record_id,
+                &pat_a,
+                String::from_str(&fx.env, HASH),
+                None,
+                hash_alg::UNKNOWN,
+                dr_a.clone(),
+                fx.env.ledger().timestamp(),
+            )
+            .unwrap();
+        });
+    }
+    record_id
+}
+
+fn grant_to_many(fx: &TestContract, count: u32) {
+    let pat_a = fx.patient("pat_a");
+    for _ in 0..count {
+        let grantee = Address::generate(&fx.env);
+        fx.client
+            .grant_access(&pat_a, &pat_a, &grantee, &AccessLevel::Read, &DAY);
+    }
+}
+
+/// Tops the patient's audit trail up to `count` entries.
+fn fill_audit_trail(fx: &TestContract, count: u32) {
+    let (dr_a, pat_a) = (fx.provider("dr_a"), fx.patient("pat_a"));
+    let existing = fx.env.as_contract(&fx.contract_id, || {
+        audit::patient_audit_count(&fx.env, &pat_a)
+    });
+    for _ in existing..count {
+        fx.env.as_contract(&fx.contract_id, || {
+            let entry = audit::create_audit_entry(
+                &fx.env,
+                dr_a.clone(),
+                pat_a.clone(),
+                None,
+                AccessAction::Read,
+                AccessResult::Success,
+                None,
+            )
+            .unwrap();
+            audit::add_audit_entry(&fx.env, &entry);
+        });
+    }
+}
+
+#[test]
+fn test_history_at_the_boundary() {
+    let fx = setup();
+    let dr_a = fx.provider("dr_a");
+
+    let under = record_with_versions(&fx, MAX_BOUNDED_ITEMS - 1);
+    let result = fx.client.get_record_history_bounded(&dr_a, &under, &0);
+    assert_eq!(result.items.len(), MAX_BOUNDED_ITEMS - 1);
+    assert!(!result.truncated);
+    assert_eq!(result.next_offset, MAX_BOUNDED_ITEMS - 1);
+
+    let at = record_with_versions(&fx, MAX_BOUNDED_ITEMS);
+    let result = fx.client.get_record_history_bounded(&dr_a, &at, &0);
+    assert_eq!(result.items.len(), MAX_BOUNDED_ITEMS);
+    assert!(!result.truncated);
+
+    let over = record_with_versions(&fx, MAX_BOUNDED_ITEMS + 1);
+    let result = fx.client.get_record_history_bounded(&dr_a, &over, &0);
+    assert_eq!(result.items.len(), MAX_BOUNDED_ITEMS);
+    assert!(result.truncated);
+    assert_eq!(result.next_offset, MAX_BOUNDED_ITEMS);
+    assert_eq!(result.items.last().unwrap().version, MAX_BOUNDED_ITEMS);
+
+    let rest = fx
+        .client
+        .get_record_history_bounded(&dr_a, &over, &result.next_offset);
+    assert_eq!(rest.items.len(), 1);
+    assert_eq!(rest.items.get(0).unwrap().version, MAX_BOUNDED_ITEMS + 1);
+    assert!(!rest.truncated);
+}
+
+#[test]
+fn test_grants_at_the_boundary() {
+    let fx = setup();
+    let pat_a = fx.patient("pat_a");
+
+    grant_to_many(&fx, MAX_BOUNDED_GRANTS - 1);
+    let result = fx.client.get_patient_grants_bounded(&pat_a, &pat_a, &0);
+    assert_eq!(result.items.len(), MAX_BOUNDED_GRANTS - 1);
+    assert!(!result.truncated);
+
+    grant_to_many(&fx, 1);
+    let result = fx.client.get_patient_grants_bounded(&pat_a, &pat_a, &0);
+    assert_eq!(result.items.len(), MAX_BOUNDED_GRANTS);
+    assert!(!result.truncated);
+    assert_eq!(result.next_offset, MAX_BOUNDED_GRANTS);
+
+    grant_to_many(&fx, 1);
+    let result = fx.client.get_patient_grants_bounded(&pat_a, &pat_a, &0);
+    assert_eq!(result.items.len(), MAX_BOUNDED_GRANTS);
+    assert!(result.truncated);
+
+    let rest = fx
+        .client
+        .get_patient_grants_bounded(&pat_a, &pat_a, &result.next_offset);
+    assert_eq!(rest.items.len(), 1);
+    assert!(!rest.truncated);
+    assert!(!result.items.contains(&rest.items.get(0).unwrap()));
+}
+
+#[test]
+fn test_audit_trail_at_the_boundary() {
+    let fx = setup();
+    let pat_a = fx.patient("pat_a");
+
+    fill_audit_trail(&fx, MAX_BOUNDED_AUDIT - 1);
+    let result = fx.client.get_audit_trail_bounded(&pat_a, &pat_a, &0);
+    assert_eq!(result.items.len(), MAX_BOUNDED_AUDIT - 1);
+    assert!(!result.truncated);
+
+    fill_audit_trail(&fx, MAX_BOUNDED_AUDIT);
+    let result = fx.client.get_audit_trail_bounded(&pat_a, &pat_a, &0);
+    assert_eq!(result.items.len(), MAX_BOUNDED_AUDIT);
+    assert!(!result.truncated);
+
+    fill_audit_trail(&fx, MAX_BOUNDED_AUDIT + 1);
+    let result = fx.client.get_audit_trail_bounded(&pat_a, &pat_a, &0);
+    assert_eq!(result.items.len(), MAX_BOUNDED_AUDIT);
+    assert!(result.truncated);
+    assert_eq!(result.next_offset, MAX_BOUNDED_AUDIT);
+
+    let rest = fx
+        .client
+        .get_audit_trail_bounded(&pat_a, &pat_a, &result.next_offset);
+    assert_eq!(rest.items.len(), 1);
+    assert!(!rest.truncated);
+    assert!(rest.items.get(0).unwrap().id > result.items.last().unwrap().id);
+}
+
+#[test]
+fn test_bounded_queries_check_access() {
+    let fx = setup();
+    let pat_a = fx.patient("pat_a");
+    let outsider = Address::generate(&fx.env);
+    let record_id = record_with_versions(&fx, 1);
+
+    assert!(fx
+        .client
+        .try_get_record_history_bounded(&outsider, &record_id, &0)
+        .is_err());
+    assert_err(
+        fx.client
+            .try_get_patient_grants_bounded(&outsider, &pat_a, &0),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client
+            .try_get_audit_trail_bounded(&fx.provider("dr_a"), &pat_a, &0),
+        ContractError::Unauthorized,
+    );
+
+    // Admins may read grants and the audit trail
+    fx.client
+        .get_patient_grants_bounded(&fx.admin(), &pat_a, &0);
+    fx.client.get_audit_trail_bounded(&fx.admin(), &pat_a, &0);
+}
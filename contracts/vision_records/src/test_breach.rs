@@ -0,0 +1,165 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessLevel, BreachFlag, ContractError, Permission};
+use crate::breach::MAX_BREACH_BATCH;
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{testutils::Address as _, Address, String, Vec};
+
+const INCIDENT: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_patient("pat_b")
+        .build()
+}
+
+fn flag(fx: &TestContract, patients: &[&str]) {
+    let mut list = Vec::new(&fx.env);
+    for name in patients {
+        list.push_back(fx.patient(name));
+    }
+    fx.client
+        .flag_breach(&fx.admin(), &list, &String::from_str(&fx.env, INCIDENT));
+}
+
+#[test]
+fn test_lockdown_blocks_grants_by_others() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let guardian = Address::generate(&fx.env);
+    let doctor = fx.provider("dr_a");
+    fx.client.delegate_permissions(
+        &patient,
+        &guardian,
+        &Vec::from_array(&fx.env, [Permission::ManageAccess]),
+        &0,
+    );
+    flag(&fx, &["pat_a"]);
+
+    assert_err(
+        fx.client
+            .try_grant_access(&guardian, &patient, &doctor, &AccessLevel::Read, &3_600),
+        ContractError::BreachLockdown,
+    );
+    assert_err(
+        fx.client
+            .try_grant_access(&fx.admin(), &patient, &doctor, &AccessLevel::Read, &3_600),
+        ContractError::BreachLockdown,
+    );
+    // A stranger still gets Unauthorized rather than learning of the flag.
+    assert_err(
+        fx.client.try_grant_access(
+            &Address::generate(&fx.env),
+            &patient,
+            &doctor,
+            &AccessLevel::Read,
+            &3_600,
+        ),
+        ContractError::Unauthorized,
+    );
+
+    // The patient may still share their own records.
+    fx.client
+        .grant_access(&patient, &patient, &doctor, &AccessLevel::Read, &3_600);
+
+    fx.client.clear_breach(&fx.admin(), &patient);
+    fx.client
+        .grant_access(&guardian, &patient, &doctor, &AccessLevel::Write, &3_600);
+}
+
+#[test]
+fn test_lockdown_is_per_patient() {
+    let fx = setup();
+    flag(&fx, &["pat_a"]);
+
+    fx.client.grant_access(
+        &fx.admin(),
+        &fx.patient("pat_b"),
+        &fx.provider("dr_a"),
+        &AccessLevel::Read,
+        &3_600,
+    );
+}
+
+#[test]
+fn test_breach_status_visibility() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    assert_eq!(fx.client.get_breach_status(&patient, &patient), None);
+
+    fx.advance_time(60);
+    flag(&fx, &["pat_a", "pat_b"]);
+    let expected = BreachFlag {
+        patient: patient.clone(),
+        incident_ref_hash: String::from_str(&fx.env, INCIDENT),
+        flagged_by: fx.admin(),
+        flagged_at: FIXTURE_START_TIME + 60,
+    };
+    assert_eq!(
+        fx.client.get_breach_status(&patient, &patient),
+        Some(expected.clone())
+    );
+    assert_eq!(
+        fx.client.get_breach_status(&fx.admin(), &patient),
+        Some(expected)
+    );
+    assert_err(
+        fx.client
+            .try_get_breach_status(&fx.patient("pat_b"), &patient),
+        ContractError::Unauthorized,
+    );
+
+    fx.client.clear_breach(&fx.admin(), &patient);
+    assert_eq!(fx.client.get_breach_status(&patient, &patient), None);
+    assert!(fx
+        .client
+        .get_breach_status(&fx.patient("pat_b"), &fx.patient("pat_b"))
+        .is_some());
+}
+
+#[test]
+fn test_flag_breach_requires_admin_and_bounded_batch() {
+    let fx = setup();
+    let incident = String::from_str(&fx.env, INCIDENT);
+    let patients = Vec::from_array(&fx.env, [fx.patient("pat_a")]);
+
+    assert_err(
+        fx.client
+            .try_flag_breach(&fx.provider("dr_a"), &patients, &incident),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client
+            .try_clear_breach(&fx.provider("dr_a"), &fx.patient("pat_a")),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client
+            .try_flag_breach(&fx.admin(), &Vec::new(&fx.env), &incident),
+        ContractError::InvalidInput,
+    );
+    assert_err(
+        fx.client
+            .try_flag_breach(&fx.admin(), &patients, &String::from_str(&fx.env, "")),
+        ContractError::InvalidInput,
+    );
+
+    let mut too_many = Vec::new(&fx.env);
+    for _ in 0..=MAX_BREACH_BATCH {
+        too_many.push_back(Address::generate(&fx.env));
+    }
+    assert_err(
+        fx.client.try_flag_breach(&fx.admin(), &too_many, &incident),
+        ContractError::InvalidInput,
+    );
+    too_many.pop_back();
+    fx.client.flag_breach(&fx.admin(), &too_many, &incident);
+}
@@ -0,0 +1,216 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessLevel, ContractError, RecordType};
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const CLAIM_A: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+const CLAIM_B: &str = "QmPZ9gcCEpqKTo6aq61g2nXGUhM4iCL3ewB6LDXZCtioEB";
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_b")
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .build()
+}
+
+fn text(fx: &TestContract, value: &str) -> String {
+    String::from_str(&fx.env, value)
+}
+
+fn attest(fx: &TestContract, claim: &str) {
+    fx.client.create_claim_attestation(
+        &fx.provider("dr_a"),
+        &fx.record(0),
+        &text(fx, claim),
+        &12_500,
+    );
+}
+
+#[test]
+fn test_claim_attestation_lifecycle() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    let provider = fx.provider("dr_a");
+
+    assert!(!fx
+        .client
+        .verify_claim_attestation(&record_id, &text(&fx, CLAIM_A)));
+    attest(&fx, CLAIM_A);
+    assert!(fx
+        .client
+        .verify_claim_attestation(&record_id, &text(&fx, CLAIM_A)));
+    assert!(!fx
+        .client
+        .verify_claim_attestation(&record_id, &text(&fx, CLAIM_B)));
+
+    let attestations = fx
+        .client
+        .get_claim_attestations(&fx.patient("pat_a"), &record_id);
+    assert_eq!(attestations.len(), 1);
+    let attestation = attestations.get(0).unwrap();
+    assert_eq!(attestation.provider, provider);
+    assert_eq!(attestation.amount_hint, 12_500);
+    assert_eq!(attestation.attested_at, FIXTURE_START_TIME);
+    assert!(!attestation.is_voided());
+
+    fx.advance_time(60);
+    fx.client.void_claim_attestation(
+        &provider,
+        &record_id,
+        &text(&fx, CLAIM_A),
+        &text(&fx, "billed under wrong record"),
+    );
+    assert!(!fx
+        .client
+        .verify_claim_attestation(&record_id, &text(&fx, CLAIM_A)));
+
+    let voided = fx
+        .client
+        .get_claim_attestations(&provider, &record_id)
+        .get(0)
+        .unwrap();
+    assert_eq!(voided.voided_by, Some(provider.clone()));
+    assert_eq!(
+        voided.void_reason,
+        Some(text(&fx, "billed under wrong record"))
+    );
+    assert_eq!(voided.voided_at, Some(FIXTURE_START_TIME + 60));
+    // The original assertion is preserved.
+    assert_eq!(voided.amount_hint, 12_500);
+    assert_eq!(voided.attested_at, FIXTURE_START_TIME);
+
+    // A voided claim can be attested again.
+    attest(&fx, CLAIM_A);
+    assert!(fx
+        .client
+        .verify_claim_attestation(&record_id, &text(&fx, CLAIM_A)));
+    assert_eq!(
+        fx.client
+            .get_claim_attestations(&provider, &record_id)
+            .len(),
+        2
+    );
+}
+
+#[test]
+fn test_only_record_provider_can_attest() {
+    let fx = setup();
+    let record_id = fx.record(0);
+
+    assert_err(
+        fx.client.try_create_claim_attestation(
+            &fx.provider("dr_b"),
+            &record_id,
+            &text(&fx, CLAIM_A),
+            &100,
+        ),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_create_claim_attestation(
+            &fx.provider("dr_a"),
+            &record_id,
+            &text(&fx, CLAIM_A),
+            &-1,
+        ),
+        ContractError::InvalidInput,
+    );
+    assert_err(
+        fx.client.try_create_claim_attestation(
+            &fx.provider("dr_a"),
+            &999,
+            &text(&fx, CLAIM_A),
+            &100,
+        ),
+        ContractError::RecordNotFound,
+    );
+
+    attest(&fx, CLAIM_A);
+    assert_err(
+        fx.client.try_create_claim_attestation(
+            &fx.provider("dr_a"),
+            &record_id,
+            &text(&fx, CLAIM_A),
+            &100,
+        ),
+        ContractError::AlreadyExists,
+    );
+}
+
+#[test]
+fn test_attestations_readable_by_patient_provider_and_readers() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    attest(&fx, CLAIM_A);
+
+    let reader = Address::generate(&fx.env);
+    fx.client.grant_record_access(
+        &fx.patient("pat_a"),
+        &reader,
+        &record_id,
+        &AccessLevel::Read,
+        &3_600,
+    );
+    assert_eq!(
+        fx.client.get_claim_attestations(&reader, &record_id).len(),
+        1
+    );
+
+    assert_err(
+        fx.client
+            .try_get_claim_attestations(&fx.provider("dr_b"), &record_id),
+        ContractError::Unauthorized,
+    );
+}
+
+#[test]
+fn test_void_requires_provider_or_admin() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    attest(&fx, CLAIM_A);
+    let reason = text(&fx, "duplicate claim");
+
+    assert_err(
+        fx.client.try_void_claim_attestation(
+            &fx.provider("dr_b"),
+            &record_id,
+            &text(&fx, CLAIM_A),
+            &reason,
+        ),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_void_claim_attestation(
+            &fx.provider("dr_a"),
+            &record_id,
+            &text(&fx, CLAIM_A),
+            &text(&fx, ""),
+        ),
+        ContractError::InvalidInput,
+    );
+
+    fx.client
+        .void_claim_attestation(&fx.admin(), &record_id, &text(&fx, CLAIM_A), &reason);
+    let voided = fx
+        .client
+        .get_claim_attestations(&fx.patient("pat_a"), &record_id)
+        .get(0)
+        .unwrap();
+    assert_eq!(voided.voided_by, Some(fx.admin()));
+
+    assert_err(
+        fx.client
+            .try_void_claim_attestation(&fx.admin(), &record_id, &text(&fx, CLAIM_A), &reason),
+        ContractError::ClaimAttestationNotFound,
+    );
+}
@@ -0,0 +1,170 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{ContractError, Permission, ProviderRecord, RecordType, Role};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{testutils::Address as _, Address, String, Vec};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+
+/// `tech` is staff allowed to append records; `dr_a` signs off on them.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider_role("tech", Role::Staff)
+        .with_provider("dr_b")
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .build();
+    fx.client
+        .grant_custom_permission(&fx.admin(), &fx.provider("tech"), &Permission::AppendRecord);
+    fx
+}
+
+fn coauthored(fx: &TestContract, coauthors: &[Address]) -> Result<u64, ContractError> {
+    let provider = fx.provider("dr_a");
+    let mut list = Vec::new(&fx.env);
+    for coauthor in coauthors {
+        list.push_back(coauthor.clone());
+    }
+    fx.client
+        .try_add_record_with_coauthors(
+            &provider,
+            &fx.patient("pat_a"),
+            &provider,
+            &list,
+            &RecordType::Examination,
+            &String::from_str(&fx.env, HASH_A),
+        )
+        .map(|id| id.unwrap())
+        .map_err(|err| err.unwrap())
+}
+
+fn optometrist(fx: &TestContract, name: &str) -> Address {
+    let user = Address::generate(&fx.env);
+    fx.client.register_user(
+        &fx.admin(),
+        &user,
+        &Role::Optometrist,
+        &String::from_str(&fx.env, name),
+    );
+    user
+}
+
+fn credited(record_id: u64, coauthored: bool) -> ProviderRecord {
+    ProviderRecord {
+        record_id,
+        coauthored,
+    }
+}
+
+#[test]
+fn test_coauthored_record_credits_both() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    let tech = fx.provider("tech");
+    let record_id = coauthored(&fx, core::slice::from_ref(&tech)).unwrap();
+
+    let record = fx.client.get_record(&provider, &record_id);
+    assert_eq!(record.provider, provider);
+    assert_eq!(record.coauthors, Vec::from_array(&fx.env, [tech.clone()]));
+
+    let plain = fx.client.add_record(
+        &provider,
+        &fx.patient("pat_a"),
+        &provider,
+        &RecordType::Examination,
+        &String::from_str(&fx.env, HASH_B),
+    );
+    assert!(fx.client.get_record(&provider, &plain).coauthors.is_empty());
+
+    assert_eq!(
+        fx.client.get_provider_records(&provider),
+        Vec::from_array(
+            &fx.env,
+            [credited(record_id, false), credited(plain, false)]
+        )
+    );
+    assert_eq!(
+        fx.client.get_provider_records(&tech),
+        Vec::from_array(&fx.env, [credited(record_id, true)])
+    );
+}
+
+#[test]
+fn test_coauthors_need_write_or_append_permission() {
+    let fx = setup();
+    let tech = fx.provider("tech");
+    fx.client
+        .revoke_custom_permission(&fx.admin(), &tech, &Permission::AppendRecord);
+    assert_eq!(
+        coauthored(&fx, &[tech]),
+        Err(ContractError::InsufficientPermissions)
+    );
+    assert_eq!(
+        coauthored(&fx, &[fx.patient("pat_a")]),
+        Err(ContractError::InsufficientPermissions)
+    );
+    assert_eq!(
+        coauthored(&fx, &[Address::generate(&fx.env)]),
+        Err(ContractError::InsufficientPermissions)
+    );
+
+    // An optometrist holds WriteRecord through their role.
+    assert!(coauthored(&fx, &[fx.provider("dr_b")]).is_ok());
+}
+
+#[test]
+fn test_coauthor_list_validation() {
+    let fx = setup();
+    let tech = fx.provider("tech");
+    let dr_b = fx.provider("dr_b");
+
+    assert_eq!(
+        coauthored(&fx, &[tech.clone(), tech.clone()]),
+        Err(ContractError::InvalidInput)
+    );
+    assert_eq!(
+        coauthored(&fx, &[fx.provider("dr_a")]),
+        Err(ContractError::InvalidInput)
+    );
+    let four = [
+        tech,
+        dr_b,
+        optometrist(&fx, "dr_c"),
+        optometrist(&fx, "dr_d"),
+    ];
+    assert_eq!(coauthored(&fx, &four), Err(ContractError::InvalidInput));
+    assert!(fx
+        .client
+        .get_patient_records(&fx.patient("pat_a"))
+        .is_empty());
+
+    assert!(coauthored(&fx, &four[..3]).is_ok());
+}
+
+#[test]
+fn test_coauthorship_grants_no_write_rights() {
+    let fx = setup();
+    let tech = fx.provider("tech");
+    let dr_b = fx.provider("dr_b");
+    let record_id = coauthored(&fx, &[tech.clone(), dr_b.clone()]).unwrap();
+
+    for coauthor in [tech, dr_b] {
+        assert_err(
+            fx.client
+                .try_update_record(&coauthor, &record_id, &String::from_str(&fx.env, HASH_B)),
+            ContractError::Unauthorized,
+        );
+    }
+    fx.client.update_record(
+        &fx.provider("dr_a"),
+        &record_id,
+        &String::from_str(&fx.env, HASH_B),
+    );
+}
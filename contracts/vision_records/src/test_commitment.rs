@@ -0,0 +1,166 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{BatchRecordInput, CommitmentEntry, Permission, RecordType};
+use crate::commitment::MAX_COMMITMENT_PAGE;
+use crate::testutils::{Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{Address, Bytes, BytesN, String, Vec};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+const DAY: u64 = 86_400;
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_b")
+        .with_patient("pat_a")
+        .build()
+}
+
+fn add(fx: &TestContract, patient: &Address) -> u64 {
+    let provider = fx.provider("dr_a");
+    fx.client.add_record(
+        &provider,
+        patient,
+        &provider,
+        &RecordType::Examination,
+        &String::from_str(&fx.env, HASH_A),
+    )
+}
+
+/// Replays a log the way an off-chain verifier would.
+fn replay(fx: &TestContract, log: &Vec<CommitmentEntry>) -> BytesN<32> {
+    let mut commitment = [0u8; 32];
+    for entry in log.iter() {
+        let mut preimage = Bytes::from_array(&fx.env, &commitment);
+        preimage.extend_from_array(&entry.record_id.to_be_bytes());
+        preimage.extend_from_array(&entry.timestamp.to_be_bytes());
+        commitment = fx.env.crypto().sha256(&preimage).to_array();
+    }
+    BytesN::from_array(&fx.env, &commitment)
+}
+
+fn full_log(fx: &TestContract, patient: &Address) -> Vec<CommitmentEntry> {
+    fx.client
+        .get_commitment_log(patient, &0, &MAX_COMMITMENT_PAGE)
+}
+
+#[test]
+fn test_empty_commitment() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let head = fx.client.get_patient_commitment(&patient);
+    assert_eq!(head.entries, 0);
+    assert_eq!(head.commitment, BytesN::from_array(&fx.env, &[0; 32]));
+    assert!(full_log(&fx, &patient).is_empty());
+}
+
+#[test]
+fn test_replayed_chain_matches_stored_commitment() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let provider = fx.provider("dr_a");
+    let first = add(&fx, &patient);
+    fx.advance_time(DAY);
+    let mut inputs = Vec::new(&fx.env);
+    for hash in [HASH_A, HASH_B] {
+        inputs.push_back(BatchRecordInput {
+            patient: patient.clone(),
+            record_type: RecordType::Prescription,
+            data_hash: String::from_str(&fx.env, hash),
+        });
+    }
+    let batch = fx.client.add_records(&provider, &inputs);
+
+    // A backdated import is logged at the time it was indexed.
+    fx.advance_time(DAY);
+    fx.client
+        .grant_custom_permission(&fx.admin(), &provider, &Permission::ImportRecords);
+    let imported = fx.client.add_record_backdated(
+        &provider,
+        &patient,
+        &provider,
+        &RecordType::Examination,
+        &String::from_str(&fx.env, HASH_B),
+        &FIXTURE_START_TIME,
+    );
+
+    let log = full_log(&fx, &patient);
+    let expected = [
+        (first, FIXTURE_START_TIME),
+        (batch.get(0).unwrap(), FIXTURE_START_TIME + DAY),
+        (batch.get(1).unwrap(), FIXTURE_START_TIME + DAY),
+        (imported, FIXTURE_START_TIME + 2 * DAY),
+    ];
+    assert_eq!(log.len(), expected.len() as u32);
+    for (entry, (record_id, timestamp)) in log.iter().zip(expected) {
+        assert_eq!(
+            entry,
+            CommitmentEntry {
+                record_id,
+                timestamp
+            }
+        );
+    }
+
+    let head = fx.client.get_patient_commitment(&patient);
+    assert_eq!(head.entries, 4);
+    assert_eq!(replay(&fx, &log), head.commitment);
+
+    // A log with any entry altered no longer reaches the commitment.
+    let mut forged = log.clone();
+    forged.set(
+        1,
+        CommitmentEntry {
+            record_id: first,
+            timestamp: FIXTURE_START_TIME + DAY,
+        },
+    );
+    assert_ne!(replay(&fx, &forged), head.commitment);
+}
+
+#[test]
+fn test_earlier_commitments_are_prefixes_of_later_ones() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    add(&fx, &patient);
+    let earlier = fx.client.get_patient_commitment(&patient);
+    add(&fx, &fx.patient("pat_b"));
+    add(&fx, &patient);
+
+    let log = full_log(&fx, &patient);
+    let mut prefix = Vec::new(&fx.env);
+    prefix.push_back(log.get(0).unwrap());
+    assert_eq!(replay(&fx, &prefix), earlier.commitment);
+    assert_eq!(
+        replay(&fx, &log),
+        fx.client.get_patient_commitment(&patient).commitment
+    );
+
+    // Other patients' records never enter the chain.
+    assert_eq!(full_log(&fx, &fx.patient("pat_b")).len(), 1);
+}
+
+#[test]
+fn test_commitment_log_paging() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let mut ids = Vec::new(&fx.env);
+    for _ in 0..5 {
+        ids.push_back(add(&fx, &patient));
+    }
+
+    let page = fx.client.get_commitment_log(&patient, &1, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().record_id, ids.get(1).unwrap());
+    assert_eq!(page.get(1).unwrap().record_id, ids.get(2).unwrap());
+
+    assert_eq!(fx.client.get_commitment_log(&patient, &4, &10).len(), 1);
+    assert!(fx.client.get_commitment_log(&patient, &5, &10).is_empty());
+}
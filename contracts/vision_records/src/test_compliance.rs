@@ -0,0 +1,184 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{
+    compliance::{self, COMPLIANCE_PAGE_SIZE},
+    AccessLevel, BatchGrantInput, ComplianceEntry, ContractError, RecordType,
+};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{BytesN, String, Vec};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+const HASH_C: &str = "QmPZ9gcCEpqKTo6aq61g2nXGUhM4iCL3ewB6LDXZCtioEB";
+const DAY: u64 = 86_400;
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_b")
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .build()
+}
+
+/// Five privileged actions: a patient-level grant and its revocation, a
+/// record grant and its revocation, and an admin read.
+fn mixed_round(fx: &TestContract) {
+    let (pat_a, dr_b) = (fx.patient("pat_a"), fx.provider("dr_b"));
+    let record_id = fx.record(0);
+    fx.client
+        .grant_access(&pat_a, &pat_a, &dr_b, &AccessLevel::Read, &DAY);
+    fx.client.revoke_access(&pat_a, &dr_b);
+    fx.client
+        .grant_record_access(&pat_a, &dr_b, &record_id, &AccessLevel::Read, &DAY);
+    fx.client.revoke_record_access(&pat_a, &dr_b, &record_id);
+    fx.client
+        .read_record_as_admin(&fx.admin(), &record_id, &String::from_str(&fx.env, HASH_A));
+    fx.advance_time(60);
+}
+
+fn all_entries(fx: &TestContract) -> Vec<ComplianceEntry> {
+    let admin = fx.admin();
+    let mut entries = Vec::new(&fx.env);
+    let mut page_no = 0;
+    loop {
+        let page = fx.client.get_compliance_page(&admin, &page_no);
+        if page.entries.is_empty() {
+            return entries;
+        }
+        entries.append(&page.entries);
+        page_no += 1;
+    }
+}
+
+#[test]
+fn test_sequence_has_no_gaps() {
+    let fx = setup();
+    for _ in 0..23 {
+        mixed_round(&fx);
+    }
+
+    let entries = all_entries(&fx);
+    assert_eq!(entries.len(), 115);
+    for (index, entry) in entries.iter().enumerate() {
+        assert_eq!(entry.seq, index as u64);
+    }
+    assert_eq!(fx.client.get_compliance_page(&fx.admin(), &0).total, 115);
+    let actions = [
+        compliance::GRANT,
+        compliance::REVOKE,
+        compliance::GRANT,
+        compliance::REVOKE,
+        compliance::ADMIN_READ,
+    ];
+    for (entry, action) in entries.iter().zip(actions.iter().cycle()) {
+        assert_eq!(entry.action, *action);
+        assert_eq!(entry.patient, fx.patient("pat_a"));
+    }
+    let record_grant = entries.get(2).unwrap();
+    assert_eq!(record_grant.record_id, fx.record(0));
+    assert_eq!(record_grant.counterparty, Some(fx.provider("dr_b")));
+    assert_eq!(entries.get(4).unwrap().actor, fx.admin());
+}
+
+#[test]
+fn test_pages_fill_and_roll_over() {
+    let fx = setup();
+    let admin = fx.admin();
+    for _ in 0..10 {
+        mixed_round(&fx);
+    }
+
+    let first = fx.client.get_compliance_page(&admin, &0);
+    assert_eq!(first.entries.len() as u64, COMPLIANCE_PAGE_SIZE);
+    assert_eq!(first.total, 50);
+    assert!(fx.client.get_compliance_page(&admin, &1).entries.is_empty());
+
+    // The 51st entry opens the next page and leaves the first untouched
+    mixed_round(&fx);
+    let second = fx.client.get_compliance_page(&admin, &1);
+    assert_eq!(second.entries.len(), 5);
+    assert_eq!(second.entries.get(0).unwrap().seq, COMPLIANCE_PAGE_SIZE);
+    assert_eq!(second.total, 55);
+    assert_eq!(
+        fx.client.get_compliance_page(&admin, &0).entries,
+        first.entries
+    );
+}
+
+#[test]
+fn test_batch_grants_are_logged() {
+    let fx = setup();
+    let pat_a = fx.patient("pat_a");
+    let grants = Vec::from_array(
+        &fx.env,
+        [
+            BatchGrantInput {
+                grantee: fx.provider("dr_a"),
+                level: AccessLevel::Read,
+                duration_seconds: DAY,
+            },
+            BatchGrantInput {
+                grantee: fx.provider("dr_b"),
+                level: AccessLevel::Write,
+                duration_seconds: DAY,
+            },
+        ],
+    );
+    fx.client.grant_access_batch(&pat_a, &grants);
+
+    let entries = all_entries(&fx);
+    assert_eq!(entries.len(), 2);
+    for (entry, grant) in entries.iter().zip(grants.iter()) {
+        assert_eq!(entry.action, compliance::GRANT);
+        assert_eq!(entry.actor, pat_a);
+        assert_eq!(entry.counterparty, Some(grant.grantee));
+    }
+}
+
+#[test]
+fn test_erasure_is_logged_without_touching_entries() {
+    let fx = setup();
+    let (admin, dr_a) = (fx.admin(), fx.provider("dr_a"));
+    let record_id = fx.record(0);
+    mixed_round(&fx);
+    for hash in [HASH_B, HASH_C] {
+        fx.client
+            .update_record(&dr_a, &record_id, &String::from_str(&fx.env, hash));
+    }
+    let before = fx.client.get_compliance_page(&admin, &0).entries;
+
+    fx.client.redact_version(
+        &admin,
+        &record_id,
+        &2,
+        &BytesN::from_array(&fx.env, &[7; 32]),
+    );
+    let after = fx.client.get_compliance_page(&admin, &0).entries;
+    assert_eq!(after.len(), before.len() + 1);
+    assert_eq!(after.slice(0..before.len()), before);
+    let erasure = after.last().unwrap();
+    assert_eq!(erasure.action, compliance::ERASURE);
+    assert_eq!(erasure.record_id, record_id);
+    assert_eq!(erasure.actor, admin);
+}
+
+#[test]
+fn test_only_admins_read_the_log() {
+    let fx = setup();
+    mixed_round(&fx);
+    assert_err(
+        fx.client.try_get_compliance_page(&fx.patient("pat_a"), &0),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_get_compliance_page(&fx.provider("dr_a"), &0),
+        ContractError::Unauthorized,
+    );
+}
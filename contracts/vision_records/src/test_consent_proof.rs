@@ -0,0 +1,151 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{ConsentProof, ConsentProofFields, ConsentType, ContractError};
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{testutils::Address as _, xdr::ToXdr, Address, BytesN};
+
+const DAY: u64 = 86_400;
+
+/// `pat_a` consented to treatment by `dr_a` at the fixture start.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_b")
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .build();
+    fx.client.grant_consent(
+        &fx.patient("pat_a"),
+        &fx.provider("dr_a"),
+        &ConsentType::Treatment,
+        &(30 * DAY),
+    );
+    fx
+}
+
+fn proof(fx: &TestContract) -> ConsentProof {
+    let patient = fx.patient("pat_a");
+    fx.client
+        .get_consent_proof(&patient, &patient, &fx.provider("dr_a"))
+}
+
+/// Recomputes a digest the way an off-chain verifier would.
+fn digest(fx: &TestContract, fields: ConsentProofFields) -> BytesN<32> {
+    fx.env.crypto().sha256(&fields.to_xdr(&fx.env)).to_bytes()
+}
+
+fn fields_of(fx: &TestContract, proof: &ConsentProof) -> ConsentProofFields {
+    ConsentProofFields {
+        contract: fx.contract_id.clone(),
+        patient: proof.patient.clone(),
+        provider: proof.provider.clone(),
+        scope: proof.scope.clone(),
+        given_at: proof.given_at,
+        withdrawn_at: proof.withdrawn_at,
+    }
+}
+
+#[test]
+fn test_proof_of_active_consent_recomputes() {
+    let fx = setup();
+    let proof = proof(&fx);
+    assert_eq!(proof.patient, fx.patient("pat_a"));
+    assert_eq!(proof.provider, fx.provider("dr_a"));
+    assert_eq!(proof.scope, ConsentType::Treatment);
+    assert_eq!(proof.given_at, FIXTURE_START_TIME);
+    assert_eq!(proof.withdrawn_at, None);
+    assert_eq!(digest(&fx, fields_of(&fx, &proof)), proof.proof_digest);
+}
+
+#[test]
+fn test_changing_any_field_breaks_the_match() {
+    let fx = setup();
+    let proof = proof(&fx);
+    let fields = fields_of(&fx, &proof);
+
+    let altered = [
+        ConsentProofFields {
+            given_at: fields.given_at + 1,
+            ..fields.clone()
+        },
+        ConsentProofFields {
+            scope: ConsentType::Research,
+            ..fields.clone()
+        },
+        ConsentProofFields {
+            provider: fx.provider("dr_b"),
+            ..fields.clone()
+        },
+        ConsentProofFields {
+            withdrawn_at: Some(FIXTURE_START_TIME),
+            ..fields.clone()
+        },
+        ConsentProofFields {
+            contract: Address::generate(&fx.env),
+            ..fields.clone()
+        },
+    ];
+    for fields in altered {
+        assert_ne!(digest(&fx, fields), proof.proof_digest);
+    }
+}
+
+#[test]
+fn test_withdrawn_consent_is_retained_and_provable() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let provider = fx.provider("dr_a");
+    let given = proof(&fx);
+
+    fx.advance_time(DAY);
+    fx.client.revoke_consent(&patient, &provider);
+    let withdrawn = proof(&fx);
+    assert_eq!(withdrawn.given_at, FIXTURE_START_TIME);
+    assert_eq!(withdrawn.withdrawn_at, Some(FIXTURE_START_TIME + DAY));
+    assert_eq!(
+        digest(&fx, fields_of(&fx, &withdrawn)),
+        withdrawn.proof_digest
+    );
+    assert_ne!(withdrawn.proof_digest, given.proof_digest);
+
+    // Revoking again keeps the original withdrawal time.
+    fx.advance_time(DAY);
+    fx.client.revoke_consent(&patient, &provider);
+    assert_eq!(proof(&fx), withdrawn);
+
+    // Consenting again starts a fresh, unwithdrawn consent.
+    fx.client
+        .grant_consent(&patient, &provider, &ConsentType::Sharing, &DAY);
+    let renewed = proof(&fx);
+    assert_eq!(renewed.given_at, FIXTURE_START_TIME + 2 * DAY);
+    assert_eq!(renewed.withdrawn_at, None);
+    assert_eq!(renewed.scope, ConsentType::Sharing);
+}
+
+#[test]
+fn test_consent_proof_access() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let provider = fx.provider("dr_a");
+
+    assert_eq!(
+        fx.client
+            .get_consent_proof(&fx.admin(), &patient, &provider),
+        proof(&fx)
+    );
+    assert_err(
+        fx.client
+            .try_get_consent_proof(&provider, &patient, &provider),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client
+            .try_get_consent_proof(&patient, &patient, &fx.provider("dr_b")),
+        ContractError::ConsentNotFound,
+    );
+}
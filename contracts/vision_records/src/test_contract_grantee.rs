@@ -0,0 +1,153 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessLevel, ConsentType, ContractError, GrantPurpose};
+use crate::contract_grantee::DEFAULT_CONTRACT_GRANT_MAX_DURATION;
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{testutils::Address as _, vec, Address, ConversionError, InvokeError};
+
+const DAY: u64 = 86_400;
+
+/// `analytics` is a marked integration contract; `dr_a` is an ordinary
+/// provider.
+fn setup() -> (TestContract, Address) {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .build();
+    let analytics = Address::generate(&fx.env);
+    fx.client.mark_contract_grantee(&fx.admin(), &analytics);
+    (fx, analytics)
+}
+
+fn try_grant_for_payment(
+    fx: &TestContract,
+    grantee: &Address,
+    duration_seconds: u64,
+) -> Result<Result<(), ConversionError>, Result<ContractError, InvokeError>> {
+    let patient = fx.patient("pat_a");
+    fx.client.try_grant_access_with_purpose(
+        &patient,
+        &patient,
+        grantee,
+        &AccessLevel::Read,
+        &GrantPurpose::Payment,
+        &duration_seconds,
+    )
+}
+
+#[test]
+fn test_contract_grant_requires_purpose() {
+    let (fx, analytics) = setup();
+    let patient = fx.patient("pat_a");
+    assert!(fx.client.is_contract_grantee(&analytics));
+    assert_err(
+        fx.client
+            .try_grant_access(&patient, &patient, &analytics, &AccessLevel::Read, &DAY),
+        ContractError::PurposeRequired,
+    );
+    assert_err(
+        fx.client.try_grant_access_pending_acceptance(
+            &patient,
+            &analytics,
+            &AccessLevel::Read,
+            &DAY,
+        ),
+        ContractError::PurposeRequired,
+    );
+    // Unmarked grantees are unaffected
+    fx.client.grant_access(
+        &patient,
+        &patient,
+        &fx.provider("dr_a"),
+        &AccessLevel::Read,
+        &DAY,
+    );
+}
+
+#[test]
+fn test_contract_grant_duration_cap() {
+    let (fx, analytics) = setup();
+    assert_eq!(
+        fx.client.get_contract_grant_max_duration(),
+        DEFAULT_CONTRACT_GRANT_MAX_DURATION
+    );
+    assert_err(
+        try_grant_for_payment(&fx, &analytics, DEFAULT_CONTRACT_GRANT_MAX_DURATION + 1),
+        ContractError::DurationTooLong,
+    );
+    assert!(try_grant_for_payment(&fx, &analytics, DEFAULT_CONTRACT_GRANT_MAX_DURATION).is_ok());
+
+    // A shortened cap applies to the next grant
+    fx.client.set_contract_grant_max_duration(&fx.admin(), &DAY);
+    assert_err(
+        try_grant_for_payment(&fx, &analytics, DAY + 1),
+        ContractError::DurationTooLong,
+    );
+    assert!(try_grant_for_payment(&fx, &analytics, DAY).is_ok());
+    // Ordinary grantees keep the general cap
+    assert!(try_grant_for_payment(&fx, &fx.provider("dr_a"), 2 * DAY).is_ok());
+}
+
+#[test]
+fn test_listing_and_revoke_all_contract_grants() {
+    let (fx, analytics) = setup();
+    let patient = fx.patient("pat_a");
+    let dr_a = fx.provider("dr_a");
+    assert!(try_grant_for_payment(&fx, &analytics, DAY).is_ok());
+    fx.client
+        .grant_consent(&patient, &dr_a, &ConsentType::Treatment, &DAY);
+    fx.client
+        .grant_access(&patient, &patient, &dr_a, &AccessLevel::Read, &DAY);
+
+    let listed = fx.client.get_patient_grants_bounded(&patient, &patient, &0);
+    for detail in listed.items.iter() {
+        assert_eq!(detail.is_contract_grantee, detail.grantee == analytics);
+    }
+    assert_eq!(listed.items.len(), 2);
+
+    assert_eq!(fx.client.revoke_all_contract_grants(&patient), 1);
+    assert_eq!(
+        fx.client.check_access(&patient, &analytics),
+        AccessLevel::None
+    );
+    assert_eq!(fx.client.check_access(&patient, &dr_a), AccessLevel::Read);
+    assert_eq!(fx.client.revoke_all_contract_grants(&patient), 0);
+}
+
+#[test]
+fn test_marking_requires_contract_admin() {
+    let (fx, analytics) = setup();
+    let dr_a = fx.provider("dr_a");
+    let other = Address::generate(&fx.env);
+    assert_err(
+        fx.client.try_mark_contract_grantee(&dr_a, &other),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_set_contract_grant_max_duration(&dr_a, &DAY),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client
+            .try_set_contract_grant_max_duration(&fx.admin(), &0),
+        ContractError::InvalidInput,
+    );
+    assert_eq!(
+        fx.client.get_contract_grantees(),
+        vec![&fx.env, analytics.clone()]
+    );
+
+    fx.client.unmark_contract_grantee(&fx.admin(), &analytics);
+    assert!(!fx.client.is_contract_grantee(&analytics));
+    assert_err(
+        fx.client
+            .try_unmark_contract_grantee(&fx.admin(), &analytics),
+        ContractError::ContractGranteeNotFound,
+    );
+}
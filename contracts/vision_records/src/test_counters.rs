@@ -0,0 +1,133 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{
+    audit, counters, record_counter, versioning, ContractError, RecordType, Role,
+    VisionRecordsContract, VisionRecordsContractClient,
+};
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+
+fn setup() -> (Env, VisionRecordsContractClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VisionRecordsContract, ());
+    let client = VisionRecordsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &None);
+
+    let provider = Address::generate(&env);
+    client.register_user(
+        &admin,
+        &provider,
+        &Role::Optometrist,
+        &String::from_str(&env, "Dr. Provider"),
+    );
+
+    (env, client, admin, provider)
+}
+
+#[test]
+fn test_checked_next_at_max() {
+    assert_eq!(counters::checked_next_u64(0), Ok(1));
+    assert_eq!(
+        counters::checked_next_u64(u64::MAX),
+        Err(ContractError::CounterOverflow)
+    );
+    assert_eq!(counters::checked_next_u32(41), Ok(42));
+    assert_eq!(
+        counters::checked_next_u32(u32::MAX),
+        Err(ContractError::CounterOverflow)
+    );
+}
+
+#[test]
+fn test_next_id_leaves_counter_untouched_on_overflow() {
+    let (env, client, _admin, _provider) = setup();
+    let key = symbol_short!("TEST_CTR");
+
+    env.as_contract(&client.address, || {
+        assert_eq!(counters::next_id(&env, &key), Ok(1));
+        env.storage().instance().set(&key, &u64::MAX);
+        assert_eq!(
+            counters::next_id(&env, &key),
+            Err(ContractError::CounterOverflow)
+        );
+        assert_eq!(counters::current_id(&env, &key), u64::MAX);
+    });
+}
+
+#[test]
+fn test_record_counter_overflow_is_clean_error() {
+    let (env, client, _admin, provider) = setup();
+    let shard = env.as_contract(&client.address, || {
+        let shard = record_counter::shard_of(&env, &provider);
+        env.storage().persistent().set(
+            &record_counter::shard_key(shard),
+            &record_counter::MAX_SEQUENCE,
+        );
+        shard
+    });
+
+    let res = client.try_add_record(
+        &provider,
+        &Address::generate(&env),
+        &provider,
+        &RecordType::Examination,
+        &String::from_str(&env, HASH_A),
+    );
+    assert_eq!(res, Err(Ok(ContractError::CounterOverflow)));
+    // The shard did not move
+    env.as_contract(&client.address, || {
+        assert_eq!(
+            record_counter::current_sequence(&env, shard),
+            record_counter::MAX_SEQUENCE
+        );
+    });
+    assert_eq!(client.get_record_count(), record_counter::MAX_SEQUENCE);
+}
+
+#[test]
+fn test_version_counter_overflow_is_clean_error() {
+    let (env, client, _admin, provider) = setup();
+    let record_id = client.add_record(
+        &provider,
+        &Address::generate(&env),
+        &provider,
+        &RecordType::Examination,
+        &String::from_str(&env, HASH_A),
+    );
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .set(&versioning::version_count_key(record_id), &u32::MAX);
+    });
+
+    let res = client.try_update_record(&provider, &record_id, &String::from_str(&env, HASH_B));
+    assert_eq!(res, Err(Ok(ContractError::CounterOverflow)));
+}
+
+#[test]
+fn test_audit_counter_overflow_is_clean_error() {
+    let (env, client, _admin, provider) = setup();
+    env.as_contract(&client.address, || {
+        env.storage().instance().set(&audit::AUDIT_CTR, &u64::MAX);
+    });
+
+    let res = client.try_add_record(
+        &provider,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &RecordType::Examination,
+        &String::from_str(&env, HASH_A),
+    );
+    assert_eq!(res, Err(Ok(ContractError::CounterOverflow)));
+}
@@ -0,0 +1,191 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{ContractError, Cursor, Permission, RecordType};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{testutils::Address as _, Address, String, Vec};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+const YEAR: u64 = 31_536_000;
+
+/// `dr_a` may import historical records, and the clock is set ten years in
+/// so there is a past to import into.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .build();
+    fx.client.grant_custom_permission(
+        &fx.admin(),
+        &fx.provider("dr_a"),
+        &Permission::ImportRecords,
+    );
+    fx.set_time(10 * YEAR);
+    fx
+}
+
+fn add(fx: &TestContract) -> u64 {
+    let provider = fx.provider("dr_a");
+    fx.client.add_record(
+        &provider,
+        &fx.patient("pat_a"),
+        &provider,
+        &RecordType::Examination,
+        &String::from_str(&fx.env, HASH_A),
+    )
+}
+
+fn import(fx: &TestContract, original_created_at: u64) -> u64 {
+    let provider = fx.provider("dr_a");
+    fx.client.add_record_backdated(
+        &provider,
+        &fx.patient("pat_a"),
+        &provider,
+        &RecordType::Examination,
+        &String::from_str(&fx.env, HASH_A),
+        &original_created_at,
+    )
+}
+
+fn update(fx: &TestContract, record_id: u64) {
+    fx.advance_time(60);
+    fx.client.update_record(
+        &fx.provider("dr_a"),
+        &record_id,
+        &String::from_str(&fx.env, HASH_B),
+    );
+}
+
+#[test]
+fn test_patient_records_page_through() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let ids: Vec<u64> = Vec::from_array(&fx.env, [add(&fx), add(&fx), add(&fx)]);
+
+    let first = fx.client.get_patient_records_page(&patient, &None, &2);
+    assert_eq!(first.record_ids, ids.slice(0..2));
+    assert!(first.has_more);
+    let second = fx
+        .client
+        .get_patient_records_page(&patient, &Some(first.next), &2);
+    assert_eq!(second.record_ids, ids.slice(2..3));
+    assert!(!second.has_more);
+    let past_end = fx
+        .client
+        .get_patient_records_page(&patient, &Some(second.next), &2);
+    assert!(past_end.record_ids.is_empty());
+
+    assert_err(
+        fx.client.try_get_patient_records_page(&patient, &None, &0),
+        ContractError::InvalidInput,
+    );
+}
+
+#[test]
+fn test_append_keeps_cursor_valid() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    add(&fx);
+    add(&fx);
+    let third = add(&fx);
+
+    let first = fx.client.get_patient_records_page(&patient, &None, &2);
+    let added = add(&fx);
+    let second = fx
+        .client
+        .get_patient_records_page(&patient, &Some(first.next), &2);
+    assert_eq!(second.record_ids, Vec::from_array(&fx.env, [third, added]));
+}
+
+#[test]
+fn test_insert_ahead_invalidates_cursor() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    add(&fx);
+    add(&fx);
+    add(&fx);
+
+    let first = fx.client.get_patient_records_page(&patient, &None, &2);
+    let older = import(&fx, YEAR);
+    assert_err(
+        fx.client
+            .try_get_patient_records_page(&patient, &Some(first.next), &2),
+        ContractError::StaleCursor,
+    );
+
+    let restarted = fx.client.get_patient_records_page(&patient, &None, &2);
+    assert_eq!(restarted.record_ids.get(0), Some(older));
+    assert!(fx
+        .client
+        .try_get_patient_records_page(&patient, &Some(restarted.next), &2)
+        .is_ok());
+}
+
+#[test]
+fn test_history_page_through() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    let record_id = add(&fx);
+    update(&fx, record_id);
+    update(&fx, record_id);
+
+    let first = fx
+        .client
+        .get_record_history_page(&provider, &record_id, &None, &2);
+    assert_eq!(first.versions.len(), 2);
+    assert_eq!(first.versions.get(0).unwrap().version, 1);
+    assert_eq!(
+        first.versions.get(0).unwrap().data_hash,
+        String::from_str(&fx.env, HASH_A)
+    );
+
+    // New versions are appended, so the cursor stays valid
+    update(&fx, record_id);
+    let second = fx
+        .client
+        .get_record_history_page(&provider, &record_id, &Some(first.next), &2);
+    assert_eq!(second.versions.get(0).unwrap().version, 3);
+    assert_eq!(second.versions.get(1).unwrap().version, 4);
+    assert!(!second.has_more);
+}
+
+#[test]
+fn test_history_page_rejects_stale_cursor() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    let record_id = add(&fx);
+    update(&fx, record_id);
+
+    let stale = Some(Cursor {
+        position: 1,
+        generation: 1,
+    });
+    assert_err(
+        fx.client
+            .try_get_record_history_page(&provider, &record_id, &stale, &2),
+        ContractError::StaleCursor,
+    );
+}
+
+#[test]
+fn test_history_page_requires_read_access() {
+    let fx = setup();
+    let record_id = add(&fx);
+    let patient = fx.patient("pat_a");
+    let page = fx
+        .client
+        .get_record_history_page(&patient, &record_id, &None, &2);
+    assert_eq!(page.versions.len(), 1);
+
+    assert_err(
+        fx.client
+            .try_get_record_history_page(&Address::generate(&fx.env), &record_id, &None, &2),
+        ContractError::Unauthorized,
+    );
+}
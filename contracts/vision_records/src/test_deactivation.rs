@@ -0,0 +1,202 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{
+    AccessLevel, ContractError, DeactivationCascade, DeactivationSummary, Permission, RecordType,
+    Role,
+};
+use crate::rbac;
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{testutils::Address as _, Address, String, Vec};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+
+/// `dr_a` has delegated to two addresses, holds grants from two patients,
+/// and has itself been delegated to by `dr_b`.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_provider("dr_b")
+        .with_patient("pat_a")
+        .with_patient("pat_b")
+        .build();
+    let provider = fx.provider("dr_a");
+    fx.client.delegate_role(
+        &provider,
+        &Address::generate(&fx.env),
+        &Role::Optometrist,
+        &0,
+    );
+    fx.client.delegate_permissions(
+        &provider,
+        &Address::generate(&fx.env),
+        &Vec::from_array(&fx.env, [Permission::WriteRecord]),
+        &0,
+    );
+    fx.client
+        .delegate_role(&fx.provider("dr_b"), &provider, &Role::Optometrist, &0);
+    for patient in [fx.patient("pat_a"), fx.patient("pat_b")] {
+        fx.client
+            .grant_access(&patient, &patient, &provider, &AccessLevel::Read, &3_600);
+    }
+    fx
+}
+
+fn outgoing_delegations(fx: &TestContract, user: &Address) -> u32 {
+    fx.env.as_contract(&fx.contract_id, || {
+        rbac::count_active_delegations_from(&fx.env, user)
+    })
+}
+
+fn received_grants(fx: &TestContract, user: &Address) -> u32 {
+    fx.client.get_grantee_patients(user).len()
+}
+
+fn deactivate(fx: &TestContract, cascade: DeactivationCascade, limit: u32) -> DeactivationSummary {
+    fx.client
+        .deactivate_user(&fx.admin(), &fx.provider("dr_a"), &cascade, &limit)
+}
+
+#[test]
+fn test_deactivation_without_cascade_touches_nothing_else() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    let summary = deactivate(&fx, DeactivationCascade::None, 10);
+    assert_eq!(
+        summary,
+        DeactivationSummary {
+            user: provider.clone(),
+            cascade: DeactivationCascade::None,
+            delegations_revoked: 0,
+            grants_revoked: 0,
+            remaining: 0,
+        }
+    );
+
+    assert!(!fx.client.get_user(&provider).is_active);
+    assert_err(
+        fx.client.try_add_record(
+            &provider,
+            &fx.patient("pat_a"),
+            &provider,
+            &RecordType::Examination,
+            &String::from_str(&fx.env, HASH_A),
+        ),
+        ContractError::Unauthorized,
+    );
+    assert_eq!(outgoing_delegations(&fx, &provider), 2);
+    assert_eq!(received_grants(&fx, &provider), 2);
+    assert_eq!(outgoing_delegations(&fx, &fx.provider("dr_b")), 1);
+}
+
+#[test]
+fn test_revoke_outgoing_delegations_keeps_grants() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    let summary = deactivate(&fx, DeactivationCascade::RevokeOutgoingDelegations, 10);
+    assert_eq!(summary.delegations_revoked, 2);
+    assert_eq!(summary.grants_revoked, 0);
+    assert_eq!(summary.remaining, 0);
+
+    assert_eq!(outgoing_delegations(&fx, &provider), 0);
+    assert_eq!(received_grants(&fx, &provider), 2);
+    assert!(fx
+        .client
+        .check_access_detailed(&fx.patient("pat_a"), &provider)
+        .is_some());
+    // Delegations made to the user are not theirs to lose.
+    assert_eq!(outgoing_delegations(&fx, &fx.provider("dr_b")), 1);
+}
+
+#[test]
+fn test_revoke_all_removes_delegations_and_received_grants() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    let summary = deactivate(&fx, DeactivationCascade::RevokeAll, 10);
+    assert_eq!(summary.delegations_revoked, 2);
+    assert_eq!(summary.grants_revoked, 2);
+    assert_eq!(summary.remaining, 0);
+
+    assert_eq!(outgoing_delegations(&fx, &provider), 0);
+    assert_eq!(received_grants(&fx, &provider), 0);
+    for patient in [fx.patient("pat_a"), fx.patient("pat_b")] {
+        assert_eq!(fx.client.check_access_detailed(&patient, &provider), None);
+    }
+    assert_eq!(outgoing_delegations(&fx, &fx.provider("dr_b")), 1);
+}
+
+#[test]
+fn test_cascade_resumes_across_calls() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+
+    let first = deactivate(&fx, DeactivationCascade::RevokeAll, 3);
+    assert_eq!(first.delegations_revoked, 2);
+    assert_eq!(first.grants_revoked, 1);
+    assert_eq!(first.remaining, 1);
+
+    let second = deactivate(&fx, DeactivationCascade::RevokeAll, 3);
+    assert_eq!(second.delegations_revoked, 0);
+    assert_eq!(second.grants_revoked, 1);
+    assert_eq!(second.remaining, 0);
+    assert_eq!(received_grants(&fx, &provider), 0);
+}
+
+#[test]
+fn test_reactivation_restores_permissions_but_not_artifacts() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    deactivate(&fx, DeactivationCascade::RevokeAll, 10);
+
+    fx.client.reactivate_user(&fx.admin(), &provider);
+    assert!(fx.client.get_user(&provider).is_active);
+    fx.client.add_record(
+        &provider,
+        &fx.patient("pat_a"),
+        &provider,
+        &RecordType::Examination,
+        &String::from_str(&fx.env, HASH_A),
+    );
+    assert_eq!(outgoing_delegations(&fx, &provider), 0);
+    assert_eq!(received_grants(&fx, &provider), 0);
+}
+
+#[test]
+fn test_deactivation_requires_operator_admin() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    assert_err(
+        fx.client.try_deactivate_user(
+            &fx.provider("dr_b"),
+            &provider,
+            &DeactivationCascade::None,
+            &10,
+        ),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client
+            .try_reactivate_user(&fx.provider("dr_b"), &provider),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_deactivate_user(
+            &fx.admin(),
+            &Address::generate(&fx.env),
+            &DeactivationCascade::None,
+            &10,
+        ),
+        ContractError::UserNotFound,
+    );
+    assert_err(
+        fx.client
+            .try_deactivate_user(&fx.admin(), &provider, &DeactivationCascade::None, &0),
+        ContractError::InvalidInput,
+    );
+    assert!(fx.client.get_user(&provider).is_active);
+}
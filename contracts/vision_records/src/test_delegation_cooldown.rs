@@ -0,0 +1,121 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{ContractError, Role};
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{testutils::Address as _, Address};
+
+const COOLDOWN: u64 = 3_600;
+
+/// `dr_a` has delegated its role to `deputy`, with cooldowns turned on.
+fn setup() -> (TestContract, Address) {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_provider("dr_b")
+        .build();
+    fx.client.set_delegation_cooldown(&fx.admin(), &COOLDOWN);
+    let deputy = Address::generate(&fx.env);
+    fx.client
+        .delegate_role(&fx.provider("dr_a"), &deputy, &Role::Optometrist, &0);
+    (fx, deputy)
+}
+
+#[test]
+fn test_redelegation_waits_for_cooldown() {
+    let (fx, deputy) = setup();
+    let dr_a = fx.provider("dr_a");
+    fx.client.revoke_delegation(&dr_a, &dr_a, &deputy);
+    let until = FIXTURE_START_TIME + COOLDOWN;
+    assert_eq!(
+        fx.client
+            .get_delegation_cooldown_until(&dr_a, &deputy, &Role::Optometrist),
+        Some(until)
+    );
+
+    fx.advance_time(COOLDOWN - 1);
+    assert_err(
+        fx.client
+            .try_delegate_role(&dr_a, &deputy, &Role::Optometrist, &0),
+        ContractError::DelegationCooldown,
+    );
+    // Only the revoked tuple is blocked
+    fx.client
+        .delegate_role(&dr_a, &Address::generate(&fx.env), &Role::Optometrist, &0);
+    fx.client
+        .delegate_role(&fx.provider("dr_b"), &deputy, &Role::Optometrist, &0);
+
+    fx.advance_time(1);
+    assert_eq!(
+        fx.client
+            .get_delegation_cooldown_until(&dr_a, &deputy, &Role::Optometrist),
+        None
+    );
+    fx.client
+        .delegate_role(&dr_a, &deputy, &Role::Optometrist, &0);
+}
+
+#[test]
+fn test_forced_revocation_and_admin_clear() {
+    let (fx, deputy) = setup();
+    let (admin, dr_a) = (fx.admin(), fx.provider("dr_a"));
+    assert_err(
+        fx.client
+            .try_revoke_delegation(&fx.provider("dr_b"), &dr_a, &deputy),
+        ContractError::Unauthorized,
+    );
+
+    fx.client.revoke_delegation(&admin, &dr_a, &deputy);
+    assert_err(
+        fx.client
+            .try_delegate_role(&dr_a, &deputy, &Role::Optometrist, &0),
+        ContractError::DelegationCooldown,
+    );
+
+    assert_err(
+        fx.client
+            .try_clear_delegation_cooldown(&dr_a, &dr_a, &deputy, &Role::Optometrist),
+        ContractError::Unauthorized,
+    );
+    fx.client
+        .clear_delegation_cooldown(&admin, &dr_a, &deputy, &Role::Optometrist);
+    fx.client
+        .delegate_role(&dr_a, &deputy, &Role::Optometrist, &0);
+}
+
+#[test]
+fn test_no_cooldown_when_off() {
+    let (fx, deputy) = setup();
+    let dr_a = fx.provider("dr_a");
+    fx.client.set_delegation_cooldown(&fx.admin(), &0);
+    assert_eq!(fx.client.get_delegation_cooldown(), 0);
+
+    fx.client.revoke_delegation(&dr_a, &dr_a, &deputy);
+    fx.client
+        .delegate_role(&dr_a, &deputy, &Role::Optometrist, &0);
+}
+
+#[test]
+fn test_cooldown_bounded_so_revocation_still_works() {
+    let (fx, deputy) = setup();
+    let dr_a = fx.provider("dr_a");
+    assert_err(
+        fx.client
+            .try_set_delegation_cooldown(&fx.admin(), &(365 * 86_400)),
+        ContractError::InvalidInput,
+    );
+    assert_eq!(fx.client.get_delegation_cooldown(), COOLDOWN);
+
+    let longest = 150 * 86_400;
+    fx.client.set_delegation_cooldown(&fx.admin(), &longest);
+    fx.client.revoke_delegation(&dr_a, &dr_a, &deputy);
+    assert_eq!(
+        fx.client
+            .get_delegation_cooldown_until(&dr_a, &deputy, &Role::Optometrist),
+        Some(FIXTURE_START_TIME + longest)
+    );
+}
@@ -0,0 +1,154 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{ContractError, DigestScope, RecordType, VisionRecord};
+use crate::digest::{PatientState, RecordState};
+use crate::redaction;
+use crate::testutils::{assert_err, Fixture, TestContract};
+use crate::versioning;
+use soroban_sdk::{symbol_short, xdr::ToXdr, BytesN, String, Vec};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .with_record(RecordType::Prescription, HASH_B)
+        .build()
+}
+
+/// Reads a record's state straight from storage, independently of the
+/// contract's digest code.
+fn stored_state(fx: &TestContract, record_id: u64) -> RecordState {
+    fx.env.as_contract(&fx.contract_id, || {
+        let record: VisionRecord = fx
+            .env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .unwrap();
+        let version_count = versioning::get_version_count(&fx.env, record_id);
+        let latest = versioning::get_version(&fx.env, record_id, version_count).unwrap();
+        let redacted_versions = fx
+            .env
+            .storage()
+            .persistent()
+            .get(&redaction::versions_key(record_id))
+            .unwrap_or(Vec::new(&fx.env));
+        RecordState {
+            record,
+            version_count,
+            latest_hash: Some(latest.data_hash),
+            redacted_versions,
+        }
+    })
+}
+
+fn sha256_of(fx: &TestContract, state: RecordState) -> BytesN<32> {
+    fx.env.crypto().sha256(&state.to_xdr(&fx.env)).to_bytes()
+}
+
+fn digest(fx: &TestContract, scope: DigestScope) -> BytesN<32> {
+    fx.client.get_state_digest(&scope)
+}
+
+#[test]
+fn test_record_digest_matches_harness() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    let expected = sha256_of(&fx, stored_state(&fx, record_id));
+
+    assert_eq!(digest(&fx, DigestScope::Record(record_id)), expected);
+    assert_eq!(digest(&fx, DigestScope::Record(record_id)), expected);
+    assert_ne!(digest(&fx, DigestScope::Record(fx.record(1))), expected);
+}
+
+#[test]
+fn test_single_field_change_flips_record_digest() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    let state = stored_state(&fx, record_id);
+    let before = digest(&fx, DigestScope::Record(record_id));
+
+    let mut changed = state.clone();
+    changed.record.updated_at += 1;
+    assert_ne!(sha256_of(&fx, changed), before);
+    let mut changed = state;
+    changed.version_count += 1;
+    assert_ne!(sha256_of(&fx, changed), before);
+
+    fx.client.update_record(
+        &fx.provider("dr_a"),
+        &record_id,
+        &String::from_str(&fx.env, HASH_B),
+    );
+    let after = digest(&fx, DigestScope::Record(record_id));
+    assert_ne!(after, before);
+    assert_eq!(after, sha256_of(&fx, stored_state(&fx, record_id)));
+}
+
+#[test]
+fn test_patient_digest_covers_each_record() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let expected = PatientState {
+        patient: patient.clone(),
+        record_digests: Vec::from_array(
+            &fx.env,
+            [
+                sha256_of(&fx, stored_state(&fx, fx.record(0))),
+                sha256_of(&fx, stored_state(&fx, fx.record(1))),
+            ],
+        ),
+    };
+    let expected = fx.env.crypto().sha256(&expected.to_xdr(&fx.env)).to_bytes();
+    assert_eq!(digest(&fx, DigestScope::Patient(patient.clone())), expected);
+
+    fx.client.update_record(
+        &fx.provider("dr_a"),
+        &fx.record(1),
+        &String::from_str(&fx.env, HASH_A),
+    );
+    assert_ne!(digest(&fx, DigestScope::Patient(patient)), expected);
+}
+
+#[test]
+fn test_global_digest_tracks_counters_and_config_only() {
+    let fx = setup();
+    let before = digest(&fx, DigestScope::Global);
+    assert_eq!(digest(&fx, DigestScope::Global), before);
+
+    // Reads write audit entries but leave counters and config alone.
+    fx.client.get_record(&fx.patient("pat_a"), &fx.record(0));
+    assert_eq!(digest(&fx, DigestScope::Global), before);
+
+    fx.client.set_expiry_grace(&fx.admin(), &30);
+    let after_config = digest(&fx, DigestScope::Global);
+    assert_ne!(after_config, before);
+
+    fx.client.add_record(
+        &fx.provider("dr_a"),
+        &fx.patient("pat_a"),
+        &fx.provider("dr_a"),
+        &RecordType::Examination,
+        &String::from_str(&fx.env, HASH_A),
+    );
+    assert_ne!(digest(&fx, DigestScope::Global), after_config);
+}
+
+#[test]
+fn test_record_digest_unknown_record() {
+    let fx = setup();
+    assert_err(
+        fx.client.try_get_state_digest(&DigestScope::Record(999)),
+        ContractError::RecordNotFound,
+    );
+}
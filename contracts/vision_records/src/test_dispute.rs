@@ -0,0 +1,264 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{auth, AuthAction, ContractError, DisputeResolution, DisputeStatus, RecordType};
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{testutils::Address as _, Address, String, Symbol};
+
+const RECORD_HASH: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const REASON: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+const RESPONSE: &str = "QmPChd2hVbrJ6bfo3WBcTW4iZnpHm8TEzWkLHmLpXhF68A";
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, RECORD_HASH)
+        .build()
+}
+
+fn text(fx: &TestContract, value: &str) -> String {
+    String::from_str(&fx.env, value)
+}
+
+fn flag(fx: &TestContract) {
+    fx.client
+        .flag_record(&fx.patient("pat_a"), &fx.record(0), &text(fx, REASON));
+}
+
+fn respond(fx: &TestContract) {
+    fx.client
+        .respond_to_dispute(&fx.provider("dr_a"), &fx.record(0), &text(fx, RESPONSE));
+}
+
+#[test]
+fn test_full_dispute_lifecycle() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let provider = fx.provider("dr_a");
+    let record_id = fx.record(0);
+    assert_eq!(fx.client.get_dispute(&patient, &record_id), None);
+
+    let opened = fx
+        .client
+        .flag_record(&patient, &record_id, &text(&fx, REASON));
+    assert_eq!(opened.status, DisputeStatus::Open);
+    assert_eq!(opened.opened_at, FIXTURE_START_TIME);
+    assert_eq!(opened.reason_hash, text(&fx, REASON));
+    assert_eq!(fx.client.get_dispute(&provider, &record_id), Some(opened));
+
+    fx.advance_time(60);
+    let responded = fx
+        .client
+        .respond_to_dispute(&provider, &record_id, &text(&fx, RESPONSE));
+    assert_eq!(responded.status, DisputeStatus::Responded);
+    assert_eq!(responded.response_hash, Some(text(&fx, RESPONSE)));
+    assert_eq!(responded.responded_at, Some(FIXTURE_START_TIME + 60));
+
+    fx.advance_time(60);
+    let resolved = fx
+        .client
+        .resolve_dispute(&provider, &record_id, &DisputeResolution::Amended);
+    assert_eq!(resolved.status, DisputeStatus::Resolved);
+    assert_eq!(resolved.resolution, DisputeResolution::Amended);
+    assert_eq!(resolved.resolved_by, Some(provider.clone()));
+    assert_eq!(resolved.resolved_at, Some(FIXTURE_START_TIME + 120));
+    assert_eq!(fx.client.get_dispute(&patient, &record_id), Some(resolved));
+}
+
+#[test]
+fn test_one_open_dispute_per_record() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    flag(&fx);
+    assert_err(
+        fx.client
+            .try_flag_record(&patient, &fx.record(0), &text(&fx, REASON)),
+        ContractError::AlreadyExists,
+    );
+
+    // Responded disputes are still unresolved
+    respond(&fx);
+    assert_err(
+        fx.client
+            .try_flag_record(&patient, &fx.record(0), &text(&fx, REASON)),
+        ContractError::AlreadyExists,
+    );
+
+    fx.client.resolve_dispute(
+        &fx.provider("dr_a"),
+        &fx.record(0),
+        &DisputeResolution::Upheld,
+    );
+    let reopened = fx
+        .client
+        .flag_record(&patient, &fx.record(0), &text(&fx, RESPONSE));
+    assert_eq!(reopened.status, DisputeStatus::Open);
+    assert_eq!(reopened.resolution, DisputeResolution::Pending);
+}
+
+#[test]
+fn test_only_patient_may_flag() {
+    let fx = setup();
+    assert_err(
+        fx.client
+            .try_flag_record(&fx.provider("dr_a"), &fx.record(0), &text(&fx, REASON)),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client
+            .try_flag_record(&fx.patient("pat_a"), &999, &text(&fx, REASON)),
+        ContractError::RecordNotFound,
+    );
+    assert_err(
+        fx.client
+            .try_flag_record(&fx.patient("pat_a"), &fx.record(0), &text(&fx, "")),
+        ContractError::InvalidInput,
+    );
+}
+
+#[test]
+fn test_respond_transitions() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    assert_err(
+        fx.client
+            .try_respond_to_dispute(&provider, &fx.record(0), &text(&fx, RESPONSE)),
+        ContractError::DisputeNotFound,
+    );
+
+    flag(&fx);
+    assert_err(
+        fx.client
+            .try_respond_to_dispute(&fx.patient("pat_a"), &fx.record(0), &text(&fx, RESPONSE)),
+        ContractError::Unauthorized,
+    );
+    respond(&fx);
+    assert_err(
+        fx.client
+            .try_respond_to_dispute(&provider, &fx.record(0), &text(&fx, RESPONSE)),
+        ContractError::InvalidDisputeStatus,
+    );
+
+    fx.client
+        .resolve_dispute(&provider, &fx.record(0), &DisputeResolution::Upheld);
+    assert_err(
+        fx.client
+            .try_respond_to_dispute(&provider, &fx.record(0), &text(&fx, RESPONSE)),
+        ContractError::InvalidDisputeStatus,
+    );
+}
+
+#[test]
+fn test_resolve_transitions() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    assert_err(
+        fx.client
+            .try_resolve_dispute(&fx.admin(), &record_id, &DisputeResolution::Upheld),
+        ContractError::DisputeNotFound,
+    );
+
+    // An open dispute may be resolved without a response, here by an admin
+    flag(&fx);
+    assert_err(
+        fx.client
+            .try_resolve_dispute(&fx.admin(), &record_id, &DisputeResolution::Pending),
+        ContractError::InvalidInput,
+    );
+    let resolved =
+        fx.client
+            .resolve_dispute(&fx.admin(), &record_id, &DisputeResolution::Withdrawn);
+    assert_eq!(resolved.response_hash, None);
+    assert_eq!(resolved.resolved_by, Some(fx.admin()));
+
+    assert_err(
+        fx.client
+            .try_resolve_dispute(&fx.admin(), &record_id, &DisputeResolution::Amended),
+        ContractError::InvalidDisputeStatus,
+    );
+}
+
+#[test]
+fn test_resolve_requires_provider_or_admin() {
+    let fx = setup();
+    flag(&fx);
+    let outsider = Address::generate(&fx.env);
+    for caller in [outsider, fx.patient("pat_a")] {
+        assert_err(
+            fx.client
+                .try_resolve_dispute(&caller, &fx.record(0), &DisputeResolution::Withdrawn),
+            ContractError::Unauthorized,
+        );
+    }
+}
+
+#[test]
+fn test_open_dispute_blocks_rollback() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    let record_id = fx.record(0);
+    assert!(
+        fx.client
+            .authorize(&provider, &AuthAction::Rollback(record_id))
+            .allowed
+    );
+
+    flag(&fx);
+    let decision = fx
+        .client
+        .authorize(&provider, &AuthAction::Rollback(record_id));
+    assert!(!decision.allowed);
+    assert_eq!(decision.reason, Symbol::new(&fx.env, auth::DISPUTED));
+    // Amending the record is how a dispute gets fixed
+    assert!(
+        fx.client
+            .authorize(&provider, &AuthAction::WriteRecord(record_id))
+            .allowed
+    );
+
+    respond(&fx);
+    assert!(
+        !fx.client
+            .authorize(&provider, &AuthAction::Rollback(record_id))
+            .allowed
+    );
+
+    fx.client
+        .resolve_dispute(&provider, &record_id, &DisputeResolution::Amended);
+    assert!(
+        fx.client
+            .authorize(&provider, &AuthAction::Rollback(record_id))
+            .allowed
+    );
+}
+
+#[test]
+fn test_record_debug_surfaces_dispute() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    assert!(!fx.client.get_record_debug(&fx.admin(), &record_id).disputed);
+
+    flag(&fx);
+    assert!(fx.client.get_record_debug(&fx.admin(), &record_id).disputed);
+
+    fx.client
+        .resolve_dispute(&fx.provider("dr_a"), &record_id, &DisputeResolution::Upheld);
+    assert!(!fx.client.get_record_debug(&fx.admin(), &record_id).disputed);
+}
+
+#[test]
+fn test_get_dispute_requires_read_access() {
+    let fx = setup();
+    flag(&fx);
+    assert_err(
+        fx.client
+            .try_get_dispute(&Address::generate(&fx.env), &fx.record(0)),
+        ContractError::Unauthorized,
+    );
+}
@@ -0,0 +1,175 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{
+    AccessLevel, ConsentType, ContractError, RecordEmbargo, RecordFetchResult, RecordType, Role,
+};
+use crate::embargo::{EMBARGO_SET_WINDOW, MAX_EMBARGO_DURATION};
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{testutils::Address as _, Address, Vec};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const RELEASE_AT: u64 = FIXTURE_START_TIME + 7 * 86_400;
+
+/// `dr_a` wrote one record for `pat_a`, and embargoed it until `RELEASE_AT`.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider_role("staff", Role::Staff)
+        .with_provider("dr_b")
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .build();
+    fx.client
+        .set_record_embargo(&fx.provider("dr_a"), &fx.record(0), &RELEASE_AT);
+    fx
+}
+
+/// Gives the staff member read access through the patient.
+fn grant_staff(fx: &TestContract) {
+    let patient = fx.patient("pat_a");
+    let staff = fx.provider("staff");
+    fx.client
+        .grant_consent(&patient, &staff, &ConsentType::Treatment, &(30 * 86_400));
+    fx.client.grant_access(
+        &patient,
+        &patient,
+        &staff,
+        &AccessLevel::Read,
+        &(30 * 86_400),
+    );
+}
+
+#[test]
+fn test_patient_reads_resume_at_release() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    let patient = fx.patient("pat_a");
+
+    assert_err(
+        fx.client.try_get_record(&patient, &record_id),
+        ContractError::Embargoed,
+    );
+    // The provider and role-based readers are not held back.
+    for reader in [fx.provider("dr_a"), fx.provider("dr_b")] {
+        assert_eq!(fx.client.get_record(&reader, &record_id).id, record_id);
+    }
+
+    fx.set_time(RELEASE_AT - 1);
+    assert_err(
+        fx.client.try_get_record(&patient, &record_id),
+        ContractError::Embargoed,
+    );
+    fx.set_time(RELEASE_AT);
+    assert_eq!(fx.client.get_record(&patient, &record_id).id, record_id);
+}
+
+#[test]
+fn test_grantees_of_the_patient_are_embargoed() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    let staff = fx.provider("staff");
+    grant_staff(&fx);
+
+    assert_err(
+        fx.client.try_get_record(&staff, &record_id),
+        ContractError::Embargoed,
+    );
+    let results = fx
+        .client
+        .get_records(&staff, &Vec::from_array(&fx.env, [record_id]));
+    assert!(matches!(
+        results.get(0),
+        Some(RecordFetchResult::AccessDenied(id)) if id == record_id
+    ));
+
+    fx.client.clear_embargo(&fx.provider("dr_a"), &record_id);
+    assert_eq!(fx.client.get_record(&staff, &record_id).id, record_id);
+    assert_eq!(
+        fx.client.get_record(&fx.patient("pat_a"), &record_id).id,
+        record_id
+    );
+}
+
+#[test]
+fn test_embargo_metadata_visible_without_contents() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    let patient = fx.patient("pat_a");
+    grant_staff(&fx);
+
+    let expected = Some(RecordEmbargo {
+        record_id,
+        provider: fx.provider("dr_a"),
+        release_at: RELEASE_AT,
+        set_at: FIXTURE_START_TIME,
+    });
+    assert!(fx.client.get_patient_records(&patient).contains(record_id));
+    assert_eq!(fx.client.get_record_embargo(&patient, &record_id), expected);
+    assert_eq!(
+        fx.client
+            .get_record_embargo(&fx.provider("staff"), &record_id),
+        expected
+    );
+    assert_err(
+        fx.client
+            .try_get_record_embargo(&Address::generate(&fx.env), &record_id),
+        ContractError::Unauthorized,
+    );
+
+    fx.set_time(RELEASE_AT);
+    assert_eq!(fx.client.get_record_embargo(&patient, &record_id), None);
+}
+
+#[test]
+fn test_only_authoring_provider_sets_embargo_soon_after_creation() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    let provider = fx.provider("dr_a");
+    let now = FIXTURE_START_TIME;
+
+    for caller in [fx.provider("dr_b"), fx.patient("pat_a"), fx.admin()] {
+        assert_err(
+            fx.client
+                .try_set_record_embargo(&caller, &record_id, &RELEASE_AT),
+            ContractError::Unauthorized,
+        );
+        assert_err(
+            fx.client.try_clear_embargo(&caller, &record_id),
+            ContractError::Unauthorized,
+        );
+    }
+    for release_at in [now, now + MAX_EMBARGO_DURATION + 1] {
+        assert_err(
+            fx.client
+                .try_set_record_embargo(&provider, &record_id, &release_at),
+            ContractError::InvalidInput,
+        );
+    }
+
+    // Moving the release date is allowed while the window is open.
+    fx.client
+        .set_record_embargo(&provider, &record_id, &(RELEASE_AT + 86_400));
+    fx.set_time(now + EMBARGO_SET_WINDOW + 1);
+    assert_err(
+        fx.client
+            .try_set_record_embargo(&provider, &record_id, &RELEASE_AT),
+        ContractError::InvalidInput,
+    );
+
+    fx.client.clear_embargo(&provider, &record_id);
+    assert_err(
+        fx.client.try_clear_embargo(&provider, &record_id),
+        ContractError::RecordNotFound,
+    );
+    assert_err(
+        fx.client
+            .try_set_record_embargo(&provider, &99, &RELEASE_AT),
+        ContractError::RecordNotFound,
+    );
+}
@@ -0,0 +1,162 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{
+    AccessLevel, ConsentType, ContractError, LensType, OptionalContactLensData, PrescriptionData,
+    Role,
+};
+use crate::expiry::MAX_EXPIRY_GRACE_SECONDS;
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+const GRACE: u64 = 30;
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .build()
+}
+
+fn text(fx: &TestContract, value: &str) -> String {
+    String::from_str(&fx.env, value)
+}
+
+#[test]
+fn test_grant_boundary_without_grace() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let grantee = Address::generate(&fx.env);
+    fx.client
+        .grant_consent(&patient, &grantee, &ConsentType::Treatment, &7_200);
+    fx.client
+        .grant_access(&patient, &patient, &grantee, &AccessLevel::Read, &3_600);
+
+    fx.set_time(FIXTURE_START_TIME + 3_599);
+    assert_eq!(
+        fx.client.check_access(&patient, &grantee),
+        AccessLevel::Read
+    );
+    fx.set_time(FIXTURE_START_TIME + 3_600);
+    assert_eq!(
+        fx.client.check_access(&patient, &grantee),
+        AccessLevel::None
+    );
+    assert!(fx
+        .client
+        .check_access_detailed(&patient, &grantee)
+        .is_none());
+}
+
+#[test]
+fn test_grace_extends_grants_and_consent() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let grantee = Address::generate(&fx.env);
+    fx.client.set_expiry_grace(&fx.admin(), &GRACE);
+    fx.client
+        .grant_consent(&patient, &grantee, &ConsentType::Treatment, &3_600);
+    fx.client
+        .grant_access(&patient, &patient, &grantee, &AccessLevel::Read, &3_600);
+
+    fx.set_time(FIXTURE_START_TIME + 3_600 + GRACE - 1);
+    assert_eq!(
+        fx.client.check_access(&patient, &grantee),
+        AccessLevel::Read
+    );
+    // Purge agrees with the access check about what has lapsed.
+    assert_eq!(fx.client.purge_expired_grants(&patient, &patient), 0);
+
+    fx.set_time(FIXTURE_START_TIME + 3_600 + GRACE);
+    assert_eq!(
+        fx.client.check_access(&patient, &grantee),
+        AccessLevel::None
+    );
+    assert_eq!(fx.client.purge_expired_grants(&patient, &patient), 1);
+}
+
+#[test]
+fn test_grace_applies_to_sessions_and_delegations() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let session_key = Address::generate(&fx.env);
+    let delegate = Address::generate(&fx.env);
+    fx.client.set_expiry_grace(&fx.admin(), &GRACE);
+    fx.client
+        .create_session(&patient, &session_key, &AccessLevel::Read, &600);
+    fx.client.delegate_role(
+        &patient,
+        &delegate,
+        &Role::Optometrist,
+        &(FIXTURE_START_TIME + 600),
+    );
+
+    fx.set_time(FIXTURE_START_TIME + 600 + GRACE - 1);
+    assert!(fx.client.get_session(&patient, &session_key).is_some());
+    fx.client.get_grant_stats(&delegate, &patient);
+
+    fx.set_time(FIXTURE_START_TIME + 600 + GRACE);
+    assert!(fx.client.get_session(&patient, &session_key).is_none());
+    assert_err(
+        fx.client.try_get_grant_stats(&delegate, &patient),
+        ContractError::Unauthorized,
+    );
+}
+
+#[test]
+fn test_expired_prescription_cannot_be_verified() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    let data = PrescriptionData {
+        sphere: text(&fx, "-2.50"),
+        cylinder: text(&fx, "-1.25"),
+        axis: text(&fx, "180"),
+        add: text(&fx, "0.00"),
+        pd: text(&fx, "62"),
+    };
+    let add = |duration: u64| {
+        fx.client.add_prescription(
+            &fx.patient("pat_a"),
+            &provider,
+            &LensType::Glasses,
+            &data,
+            &data,
+            &OptionalContactLensData::None,
+            &duration,
+            &text(&fx, "metadata_hash"),
+        )
+    };
+    let (short, long) = (add(600), add(3_600));
+
+    fx.client.set_expiry_grace(&fx.admin(), &GRACE);
+    fx.set_time(FIXTURE_START_TIME + 600 + GRACE);
+    assert!(!fx.client.verify_prescription(&short, &provider));
+    assert!(!fx.client.get_prescription(&short).verified);
+    assert!(fx.client.verify_prescription(&long, &provider));
+}
+
+#[test]
+fn test_set_expiry_grace_requires_admin_and_cap() {
+    let fx = setup();
+    assert_eq!(fx.client.get_expiry_grace(), 0);
+
+    assert_err(
+        fx.client
+            .try_set_expiry_grace(&Address::generate(&fx.env), &GRACE),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client
+            .try_set_expiry_grace(&fx.admin(), &(MAX_EXPIRY_GRACE_SECONDS + 1)),
+        ContractError::InvalidInput,
+    );
+
+    fx.client
+        .set_expiry_grace(&fx.admin(), &MAX_EXPIRY_GRACE_SECONDS);
+    assert_eq!(fx.client.get_expiry_grace(), MAX_EXPIRY_GRACE_SECONDS);
+}
@@ -0,0 +1,207 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessLevel, ContractError, ExpiryBeaconEntry, ExpiryBeaconResult};
+use crate::events::topics;
+use crate::expiry_beacon::MAX_BEACONS_PER_CALL;
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Vec};
+
+const HOUR: u64 = 3_600;
+/// Bucket of a one-hour grant made at `FIXTURE_START_TIME`.
+const FIRST_BUCKET: u64 = (FIXTURE_START_TIME + HOUR) / HOUR;
+
+/// `pat_a` may grant `dr_a` and `dr_b`; expiry beacons are on.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_provider("dr_b")
+        .with_patient("pat_a")
+        .build();
+    fx.client.set_expiry_beacons(&fx.admin(), &true);
+    fx
+}
+
+fn grant(fx: &TestContract, grantee: &Address, duration_seconds: u64) {
+    let patient = fx.patient("pat_a");
+    fx.client.grant_access(
+        &patient,
+        &patient,
+        grantee,
+        &AccessLevel::Read,
+        &duration_seconds,
+    );
+}
+
+fn emit(fx: &TestContract, bucket: u64) -> ExpiryBeaconResult {
+    fx.client.emit_expiry_beacons(&fx.admin(), &bucket)
+}
+
+/// Whether the last call published a beacon for `grantee`'s grant.
+fn beaconed(fx: &TestContract, grantee: &Address) -> bool {
+    fx.published((topics::EXP_BCN, fx.patient("pat_a"), grantee.clone()))
+}
+
+fn has_grant(fx: &TestContract, grantee: &Address) -> bool {
+    fx.env.as_contract(&fx.contract_id, || {
+        fx.env.storage().persistent().has(&(
+            symbol_short!("ACCESS"),
+            fx.patient("pat_a"),
+            grantee.clone(),
+        ))
+    })
+}
+
+#[test]
+fn test_beacons_are_emitted_once() {
+    let fx = setup();
+    let (dr_a, dr_b) = (fx.provider("dr_a"), fx.provider("dr_b"));
+    grant(&fx, &dr_a, HOUR);
+    grant(&fx, &dr_b, HOUR);
+    assert_eq!(fx.client.get_expiry_bucket(&FIRST_BUCKET).len(), 2);
+
+    fx.set_time((FIRST_BUCKET + 1) * HOUR);
+    assert_eq!(
+        emit(&fx, FIRST_BUCKET),
+        ExpiryBeaconResult {
+            emitted: 2,
+            remaining: 0,
+        }
+    );
+    assert!(beaconed(&fx, &dr_a) && beaconed(&fx, &dr_b));
+
+    assert_eq!(emit(&fx, FIRST_BUCKET).emitted, 0);
+    assert!(!beaconed(&fx, &dr_a));
+    // Beacons never touch the grants themselves
+    assert!(has_grant(&fx, &dr_a) && has_grant(&fx, &dr_b));
+}
+
+#[test]
+fn test_bucket_must_have_ended() {
+    let fx = setup();
+    grant(&fx, &fx.provider("dr_a"), HOUR);
+
+    // Expired, but the bucket still runs until the top of the next hour
+    fx.set_time(FIXTURE_START_TIME + HOUR);
+    assert_err(
+        fx.client
+            .try_emit_expiry_beacons(&fx.admin(), &FIRST_BUCKET),
+        ContractError::InvalidInput,
+    );
+}
+
+#[test]
+fn test_grants_in_expiry_grace_stay_for_a_later_call() {
+    let fx = setup();
+    let (dr_a, dr_b) = (fx.provider("dr_a"), fx.provider("dr_b"));
+    fx.client.set_expiry_grace(&fx.admin(), &600);
+    grant(&fx, &dr_a, HOUR);
+    // Expires 100 seconds before the bucket ends
+    fx.set_time(FIRST_BUCKET * HOUR - 100);
+    grant(&fx, &dr_b, HOUR);
+
+    fx.set_time((FIRST_BUCKET + 1) * HOUR);
+    assert_eq!(
+        emit(&fx, FIRST_BUCKET),
+        ExpiryBeaconResult {
+            emitted: 1,
+            remaining: 1,
+        }
+    );
+    assert!(beaconed(&fx, &dr_a) && !beaconed(&fx, &dr_b));
+
+    fx.advance_time(600);
+    assert_eq!(
+        emit(&fx, FIRST_BUCKET),
+        ExpiryBeaconResult {
+            emitted: 1,
+            remaining: 0,
+        }
+    );
+    assert!(beaconed(&fx, &dr_b));
+}
+
+#[test]
+fn test_emit_is_bounded_per_call() {
+    let fx = setup();
+    for _ in 0..=MAX_BEACONS_PER_CALL {
+        grant(&fx, &Address::generate(&fx.env), HOUR);
+    }
+
+    fx.set_time((FIRST_BUCKET + 1) * HOUR);
+    assert_eq!(
+        emit(&fx, FIRST_BUCKET),
+        ExpiryBeaconResult {
+            emitted: MAX_BEACONS_PER_CALL,
+            remaining: 1,
+        }
+    );
+    assert_eq!(
+        emit(&fx, FIRST_BUCKET),
+        ExpiryBeaconResult {
+            emitted: 1,
+            remaining: 0,
+        }
+    );
+}
+
+#[test]
+fn test_extended_and_revoked_grants_leave_their_bucket() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let (dr_a, dr_b) = (fx.provider("dr_a"), fx.provider("dr_b"));
+    grant(&fx, &dr_a, HOUR);
+    grant(&fx, &dr_b, HOUR);
+    // Re-granting moves dr_a's expiry two buckets later
+    grant(&fx, &dr_a, 3 * HOUR);
+    fx.client.revoke_access(&patient, &dr_b);
+
+    let later_bucket = (FIXTURE_START_TIME + 3 * HOUR) / HOUR;
+    assert_eq!(
+        fx.client.get_expiry_bucket(&later_bucket),
+        Vec::from_array(
+            &fx.env,
+            [ExpiryBeaconEntry {
+                patient: patient.clone(),
+                grantee: dr_a.clone(),
+            }]
+        )
+    );
+
+    fx.set_time((FIRST_BUCKET + 1) * HOUR);
+    assert_eq!(
+        emit(&fx, FIRST_BUCKET),
+        ExpiryBeaconResult {
+            emitted: 0,
+            remaining: 0,
+        }
+    );
+    assert!(fx.client.get_expiry_bucket(&FIRST_BUCKET).is_empty());
+
+    fx.set_time((later_bucket + 1) * HOUR);
+    assert_eq!(emit(&fx, later_bucket).emitted, 1);
+    assert!(beaconed(&fx, &dr_a));
+    // Revocation leaves dr_b in the patient's grantee list until compaction
+    fx.client.compact_grant_indexes(&patient, &patient);
+    fx.assert_invariants();
+}
+
+#[test]
+fn test_disabled_beacons_index_nothing() {
+    let fx = setup();
+    let dr_a = fx.provider("dr_a");
+    fx.client.set_expiry_beacons(&fx.admin(), &false);
+    assert!(!fx.client.is_expiry_beacons_enabled());
+    grant(&fx, &dr_a, HOUR);
+    assert!(fx.client.get_expiry_bucket(&FIRST_BUCKET).is_empty());
+
+    assert_err(
+        fx.client.try_set_expiry_beacons(&dr_a, &true),
+        ContractError::Unauthorized,
+    );
+}
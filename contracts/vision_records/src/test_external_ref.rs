@@ -0,0 +1,172 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+extern crate std;
+
+use super::{ContractError, RecordType};
+use crate::external_ref::MAX_EXTERNAL_REF_LEN;
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const REF_A: &str = "DiagnosticReport/abc-123";
+const REF_B: &str = "DiagnosticReport/def-456";
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_b")
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .with_record(RecordType::Examination, HASH_A)
+        .build()
+}
+
+fn text(fx: &TestContract, value: &str) -> String {
+    String::from_str(&fx.env, value)
+}
+
+fn add_with_ref(fx: &TestContract, external_ref: &str) -> u64 {
+    let provider = fx.provider("dr_a");
+    fx.client.add_record_with_ref(
+        &provider,
+        &fx.patient("pat_a"),
+        &provider,
+        &RecordType::Examination,
+        &text(fx, HASH_A),
+        &text(fx, external_ref),
+    )
+}
+
+#[test]
+fn test_lookup_by_external_ref() {
+    let fx = setup();
+    let record_id = add_with_ref(&fx, REF_A);
+
+    assert_eq!(
+        fx.client.get_external_ref(&record_id),
+        Some(text(&fx, REF_A))
+    );
+    let record = fx
+        .client
+        .get_record_by_external_ref(&fx.patient("pat_a"), &text(&fx, REF_A));
+    assert_eq!(record.id, record_id);
+
+    // Lookup needs the same read access as the record itself.
+    assert_err(
+        fx.client
+            .try_get_record_by_external_ref(&Address::generate(&fx.env), &text(&fx, REF_A)),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client
+            .try_get_record_by_external_ref(&fx.patient("pat_a"), &text(&fx, REF_B)),
+        ContractError::RecordNotFound,
+    );
+}
+
+#[test]
+fn test_external_ref_must_be_unique() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    add_with_ref(&fx, REF_A);
+    let count = fx.client.get_record_count();
+
+    assert_err(
+        fx.client.try_add_record_with_ref(
+            &provider,
+            &fx.patient("pat_a"),
+            &provider,
+            &RecordType::Examination,
+            &text(&fx, HASH_A),
+            &text(&fx, REF_A),
+        ),
+        ContractError::DuplicateExternalRef,
+    );
+    assert_eq!(fx.client.get_record_count(), count);
+
+    assert_err(
+        fx.client
+            .try_set_external_ref(&provider, &fx.record(0), &text(&fx, REF_A)),
+        ContractError::DuplicateExternalRef,
+    );
+}
+
+#[test]
+fn test_external_ref_immutable_unless_admin() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    let (first, second) = (fx.record(0), fx.record(1));
+
+    fx.client
+        .set_external_ref(&provider, &first, &text(&fx, REF_A));
+    // Setting the same reference again is a no-op.
+    fx.client
+        .set_external_ref(&provider, &first, &text(&fx, REF_A));
+    assert_err(
+        fx.client
+            .try_set_external_ref(&provider, &first, &text(&fx, REF_B)),
+        ContractError::Unauthorized,
+    );
+
+    fx.client
+        .set_external_ref(&fx.admin(), &first, &text(&fx, REF_B));
+    assert_eq!(fx.client.get_external_ref(&first), Some(text(&fx, REF_B)));
+    assert_err(
+        fx.client
+            .try_get_record_by_external_ref(&fx.patient("pat_a"), &text(&fx, REF_A)),
+        ContractError::RecordNotFound,
+    );
+
+    // The replaced reference is free for another record.
+    fx.client
+        .set_external_ref(&provider, &second, &text(&fx, REF_A));
+    assert_eq!(
+        fx.client
+            .get_record_by_external_ref(&fx.patient("pat_a"), &text(&fx, REF_A))
+            .id,
+        second
+    );
+}
+
+#[test]
+fn test_set_external_ref_requires_write_access() {
+    let fx = setup();
+
+    assert_err(
+        fx.client
+            .try_set_external_ref(&fx.provider("dr_b"), &fx.record(0), &text(&fx, REF_A)),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client
+            .try_set_external_ref(&fx.patient("pat_a"), &fx.record(0), &text(&fx, REF_A)),
+        ContractError::Unauthorized,
+    );
+}
+
+#[test]
+fn test_external_ref_length_cap() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    let longest = "x".repeat(MAX_EXTERNAL_REF_LEN as usize);
+    let too_long = "x".repeat(MAX_EXTERNAL_REF_LEN as usize + 1);
+
+    assert_err(
+        fx.client
+            .try_set_external_ref(&provider, &fx.record(0), &text(&fx, &too_long)),
+        ContractError::InvalidInput,
+    );
+    assert_err(
+        fx.client
+            .try_set_external_ref(&provider, &fx.record(0), &text(&fx, "")),
+        ContractError::InvalidInput,
+    );
+    fx.client
+        .set_external_ref(&provider, &fx.record(0), &text(&fx, &longest));
+}
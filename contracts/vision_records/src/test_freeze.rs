@@ -0,0 +1,230 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{
+    grant_index, has_active_consent, AccessLevel, AccessSweepResult, ConsentType, ContractError,
+    Permission, RecordType, Role, VisionRecordsContract, VisionRecordsContractClient,
+};
+use crate::testutils::assert_err;
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+
+fn setup() -> (Env, VisionRecordsContractClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VisionRecordsContract, ());
+    let client = VisionRecordsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &None);
+
+    (env, client, admin)
+}
+
+fn has_access_grant(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    patient: &Address,
+    grantee: &Address,
+) -> bool {
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .has(&(symbol_short!("ACCESS"), patient.clone(), grantee.clone()))
+    })
+}
+
+// ======================== Grantee Revocation Sweep ========================
+
+#[test]
+fn test_grantee_index_tracks_grants_and_revocations() {
+    let (env, client, _admin) = setup();
+    let doctor = Address::generate(&env);
+    let p1 = Address::generate(&env);
+    let p2 = Address::generate(&env);
+
+    client.grant_access(&p1, &p1, &doctor, &AccessLevel::Read, &86400);
+    client.grant_access(&p2, &p2, &doctor, &AccessLevel::Write, &86400);
+    // Re-granting does not duplicate the index entry.
+    client.grant_access(&p1, &p1, &doctor, &AccessLevel::Write, &86400);
+    assert_eq!(client.get_grantee_patients(&doctor).len(), 2);
+
+    client.revoke_access(&p1, &doctor);
+    let patients = client.get_grantee_patients(&doctor);
+    assert_eq!(patients.len(), 1);
+    assert_eq!(patients.get(0).unwrap(), p2);
+}
+
+#[test]
+fn test_revoke_all_access_for_grantee_resumes_with_limit() {
+    let (env, client, admin) = setup();
+    let doctor = Address::generate(&env);
+    let p1 = Address::generate(&env);
+    let p2 = Address::generate(&env);
+    let p3 = Address::generate(&env);
+
+    for patient in [&p1, &p2, &p3] {
+        client.grant_access(patient, patient, &doctor, &AccessLevel::Read, &86400);
+    }
+
+    let first = client.revoke_all_access_for_grantee(&admin, &doctor, &2);
+    assert_eq!(
+        first,
+        AccessSweepResult {
+            revoked: 2,
+            remaining: 1
+        }
+    );
+    assert!(!has_access_grant(&env, &client, &p1, &doctor));
+    assert!(!has_access_grant(&env, &client, &p2, &doctor));
+    assert!(has_access_grant(&env, &client, &p3, &doctor));
+
+    let second = client.revoke_all_access_for_grantee(&admin, &doctor, &2);
+    assert_eq!(
+        second,
+        AccessSweepResult {
+            revoked: 1,
+            remaining: 0
+        }
+    );
+    assert!(!has_access_grant(&env, &client, &p3, &doctor));
+    assert_eq!(client.get_grantee_patients(&doctor).len(), 0);
+}
+
+#[test]
+fn test_sweep_clears_grantee_list_consent_and_scheduled_revocation() {
+    let (env, client, admin) = setup();
+    let doctor = Address::generate(&env);
+    let p1 = Address::generate(&env);
+    let p2 = Address::generate(&env);
+    for patient in [&p1, &p2] {
+        client.grant_access(patient, patient, &doctor, &AccessLevel::Read, &86400);
+        client.grant_consent(patient, &doctor, &ConsentType::Treatment, &86400);
+    }
+    // p2's revocation is still inside its grace window when the sweep runs
+    client.set_revocation_grace(&admin, &3600);
+    client.revoke_access(&p2, &doctor);
+    assert!(client.get_pending_revocation(&p2, &doctor).is_some());
+
+    client.revoke_all_access_for_grantee(&admin, &doctor, &10);
+    for patient in [&p1, &p2] {
+        assert!(!has_access_grant(&env, &client, patient, &doctor));
+        env.as_contract(&client.address, || {
+            assert!(grant_index::get_list(&env, patient).is_empty());
+            assert!(!has_active_consent(&env, patient, &doctor));
+        });
+    }
+    assert!(client.get_pending_revocation(&p2, &doctor).is_none());
+}
+
+#[test]
+fn test_revoke_all_access_for_grantee_requires_admin() {
+    let (env, client, _admin) = setup();
+    let doctor = Address::generate(&env);
+    let patient = Address::generate(&env);
+    client.grant_access(&patient, &patient, &doctor, &AccessLevel::Read, &86400);
+
+    let res = client.try_revoke_all_access_for_grantee(&patient, &doctor, &10);
+    assert_eq!(res, Err(Ok(ContractError::Unauthorized)));
+    assert!(has_access_grant(&env, &client, &patient, &doctor));
+}
+
+// ======================== Freeze ========================
+
+#[test]
+fn test_frozen_but_granted_user_is_denied_reads() {
+    let (env, client, admin) = setup();
+    let provider = Address::generate(&env);
+    let patient = Address::generate(&env);
+    let reader = Address::generate(&env);
+    client.register_user(
+        &admin,
+        &provider,
+        &Role::Optometrist,
+        &String::from_str(&env, "Dr. Provider"),
+    );
+    let record_id = client.add_record(
+        &provider,
+        &patient,
+        &provider,
+        &RecordType::Examination,
+        &String::from_str(&env, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"),
+    );
+    client.grant_record_access(&patient, &reader, &record_id, &AccessLevel::Read, &86400);
+    client.get_record(&reader, &record_id);
+
+    client.freeze_user(&admin, &reader);
+    assert!(client.is_user_frozen(&reader));
+    assert_eq!(
+        client.check_record_access(&record_id, &reader),
+        AccessLevel::None
+    );
+    assert_err(
+        client.try_get_record(&reader, &record_id),
+        ContractError::Unauthorized,
+    );
+
+    // The grant itself is untouched and applies again once unfrozen.
+    client.unfreeze_user(&admin, &reader);
+    assert!(!client.is_user_frozen(&reader));
+    assert_eq!(
+        client.check_record_access(&record_id, &reader),
+        AccessLevel::Read
+    );
+    client.get_record(&reader, &record_id);
+}
+
+#[test]
+fn test_frozen_user_loses_role_permissions() {
+    let (env, client, admin) = setup();
+    let provider = Address::generate(&env);
+    client.register_user(
+        &admin,
+        &provider,
+        &Role::Optometrist,
+        &String::from_str(&env, "Dr. Provider"),
+    );
+    assert!(client.check_permission(&provider, &Permission::WriteRecord));
+
+    client.freeze_user(&admin, &provider);
+    assert!(!client.check_permission(&provider, &Permission::WriteRecord));
+    let res = client.try_add_record(
+        &provider,
+        &Address::generate(&env),
+        &provider,
+        &RecordType::Examination,
+        &String::from_str(&env, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"),
+    );
+    assert_eq!(res, Err(Ok(ContractError::Unauthorized)));
+}
+
+#[test]
+fn test_freeze_requires_admin() {
+    let (env, client, _admin) = setup();
+    let outsider = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let res = client.try_freeze_user(&outsider, &user);
+    assert_eq!(res, Err(Ok(ContractError::Unauthorized)));
+    assert!(!client.is_user_frozen(&user));
+}
+
+#[test]
+fn test_frozen_admin_is_denied_admin_actions() {
+    let (env, client, admin) = setup();
+    let user = Address::generate(&env);
+
+    client.freeze_user(&admin, &admin);
+    let res = client.try_freeze_user(&admin, &user);
+    assert_eq!(res, Err(Ok(ContractError::Unauthorized)));
+    // Nor can it lift its own freeze
+    let res = client.try_unfreeze_user(&admin, &admin);
+    assert_eq!(res, Err(Ok(ContractError::Unauthorized)));
+    let res = client.try_set_counter_shards(&admin, &4);
+    assert_eq!(res, Err(Ok(ContractError::Unauthorized)));
+    assert!(client.is_user_frozen(&admin));
+}
@@ -0,0 +1,179 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessLevel, ConsentType, ContractError};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::Address;
+
+const HOUR: u64 = 3_600;
+
+/// `dr_a` holds the patient's `Full` grant for two hours; `dr_b` has the
+/// patient's consent but no grant.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_provider("dr_b")
+        .with_provider("dr_c")
+        .with_patient("pat_a")
+        .build();
+    let patient = fx.patient("pat_a");
+    let dr_a = fx.provider("dr_a");
+    fx.client
+        .grant_access(&patient, &patient, &dr_a, &AccessLevel::Full, &(2 * HOUR));
+    for provider in [dr_a, fx.provider("dr_b")] {
+        fx.client
+            .grant_consent(&patient, &provider, &ConsentType::Treatment, &(2 * HOUR));
+    }
+    fx
+}
+
+fn share(
+    fx: &TestContract,
+    caller: &str,
+    grantee: &Address,
+    level: AccessLevel,
+    duration: u64,
+) -> Result<(), ContractError> {
+    match fx.client.try_grant_access(
+        &fx.provider(caller),
+        &fx.patient("pat_a"),
+        grantee,
+        &level,
+        &duration,
+    ) {
+        Ok(Ok(())) => Ok(()),
+        Err(Ok(err)) => Err(err),
+        other => panic!("unexpected result {:?}", other),
+    }
+}
+
+#[test]
+fn test_full_grantee_shares_read_access() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let dr_b = fx.provider("dr_b");
+
+    assert_eq!(share(&fx, "dr_a", &dr_b, AccessLevel::Read, HOUR), Ok(()));
+    assert_eq!(fx.client.check_access(&patient, &dr_b), AccessLevel::Read);
+    let detail = fx.client.check_access_detailed(&patient, &dr_b).unwrap();
+    assert_eq!(detail.created_by, fx.provider("dr_a"));
+
+    let own = fx
+        .client
+        .check_access_detailed(&patient, &fx.provider("dr_a"))
+        .unwrap();
+    assert_eq!(own.created_by, patient);
+}
+
+#[test]
+fn test_full_grantee_cannot_grant_above_read() {
+    let fx = setup();
+    let dr_b = fx.provider("dr_b");
+
+    for level in [AccessLevel::Write, AccessLevel::Full] {
+        assert_eq!(
+            share(&fx, "dr_a", &dr_b, level, HOUR),
+            Err(ContractError::Unauthorized)
+        );
+    }
+    assert!(fx
+        .client
+        .check_access_detailed(&fx.patient("pat_a"), &dr_b)
+        .is_none());
+}
+
+#[test]
+fn test_shared_grant_cannot_outlive_own_grant() {
+    let fx = setup();
+    let dr_b = fx.provider("dr_b");
+
+    assert_eq!(
+        share(&fx, "dr_a", &dr_b, AccessLevel::Read, 2 * HOUR + 1),
+        Err(ContractError::DurationTooLong)
+    );
+    fx.advance_time(HOUR);
+    assert_eq!(
+        share(&fx, "dr_a", &dr_b, AccessLevel::Read, HOUR + 1),
+        Err(ContractError::DurationTooLong)
+    );
+    assert_eq!(share(&fx, "dr_a", &dr_b, AccessLevel::Read, HOUR), Ok(()));
+}
+
+#[test]
+fn test_only_full_grantees_may_share() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let dr_b = fx.provider("dr_b");
+    let dr_c = fx.provider("dr_c");
+    fx.client
+        .grant_access(&patient, &patient, &dr_b, &AccessLevel::Write, &HOUR);
+
+    assert_eq!(
+        share(&fx, "dr_b", &dr_c, AccessLevel::Read, HOUR),
+        Err(ContractError::Unauthorized)
+    );
+}
+
+#[test]
+fn test_full_grantee_cannot_replace_patient_grant() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let dr_b = fx.provider("dr_b");
+    fx.client
+        .grant_access(&patient, &patient, &dr_b, &AccessLevel::Write, &HOUR);
+
+    assert_eq!(
+        share(&fx, "dr_a", &dr_b, AccessLevel::Read, HOUR),
+        Err(ContractError::Unauthorized)
+    );
+    assert_eq!(fx.client.check_access(&patient, &dr_b), AccessLevel::Write);
+}
+
+#[test]
+fn test_full_grantee_revokes_only_own_grants() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let dr_a = fx.provider("dr_a");
+    let dr_b = fx.provider("dr_b");
+    let dr_c = fx.provider("dr_c");
+    share(&fx, "dr_a", &dr_b, AccessLevel::Read, HOUR).unwrap();
+    fx.client
+        .grant_access(&patient, &patient, &dr_c, &AccessLevel::Read, &HOUR);
+
+    assert_err(
+        fx.client.try_revoke_created_grant(&dr_a, &patient, &dr_c),
+        ContractError::Unauthorized,
+    );
+    fx.client.revoke_created_grant(&dr_a, &patient, &dr_b);
+    assert_eq!(fx.client.check_access(&patient, &dr_b), AccessLevel::None);
+    assert_err(
+        fx.client.try_revoke_created_grant(&dr_a, &patient, &dr_b),
+        ContractError::AccessGrantNotFound,
+    );
+}
+
+#[test]
+fn test_revoking_full_grant_ends_sharing_at_once() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let dr_a = fx.provider("dr_a");
+    let dr_b = fx.provider("dr_b");
+    share(&fx, "dr_a", &dr_b, AccessLevel::Read, HOUR).unwrap();
+
+    fx.client.revoke_access(&patient, &dr_a);
+    assert_eq!(
+        share(&fx, "dr_a", &fx.provider("dr_c"), AccessLevel::Read, HOUR),
+        Err(ContractError::Unauthorized)
+    );
+    assert_err(
+        fx.client.try_revoke_created_grant(&dr_a, &patient, &dr_b),
+        ContractError::Unauthorized,
+    );
+    // Grants already made stand until the patient revokes them
+    assert_eq!(fx.client.check_access(&patient, &dr_b), AccessLevel::Read);
+}
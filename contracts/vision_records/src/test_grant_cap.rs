@@ -0,0 +1,223 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessLevel, AccessTemplateEntry, BatchGrantInput, ContractError, RecordType, Role};
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Vec};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const YEAR: u64 = 31_536_000;
+
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .build();
+    fx.client.set_max_grant_duration(&fx.admin(), &YEAR);
+    fx
+}
+
+fn expires_at(fx: &TestContract, grantee: &Address) -> Option<u64> {
+    fx.client
+        .check_access_detailed(&fx.patient("pat_a"), grantee)
+        .map(|grant| grant.expires_at)
+}
+
+#[test]
+fn test_direct_grants_capped_at_boundary() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let grantee = Address::generate(&fx.env);
+
+    assert_err(
+        fx.client.try_grant_access(
+            &patient,
+            &patient,
+            &grantee,
+            &AccessLevel::Read,
+            &(YEAR + 1),
+        ),
+        ContractError::DurationTooLong,
+    );
+    assert_eq!(expires_at(&fx, &grantee), None);
+    fx.client
+        .grant_access(&patient, &patient, &grantee, &AccessLevel::Read, &YEAR);
+    assert_eq!(expires_at(&fx, &grantee), Some(FIXTURE_START_TIME + YEAR));
+
+    assert_err(
+        fx.client.try_grant_record_access(
+            &patient,
+            &grantee,
+            &fx.record(0),
+            &AccessLevel::Read,
+            &(YEAR + 1),
+        ),
+        ContractError::DurationTooLong,
+    );
+    fx.client
+        .grant_record_access(&patient, &grantee, &fx.record(0), &AccessLevel::Read, &YEAR);
+}
+
+#[test]
+fn test_offers_batches_and_templates_capped() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let grantee = Address::generate(&fx.env);
+
+    assert_err(
+        fx.client.try_grant_access_pending_acceptance(
+            &patient,
+            &grantee,
+            &AccessLevel::Read,
+            &(YEAR + 1),
+        ),
+        ContractError::DurationTooLong,
+    );
+
+    let batch = Vec::from_array(
+        &fx.env,
+        [
+            BatchGrantInput {
+                grantee: grantee.clone(),
+                level: AccessLevel::Read,
+                duration_seconds: YEAR,
+            },
+            BatchGrantInput {
+                grantee: Address::generate(&fx.env),
+                level: AccessLevel::Read,
+                duration_seconds: YEAR + 1,
+            },
+        ],
+    );
+    assert_err(
+        fx.client.try_grant_access_batch(&patient, &batch),
+        ContractError::DurationTooLong,
+    );
+    assert_eq!(expires_at(&fx, &grantee), None);
+
+    // Templates are checked when applied, since the cap may have changed.
+    let name = symbol_short!("long");
+    fx.client.set_max_grant_duration(&fx.admin(), &0);
+    fx.client.create_access_template(
+        &patient,
+        &name,
+        &Vec::from_array(
+            &fx.env,
+            [AccessTemplateEntry {
+                level: AccessLevel::Read,
+                duration_seconds: YEAR + 1,
+            }],
+        ),
+    );
+    fx.client.set_max_grant_duration(&fx.admin(), &YEAR);
+    assert_err(
+        fx.client.try_apply_access_template(
+            &patient,
+            &name,
+            &Vec::from_array(&fx.env, [grantee.clone()]),
+        ),
+        ContractError::DurationTooLong,
+    );
+}
+
+#[test]
+fn test_admin_override_exceeds_cap() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let admin = fx.admin();
+    let grantee = Address::generate(&fx.env);
+
+    assert_err(
+        fx.client
+            .try_grant_access(&admin, &patient, &grantee, &AccessLevel::Read, &(YEAR + 1)),
+        ContractError::DurationTooLong,
+    );
+    assert_err(
+        fx.client.try_grant_access_with_override(
+            &admin,
+            &patient,
+            &grantee,
+            &AccessLevel::Read,
+            &(YEAR + 1),
+            &false,
+        ),
+        ContractError::DurationTooLong,
+    );
+    // Only a SystemAdmin may use the override, not even the patient.
+    for caller in [patient.clone(), fx.provider("dr_a")] {
+        assert_err(
+            fx.client.try_grant_access_with_override(
+                &caller,
+                &patient,
+                &grantee,
+                &AccessLevel::Read,
+                &(YEAR + 1),
+                &true,
+            ),
+            ContractError::Unauthorized,
+        );
+    }
+
+    fx.client.grant_access_with_override(
+        &admin,
+        &patient,
+        &grantee,
+        &AccessLevel::Read,
+        &(YEAR + 1),
+        &true,
+    );
+    assert_eq!(
+        expires_at(&fx, &grantee),
+        Some(FIXTURE_START_TIME + YEAR + 1)
+    );
+}
+
+#[test]
+fn test_max_grant_duration_config() {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider_role("staff", Role::Staff)
+        .with_patient("pat_a")
+        .build();
+    let patient = fx.patient("pat_a");
+    assert_eq!(fx.client.get_max_grant_duration(), 0);
+
+    // Unlimited by default, up to the hard validation limit.
+    let grantee = Address::generate(&fx.env);
+    fx.client.grant_access(
+        &patient,
+        &patient,
+        &grantee,
+        &AccessLevel::Read,
+        &(5 * YEAR),
+    );
+
+    assert_err(
+        fx.client
+            .try_set_max_grant_duration(&fx.provider("staff"), &YEAR),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_set_max_grant_duration(&fx.admin(), &60),
+        ContractError::InvalidInput,
+    );
+    fx.client.set_max_grant_duration(&fx.admin(), &YEAR);
+    assert_eq!(fx.client.get_max_grant_duration(), YEAR);
+    assert_err(
+        fx.client
+            .try_set_auto_grant_on_registration(&fx.admin(), &Some((AccessLevel::Read, YEAR + 1))),
+        ContractError::DurationTooLong,
+    );
+
+    // Existing grants are left alone.
+    assert_eq!(
+        expires_at(&fx, &grantee),
+        Some(FIXTURE_START_TIME + 5 * YEAR)
+    );
+}
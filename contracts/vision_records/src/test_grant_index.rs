@@ -0,0 +1,195 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessLevel, ContractError, GrantIndexCompaction};
+use crate::grant_index::{self, MAX_COMPACT_INDEX_SIZE};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use crate::{grantee_index_key, remove_from_grantee_index};
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Vec};
+
+const DAY: u64 = 86_400;
+
+fn setup() -> TestContract {
+    Fixture::new().with_admin().with_patient("pat_a").build()
+}
+
+/// Grants `pat_a`'s chart to a fresh grantee.
+fn grant(fx: &TestContract) -> Address {
+    let patient = fx.patient("pat_a");
+    let grantee = Address::generate(&fx.env);
+    fx.client
+        .grant_access(&patient, &patient, &grantee, &AccessLevel::Read, &DAY);
+    grantee
+}
+
+fn compact(fx: &TestContract) -> GrantIndexCompaction {
+    let patient = fx.patient("pat_a");
+    fx.client.compact_grant_indexes(&patient, &patient)
+}
+
+fn verify(fx: &TestContract) -> bool {
+    fx.client.verify_grant_indexes(&fx.patient("pat_a"))
+}
+
+fn listed(fx: &TestContract) -> Vec<Address> {
+    fx.env.as_contract(&fx.contract_id, || {
+        grant_index::get_list(&fx.env, &fx.patient("pat_a"))
+    })
+}
+
+/// Test-only hook: overwrites `pat_a`'s grantee list.
+fn write_list(fx: &TestContract, grantees: &Vec<Address>) {
+    fx.env.as_contract(&fx.contract_id, || {
+        fx.env
+            .storage()
+            .persistent()
+            .set(&(symbol_short!("ACC_LST"), fx.patient("pat_a")), grantees);
+    });
+}
+
+/// Test-only hook: overwrites a grantee's reverse index.
+fn write_reverse(fx: &TestContract, grantee: &Address, patients: &Vec<Address>) {
+    fx.env.as_contract(&fx.contract_id, || {
+        fx.env
+            .storage()
+            .persistent()
+            .set(&grantee_index_key(grantee), patients);
+    });
+}
+
+#[test]
+fn test_revocations_leave_stale_grantees_until_compacted() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let kept = grant(&fx);
+    let revoked = grant(&fx);
+    let swept = grant(&fx);
+    assert!(verify(&fx));
+
+    // The admin sweep prunes the patient's grantee list; a plain
+    // revocation does not.
+    fx.client
+        .revoke_all_access_for_grantee(&fx.admin(), &swept, &10);
+    assert_eq!(listed(&fx).len(), 2);
+    assert!(verify(&fx));
+    fx.client.revoke_access(&patient, &revoked);
+    assert_eq!(listed(&fx).len(), 2);
+    assert!(!verify(&fx));
+
+    assert_eq!(
+        compact(&fx),
+        GrantIndexCompaction {
+            grantees_removed: 1,
+            reverse_removed: 0,
+            reverse_restored: 0,
+        }
+    );
+    assert!(verify(&fx));
+    assert_eq!(listed(&fx), Vec::from_array(&fx.env, [kept.clone()]));
+    assert!(fx.client.get_grantee_patients(&kept).contains(&patient));
+
+    // A second run finds nothing left to do.
+    assert_eq!(
+        compact(&fx),
+        GrantIndexCompaction {
+            grantees_removed: 0,
+            reverse_removed: 0,
+            reverse_restored: 0,
+        }
+    );
+}
+
+#[test]
+fn test_corrupted_reverse_index_is_rebuilt() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let other = Address::generate(&fx.env);
+    let live = grant(&fx);
+    let revoked = grant(&fx);
+    fx.client.revoke_access(&patient, &revoked);
+
+    // Drop the live pair from its reverse index and leave the revoked pair
+    // listed twice alongside another patient.
+    fx.env.as_contract(&fx.contract_id, || {
+        remove_from_grantee_index(&fx.env, &live, &patient);
+    });
+    write_reverse(
+        &fx,
+        &revoked,
+        &Vec::from_array(&fx.env, [patient.clone(), other.clone(), patient.clone()]),
+    );
+    assert!(!verify(&fx));
+
+    assert_eq!(
+        compact(&fx),
+        GrantIndexCompaction {
+            grantees_removed: 1,
+            reverse_removed: 2,
+            reverse_restored: 1,
+        }
+    );
+    assert!(verify(&fx));
+    assert_eq!(
+        fx.client.get_grantee_patients(&live),
+        Vec::from_array(&fx.env, [patient])
+    );
+    assert_eq!(
+        fx.client.get_grantee_patients(&revoked),
+        Vec::from_array(&fx.env, [other])
+    );
+}
+
+#[test]
+fn test_duplicate_grantees_are_collapsed() {
+    let fx = setup();
+    let grantee = grant(&fx);
+    write_list(
+        &fx,
+        &Vec::from_array(&fx.env, [grantee.clone(), grantee.clone()]),
+    );
+    assert!(!verify(&fx));
+
+    assert_eq!(compact(&fx).grantees_removed, 1);
+    assert!(verify(&fx));
+    assert_eq!(listed(&fx), Vec::from_array(&fx.env, [grantee]));
+}
+
+#[test]
+fn test_oversized_index_is_refused() {
+    let fx = setup();
+    let mut grantees = Vec::new(&fx.env);
+    for _ in 0..=MAX_COMPACT_INDEX_SIZE {
+        grantees.push_back(Address::generate(&fx.env));
+    }
+    write_list(&fx, &grantees);
+
+    let patient = fx.patient("pat_a");
+    assert_err(
+        fx.client.try_compact_grant_indexes(&patient, &patient),
+        ContractError::IndexTooLarge,
+    );
+    assert_eq!(listed(&fx).len(), MAX_COMPACT_INDEX_SIZE + 1);
+}
+
+#[test]
+fn test_compaction_access() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let revoked = grant(&fx);
+    fx.client.revoke_access(&patient, &revoked);
+
+    let stranger = Address::generate(&fx.env);
+    assert_err(
+        fx.client.try_compact_grant_indexes(&stranger, &patient),
+        ContractError::Unauthorized,
+    );
+    assert!(!verify(&fx));
+
+    let result = fx.client.compact_grant_indexes(&fx.admin(), &patient);
+    assert_eq!(result.grantees_removed, 1);
+    assert!(verify(&fx));
+}
@@ -0,0 +1,169 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessLevel, BatchGrantInput, ConsentType, RecordType, Role};
+use crate::testutils::{Fixture, TestContract};
+use soroban_sdk::{Address, Vec};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const DAY: u64 = 86_400;
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider_role("staff", Role::Staff)
+        .with_provider_role("clerk", Role::Staff)
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .build()
+}
+
+fn revision(fx: &TestContract, grantee: &Address) -> u64 {
+    fx.client.get_grant_revision(&fx.patient("pat_a"), grantee)
+}
+
+fn epoch(fx: &TestContract) -> u64 {
+    fx.client.get_access_epoch(&fx.patient("pat_a"))
+}
+
+fn grant(fx: &TestContract, grantee: &Address) {
+    let patient = fx.patient("pat_a");
+    fx.client
+        .grant_access(&patient, &patient, grantee, &AccessLevel::Read, &DAY);
+}
+
+#[test]
+fn test_grant_bumps_only_its_pair_and_reads_do_not() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let staff = fx.provider("staff");
+    let clerk = fx.provider("clerk");
+    assert_eq!(revision(&fx, &staff), 0);
+
+    fx.client
+        .grant_consent(&patient, &staff, &ConsentType::Treatment, &DAY);
+    grant(&fx, &staff);
+    assert_eq!(revision(&fx, &staff), 2);
+    assert_eq!(revision(&fx, &clerk), 0);
+    assert_eq!(epoch(&fx), 0);
+
+    fx.client.check_access(&patient, &staff);
+    fx.client.check_access_detailed(&patient, &staff);
+    fx.client.get_record(&staff, &fx.record(0));
+    assert_eq!(revision(&fx, &staff), 2);
+    assert_eq!(epoch(&fx), 0);
+
+    // Re-granting changes the grant's expiry, so it counts
+    fx.advance_time(60);
+    grant(&fx, &staff);
+    assert_eq!(revision(&fx, &staff), 3);
+}
+
+#[test]
+fn test_revocation_paths_bump_revision() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let staff = fx.provider("staff");
+    fx.client.set_revocation_grace(&fx.admin(), &600);
+    grant(&fx, &staff);
+
+    fx.client.revoke_access(&patient, &staff);
+    assert_eq!(revision(&fx, &staff), 2);
+    fx.client.cancel_revocation(&patient, &staff);
+    assert_eq!(revision(&fx, &staff), 3);
+    fx.client.revoke_access_immediate(&patient, &staff);
+    assert_eq!(revision(&fx, &staff), 4);
+
+    fx.client
+        .grant_consent(&patient, &staff, &ConsentType::Treatment, &DAY);
+    fx.client.revoke_consent(&patient, &staff);
+    assert_eq!(revision(&fx, &staff), 6);
+    // Revoking consent that is already revoked changes nothing
+    fx.client.revoke_consent(&patient, &staff);
+    assert_eq!(revision(&fx, &staff), 6);
+    assert_eq!(epoch(&fx), 0);
+}
+
+#[test]
+fn test_record_grants_and_overrides_bump_revision() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let staff = fx.provider("staff");
+    let record_id = fx.record(0);
+
+    fx.client
+        .grant_record_access(&patient, &staff, &record_id, &AccessLevel::Read, &DAY);
+    assert_eq!(revision(&fx, &staff), 1);
+    fx.client
+        .set_record_access_override(&patient, &staff, &record_id, &0);
+    assert_eq!(revision(&fx, &staff), 2);
+    fx.client
+        .clear_record_access_override(&patient, &staff, &record_id);
+    assert_eq!(revision(&fx, &staff), 3);
+    fx.client.revoke_record_access(&patient, &staff, &record_id);
+    assert_eq!(revision(&fx, &staff), 4);
+    assert_eq!(epoch(&fx), 0);
+}
+
+#[test]
+fn test_bulk_paths_bump_each_pair() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let staff = fx.provider("staff");
+    let clerk = fx.provider("clerk");
+
+    fx.client.grant_access_batch(
+        &patient,
+        &Vec::from_array(
+            &fx.env,
+            [
+                BatchGrantInput {
+                    grantee: staff.clone(),
+                    level: AccessLevel::Read,
+                    duration_seconds: DAY,
+                },
+                BatchGrantInput {
+                    grantee: clerk.clone(),
+                    level: AccessLevel::Read,
+                    duration_seconds: 2 * DAY,
+                },
+            ],
+        ),
+    );
+    assert_eq!(revision(&fx, &staff), 1);
+    assert_eq!(revision(&fx, &clerk), 1);
+
+    grant(&fx, &staff);
+    fx.advance_time(DAY + 1);
+    fx.client.purge_expired_grants(&patient, &patient);
+    assert_eq!(revision(&fx, &staff), 3);
+    assert_eq!(revision(&fx, &clerk), 1);
+
+    fx.client
+        .revoke_all_access_for_grantee(&fx.admin(), &clerk, &10);
+    assert_eq!(revision(&fx, &clerk), 2);
+    assert_eq!(epoch(&fx), 0);
+}
+
+#[test]
+fn test_freeze_bumps_epoch_only() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let staff = fx.provider("staff");
+    grant(&fx, &staff);
+
+    fx.client.freeze_my_access(&patient, &false);
+    assert_eq!(epoch(&fx), 1);
+    fx.client.is_access_frozen(&patient);
+    fx.client.unfreeze_my_access(&patient);
+    assert_eq!(epoch(&fx), 2);
+    // Nothing to lift, nothing changes
+    fx.client.unfreeze_my_access(&patient);
+    assert_eq!(epoch(&fx), 2);
+    assert_eq!(revision(&fx, &staff), 1);
+}
@@ -0,0 +1,154 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{
+    AccessLevel, AccessTemplateEntry, BatchGrantInput, ContractError, DeactivationCascade,
+    GrantPurpose, RecordType, VisionRecordsContract,
+};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Vec};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const DAY: u64 = 86_400;
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_provider("dr_b")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .build()
+}
+
+/// Every patient-initiated grant shape, each of which must reject `level`
+/// for `grantee` with `InvalidInput`.
+fn assert_grant_rejected(fx: &TestContract, grantee: &Address, level: AccessLevel) {
+    let patient = fx.patient("pat_a");
+    assert_err(
+        fx.client
+            .try_grant_access(&patient, &patient, grantee, &level, &DAY),
+        ContractError::InvalidInput,
+    );
+    assert_err(
+        fx.client.try_grant_access_batch(
+            &patient,
+            &Vec::from_array(
+                &fx.env,
+                [BatchGrantInput {
+                    grantee: grantee.clone(),
+                    level: level.clone(),
+                    duration_seconds: DAY,
+                }],
+            ),
+        ),
+        ContractError::InvalidInput,
+    );
+    assert_err(
+        fx.client
+            .try_grant_record_access(&patient, grantee, &fx.record(0), &level, &DAY),
+        ContractError::InvalidInput,
+    );
+    assert_err(
+        fx.client
+            .try_grant_access_pending_acceptance(&patient, grantee, &level, &DAY),
+        ContractError::InvalidInput,
+    );
+    assert_eq!(fx.client.get_grantee_patients(grantee).len(), 0);
+}
+
+#[test]
+fn test_none_level_rejected() {
+    let fx = setup();
+    assert_grant_rejected(&fx, &fx.provider("dr_a"), AccessLevel::None);
+}
+
+#[test]
+fn test_self_grant_rejected() {
+    let fx = setup();
+    assert_grant_rejected(&fx, &fx.patient("pat_a"), AccessLevel::Read);
+}
+
+#[test]
+fn test_frozen_grantee_rejected() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    fx.client.freeze_user(&fx.admin(), &provider);
+    assert_grant_rejected(&fx, &provider, AccessLevel::Read);
+
+    fx.client.unfreeze_user(&fx.admin(), &provider);
+    let patient = fx.patient("pat_a");
+    fx.client
+        .grant_access(&patient, &patient, &provider, &AccessLevel::Read, &DAY);
+}
+
+#[test]
+fn test_deactivated_grantee_rejected() {
+    let fx = setup();
+    let provider = fx.provider("dr_b");
+    fx.client
+        .deactivate_user(&fx.admin(), &provider, &DeactivationCascade::None, &10);
+    assert_grant_rejected(&fx, &provider, AccessLevel::Read);
+}
+
+#[test]
+fn test_template_rejects_self_grant() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let name = symbol_short!("CARE");
+    fx.client.create_access_template(
+        &patient,
+        &name,
+        &Vec::from_array(
+            &fx.env,
+            [AccessTemplateEntry {
+                level: AccessLevel::Read,
+                duration_seconds: DAY,
+            }],
+        ),
+    );
+    assert_err(
+        fx.client.try_apply_access_template(
+            &patient,
+            &name,
+            &Vec::from_array(&fx.env, [fx.provider("dr_a"), patient.clone()]),
+        ),
+        ContractError::InvalidInput,
+    );
+    // Nothing from the rejected batch was stored
+    assert_eq!(
+        fx.client.get_grantee_patients(&fx.provider("dr_a")).len(),
+        0
+    );
+}
+
+#[test]
+fn test_purge_drops_legacy_none_grants() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let legacy = Address::generate(&fx.env);
+    let provider = fx.provider("dr_a");
+    fx.client
+        .grant_access(&patient, &patient, &provider, &AccessLevel::Read, &DAY);
+    // Stored the way grant_access did before `None` was rejected
+    fx.env.as_contract(&fx.contract_id, || {
+        VisionRecordsContract::store_access_grant(
+            &fx.env,
+            &patient,
+            &legacy,
+            &AccessLevel::None,
+            &GrantPurpose::Treatment,
+            DAY,
+            &patient,
+        );
+    });
+    assert_eq!(fx.client.get_grantee_patients(&legacy).len(), 1);
+
+    assert_eq!(fx.client.purge_expired_grants(&patient, &patient), 1);
+    assert_eq!(fx.client.get_grantee_patients(&legacy).len(), 0);
+    assert_eq!(fx.client.get_grantee_patients(&provider).len(), 1);
+}
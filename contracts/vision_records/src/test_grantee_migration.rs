@@ -0,0 +1,234 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{
+    AccessLevel, ConsentType, ContractError, DeactivationCascade, GrantPurpose,
+    GranteeMigrationResult, RecordType,
+};
+use crate::confirmation::MIGRATE_GRANTEE;
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    Address, BytesN, IntoVal,
+};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .with_patient("pat_b")
+        .build()
+}
+
+fn grant(fx: &TestContract, patient: &Address, grantee: &Address, duration: u64) {
+    fx.client
+        .grant_consent(patient, grantee, &ConsentType::Treatment, &duration);
+    fx.client
+        .grant_access(patient, patient, grantee, &AccessLevel::Read, &duration);
+}
+
+fn confirm(fx: &TestContract, old: &Address) -> BytesN<32> {
+    fx.client.compute_confirmation(&MIGRATE_GRANTEE, old)
+}
+
+#[test]
+fn test_migration_moves_grants_intact() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let old = Address::generate(&fx.env);
+    let new = Address::generate(&fx.env);
+    fx.client
+        .grant_consent(&patient, &old, &ConsentType::Research, &7_200);
+    fx.client.grant_access_with_purpose(
+        &patient,
+        &patient,
+        &old,
+        &AccessLevel::Write,
+        &GrantPurpose::Research,
+        &7_200,
+    );
+    let before = fx.client.check_access_detailed(&patient, &old).unwrap();
+    fx.advance_time(60);
+
+    let result = fx
+        .client
+        .migrate_grantee(&old, &new, &10, &confirm(&fx, &old));
+    assert_eq!(
+        result,
+        GranteeMigrationResult {
+            migrated: 1,
+            skipped: 0,
+            remaining: 0,
+        }
+    );
+
+    let after = fx.client.check_access_detailed(&patient, &new).unwrap();
+    assert_eq!(after.grantee, new);
+    assert_eq!(after.level, AccessLevel::Write);
+    assert_eq!(after.purpose, GrantPurpose::Research);
+    assert_eq!(after.granted_at, before.granted_at);
+    assert_eq!(after.expires_at, before.expires_at);
+    assert_eq!(fx.client.check_access_detailed(&patient, &old), None);
+    assert_eq!(fx.client.get_grantee_patients(&old).len(), 0);
+    assert_eq!(fx.client.get_grantee_patients(&new).len(), 1);
+
+    // Consent moved with the grant, so the new address can read.
+    assert_eq!(fx.client.get_record(&new, &fx.record(0)).id, fx.record(0));
+    assert_err(
+        fx.client.try_get_record(&old, &fx.record(0)),
+        ContractError::Unauthorized,
+    );
+}
+
+#[test]
+fn test_migration_resumes_and_skips_grants_that_expire() {
+    let fx = setup();
+    let old = Address::generate(&fx.env);
+    let new = Address::generate(&fx.env);
+    grant(&fx, &fx.patient("pat_a"), &old, 7_200);
+    grant(&fx, &fx.patient("pat_b"), &old, 3_600);
+
+    let first = fx
+        .client
+        .migrate_grantee(&old, &new, &1, &confirm(&fx, &old));
+    assert_eq!(
+        first,
+        GranteeMigrationResult {
+            migrated: 1,
+            skipped: 0,
+            remaining: 1,
+        }
+    );
+
+    // pat_b's grant lapses before the second batch runs.
+    fx.advance_time(3_700);
+    let second = fx
+        .client
+        .migrate_grantee(&old, &new, &1, &confirm(&fx, &old));
+    assert_eq!(
+        second,
+        GranteeMigrationResult {
+            migrated: 0,
+            skipped: 1,
+            remaining: 0,
+        }
+    );
+    assert_eq!(
+        fx.client.check_access(&fx.patient("pat_a"), &new),
+        AccessLevel::Read
+    );
+    assert_eq!(
+        fx.client.check_access(&fx.patient("pat_b"), &new),
+        AccessLevel::None
+    );
+    assert_eq!(fx.client.get_grantee_patients(&old).len(), 0);
+}
+
+#[test]
+fn test_existing_grant_on_new_address_is_kept() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let old = Address::generate(&fx.env);
+    let new = Address::generate(&fx.env);
+    grant(&fx, &patient, &old, 3_600);
+    grant(&fx, &patient, &new, 3_600);
+    let existing = fx.client.check_access_detailed(&patient, &new);
+
+    let result = fx
+        .client
+        .migrate_grantee(&old, &new, &10, &confirm(&fx, &old));
+    assert_eq!(result.skipped, 1);
+    assert_eq!(fx.client.check_access_detailed(&patient, &new), existing);
+    assert_eq!(fx.client.check_access_detailed(&patient, &old), None);
+}
+
+#[test]
+fn test_new_address_must_be_grantable() {
+    let fx = setup();
+    let (pat_a, pat_b) = (fx.patient("pat_a"), fx.patient("pat_b"));
+    let old = Address::generate(&fx.env);
+    grant(&fx, &pat_a, &old, 3_600);
+    let before = fx.client.check_access_detailed(&pat_a, &old);
+
+    // A patient cannot become a grantee of their own record
+    assert_err(
+        fx.client
+            .try_migrate_grantee(&old, &pat_a, &10, &confirm(&fx, &old)),
+        ContractError::InvalidInput,
+    );
+    assert_eq!(fx.client.check_access_detailed(&pat_a, &old), before);
+
+    fx.client
+        .deactivate_user(&fx.admin(), &pat_b, &DeactivationCascade::None, &10);
+    assert_err(
+        fx.client
+            .try_migrate_grantee(&old, &pat_b, &10, &confirm(&fx, &old)),
+        ContractError::InvalidInput,
+    );
+    assert_eq!(fx.client.check_access_detailed(&pat_a, &old), before);
+}
+
+#[test]
+fn test_migration_requires_both_addresses() {
+    let fx = setup();
+    let old = Address::generate(&fx.env);
+    let new = Address::generate(&fx.env);
+    grant(&fx, &fx.patient("pat_a"), &old, 3_600);
+
+    for signer in [&old, &new] {
+        fx.env.mock_auths(&[MockAuth {
+            address: signer,
+            invoke: &MockAuthInvoke {
+                contract: &fx.contract_id,
+                fn_name: "migrate_grantee",
+                args: (&old, &new, 10u32, confirm(&fx, &old)).into_val(&fx.env),
+                sub_invokes: &[],
+            },
+        }]);
+        assert!(fx
+            .client
+            .try_migrate_grantee(&old, &new, &10, &confirm(&fx, &old))
+            .is_err());
+    }
+
+    fx.env.mock_all_auths();
+    assert_eq!(
+        fx.client.check_access(&fx.patient("pat_a"), &old),
+        AccessLevel::Read
+    );
+    assert_err(
+        fx.client
+            .try_migrate_grantee(&old, &old, &10, &confirm(&fx, &old)),
+        ContractError::InvalidInput,
+    );
+    assert_err(
+        fx.client
+            .try_migrate_grantee(&old, &new, &0, &confirm(&fx, &old)),
+        ContractError::InvalidInput,
+    );
+}
+
+#[test]
+fn test_swapped_addresses_are_caught() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let old = Address::generate(&fx.env);
+    let new = Address::generate(&fx.env);
+    grant(&fx, &patient, &old, 3_600);
+
+    assert_err(
+        fx.client
+            .try_migrate_grantee(&new, &old, &10, &confirm(&fx, &old)),
+        ContractError::ConfirmationMismatch,
+    );
+    assert_eq!(fx.client.check_access(&patient, &old), AccessLevel::Read);
+    assert_eq!(fx.client.check_access(&patient, &new), AccessLevel::None);
+}
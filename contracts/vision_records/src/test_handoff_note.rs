@@ -0,0 +1,107 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessLevel, ConsentType, ContractError, RecordType, Role};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+const HASH: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const NOTE: &str = "QmHandoffSummaryForDrA0000000000000000000000000";
+const DAY: u64 = 86_400;
+
+/// `dr_w` wrote a record for `pat_a`, who has shared their chart with
+/// `dr_a`. `dr_a` is staff, so the grant is their only way in.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider_role("dr_a", Role::Staff)
+        .with_provider("dr_w")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH)
+        .build();
+    let (patient, dr_a) = (fx.patient("pat_a"), fx.provider("dr_a"));
+    fx.client
+        .grant_consent(&patient, &dr_a, &ConsentType::Treatment, &(30 * DAY));
+    fx.client
+        .grant_access(&patient, &patient, &dr_a, &AccessLevel::Read, &(30 * DAY));
+    fx
+}
+
+fn note(fx: &TestContract) -> String {
+    String::from_str(&fx.env, NOTE)
+}
+
+#[test]
+fn test_revoked_provider_keeps_only_the_note() {
+    let fx = setup();
+    let (patient, dr_a) = (fx.patient("pat_a"), fx.provider("dr_a"));
+    fx.client.get_record(&dr_a, &fx.record(0));
+    assert!(fx.client.get_handoff_notes(&dr_a).is_empty());
+
+    fx.client
+        .revoke_access_with_note(&patient, &patient, &dr_a, &note(&fx));
+
+    assert_eq!(fx.client.check_access(&patient, &dr_a), AccessLevel::None);
+    // Consent on its own still lets dr_a read, so the patient ends that too
+    fx.client.revoke_consent(&patient, &dr_a);
+    assert_err(
+        fx.client.try_get_record(&dr_a, &fx.record(0)),
+        ContractError::Unauthorized,
+    );
+    let notes = fx.client.get_handoff_notes(&dr_a);
+    assert_eq!(notes.len(), 1);
+    let handoff = notes.get(0).unwrap();
+    assert_eq!(handoff.patient, patient);
+    assert_eq!(handoff.note_hash, note(&fx));
+    assert_eq!(handoff.revoked_by, patient);
+    assert_eq!(handoff.revoked_at, fx.env.ledger().timestamp());
+
+    // With the grant gone there is nothing left to attach a second note to
+    assert_err(
+        fx.client
+            .try_revoke_access_with_note(&patient, &patient, &dr_a, &note(&fx)),
+        ContractError::AccessGrantNotFound,
+    );
+    assert_eq!(fx.client.get_handoff_notes(&dr_a).len(), 1);
+}
+
+#[test]
+fn test_guardian_may_leave_a_note() {
+    let fx = setup();
+    let (patient, dr_a) = (fx.patient("pat_a"), fx.provider("dr_a"));
+    let guardian = Address::generate(&fx.env);
+    fx.client
+        .delegate_role(&patient, &guardian, &Role::Optometrist, &0);
+
+    fx.client
+        .revoke_access_with_note(&guardian, &patient, &dr_a, &note(&fx));
+    let handoff = fx.client.get_handoff_notes(&dr_a).get(0).unwrap();
+    assert_eq!(handoff.revoked_by, guardian);
+}
+
+#[test]
+fn test_note_rejections() {
+    let fx = setup();
+    let (patient, dr_a) = (fx.patient("pat_a"), fx.provider("dr_a"));
+    assert_err(
+        fx.client
+            .try_revoke_access_with_note(&fx.provider("dr_w"), &patient, &dr_a, &note(&fx)),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_revoke_access_with_note(
+            &patient,
+            &patient,
+            &dr_a,
+            &String::from_str(&fx.env, "not a hash!"),
+        ),
+        ContractError::InvalidInput,
+    );
+    // Nothing was revoked
+    assert_eq!(fx.client.check_access(&patient, &dr_a), AccessLevel::Read);
+    assert!(fx.client.get_handoff_notes(&dr_a).is_empty());
+}
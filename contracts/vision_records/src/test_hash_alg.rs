@@ -0,0 +1,232 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{hash_alg, ContractError, RecordType};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, String, Symbol};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .build()
+}
+
+fn hash(fx: &TestContract, value: &str) -> String {
+    String::from_str(&fx.env, value)
+}
+
+fn add_tagged(fx: &TestContract, data_hash: &str, alg: Symbol) -> u64 {
+    let provider = fx.provider("dr_a");
+    fx.client.add_record_tagged(
+        &provider,
+        &fx.patient("pat_a"),
+        &provider,
+        &RecordType::Examination,
+        &hash(fx, data_hash),
+        &alg,
+    )
+}
+
+#[test]
+fn test_untagged_records_report_unknown() {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .build();
+    let provider = fx.provider("dr_a");
+
+    let record = fx.client.get_record(&provider, &fx.record(0));
+    assert_eq!(record.hash_alg, hash_alg::UNKNOWN);
+    let version = fx.client.get_record_version(&provider, &fx.record(0), &1);
+    assert_eq!(version.hash_alg, hash_alg::UNKNOWN);
+}
+
+#[test]
+fn test_tagged_writes_carry_algorithm_into_history() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    let record_id = add_tagged(&fx, HASH_A, hash_alg::SHA256);
+
+    fx.advance_time(10);
+    fx.client
+        .update_record_tagged(&provider, &record_id, &hash(&fx, HASH_B), &hash_alg::CIDV1);
+
+    let record = fx.client.get_record(&provider, &record_id);
+    assert_eq!(record.hash_alg, hash_alg::CIDV1);
+    let first = fx.client.get_record_version(&provider, &record_id, &1);
+    assert_eq!(first.hash_alg, hash_alg::SHA256);
+    let second = fx
+        .client
+        .get_record_at(&provider, &record_id, &fx.env.ledger().timestamp());
+    assert_eq!(second.hash_alg, hash_alg::CIDV1);
+
+    // An untagged update resets the tag rather than inheriting it.
+    fx.advance_time(10);
+    fx.client
+        .update_record(&provider, &record_id, &hash(&fx, HASH_A));
+    let record = fx.client.get_record(&provider, &record_id);
+    assert_eq!(record.hash_alg, hash_alg::UNKNOWN);
+}
+
+#[test]
+fn test_unregistered_algorithm_rejected() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    let md5 = symbol_short!("md5");
+
+    assert_err(
+        fx.client.try_add_record_tagged(
+            &provider,
+            &fx.patient("pat_a"),
+            &provider,
+            &RecordType::Examination,
+            &hash(&fx, HASH_A),
+            &md5,
+        ),
+        ContractError::UnsupportedHashAlgorithm,
+    );
+    assert_eq!(fx.client.get_record_count(), 0);
+
+    let record_id = add_tagged(&fx, HASH_A, hash_alg::SHA256);
+    assert_err(
+        fx.client
+            .try_update_record_tagged(&provider, &record_id, &hash(&fx, HASH_B), &md5),
+        ContractError::UnsupportedHashAlgorithm,
+    );
+    // `unknown` is reserved for untagged writes.
+    assert_err(
+        fx.client.try_update_record_tagged(
+            &provider,
+            &record_id,
+            &hash(&fx, HASH_B),
+            &hash_alg::UNKNOWN,
+        ),
+        ContractError::UnsupportedHashAlgorithm,
+    );
+    assert_eq!(fx.client.get_record_version_count(&record_id), 1);
+}
+
+#[test]
+fn test_registered_algorithm_accepted() {
+    let fx = setup();
+    let sha3 = symbol_short!("sha3_256");
+
+    fx.client.register_hash_algorithm(&fx.admin(), &sha3);
+    let algorithms = fx.client.get_hash_algorithms();
+    assert!(algorithms.contains(&hash_alg::SHA256));
+    assert!(algorithms.contains(&sha3));
+
+    let record_id = add_tagged(&fx, HASH_A, sha3.clone());
+    let record = fx.client.get_record(&fx.provider("dr_a"), &record_id);
+    assert_eq!(record.hash_alg, sha3);
+
+    // Registering again is a no-op.
+    fx.client.register_hash_algorithm(&fx.admin(), &sha3);
+    assert_eq!(fx.client.get_hash_algorithms().len(), algorithms.len());
+}
+
+#[test]
+fn test_register_hash_algorithm_requires_admin() {
+    let fx = setup();
+    let outsider = Address::generate(&fx.env);
+
+    assert_err(
+        fx.client
+            .try_register_hash_algorithm(&outsider, &symbol_short!("sha3_256")),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client
+            .try_register_hash_algorithm(&fx.admin(), &hash_alg::UNKNOWN),
+        ContractError::InvalidInput,
+    );
+}
+
+#[test]
+fn test_verify_record_hash_matches_algorithm() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    let tagged = add_tagged(&fx, HASH_A, hash_alg::SHA256);
+
+    assert!(fx.client.verify_record_hash(
+        &provider,
+        &tagged,
+        &hash(&fx, HASH_A),
+        &hash_alg::SHA256
+    ));
+    assert!(!fx.client.verify_record_hash(
+        &provider,
+        &tagged,
+        &hash(&fx, HASH_A),
+        &hash_alg::BLAKE3
+    ));
+    assert!(!fx.client.verify_record_hash(
+        &provider,
+        &tagged,
+        &hash(&fx, HASH_B),
+        &hash_alg::SHA256
+    ));
+
+    let untagged = fx.client.add_record(
+        &provider,
+        &fx.patient("pat_a"),
+        &provider,
+        &RecordType::Examination,
+        &hash(&fx, HASH_A),
+    );
+    assert!(fx.client.verify_record_hash(
+        &provider,
+        &untagged,
+        &hash(&fx, HASH_A),
+        &hash_alg::UNKNOWN
+    ));
+    assert!(!fx.client.verify_record_hash(
+        &provider,
+        &untagged,
+        &hash(&fx, HASH_A),
+        &hash_alg::SHA256
+    ));
+}
+
+#[test]
+fn test_verify_record_hash_requires_read_access() {
+    let fx = setup();
+    let tagged = add_tagged(&fx, HASH_A, hash_alg::SHA256);
+    let stranger = Address::generate(&fx.env);
+
+    assert_err(
+        fx.client
+            .try_verify_record_hash(&stranger, &tagged, &hash(&fx, HASH_A), &hash_alg::SHA256),
+        ContractError::Unauthorized,
+    );
+}
+
+#[test]
+fn test_supersede_keeps_algorithm_on_terminal_version() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    let record_id = add_tagged(&fx, HASH_A, hash_alg::BLAKE3);
+
+    fx.advance_time(10);
+    fx.client.supersede_record(
+        &provider,
+        &record_id,
+        &hash(&fx, HASH_B),
+        &RecordType::Examination,
+    );
+
+    let terminal = fx.client.get_record_version(&provider, &record_id, &2);
+    assert_eq!(terminal.superseded_by, Some(record_id + 1));
+    assert_eq!(terminal.hash_alg, hash_alg::BLAKE3);
+}
@@ -0,0 +1,160 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessLevel, ContractError, HeartbeatConfig, HeartbeatInfo};
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address,
+};
+
+const THRESHOLD: u64 = 3_600;
+const DAY: u64 = 86_400;
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .build()
+}
+
+fn configure(fx: &TestContract, lock_grants_when_stale: bool) {
+    fx.client.set_heartbeat_config(
+        &fx.admin(),
+        &HeartbeatConfig {
+            stale_threshold: THRESHOLD,
+            lock_grants_when_stale,
+        },
+    );
+}
+
+/// A grant made on the patient's behalf by the admin.
+fn try_admin_grant(fx: &TestContract) -> Result<(), ContractError> {
+    match fx.client.try_grant_access(
+        &fx.admin(),
+        &fx.patient("pat_a"),
+        &Address::generate(&fx.env),
+        &AccessLevel::Read,
+        &DAY,
+    ) {
+        Ok(_) => Ok(()),
+        Err(Ok(err)) => Err(err),
+        Err(Err(err)) => panic!("unexpected invoke error {:?}", err),
+    }
+}
+
+#[test]
+fn test_heartbeat_reports_admin_and_ledgers_since() {
+    let fx = setup();
+    assert_eq!(fx.client.get_last_heartbeat(), None);
+
+    fx.env.ledger().set_sequence_number(10);
+    fx.client.heartbeat(&fx.admin());
+    fx.env.ledger().set_sequence_number(25);
+    fx.advance_time(60);
+    assert_eq!(
+        fx.client.get_last_heartbeat(),
+        Some(HeartbeatInfo {
+            admin: fx.admin(),
+            timestamp: FIXTURE_START_TIME,
+            ledgers_since: 15,
+        })
+    );
+
+    assert_err(
+        fx.client.try_heartbeat(&fx.provider("dr_a")),
+        ContractError::Unauthorized,
+    );
+}
+
+#[test]
+fn test_staleness_around_threshold() {
+    let fx = setup();
+    // No threshold, never stale.
+    fx.advance_time(10 * DAY);
+    assert!(!fx.client.is_maintenance_stale());
+
+    configure(&fx, false);
+    // A threshold with no heartbeat on record counts as stale.
+    assert!(fx.client.is_maintenance_stale());
+
+    fx.client.heartbeat(&fx.admin());
+    fx.advance_time(THRESHOLD);
+    assert!(!fx.client.is_maintenance_stale());
+    fx.advance_time(1);
+    assert!(fx.client.is_maintenance_stale());
+
+    fx.client.heartbeat(&fx.admin());
+    assert!(!fx.client.is_maintenance_stale());
+}
+
+#[test]
+fn test_stale_lockout_refuses_grants_from_non_patients() {
+    let fx = setup();
+    configure(&fx, true);
+    fx.client.heartbeat(&fx.admin());
+    assert_eq!(try_admin_grant(&fx), Ok(()));
+
+    fx.advance_time(THRESHOLD + 1);
+    assert_eq!(try_admin_grant(&fx), Err(ContractError::MaintenanceStale));
+    // The patient can still share their own records.
+    let patient = fx.patient("pat_a");
+    fx.client.grant_access(
+        &patient,
+        &patient,
+        &Address::generate(&fx.env),
+        &AccessLevel::Read,
+        &DAY,
+    );
+
+    fx.client.heartbeat(&fx.admin());
+    assert_eq!(try_admin_grant(&fx), Ok(()));
+}
+
+#[test]
+fn test_stale_without_lockout_allows_grants() {
+    let fx = setup();
+    configure(&fx, false);
+    fx.advance_time(THRESHOLD + 1);
+    assert!(fx.client.is_maintenance_stale());
+    assert_eq!(try_admin_grant(&fx), Ok(()));
+}
+
+#[test]
+fn test_heartbeat_config_validation() {
+    let fx = setup();
+    assert_err(
+        fx.client.try_set_heartbeat_config(
+            &fx.admin(),
+            &HeartbeatConfig {
+                stale_threshold: 0,
+                lock_grants_when_stale: true,
+            },
+        ),
+        ContractError::InvalidInput,
+    );
+    assert_err(
+        fx.client.try_set_heartbeat_config(
+            &fx.provider("dr_a"),
+            &HeartbeatConfig {
+                stale_threshold: THRESHOLD,
+                lock_grants_when_stale: false,
+            },
+        ),
+        ContractError::Unauthorized,
+    );
+
+    configure(&fx, true);
+    assert_eq!(
+        fx.client.get_heartbeat_config(),
+        HeartbeatConfig {
+            stale_threshold: THRESHOLD,
+            lock_grants_when_stale: true,
+        }
+    );
+}
@@ -0,0 +1,194 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{
+    auth, versioning, AccessLevel, AuthAction, ConsentType, ContractError, RecordType, Role,
+};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{Address, String, Symbol};
+
+const HASH_V1: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_V2: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+const DAY: u64 = 86_400;
+
+/// `dr_a` wrote one record for `pat_a` and updated it once. Staff read
+/// through grants, since only providers read any record by role; `pat_a`
+/// has consented to `staff`.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider_role("staff", Role::Staff)
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_V1)
+        .build();
+    fx.advance_time(60);
+    fx.client.update_record(
+        &fx.provider("dr_a"),
+        &fx.record(0),
+        &String::from_str(&fx.env, HASH_V2),
+    );
+    fx.client.grant_consent(
+        &fx.patient("pat_a"),
+        &fx.provider("staff"),
+        &ConsentType::Treatment,
+        &(30 * DAY),
+    );
+    fx
+}
+
+fn grant(fx: &TestContract, level: AccessLevel, include_history: bool) {
+    fx.client.grant_access_with_history(
+        &fx.patient("pat_a"),
+        &fx.provider("staff"),
+        &level,
+        &(30 * DAY),
+        &include_history,
+    );
+}
+
+fn assert_history_denied(fx: &TestContract, caller: &Address) {
+    let record_id = fx.record(0);
+    assert_err(
+        fx.client.try_get_record_version(caller, &record_id, &1),
+        ContractError::AccessDenied,
+    );
+    assert_err(
+        fx.client.try_get_record_at(caller, &record_id, &1_000),
+        ContractError::AccessDenied,
+    );
+    assert_err(
+        fx.client
+            .try_compare_record_versions(caller, &record_id, &1, &2),
+        ContractError::AccessDenied,
+    );
+}
+
+fn assert_history_readable(fx: &TestContract, caller: &Address) {
+    let record_id = fx.record(0);
+    assert_eq!(
+        fx.client.get_record_version(caller, &record_id, &1).version,
+        1
+    );
+    assert_eq!(
+        fx.client.get_record_at(caller, &record_id, &1_000).version,
+        1
+    );
+    assert!(
+        fx.client
+            .compare_record_versions(caller, &record_id, &1, &2)
+            .changed
+    );
+}
+
+#[test]
+fn test_grants_include_history_by_default() {
+    let fx = setup();
+    let staff = fx.provider("staff");
+    fx.client.grant_access(
+        &fx.patient("pat_a"),
+        &fx.patient("pat_a"),
+        &staff,
+        &AccessLevel::Read,
+        &(30 * DAY),
+    );
+    assert!(fx
+        .client
+        .grant_includes_history(&fx.patient("pat_a"), &staff));
+    assert_history_readable(&fx, &staff);
+
+    grant(&fx, AccessLevel::Read, true);
+    assert_history_readable(&fx, &staff);
+}
+
+#[test]
+fn test_hidden_history_keeps_current_record() {
+    let fx = setup();
+    let staff = fx.provider("staff");
+    grant(&fx, AccessLevel::Read, false);
+    assert!(!fx
+        .client
+        .grant_includes_history(&fx.patient("pat_a"), &staff));
+
+    let record = fx.client.get_record(&staff, &fx.record(0));
+    assert_eq!(record.data_hash, String::from_str(&fx.env, HASH_V2));
+    assert_history_denied(&fx, &staff);
+
+    let decision = fx
+        .client
+        .authorize(&staff, &AuthAction::ReadHistory(fx.record(0)));
+    assert!(!decision.allowed);
+    assert_eq!(decision.reason, Symbol::new(&fx.env, auth::HISTORY_HIDDEN));
+    assert!(
+        fx.client
+            .authorize(&staff, &AuthAction::ReadRecord(fx.record(0)))
+            .allowed
+    );
+}
+
+#[test]
+fn test_regrant_and_revoke_reset_flag() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let staff = fx.provider("staff");
+    grant(&fx, AccessLevel::Read, false);
+
+    fx.client
+        .grant_access(&patient, &patient, &staff, &AccessLevel::Read, &(30 * DAY));
+    assert!(fx.client.grant_includes_history(&patient, &staff));
+    assert_history_readable(&fx, &staff);
+
+    grant(&fx, AccessLevel::Read, false);
+    fx.client.revoke_access_immediate(&patient, &staff);
+    assert!(fx.client.grant_includes_history(&patient, &staff));
+}
+
+#[test]
+fn test_patient_and_provider_unaffected() {
+    let fx = setup();
+    grant(&fx, AccessLevel::Read, false);
+    assert_history_readable(&fx, &fx.patient("pat_a"));
+    assert_history_readable(&fx, &fx.provider("dr_a"));
+    // Admins read records, history included, only with a stated reason
+    assert_err(
+        fx.client
+            .try_get_record_version(&fx.admin(), &fx.record(0), &1),
+        ContractError::AdminReasonRequired,
+    );
+}
+
+#[test]
+fn test_version_author_keeps_history() {
+    let fx = setup();
+    let staff = fx.provider("staff");
+    grant(&fx, AccessLevel::Write, false);
+    assert_history_denied(&fx, &staff);
+
+    fx.advance_time(60);
+    fx.client
+        .update_record(&staff, &fx.record(0), &String::from_str(&fx.env, HASH_V1));
+    assert_history_readable(&fx, &staff);
+}
+
+#[test]
+fn test_authors_listed_once_each() {
+    let fx = setup();
+    let staff = fx.provider("staff");
+    grant(&fx, AccessLevel::Write, false);
+    for hash in [HASH_V1, HASH_V2] {
+        fx.advance_time(60);
+        fx.client
+            .update_record(&staff, &fx.record(0), &String::from_str(&fx.env, hash));
+    }
+
+    // The creator is implied by version 1, so only `staff` is stored
+    let authors = fx.env.as_contract(&fx.client.address, || {
+        versioning::get_later_authors(&fx.env, fx.record(0))
+    });
+    assert_eq!(authors.len(), 1);
+    assert_eq!(authors.get(0).unwrap(), staff);
+}
@@ -0,0 +1,207 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessLevel, ContractError, Permission, RecordPriority, RecordType, StorageFootprint};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{testutils::Address as _, Address, BytesN, String, Vec};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .with_record(RecordType::Prescription, HASH_B)
+        .build()
+}
+
+fn text(fx: &TestContract, value: &str) -> String {
+    String::from_str(&fx.env, value)
+}
+
+fn footprint(fx: &TestContract) -> StorageFootprint {
+    fx.client
+        .get_storage_footprint(&fx.admin(), &fx.patient("pat_a"))
+}
+
+/// Asserts the running version total matches the per-record counts.
+fn assert_versions_consistent(fx: &TestContract) {
+    let records = fx.client.get_patient_records(&fx.patient("pat_a"));
+    let summed: u64 = records
+        .iter()
+        .map(|id| u64::from(fx.client.get_record_version_count(&id)))
+        .sum();
+    let footprint = footprint(fx);
+    assert_eq!(footprint.records, records.len());
+    assert_eq!(footprint.versions, summed);
+}
+
+#[test]
+fn test_version_total_tracks_updates_and_supersedes() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    assert_eq!(footprint(&fx).versions, 2);
+    assert_versions_consistent(&fx);
+
+    fx.client
+        .update_record(&provider, &fx.record(0), &text(&fx, HASH_B));
+    fx.client
+        .update_record(&provider, &fx.record(0), &text(&fx, HASH_A));
+    assert_eq!(footprint(&fx).versions, 4);
+    assert_versions_consistent(&fx);
+
+    fx.client.supersede_record(
+        &provider,
+        &fx.record(1),
+        &text(&fx, HASH_A),
+        &RecordType::Prescription,
+    );
+    assert_eq!(footprint(&fx).records, 3);
+    assert_versions_consistent(&fx);
+
+    // A failed update leaves the total alone.
+    assert_err(
+        fx.client
+            .try_update_record(&provider, &fx.record(1), &text(&fx, HASH_B)),
+        ContractError::RecordSuperseded,
+    );
+    assert_versions_consistent(&fx);
+}
+
+#[test]
+fn test_claimed_provisional_versions_move_to_patient() {
+    let fx = setup();
+    let identity = BytesN::from_array(&fx.env, &[7; 32]);
+    let record_id = fx.client.add_record_provisional(
+        &fx.provider("dr_a"),
+        &identity,
+        &RecordType::Examination,
+        &text(&fx, HASH_A),
+    );
+    assert_eq!(footprint(&fx).versions, 2);
+
+    fx.client
+        .claim_provisional_records(&fx.patient("pat_a"), &identity, &fx.admin());
+    assert_eq!(fx.client.get_record_version_count(&record_id), 2);
+    assert_eq!(footprint(&fx).versions, 4);
+    assert_versions_consistent(&fx);
+    assert_eq!(
+        fx.client
+            .get_storage_footprint(&fx.admin(), &fx.contract_id)
+            .versions,
+        0
+    );
+}
+
+#[test]
+fn test_footprint_counts_grants_delegations_and_pending() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let guardian = Address::generate(&fx.env);
+    let grantee = Address::generate(&fx.env);
+
+    fx.client
+        .grant_access(&patient, &patient, &grantee, &AccessLevel::Read, &3_600);
+    fx.client.delegate_permissions(
+        &patient,
+        &guardian,
+        &Vec::from_array(&fx.env, [Permission::ManageAccess]),
+        &0,
+    );
+    fx.client.set_require_patient_approval(&patient, &true);
+    fx.client.grant_access(
+        &guardian,
+        &patient,
+        &fx.provider("dr_a"),
+        &AccessLevel::Read,
+        &7_200,
+    );
+    assert_eq!(
+        footprint(&fx),
+        StorageFootprint {
+            patient: patient.clone(),
+            records: 2,
+            versions: 2,
+            grants: 1,
+            delegations: 1,
+            pending_requests: 1,
+        }
+    );
+
+    fx.client.approve_grant(&patient, &fx.provider("dr_a"));
+    fx.advance_time(3_700);
+    let after = footprint(&fx);
+    assert_eq!(after.pending_requests, 0);
+    // The short grant lapsed; the approved one is live.
+    assert_eq!(after.grants, 1);
+}
+
+#[test]
+fn test_record_debug() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let provider = fx.provider("dr_a");
+    let record_id = fx.record(0);
+
+    fx.client
+        .update_record(&provider, &record_id, &text(&fx, HASH_B));
+    fx.client
+        .grant_access(&patient, &patient, &provider, &AccessLevel::Read, &3_600);
+    fx.client.grant_record_access(
+        &patient,
+        &Address::generate(&fx.env),
+        &record_id,
+        &AccessLevel::Read,
+        &3_600,
+    );
+
+    let debug = fx.client.get_record_debug(&fx.admin(), &record_id);
+    assert_eq!(debug.record.id, record_id);
+    assert_eq!(debug.version_count, 2);
+    assert_eq!(debug.last_version.len(), 1);
+    let last = debug.last_version.get(0).unwrap();
+    assert_eq!(last.version, 2);
+    assert_eq!(last.modified_by, provider);
+    assert_eq!(debug.active_grant_count, 2);
+    assert!(!debug.locked);
+    assert!(!debug.archived);
+
+    fx.client
+        .set_record_priority(&provider, &record_id, &RecordPriority::Archivable);
+    fx.client.supersede_record(
+        &provider,
+        &record_id,
+        &text(&fx, HASH_A),
+        &RecordType::Examination,
+    );
+    let debug = fx.client.get_record_debug(&fx.admin(), &record_id);
+    assert!(debug.locked);
+    assert!(debug.archived);
+    assert_eq!(debug.version_count, 3);
+}
+
+#[test]
+fn test_inspection_requires_system_admin() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    assert_err(
+        fx.client
+            .try_get_storage_footprint(&provider, &fx.patient("pat_a")),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_get_record_debug(&provider, &fx.record(0)),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_get_record_debug(&fx.admin(), &999),
+        ContractError::RecordNotFound,
+    );
+}
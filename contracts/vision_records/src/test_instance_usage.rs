@@ -0,0 +1,169 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::ContractError;
+use crate::instance_usage::{
+    self, ADDRESS_BYTES, DEFAULT_WARN_THRESHOLD_BYTES, KEY_BYTES, LIST_BYTES,
+};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{testutils::Address as _, Address, Symbol, Vec};
+
+/// Every key the contract keeps in instance storage. A module that adds an
+/// instance key must list it in its `INSTANCE_KEYS` table, and here.
+const CANONICAL_INSTANCE_KEYS: &[&str] = &[
+    // lib
+    "ADMIN",
+    "PEND_ADM",
+    "INIT",
+    "RL_IN_CFG",
+    "ENC_CUR",
+    "RX_CTR",
+    // appointment, audit, emergency
+    "APPT_CTR",
+    "AUD_CTR",
+    "EMRG_CTR",
+    // circuit_breaker
+    "P_GLOB",
+    "P_FUNC",
+    // compliance
+    "COMP_SEQ",
+    // contract_grantee
+    "CTR_GRTS",
+    "CTR_GMAX",
+    // delegation_cooldown
+    "DLG_CDS",
+    // errors
+    "ERR_LOG",
+    "ERR_CNT",
+    "RETRY_CNT",
+    // expiry, expiry_beacon
+    "EXP_GRACE",
+    "MAX_GRANT",
+    "BCN_ON",
+    // hash_alg
+    "HASH_ALGS",
+    // heartbeat
+    "HEARTBEAT",
+    "HB_CONFIG",
+    // instance_usage
+    "INST_WARN",
+    // jobs
+    "JOB_SEQ",
+    // legacy_read
+    "LEG_READ",
+    // observer
+    "OBSERVERS",
+    "OBS_ON",
+    // offer
+    "OFR_WIN",
+    // period_stats
+    "PRD_OFF",
+    // priority
+    "PRIO_AUTO",
+    // provider
+    "PROV_CTR",
+    "PROV_IDS",
+    // quota
+    "QUOTA_DEF",
+    // rbac
+    "SYSADM_OF",
+    // record_counter
+    "REC_CTR",
+    "CTR_SHDS",
+    "CTR_SHHW",
+    // record_preset
+    "REC_PRSTS",
+    // registration
+    "SREG_ON",
+    "SREG_CAP",
+    "REG_AUTO",
+    // residency
+    "RES_ON",
+    // revocation
+    "REV_GRACE",
+    // teye_common
+    "S_ADMIN",
+    "MSIG_CFG",
+    "MSIG_CTR",
+    "WL_EN",
+    "REN_LOCK",
+];
+
+fn setup() -> TestContract {
+    Fixture::new().with_admin().with_provider("dr_a").build()
+}
+
+#[test]
+fn test_registry_matches_canonical_list() {
+    let fx = setup();
+    let mut registered: Vec<Symbol> = Vec::new(&fx.env);
+    for key in instance_usage::registered_keys() {
+        assert!(
+            !registered.contains(&key.key),
+            "{:?} is registered twice",
+            key.key
+        );
+        registered.push_back(key.key.clone());
+    }
+    for name in CANONICAL_INSTANCE_KEYS {
+        assert!(
+            registered.contains(Symbol::new(&fx.env, name)),
+            "{name} is missing from the instance key registry"
+        );
+    }
+    assert_eq!(registered.len() as usize, CANONICAL_INSTANCE_KEYS.len());
+}
+
+#[test]
+fn test_usage_tracks_list_growth() {
+    let fx = setup();
+    let admin = fx.admin();
+    let before = fx.client.get_instance_usage();
+    assert!(before.key_count > 0);
+    assert_eq!(before.warn_threshold_bytes, DEFAULT_WARN_THRESHOLD_BYTES);
+    assert!(!before.warning);
+
+    fx.client
+        .mark_contract_grantee(&admin, &Address::generate(&fx.env));
+    let one = fx.client.get_instance_usage();
+    assert_eq!(one.key_count, before.key_count + 1);
+    assert_eq!(
+        one.estimated_bytes,
+        before.estimated_bytes + KEY_BYTES + LIST_BYTES + ADDRESS_BYTES
+    );
+
+    fx.client
+        .mark_contract_grantee(&admin, &Address::generate(&fx.env));
+    let two = fx.client.get_instance_usage();
+    assert_eq!(two.key_count, one.key_count);
+    assert_eq!(two.estimated_bytes, one.estimated_bytes + ADDRESS_BYTES);
+}
+
+#[test]
+fn test_warning_threshold() {
+    let fx = setup();
+    let admin = fx.admin();
+    fx.client.set_instance_usage_threshold(&admin, &1);
+    let usage = fx.client.get_instance_usage();
+    assert_eq!(usage.warn_threshold_bytes, 1);
+    assert!(usage.warning);
+
+    // At the threshold exactly there is no warning yet
+    fx.client
+        .set_instance_usage_threshold(&admin, &usage.estimated_bytes);
+    assert!(!fx.client.get_instance_usage().warning);
+
+    assert_err(
+        fx.client
+            .try_set_instance_usage_threshold(&fx.provider("dr_a"), &1),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_set_instance_usage_threshold(&admin, &0),
+        ContractError::InvalidInput,
+    );
+}
@@ -0,0 +1,227 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessLevel, ContractError, InvariantScope, RecordType, VisionRecord};
+use crate::invariants::*;
+use crate::testutils::{assert_err, Fixture, TestContract};
+use crate::{grantee_index_key, patient_index, record_counter, versioning};
+use soroban_sdk::{symbol_short, Address, String, Symbol, Vec};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+
+/// `pat_a` holds records 0 and 1 and has granted `dr_a`; `pat_b` holds
+/// record 2. Record 0 has two versions.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .with_record(RecordType::Examination, HASH_B)
+        .with_patient("pat_b")
+        .with_record(RecordType::Examination, HASH_A)
+        .build();
+    let (dr_a, pat_a) = (fx.provider("dr_a"), fx.patient("pat_a"));
+    fx.client
+        .update_record(&dr_a, &fx.record(0), &String::from_str(&fx.env, HASH_B));
+    fx.client
+        .grant_access(&pat_a, &pat_a, &dr_a, &AccessLevel::Read, &3_600);
+    fx
+}
+
+fn patient_scope(fx: &TestContract, name: &str) -> Vec<Symbol> {
+    let scope = InvariantScope::Patient(fx.patient(name));
+    fx.client.check_invariants(&fx.admin(), &scope)
+}
+
+fn record_scope(fx: &TestContract, index: usize) -> Vec<Symbol> {
+    let scope = InvariantScope::Record(fx.record(index));
+    fx.client.check_invariants(&fx.admin(), &scope)
+}
+
+fn grantee_scope(fx: &TestContract, name: &str) -> Vec<Symbol> {
+    let scope = InvariantScope::Patient(fx.provider(name));
+    fx.client.check_invariants(&fx.admin(), &scope)
+}
+
+fn set_index(fx: &TestContract, patient: &Address, record_ids: &[u64]) {
+    fx.env.as_contract(&fx.contract_id, || {
+        fx.env.storage().persistent().set(
+            &patient_index::records_key(patient),
+            &Vec::from_slice(&fx.env, record_ids),
+        );
+    });
+}
+
+fn edit_record(fx: &TestContract, index: usize, edit: impl FnOnce(&mut VisionRecord)) {
+    let key = (symbol_short!("RECORD"), fx.record(index));
+    fx.env.as_contract(&fx.contract_id, || {
+        let mut record: VisionRecord = fx.env.storage().persistent().get(&key).unwrap();
+        edit(&mut record);
+        fx.env.storage().persistent().set(&key, &record);
+    });
+}
+
+#[test]
+fn test_consistent_state_reports_nothing() {
+    let fx = setup();
+    assert!(patient_scope(&fx, "pat_a").is_empty());
+    assert!(grantee_scope(&fx, "dr_a").is_empty());
+    fx.assert_invariants();
+}
+
+#[test]
+fn test_patient_index_invariants() {
+    let fx = setup();
+    let pat_a = fx.patient("pat_a");
+
+    set_index(&fx, &pat_a, &[fx.record(0), fx.record(1), 999]);
+    assert!(patient_scope(&fx, "pat_a").contains(IDX_DANGLING));
+    assert!(patient_scope(&fx, "pat_a").contains(COUNTER_BEHIND));
+
+    set_index(&fx, &pat_a, &[fx.record(0), fx.record(1), fx.record(2)]);
+    assert_eq!(
+        patient_scope(&fx, "pat_a"),
+        Vec::from_array(&fx.env, [IDX_FOREIGN])
+    );
+
+    set_index(&fx, &pat_a, &[fx.record(1), fx.record(0)]);
+    assert_eq!(
+        patient_scope(&fx, "pat_a"),
+        Vec::from_array(&fx.env, [IDX_ORDER])
+    );
+
+    // Unindexed from the record's side
+    set_index(&fx, &pat_a, &[fx.record(1)]);
+    assert_eq!(
+        record_scope(&fx, 0),
+        Vec::from_array(&fx.env, [REC_UNINDEXED])
+    );
+}
+
+#[test]
+fn test_record_invariants() {
+    let fx = setup();
+
+    edit_record(&fx, 1, |record| record.id = 999);
+    assert_eq!(record_scope(&fx, 1), Vec::from_array(&fx.env, [REC_ID]));
+
+    edit_record(&fx, 2, |record| {
+        record.data_hash = String::from_str(&fx.env, HASH_B);
+        record.key_version = None;
+    });
+    assert_eq!(record_scope(&fx, 2), Vec::from_array(&fx.env, [VER_HASH]));
+
+    // Rewind the record's counter shard to just before it
+    let (shard, sequence) = record_counter::split(fx.record(1));
+    fx.env.as_contract(&fx.contract_id, || {
+        fx.env
+            .storage()
+            .persistent()
+            .set(&record_counter::shard_key(shard), &(sequence - 1));
+    });
+    assert_eq!(
+        record_scope(&fx, 1),
+        Vec::from_array(&fx.env, [REC_ID, COUNTER_BEHIND])
+    );
+}
+
+#[test]
+fn test_version_invariants() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    let set_count = |count: u32| {
+        fx.env.as_contract(&fx.contract_id, || {
+            fx.env
+                .storage()
+                .persistent()
+                .set(&versioning::version_count_key(record_id), &count);
+        });
+    };
+
+    // Version 2 is now past the count, and version 1 is stale content
+    set_count(1);
+    assert_eq!(
+        record_scope(&fx, 0),
+        Vec::from_array(&fx.env, [VER_HASH, VER_OVERRUN])
+    );
+
+    set_count(0);
+    assert!(record_scope(&fx, 0).contains(VER_NONE));
+
+    set_count(3);
+    assert_eq!(
+        record_scope(&fx, 0),
+        Vec::from_array(&fx.env, [VER_MISSING])
+    );
+}
+
+#[test]
+fn test_grant_index_invariants() {
+    let fx = setup();
+    let (dr_a, pat_a, pat_b) = (
+        fx.provider("dr_a"),
+        fx.patient("pat_a"),
+        fx.patient("pat_b"),
+    );
+    let list_key = (symbol_short!("ACC_LST"), pat_a.clone());
+
+    fx.env.as_contract(&fx.contract_id, || {
+        fx.env.storage().persistent().set(
+            &list_key,
+            &Vec::from_array(&fx.env, [dr_a.clone(), dr_a.clone()]),
+        );
+    });
+    assert_eq!(
+        patient_scope(&fx, "pat_a"),
+        Vec::from_array(&fx.env, [GRANT_LIST])
+    );
+
+    // A reverse entry naming a patient that never granted dr_a
+    fx.env.as_contract(&fx.contract_id, || {
+        fx.env
+            .storage()
+            .persistent()
+            .set(&list_key, &Vec::from_array(&fx.env, [dr_a.clone()]));
+        fx.env.storage().persistent().set(
+            &grantee_index_key(&dr_a),
+            &Vec::from_array(&fx.env, [pat_a.clone(), pat_b.clone()]),
+        );
+    });
+    assert!(patient_scope(&fx, "pat_a").is_empty());
+    assert_eq!(
+        grantee_scope(&fx, "dr_a"),
+        Vec::from_array(&fx.env, [GRANT_RECEIVED])
+    );
+
+    fx.env.as_contract(&fx.contract_id, || {
+        fx.env
+            .storage()
+            .persistent()
+            .remove(&grantee_index_key(&dr_a));
+    });
+    assert_eq!(
+        patient_scope(&fx, "pat_a"),
+        Vec::from_array(&fx.env, [GRANT_REVERSE])
+    );
+}
+
+#[test]
+fn test_check_requires_operator_admin() {
+    let fx = setup();
+    let scope = InvariantScope::Patient(fx.patient("pat_a"));
+    assert_err(
+        fx.client.try_check_invariants(&fx.provider("dr_a"), &scope),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client
+            .try_check_invariants(&fx.admin(), &InvariantScope::Record(999)),
+        ContractError::RecordNotFound,
+    );
+}
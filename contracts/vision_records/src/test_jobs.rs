@@ -0,0 +1,161 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessLevel, ContractError, JobParams, JobStatus, PendingCategory};
+use crate::events::admin_ops;
+use crate::offer::DEFAULT_OFFER_WINDOW_SECONDS;
+use crate::pending_gc;
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{testutils::Address as _, Address};
+
+const DAY: u64 = 86_400;
+const OFFERS: u32 = 5;
+
+/// `pat_a` has made five access offers that have all lapsed.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .build();
+    for _ in 0..OFFERS {
+        fx.client.grant_access_pending_acceptance(
+            &fx.patient("pat_a"),
+            &Address::generate(&fx.env),
+            &AccessLevel::Read,
+            &DAY,
+        );
+    }
+    // Past the end of the day the offers lapsed on
+    fx.advance_time(DEFAULT_OFFER_WINDOW_SECONDS + DAY);
+    fx
+}
+
+fn offers_indexed(fx: &TestContract) -> u32 {
+    let lapsed_on = pending_gc::day_of(FIXTURE_START_TIME + DEFAULT_OFFER_WINDOW_SECONDS);
+    fx.env.as_contract(&fx.contract_id, || {
+        pending_gc::bucket_len(&fx.env, &PendingCategory::AccessOffer, lapsed_on)
+    })
+}
+
+fn start_gc(fx: &TestContract) -> u64 {
+    fx.client.start_job(
+        &fx.admin(),
+        &admin_ops::PENDING_GC,
+        &JobParams::PendingGc(PendingCategory::AccessOffer),
+    )
+}
+
+#[test]
+fn test_purge_job_runs_to_completion() {
+    let fx = setup();
+    let admin = fx.admin();
+    let job_id = start_gc(&fx);
+    assert_eq!(
+        fx.client.get_active_job(&admin_ops::PENDING_GC),
+        Some(job_id)
+    );
+
+    let first = fx.client.run_job(&admin, &job_id, &2);
+    assert_eq!((first.processed, first.status), (2, JobStatus::Running));
+    let second = fx.client.run_job(&admin, &job_id, &2);
+    assert_eq!((second.processed, second.status), (4, JobStatus::Running));
+    let last = fx.client.run_job(&admin, &job_id, &2);
+    assert_eq!(
+        (last.processed, last.status.clone()),
+        (OFFERS, JobStatus::Completed)
+    );
+
+    assert_eq!(offers_indexed(&fx), 0);
+    assert_eq!(fx.client.get_job(&job_id), Some(last));
+    assert_eq!(fx.client.get_active_job(&admin_ops::PENDING_GC), None);
+    assert_err(
+        fx.client.try_run_job(&admin, &job_id, &2),
+        ContractError::JobNotRunning,
+    );
+}
+
+#[test]
+fn test_cancel_midway_keeps_work_done() {
+    let fx = setup();
+    let admin = fx.admin();
+    let job_id = start_gc(&fx);
+    fx.client.run_job(&admin, &job_id, &2);
+
+    fx.client.cancel_job(&admin, &job_id);
+    let job = fx.client.get_job(&job_id).unwrap();
+    assert_eq!((job.processed, job.status), (2, JobStatus::Cancelled));
+    assert_eq!(offers_indexed(&fx), OFFERS - 2);
+    assert_err(
+        fx.client.try_run_job(&admin, &job_id, &2),
+        ContractError::JobNotRunning,
+    );
+
+    // The kind is free again, and a new job picks up the rest
+    let next = start_gc(&fx);
+    assert_ne!(next, job_id);
+    let done = fx.client.run_job(&admin, &next, &10);
+    assert_eq!((done.processed, done.status), (3, JobStatus::Completed));
+}
+
+#[test]
+fn test_one_running_job_per_kind() {
+    let fx = setup();
+    let admin = fx.admin();
+    start_gc(&fx);
+    assert_err(
+        fx.client.try_start_job(
+            &admin,
+            &admin_ops::PENDING_GC,
+            &JobParams::PendingGc(PendingCategory::ShareCode),
+        ),
+        ContractError::JobAlreadyRunning,
+    );
+    // Hand-driven runs would move the index under the job's cursor
+    assert_err(
+        fx.client
+            .try_gc_pending(&admin, &PendingCategory::AccessOffer, &0, &10),
+        ContractError::JobAlreadyRunning,
+    );
+    // Other kinds are unaffected
+    let sweep = fx.client.start_job(
+        &admin,
+        &admin_ops::GRANTEE_SWEEP,
+        &JobParams::GranteeSweep(fx.provider("dr_a")),
+    );
+    let swept = fx.client.run_job(&admin, &sweep, &10);
+    assert_eq!(swept.status, JobStatus::Completed);
+}
+
+#[test]
+fn test_job_rejections() {
+    let fx = setup();
+    let params = JobParams::PendingGc(PendingCategory::AccessOffer);
+    assert_err(
+        fx.client
+            .try_start_job(&fx.provider("dr_a"), &admin_ops::PENDING_GC, &params),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client
+            .try_start_job(&fx.admin(), &admin_ops::GRANTEE_SWEEP, &params),
+        ContractError::InvalidInput,
+    );
+    assert_err(
+        fx.client.try_run_job(&fx.admin(), &99, &1),
+        ContractError::JobNotFound,
+    );
+    let job_id = start_gc(&fx);
+    assert_err(
+        fx.client.try_run_job(&fx.provider("dr_a"), &job_id, &1),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_run_job(&fx.admin(), &job_id, &0),
+        ContractError::InvalidInput,
+    );
+}
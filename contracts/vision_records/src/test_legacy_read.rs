@@ -0,0 +1,132 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessLevel, ConsentType, ContractError, LegacyReadMode, RecordType, Role};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{testutils::Address as _, Address, Vec};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const DAY: u64 = 86_400;
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider_role("dr_b", Role::Staff)
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .build()
+}
+
+fn set_mode(fx: &TestContract, mode: LegacyReadMode) {
+    fx.client.set_legacy_read_mode(&fx.admin(), &mode);
+}
+
+#[test]
+fn test_open_by_default() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let expected = Vec::from_array(&fx.env, [fx.record(0)]);
+    assert_eq!(fx.client.get_legacy_read_mode(), LegacyReadMode::Open);
+    assert_eq!(fx.client.get_patient_records(&patient), expected);
+    assert_eq!(
+        fx.client.get_patient_records_filtered(&patient, &true),
+        expected
+    );
+    assert_eq!(
+        fx.client
+            .get_patient_records_page(&patient, &None, &10)
+            .record_ids,
+        expected
+    );
+}
+
+#[test]
+fn test_admin_only_requires_admin_auth() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    set_mode(&fx, LegacyReadMode::AdminOnly);
+
+    assert_eq!(fx.client.get_patient_records(&patient).len(), 1);
+    let auths = fx.env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths.first().unwrap().0, fx.admin());
+
+    fx.client.get_patient_records_filtered(&patient, &false);
+    assert_eq!(fx.env.auths().first().unwrap().0, fx.admin());
+    fx.client.get_patient_records_page(&patient, &None, &10);
+    assert_eq!(fx.env.auths().first().unwrap().0, fx.admin());
+}
+
+#[test]
+fn test_disabled_rejects_legacy_endpoints() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    set_mode(&fx, LegacyReadMode::Disabled);
+
+    assert_err(
+        fx.client.try_get_patient_records(&patient),
+        ContractError::LegacyEndpointDisabled,
+    );
+    assert_err(
+        fx.client.try_get_patient_records_filtered(&patient, &true),
+        ContractError::LegacyEndpointDisabled,
+    );
+    assert_err(
+        fx.client.try_get_patient_records_page(&patient, &None, &10),
+        ContractError::LegacyEndpointDisabled,
+    );
+
+    // The authenticated variant and internal bookkeeping are unaffected
+    assert_eq!(
+        fx.client.get_patient_records_as(&patient, &patient),
+        Vec::from_array(&fx.env, [fx.record(0)])
+    );
+    assert_eq!(fx.client.get_patient_quota_usage(&patient).used, 1);
+
+    set_mode(&fx, LegacyReadMode::Open);
+    assert_eq!(fx.client.get_patient_records(&patient).len(), 1);
+}
+
+#[test]
+fn test_authenticated_variant_checks_caller() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let dr_b = fx.provider("dr_b");
+    assert_err(
+        fx.client
+            .try_get_patient_records_as(&Address::generate(&fx.env), &patient),
+        ContractError::AccessDenied,
+    );
+    assert_err(
+        fx.client.try_get_patient_records_as(&dr_b, &patient),
+        ContractError::AccessDenied,
+    );
+
+    fx.client
+        .grant_consent(&patient, &dr_b, &ConsentType::Treatment, &DAY);
+    fx.client
+        .grant_access(&patient, &patient, &dr_b, &AccessLevel::Read, &DAY);
+    assert_eq!(fx.client.get_patient_records_as(&dr_b, &patient).len(), 1);
+    assert_eq!(
+        fx.client
+            .get_patient_records_as(&fx.admin(), &patient)
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn test_set_mode_requires_contract_admin() {
+    let fx = setup();
+    assert_err(
+        fx.client
+            .try_set_legacy_read_mode(&fx.provider("dr_a"), &LegacyReadMode::Disabled),
+        ContractError::Unauthorized,
+    );
+    assert_eq!(fx.client.get_legacy_read_mode(), LegacyReadMode::Open);
+}
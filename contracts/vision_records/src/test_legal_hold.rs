@@ -0,0 +1,149 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{ContractError, LegalHold, RecordPriority, RecordType};
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{vec, BytesN, String};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+
+/// `pat_a`'s examination has two versions and is under a legal hold.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .build();
+    fx.client.update_record(
+        &fx.provider("dr_a"),
+        &fx.record(0),
+        &String::from_str(&fx.env, HASH_B),
+    );
+    fx.client
+        .place_legal_hold(&fx.admin(), &fx.record(0), &case_ref(&fx));
+    fx
+}
+
+fn case_ref(fx: &TestContract) -> BytesN<32> {
+    BytesN::from_array(&fx.env, &[9; 32])
+}
+
+fn release(fx: &TestContract) {
+    fx.client.release_legal_hold(&fx.admin(), &fx.record(0));
+}
+
+#[test]
+fn test_hold_blocks_redaction() {
+    let fx = setup();
+    let redact = || {
+        fx.client
+            .try_redact_version(&fx.admin(), &fx.record(0), &1, &case_ref(&fx))
+    };
+    assert_err(redact(), ContractError::LegalHold);
+    release(&fx);
+    assert!(redact().is_ok());
+}
+
+#[test]
+fn test_hold_blocks_archival() {
+    let fx = setup();
+    let archive = || {
+        fx.client.try_set_record_priority(
+            &fx.provider("dr_a"),
+            &fx.record(0),
+            &RecordPriority::Archivable,
+        )
+    };
+    assert_err(archive(), ContractError::LegalHold);
+    // Raising the priority keeps the record, so it stays allowed
+    fx.client.set_record_priority(
+        &fx.provider("dr_a"),
+        &fx.record(0),
+        &RecordPriority::Critical,
+    );
+    release(&fx);
+    assert!(archive().is_ok());
+}
+
+#[test]
+fn test_hold_blocks_supersede() {
+    let fx = setup();
+    let supersede = || {
+        fx.client.try_supersede_record(
+            &fx.provider("dr_a"),
+            &fx.record(0),
+            &String::from_str(&fx.env, HASH_A),
+            &RecordType::Examination,
+        )
+    };
+    assert_err(supersede(), ContractError::LegalHold);
+    assert_eq!(fx.client.get_superseded_by(&fx.record(0)), None);
+    release(&fx);
+    assert!(supersede().is_ok());
+}
+
+#[test]
+fn test_held_record_stays_readable_and_updatable() {
+    let fx = setup();
+    let dr_a = fx.provider("dr_a");
+    let record = fx.client.get_record(&fx.patient("pat_a"), &fx.record(0));
+    assert_eq!(record.data_hash, String::from_str(&fx.env, HASH_B));
+    assert_eq!(
+        fx.client
+            .update_record(&dr_a, &fx.record(0), &String::from_str(&fx.env, HASH_A)),
+        3
+    );
+}
+
+#[test]
+fn test_hold_listing_and_access() {
+    let fx = setup();
+    let (admin, dr_a) = (fx.admin(), fx.provider("dr_a"));
+    assert_eq!(
+        fx.client.get_legal_holds(&admin),
+        vec![
+            &fx.env,
+            LegalHold {
+                record_id: fx.record(0),
+                case_ref_hash: case_ref(&fx),
+                placed_by: admin.clone(),
+                placed_at: FIXTURE_START_TIME,
+            }
+        ]
+    );
+    assert_err(
+        fx.client
+            .try_place_legal_hold(&admin, &fx.record(0), &case_ref(&fx)),
+        ContractError::AlreadyExists,
+    );
+    assert_err(
+        fx.client
+            .try_place_legal_hold(&dr_a, &fx.record(0), &case_ref(&fx)),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_release_legal_hold(&dr_a, &fx.record(0)),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_get_legal_holds(&dr_a),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_place_legal_hold(&admin, &999, &case_ref(&fx)),
+        ContractError::RecordNotFound,
+    );
+
+    release(&fx);
+    assert!(fx.client.get_legal_holds(&admin).is_empty());
+    assert_err(
+        fx.client.try_release_legal_hold(&admin, &fx.record(0)),
+        ContractError::LegalHoldNotFound,
+    );
+}
@@ -0,0 +1,238 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{ContractError, RecordType};
+use crate::events::topics;
+use crate::observer::MAX_OBSERVERS;
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, String, Symbol, Vec};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+
+mod recording {
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, Symbol, Vec};
+
+    /// Stub observer that stores every notification it receives.
+    #[contract]
+    pub struct RecordingObserver;
+
+    #[contractimpl]
+    impl RecordingObserver {
+        pub fn on_record_event(env: Env, topic: Symbol, record_id: u64, patient: Address) {
+            let mut calls = Self::calls(env.clone());
+            calls.push_back((topic, record_id, patient));
+            env.storage()
+                .instance()
+                .set(&symbol_short!("CALLS"), &calls);
+        }
+
+        pub fn calls(env: Env) -> Vec<(Symbol, u64, Address)> {
+            env.storage()
+                .instance()
+                .get(&symbol_short!("CALLS"))
+                .unwrap_or(Vec::new(&env))
+        }
+    }
+}
+
+mod failing {
+    use soroban_sdk::{contract, contractimpl, Address, Env, Symbol};
+
+    /// Stub observer that fails every notification.
+    #[contract]
+    pub struct FailingObserver;
+
+    #[contractimpl]
+    impl FailingObserver {
+        pub fn on_record_event(_env: Env, _topic: Symbol, _record_id: u64, _patient: Address) {
+            panic!("observer failure");
+        }
+    }
+}
+
+use failing::FailingObserver;
+use recording::{RecordingObserver, RecordingObserverClient};
+
+/// Observer notification is turned on.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .build();
+    fx.client.set_observers_enabled(&fx.admin(), &true);
+    fx
+}
+
+fn add(fx: &TestContract) -> u64 {
+    let provider = fx.provider("dr_a");
+    fx.client.add_record(
+        &provider,
+        &fx.patient("pat_a"),
+        &provider,
+        &RecordType::Examination,
+        &String::from_str(&fx.env, HASH_A),
+    )
+}
+
+fn subscribe(fx: &TestContract, events: &[Symbol]) -> Vec<Symbol> {
+    let mut subscribed = Vec::new(&fx.env);
+    for event in events {
+        subscribed.push_back(event.clone());
+    }
+    subscribed
+}
+
+fn register_recorder<'a>(fx: &'a TestContract, events: &[Symbol]) -> RecordingObserverClient<'a> {
+    let address = fx.env.register(RecordingObserver, ());
+    fx.client
+        .register_observer(&fx.admin(), &address, &subscribe(fx, events));
+    RecordingObserverClient::new(&fx.env, &address)
+}
+
+#[test]
+fn test_observer_notified_on_add_and_update() {
+    let fx = setup();
+    let recorder = register_recorder(&fx, &[symbol_short!("REC_ADD"), symbol_short!("REC_UPD")]);
+    let patient = fx.patient("pat_a");
+
+    let record_id = add(&fx);
+    fx.client.update_record(
+        &fx.provider("dr_a"),
+        &record_id,
+        &String::from_str(&fx.env, HASH_B),
+    );
+
+    let calls = recorder.calls();
+    assert_eq!(calls.len(), 2);
+    assert_eq!(
+        calls.get(0).unwrap(),
+        (symbol_short!("REC_ADD"), record_id, patient.clone())
+    );
+    assert_eq!(
+        calls.get(1).unwrap(),
+        (symbol_short!("REC_UPD"), record_id, patient)
+    );
+}
+
+#[test]
+fn test_observers_not_called_while_disabled() {
+    let fx = setup();
+    let recorder = register_recorder(&fx, &[symbol_short!("REC_ADD")]);
+    assert!(fx.client.are_observers_enabled());
+
+    fx.client.set_observers_enabled(&fx.admin(), &false);
+    assert!(!fx.client.are_observers_enabled());
+    add(&fx);
+    assert_eq!(recorder.calls().len(), 0);
+
+    fx.client.set_observers_enabled(&fx.admin(), &true);
+    add(&fx);
+    assert_eq!(recorder.calls().len(), 1);
+
+    assert_err(
+        fx.client
+            .try_set_observers_enabled(&fx.provider("dr_a"), &false),
+        ContractError::Unauthorized,
+    );
+}
+
+#[test]
+fn test_observer_only_receives_subscribed_events() {
+    let fx = setup();
+    let recorder = register_recorder(&fx, &[symbol_short!("REC_UPD")]);
+
+    let record_id = add(&fx);
+    assert_eq!(recorder.calls().len(), 0);
+
+    fx.client.update_record(
+        &fx.provider("dr_a"),
+        &record_id,
+        &String::from_str(&fx.env, HASH_B),
+    );
+    assert_eq!(recorder.calls().len(), 1);
+}
+
+#[test]
+fn test_failing_observer_does_not_abort_write() {
+    let fx = setup();
+    let failing = fx.env.register(FailingObserver, ());
+    fx.client.register_observer(
+        &fx.admin(),
+        &failing,
+        &subscribe(&fx, &[symbol_short!("REC_ADD")]),
+    );
+    let recorder = register_recorder(&fx, &[symbol_short!("REC_ADD")]);
+
+    let record_id = add(&fx);
+    assert!(fx.published((topics::OBS_FAIL, failing, record_id)));
+
+    // The record was stored and later observers still ran
+    assert_eq!(
+        fx.client.get_record(&fx.provider("dr_a"), &record_id).id,
+        record_id
+    );
+    assert_eq!(recorder.calls().len(), 1);
+}
+
+#[test]
+fn test_observer_cap_and_reregistration() {
+    let fx = setup();
+    let events = subscribe(&fx, &[symbol_short!("REC_ADD")]);
+    let mut first = None;
+    for _ in 0..MAX_OBSERVERS {
+        let address = Address::generate(&fx.env);
+        fx.client.register_observer(&fx.admin(), &address, &events);
+        first.get_or_insert(address);
+    }
+    assert_err(
+        fx.client
+            .try_register_observer(&fx.admin(), &Address::generate(&fx.env), &events),
+        ContractError::ObserverLimitReached,
+    );
+
+    // Re-registering replaces subscriptions without taking a new slot
+    let first = first.unwrap();
+    let updated = subscribe(&fx, &[symbol_short!("REC_UPD")]);
+    fx.client.register_observer(&fx.admin(), &first, &updated);
+    let observers = fx.client.get_observers();
+    assert_eq!(observers.len(), MAX_OBSERVERS);
+    assert_eq!(observers.get(0).unwrap().events, updated);
+
+    fx.client.unregister_observer(&fx.admin(), &first);
+    fx.client
+        .register_observer(&fx.admin(), &Address::generate(&fx.env), &events);
+}
+
+#[test]
+fn test_register_observer_validation() {
+    let fx = setup();
+    let address = Address::generate(&fx.env);
+    assert_err(
+        fx.client.try_register_observer(
+            &fx.provider("dr_a"),
+            &address,
+            &subscribe(&fx, &[symbol_short!("REC_ADD")]),
+        ),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client
+            .try_register_observer(&fx.admin(), &address, &Vec::new(&fx.env)),
+        ContractError::InvalidInput,
+    );
+    assert_err(
+        fx.client.try_register_observer(
+            &fx.admin(),
+            &address,
+            &subscribe(&fx, &[symbol_short!("REC_DEL")]),
+        ),
+        ContractError::InvalidInput,
+    );
+    assert_eq!(fx.client.get_observers().len(), 0);
+}
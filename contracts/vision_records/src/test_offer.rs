@@ -0,0 +1,144 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{
+    offer, AccessGrant, AccessLevel, ContractError, VisionRecordsContract,
+    VisionRecordsContractClient,
+};
+use soroban_sdk::{symbol_short, testutils::Address as _, testutils::Ledger, Address, Env};
+
+fn setup() -> (Env, VisionRecordsContractClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register(VisionRecordsContract, ());
+    let client = VisionRecordsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &None);
+
+    (env, client, admin)
+}
+
+fn access_grant(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    patient: &Address,
+    grantee: &Address,
+) -> Option<AccessGrant> {
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("ACCESS"), patient.clone(), grantee.clone()))
+    })
+}
+
+#[test]
+fn test_offer_grants_nothing_until_accepted() {
+    let (env, client, _admin) = setup();
+    let patient = Address::generate(&env);
+    let grantee = Address::generate(&env);
+
+    client.grant_access_pending_acceptance(&patient, &grantee, &AccessLevel::Read, &3_600);
+
+    assert!(access_grant(&env, &client, &patient, &grantee).is_none());
+    assert!(!client.meets_access_level(&patient, &grantee, &AccessLevel::Read));
+
+    let pending = client.get_access_offer(&patient, &grantee).unwrap();
+    assert_eq!(pending.offered_at, 1_000);
+    assert_eq!(
+        pending.expires_at,
+        1_000 + offer::DEFAULT_OFFER_WINDOW_SECONDS
+    );
+}
+
+#[test]
+fn test_accept_starts_duration_at_acceptance() {
+    let (env, client, _admin) = setup();
+    let patient = Address::generate(&env);
+    let grantee = Address::generate(&env);
+
+    client.grant_access_pending_acceptance(&patient, &grantee, &AccessLevel::Write, &3_600);
+
+    env.ledger().set_timestamp(5_000);
+    client.accept_access(&grantee, &patient);
+
+    let grant = access_grant(&env, &client, &patient, &grantee).unwrap();
+    assert_eq!(grant.level, AccessLevel::Write);
+    assert_eq!(grant.granted_at, 5_000);
+    assert_eq!(grant.expires_at, 5_000 + 3_600);
+    assert!(client.get_access_offer(&patient, &grantee).is_none());
+}
+
+#[test]
+fn test_decline_removes_offer() {
+    let (env, client, _admin) = setup();
+    let patient = Address::generate(&env);
+    let grantee = Address::generate(&env);
+
+    client.grant_access_pending_acceptance(&patient, &grantee, &AccessLevel::Read, &3_600);
+    client.decline_access(&grantee, &patient);
+
+    assert!(client.get_access_offer(&patient, &grantee).is_none());
+    assert!(access_grant(&env, &client, &patient, &grantee).is_none());
+
+    let res = client.try_accept_access(&grantee, &patient);
+    assert_eq!(res, Err(Ok(ContractError::AccessOfferNotFound)));
+    let res = client.try_decline_access(&grantee, &patient);
+    assert_eq!(res, Err(Ok(ContractError::AccessOfferNotFound)));
+}
+
+#[test]
+fn test_offer_lapses_after_configured_window() {
+    let (env, client, admin) = setup();
+    let patient = Address::generate(&env);
+    let grantee = Address::generate(&env);
+
+    client.set_access_offer_window(&admin, &600);
+    assert_eq!(client.get_access_offer_window(), 600);
+    client.grant_access_pending_acceptance(&patient, &grantee, &AccessLevel::Read, &3_600);
+
+    env.ledger().set_timestamp(1_600);
+    let res = client.try_accept_access(&grantee, &patient);
+    assert_eq!(res, Err(Ok(ContractError::ExpiredAccess)));
+    assert!(access_grant(&env, &client, &patient, &grantee).is_none());
+}
+
+#[test]
+fn test_offer_window_requires_admin_and_is_bounded() {
+    let (env, client, admin) = setup();
+    let outsider = Address::generate(&env);
+
+    let res = client.try_set_access_offer_window(&outsider, &600);
+    assert_eq!(res, Err(Ok(ContractError::Unauthorized)));
+
+    let res = client.try_set_access_offer_window(&admin, &0);
+    assert_eq!(res, Err(Ok(ContractError::InvalidInput)));
+    // Beyond the max temporary-entry TTL every new offer would trap
+    let res = client.try_set_access_offer_window(&admin, &(365 * 86_400));
+    assert_eq!(res, Err(Ok(ContractError::InvalidInput)));
+    assert_eq!(
+        client.get_access_offer_window(),
+        offer::DEFAULT_OFFER_WINDOW_SECONDS
+    );
+}
+
+#[test]
+fn test_frozen_grantee_cannot_accept() {
+    let (env, client, admin) = setup();
+    let patient = Address::generate(&env);
+    let grantee = Address::generate(&env);
+
+    client.grant_access_pending_acceptance(&patient, &grantee, &AccessLevel::Read, &3_600);
+    client.freeze_user(&admin, &grantee);
+
+    let res = client.try_accept_access(&grantee, &patient);
+    assert_eq!(res, Err(Ok(ContractError::InvalidInput)));
+    assert!(access_grant(&env, &client, &patient, &grantee).is_none());
+    assert!(client.get_access_offer(&patient, &grantee).is_some());
+}
@@ -0,0 +1,191 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+extern crate std;
+
+use super::{AccessLevel, ConsentType, ContractError, RecordType};
+use crate::organization::MAX_UNITS_PER_ORG;
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Symbol};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const DAY: u64 = 86_400;
+const ORG: Symbol = symbol_short!("HOSP");
+const OPHTH: Symbol = symbol_short!("OPHTH");
+
+/// `HOSP` has an `OPHTH` unit. `dr_a` is only in the unit, `dr_b` is only
+/// a direct member and `dr_c` is both.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_provider("dr_b")
+        .with_provider("dr_c")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .build();
+    let admin = fx.admin();
+    fx.client.create_organization(&admin, &ORG, &admin);
+    fx.client.create_unit(&admin, &ORG, &OPHTH);
+    fx.client
+        .add_member_to_unit(&admin, &ORG, &OPHTH, &fx.provider("dr_a"));
+    fx.client.add_org_member(&admin, &ORG, &fx.provider("dr_b"));
+    fx.client.add_org_member(&admin, &ORG, &fx.provider("dr_c"));
+    fx.client
+        .add_member_to_unit(&admin, &ORG, &OPHTH, &fx.provider("dr_c"));
+    fx
+}
+
+fn grant(fx: &TestContract, unit: Option<Symbol>, level: AccessLevel, duration: u64) {
+    fx.client
+        .grant_org_access(&fx.patient("pat_a"), &ORG, &unit, &level, &duration);
+}
+
+fn access(fx: &TestContract, provider: &str) -> AccessLevel {
+    fx.client
+        .check_access(&fx.patient("pat_a"), &fx.provider(provider))
+}
+
+#[test]
+fn test_unit_grant_wins_over_org_grant() {
+    let fx = setup();
+    grant(&fx, None, AccessLevel::Full, 30 * DAY);
+    grant(&fx, Some(OPHTH), AccessLevel::Read, DAY);
+
+    // Most specific wins, even when it is the lower level
+    assert_eq!(access(&fx, "dr_a"), AccessLevel::Read);
+    assert_eq!(access(&fx, "dr_c"), AccessLevel::Read);
+    assert_eq!(access(&fx, "dr_b"), AccessLevel::Full);
+
+    // Expiries do not stack: once the unit grant lapses the org grant applies
+    fx.advance_time(DAY + 1);
+    assert_eq!(access(&fx, "dr_a"), AccessLevel::Full);
+    assert_eq!(access(&fx, "dr_c"), AccessLevel::Full);
+
+    fx.advance_time(30 * DAY);
+    assert_eq!(access(&fx, "dr_a"), AccessLevel::None);
+}
+
+#[test]
+fn test_personal_grant_wins_over_org_grant() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let dr_b = fx.provider("dr_b");
+    grant(&fx, None, AccessLevel::Write, 30 * DAY);
+    fx.client
+        .grant_consent(&patient, &dr_b, &ConsentType::Treatment, &DAY);
+    fx.client
+        .grant_access(&patient, &patient, &dr_b, &AccessLevel::Read, &DAY);
+    assert_eq!(access(&fx, "dr_b"), AccessLevel::Read);
+
+    fx.client.revoke_access_immediate(&patient, &dr_b);
+    assert_eq!(access(&fx, "dr_b"), AccessLevel::Write);
+}
+
+#[test]
+fn test_unit_removal_keeps_only_direct_org_access() {
+    let fx = setup();
+    let admin = fx.admin();
+    grant(&fx, None, AccessLevel::Read, DAY);
+    grant(&fx, Some(OPHTH), AccessLevel::Write, DAY);
+
+    fx.client
+        .remove_member_from_unit(&admin, &ORG, &OPHTH, &fx.provider("dr_a"));
+    fx.client
+        .remove_member_from_unit(&admin, &ORG, &OPHTH, &fx.provider("dr_c"));
+    // dr_a only reached the org through the unit; dr_c is also a direct member
+    assert_eq!(access(&fx, "dr_a"), AccessLevel::None);
+    assert!(fx.client.get_org_membership(&fx.provider("dr_a")).is_none());
+    assert_eq!(access(&fx, "dr_c"), AccessLevel::Read);
+
+    fx.client
+        .remove_org_member(&admin, &ORG, &fx.provider("dr_c"));
+    assert_eq!(access(&fx, "dr_c"), AccessLevel::None);
+
+    // Rejoining restores unit access without a new grant
+    fx.client
+        .add_member_to_unit(&admin, &ORG, &OPHTH, &fx.provider("dr_a"));
+    assert_eq!(access(&fx, "dr_a"), AccessLevel::Write);
+}
+
+#[test]
+fn test_org_access_reads_records_and_revokes() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let dr_a = fx.provider("dr_a");
+    grant(&fx, Some(OPHTH), AccessLevel::Read, DAY);
+    assert_eq!(fx.client.get_access_epoch(&patient), 1);
+    assert_eq!(fx.client.get_record(&dr_a, &fx.record(0)).id, fx.record(0));
+
+    fx.client.revoke_org_access(&patient, &ORG, &Some(OPHTH));
+    assert_eq!(fx.client.get_access_epoch(&patient), 2);
+    assert_eq!(access(&fx, "dr_a"), AccessLevel::None);
+    assert!(fx
+        .client
+        .get_org_grant(&patient, &ORG, &Some(OPHTH))
+        .is_none());
+    assert_err(
+        fx.client
+            .try_revoke_org_access(&patient, &ORG, &Some(OPHTH)),
+        ContractError::OrganizationNotFound,
+    );
+}
+
+#[test]
+fn test_membership_and_grant_validation() {
+    let fx = setup();
+    let admin = fx.admin();
+    let patient = fx.patient("pat_a");
+    let other = symbol_short!("CLINIC");
+    fx.client.create_organization(&admin, &other, &admin);
+
+    // A user belongs to one organization at a time
+    assert_err(
+        fx.client
+            .try_add_org_member(&admin, &other, &fx.provider("dr_a")),
+        ContractError::AlreadyExists,
+    );
+    assert_err(
+        fx.client
+            .try_create_unit(&fx.provider("dr_b"), &ORG, &symbol_short!("CARDIO")),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_add_member_to_unit(
+            &admin,
+            &ORG,
+            &symbol_short!("CARDIO"),
+            &Address::generate(&fx.env),
+        ),
+        ContractError::OrganizationNotFound,
+    );
+    assert_err(
+        fx.client.try_grant_org_access(
+            &patient,
+            &ORG,
+            &Some(symbol_short!("CARDIO")),
+            &AccessLevel::Read,
+            &DAY,
+        ),
+        ContractError::OrganizationNotFound,
+    );
+    assert_err(
+        fx.client
+            .try_grant_org_access(&patient, &ORG, &None, &AccessLevel::None, &DAY),
+        ContractError::InvalidInput,
+    );
+
+    for i in 0..MAX_UNITS_PER_ORG {
+        let unit = Symbol::new(&fx.env, &std::format!("U{}", i));
+        fx.client.create_unit(&admin, &other, &unit);
+    }
+    assert_err(
+        fx.client
+            .try_create_unit(&admin, &other, &symbol_short!("MORE")),
+        ContractError::InvalidInput,
+    );
+}
@@ -0,0 +1,241 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+extern crate std;
+
+use super::{ContractError, PatientIndexCheck, PatientIndexRepair, Permission, RecordType};
+use crate::bounded::MAX_BOUNDED_ITEMS;
+use crate::cursor;
+use crate::patient_index::{self, MAX_REPAIR_INDEX_SIZE};
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{BytesN, String, Vec};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const DAY: u64 = 86_400;
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .with_record(RecordType::Examination, HASH_A)
+        .with_record(RecordType::Examination, HASH_A)
+        .build()
+}
+
+fn add(fx: &TestContract) -> u64 {
+    let dr_a = fx.provider("dr_a");
+    fx.client.add_record(
+        &dr_a,
+        &fx.patient("pat_a"),
+        &dr_a,
+        &RecordType::Examination,
+        &String::from_str(&fx.env, HASH_A),
+    )
+}
+
+fn listed(fx: &TestContract) -> Vec<u64> {
+    fx.client.get_patient_records(&fx.patient("pat_a"))
+}
+
+/// Walks every window of `pat_a`'s list.
+fn verify(fx: &TestContract) -> bool {
+    let mut offset = 0;
+    loop {
+        let check = fx
+            .client
+            .verify_patient_index(&fx.patient("pat_a"), &offset);
+        if !check.ordered {
+            return false;
+        }
+        if !check.truncated {
+            return true;
+        }
+        offset = check.next_offset;
+    }
+}
+
+/// Test-only hook: overwrites `pat_a`'s record list, as a writer that
+/// appended blindly would have left it.
+fn write_list(fx: &TestContract, record_ids: &[u64]) {
+    let patient = fx.patient("pat_a");
+    fx.env.as_contract(&fx.contract_id, || {
+        fx.env.storage().persistent().set(
+            &patient_index::records_key(&patient),
+            &Vec::from_slice(&fx.env, record_ids),
+        );
+    });
+}
+
+fn generation(fx: &TestContract) -> u32 {
+    fx.env.as_contract(&fx.contract_id, || {
+        cursor::records_generation(&fx.env, &fx.patient("pat_a"))
+    })
+}
+
+#[test]
+fn test_writers_keep_index_in_creation_order() {
+    let fx = setup();
+    fx.advance_time(DAY);
+    let latest = add(&fx);
+    let dr_a = fx.provider("dr_a");
+    fx.client
+        .grant_custom_permission(&fx.admin(), &dr_a, &Permission::ImportRecords);
+    let backdated = fx.client.add_record_backdated(
+        &dr_a,
+        &fx.patient("pat_a"),
+        &dr_a,
+        &RecordType::Examination,
+        &String::from_str(&fx.env, HASH_A),
+        &(FIXTURE_START_TIME - 1),
+    );
+
+    assert!(verify(&fx));
+    assert_eq!(listed(&fx).first(), Some(backdated));
+    assert_eq!(listed(&fx).last(), Some(latest));
+}
+
+#[test]
+fn test_insert_rejects_duplicates() {
+    let fx = setup();
+    let record_id = fx.record(1);
+    let patient = fx.patient("pat_a");
+
+    let inserted = fx.env.as_contract(&fx.contract_id, || {
+        patient_index::insert(&fx.env, &patient, record_id)
+    });
+    assert!(!inserted);
+    assert_eq!(listed(&fx).len(), 3);
+    assert!(verify(&fx));
+}
+
+#[test]
+fn test_claimed_records_are_placed_by_creation_time() {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .build();
+    let identity = BytesN::from_array(&fx.env, &[7; 32]);
+    let walk_in = fx.client.add_record_provisional(
+        &fx.provider("dr_a"),
+        &identity,
+        &RecordType::Examination,
+        &String::from_str(&fx.env, HASH_A),
+    );
+    fx.advance_time(DAY);
+    let own = add(&fx);
+
+    fx.client
+        .claim_provisional_records(&fx.patient("pat_a"), &identity, &fx.admin());
+    assert_eq!(listed(&fx), Vec::from_array(&fx.env, [walk_in, own]));
+    assert!(verify(&fx));
+    fx.assert_invariants();
+}
+
+#[test]
+fn test_repair_restores_messy_index() {
+    let fx = setup();
+    let (r0, r1, r2) = (fx.record(0), fx.record(1), fx.record(2));
+    write_list(&fx, &[r2, r0, r1, r0, r2]);
+    assert!(!verify(&fx));
+    let before = generation(&fx);
+
+    let result = fx
+        .client
+        .repair_patient_index(&fx.admin(), &fx.patient("pat_a"));
+    assert_eq!(
+        result,
+        PatientIndexRepair {
+            duplicates_removed: 2,
+            entries_moved: 3,
+        }
+    );
+    assert_eq!(listed(&fx), Vec::from_array(&fx.env, [r0, r1, r2]));
+    assert!(verify(&fx));
+    assert_ne!(generation(&fx), before);
+
+    // Later writes preserve the restored invariant
+    let next = add(&fx);
+    assert_eq!(listed(&fx).last(), Some(next));
+    assert!(verify(&fx));
+    fx.assert_invariants();
+}
+
+#[test]
+fn test_repair_of_clean_index_changes_nothing() {
+    let fx = setup();
+    let before = generation(&fx);
+
+    let result = fx
+        .client
+        .repair_patient_index(&fx.admin(), &fx.patient("pat_a"));
+    assert_eq!(
+        result,
+        PatientIndexRepair {
+            duplicates_removed: 0,
+            entries_moved: 0,
+        }
+    );
+    assert_eq!(generation(&fx), before);
+}
+
+#[test]
+fn test_repair_requires_admin_and_bounded_index() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    assert_err(
+        fx.client
+            .try_repair_patient_index(&fx.provider("dr_a"), &patient),
+        ContractError::Unauthorized,
+    );
+
+    let oversized: std::vec::Vec<u64> = (0..=u64::from(MAX_REPAIR_INDEX_SIZE)).collect();
+    write_list(&fx, &oversized);
+    assert_err(
+        fx.client.try_repair_patient_index(&fx.admin(), &patient),
+        ContractError::IndexTooLarge,
+    );
+}
+
+#[test]
+fn test_verify_is_bounded_and_checks_across_windows() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    // Missing records sort first, by ID. The first window is in order; the
+    // entry opening the second sorts before the one that closed the first.
+    let mut ids: std::vec::Vec<u64> = (1_000..1_000 + u64::from(MAX_BOUNDED_ITEMS)).collect();
+    ids.push(999);
+    ids.push(fx.record(0));
+    write_list(&fx, &ids);
+
+    assert_eq!(
+        fx.client.verify_patient_index(&patient, &0),
+        PatientIndexCheck {
+            ordered: true,
+            truncated: true,
+            next_offset: MAX_BOUNDED_ITEMS,
+        }
+    );
+    assert_eq!(
+        fx.client.verify_patient_index(&patient, &MAX_BOUNDED_ITEMS),
+        PatientIndexCheck {
+            ordered: false,
+            truncated: false,
+            next_offset: MAX_BOUNDED_ITEMS + 2,
+        }
+    );
+    assert_eq!(
+        fx.client.verify_patient_index(&patient, &u32::MAX),
+        PatientIndexCheck {
+            ordered: true,
+            truncated: false,
+            next_offset: MAX_BOUNDED_ITEMS + 2,
+        }
+    );
+}
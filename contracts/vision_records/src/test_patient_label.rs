@@ -0,0 +1,130 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessLevel, ConsentType, ContractError, RecordPriority, RecordType};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+const DAY: u64 = 86_400;
+
+/// `pat_a` has one record from `dr_a` and labeled it; `pat_b` has one of
+/// their own.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .with_patient("pat_b")
+        .with_record(RecordType::Examination, HASH_A)
+        .build();
+    fx.client
+        .set_patient_label(&fx.patient("pat_a"), &fx.record(0), &label(&fx, "my LASIK"));
+    fx
+}
+
+fn label(fx: &TestContract, text: &str) -> String {
+    String::from_str(&fx.env, text)
+}
+
+fn label_seen_by(fx: &TestContract, caller: &Address, record_id: u64) -> Option<String> {
+    fx.client
+        .get_record_with_metadata(caller, &record_id)
+        .patient_label
+}
+
+#[test]
+fn test_label_shown_only_to_patient() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let grantee = Address::generate(&fx.env);
+    fx.client
+        .grant_consent(&patient, &grantee, &ConsentType::Treatment, &DAY);
+    fx.client
+        .grant_access(&patient, &patient, &grantee, &AccessLevel::Read, &DAY);
+
+    let mine = Some(label(&fx, "my LASIK"));
+    assert_eq!(label_seen_by(&fx, &patient, fx.record(0)), mine);
+    assert_eq!(label_seen_by(&fx, &fx.provider("dr_a"), fx.record(0)), None);
+    assert_eq!(label_seen_by(&fx, &grantee, fx.record(0)), None);
+
+    let page = |caller: &Address| {
+        fx.client
+            .get_patient_records_meta(caller, &patient, &0, &10)
+            .get_unchecked(0)
+            .patient_label
+    };
+    assert_eq!(page(&patient), mine);
+    assert_eq!(page(&grantee), None);
+}
+
+#[test]
+fn test_label_rejections() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    assert_err(
+        fx.client
+            .try_set_patient_label(&patient, &fx.record(1), &label(&fx, "not mine")),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_remove_patient_label(&patient, &fx.record(1)),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client
+            .try_set_patient_label(&patient, &fx.record(0), &label(&fx, &"x".repeat(49))),
+        ContractError::InvalidInput,
+    );
+    assert_err(
+        fx.client
+            .try_set_patient_label(&patient, &fx.record(0), &label(&fx, "")),
+        ContractError::InvalidInput,
+    );
+    assert_err(
+        fx.client
+            .try_set_patient_label(&patient, &999, &label(&fx, "gone")),
+        ContractError::RecordNotFound,
+    );
+    // The other patient's record stays unlabeled
+    assert_eq!(label_seen_by(&fx, &fx.patient("pat_b"), fx.record(1)), None);
+}
+
+#[test]
+fn test_label_survives_supersede_and_archival() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    fx.client.set_record_priority(
+        &fx.provider("dr_a"),
+        &fx.record(0),
+        &RecordPriority::Archivable,
+    );
+    fx.client
+        .set_patient_label(&patient, &fx.record(0), &label(&fx, "old glasses"));
+
+    let new_id = fx.client.supersede_record(
+        &fx.provider("dr_a"),
+        &fx.record(0),
+        &String::from_str(&fx.env, HASH_B),
+        &RecordType::Examination,
+    );
+    assert_eq!(
+        label_seen_by(&fx, &patient, new_id),
+        Some(label(&fx, "old glasses"))
+    );
+
+    fx.client.remove_patient_label(&patient, &fx.record(0));
+    assert_eq!(label_seen_by(&fx, &patient, fx.record(0)), None);
+    assert_err(
+        fx.client.try_remove_patient_label(&patient, &fx.record(0)),
+        ContractError::PatientLabelNotFound,
+    );
+    // The superseding record keeps its own copy
+    assert!(label_seen_by(&fx, &patient, new_id).is_some());
+}
@@ -0,0 +1,165 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessLevel, AuthAction, BatchRecordInput, ConsentType, ContractError, RecordType};
+use crate::auth;
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{testutils::Address as _, Address, String, Symbol, Vec};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_patient("pat_b")
+        .build()
+}
+
+fn text(fx: &TestContract, value: &str) -> String {
+    String::from_str(&fx.env, value)
+}
+
+fn add_note(fx: &TestContract) -> u64 {
+    fx.client
+        .add_patient_note(&fx.patient("pat_a"), &text(fx, HASH_A))
+}
+
+#[test]
+fn test_patient_adds_note_without_write_permission() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let note_id = add_note(&fx);
+
+    let note = fx.client.get_record(&patient, &note_id);
+    assert_eq!(note.record_type, RecordType::PatientNote);
+    assert_eq!(note.patient, patient);
+    assert_eq!(note.provider, patient);
+    assert_eq!(fx.client.get_patient_records(&patient).len(), 1);
+
+    // Adding a note through add_record follows the same rule.
+    fx.client.add_record(
+        &patient,
+        &patient,
+        &patient,
+        &RecordType::PatientNote,
+        &text(&fx, HASH_B),
+    );
+}
+
+#[test]
+fn test_only_the_patient_may_add_notes() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let provider = fx.provider("dr_a");
+
+    // Providers may not author notes, even with write permission.
+    assert_err(
+        fx.client.try_add_record(
+            &provider,
+            &patient,
+            &provider,
+            &RecordType::PatientNote,
+            &text(&fx, HASH_A),
+        ),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_add_records(
+            &provider,
+            &Vec::from_array(
+                &fx.env,
+                [BatchRecordInput {
+                    patient: patient.clone(),
+                    record_type: RecordType::PatientNote,
+                    data_hash: text(&fx, HASH_A),
+                }],
+            ),
+        ),
+        ContractError::InvalidRecordType,
+    );
+
+    // A patient writes only on their own chart.
+    assert_err(
+        fx.client.try_add_record(
+            &patient,
+            &fx.patient("pat_b"),
+            &patient,
+            &RecordType::PatientNote,
+            &text(&fx, HASH_A),
+        ),
+        ContractError::Unauthorized,
+    );
+
+    // The Patient role is required.
+    assert_err(
+        fx.client
+            .try_add_patient_note(&Address::generate(&fx.env), &text(&fx, HASH_A)),
+        ContractError::Unauthorized,
+    );
+
+    fx.client.freeze_user(&fx.admin(), &patient);
+    assert_err(
+        fx.client.try_add_patient_note(&patient, &text(&fx, HASH_A)),
+        ContractError::Unauthorized,
+    );
+}
+
+#[test]
+fn test_notes_readable_with_read_access() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let note_id = add_note(&fx);
+    let clinic = Address::generate(&fx.env);
+
+    assert_err(
+        fx.client.try_get_record(&clinic, &note_id),
+        ContractError::Unauthorized,
+    );
+
+    fx.client
+        .grant_consent(&patient, &clinic, &ConsentType::Treatment, &3_600);
+    fx.client
+        .grant_access(&patient, &patient, &clinic, &AccessLevel::Read, &3_600);
+    assert_eq!(fx.client.get_record(&clinic, &note_id).id, note_id);
+    assert_eq!(
+        fx.client.get_record(&fx.provider("dr_a"), &note_id).id,
+        note_id
+    );
+}
+
+#[test]
+fn test_notes_are_immutable() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let note_id = add_note(&fx);
+
+    for caller in [patient.clone(), fx.admin()] {
+        assert_err(
+            fx.client
+                .try_update_record(&caller, &note_id, &text(&fx, HASH_B)),
+            ContractError::RecordImmutable,
+        );
+        assert_err(
+            fx.client.try_supersede_record(
+                &caller,
+                &note_id,
+                &text(&fx, HASH_B),
+                &RecordType::PatientNote,
+            ),
+            ContractError::RecordImmutable,
+        );
+        let decision = fx.client.authorize(&caller, &AuthAction::Rollback(note_id));
+        assert!(!decision.allowed);
+        assert_eq!(decision.reason, Symbol::new(&fx.env, auth::IMMUTABLE));
+    }
+
+    assert_eq!(fx.client.get_record_version_count(&note_id), 1);
+    assert_eq!(fx.client.get_superseded_by(&note_id), None);
+}
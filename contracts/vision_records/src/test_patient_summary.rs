@@ -0,0 +1,198 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{
+    AccessLevel, ConsentType, ContractError, Permission, RecordType, RecordTypeCount, Role,
+    SummaryVisibility,
+};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+const HASH: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const DAY: u64 = 86_400;
+
+/// `dr_a` wrote two examinations and a diagnosis for `pat_a`; `dr_b` is
+/// a clinician with no grant, `carer` a non-clinician holding a grant and
+/// `clerk` a staff member with neither.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider_role("clerk", Role::Staff)
+        .with_provider_role("carer", Role::Staff)
+        .with_provider("dr_b")
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH)
+        .with_record(RecordType::Diagnosis, HASH)
+        .with_record(RecordType::Examination, HASH)
+        .build();
+    let (patient, carer) = (fx.patient("pat_a"), fx.provider("carer"));
+    fx.client
+        .grant_access(&patient, &patient, &carer, &AccessLevel::Read, &DAY);
+    fx.client
+        .grant_consent(&patient, &carer, &ConsentType::Treatment, &DAY);
+    fx
+}
+
+fn can_read(fx: &TestContract, caller: &Address) -> bool {
+    match fx
+        .client
+        .try_get_patient_profile_summary(caller, &fx.patient("pat_a"))
+    {
+        Ok(_) => true,
+        Err(Ok(ContractError::AccessDenied)) => false,
+        other => panic!("unexpected result {:?}", other),
+    }
+}
+
+/// Who can read the summary, in the order patient, admin, clinician
+/// without a grant, grantee, staff without a grant, unregistered address.
+fn readers(fx: &TestContract) -> [bool; 6] {
+    let callers = [
+        fx.patient("pat_a"),
+        fx.admin(),
+        fx.provider("dr_b"),
+        fx.provider("carer"),
+        fx.provider("clerk"),
+        Address::generate(&fx.env),
+    ];
+    callers.map(|caller| can_read(fx, &caller))
+}
+
+#[test]
+fn test_visibility_modes() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    assert_eq!(
+        fx.client.get_summary_visibility(&patient),
+        SummaryVisibility::Private
+    );
+    assert_eq!(readers(&fx), [true, true, false, false, false, false]);
+
+    let cases = [
+        (
+            SummaryVisibility::GranteesOnly,
+            [true, true, false, true, false, false],
+        ),
+        (
+            SummaryVisibility::RegisteredProvidersOnly,
+            [true, true, true, true, false, false],
+        ),
+        (
+            SummaryVisibility::Public,
+            [true, true, true, true, true, true],
+        ),
+        (
+            SummaryVisibility::Private,
+            [true, true, false, false, false, false],
+        ),
+    ];
+    for (mode, expected) in cases {
+        fx.client.set_summary_visibility(&patient, &mode);
+        assert_eq!(fx.client.get_summary_visibility(&patient), mode);
+        assert_eq!(readers(&fx), expected, "{:?}", mode);
+    }
+}
+
+#[test]
+fn test_counts_and_latest_per_type() {
+    let fx = setup();
+    let (patient, dr_a) = (fx.patient("pat_a"), fx.provider("dr_a"));
+    let summary = fx.client.get_patient_profile_summary(&patient, &patient);
+    assert_eq!(summary.total, 3);
+    assert_eq!(summary.by_type.len(), 2);
+    assert_eq!(
+        summary.by_type.get(0).unwrap(),
+        RecordTypeCount {
+            record_type: RecordType::Examination,
+            count: 2,
+            latest_created_at: fx.env.ledger().timestamp(),
+        }
+    );
+
+    fx.advance_time(30 * DAY);
+    let now = fx.env.ledger().timestamp();
+    fx.client.add_record(
+        &dr_a,
+        &patient,
+        &dr_a,
+        &RecordType::Diagnosis,
+        &String::from_str(&fx.env, HASH),
+    );
+    let diagnosis = fx
+        .client
+        .get_patient_profile_summary(&patient, &patient)
+        .by_type
+        .get(1)
+        .unwrap();
+    assert_eq!(diagnosis.count, 2);
+    assert_eq!(diagnosis.latest_created_at, now);
+}
+
+#[test]
+fn test_backdated_import_keeps_latest() {
+    let fx = setup();
+    let (patient, dr_a) = (fx.patient("pat_a"), fx.provider("dr_a"));
+    fx.client
+        .grant_custom_permission(&fx.admin(), &dr_a, &Permission::ImportRecords);
+    fx.advance_time(30 * DAY);
+    let before = fx.client.get_patient_profile_summary(&patient, &patient);
+    let exam_latest = before.by_type.get(0).unwrap().latest_created_at;
+
+    let import = |record_type: RecordType, created_at: u64| {
+        fx.client.add_record_backdated(
+            &dr_a,
+            &patient,
+            &dr_a,
+            &record_type,
+            &String::from_str(&fx.env, HASH),
+            &created_at,
+        )
+    };
+    // An older examination is counted without moving the latest date
+    import(RecordType::Examination, exam_latest - 1);
+    // The first record of a type takes its original date
+    import(RecordType::Surgery, DAY);
+    // A backdated record newer than the latest one does move it
+    import(RecordType::Examination, exam_latest + DAY);
+
+    let after = fx.client.get_patient_profile_summary(&patient, &patient);
+    assert_eq!(after.total, 6);
+    let exam = after.by_type.get(0).unwrap();
+    assert_eq!(exam.count, 4);
+    assert_eq!(exam.latest_created_at, exam_latest + DAY);
+    assert_eq!(
+        after.by_type.get(2).unwrap(),
+        RecordTypeCount {
+            record_type: RecordType::Surgery,
+            count: 1,
+            latest_created_at: DAY,
+        }
+    );
+}
+
+#[test]
+fn test_public_summary_and_empty_patient() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    fx.client
+        .set_summary_visibility(&patient, &SummaryVisibility::Public);
+    let summary = fx
+        .client
+        .get_patient_profile_summary(&fx.provider("clerk"), &patient);
+    assert_eq!(summary.patient, patient);
+
+    // A patient with no records still has an empty summary
+    let other = Address::generate(&fx.env);
+    let empty = fx.client.get_patient_profile_summary(&other, &other);
+    assert_eq!(empty.total, 0);
+    assert!(empty.by_type.is_empty());
+    assert_err(
+        fx.client.try_get_patient_profile_summary(&patient, &other),
+        ContractError::AccessDenied,
+    );
+}
@@ -0,0 +1,282 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessLevel, ContractError, PendingCategory, PendingGcResult, RecordType, Role};
+use crate::approval::PENDING_GRANT_WINDOW_SECONDS;
+use crate::expiry::MAX_EXPIRY_GRACE_SECONDS;
+use crate::offer::DEFAULT_OFFER_WINDOW_SECONDS;
+use crate::pending_gc;
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{testutils::Address as _, Address, Bytes, BytesN};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const DAY: u64 = 86_400;
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .build()
+}
+
+/// Entries listed in `category`'s bucket for the day containing `at`.
+fn indexed(fx: &TestContract, category: &PendingCategory, at: u64) -> u32 {
+    fx.env.as_contract(&fx.contract_id, || {
+        pending_gc::bucket_len(&fx.env, category, pending_gc::day_of(at))
+    })
+}
+
+/// The first moment after the day containing `at`.
+fn day_after(at: u64) -> u64 {
+    (at / DAY + 1) * DAY
+}
+
+fn gc(fx: &TestContract, category: PendingCategory, cursor: u32, limit: u32) -> PendingGcResult {
+    fx.client
+        .gc_pending(&fx.admin(), &category, &cursor, &limit)
+}
+
+/// Offers access to a fresh grantee, open for the default offer window.
+fn offer(fx: &TestContract) -> Address {
+    let grantee = Address::generate(&fx.env);
+    fx.client.grant_access_pending_acceptance(
+        &fx.patient("pat_a"),
+        &grantee,
+        &AccessLevel::Read,
+        &DAY,
+    );
+    grantee
+}
+
+#[test]
+fn test_only_expired_offers_removed() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let stale = offer(&fx);
+    let accepted = offer(&fx);
+    fx.client.accept_access(&accepted, &patient);
+    fx.advance_time(DAY);
+    let live = offer(&fx);
+
+    let lapsed = FIXTURE_START_TIME + DEFAULT_OFFER_WINDOW_SECONDS;
+    fx.set_time(day_after(lapsed));
+    assert_eq!(
+        gc(&fx, PendingCategory::AccessOffer, 0, 10),
+        PendingGcResult {
+            removed: 1,
+            next_cursor: None,
+        }
+    );
+    assert_eq!(fx.client.get_access_offer(&patient, &stale), None);
+    assert!(fx.client.get_access_offer(&patient, &live).is_some());
+    // The accepted offer's stale reference is dropped without counting,
+    // and the live offer's bucket is not due yet.
+    assert_eq!(indexed(&fx, &PendingCategory::AccessOffer, lapsed), 0);
+    assert_eq!(indexed(&fx, &PendingCategory::AccessOffer, lapsed + DAY), 1);
+}
+
+#[test]
+fn test_only_expired_access_requests_removed() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let delegate = Address::generate(&fx.env);
+    fx.client
+        .delegate_role(&patient, &delegate, &Role::Optometrist, &0);
+    fx.client.set_require_patient_approval(&patient, &true);
+
+    let stale = Address::generate(&fx.env);
+    fx.client
+        .grant_access(&delegate, &patient, &stale, &AccessLevel::Read, &DAY);
+    fx.advance_time(DAY);
+    let live = Address::generate(&fx.env);
+    fx.client
+        .grant_access(&delegate, &patient, &live, &AccessLevel::Read, &DAY);
+
+    let lapsed = FIXTURE_START_TIME + PENDING_GRANT_WINDOW_SECONDS;
+    fx.set_time(day_after(lapsed));
+    let result = gc(&fx, PendingCategory::AccessRequest, 0, 10);
+    assert_eq!(result.removed, 1);
+    assert!(fx.client.get_pending_grant(&patient, &stale).is_none());
+    assert!(fx.client.get_pending_grant(&patient, &live).is_some());
+    assert_eq!(indexed(&fx, &PendingCategory::AccessRequest, lapsed), 0);
+    assert_eq!(
+        indexed(&fx, &PendingCategory::AccessRequest, lapsed + DAY),
+        1
+    );
+    // Other categories are untouched by the run.
+    assert_eq!(indexed(&fx, &PendingCategory::AccessOffer, lapsed), 0);
+}
+
+#[test]
+fn test_only_expired_share_codes_removed() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let record_id = fx.record(0);
+    let code = |seed: u8, expires_at: u64| {
+        let hash = BytesN::from_array(&fx.env, &[seed; 32]);
+        fx.client
+            .create_share_code(&patient, &record_id, &hash, &AccessLevel::Read, &expires_at);
+        hash
+    };
+    let stale = code(1, FIXTURE_START_TIME + DAY);
+    let live = code(2, FIXTURE_START_TIME + 3 * DAY);
+
+    // A redeemed code is already gone; its reference is dropped uncounted.
+    let preimage = Bytes::from_slice(&fx.env, b"redeem-me");
+    let hash = fx.env.crypto().sha256(&preimage).to_bytes();
+    fx.client.create_share_code(
+        &patient,
+        &record_id,
+        &hash,
+        &AccessLevel::Read,
+        &(FIXTURE_START_TIME + DAY),
+    );
+    fx.client
+        .redeem_share_code(&Address::generate(&fx.env), &record_id, &preimage);
+
+    fx.set_time(day_after(FIXTURE_START_TIME + DAY));
+    assert_eq!(gc(&fx, PendingCategory::ShareCode, 0, 10).removed, 1);
+    assert_eq!(fx.client.get_share_code(&record_id, &stale), None);
+    assert!(fx.client.get_share_code(&record_id, &live).is_some());
+    assert_eq!(
+        indexed(&fx, &PendingCategory::ShareCode, FIXTURE_START_TIME + DAY),
+        0
+    );
+    assert_eq!(
+        indexed(
+            &fx,
+            &PendingCategory::ShareCode,
+            FIXTURE_START_TIME + 3 * DAY
+        ),
+        1
+    );
+}
+
+#[test]
+fn test_cursor_resumes_at_next_day() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let record_id = fx.record(0);
+    // Stale codes lapsing on two different days, and a live one.
+    let codes = [(1u8, DAY), (2, 2 * DAY), (3, 5 * DAY)].map(|(seed, lifetime)| {
+        let hash = BytesN::from_array(&fx.env, &[seed; 32]);
+        fx.client.create_share_code(
+            &patient,
+            &record_id,
+            &hash,
+            &AccessLevel::Read,
+            &(FIXTURE_START_TIME + lifetime),
+        );
+        hash
+    });
+    fx.set_time(day_after(FIXTURE_START_TIME + 2 * DAY));
+
+    let second_day = pending_gc::day_of(FIXTURE_START_TIME + 2 * DAY);
+    assert_eq!(
+        gc(&fx, PendingCategory::ShareCode, 0, 1),
+        PendingGcResult {
+            removed: 1,
+            next_cursor: Some(second_day),
+        }
+    );
+    assert_eq!(
+        gc(&fx, PendingCategory::ShareCode, second_day, 1),
+        PendingGcResult {
+            removed: 1,
+            next_cursor: None,
+        }
+    );
+    // Nothing further is due
+    assert_eq!(
+        gc(&fx, PendingCategory::ShareCode, 0, 10),
+        PendingGcResult {
+            removed: 0,
+            next_cursor: None,
+        }
+    );
+
+    for (i, hash) in codes.iter().enumerate() {
+        let remaining = fx.client.get_share_code(&record_id, hash);
+        assert_eq!(remaining.is_some(), i == 2);
+    }
+}
+
+#[test]
+fn test_expiry_day_not_collected_until_grace_ends() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let record_id = fx.record(0);
+    let hash = BytesN::from_array(&fx.env, &[1; 32]);
+    let expires_at = FIXTURE_START_TIME + DAY;
+    fx.client
+        .create_share_code(&patient, &record_id, &hash, &AccessLevel::Read, &expires_at);
+    let grace = MAX_EXPIRY_GRACE_SECONDS;
+    fx.client.set_expiry_grace(&fx.admin(), &grace);
+
+    fx.set_time(day_after(expires_at));
+    assert_eq!(gc(&fx, PendingCategory::ShareCode, 0, 10).removed, 0);
+    assert_eq!(indexed(&fx, &PendingCategory::ShareCode, expires_at), 1);
+
+    fx.set_time(day_after(expires_at) + grace);
+    assert_eq!(gc(&fx, PendingCategory::ShareCode, 0, 10).removed, 1);
+    assert_eq!(fx.client.get_share_code(&record_id, &hash), None);
+}
+
+#[test]
+fn test_bucket_spans_pages() {
+    let fx = setup();
+    let expires_at = FIXTURE_START_TIME + DAY;
+    let listed = pending_gc::PENDING_PAGE_SIZE + 10;
+    fx.env.as_contract(&fx.contract_id, || {
+        for seed in 0..listed {
+            let hash = BytesN::from_array(&fx.env, &[seed as u8; 32]);
+            pending_gc::register(
+                &fx.env,
+                &PendingCategory::ShareCode,
+                &pending_gc::PendingEntry::ShareCode(99, hash),
+                expires_at,
+            );
+        }
+    });
+    assert_eq!(
+        indexed(&fx, &PendingCategory::ShareCode, expires_at),
+        listed
+    );
+
+    fx.set_time(day_after(expires_at));
+    let day = pending_gc::day_of(expires_at);
+    assert_eq!(
+        gc(&fx, PendingCategory::ShareCode, 0, 40),
+        PendingGcResult {
+            removed: 0,
+            next_cursor: Some(day),
+        }
+    );
+    assert_eq!(indexed(&fx, &PendingCategory::ShareCode, expires_at), 20);
+    assert_eq!(
+        gc(&fx, PendingCategory::ShareCode, day, 40).next_cursor,
+        None
+    );
+    assert_eq!(indexed(&fx, &PendingCategory::ShareCode, expires_at), 0);
+}
+
+#[test]
+fn test_gc_pending_requires_operator_admin() {
+    let fx = setup();
+    assert_err(
+        fx.client
+            .try_gc_pending(&fx.provider("dr_a"), &PendingCategory::AccessOffer, &0, &10),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client
+            .try_gc_pending(&fx.admin(), &PendingCategory::AccessOffer, &0, &0),
+        ContractError::InvalidInput,
+    );
+}
@@ -0,0 +1,145 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{events::topics, pending_record::PENDING_RECORD_SECONDS, ContractError, RecordType};
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+const HASH: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .build()
+}
+
+fn begin(fx: &TestContract) -> u64 {
+    let dr_a = fx.provider("dr_a");
+    fx.client
+        .begin_record(&dr_a, &fx.patient("pat_a"), &dr_a, &RecordType::Examination)
+}
+
+fn hash(fx: &TestContract) -> String {
+    String::from_str(&fx.env, HASH)
+}
+
+fn record_added(fx: &TestContract) -> bool {
+    fx.published((topics::REC_ADD, fx.patient("pat_a"), fx.provider("dr_a")))
+}
+
+#[test]
+fn test_commit_finalizes_reserved_record() {
+    let fx = setup();
+    let (dr_a, pat_a) = (fx.provider("dr_a"), fx.patient("pat_a"));
+    let record_id = begin(&fx);
+
+    assert_err(
+        fx.client.try_get_record(&dr_a, &record_id),
+        ContractError::RecordPending,
+    );
+    assert!(fx.client.get_patient_records(&pat_a).is_empty());
+    assert_eq!(
+        fx.client.get_pending_record(&record_id).unwrap().expires_at,
+        FIXTURE_START_TIME + PENDING_RECORD_SECONDS
+    );
+
+    fx.advance_time(60);
+    let receipt = fx.client.commit_record(&dr_a, &record_id, &hash(&fx));
+    assert!(record_added(&fx));
+    assert_eq!(receipt.record_id, record_id);
+    assert_eq!(receipt.version, 1);
+    assert_eq!(receipt.created_at, FIXTURE_START_TIME + 60);
+
+    let record = fx.client.get_record(&dr_a, &record_id);
+    assert_eq!(record.patient, pat_a);
+    assert_eq!(record.data_hash, hash(&fx));
+    assert_eq!(fx.client.get_record_version_count(&record_id), 1);
+    assert_eq!(fx.client.get_patient_records(&pat_a).len(), 1);
+    assert_eq!(fx.client.get_pending_record(&record_id), None);
+}
+
+#[test]
+fn test_double_commit_is_rejected() {
+    let fx = setup();
+    let dr_a = fx.provider("dr_a");
+    let record_id = begin(&fx);
+    fx.client.commit_record(&dr_a, &record_id, &hash(&fx));
+
+    assert_err(
+        fx.client.try_commit_record(&dr_a, &record_id, &hash(&fx)),
+        ContractError::AlreadyExists,
+    );
+    assert_eq!(fx.client.get_record_version_count(&record_id), 1);
+}
+
+#[test]
+fn test_abandoned_id_is_burned() {
+    let fx = setup();
+    let dr_a = fx.provider("dr_a");
+    let record_id = begin(&fx);
+
+    let other = Address::generate(&fx.env);
+    assert_err(
+        fx.client.try_abandon_record(&other, &record_id),
+        ContractError::Unauthorized,
+    );
+    fx.client.abandon_record(&dr_a, &record_id);
+
+    assert_err(
+        fx.client.try_commit_record(&dr_a, &record_id, &hash(&fx)),
+        ContractError::RecordNotFound,
+    );
+    assert_err(
+        fx.client.try_get_record(&dr_a, &record_id),
+        ContractError::RecordNotFound,
+    );
+    assert_ne!(begin(&fx), record_id);
+}
+
+#[test]
+fn test_reservation_expires() {
+    let fx = setup();
+    let dr_a = fx.provider("dr_a");
+    let record_id = begin(&fx);
+
+    fx.advance_time(PENDING_RECORD_SECONDS);
+    assert_err(
+        fx.client.try_commit_record(&dr_a, &record_id, &hash(&fx)),
+        ContractError::RecordNotFound,
+    );
+    assert_err(
+        fx.client.try_get_record(&dr_a, &record_id),
+        ContractError::RecordNotFound,
+    );
+    assert!(fx
+        .client
+        .get_patient_records(&fx.patient("pat_a"))
+        .is_empty());
+}
+
+#[test]
+fn test_only_reserver_commits() {
+    let fx = setup();
+    let record_id = begin(&fx);
+    let other = Address::generate(&fx.env);
+
+    assert_err(
+        fx.client.try_commit_record(&other, &record_id, &hash(&fx)),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_commit_record(
+            &fx.provider("dr_a"),
+            &record_id,
+            &String::from_str(&fx.env, ""),
+        ),
+        ContractError::InvalidInput,
+    );
+    assert!(fx.client.get_pending_record(&record_id).is_some());
+}
@@ -0,0 +1,126 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{ContractError, Permission, RecordType};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{vec, String};
+
+const HASH: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+
+// 2024-01-31T23:59:59Z, 2024-02-01T00:00:00Z and 2026-06-15T00:00:00Z
+const END_OF_JANUARY: u64 = 1_706_745_599;
+const START_OF_FEBRUARY: u64 = 1_706_745_600;
+const MID_2026: u64 = 1_781_481_600;
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .build()
+}
+
+fn add(fx: &TestContract, record_type: RecordType) {
+    let provider = fx.provider("dr_a");
+    fx.client.add_record(
+        &provider,
+        &fx.patient("pat_a"),
+        &provider,
+        &record_type,
+        &String::from_str(&fx.env, HASH),
+    );
+}
+
+#[test]
+fn test_counts_split_at_month_boundary() {
+    let fx = setup();
+    fx.set_time(END_OF_JANUARY);
+    add(&fx, RecordType::Diagnosis);
+    add(&fx, RecordType::Examination);
+    fx.set_time(START_OF_FEBRUARY);
+    add(&fx, RecordType::Diagnosis);
+    add(&fx, RecordType::Diagnosis);
+
+    assert_eq!(
+        fx.client
+            .get_period_counts(&RecordType::Diagnosis, &202401, &202403),
+        vec![&fx.env, (202401, 1), (202402, 2), (202403, 0)]
+    );
+    assert_eq!(
+        fx.client
+            .get_period_counts(&RecordType::Examination, &202312, &202401),
+        vec![&fx.env, (202312, 0), (202401, 1)]
+    );
+}
+
+#[test]
+fn test_imported_records_count_toward_original_month() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    fx.client
+        .grant_custom_permission(&fx.admin(), &provider, &Permission::ImportRecords);
+    fx.set_time(MID_2026);
+    fx.client.add_record_backdated(
+        &provider,
+        &fx.patient("pat_a"),
+        &provider,
+        &RecordType::Diagnosis,
+        &String::from_str(&fx.env, HASH),
+        &END_OF_JANUARY,
+    );
+
+    let counts = |from: u32, to: u32| {
+        fx.client
+            .get_period_counts(&RecordType::Diagnosis, &from, &to)
+    };
+    assert_eq!(counts(202401, 202401), vec![&fx.env, (202401, 1)]);
+    assert_eq!(counts(202606, 202606), vec![&fx.env, (202606, 0)]);
+}
+
+#[test]
+fn test_disabled_counts_are_neither_written_nor_readable() {
+    let fx = setup();
+    let admin = fx.admin();
+    fx.set_time(START_OF_FEBRUARY);
+    add(&fx, RecordType::Diagnosis);
+
+    fx.client.set_period_stats_enabled(&admin, &false);
+    assert!(!fx.client.is_period_stats_enabled());
+    add(&fx, RecordType::Diagnosis);
+    assert_err(
+        fx.client
+            .try_get_period_counts(&RecordType::Diagnosis, &202402, &202402),
+        ContractError::FeatureDisabled,
+    );
+
+    fx.client.set_period_stats_enabled(&admin, &true);
+    assert_eq!(
+        fx.client
+            .get_period_counts(&RecordType::Diagnosis, &202402, &202402),
+        vec![&fx.env, (202402, 1)]
+    );
+
+    assert_err(
+        fx.client
+            .try_set_period_stats_enabled(&fx.provider("dr_a"), &false),
+        ContractError::Unauthorized,
+    );
+}
+
+#[test]
+fn test_malformed_ranges_are_rejected() {
+    let fx = setup();
+    let counts = |from: u32, to: u32| {
+        fx.client
+            .try_get_period_counts(&RecordType::Diagnosis, &from, &to)
+    };
+    assert_err(counts(202413, 202501), ContractError::InvalidInput);
+    assert_err(counts(202402, 202401), ContractError::InvalidInput);
+    // Sixty-one months is past the five-year cap
+    assert_err(counts(201912, 202412), ContractError::InvalidInput);
+    assert_eq!(counts(202001, 202412).unwrap().unwrap().len(), 60);
+}
@@ -0,0 +1,234 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+extern crate std;
+
+use super::{
+    priority, ContractError, RecordPriority, RecordType, Role, VisionRecordsContract,
+    VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Address as _, Address, Env, String, Vec};
+
+fn setup() -> (Env, VisionRecordsContractClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VisionRecordsContract, ());
+    let client = VisionRecordsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &None);
+
+    (env, client, admin)
+}
+
+fn add_record(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+    record_type: RecordType,
+) -> (u64, Address, Address) {
+    let provider = Address::generate(env);
+    let patient = Address::generate(env);
+    client.register_user(
+        admin,
+        &provider,
+        &Role::Optometrist,
+        &String::from_str(env, "Dr. Provider"),
+    );
+    let id = client.add_record(
+        &provider,
+        &patient,
+        &provider,
+        &record_type,
+        &String::from_str(env, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"),
+    );
+    (id, patient, provider)
+}
+
+#[test]
+fn test_new_record_defaults_to_standard() {
+    let (env, client, admin) = setup();
+    let (id, _, _) = add_record(&env, &client, &admin, RecordType::Surgery);
+
+    assert_eq!(client.get_record_priority(&id), RecordPriority::Standard);
+    // The default priority is not indexed, but is still listed
+    let standard = client.get_records_by_priority(&admin, &RecordPriority::Standard, &0, &10);
+    assert_eq!(standard.items, Vec::from_array(&env, [id]));
+    assert!(!standard.truncated);
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().persistent().has(&priority::priority_key(id)));
+    });
+}
+
+#[test]
+fn test_auto_critical_for_surgery_and_diagnosis() {
+    let (env, client, admin) = setup();
+    client.set_priority_auto_critical(&admin, &true);
+    assert!(client.is_priority_auto_critical());
+
+    let (surgery, _, _) = add_record(&env, &client, &admin, RecordType::Surgery);
+    let (diagnosis, _, _) = add_record(&env, &client, &admin, RecordType::Diagnosis);
+    let (exam, _, _) = add_record(&env, &client, &admin, RecordType::Examination);
+
+    assert_eq!(
+        client.get_record_priority(&surgery),
+        RecordPriority::Critical
+    );
+    assert_eq!(
+        client.get_record_priority(&diagnosis),
+        RecordPriority::Critical
+    );
+    assert_eq!(client.get_record_priority(&exam), RecordPriority::Standard);
+}
+
+#[test]
+fn test_patient_and_provider_can_set_priority() {
+    let (env, client, admin) = setup();
+    let (id, patient, provider) = add_record(&env, &client, &admin, RecordType::Examination);
+
+    client.set_record_priority(&patient, &id, &RecordPriority::Archivable);
+    assert_eq!(client.get_record_priority(&id), RecordPriority::Archivable);
+
+    client.set_record_priority(&provider, &id, &RecordPriority::Critical);
+    assert_eq!(client.get_record_priority(&id), RecordPriority::Critical);
+
+    // Index moves with the priority.
+    let archivable = client.get_records_by_priority(&admin, &RecordPriority::Archivable, &0, &10);
+    let critical = client.get_records_by_priority(&admin, &RecordPriority::Critical, &0, &10);
+    assert_eq!(archivable.items.len(), 0);
+    assert_eq!(critical.items.len(), 1);
+
+    client.set_record_priority(&patient, &id, &RecordPriority::Standard);
+    let critical = client.get_records_by_priority(&admin, &RecordPriority::Critical, &0, &10);
+    assert_eq!(critical.items.len(), 0);
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().persistent().has(&priority::priority_key(id)));
+    });
+}
+
+#[test]
+fn test_stranger_cannot_set_priority() {
+    let (env, client, admin) = setup();
+    let (id, _, _) = add_record(&env, &client, &admin, RecordType::Examination);
+    let stranger = Address::generate(&env);
+
+    let res = client.try_set_record_priority(&stranger, &id, &RecordPriority::Critical);
+    assert_eq!(res, Err(Ok(ContractError::Unauthorized)));
+}
+
+#[test]
+fn test_get_records_by_priority_paginates_and_requires_admin() {
+    let (env, client, admin) = setup();
+    client.set_priority_auto_critical(&admin, &true);
+    let mut ids = Vec::new(&env);
+    for _ in 0..5 {
+        ids.push_back(add_record(&env, &client, &admin, RecordType::Surgery).0);
+    }
+
+    let first = client.get_records_by_priority(&admin, &RecordPriority::Critical, &0, &2);
+    let last = client.get_records_by_priority(&admin, &RecordPriority::Critical, &4, &2);
+    assert_eq!(first.items, ids.slice(0..2));
+    assert!(first.truncated);
+    assert_eq!(first.next_cursor, 2);
+    assert_eq!(last.items, ids.slice(4..5));
+    assert!(!last.truncated);
+    assert_eq!(
+        client.try_get_records_by_priority(&admin, &RecordPriority::Critical, &0, &0),
+        Err(Ok(ContractError::InvalidInput))
+    );
+
+    let outsider = Address::generate(&env);
+    let res = client.try_get_records_by_priority(&outsider, &RecordPriority::Critical, &0, &2);
+    assert_eq!(res, Err(Ok(ContractError::Unauthorized)));
+}
+
+#[test]
+fn test_index_spans_pages_and_keeps_positions() {
+    let (env, client, _admin) = setup();
+    let count = priority::PRIORITY_PAGE_SIZE as u64 + 10;
+    let set = |id: u64, level: RecordPriority| {
+        env.as_contract(&client.address, || {
+            priority::set_priority(&env, id, &level);
+        });
+    };
+    let range = |level: RecordPriority, offset: u32, limit: u32| {
+        env.as_contract(&client.address, || {
+            priority::get_range(&env, &level, offset, limit).items
+        })
+    };
+    for id in 1..=count {
+        set(id, RecordPriority::Critical);
+    }
+    let all = range(RecordPriority::Critical, 0, count as u32);
+    assert_eq!(all.len() as u64, count);
+    assert_eq!(all.get(priority::PRIORITY_PAGE_SIZE).unwrap(), 51);
+
+    // A removed ID leaves a hole; later IDs keep their positions
+    set(2, RecordPriority::Standard);
+    assert_eq!(
+        range(RecordPriority::Critical, 0, 3),
+        Vec::from_array(&env, [1, 3])
+    );
+    assert_eq!(
+        range(RecordPriority::Critical, 2, 1),
+        Vec::from_array(&env, [3])
+    );
+
+    // Removing the last position shortens the index instead
+    set(count, RecordPriority::Archivable);
+    env.as_contract(&client.address, || {
+        assert_eq!(
+            priority::index_len(&env, &RecordPriority::Critical) as u64,
+            count - 1
+        );
+    });
+    assert_eq!(
+        range(RecordPriority::Critical, 0, count as u32).len() as u64,
+        count - 2
+    );
+    assert_eq!(
+        range(RecordPriority::Archivable, 0, 10),
+        Vec::from_array(&env, [count])
+    );
+}
+
+#[test]
+fn test_standard_records_page_across_shards() {
+    let (env, client, admin) = setup();
+    let mut standard = std::vec::Vec::new();
+    for n in 0..6 {
+        let (id, patient, _) = add_record(&env, &client, &admin, RecordType::Examination);
+        if n == 2 {
+            client.set_record_priority(&patient, &id, &RecordPriority::Critical);
+        } else {
+            standard.push(id);
+        }
+    }
+
+    let mut listed = std::vec::Vec::new();
+    let mut cursor = 0;
+    loop {
+        let page = client.get_records_by_priority(&admin, &RecordPriority::Standard, &cursor, &2);
+        assert!(page.items.len() <= 2);
+        listed.extend(page.items.iter());
+        if !page.truncated {
+            break;
+        }
+        cursor = page.next_cursor;
+    }
+    listed.sort_unstable();
+    standard.sort_unstable();
+    assert_eq!(listed, standard);
+}
+
+#[test]
+fn test_unknown_record_priority_is_not_found() {
+    let (_env, client, _admin) = setup();
+    let res = client.try_get_record_priority(&42);
+    assert_eq!(res, Err(Ok(ContractError::RecordNotFound)));
+}
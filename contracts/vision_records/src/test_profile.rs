@@ -12,7 +12,7 @@ fn setup_test() -> (Env, VisionRecordsContractClient<'static>, Address) {
     let client = VisionRecordsContractClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
-    client.initialize(&admin);
+    client.initialize(&admin, &None);
 
     (env, client, admin)
 }
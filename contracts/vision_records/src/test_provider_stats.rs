@@ -0,0 +1,170 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{
+    provider_stats, ContractError, RecordType, Role, VisionRecordsContract,
+    VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger, Address, Env, String};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+
+/// 2026-01-31T23:00:00Z
+const JAN_31: u64 = 1_769_900_400;
+/// 2026-02-01T00:00:00Z
+const FEB_01: u64 = 1_769_904_000;
+
+fn setup() -> (Env, VisionRecordsContractClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(JAN_31);
+
+    let contract_id = env.register(VisionRecordsContract, ());
+    let client = VisionRecordsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &None);
+
+    let provider = Address::generate(&env);
+    client.register_user(
+        &admin,
+        &provider,
+        &Role::Optometrist,
+        &String::from_str(&env, "Dr. Provider"),
+    );
+
+    (env, client, admin, provider)
+}
+
+fn add_record(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    provider: &Address,
+    patient: &Address,
+) -> u64 {
+    client.add_record(
+        provider,
+        patient,
+        provider,
+        &RecordType::Examination,
+        &String::from_str(env, HASH_A),
+    )
+}
+
+#[test]
+fn test_month_bucket() {
+    assert_eq!(provider_stats::month_bucket(0), 197001);
+    assert_eq!(provider_stats::month_bucket(951_868_799), 200002);
+    assert_eq!(provider_stats::month_bucket(951_868_800), 200003);
+    assert_eq!(provider_stats::month_bucket(JAN_31), 202601);
+    assert_eq!(provider_stats::month_bucket(FEB_01), 202602);
+}
+
+#[test]
+fn test_stats_track_creates_updates_and_patients() {
+    let (env, client, _admin, provider) = setup();
+    let p1 = Address::generate(&env);
+    let p2 = Address::generate(&env);
+
+    let record_id = add_record(&env, &client, &provider, &p1);
+    add_record(&env, &client, &provider, &p1);
+    add_record(&env, &client, &provider, &p2);
+
+    env.ledger().set_timestamp(JAN_31 + 60);
+    client.update_record(&provider, &record_id, &String::from_str(&env, HASH_B));
+
+    let stats = client.get_provider_stats(&provider, &provider);
+    assert_eq!(stats.records_created, 3);
+    assert_eq!(stats.records_updated, 1);
+    assert_eq!(stats.distinct_patients, 2);
+    assert!(!stats.patients_approximate);
+    assert_eq!(stats.last_activity, JAN_31 + 60);
+}
+
+#[test]
+fn test_period_counters_across_month_boundary() {
+    let (env, client, _admin, provider) = setup();
+    let patient = Address::generate(&env);
+
+    add_record(&env, &client, &provider, &patient);
+    add_record(&env, &client, &provider, &patient);
+
+    let stats = client.get_provider_stats(&provider, &provider);
+    assert_eq!(stats.current_period, 202601);
+    assert_eq!(stats.created_this_period, 2);
+
+    // The new month starts with an empty period counter.
+    env.ledger().set_timestamp(FEB_01);
+    let stats = client.get_provider_stats(&provider, &provider);
+    assert_eq!(stats.current_period, 202602);
+    assert_eq!(stats.created_this_period, 0);
+    assert_eq!(stats.records_created, 2);
+
+    add_record(&env, &client, &provider, &patient);
+    let stats = client.get_provider_stats(&provider, &provider);
+    assert_eq!(stats.created_this_period, 1);
+    assert_eq!(stats.records_created, 3);
+
+    assert_eq!(
+        client.get_provider_monthly_created(&provider, &provider, &202601),
+        2
+    );
+    assert_eq!(
+        client.get_provider_monthly_created(&provider, &provider, &202602),
+        1
+    );
+}
+
+#[test]
+fn test_distinct_patients_become_approximate_past_cap() {
+    let (env, client, _admin, provider) = setup();
+
+    let repeat = Address::generate(&env);
+    add_record(&env, &client, &provider, &repeat);
+    for _ in 1..provider_stats::MAX_TRACKED_PATIENTS {
+        add_record(&env, &client, &provider, &Address::generate(&env));
+    }
+    // Repeat patients inside the tracked set are still counted once.
+    add_record(&env, &client, &provider, &repeat);
+    let stats = client.get_provider_stats(&provider, &provider);
+    assert_eq!(
+        stats.distinct_patients,
+        provider_stats::MAX_TRACKED_PATIENTS
+    );
+    assert!(!stats.patients_approximate);
+
+    // Past the cap a repeat patient is counted again.
+    let overflow = Address::generate(&env);
+    add_record(&env, &client, &provider, &overflow);
+    add_record(&env, &client, &provider, &overflow);
+    let stats = client.get_provider_stats(&provider, &provider);
+    assert_eq!(
+        stats.distinct_patients,
+        provider_stats::MAX_TRACKED_PATIENTS + 2
+    );
+    assert!(stats.patients_approximate);
+}
+
+#[test]
+fn test_stats_access_control() {
+    let (env, client, admin, provider) = setup();
+    let manager = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    client.register_user(
+        &admin,
+        &manager,
+        &Role::Staff,
+        &String::from_str(&env, "Practice Manager"),
+    );
+
+    client.get_provider_stats(&admin, &provider);
+    client.get_provider_stats(&manager, &provider);
+
+    let res = client.try_get_provider_stats(&outsider, &provider);
+    assert_eq!(res, Err(Ok(ContractError::Unauthorized)));
+}
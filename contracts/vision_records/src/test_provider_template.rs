@@ -0,0 +1,140 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessGrant, AccessLevel, ContractError, GrantPurpose, ProviderAccessTemplate};
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::symbol_short;
+
+const HOUR: u64 = 3_600;
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .build()
+}
+
+fn set_template(fx: &TestContract, level: AccessLevel, duration: u64, purpose: GrantPurpose) {
+    fx.client
+        .set_provider_access_template(&fx.provider("dr_a"), &level, &duration, &purpose);
+}
+
+fn stored_grant(fx: &TestContract) -> AccessGrant {
+    fx.env.as_contract(&fx.contract_id, || {
+        fx.env
+            .storage()
+            .persistent()
+            .get(&(
+                symbol_short!("ACCESS"),
+                fx.patient("pat_a"),
+                fx.provider("dr_a"),
+            ))
+            .unwrap()
+    })
+}
+
+#[test]
+fn test_accept_uses_template_at_acceptance_time() {
+    let fx = setup();
+    let (dr_a, pat_a) = (fx.provider("dr_a"), fx.patient("pat_a"));
+    set_template(&fx, AccessLevel::Read, HOUR, GrantPurpose::Treatment);
+
+    // The wallet renders the template, then the provider changes it
+    let rendered = fx.client.get_provider_access_template(&dr_a).unwrap();
+    assert_eq!(
+        rendered,
+        ProviderAccessTemplate {
+            provider: dr_a.clone(),
+            level: AccessLevel::Read,
+            duration_seconds: HOUR,
+            purpose: GrantPurpose::Treatment,
+            updated_at: FIXTURE_START_TIME,
+        }
+    );
+    fx.advance_time(60);
+    set_template(&fx, AccessLevel::Write, 2 * HOUR, GrantPurpose::Payment);
+
+    fx.client.accept_provider_template(&pat_a, &dr_a);
+    let grant = stored_grant(&fx);
+    assert_eq!(grant.level, AccessLevel::Write);
+    assert_eq!(grant.expires_at, FIXTURE_START_TIME + 60 + 2 * HOUR);
+    let detail = fx.client.check_access_detailed(&pat_a, &dr_a).unwrap();
+    assert_eq!(detail.purpose, GrantPurpose::Payment);
+    assert_eq!(detail.created_by, pat_a);
+}
+
+#[test]
+fn test_template_changes_leave_existing_grants() {
+    let fx = setup();
+    let dr_a = fx.provider("dr_a");
+    set_template(&fx, AccessLevel::Read, HOUR, GrantPurpose::Treatment);
+    fx.client
+        .accept_provider_template(&fx.patient("pat_a"), &dr_a);
+    let before = stored_grant(&fx);
+
+    set_template(&fx, AccessLevel::Full, 4 * HOUR, GrantPurpose::Research);
+    fx.client.remove_provider_access_template(&dr_a);
+    let after = stored_grant(&fx);
+    assert_eq!(after.level, before.level);
+    assert_eq!(after.expires_at, before.expires_at);
+}
+
+#[test]
+fn test_absent_template() {
+    let fx = setup();
+    let (dr_a, pat_a) = (fx.provider("dr_a"), fx.patient("pat_a"));
+    assert_eq!(fx.client.get_provider_access_template(&dr_a), None);
+    assert_err(
+        fx.client.try_accept_provider_template(&pat_a, &dr_a),
+        ContractError::TemplateNotFound,
+    );
+
+    set_template(&fx, AccessLevel::Read, HOUR, GrantPurpose::Treatment);
+    fx.client.remove_provider_access_template(&dr_a);
+    assert_eq!(fx.client.get_provider_access_template(&dr_a), None);
+    assert_err(
+        fx.client.try_accept_provider_template(&pat_a, &dr_a),
+        ContractError::TemplateNotFound,
+    );
+    assert_err(
+        fx.client.try_remove_provider_access_template(&dr_a),
+        ContractError::TemplateNotFound,
+    );
+}
+
+#[test]
+fn test_only_clinicians_set_valid_templates() {
+    let fx = setup();
+    assert_err(
+        fx.client.try_set_provider_access_template(
+            &fx.patient("pat_a"),
+            &AccessLevel::Read,
+            &HOUR,
+            &GrantPurpose::Treatment,
+        ),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_set_provider_access_template(
+            &fx.provider("dr_a"),
+            &AccessLevel::None,
+            &HOUR,
+            &GrantPurpose::Treatment,
+        ),
+        ContractError::InvalidInput,
+    );
+    assert_err(
+        fx.client.try_set_provider_access_template(
+            &fx.provider("dr_a"),
+            &AccessLevel::Read,
+            &0,
+            &GrantPurpose::Treatment,
+        ),
+        ContractError::InvalidInput,
+    );
+}
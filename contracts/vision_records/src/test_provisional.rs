@@ -0,0 +1,147 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{ContractError, RecordType};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{BytesN, String};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_provider("dr_b")
+        .with_patient("pat_b")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .build()
+}
+
+fn walk_in(fx: &TestContract, seed: u8) -> BytesN<32> {
+    BytesN::from_array(&fx.env, &[seed; 32])
+}
+
+fn add_provisional(fx: &TestContract, identity: &BytesN<32>) -> u64 {
+    fx.client.add_record_provisional(
+        &fx.provider("dr_a"),
+        identity,
+        &RecordType::Examination,
+        &String::from_str(&fx.env, HASH_A),
+    )
+}
+
+#[test]
+fn test_unclaimed_records_visible_to_provider_and_admin_only() {
+    let fx = setup();
+    let record_id = add_provisional(&fx, &walk_in(&fx, 7));
+
+    fx.client.get_record(&fx.provider("dr_a"), &record_id);
+    fx.client
+        .read_record_as_admin(&fx.admin(), &record_id, &String::from_str(&fx.env, HASH_A));
+    // Another provider's ReadAnyRecord does not reach unclaimed records.
+    assert_err(
+        fx.client.try_get_record(&fx.provider("dr_b"), &record_id),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_get_record(&fx.patient("pat_a"), &record_id),
+        ContractError::Unauthorized,
+    );
+}
+
+#[test]
+fn test_claim_merges_provisional_records() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    // Sharded IDs do not follow creation order, so create the walk-in
+    // records later than the patient's own.
+    fx.advance_time(60);
+    let identity = walk_in(&fx, 7);
+    let first = add_provisional(&fx, &identity);
+    let second = add_provisional(&fx, &identity);
+    // Records under another identity stay put.
+    let other = add_provisional(&fx, &walk_in(&fx, 8));
+
+    let claimed = fx
+        .client
+        .claim_provisional_records(&patient, &identity, &fx.admin());
+    assert_eq!(claimed.len(), 2);
+    assert_eq!(claimed.get(0), Some(first));
+    assert_eq!(claimed.get(1), Some(second));
+
+    let records = fx.client.get_patient_records(&patient);
+    assert_eq!(records.len(), 3);
+    assert_eq!(records.get(0), Some(fx.record(0)));
+    assert!(records.contains(first) && records.contains(second));
+    assert!(!records.contains(other));
+
+    for record_id in [first, second] {
+        assert_eq!(fx.client.get_record(&patient, &record_id).patient, patient);
+        assert_eq!(fx.client.get_record_version_count(&record_id), 2);
+        let note = fx.client.get_record_version(&patient, &record_id, &2);
+        assert_eq!(note.modified_by, fx.admin());
+        assert_eq!(
+            fx.client.get_provisional_origin(&record_id),
+            Some(identity.clone())
+        );
+    }
+}
+
+#[test]
+fn test_second_claim_fails() {
+    let fx = setup();
+    let identity = walk_in(&fx, 7);
+    add_provisional(&fx, &identity);
+    fx.client
+        .claim_provisional_records(&fx.patient("pat_a"), &identity, &fx.admin());
+
+    assert_err(
+        fx.client
+            .try_claim_provisional_records(&fx.patient("pat_a"), &identity, &fx.admin()),
+        ContractError::ProvisionalAlreadyClaimed,
+    );
+    assert_err(
+        fx.client
+            .try_claim_provisional_records(&fx.patient("pat_b"), &identity, &fx.admin()),
+        ContractError::ProvisionalAlreadyClaimed,
+    );
+    assert_err(
+        fx.client.try_add_record_provisional(
+            &fx.provider("dr_a"),
+            &identity,
+            &RecordType::Examination,
+            &String::from_str(&fx.env, HASH_A),
+        ),
+        ContractError::ProvisionalAlreadyClaimed,
+    );
+    assert_eq!(fx.client.get_patient_records(&fx.patient("pat_b")).len(), 0);
+}
+
+#[test]
+fn test_claim_requires_admin_and_records() {
+    let fx = setup();
+    let identity = walk_in(&fx, 7);
+    add_provisional(&fx, &identity);
+
+    assert_err(
+        fx.client.try_claim_provisional_records(
+            &fx.patient("pat_a"),
+            &identity,
+            &fx.provider("dr_a"),
+        ),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_claim_provisional_records(
+            &fx.patient("pat_a"),
+            &walk_in(&fx, 9),
+            &fx.admin(),
+        ),
+        ContractError::RecordNotFound,
+    );
+}
@@ -0,0 +1,238 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+extern crate std;
+
+use super::{
+    AccessGrant, AccessLevel, ConsentType, ContractError, GrantPurpose, GrantStats, PurposeCount,
+    Role,
+};
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Vec};
+
+fn setup() -> TestContract {
+    Fixture::new().with_admin().with_patient("pat_a").build()
+}
+
+fn grant(fx: &TestContract, grantee: &Address, purpose: GrantPurpose, duration: u64) {
+    let patient = fx.patient("pat_a");
+    fx.client.grant_access_with_purpose(
+        &patient,
+        &patient,
+        grantee,
+        &AccessLevel::Read,
+        &purpose,
+        &duration,
+    );
+}
+
+fn count_for(stats: &GrantStats, purpose: GrantPurpose) -> u32 {
+    stats
+        .grants_active_by_purpose
+        .iter()
+        .find(|entry| entry.purpose == purpose)
+        .unwrap()
+        .count
+}
+
+#[test]
+fn test_purpose_reported_by_check_access_detailed() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let grantee = Address::generate(&fx.env);
+
+    grant(&fx, &grantee, GrantPurpose::Payment, 3_600);
+
+    // Without consent the grant is stored but not effective.
+    let detail = fx.client.check_access_detailed(&patient, &grantee).unwrap();
+    assert_eq!(detail.purpose, GrantPurpose::Payment);
+    assert_eq!(detail.level, AccessLevel::None);
+    assert_eq!(detail.granted_at, FIXTURE_START_TIME);
+    assert_eq!(detail.expires_at, FIXTURE_START_TIME + 3_600);
+
+    fx.client
+        .grant_consent(&patient, &grantee, &ConsentType::Treatment, &3_600);
+    let detail = fx.client.check_access_detailed(&patient, &grantee).unwrap();
+    assert_eq!(detail.level, AccessLevel::Read);
+
+    fx.advance_time(3_600);
+    assert!(fx
+        .client
+        .check_access_detailed(&patient, &grantee)
+        .is_none());
+}
+
+#[test]
+fn test_legacy_grant_defaults_to_treatment() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let grantee = Address::generate(&fx.env);
+
+    // A grant stored before purposes were recorded.
+    fx.env.as_contract(&fx.contract_id, || {
+        fx.env.storage().persistent().set(
+            &(symbol_short!("ACCESS"), patient.clone(), grantee.clone()),
+            &AccessGrant {
+                patient: patient.clone(),
+                grantee: grantee.clone(),
+                level: AccessLevel::Read,
+                granted_at: FIXTURE_START_TIME,
+                expires_at: FIXTURE_START_TIME + 3_600,
+            },
+        );
+    });
+
+    let detail = fx.client.check_access_detailed(&patient, &grantee).unwrap();
+    assert_eq!(detail.purpose, GrantPurpose::Treatment);
+}
+
+#[test]
+fn test_regrant_without_purpose_resets_to_treatment() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let grantee = Address::generate(&fx.env);
+
+    grant(&fx, &grantee, GrantPurpose::Personal, 3_600);
+    fx.client
+        .grant_access(&patient, &patient, &grantee, &AccessLevel::Read, &3_600);
+
+    let detail = fx.client.check_access_detailed(&patient, &grantee).unwrap();
+    assert_eq!(detail.purpose, GrantPurpose::Treatment);
+}
+
+#[test]
+fn test_research_grant_requires_patient() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let delegate = Address::generate(&fx.env);
+    let grantee = Address::generate(&fx.env);
+    fx.client
+        .delegate_role(&patient, &delegate, &Role::Optometrist, &0);
+
+    assert_err(
+        fx.client.try_grant_access_with_purpose(
+            &delegate,
+            &patient,
+            &grantee,
+            &AccessLevel::Read,
+            &GrantPurpose::Research,
+            &3_600,
+        ),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_grant_access_with_purpose(
+            &fx.admin(),
+            &patient,
+            &grantee,
+            &AccessLevel::Read,
+            &GrantPurpose::Research,
+            &3_600,
+        ),
+        ContractError::Unauthorized,
+    );
+
+    // Delegates may still grant for other purposes.
+    fx.client.grant_access_with_purpose(
+        &delegate,
+        &patient,
+        &grantee,
+        &AccessLevel::Read,
+        &GrantPurpose::Payment,
+        &3_600,
+    );
+
+    grant(&fx, &grantee, GrantPurpose::Research, 3_600);
+    let detail = fx.client.check_access_detailed(&patient, &grantee).unwrap();
+    assert_eq!(detail.purpose, GrantPurpose::Research);
+}
+
+#[test]
+fn test_pending_grant_keeps_purpose_until_approved() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let delegate = Address::generate(&fx.env);
+    let grantee = Address::generate(&fx.env);
+    fx.client
+        .delegate_role(&patient, &delegate, &Role::Optometrist, &0);
+    fx.client.set_require_patient_approval(&patient, &true);
+
+    fx.client.grant_access_with_purpose(
+        &delegate,
+        &patient,
+        &grantee,
+        &AccessLevel::Read,
+        &GrantPurpose::Payment,
+        &3_600,
+    );
+    let pending = fx.client.get_pending_grant(&patient, &grantee).unwrap();
+    assert_eq!(pending.purpose, GrantPurpose::Payment);
+
+    fx.client.approve_grant(&patient, &grantee);
+    let detail = fx.client.check_access_detailed(&patient, &grantee).unwrap();
+    assert_eq!(detail.purpose, GrantPurpose::Payment);
+}
+
+#[test]
+fn test_grant_stats_count_active_grants_by_purpose() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let grantees: std::vec::Vec<Address> = (0..5).map(|_| Address::generate(&fx.env)).collect();
+
+    grant(&fx, &grantees[0], GrantPurpose::Treatment, 7_200);
+    grant(&fx, &grantees[1], GrantPurpose::Payment, 7_200);
+    grant(&fx, &grantees[2], GrantPurpose::Payment, 7_200);
+    grant(&fx, &grantees[3], GrantPurpose::Research, 3_600);
+    grant(&fx, &grantees[4], GrantPurpose::Personal, 7_200);
+
+    let stats = fx.client.get_grant_stats(&patient, &patient);
+    assert_eq!(stats.grants_active, 5);
+    assert_eq!(
+        stats.grants_active_by_purpose,
+        Vec::from_array(
+            &fx.env,
+            [
+                PurposeCount {
+                    purpose: GrantPurpose::Treatment,
+                    count: 1,
+                },
+                PurposeCount {
+                    purpose: GrantPurpose::Payment,
+                    count: 2,
+                },
+                PurposeCount {
+                    purpose: GrantPurpose::Research,
+                    count: 1,
+                },
+                PurposeCount {
+                    purpose: GrantPurpose::Personal,
+                    count: 1,
+                },
+            ],
+        )
+    );
+
+    // Expired and revoked grants drop out of the counts.
+    fx.advance_time(3_600);
+    fx.client.revoke_access(&patient, &grantees[1]);
+    let stats = fx.client.get_grant_stats(&fx.admin(), &patient);
+    assert_eq!(stats.grants_active, 3);
+    assert_eq!(count_for(&stats, GrantPurpose::Payment), 1);
+    assert_eq!(count_for(&stats, GrantPurpose::Research), 0);
+}
+
+#[test]
+fn test_grant_stats_require_patient_or_admin() {
+    let fx = setup();
+    let stranger = Address::generate(&fx.env);
+
+    assert_err(
+        fx.client
+            .try_get_grant_stats(&stranger, &fx.patient("pat_a")),
+        ContractError::Unauthorized,
+    );
+}
@@ -0,0 +1,157 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{BatchRecordInput, ContractError, QuotaUsage, RecordType};
+use crate::quota::DEFAULT_PATIENT_RECORD_QUOTA;
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{testutils::Address as _, Address, String, Vec};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_patient("pat_b")
+        .build()
+}
+
+fn try_add(fx: &TestContract, patient: &str) -> Result<u64, ContractError> {
+    let provider = fx.provider("dr_a");
+    match fx.client.try_add_record(
+        &provider,
+        &fx.patient(patient),
+        &provider,
+        &RecordType::Examination,
+        &String::from_str(&fx.env, HASH_A),
+    ) {
+        Ok(Ok(id)) => Ok(id),
+        Err(Ok(err)) => Err(err),
+        other => panic!("unexpected result {:?}", other),
+    }
+}
+
+#[test]
+fn test_quota_enforced_at_boundary() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+
+    assert_eq!(
+        fx.client.get_patient_quota_usage(&patient),
+        QuotaUsage {
+            used: 0,
+            limit: DEFAULT_PATIENT_RECORD_QUOTA,
+        }
+    );
+
+    fx.client.set_default_patient_quota(&fx.admin(), &3);
+    for _ in 0..3 {
+        try_add(&fx, "pat_a").unwrap();
+    }
+    assert_eq!(
+        fx.client.get_patient_quota_usage(&patient),
+        QuotaUsage { used: 3, limit: 3 }
+    );
+    assert_eq!(try_add(&fx, "pat_a"), Err(ContractError::QuotaExceeded));
+    assert_eq!(fx.client.get_patient_records(&patient).len(), 3);
+}
+
+#[test]
+fn test_patient_override_takes_precedence_over_default() {
+    let fx = setup();
+    let admin = fx.admin();
+    fx.client.set_default_patient_quota(&admin, &1);
+    fx.client
+        .set_patient_quota(&admin, &fx.patient("pat_a"), &2);
+
+    try_add(&fx, "pat_a").unwrap();
+    try_add(&fx, "pat_a").unwrap();
+    assert_eq!(try_add(&fx, "pat_a"), Err(ContractError::QuotaExceeded));
+
+    // Other patients stay on the default.
+    try_add(&fx, "pat_b").unwrap();
+    assert_eq!(try_add(&fx, "pat_b"), Err(ContractError::QuotaExceeded));
+
+    // An override below current usage blocks new records only.
+    fx.client
+        .set_patient_quota(&admin, &fx.patient("pat_a"), &1);
+    assert_eq!(
+        fx.client.get_patient_quota_usage(&fx.patient("pat_a")),
+        QuotaUsage { used: 2, limit: 1 }
+    );
+    assert_eq!(try_add(&fx, "pat_a"), Err(ContractError::QuotaExceeded));
+
+    // Clearing the override falls back to the default.
+    fx.client
+        .set_patient_quota(&admin, &fx.patient("pat_a"), &0);
+    assert_eq!(
+        fx.client
+            .get_patient_quota_usage(&fx.patient("pat_a"))
+            .limit,
+        1
+    );
+}
+
+#[test]
+fn test_batch_add_respects_quota() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    fx.client.set_patient_quota(&fx.admin(), &patient, &2);
+    try_add(&fx, "pat_a").unwrap();
+
+    let input = BatchRecordInput {
+        patient: patient.clone(),
+        record_type: RecordType::Examination,
+        data_hash: String::from_str(&fx.env, HASH_A),
+    };
+    assert_err(
+        fx.client.try_add_records(
+            &fx.provider("dr_a"),
+            &Vec::from_array(&fx.env, [input.clone(), input]),
+        ),
+        ContractError::QuotaExceeded,
+    );
+    assert_eq!(fx.client.get_patient_records(&patient).len(), 1);
+}
+
+#[test]
+fn test_superseded_records_still_count() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    fx.client.set_patient_quota(&fx.admin(), &patient, &2);
+    let first = try_add(&fx, "pat_a").unwrap();
+
+    fx.client.supersede_record(
+        &fx.provider("dr_a"),
+        &first,
+        &String::from_str(&fx.env, HASH_A),
+        &RecordType::Examination,
+    );
+    assert_eq!(fx.client.get_patient_quota_usage(&patient).used, 2);
+    assert_eq!(try_add(&fx, "pat_a"), Err(ContractError::QuotaExceeded));
+}
+
+#[test]
+fn test_quota_setters_require_admin() {
+    let fx = setup();
+    let stranger = Address::generate(&fx.env);
+
+    assert_err(
+        fx.client.try_set_default_patient_quota(&stranger, &10),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client
+            .try_set_patient_quota(&stranger, &fx.patient("pat_a"), &10),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_set_default_patient_quota(&fx.admin(), &0),
+        ContractError::InvalidInput,
+    );
+}
@@ -6,6 +6,7 @@
 )]
 
 use super::{ConsentType, Permission, Role, VisionRecordsContract, VisionRecordsContractClient};
+use crate::testutils::install_default_policy;
 use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, Env, String, Vec};
 
 fn setup_test() -> (Env, VisionRecordsContractClient<'static>, Address) {
@@ -14,9 +15,10 @@ fn setup_test() -> (Env, VisionRecordsContractClient<'static>, Address) {
 
     let contract_id = env.register(VisionRecordsContract, ());
     let client = VisionRecordsContractClient::new(&env, &contract_id);
+    install_default_policy(&env, &contract_id);
 
     let admin = Address::generate(&env);
-    client.initialize(&admin);
+    client.initialize(&admin, &None);
 
     (env, client, admin)
 }
@@ -0,0 +1,147 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessLevel, AddRecordReceipt, RecordType, Role};
+use crate::testutils::{Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+const HASH_C: &str = "QmPChd2hVbrJ6bfo3WBcTW4iZnpHm8TEzWkLHmLpXhF68A";
+const DAY: u64 = 86_400;
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .build()
+}
+
+fn add_v2(fx: &TestContract) -> AddRecordReceipt {
+    let provider = fx.provider("dr_a");
+    fx.client.add_record_v2(
+        &provider,
+        &fx.patient("pat_a"),
+        &provider,
+        &RecordType::Examination,
+        &String::from_str(&fx.env, HASH_A),
+    )
+}
+
+#[test]
+fn test_add_record_receipt_matches_record() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+
+    let receipt = add_v2(&fx);
+    assert_eq!(receipt.version, 1);
+    assert_eq!(receipt.created_at, FIXTURE_START_TIME);
+
+    let record = fx.client.get_record(&provider, &receipt.record_id);
+    assert_eq!(record.id, receipt.record_id);
+    assert_eq!(record.created_at, receipt.created_at);
+    let version = fx
+        .client
+        .get_record_version(&provider, &receipt.record_id, &receipt.version);
+    assert_eq!(version.modified_at, receipt.created_at);
+}
+
+#[test]
+fn test_add_record_v2_shares_id_sequence() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    let first = fx.client.add_record(
+        &provider,
+        &fx.patient("pat_a"),
+        &provider,
+        &RecordType::Examination,
+        &String::from_str(&fx.env, HASH_A),
+    );
+    assert_eq!(add_v2(&fx).record_id, first + 1);
+}
+
+#[test]
+fn test_update_receipt_matches_version() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    let record_id = add_v2(&fx).record_id;
+
+    fx.advance_time(60);
+    let first =
+        fx.client
+            .update_record_v2(&provider, &record_id, &String::from_str(&fx.env, HASH_B));
+    assert_eq!(first.record_id, record_id);
+    assert_eq!(first.new_version, 2);
+    assert_eq!(first.updated_at, FIXTURE_START_TIME + 60);
+    assert_eq!(first.previous_hash, String::from_str(&fx.env, HASH_A));
+
+    fx.advance_time(60);
+    let second =
+        fx.client
+            .update_record_v2(&provider, &record_id, &String::from_str(&fx.env, HASH_C));
+    assert_eq!(second.new_version, 3);
+    assert_eq!(second.previous_hash, String::from_str(&fx.env, HASH_B));
+
+    let record = fx.client.get_record(&provider, &record_id);
+    assert_eq!(record.updated_at, second.updated_at);
+    let version = fx
+        .client
+        .get_record_version(&provider, &record_id, &second.new_version);
+    assert_eq!(version.modified_at, second.updated_at);
+    assert_eq!(version.data_hash, String::from_str(&fx.env, HASH_C));
+}
+
+#[test]
+fn test_update_record_still_returns_version() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    let record_id = add_v2(&fx).record_id;
+    let version =
+        fx.client
+            .update_record(&provider, &record_id, &String::from_str(&fx.env, HASH_B));
+    assert_eq!(version, 2);
+}
+
+#[test]
+fn test_grant_receipt_matches_stored_grant() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let grantee = Address::generate(&fx.env);
+
+    let receipt = fx
+        .client
+        .grant_access_v2(&patient, &patient, &grantee, &AccessLevel::Read, &DAY)
+        .unwrap();
+    assert_eq!(receipt.granted_at, FIXTURE_START_TIME);
+    assert_eq!(receipt.expires_at, FIXTURE_START_TIME + DAY);
+
+    let detail = fx.client.check_access_detailed(&patient, &grantee).unwrap();
+    assert_eq!(detail.granted_at, receipt.granted_at);
+    assert_eq!(detail.expires_at, receipt.expires_at);
+}
+
+#[test]
+fn test_pending_grant_has_no_receipt() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let delegate = Address::generate(&fx.env);
+    let grantee = Address::generate(&fx.env);
+    fx.client
+        .delegate_role(&patient, &delegate, &Role::Optometrist, &0);
+    fx.client.set_require_patient_approval(&patient, &true);
+
+    let receipt =
+        fx.client
+            .grant_access_v2(&delegate, &patient, &grantee, &AccessLevel::Read, &DAY);
+    assert_eq!(receipt, None);
+    assert!(fx
+        .client
+        .check_access_detailed(&patient, &grantee)
+        .is_none());
+    assert!(fx.client.get_pending_grant(&patient, &grantee).is_some());
+}
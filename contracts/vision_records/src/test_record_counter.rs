@@ -0,0 +1,162 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+extern crate std;
+
+use super::{ContractError, RecordType};
+use crate::record_counter::{self, DEFAULT_COUNTER_SHARDS, MAX_COUNTER_SHARDS};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{symbol_short, Address, String};
+use std::vec::Vec as StdVec;
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const PROVIDERS: [&str; 6] = ["dr_a", "dr_b", "dr_c", "dr_d", "dr_e", "dr_f"];
+
+fn setup() -> TestContract {
+    let mut fixture = Fixture::new().with_admin();
+    for name in PROVIDERS {
+        fixture = fixture.with_provider(name);
+    }
+    fixture.with_patient("pat_a").build()
+}
+
+fn add(fx: &TestContract, provider: &Address) -> u64 {
+    fx.client.add_record(
+        provider,
+        &fx.patient("pat_a"),
+        provider,
+        &RecordType::Examination,
+        &String::from_str(&fx.env, HASH_A),
+    )
+}
+
+fn shard_of(fx: &TestContract, provider: &Address) -> u32 {
+    fx.env.as_contract(&fx.contract_id, || {
+        record_counter::shard_of(&fx.env, provider)
+    })
+}
+
+#[test]
+fn test_ids_are_unique_across_shards() {
+    let fx = setup();
+    assert_eq!(fx.client.get_counter_shards(), DEFAULT_COUNTER_SHARDS);
+
+    let mut ids: StdVec<u64> = StdVec::new();
+    let mut shards: StdVec<u32> = StdVec::new();
+    for name in PROVIDERS {
+        let provider = fx.provider(name);
+        let shard = shard_of(&fx, &provider);
+        let first = add(&fx, &provider);
+        let second = add(&fx, &provider);
+        // Each provider allocates from its own shard, in sequence
+        assert_eq!(record_counter::split(first).0, shard);
+        assert_eq!(second, first + 1);
+        ids.extend([first, second]);
+        if !shards.contains(&shard) {
+            shards.push(shard);
+        }
+    }
+    assert!(shards.len() > 1);
+
+    ids.sort_unstable();
+    ids.dedup();
+    assert_eq!(ids.len(), 2 * PROVIDERS.len());
+    for record_id in ids {
+        assert_eq!(
+            fx.client.get_record(&fx.provider("dr_a"), &record_id).id,
+            record_id
+        );
+    }
+}
+
+#[test]
+fn test_count_sums_every_shard() {
+    let fx = setup();
+    for name in PROVIDERS {
+        add(&fx, &fx.provider(name));
+    }
+    assert_eq!(fx.client.get_record_count(), PROVIDERS.len() as u64);
+
+    // Shrinking the shard count still counts records in the dropped shards
+    fx.client.set_counter_shards(&fx.admin(), &2);
+    assert_eq!(fx.client.get_record_count(), PROVIDERS.len() as u64);
+    let record_id = add(&fx, &fx.provider("dr_a"));
+    assert!(record_counter::split(record_id).0 < 2);
+    assert_eq!(fx.client.get_record_count(), PROVIDERS.len() as u64 + 1);
+}
+
+#[test]
+fn test_shard_zero_continues_after_legacy_ids() {
+    let fx = setup();
+    let admin = fx.admin();
+    let dr_a = fx.provider("dr_a");
+    // With one shard every ID has the pre-sharding form
+    fx.client.set_counter_shards(&admin, &1);
+    let legacy = add(&fx, &dr_a);
+    assert_eq!(legacy, 1);
+    // Pretend IDs 1..=5 came from the old single instance counter
+    fx.env.as_contract(&fx.contract_id, || {
+        fx.env
+            .storage()
+            .persistent()
+            .remove(&record_counter::shard_key(0));
+        fx.env
+            .storage()
+            .instance()
+            .set(&symbol_short!("REC_CTR"), &5u64);
+    });
+    assert_eq!(fx.client.get_record_count(), 5);
+    assert_eq!(add(&fx, &dr_a), 6);
+
+    fx.client
+        .set_counter_shards(&admin, &DEFAULT_COUNTER_SHARDS);
+    let sharded = PROVIDERS
+        .iter()
+        .map(|name| fx.provider(name))
+        .find(|provider| shard_of(&fx, provider) != 0)
+        .unwrap();
+    let record_id = add(&fx, &sharded);
+    assert!(record_id > record_counter::MAX_SEQUENCE);
+    assert_eq!(fx.client.get_record_count(), 7);
+    assert_eq!(fx.client.get_record(&dr_a, &legacy).id, legacy);
+    fx.assert_invariants();
+}
+
+#[test]
+fn test_count_tracks_allocations_across_shards() {
+    let fx = setup();
+    for (i, name) in PROVIDERS.iter().enumerate() {
+        for _ in 0..=i {
+            add(&fx, &fx.provider(name));
+        }
+    }
+    let summed = fx.env.as_contract(&fx.contract_id, || {
+        (0..DEFAULT_COUNTER_SHARDS)
+            .map(|shard| record_counter::current_sequence(&fx.env, shard))
+            .sum::<u64>()
+    });
+    assert_eq!(summed, 21);
+    assert_eq!(fx.client.get_record_count(), summed);
+}
+
+#[test]
+fn test_set_counter_shards_is_bounded_and_admin_only() {
+    let fx = setup();
+    let admin = fx.admin();
+    for shards in [0, MAX_COUNTER_SHARDS + 1] {
+        assert_err(
+            fx.client.try_set_counter_shards(&admin, &shards),
+            ContractError::InvalidInput,
+        );
+    }
+    assert_err(
+        fx.client.try_set_counter_shards(&fx.provider("dr_a"), &4),
+        ContractError::Unauthorized,
+    );
+    fx.client.set_counter_shards(&admin, &MAX_COUNTER_SHARDS);
+    assert_eq!(fx.client.get_counter_shards(), MAX_COUNTER_SHARDS);
+}
@@ -0,0 +1,193 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+extern crate std;
+
+use super::{record_order, ContractError, RecordOrder, RecordType};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{testutils::Address as _, Address, String, Vec};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_patient("pat_b")
+        .build()
+}
+
+/// Adds a record for `patient` ten seconds after the previous one.
+fn add_record(fx: &TestContract, patient: &str) -> u64 {
+    fx.advance_time(10);
+    let provider = fx.provider("dr_a");
+    fx.client.add_record(
+        &provider,
+        &fx.patient(patient),
+        &provider,
+        &RecordType::Prescription,
+        &String::from_str(&fx.env, HASH_A),
+    )
+}
+
+fn ordered_ids(fx: &TestContract, order: RecordOrder, offset: u32, limit: u32) -> Vec<u64> {
+    let patient = fx.patient("pat_a");
+    let records = fx
+        .client
+        .get_patient_records_ordered(&patient, &patient, &order, &offset, &limit);
+    let mut ids = Vec::new(&fx.env);
+    for record in records.iter() {
+        ids.push_back(record.id);
+    }
+    ids
+}
+
+#[test]
+fn test_pin_limit() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let mut records = std::vec::Vec::new();
+    for _ in 0..=record_order::MAX_PINS_PER_PATIENT {
+        records.push(add_record(&fx, "pat_a"));
+    }
+
+    for record_id in records.iter().take(5) {
+        fx.client.pin_record(&patient, record_id);
+    }
+    assert_err(
+        fx.client.try_pin_record(&patient, &records[5]),
+        ContractError::PinLimitReached,
+    );
+    assert_err(
+        fx.client.try_pin_record(&patient, &records[0]),
+        ContractError::AlreadyExists,
+    );
+
+    fx.client.unpin_record(&patient, &records[0]);
+    fx.client.pin_record(&patient, &records[5]);
+
+    let pins = fx.client.get_pinned_records(&patient);
+    assert_eq!(pins.len(), 5);
+    assert!(!pins.contains(records[0]));
+    assert_eq!(pins.last().unwrap(), records[5]);
+
+    assert_err(
+        fx.client.try_unpin_record(&patient, &records[0]),
+        ContractError::RecordNotFound,
+    );
+}
+
+#[test]
+fn test_cannot_pin_another_patients_record() {
+    let fx = setup();
+    let other = add_record(&fx, "pat_b");
+
+    assert_err(
+        fx.client.try_pin_record(&fx.patient("pat_a"), &other),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_pin_record(&fx.patient("pat_a"), &999),
+        ContractError::RecordNotFound,
+    );
+    assert!(fx
+        .client
+        .get_pinned_records(&fx.patient("pat_a"))
+        .is_empty());
+}
+
+#[test]
+fn test_each_ordering() {
+    let fx = setup();
+    let first = add_record(&fx, "pat_a");
+    let second = add_record(&fx, "pat_a");
+    let third = add_record(&fx, "pat_a");
+    add_record(&fx, "pat_b");
+
+    fx.advance_time(10);
+    let provider = fx.provider("dr_a");
+    fx.client
+        .update_record(&provider, &first, &String::from_str(&fx.env, HASH_B));
+    fx.client.pin_record(&fx.patient("pat_a"), &second);
+
+    let ids = |order| ordered_ids(&fx, order, 0, 10);
+    assert_eq!(
+        ids(RecordOrder::CreatedAsc),
+        Vec::from_array(&fx.env, [first, second, third])
+    );
+    assert_eq!(
+        ids(RecordOrder::CreatedDesc),
+        Vec::from_array(&fx.env, [third, second, first])
+    );
+    assert_eq!(
+        ids(RecordOrder::UpdatedDesc),
+        Vec::from_array(&fx.env, [first, third, second])
+    );
+    assert_eq!(
+        ids(RecordOrder::PinnedFirst),
+        Vec::from_array(&fx.env, [second, third, first])
+    );
+}
+
+#[test]
+fn test_ordered_records_are_paged() {
+    let fx = setup();
+    let first = add_record(&fx, "pat_a");
+    let second = add_record(&fx, "pat_a");
+    let third = add_record(&fx, "pat_a");
+
+    assert_eq!(
+        ordered_ids(&fx, RecordOrder::CreatedDesc, 1, 1),
+        Vec::from_array(&fx.env, [second])
+    );
+    assert_eq!(
+        ordered_ids(&fx, RecordOrder::CreatedAsc, 2, 5),
+        Vec::from_array(&fx.env, [third])
+    );
+    assert!(ordered_ids(&fx, RecordOrder::CreatedAsc, 3, 5).is_empty());
+
+    let patient = fx.patient("pat_a");
+    let page =
+        fx.client
+            .get_patient_records_ordered(&patient, &patient, &RecordOrder::CreatedAsc, &0, &1);
+    assert_eq!(page.get(0).unwrap().id, first);
+    assert_eq!(
+        page.get(0).unwrap().data_hash,
+        String::from_str(&fx.env, HASH_A)
+    );
+
+    assert_err(
+        fx.client.try_get_patient_records_ordered(
+            &patient,
+            &patient,
+            &RecordOrder::CreatedAsc,
+            &0,
+            &0,
+        ),
+        ContractError::InvalidInput,
+    );
+}
+
+#[test]
+fn test_ordered_records_require_read_access() {
+    let fx = setup();
+    add_record(&fx, "pat_a");
+    let stranger = Address::generate(&fx.env);
+
+    assert_err(
+        fx.client.try_get_patient_records_ordered(
+            &stranger,
+            &fx.patient("pat_a"),
+            &RecordOrder::CreatedDesc,
+            &0,
+            &10,
+        ),
+        ContractError::Unauthorized,
+    );
+}
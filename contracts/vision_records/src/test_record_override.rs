@@ -0,0 +1,265 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{
+    AccessLevel, AuthAction, ConsentType, ContractError, EffectiveAccess, RecordType, Role,
+};
+use crate::auth;
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{testutils::Address as _, Address, Symbol};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+const DAY: u64 = 86_400;
+const YEAR: u64 = 365 * DAY;
+const GRANT_ENDS: u64 = FIXTURE_START_TIME + YEAR;
+const WEEK_ENDS: u64 = FIXTURE_START_TIME + 7 * DAY;
+
+/// `pat_a` has two records and shares their whole chart with the staff
+/// member for a year.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider_role("staff", Role::Staff)
+        .with_provider("dr_b")
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .with_record(RecordType::Examination, HASH_B)
+        .build();
+    let patient = fx.patient("pat_a");
+    let staff = fx.provider("staff");
+    fx.client
+        .grant_consent(&patient, &staff, &ConsentType::Treatment, &YEAR);
+    fx.client
+        .grant_access(&patient, &patient, &staff, &AccessLevel::Read, &YEAR);
+    fx
+}
+
+fn can_read(fx: &TestContract, reader: &Address, record_id: u64) -> bool {
+    fx.client.try_get_record(reader, &record_id).is_ok()
+}
+
+fn effective(fx: &TestContract, grantee: &Address, record_id: u64) -> EffectiveAccess {
+    let patient = fx.patient("pat_a");
+    fx.client
+        .get_effective_access(&patient, &patient, grantee, &record_id)
+}
+
+#[test]
+fn test_override_caps_one_record_of_a_chart_wide_grant() {
+    let fx = setup();
+    let staff = fx.provider("staff");
+    let (sensitive, other) = (fx.record(0), fx.record(1));
+    fx.client
+        .set_record_access_override(&fx.patient("pat_a"), &staff, &sensitive, &WEEK_ENDS);
+
+    assert!(can_read(&fx, &staff, sensitive));
+    assert_eq!(
+        effective(&fx, &staff, sensitive),
+        EffectiveAccess {
+            level: AccessLevel::Read,
+            expires_at: Some(WEEK_ENDS),
+            override_expires_at: Some(WEEK_ENDS),
+        }
+    );
+
+    fx.set_time(WEEK_ENDS);
+    assert_err(
+        fx.client.try_get_record(&staff, &sensitive),
+        ContractError::Unauthorized,
+    );
+    let decision = fx
+        .client
+        .authorize(&staff, &AuthAction::ReadRecord(sensitive));
+    assert_eq!(decision.reason, Symbol::new(&fx.env, auth::OVERRIDDEN));
+    assert_eq!(effective(&fx, &staff, sensitive).level, AccessLevel::None);
+
+    // The rest of the chart stays shared for the full year.
+    assert!(can_read(&fx, &staff, other));
+    assert_eq!(
+        effective(&fx, &staff, other),
+        EffectiveAccess {
+            level: AccessLevel::Read,
+            expires_at: Some(GRANT_ENDS),
+            override_expires_at: None,
+        }
+    );
+}
+
+#[test]
+fn test_past_override_excludes_record_until_cleared() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let staff = fx.provider("staff");
+    let record_id = fx.record(0);
+    fx.set_time(FIXTURE_START_TIME + DAY);
+
+    fx.client
+        .set_record_access_override(&patient, &staff, &record_id, &FIXTURE_START_TIME);
+    assert!(!can_read(&fx, &staff, record_id));
+
+    fx.client
+        .clear_record_access_override(&patient, &staff, &record_id);
+    assert!(can_read(&fx, &staff, record_id));
+    assert_err(
+        fx.client
+            .try_clear_record_access_override(&patient, &staff, &record_id),
+        ContractError::AccessOverrideNotFound,
+    );
+}
+
+#[test]
+fn test_override_never_extends_the_grant() {
+    let fx = setup();
+    let staff = fx.provider("staff");
+    let record_id = fx.record(0);
+    fx.client.set_record_access_override(
+        &fx.patient("pat_a"),
+        &staff,
+        &record_id,
+        &(GRANT_ENDS + YEAR),
+    );
+    assert_eq!(
+        effective(&fx, &staff, record_id).expires_at,
+        Some(GRANT_ENDS)
+    );
+
+    fx.set_time(GRANT_ENDS);
+    assert!(!can_read(&fx, &staff, record_id));
+    assert_eq!(
+        effective(&fx, &staff, record_id),
+        EffectiveAccess {
+            level: AccessLevel::None,
+            expires_at: None,
+            override_expires_at: Some(GRANT_ENDS + YEAR),
+        }
+    );
+}
+
+#[test]
+fn test_override_caps_record_level_grants_too() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let record_id = fx.record(0);
+    let grantee = Address::generate(&fx.env);
+    fx.client
+        .grant_record_access(&patient, &grantee, &record_id, &AccessLevel::Full, &YEAR);
+    // A stronger record grant wins over the patient-wide one.
+    let staff = fx.provider("staff");
+    fx.client.grant_record_access(
+        &patient,
+        &staff,
+        &record_id,
+        &AccessLevel::Write,
+        &(30 * DAY),
+    );
+    assert_eq!(
+        effective(&fx, &staff, record_id),
+        EffectiveAccess {
+            level: AccessLevel::Write,
+            expires_at: Some(FIXTURE_START_TIME + 30 * DAY),
+            override_expires_at: None,
+        }
+    );
+
+    fx.client
+        .set_record_access_override(&patient, &grantee, &record_id, &WEEK_ENDS);
+    assert!(can_read(&fx, &grantee, record_id));
+    fx.set_time(WEEK_ENDS);
+    assert!(!can_read(&fx, &grantee, record_id));
+    assert_eq!(
+        fx.client.check_record_access(&record_id, &grantee),
+        AccessLevel::Full
+    );
+    assert_eq!(effective(&fx, &grantee, record_id).level, AccessLevel::None);
+}
+
+#[test]
+fn test_role_readers_ignore_overrides() {
+    let fx = setup();
+    let dr_b = fx.provider("dr_b");
+    let record_id = fx.record(0);
+    fx.client.set_record_access_override(
+        &fx.patient("pat_a"),
+        &dr_b,
+        &record_id,
+        &FIXTURE_START_TIME,
+    );
+    assert!(can_read(&fx, &dr_b, record_id));
+}
+
+#[test]
+fn test_override_follows_migrated_grantee() {
+    let fx = setup();
+    let staff = fx.provider("staff");
+    let record_id = fx.record(0);
+    fx.client
+        .set_record_access_override(&fx.patient("pat_a"), &staff, &record_id, &WEEK_ENDS);
+
+    let rotated = Address::generate(&fx.env);
+    let confirm = fx
+        .client
+        .compute_confirmation(&crate::confirmation::MIGRATE_GRANTEE, &staff);
+    fx.client.migrate_grantee(&staff, &rotated, &10, &confirm);
+    assert_eq!(
+        effective(&fx, &rotated, record_id).override_expires_at,
+        Some(WEEK_ENDS)
+    );
+    assert_eq!(effective(&fx, &staff, record_id).override_expires_at, None);
+}
+
+#[test]
+fn test_override_validation_and_access() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let staff = fx.provider("staff");
+    let record_id = fx.record(0);
+    let stranger = Address::generate(&fx.env);
+
+    assert_err(
+        fx.client
+            .try_set_record_access_override(&stranger, &staff, &record_id, &WEEK_ENDS),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client
+            .try_set_record_access_override(&patient, &patient, &record_id, &WEEK_ENDS),
+        ContractError::InvalidInput,
+    );
+    assert_err(
+        fx.client
+            .try_set_record_access_override(&patient, &staff, &(record_id + 10), &WEEK_ENDS),
+        ContractError::RecordNotFound,
+    );
+
+    fx.client
+        .set_record_access_override(&patient, &staff, &record_id, &WEEK_ENDS);
+    assert_err(
+        fx.client
+            .try_clear_record_access_override(&stranger, &staff, &record_id),
+        ContractError::Unauthorized,
+    );
+
+    // The grantee and admins may inspect; others may not.
+    for caller in [staff.clone(), fx.admin()] {
+        let resolved = fx
+            .client
+            .get_effective_access(&caller, &patient, &staff, &record_id);
+        assert_eq!(resolved.expires_at, Some(WEEK_ENDS));
+    }
+    assert_err(
+        fx.client
+            .try_get_effective_access(&stranger, &patient, &staff, &record_id),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client
+            .try_get_effective_access(&patient, &stranger, &staff, &record_id),
+        ContractError::Unauthorized,
+    );
+}
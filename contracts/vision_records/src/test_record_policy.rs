@@ -0,0 +1,181 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{
+    BatchRecordInput, ContractError, Permission, RecordType, RecordTypePolicy,
+    RecordTypeRequirement, Role,
+};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{testutils::Address as _, Address, String, Vec};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+
+/// Surgery records are reserved for ophthalmologists.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("opto")
+        .with_provider_role("ophth", Role::Ophthalmologist)
+        .with_patient("pat_a")
+        .build();
+    fx.client.set_record_type_policy(
+        &fx.admin(),
+        &RecordType::Surgery,
+        &Some(RecordTypeRequirement::MinRole(Role::Ophthalmologist)),
+    );
+    fx
+}
+
+fn try_add(
+    fx: &TestContract,
+    caller: &Address,
+    provider: &Address,
+    record_type: RecordType,
+) -> Result<u64, ContractError> {
+    fx.client
+        .try_add_record(
+            caller,
+            &fx.patient("pat_a"),
+            provider,
+            &record_type,
+            &String::from_str(&fx.env, HASH_A),
+        )
+        .map(|id| id.unwrap())
+        .map_err(|err| err.unwrap())
+}
+
+#[test]
+fn test_no_policies_by_default() {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("opto")
+        .with_patient("pat_a")
+        .build();
+    assert!(fx.client.get_record_type_policies().is_empty());
+    let opto = fx.provider("opto");
+    assert!(try_add(&fx, &opto, &opto, RecordType::Surgery).is_ok());
+}
+
+#[test]
+fn test_optometrist_rejected_for_restricted_type_only() {
+    let fx = setup();
+    let opto = fx.provider("opto");
+
+    assert!(try_add(&fx, &opto, &opto, RecordType::Examination).is_ok());
+    assert_eq!(
+        try_add(&fx, &opto, &opto, RecordType::Surgery),
+        Err(ContractError::Unauthorized)
+    );
+}
+
+#[test]
+fn test_ophthalmologist_meets_min_role() {
+    let fx = setup();
+    let ophth = fx.provider("ophth");
+    let record_id = try_add(&fx, &ophth, &ophth, RecordType::Surgery).unwrap();
+    assert_eq!(
+        fx.client.get_record(&ophth, &record_id).record_type,
+        RecordType::Surgery
+    );
+}
+
+#[test]
+fn test_admin_override() {
+    let fx = setup();
+    let opto = fx.provider("opto");
+    assert!(try_add(&fx, &fx.admin(), &opto, RecordType::Surgery).is_ok());
+}
+
+#[test]
+fn test_policy_applies_to_batch() {
+    let fx = setup();
+    let opto = fx.provider("opto");
+    let batch = Vec::from_array(
+        &fx.env,
+        [
+            BatchRecordInput {
+                patient: fx.patient("pat_a"),
+                record_type: RecordType::Examination,
+                data_hash: String::from_str(&fx.env, HASH_A),
+            },
+            BatchRecordInput {
+                patient: fx.patient("pat_a"),
+                record_type: RecordType::Surgery,
+                data_hash: String::from_str(&fx.env, HASH_A),
+            },
+        ],
+    );
+    assert_err(
+        fx.client.try_add_records(&opto, &batch),
+        ContractError::Unauthorized,
+    );
+    assert_eq!(fx.client.get_record_count(), 0);
+}
+
+#[test]
+fn test_permission_requirement() {
+    let fx = setup();
+    let opto = fx.provider("opto");
+    fx.client.set_record_type_policy(
+        &fx.admin(),
+        &RecordType::LabResult,
+        &Some(RecordTypeRequirement::Permission(Permission::ImportRecords)),
+    );
+    assert_eq!(
+        try_add(&fx, &opto, &opto, RecordType::LabResult),
+        Err(ContractError::Unauthorized)
+    );
+
+    fx.client
+        .grant_custom_permission(&fx.admin(), &opto, &Permission::ImportRecords);
+    assert!(try_add(&fx, &opto, &opto, RecordType::LabResult).is_ok());
+}
+
+#[test]
+fn test_policies_enumerate_and_clear() {
+    let fx = setup();
+    fx.client.set_record_type_policy(
+        &fx.admin(),
+        &RecordType::Diagnosis,
+        &Some(RecordTypeRequirement::MinRole(Role::Optometrist)),
+    );
+
+    let policies = fx.client.get_record_type_policies();
+    assert_eq!(
+        policies,
+        Vec::from_array(
+            &fx.env,
+            [
+                RecordTypePolicy {
+                    record_type: RecordType::Diagnosis,
+                    requirement: RecordTypeRequirement::MinRole(Role::Optometrist),
+                },
+                RecordTypePolicy {
+                    record_type: RecordType::Surgery,
+                    requirement: RecordTypeRequirement::MinRole(Role::Ophthalmologist),
+                },
+            ]
+        )
+    );
+
+    fx.client
+        .set_record_type_policy(&fx.admin(), &RecordType::Surgery, &None);
+    assert_eq!(fx.client.get_record_type_policies().len(), 1);
+    let opto = fx.provider("opto");
+    assert!(try_add(&fx, &opto, &opto, RecordType::Surgery).is_ok());
+}
+
+#[test]
+fn test_set_policy_requires_admin() {
+    let fx = setup();
+    let outsider = Address::generate(&fx.env);
+    assert_err(
+        fx.client
+            .try_set_record_type_policy(&outsider, &RecordType::Surgery, &None),
+        ContractError::Unauthorized,
+    );
+}
@@ -0,0 +1,239 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{
+    ContractError, EyeExamination, IntraocularPressure, OptFundusPhotography, OptRetinalImaging,
+    OptVisualField, Permission, RecordPriority, RecordType, Role, SlitLampFindings, VisualAcuity,
+};
+use crate::examination::{OptPhysicalMeasurement, PhysicalMeasurement};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{symbol_short, Address, String, Symbol, Vec};
+
+const HASH: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+
+/// `comp_exam` needs examination details, `surgery` needs a cosigner and
+/// `plain` needs neither.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider_role("tech", Role::Staff)
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .build();
+    let admin = fx.admin();
+    fx.client
+        .grant_custom_permission(&admin, &fx.provider("tech"), &Permission::AppendRecord);
+    fx.client.define_record_preset(
+        &admin,
+        &symbol_short!("comp_exam"),
+        &RecordType::Examination,
+        &true,
+        &false,
+        &RecordPriority::Critical,
+    );
+    fx.client.define_record_preset(
+        &admin,
+        &symbol_short!("surgery"),
+        &RecordType::Surgery,
+        &false,
+        &true,
+        &RecordPriority::Critical,
+    );
+    fx.client.define_record_preset(
+        &admin,
+        &symbol_short!("plain"),
+        &RecordType::Examination,
+        &false,
+        &false,
+        &RecordPriority::Archivable,
+    );
+    fx
+}
+
+fn text(fx: &TestContract, value: &str) -> String {
+    String::from_str(&fx.env, value)
+}
+
+fn exam(fx: &TestContract) -> EyeExamination {
+    EyeExamination {
+        record_id: 0,
+        visual_acuity: VisualAcuity {
+            uncorrected: PhysicalMeasurement {
+                left_eye: text(fx, "20/20"),
+                right_eye: text(fx, "20/25"),
+            },
+            corrected: OptPhysicalMeasurement::None,
+        },
+        iop: IntraocularPressure {
+            left_eye: 15,
+            right_eye: 16,
+            method: text(fx, "tonometry"),
+            timestamp: 0,
+        },
+        slit_lamp: SlitLampFindings {
+            cornea: text(fx, "clear"),
+            anterior_chamber: text(fx, "deep"),
+            iris: text(fx, "normal"),
+            lens: text(fx, "clear"),
+        },
+        visual_field: OptVisualField::None,
+        retina_imaging: OptRetinalImaging::None,
+        fundus_photo: OptFundusPhotography::None,
+        clinical_notes: text(fx, "routine"),
+    }
+}
+
+fn add(
+    fx: &TestContract,
+    preset: Symbol,
+    coauthors: &[Address],
+    examination: Option<EyeExamination>,
+) -> Result<u64, ContractError> {
+    let provider = fx.provider("dr_a");
+    let mut list = Vec::new(&fx.env);
+    for coauthor in coauthors {
+        list.push_back(coauthor.clone());
+    }
+    fx.client
+        .try_add_record_with_preset(
+            &provider,
+            &fx.patient("pat_a"),
+            &provider,
+            &preset,
+            &text(fx, HASH),
+            &list,
+            &examination,
+        )
+        .map(|id| id.unwrap())
+        .map_err(|err| err.unwrap())
+}
+
+#[test]
+fn test_requires_details() {
+    let fx = setup();
+    let preset = symbol_short!("comp_exam");
+    assert_eq!(
+        add(&fx, preset.clone(), &[], None),
+        Err(ContractError::PresetRequirementUnmet)
+    );
+    assert_eq!(fx.client.get_record_count(), 0);
+
+    let record_id = add(&fx, preset.clone(), &[], Some(exam(&fx))).unwrap();
+    let stored = fx
+        .client
+        .get_eye_examination(&fx.provider("dr_a"), &record_id);
+    assert_eq!(stored.record_id, record_id);
+    assert_eq!(stored.iop.right_eye, 16);
+    assert_eq!(fx.client.get_record_preset(&record_id), Some(preset));
+    assert_eq!(
+        fx.client.get_record_priority(&record_id),
+        RecordPriority::Critical
+    );
+}
+
+#[test]
+fn test_requires_cosign() {
+    let fx = setup();
+    let preset = symbol_short!("surgery");
+    assert_eq!(
+        add(&fx, preset.clone(), &[], None),
+        Err(ContractError::PresetRequirementUnmet)
+    );
+
+    let tech = fx.provider("tech");
+    let record_id = add(&fx, preset.clone(), core::slice::from_ref(&tech), None).unwrap();
+    let record = fx.client.get_record(&fx.provider("dr_a"), &record_id);
+    assert_eq!(record.record_type, RecordType::Surgery);
+    assert_eq!(record.coauthors, Vec::from_array(&fx.env, [tech]));
+    assert_eq!(fx.client.get_record_preset(&record_id), Some(preset));
+
+    // Details only fit Examination records
+    assert_eq!(
+        add(
+            &fx,
+            symbol_short!("surgery"),
+            &[fx.provider("tech")],
+            Some(exam(&fx))
+        ),
+        Err(ContractError::InvalidRecordType)
+    );
+}
+
+#[test]
+fn test_plain_preset_sets_priority_only() {
+    let fx = setup();
+    let record_id = add(&fx, symbol_short!("plain"), &[], None).unwrap();
+    assert_eq!(
+        fx.client.get_record_priority(&record_id),
+        RecordPriority::Archivable
+    );
+
+    let plain = fx.client.add_record(
+        &fx.provider("dr_a"),
+        &fx.patient("pat_a"),
+        &fx.provider("dr_a"),
+        &RecordType::Examination,
+        &text(&fx, HASH),
+    );
+    assert_eq!(fx.client.get_record_preset(&plain), None);
+}
+
+#[test]
+fn test_unknown_preset() {
+    let fx = setup();
+    assert_eq!(
+        add(&fx, symbol_short!("missing"), &[], Some(exam(&fx))),
+        Err(ContractError::PresetNotFound)
+    );
+    assert_eq!(fx.client.get_record_count(), 0);
+}
+
+#[test]
+fn test_define_and_list_presets() {
+    let fx = setup();
+    let admin = fx.admin();
+    let presets = fx.client.get_record_presets();
+    assert_eq!(presets.len(), 3);
+    assert_eq!(presets.get(0).unwrap().name, symbol_short!("comp_exam"));
+    assert!(presets.get(1).unwrap().requires_cosign);
+
+    // Redefining keeps the position
+    fx.client.define_record_preset(
+        &admin,
+        &symbol_short!("comp_exam"),
+        &RecordType::Examination,
+        &false,
+        &false,
+        &RecordPriority::Standard,
+    );
+    let presets = fx.client.get_record_presets();
+    assert_eq!(presets.len(), 3);
+    assert!(!presets.get(0).unwrap().requires_details);
+
+    assert_err(
+        fx.client.try_define_record_preset(
+            &admin,
+            &symbol_short!("bad"),
+            &RecordType::Surgery,
+            &true,
+            &false,
+            &RecordPriority::Standard,
+        ),
+        ContractError::InvalidInput,
+    );
+    assert_err(
+        fx.client.try_define_record_preset(
+            &fx.provider("dr_a"),
+            &symbol_short!("mine"),
+            &RecordType::Examination,
+            &false,
+            &false,
+            &RecordPriority::Standard,
+        ),
+        ContractError::Unauthorized,
+    );
+}
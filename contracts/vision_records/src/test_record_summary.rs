@@ -0,0 +1,168 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{
+    versioning, AccessLevel, ConsentType, ContractError, RecordFetchResult, RecordSummary,
+    RecordType,
+};
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{testutils::Address as _, Address, String, Vec};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+const DAY: u64 = 86_400;
+
+/// `dr_a` wrote a record for `pat_a`, who has given `dr_b` write access.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_b")
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .build();
+    let patient = fx.patient("pat_a");
+    let dr_b = fx.provider("dr_b");
+    fx.client
+        .grant_consent(&patient, &dr_b, &ConsentType::Treatment, &(30 * DAY));
+    fx.client
+        .grant_access(&patient, &patient, &dr_b, &AccessLevel::Write, &(30 * DAY));
+    fx
+}
+
+fn update(fx: &TestContract, author: &str, hash: &str) {
+    fx.advance_time(60);
+    fx.client.update_record(
+        &fx.provider(author),
+        &fx.record(0),
+        &String::from_str(&fx.env, hash),
+    );
+}
+
+/// The summary as computed from the full history.
+fn from_history(fx: &TestContract, record_id: u64) -> RecordSummary {
+    let caller = fx.provider("dr_a");
+    let count = fx.client.get_record_version_count(&record_id);
+    let last = fx.client.get_record_version(&caller, &record_id, &count);
+    RecordSummary {
+        record_id,
+        version_count: count,
+        last_modified_by: last.modified_by,
+        last_modified_at: last.modified_at,
+    }
+}
+
+#[test]
+fn test_new_record_summary() {
+    let fx = setup();
+    let summary = fx
+        .client
+        .get_record_summary(&fx.patient("pat_a"), &fx.record(0));
+    assert_eq!(
+        summary,
+        RecordSummary {
+            record_id: fx.record(0),
+            version_count: 1,
+            last_modified_by: fx.provider("dr_a"),
+            last_modified_at: FIXTURE_START_TIME,
+        }
+    );
+}
+
+#[test]
+fn test_summary_matches_history_after_mixed_operations() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    let caller = fx.provider("dr_a");
+
+    update(&fx, "dr_b", HASH_B);
+    assert_eq!(
+        fx.client.get_record_summary(&caller, &record_id),
+        from_history(&fx, record_id)
+    );
+
+    update(&fx, "dr_a", HASH_A);
+    // A rejected write leaves both untouched
+    assert!(fx
+        .client
+        .try_update_record(
+            &Address::generate(&fx.env),
+            &record_id,
+            &String::from_str(&fx.env, HASH_B)
+        )
+        .is_err());
+    update(&fx, "dr_b", HASH_B);
+    let summary = fx.client.get_record_summary(&caller, &record_id);
+    assert_eq!(summary, from_history(&fx, record_id));
+    assert_eq!(summary.version_count, 4);
+    assert_eq!(summary.last_modified_by, fx.provider("dr_b"));
+
+    fx.advance_time(60);
+    let replacement = fx.client.supersede_record(
+        &caller,
+        &record_id,
+        &String::from_str(&fx.env, HASH_A),
+        &RecordType::Examination,
+    );
+    let summary = fx.client.get_record_summary(&caller, &record_id);
+    assert_eq!(summary, from_history(&fx, record_id));
+    assert_eq!(summary.version_count, 5);
+    assert_eq!(summary.last_modified_by, caller);
+    assert_eq!(
+        fx.client.get_record_summary(&caller, &replacement),
+        from_history(&fx, replacement)
+    );
+}
+
+#[test]
+fn test_batch_read_includes_summary() {
+    let fx = setup();
+    update(&fx, "dr_b", HASH_B);
+    let results = fx.client.get_records(
+        &fx.patient("pat_a"),
+        &Vec::from_array(&fx.env, [fx.record(0)]),
+    );
+    match results.get(0) {
+        Some(RecordFetchResult::Found(record, summary)) => {
+            assert_eq!(summary.record_id, record.id);
+            assert_eq!(summary, from_history(&fx, record.id));
+        }
+        other => panic!("expected a record, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_summary_derived_for_records_without_one() {
+    let fx = setup();
+    update(&fx, "dr_b", HASH_B);
+    let record_id = fx.record(0);
+    fx.env.as_contract(&fx.contract_id, || {
+        fx.env
+            .storage()
+            .persistent()
+            .remove(&versioning::summary_key(record_id));
+    });
+    assert_eq!(
+        fx.client
+            .get_record_summary(&fx.provider("dr_a"), &record_id),
+        from_history(&fx, record_id)
+    );
+}
+
+#[test]
+fn test_summary_requires_read_access() {
+    let fx = setup();
+    assert_err(
+        fx.client
+            .try_get_record_summary(&Address::generate(&fx.env), &fx.record(0)),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_get_record_summary(&fx.patient("pat_a"), &999),
+        ContractError::RecordNotFound,
+    );
+}
@@ -0,0 +1,385 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessLevel, ConsentType, ContractError, RecordType, Role};
+use crate::confirmation::FINALIZE_RECOVERY;
+use crate::recovery::RECOVERY_TIMELOCK_SECONDS;
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{testutils::Address as _, Address, Bytes, BytesN, String};
+use teye_common::AdminTier;
+
+const SECRET: &[u8] = b"correct horse battery staple";
+const DAY: u64 = 86_400;
+const UNLOCKS_AT: u64 = FIXTURE_START_TIME + RECOVERY_TIMELOCK_SECONDS;
+
+/// `pat_a` has two records and has shared them with `dr_a` for 30 days.
+/// `second` is a second ContractAdmin.
+struct Setup {
+    fx: TestContract,
+    old: Address,
+    new: Address,
+    second: Address,
+}
+
+fn setup() -> Setup {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(
+            RecordType::Examination,
+            "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG",
+        )
+        .with_record(
+            RecordType::Diagnosis,
+            "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o",
+        )
+        .build();
+    let old = fx.patient("pat_a");
+    let provider = fx.provider("dr_a");
+    fx.client
+        .grant_consent(&old, &provider, &ConsentType::Treatment, &(30 * DAY));
+    fx.client
+        .grant_access(&old, &old, &provider, &AccessLevel::Read, &(30 * DAY));
+
+    let second = Address::generate(&fx.env);
+    fx.client
+        .promote_admin(&fx.admin(), &second, &AdminTier::ContractAdmin);
+    let new = Address::generate(&fx.env);
+    Setup {
+        fx,
+        old,
+        new,
+        second,
+    }
+}
+
+fn secret(s: &Setup) -> Bytes {
+    Bytes::from_slice(&s.fx.env, SECRET)
+}
+
+fn challenge(s: &Setup) -> BytesN<32> {
+    s.fx.env.crypto().sha256(&secret(s)).to_bytes()
+}
+
+fn confirm(s: &Setup) -> BytesN<32> {
+    s.fx.client.compute_confirmation(&FINALIZE_RECOVERY, &s.old)
+}
+
+fn initiate(s: &Setup) {
+    s.fx.client
+        .initiate_recovery(&s.fx.admin(), &s.old, &s.new, &challenge(s));
+}
+
+#[test]
+fn test_initiate_records_pending_recovery() {
+    let s = setup();
+    initiate(&s);
+
+    let pending = s.fx.client.get_recovery(&s.old).unwrap();
+    assert_eq!(pending.new_patient, s.new);
+    assert_eq!(pending.initiated_by, s.fx.admin());
+    assert_eq!(pending.unlocks_at, UNLOCKS_AT);
+    assert_eq!(pending.challenge_hash, challenge(&s));
+}
+
+#[test]
+fn test_finalize_rebinds_chart() {
+    let s = setup();
+    let provider = s.fx.provider("dr_a");
+    let records = s.fx.client.get_patient_records(&s.old);
+    initiate(&s);
+
+    s.fx.set_time(UNLOCKS_AT);
+    s.fx.client
+        .finalize_recovery(&s.second, &s.old, &secret(&s), &confirm(&s), &10);
+
+    assert_eq!(s.fx.client.get_patient_records(&s.new), records);
+    assert!(s.fx.client.get_patient_records(&s.old).is_empty());
+    for record_id in records.iter() {
+        let record = s.fx.client.get_record(&s.new, &record_id);
+        assert_eq!(record.patient, s.new);
+    }
+
+    assert_eq!(
+        s.fx.client.check_access(&s.new, &provider),
+        AccessLevel::Read
+    );
+    assert_eq!(
+        s.fx.client.check_access(&s.old, &provider),
+        AccessLevel::None
+    );
+    let granted_by = s.fx.client.get_grantee_patients(&provider);
+    assert!(granted_by.contains(&s.new));
+    assert!(!granted_by.contains(&s.old));
+    assert!(s.fx.client.verify_grant_indexes(&s.new));
+
+    let user = s.fx.client.get_user(&s.new);
+    assert_eq!(user.address, s.new);
+    assert_eq!(user.role, Role::Patient);
+    assert_err(
+        s.fx.client.try_get_user(&s.old),
+        ContractError::UserNotFound,
+    );
+    assert!(s.fx.client.get_recovery(&s.old).is_none());
+}
+
+#[test]
+fn test_finalize_drops_expired_grants() {
+    let s = setup();
+    initiate(&s);
+
+    s.fx.set_time(FIXTURE_START_TIME + 31 * DAY);
+    s.fx.client
+        .finalize_recovery(&s.second, &s.old, &secret(&s), &confirm(&s), &10);
+
+    let provider = s.fx.provider("dr_a");
+    assert_eq!(
+        s.fx.client.check_access(&s.new, &provider),
+        AccessLevel::None
+    );
+    assert!(s.fx.client.get_grantee_patients(&provider).is_empty());
+}
+
+#[test]
+fn test_old_patient_can_veto() {
+    let s = setup();
+    initiate(&s);
+
+    s.fx.advance_time(DAY);
+    s.fx.client.cancel_recovery(&s.old);
+    assert!(s.fx.client.get_recovery(&s.old).is_none());
+
+    s.fx.set_time(UNLOCKS_AT);
+    assert_err(
+        s.fx.client
+            .try_finalize_recovery(&s.second, &s.old, &secret(&s), &confirm(&s), &10),
+        ContractError::RecoveryNotFound,
+    );
+    assert_eq!(s.fx.client.get_patient_records(&s.old).len(), 2);
+}
+
+#[test]
+fn test_cancel_without_pending_recovery() {
+    let s = setup();
+    assert_err(
+        s.fx.client.try_cancel_recovery(&s.old),
+        ContractError::RecoveryNotFound,
+    );
+}
+
+#[test]
+fn test_premature_finalize_rejected() {
+    let s = setup();
+    initiate(&s);
+
+    s.fx.set_time(UNLOCKS_AT - 1);
+    assert_err(
+        s.fx.client
+            .try_finalize_recovery(&s.second, &s.old, &secret(&s), &confirm(&s), &10),
+        ContractError::RecoveryLocked,
+    );
+    assert!(s.fx.client.get_recovery(&s.old).is_some());
+    assert!(s.fx.client.get_patient_records(&s.new).is_empty());
+}
+
+#[test]
+fn test_wrong_preimage_rejected() {
+    let s = setup();
+    initiate(&s);
+
+    s.fx.set_time(UNLOCKS_AT);
+    let wrong = Bytes::from_slice(&s.fx.env, b"incorrect horse");
+    assert_err(
+        s.fx.client
+            .try_finalize_recovery(&s.second, &s.old, &wrong, &confirm(&s), &10),
+        ContractError::InvalidRecoveryProof,
+    );
+    assert!(s.fx.client.get_recovery(&s.old).is_some());
+    assert_eq!(s.fx.client.get_patient_records(&s.old).len(), 2);
+
+    s.fx.client
+        .finalize_recovery(&s.second, &s.old, &secret(&s), &confirm(&s), &10);
+    assert_eq!(s.fx.client.get_patient_records(&s.new).len(), 2);
+}
+
+#[test]
+fn test_confirmation_for_another_address_rejected() {
+    let s = setup();
+    initiate(&s);
+
+    s.fx.set_time(UNLOCKS_AT);
+    // Confirming the new address instead of the one being recovered
+    let swapped = s.fx.client.compute_confirmation(&FINALIZE_RECOVERY, &s.new);
+    assert_err(
+        s.fx.client
+            .try_finalize_recovery(&s.second, &s.old, &secret(&s), &swapped, &10),
+        ContractError::ConfirmationMismatch,
+    );
+    assert!(s.fx.client.get_recovery(&s.old).is_some());
+    assert_eq!(s.fx.client.get_patient_records(&s.old).len(), 2);
+}
+
+#[test]
+fn test_initiator_cannot_finalize_with_second_admin_available() {
+    let s = setup();
+    initiate(&s);
+
+    s.fx.set_time(UNLOCKS_AT);
+    assert_err(
+        s.fx.client
+            .try_finalize_recovery(&s.fx.admin(), &s.old, &secret(&s), &confirm(&s), &10),
+        ContractError::Unauthorized,
+    );
+}
+
+#[test]
+fn test_sole_admin_may_finalize() {
+    let fx = Fixture::new().with_admin().with_patient("pat_a").build();
+    let old = fx.patient("pat_a");
+    let new = Address::generate(&fx.env);
+    let secret = Bytes::from_slice(&fx.env, SECRET);
+    let challenge = fx.env.crypto().sha256(&secret).to_bytes();
+    fx.client
+        .initiate_recovery(&fx.admin(), &old, &new, &challenge);
+
+    fx.set_time(UNLOCKS_AT);
+    let confirm = fx.client.compute_confirmation(&FINALIZE_RECOVERY, &old);
+    fx.client
+        .finalize_recovery(&fx.admin(), &old, &secret, &confirm, &10);
+    assert_eq!(fx.client.get_user(&new).address, new);
+}
+
+#[test]
+fn test_initiate_requires_admin() {
+    let s = setup();
+    let outsider = Address::generate(&s.fx.env);
+    assert_err(
+        s.fx.client
+            .try_initiate_recovery(&outsider, &s.old, &s.new, &challenge(&s)),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        s.fx.client
+            .try_initiate_recovery(&s.fx.provider("dr_a"), &s.old, &s.new, &challenge(&s)),
+        ContractError::Unauthorized,
+    );
+}
+
+#[test]
+fn test_initiate_validates_addresses() {
+    let s = setup();
+    assert_err(
+        s.fx.client
+            .try_initiate_recovery(&s.fx.admin(), &s.old, &s.old, &challenge(&s)),
+        ContractError::InvalidInput,
+    );
+    assert_err(
+        s.fx.client.try_initiate_recovery(
+            &s.fx.admin(),
+            &Address::generate(&s.fx.env),
+            &s.new,
+            &challenge(&s),
+        ),
+        ContractError::UserNotFound,
+    );
+    // The target must be an unused address
+    assert_err(
+        s.fx.client.try_initiate_recovery(
+            &s.fx.admin(),
+            &s.old,
+            &s.fx.provider("dr_a"),
+            &challenge(&s),
+        ),
+        ContractError::AlreadyExists,
+    );
+
+    initiate(&s);
+    assert_err(
+        s.fx.client
+            .try_initiate_recovery(&s.second, &s.old, &s.new, &challenge(&s)),
+        ContractError::AlreadyExists,
+    );
+}
+
+#[test]
+fn test_finalize_rejects_target_claimed_meanwhile() {
+    let s = setup();
+    initiate(&s);
+    s.fx.client.register_user(
+        &s.fx.admin(),
+        &s.new,
+        &Role::Patient,
+        &String::from_str(&s.fx.env, "squatter"),
+    );
+
+    s.fx.set_time(UNLOCKS_AT);
+    assert_err(
+        s.fx.client
+            .try_finalize_recovery(&s.second, &s.old, &secret(&s), &confirm(&s), &10),
+        ContractError::AlreadyExists,
+    );
+}
+
+#[test]
+fn test_finalize_resumes_across_calls() {
+    let s = setup();
+    let provider = s.fx.provider("dr_a");
+    let records = s.fx.client.get_patient_records(&s.old);
+    initiate(&s);
+    s.fx.set_time(UNLOCKS_AT);
+    let finalize = || {
+        s.fx.client
+            .finalize_recovery(&s.second, &s.old, &secret(&s), &confirm(&s), &1)
+    };
+
+    // Two records and one grant to move, one per call
+    let first = finalize();
+    assert!(first.started);
+    assert_eq!((first.records_rebound, first.remaining), (1, 2));
+    assert_eq!(s.fx.client.get_recovery(&s.old), Some(first));
+    assert_eq!(s.fx.client.get_patient_records(&s.new).len(), 1);
+    assert_eq!(s.fx.client.get_patient_records(&s.old).len(), 1);
+    // Half-moved charts cannot be vetoed, and the user entry stays put
+    assert_err(
+        s.fx.client.try_cancel_recovery(&s.old),
+        ContractError::RecoveryInProgress,
+    );
+    assert_eq!(s.fx.client.get_user(&s.old).address, s.old);
+
+    let second = finalize();
+    assert_eq!(
+        (
+            second.records_rebound,
+            second.grants_rebound,
+            second.remaining
+        ),
+        (2, 0, 1)
+    );
+    assert_err(
+        s.fx.client.try_get_user(&s.new),
+        ContractError::UserNotFound,
+    );
+
+    let last = finalize();
+    assert_eq!(
+        (last.records_rebound, last.grants_rebound, last.remaining),
+        (2, 1, 0)
+    );
+    assert!(s.fx.client.get_recovery(&s.old).is_none());
+    assert_eq!(s.fx.client.get_patient_records(&s.new), records);
+    assert_eq!(
+        s.fx.client.check_access(&s.new, &provider),
+        AccessLevel::Read
+    );
+    assert_eq!(s.fx.client.get_user(&s.new).address, s.new);
+    assert_err(
+        s.fx.client
+            .try_finalize_recovery(&s.second, &s.old, &secret(&s), &confirm(&s), &1),
+        ContractError::RecoveryNotFound,
+    );
+}
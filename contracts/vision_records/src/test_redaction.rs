@@ -0,0 +1,159 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{ContractError, DigestScope, RecordType};
+use crate::digest;
+use crate::redaction::TOMBSTONE;
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{BytesN, String, Vec};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+const HASH_C: &str = "QmPZ9gcCEpqKTo6aq61g2nXGUhM4iCL3ewB6LDXZCtioEB";
+
+/// `pat_a`'s record has three versions: HASH_A, HASH_B, HASH_C.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .build();
+    for hash in [HASH_B, HASH_C] {
+        fx.advance_time(60);
+        fx.client.update_record(
+            &fx.provider("dr_a"),
+            &fx.record(0),
+            &String::from_str(&fx.env, hash),
+        );
+    }
+    fx
+}
+
+fn reason(fx: &TestContract) -> BytesN<32> {
+    BytesN::from_array(&fx.env, &[7; 32])
+}
+
+fn redact(fx: &TestContract, version: u32) {
+    fx.client
+        .redact_version(&fx.admin(), &fx.record(0), &version, &reason(fx));
+}
+
+#[test]
+fn test_redacted_version_shows_tombstone() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    let before = fx
+        .client
+        .get_record_version(&fx.provider("dr_a"), &record_id, &2);
+    redact(&fx, 2);
+
+    for reader in [fx.provider("dr_a"), fx.patient("pat_a")] {
+        let entry = fx.client.get_record_version(&reader, &record_id, &2);
+        assert!(entry.redacted);
+        assert_eq!(entry.data_hash, String::from_str(&fx.env, TOMBSTONE));
+        assert_eq!(entry.modified_at, before.modified_at);
+        assert_eq!(entry.modified_by, before.modified_by);
+    }
+
+    // Neighbouring versions and the record itself are untouched
+    let reader = fx.patient("pat_a");
+    let first = fx.client.get_record_version(&reader, &record_id, &1);
+    assert!(!first.redacted);
+    assert_eq!(first.data_hash, String::from_str(&fx.env, HASH_A));
+    assert_eq!(
+        fx.client.get_record(&reader, &record_id).data_hash,
+        String::from_str(&fx.env, HASH_C)
+    );
+    let page = fx
+        .client
+        .get_record_history_page(&reader, &record_id, &None, &10);
+    assert_eq!(page.versions.len(), 3);
+    assert!(page.versions.get(1).unwrap().redacted);
+}
+
+#[test]
+fn test_admin_reads_sealed_original() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    redact(&fx, 2);
+
+    let original = fx.client.get_redacted_original(&fx.admin(), &record_id, &2);
+    assert_eq!(original.data_hash, String::from_str(&fx.env, HASH_B));
+    assert_eq!(original.reason_hash, reason(&fx));
+    assert_eq!(original.redacted_by, fx.admin());
+
+    assert_err(
+        fx.client
+            .try_get_redacted_original(&fx.provider("dr_a"), &record_id, &2),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client
+            .try_get_redacted_original(&fx.admin(), &record_id, &1),
+        ContractError::RecordNotFound,
+    );
+}
+
+#[test]
+fn test_redaction_rejections() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    assert_err(
+        fx.client
+            .try_redact_version(&fx.provider("dr_a"), &record_id, &2, &reason(&fx)),
+        ContractError::Unauthorized,
+    );
+    // The latest version is the record's current content
+    assert_err(
+        fx.client
+            .try_redact_version(&fx.admin(), &record_id, &3, &reason(&fx)),
+        ContractError::InvalidInput,
+    );
+    assert_err(
+        fx.client
+            .try_redact_version(&fx.admin(), &record_id, &9, &reason(&fx)),
+        ContractError::RecordNotFound,
+    );
+
+    redact(&fx, 2);
+    assert_err(
+        fx.client
+            .try_redact_version(&fx.admin(), &record_id, &2, &reason(&fx)),
+        ContractError::InvalidInput,
+    );
+}
+
+#[test]
+fn test_digest_records_redaction_marker() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    let record_before = fx.client.get_state_digest(&DigestScope::Record(record_id));
+    let patient_scope = DigestScope::Patient(fx.patient("pat_a"));
+    let patient_before = fx.client.get_state_digest(&patient_scope);
+
+    redact(&fx, 1);
+    let record_after = fx.client.get_state_digest(&DigestScope::Record(record_id));
+    assert_ne!(record_after, record_before);
+    assert_ne!(fx.client.get_state_digest(&patient_scope), patient_before);
+    // Stable once redacted, and derived from the marker list
+    assert_eq!(
+        fx.client.get_state_digest(&DigestScope::Record(record_id)),
+        record_after
+    );
+    fx.env.as_contract(&fx.contract_id, || {
+        let state = digest::record_state(&fx.env, record_id).unwrap();
+        assert_eq!(state.redacted_versions, Vec::from_array(&fx.env, [1]));
+        assert_eq!(digest::record_digest(&fx.env, &state), record_after);
+    });
+
+    redact(&fx, 2);
+    assert_ne!(
+        fx.client.get_state_digest(&DigestScope::Record(record_id)),
+        record_after
+    );
+}
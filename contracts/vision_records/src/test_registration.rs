@@ -0,0 +1,102 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{ContractError, Permission, Role, VisionRecordsContract, VisionRecordsContractClient};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger, Address, Env, String};
+
+fn setup() -> (Env, VisionRecordsContractClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register(VisionRecordsContract, ());
+    let client = VisionRecordsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &None);
+
+    (env, client, admin)
+}
+
+fn name(env: &Env) -> String {
+    String::from_str(env, "Jane Patient")
+}
+
+#[test]
+fn test_self_registration_rejected_when_disabled() {
+    let (env, client, _admin) = setup();
+    let patient = Address::generate(&env);
+
+    assert!(!client.self_registration_allowed());
+    let res = client.try_self_register_patient(&patient, &name(&env));
+    assert_eq!(res, Err(Ok(ContractError::AccessDenied)));
+    assert!(client.try_get_user(&patient).is_err());
+}
+
+#[test]
+fn test_self_registration_accepted_when_enabled() {
+    let (env, client, admin) = setup();
+    let patient = Address::generate(&env);
+
+    client.set_patient_self_registration(&admin, &true);
+    client.self_register_patient(&patient, &name(&env));
+
+    let user = client.get_user(&patient);
+    assert_eq!(user.role, Role::Patient);
+    assert!(user.is_active);
+    assert!(!client.check_permission(&patient, &Permission::ManageUsers));
+    assert!(!client.check_permission(&patient, &Permission::WriteRecord));
+
+    let res = client.try_self_register_patient(&patient, &name(&env));
+    assert_eq!(res, Err(Ok(ContractError::AlreadyExists)));
+}
+
+#[test]
+fn test_self_registration_rejects_existing_user() {
+    let (env, client, admin) = setup();
+    let provider = Address::generate(&env);
+    client.register_user(
+        &admin,
+        &provider,
+        &Role::Optometrist,
+        &String::from_str(&env, "Dr. Provider"),
+    );
+
+    client.set_patient_self_registration(&admin, &true);
+    let res = client.try_self_register_patient(&provider, &name(&env));
+    assert_eq!(res, Err(Ok(ContractError::AlreadyExists)));
+    assert_eq!(client.get_user(&provider).role, Role::Optometrist);
+}
+
+#[test]
+fn test_self_registration_daily_cap() {
+    let (env, client, admin) = setup();
+    client.set_patient_self_registration(&admin, &true);
+    client.set_self_registration_daily_cap(&admin, &2);
+
+    client.self_register_patient(&Address::generate(&env), &name(&env));
+    client.self_register_patient(&Address::generate(&env), &name(&env));
+    let late = Address::generate(&env);
+    let res = client.try_self_register_patient(&late, &name(&env));
+    assert_eq!(res, Err(Ok(ContractError::RateLimitExceeded)));
+
+    // The cap resets on the next day.
+    env.ledger().set_timestamp(1_000 + 86_400);
+    client.self_register_patient(&late, &name(&env));
+    assert_eq!(client.get_user(&late).role, Role::Patient);
+}
+
+#[test]
+fn test_self_registration_toggle_requires_admin() {
+    let (env, client, _admin) = setup();
+    let outsider = Address::generate(&env);
+
+    let res = client.try_set_patient_self_registration(&outsider, &true);
+    assert_eq!(res, Err(Ok(ContractError::Unauthorized)));
+    let res = client.try_set_self_registration_daily_cap(&outsider, &1_000);
+    assert_eq!(res, Err(Ok(ContractError::Unauthorized)));
+}
@@ -0,0 +1,178 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{BatchRecordInput, ContractError, RecordType};
+use crate::events::topics;
+use crate::residency::MAX_JURISDICTIONS;
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{symbol_short, Address, String, Symbol, Vec};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+
+/// `pat_a` resides in the EU; `dr_a` practises in the EU and UK, `dr_b`
+/// only in the US. Enforcement is on.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_provider("dr_b")
+        .with_patient("pat_a")
+        .with_patient("pat_b")
+        .build();
+    let admin = fx.admin();
+    fx.client
+        .set_patient_residency(&admin, &fx.patient("pat_a"), &symbol_short!("EU"));
+    fx.client.set_provider_jurisdictions(
+        &admin,
+        &fx.provider("dr_a"),
+        &Vec::from_array(&fx.env, [symbol_short!("EU"), symbol_short!("UK")]),
+    );
+    fx.client.set_provider_jurisdictions(
+        &admin,
+        &fx.provider("dr_b"),
+        &Vec::from_array(&fx.env, [symbol_short!("US")]),
+    );
+    fx.client.set_residency_enforcement(&admin, &true);
+    fx
+}
+
+fn add(
+    fx: &TestContract,
+    caller: &Address,
+    provider: &str,
+    patient: &str,
+) -> Result<u64, ContractError> {
+    match fx.client.try_add_record(
+        caller,
+        &fx.patient(patient),
+        &fx.provider(provider),
+        &RecordType::Examination,
+        &String::from_str(&fx.env, HASH_A),
+    ) {
+        Ok(Ok(record_id)) => Ok(record_id),
+        Err(Ok(err)) => Err(err),
+        other => panic!("unexpected result {:?}", other),
+    }
+}
+
+#[test]
+fn test_provider_in_patient_region_may_write() {
+    let fx = setup();
+    let dr_a = fx.provider("dr_a");
+    assert!(add(&fx, &dr_a, "dr_a", "pat_a").is_ok());
+}
+
+#[test]
+fn test_provider_outside_patient_region_is_rejected() {
+    let fx = setup();
+    let dr_b = fx.provider("dr_b");
+    assert_eq!(
+        add(&fx, &dr_b, "dr_b", "pat_a"),
+        Err(ContractError::ResidencyMismatch)
+    );
+
+    // A provider with no jurisdictions at all is rejected too
+    fx.client
+        .set_provider_jurisdictions(&fx.admin(), &dr_b, &Vec::new(&fx.env));
+    assert!(fx.client.get_provider_jurisdictions(&dr_b).is_empty());
+    assert_eq!(
+        add(&fx, &dr_b, "dr_b", "pat_a"),
+        Err(ContractError::ResidencyMismatch)
+    );
+}
+
+#[test]
+fn test_patient_without_region_is_unrestricted() {
+    let fx = setup();
+    let dr_b = fx.provider("dr_b");
+    assert_eq!(fx.client.get_patient_residency(&fx.patient("pat_b")), None);
+    assert!(add(&fx, &dr_b, "dr_b", "pat_b").is_ok());
+}
+
+#[test]
+fn test_enforcement_off_ignores_residency() {
+    let fx = setup();
+    let dr_b = fx.provider("dr_b");
+    fx.client.set_residency_enforcement(&fx.admin(), &false);
+
+    assert!(!fx.client.is_residency_enforced());
+    assert!(add(&fx, &dr_b, "dr_b", "pat_a").is_ok());
+}
+
+#[test]
+fn test_batch_add_enforces_residency() {
+    let fx = setup();
+    let dr_b = fx.provider("dr_b");
+    let mut inputs = Vec::new(&fx.env);
+    for patient in ["pat_b", "pat_a"] {
+        inputs.push_back(BatchRecordInput {
+            patient: fx.patient(patient),
+            record_type: RecordType::Examination,
+            data_hash: String::from_str(&fx.env, HASH_A),
+        });
+    }
+
+    assert_err(
+        fx.client.try_add_records(&dr_b, &inputs),
+        ContractError::ResidencyMismatch,
+    );
+    assert_eq!(fx.client.get_record_count(), 0);
+}
+
+#[test]
+fn test_admin_override_publishes_event() {
+    let fx = setup();
+    let admin = fx.admin();
+    let patient = fx.patient("pat_a");
+    let dr_b = fx.provider("dr_b");
+
+    let record_id = add(&fx, &admin, "dr_b", "pat_a").unwrap();
+    assert!(fx.published((topics::RES_OVRD, patient, dr_b.clone())));
+    assert_eq!(fx.client.get_record(&dr_b, &record_id).id, record_id);
+}
+
+#[test]
+fn test_residency_setters_require_authority() {
+    let fx = setup();
+    let patient = fx.patient("pat_b");
+    let dr_a = fx.provider("dr_a");
+    let region: Symbol = symbol_short!("EU");
+
+    assert_err(
+        fx.client
+            .try_set_patient_residency(&dr_a, &patient, &region),
+        ContractError::Unauthorized,
+    );
+    fx.client.set_patient_residency(&patient, &patient, &region);
+    assert_eq!(
+        fx.client.get_patient_residency(&patient),
+        Some(region.clone())
+    );
+
+    assert_err(
+        fx.client.try_set_provider_jurisdictions(
+            &dr_a,
+            &dr_a,
+            &Vec::from_array(&fx.env, [region.clone()]),
+        ),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_set_residency_enforcement(&dr_a, &false),
+        ContractError::Unauthorized,
+    );
+
+    let mut too_many = Vec::new(&fx.env);
+    for _ in 0..=MAX_JURISDICTIONS {
+        too_many.push_back(region.clone());
+    }
+    assert_err(
+        fx.client
+            .try_set_provider_jurisdictions(&fx.admin(), &dr_a, &too_many),
+        ContractError::InvalidInput,
+    );
+}
@@ -0,0 +1,184 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessLevel, ConsentType, ContractError};
+use crate::revocation::MAX_REVOCATION_GRACE_SECONDS;
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{testutils::Address as _, Address};
+
+const DAY: u64 = 86_400;
+const GRANT_ENDS: u64 = FIXTURE_START_TIME + 30 * DAY;
+const EFFECTIVE_AT: u64 = FIXTURE_START_TIME + DAY;
+
+/// `pat_a` has shared their chart with `dr_a` for 30 days; revocations
+/// wait a day before taking effect.
+fn setup() -> TestContract {
+    let fx = setup_without_grace();
+    fx.client.set_revocation_grace(&fx.admin(), &DAY);
+    fx
+}
+
+fn setup_without_grace() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .build();
+    let patient = fx.patient("pat_a");
+    let provider = fx.provider("dr_a");
+    fx.client
+        .grant_consent(&patient, &provider, &ConsentType::Treatment, &(30 * DAY));
+    fx.client.grant_access(
+        &patient,
+        &patient,
+        &provider,
+        &AccessLevel::Read,
+        &(30 * DAY),
+    );
+    fx
+}
+
+fn level(fx: &TestContract) -> AccessLevel {
+    fx.client
+        .check_access(&fx.patient("pat_a"), &fx.provider("dr_a"))
+}
+
+fn revoke(fx: &TestContract) {
+    fx.client
+        .revoke_access(&fx.patient("pat_a"), &fx.provider("dr_a"));
+}
+
+fn cancel(fx: &TestContract) -> Result<(), ContractError> {
+    fx.client
+        .try_cancel_revocation(&fx.patient("pat_a"), &fx.provider("dr_a"))
+        .map(|ok| ok.unwrap())
+        .map_err(|err| err.unwrap())
+}
+
+fn effective_at(fx: &TestContract) -> Option<u64> {
+    fx.client
+        .get_pending_revocation(&fx.patient("pat_a"), &fx.provider("dr_a"))
+        .map(|pending| pending.effective_at)
+}
+
+#[test]
+fn test_grant_honoured_until_revocation_takes_effect() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    revoke(&fx);
+    assert_eq!(effective_at(&fx), Some(EFFECTIVE_AT));
+    assert_eq!(level(&fx), AccessLevel::Read);
+
+    fx.set_time(EFFECTIVE_AT - 1);
+    assert_eq!(level(&fx), AccessLevel::Read);
+
+    fx.set_time(EFFECTIVE_AT);
+    assert_eq!(level(&fx), AccessLevel::None);
+    assert!(fx
+        .client
+        .check_access_detailed(&patient, &fx.provider("dr_a"))
+        .is_none());
+
+    // Once in effect the grant is expired and purgeable like any other.
+    assert_eq!(fx.client.purge_expired_grants(&patient, &patient), 1);
+    assert_eq!(effective_at(&fx), None);
+}
+
+#[test]
+fn test_cancel_restores_the_grant() {
+    let fx = setup();
+    revoke(&fx);
+    fx.advance_time(DAY / 2);
+    assert_eq!(cancel(&fx), Ok(()));
+    assert_eq!(effective_at(&fx), None);
+
+    fx.set_time(GRANT_ENDS - 1);
+    assert_eq!(level(&fx), AccessLevel::Read);
+    let detail = fx
+        .client
+        .check_access_detailed(&fx.patient("pat_a"), &fx.provider("dr_a"))
+        .unwrap();
+    assert_eq!(detail.expires_at, GRANT_ENDS);
+
+    assert_eq!(cancel(&fx), Err(ContractError::RevocationNotPending));
+}
+
+#[test]
+fn test_cancel_too_late() {
+    let fx = setup();
+    revoke(&fx);
+    fx.set_time(EFFECTIVE_AT);
+    assert_eq!(cancel(&fx), Err(ContractError::RevocationNotPending));
+    assert_eq!(level(&fx), AccessLevel::None);
+}
+
+#[test]
+fn test_repeated_revoke_keeps_the_schedule() {
+    let fx = setup();
+    revoke(&fx);
+    fx.advance_time(DAY / 2);
+    revoke(&fx);
+    assert_eq!(effective_at(&fx), Some(EFFECTIVE_AT));
+}
+
+#[test]
+fn test_new_grant_replaces_pending_revocation() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let provider = fx.provider("dr_a");
+    revoke(&fx);
+    fx.client.grant_access(
+        &patient,
+        &patient,
+        &provider,
+        &AccessLevel::Read,
+        &(7 * DAY),
+    );
+    assert_eq!(effective_at(&fx), None);
+
+    fx.set_time(EFFECTIVE_AT);
+    assert_eq!(level(&fx), AccessLevel::Read);
+}
+
+#[test]
+fn test_no_grace_revokes_immediately() {
+    let fx = setup_without_grace();
+    revoke(&fx);
+    assert_eq!(level(&fx), AccessLevel::None);
+    assert_eq!(effective_at(&fx), None);
+}
+
+#[test]
+fn test_immediate_revocation_bypasses_grace() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let provider = fx.provider("dr_a");
+    revoke(&fx);
+
+    fx.client.revoke_access_immediate(&patient, &provider);
+    assert_eq!(level(&fx), AccessLevel::None);
+    assert_eq!(effective_at(&fx), None);
+    assert_eq!(cancel(&fx), Err(ContractError::RevocationNotPending));
+}
+
+#[test]
+fn test_revocation_grace_config() {
+    let fx = setup();
+    assert_eq!(fx.client.get_revocation_grace(), DAY);
+    assert_err(
+        fx.client
+            .try_set_revocation_grace(&Address::generate(&fx.env), &0),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client
+            .try_set_revocation_grace(&fx.admin(), &(MAX_REVOCATION_GRACE_SECONDS + 1)),
+        ContractError::InvalidInput,
+    );
+    fx.client.set_revocation_grace(&fx.admin(), &0);
+    assert_eq!(fx.client.get_revocation_grace(), 0);
+}
@@ -0,0 +1,263 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{
+    rbac, role_history, ContractError, DeactivationCascade, Permission, Role, RoleAction,
+    RoleHistoryEntry,
+};
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{testutils::Address as _, vec, Address, String, Vec};
+
+const DAY: u64 = 86_400;
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .build()
+}
+
+fn register(fx: &TestContract, user: &Address, role: Role) {
+    fx.client
+        .register_user(&fx.admin(), user, &role, &String::from_str(&fx.env, "user"));
+}
+
+fn history(fx: &TestContract, user: &Address) -> Vec<RoleHistoryEntry> {
+    fx.client.get_role_history(&fx.admin(), user)
+}
+
+fn entry(role: Role, action: RoleAction, by: &Address, at: u64) -> RoleHistoryEntry {
+    RoleHistoryEntry {
+        role,
+        action,
+        by: by.clone(),
+        at,
+    }
+}
+
+#[test]
+fn test_history_follows_a_multi_transition_lifecycle() {
+    let fx = setup();
+    let (admin, dr_a) = (fx.admin(), fx.provider("dr_a"));
+    let staff = Address::generate(&fx.env);
+
+    // Registration assigns the role once, however many writes it makes
+    assert_eq!(
+        history(&fx, &dr_a),
+        vec![
+            &fx.env,
+            entry(
+                Role::Optometrist,
+                RoleAction::Assigned,
+                &admin,
+                FIXTURE_START_TIME
+            )
+        ]
+    );
+
+    fx.advance_time(DAY);
+    register(&fx, &dr_a, Role::Ophthalmologist);
+    fx.advance_time(DAY);
+    fx.client
+        .delegate_role(&dr_a, &staff, &Role::Optometrist, &0);
+    fx.advance_time(DAY);
+    fx.client.deactivate_user(
+        &admin,
+        &dr_a,
+        &DeactivationCascade::RevokeOutgoingDelegations,
+        &10,
+    );
+
+    assert_eq!(
+        history(&fx, &dr_a),
+        vec![
+            &fx.env,
+            entry(
+                Role::Optometrist,
+                RoleAction::Assigned,
+                &admin,
+                FIXTURE_START_TIME
+            ),
+            entry(
+                Role::Ophthalmologist,
+                RoleAction::Changed,
+                &admin,
+                FIXTURE_START_TIME + DAY
+            ),
+        ]
+    );
+    assert_eq!(
+        history(&fx, &staff),
+        vec![
+            &fx.env,
+            entry(
+                Role::Optometrist,
+                RoleAction::Delegated,
+                &dr_a,
+                FIXTURE_START_TIME + 2 * DAY
+            ),
+            entry(
+                Role::Optometrist,
+                RoleAction::Revoked,
+                &admin,
+                FIXTURE_START_TIME + 3 * DAY
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_expired_role_is_logged_when_next_assigned() {
+    let fx = setup();
+    let admin = fx.admin();
+    let temp = Address::generate(&fx.env);
+    fx.env.as_contract(&fx.contract_id, || {
+        rbac::assign_role(
+            &fx.env,
+            temp.clone(),
+            Role::Staff,
+            FIXTURE_START_TIME + DAY,
+            &admin,
+        );
+    });
+
+    fx.advance_time(2 * DAY);
+    register(&fx, &temp, Role::Staff);
+
+    assert_eq!(
+        history(&fx, &temp),
+        vec![
+            &fx.env,
+            entry(
+                Role::Staff,
+                RoleAction::Assigned,
+                &admin,
+                FIXTURE_START_TIME
+            ),
+            entry(
+                Role::Staff,
+                RoleAction::Expired,
+                &fx.contract_id,
+                FIXTURE_START_TIME + DAY
+            ),
+            entry(
+                Role::Staff,
+                RoleAction::Assigned,
+                &admin,
+                FIXTURE_START_TIME + 2 * DAY
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_users_registered_before_history_get_an_initial_entry() {
+    let fx = setup();
+    let (admin, dr_a) = (fx.admin(), fx.provider("dr_a"));
+    fx.env.as_contract(&fx.contract_id, || {
+        fx.env
+            .storage()
+            .persistent()
+            .remove(&role_history::history_key(&dr_a));
+    });
+    assert!(history(&fx, &dr_a).is_empty());
+
+    fx.advance_time(DAY);
+    register(&fx, &dr_a, Role::Staff);
+
+    assert_eq!(
+        history(&fx, &dr_a),
+        vec![
+            &fx.env,
+            entry(
+                Role::Optometrist,
+                RoleAction::Assigned,
+                &fx.contract_id,
+                FIXTURE_START_TIME
+            ),
+            entry(
+                Role::Staff,
+                RoleAction::Changed,
+                &admin,
+                FIXTURE_START_TIME + DAY
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_history_keeps_latest_entries() {
+    let fx = setup();
+    let dr_a = fx.provider("dr_a");
+    for i in 0..role_history::MAX_ROLE_HISTORY + 5 {
+        let role = if i % 2 == 0 {
+            Role::Staff
+        } else {
+            Role::Optometrist
+        };
+        register(&fx, &dr_a, role);
+    }
+
+    let entries = history(&fx, &dr_a);
+    assert_eq!(entries.len(), role_history::MAX_ROLE_HISTORY);
+    assert!(entries
+        .iter()
+        .all(|entry| entry.action == RoleAction::Changed));
+    assert_eq!(entries.last().unwrap().role, Role::Staff);
+}
+
+#[test]
+fn test_role_history_access() {
+    let fx = setup();
+    let (dr_a, pat_a) = (fx.provider("dr_a"), fx.patient("pat_a"));
+
+    // The user themselves and ManageUsers holders may read it
+    assert_eq!(fx.client.get_role_history(&pat_a, &pat_a).len(), 1);
+    assert_eq!(fx.client.get_role_history(&dr_a, &pat_a).len(), 1);
+
+    assert_err(
+        fx.client.try_get_role_history(&pat_a, &dr_a),
+        ContractError::Unauthorized,
+    );
+    let outsider = Address::generate(&fx.env);
+    assert_err(
+        fx.client.try_get_role_history(&outsider, &pat_a),
+        ContractError::Unauthorized,
+    );
+}
+
+#[test]
+fn test_initialize_assigns_the_admin_role_once() {
+    let fx = setup();
+    let admin = fx.admin();
+
+    assert_eq!(
+        history(&fx, &admin),
+        vec![
+            &fx.env,
+            entry(
+                Role::Admin,
+                RoleAction::Assigned,
+                &admin,
+                FIXTURE_START_TIME
+            )
+        ]
+    );
+    fx.env.as_contract(&fx.contract_id, || {
+        assert!(rbac::has_permission(
+            &fx.env,
+            &admin,
+            &Permission::InfraAdmin
+        ));
+        assert!(rbac::has_permission(
+            &fx.env,
+            &admin,
+            &Permission::ClinicalAdmin
+        ));
+    });
+}
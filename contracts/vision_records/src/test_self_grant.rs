@@ -0,0 +1,81 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessLevel, ContractError, Role};
+use crate::events::topics;
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::Address;
+
+const DAY: u64 = 86_400;
+
+/// `pat_a` has delegated their Optometrist role, and with it
+/// `ManageAccess`, to `dr_a`.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_provider("dr_b")
+        .with_patient("pat_a")
+        .build();
+    fx.client.delegate_role(
+        &fx.patient("pat_a"),
+        &fx.provider("dr_a"),
+        &Role::Optometrist,
+        &0,
+    );
+    fx
+}
+
+fn has_grant(fx: &TestContract, grantee: &Address) -> bool {
+    fx.client
+        .get_grantee_patients(grantee)
+        .contains(fx.patient("pat_a"))
+}
+
+fn forced_grant_published(fx: &TestContract, grantee: &Address) -> bool {
+    fx.published((topics::GRT_FORCE, fx.patient("pat_a"), grantee.clone()))
+}
+
+#[test]
+fn test_delegate_cannot_grant_itself() {
+    let fx = setup();
+    let (patient, dr_a) = (fx.patient("pat_a"), fx.provider("dr_a"));
+    for level in [AccessLevel::Read, AccessLevel::Full] {
+        assert_err(
+            fx.client
+                .try_grant_access(&dr_a, &patient, &dr_a, &level, &DAY),
+            ContractError::SelfGrantViaDelegation,
+        );
+    }
+    assert!(!has_grant(&fx, &dr_a));
+
+    // The delegation still works for anyone else
+    let dr_b = fx.provider("dr_b");
+    fx.client
+        .grant_access(&dr_a, &patient, &dr_b, &AccessLevel::Read, &DAY);
+    assert!(has_grant(&fx, &dr_b));
+}
+
+#[test]
+fn test_admin_self_grant_is_flagged() {
+    let fx = setup();
+    let (patient, admin) = (fx.patient("pat_a"), fx.admin());
+    fx.client
+        .grant_access(&admin, &patient, &admin, &AccessLevel::Read, &DAY);
+    assert!(forced_grant_published(&fx, &admin));
+    assert!(has_grant(&fx, &admin));
+}
+
+#[test]
+fn test_patient_grants_provider_normally() {
+    let fx = setup();
+    let (patient, dr_a) = (fx.patient("pat_a"), fx.provider("dr_a"));
+    fx.client
+        .grant_access(&patient, &patient, &dr_a, &AccessLevel::Full, &DAY);
+    assert!(has_grant(&fx, &dr_a));
+    assert!(!forced_grant_published(&fx, &dr_a));
+}
@@ -0,0 +1,161 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessLevel, ContractError, RecordType};
+use crate::session::MAX_SESSION_TTL_SECONDS;
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .with_patient("pat_b")
+        .with_record(RecordType::Examination, HASH_B)
+        .build()
+}
+
+fn open_session(fx: &TestContract, scope: AccessLevel, ttl_seconds: u64) -> Address {
+    let session_key = Address::generate(&fx.env);
+    fx.client
+        .create_session(&fx.patient("pat_a"), &session_key, &scope, &ttl_seconds);
+    session_key
+}
+
+#[test]
+fn test_session_reads_with_patient_rights() {
+    let fx = setup();
+    let session_key = open_session(&fx, AccessLevel::Read, 600);
+
+    let session = fx
+        .client
+        .get_session(&fx.patient("pat_a"), &session_key)
+        .unwrap();
+    assert_eq!(session.scope, AccessLevel::Read);
+    assert_eq!(session.expires_at, FIXTURE_START_TIME + 600);
+
+    let record = fx.client.get_record(&session_key, &fx.record(0));
+    assert_eq!(record.patient, fx.patient("pat_a"));
+    let version = fx
+        .client
+        .get_record_version(&session_key, &fx.record(0), &1);
+    assert_eq!(version.data_hash, String::from_str(&fx.env, HASH_A));
+    assert_eq!(
+        fx.client
+            .get_claim_attestations(&session_key, &fx.record(0))
+            .len(),
+        0
+    );
+
+    // The session only stands in for the patient who opened it.
+    assert_err(
+        fx.client.try_get_record(&session_key, &fx.record(1)),
+        ContractError::Unauthorized,
+    );
+}
+
+#[test]
+fn test_session_never_writes_even_with_full_scope() {
+    let fx = setup();
+    let session_key = open_session(&fx, AccessLevel::Full, 600);
+
+    fx.client.get_record(&session_key, &fx.record(0));
+    assert_err(
+        fx.client.try_update_record(
+            &session_key,
+            &fx.record(0),
+            &String::from_str(&fx.env, HASH_B),
+        ),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_grant_access(
+            &session_key,
+            &fx.patient("pat_a"),
+            &Address::generate(&fx.env),
+            &AccessLevel::Read,
+            &3_600,
+        ),
+        ContractError::Unauthorized,
+    );
+}
+
+#[test]
+fn test_create_session_rejects_invalid_input() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let session_key = Address::generate(&fx.env);
+
+    assert_err(
+        fx.client
+            .try_create_session(&patient, &session_key, &AccessLevel::None, &600),
+        ContractError::InvalidInput,
+    );
+    assert_err(
+        fx.client
+            .try_create_session(&patient, &session_key, &AccessLevel::Read, &0),
+        ContractError::InvalidInput,
+    );
+    assert_err(
+        fx.client.try_create_session(
+            &patient,
+            &session_key,
+            &AccessLevel::Read,
+            &(MAX_SESSION_TTL_SECONDS + 1),
+        ),
+        ContractError::InvalidInput,
+    );
+    assert_err(
+        fx.client
+            .try_create_session(&patient, &patient, &AccessLevel::Read, &600),
+        ContractError::InvalidInput,
+    );
+}
+
+#[test]
+fn test_session_expires() {
+    let fx = setup();
+    let session_key = open_session(&fx, AccessLevel::Read, 600);
+
+    fx.advance_time(599);
+    fx.client.get_record(&session_key, &fx.record(0));
+
+    fx.advance_time(1);
+    assert!(fx
+        .client
+        .get_session(&fx.patient("pat_a"), &session_key)
+        .is_none());
+    assert_err(
+        fx.client.try_get_record(&session_key, &fx.record(0)),
+        ContractError::Unauthorized,
+    );
+}
+
+#[test]
+fn test_revoke_session_mid_session() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let session_key = open_session(&fx, AccessLevel::Read, 600);
+
+    fx.client.get_record(&session_key, &fx.record(0));
+    fx.advance_time(60);
+    fx.client.revoke_session(&patient, &session_key);
+
+    assert_err(
+        fx.client.try_get_record(&session_key, &fx.record(0)),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_revoke_session(&patient, &session_key),
+        ContractError::SessionNotFound,
+    );
+}
@@ -0,0 +1,277 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessLevel, ContractError, RecordType};
+use crate::share_code::MAX_LIVE_SHARE_CODES;
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{testutils::Address as _, Address, Bytes, BytesN};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const CODE: &[u8] = b"7F3K-Q9ZD-2MWX";
+const EXPIRES_AT: u64 = FIXTURE_START_TIME + 7 * 86_400;
+
+/// `pat_a` has shared their record with a read code expiring at `EXPIRES_AT`.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_b")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .build();
+    fx.client.create_share_code(
+        &fx.patient("pat_a"),
+        &fx.record(0),
+        &code_hash(&fx, CODE),
+        &AccessLevel::Read,
+        &EXPIRES_AT,
+    );
+    fx
+}
+
+fn preimage(fx: &TestContract, code: &[u8]) -> Bytes {
+    Bytes::from_slice(&fx.env, code)
+}
+
+fn code_hash(fx: &TestContract, code: &[u8]) -> BytesN<32> {
+    fx.env.crypto().sha256(&preimage(fx, code)).to_bytes()
+}
+
+#[test]
+fn test_redeeming_grants_record_access_until_expiry() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    let friend = Address::generate(&fx.env);
+    assert!(fx
+        .client
+        .get_share_code(&record_id, &code_hash(&fx, CODE))
+        .is_some());
+
+    fx.client
+        .redeem_share_code(&friend, &record_id, &preimage(&fx, CODE));
+    assert_eq!(
+        fx.client.check_record_access(&record_id, &friend),
+        AccessLevel::Read
+    );
+    assert_eq!(
+        fx.client.get_share_code(&record_id, &code_hash(&fx, CODE)),
+        None
+    );
+
+    fx.set_time(EXPIRES_AT);
+    assert_eq!(
+        fx.client.check_record_access(&record_id, &friend),
+        AccessLevel::None
+    );
+}
+
+#[test]
+fn test_wrong_preimage_and_replay_rejected() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    let friend = Address::generate(&fx.env);
+
+    assert_err(
+        fx.client
+            .try_redeem_share_code(&friend, &record_id, &preimage(&fx, b"7F3K-Q9ZD-2MWY")),
+        ContractError::ShareCodeNotFound,
+    );
+    // A code only unlocks the record it was made for.
+    assert_err(
+        fx.client
+            .try_redeem_share_code(&friend, &(record_id + 1), &preimage(&fx, CODE)),
+        ContractError::ShareCodeNotFound,
+    );
+    assert_err(
+        fx.client
+            .try_redeem_share_code(&fx.patient("pat_a"), &record_id, &preimage(&fx, CODE)),
+        ContractError::InvalidInput,
+    );
+
+    fx.client
+        .redeem_share_code(&friend, &record_id, &preimage(&fx, CODE));
+    let latecomer = Address::generate(&fx.env);
+    assert_err(
+        fx.client
+            .try_redeem_share_code(&latecomer, &record_id, &preimage(&fx, CODE)),
+        ContractError::ShareCodeNotFound,
+    );
+    assert_eq!(
+        fx.client.check_record_access(&record_id, &latecomer),
+        AccessLevel::None
+    );
+    assert_eq!(
+        fx.client.check_record_access(&record_id, &friend),
+        AccessLevel::Read
+    );
+}
+
+#[test]
+fn test_expired_code_cannot_be_redeemed() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    let friend = Address::generate(&fx.env);
+
+    fx.set_time(EXPIRES_AT);
+    assert_err(
+        fx.client
+            .try_redeem_share_code(&friend, &record_id, &preimage(&fx, CODE)),
+        ContractError::ExpiredAccess,
+    );
+    assert_eq!(
+        fx.client.check_record_access(&record_id, &friend),
+        AccessLevel::None
+    );
+}
+
+#[test]
+fn test_patient_cancels_code() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    let hash = code_hash(&fx, CODE);
+
+    assert_err(
+        fx.client
+            .try_cancel_share_code(&fx.patient("pat_b"), &record_id, &hash),
+        ContractError::Unauthorized,
+    );
+    fx.client
+        .cancel_share_code(&fx.patient("pat_a"), &record_id, &hash);
+    assert_err(
+        fx.client.try_redeem_share_code(
+            &Address::generate(&fx.env),
+            &record_id,
+            &preimage(&fx, CODE),
+        ),
+        ContractError::ShareCodeNotFound,
+    );
+    assert_err(
+        fx.client
+            .try_cancel_share_code(&fx.patient("pat_a"), &record_id, &hash),
+        ContractError::ShareCodeNotFound,
+    );
+}
+
+#[test]
+fn test_create_share_code_validation() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    let patient = fx.patient("pat_a");
+    let other = code_hash(&fx, b"other");
+
+    assert_err(
+        fx.client.try_create_share_code(
+            &fx.patient("pat_b"),
+            &record_id,
+            &other,
+            &AccessLevel::Read,
+            &EXPIRES_AT,
+        ),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_create_share_code(
+            &patient,
+            &record_id,
+            &other,
+            &AccessLevel::None,
+            &EXPIRES_AT,
+        ),
+        ContractError::InvalidInput,
+    );
+    assert_err(
+        fx.client.try_create_share_code(
+            &patient,
+            &record_id,
+            &other,
+            &AccessLevel::Read,
+            &(FIXTURE_START_TIME + 60),
+        ),
+        ContractError::InvalidInput,
+    );
+    assert_err(
+        fx.client.try_create_share_code(
+            &patient,
+            &record_id,
+            &code_hash(&fx, CODE),
+            &AccessLevel::Full,
+            &EXPIRES_AT,
+        ),
+        ContractError::AlreadyExists,
+    );
+    assert_err(
+        fx.client.try_create_share_code(
+            &patient,
+            &(record_id + 1),
+            &other,
+            &AccessLevel::Read,
+            &EXPIRES_AT,
+        ),
+        ContractError::RecordNotFound,
+    );
+}
+
+#[test]
+fn test_code_lifetime_bounded_by_temporary_storage() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    let patient = fx.patient("pat_a");
+
+    assert_err(
+        fx.client.try_create_share_code(
+            &patient,
+            &record_id,
+            &code_hash(&fx, b"a year"),
+            &AccessLevel::Read,
+            &(FIXTURE_START_TIME + 365 * 86_400),
+        ),
+        ContractError::DurationTooLong,
+    );
+    fx.client.create_share_code(
+        &patient,
+        &record_id,
+        &code_hash(&fx, b"five months"),
+        &AccessLevel::Read,
+        &(FIXTURE_START_TIME + 150 * 86_400),
+    );
+}
+
+#[test]
+fn test_live_codes_capped_per_patient() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    let patient = fx.patient("pat_a");
+    let create = |seed: u8, expires_at: u64| {
+        let hash = BytesN::from_array(&fx.env, &[seed; 32]);
+        fx.client
+            .try_create_share_code(&patient, &record_id, &hash, &AccessLevel::Read, &expires_at)
+            .map(|_| hash)
+    };
+    // One code from setup, plus one that lapses early
+    let short_lived = create(0, FIXTURE_START_TIME + 86_400).unwrap();
+    for seed in 1..(MAX_LIVE_SHARE_CODES - 1) as u8 {
+        create(seed, EXPIRES_AT).unwrap();
+    }
+    assert_err(
+        create(100, EXPIRES_AT),
+        ContractError::ShareCodeLimitReached,
+    );
+
+    // Cancelling frees a slot
+    fx.client
+        .cancel_share_code(&patient, &record_id, &code_hash(&fx, CODE));
+    create(100, EXPIRES_AT).unwrap();
+    assert_err(
+        create(101, EXPIRES_AT),
+        ContractError::ShareCodeLimitReached,
+    );
+
+    // So does a code lapsing, without anyone collecting it
+    fx.set_time(FIXTURE_START_TIME + 86_400);
+    assert!(fx.client.get_share_code(&record_id, &short_lived).is_some());
+    create(101, EXPIRES_AT).unwrap();
+}
@@ -0,0 +1,204 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+extern crate std;
+
+use super::{AccessLevel, ConsentType, ContractError};
+use crate::signed_grant::{approval_message, GrantApproval};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{testutils::Address as _, Address, BytesN};
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .build()
+}
+
+fn keypair(fx: &TestContract, seed: u8) -> (SigningKey, BytesN<32>) {
+    let signing_key = SigningKey::from_bytes(&[seed; 32]);
+    let pubkey = BytesN::from_array(&fx.env, &signing_key.verifying_key().to_bytes());
+    (signing_key, pubkey)
+}
+
+fn approval(fx: &TestContract, level: AccessLevel, nonce: u64) -> GrantApproval {
+    GrantApproval {
+        contract: fx.contract_id.clone(),
+        patient: fx.patient("pat_a"),
+        grantee: fx.provider("dr_a"),
+        level,
+        duration_seconds: 3_600,
+        nonce,
+    }
+}
+
+fn sign(fx: &TestContract, key: &SigningKey, approval: &GrantApproval) -> BytesN<64> {
+    let message: std::vec::Vec<u8> = approval_message(&fx.env, approval).iter().collect();
+    BytesN::from_array(&fx.env, &key.sign(&message).to_bytes())
+}
+
+fn submit(
+    fx: &TestContract,
+    approval: &GrantApproval,
+    signature: &BytesN<64>,
+    pubkey: &BytesN<32>,
+) -> Result<(), ()> {
+    fx.client
+        .try_approve_grant_with_signature(
+            &Address::generate(&fx.env),
+            &approval.patient,
+            &approval.grantee,
+            &approval.level,
+            &approval.duration_seconds,
+            &approval.nonce,
+            signature,
+            pubkey,
+        )
+        .map(|_| ())
+        .map_err(|_| ())
+}
+
+#[test]
+fn test_relayed_approval_creates_grant() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let (key, pubkey) = keypair(&fx, 1);
+    fx.client.register_signing_key(&patient, &pubkey);
+    fx.client.grant_consent(
+        &patient,
+        &fx.provider("dr_a"),
+        &ConsentType::Treatment,
+        &3_600,
+    );
+    assert_eq!(fx.client.get_signing_key(&patient), Some(pubkey.clone()));
+    assert_eq!(fx.client.get_signing_nonce(&patient), 0);
+
+    let first = approval(&fx, AccessLevel::Read, 0);
+    submit(&fx, &first, &sign(&fx, &key, &first), &pubkey).unwrap();
+    assert_eq!(
+        fx.client.check_access(&patient, &fx.provider("dr_a")),
+        AccessLevel::Read
+    );
+    assert_eq!(fx.client.get_signing_nonce(&patient), 1);
+
+    let second = approval(&fx, AccessLevel::Write, 1);
+    submit(&fx, &second, &sign(&fx, &key, &second), &pubkey).unwrap();
+    assert_eq!(
+        fx.client.check_access(&patient, &fx.provider("dr_a")),
+        AccessLevel::Write
+    );
+}
+
+#[test]
+fn test_replayed_and_skipped_nonces_rejected() {
+    let fx = setup();
+    let (key, pubkey) = keypair(&fx, 1);
+    fx.client
+        .register_signing_key(&fx.patient("pat_a"), &pubkey);
+
+    let first = approval(&fx, AccessLevel::Read, 0);
+    let signature = sign(&fx, &key, &first);
+    submit(&fx, &first, &signature, &pubkey).unwrap();
+
+    let replay = |approval: &GrantApproval, signature: &BytesN<64>| {
+        fx.client.try_approve_grant_with_signature(
+            &Address::generate(&fx.env),
+            &approval.patient,
+            &approval.grantee,
+            &approval.level,
+            &approval.duration_seconds,
+            &approval.nonce,
+            signature,
+            &pubkey,
+        )
+    };
+    assert_err(replay(&first, &signature), ContractError::NonceAlreadyUsed);
+
+    let skipped = approval(&fx, AccessLevel::Read, 5);
+    assert_err(
+        replay(&skipped, &sign(&fx, &key, &skipped)),
+        ContractError::InvalidInput,
+    );
+    assert_eq!(fx.client.get_signing_nonce(&fx.patient("pat_a")), 1);
+}
+
+#[test]
+fn test_wrong_keys_rejected() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let (key, pubkey) = keypair(&fx, 1);
+    let (other_key, other_pubkey) = keypair(&fx, 2);
+    let first = approval(&fx, AccessLevel::Read, 0);
+
+    // No key registered yet.
+    assert_err(
+        fx.client.try_approve_grant_with_signature(
+            &Address::generate(&fx.env),
+            &patient,
+            &first.grantee,
+            &first.level,
+            &first.duration_seconds,
+            &first.nonce,
+            &sign(&fx, &key, &first),
+            &pubkey,
+        ),
+        ContractError::SigningKeyNotFound,
+    );
+
+    fx.client.register_signing_key(&patient, &pubkey);
+
+    // A valid signature from a key the patient never registered.
+    assert_err(
+        fx.client.try_approve_grant_with_signature(
+            &Address::generate(&fx.env),
+            &patient,
+            &first.grantee,
+            &first.level,
+            &first.duration_seconds,
+            &first.nonce,
+            &sign(&fx, &other_key, &first),
+            &other_pubkey,
+        ),
+        ContractError::Unauthorized,
+    );
+
+    // The registered key paired with someone else's signature.
+    assert!(submit(&fx, &first, &sign(&fx, &other_key, &first), &pubkey).is_err());
+    assert_eq!(fx.client.get_signing_nonce(&patient), 0);
+}
+
+#[test]
+fn test_tampered_fields_rejected() {
+    let fx = setup();
+    let (key, pubkey) = keypair(&fx, 1);
+    fx.client
+        .register_signing_key(&fx.patient("pat_a"), &pubkey);
+    let signed = approval(&fx, AccessLevel::Read, 0);
+    let signature = sign(&fx, &key, &signed);
+
+    let mut tampered = signed.clone();
+    tampered.level = AccessLevel::Full;
+    assert!(submit(&fx, &tampered, &signature, &pubkey).is_err());
+
+    let mut tampered = signed.clone();
+    tampered.grantee = Address::generate(&fx.env);
+    assert!(submit(&fx, &tampered, &signature, &pubkey).is_err());
+
+    let mut tampered = signed.clone();
+    tampered.duration_seconds = 86_400;
+    assert!(submit(&fx, &tampered, &signature, &pubkey).is_err());
+
+    // Signed for another deployment of the contract.
+    let mut elsewhere = signed.clone();
+    elsewhere.contract = Address::generate(&fx.env);
+    assert!(submit(&fx, &signed, &sign(&fx, &key, &elsewhere), &pubkey).is_err());
+
+    assert_eq!(fx.client.get_signing_nonce(&fx.patient("pat_a")), 0);
+    submit(&fx, &signed, &signature, &pubkey).unwrap();
+}
@@ -0,0 +1,115 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{ContractError, RecordType, StalenessPolicy};
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{testutils::Address as _, vec, Address, Vec};
+
+const HASH: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const TWO_YEARS: u64 = 2 * 365 * 86_400;
+
+/// `pat_a` has a prescription and an examination, both written at
+/// `FIXTURE_START_TIME`.
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Prescription, HASH)
+        .with_record(RecordType::Examination, HASH)
+        .build()
+}
+
+fn is_stale(fx: &TestContract, index: usize) -> bool {
+    fx.client
+        .get_record_with_metadata(&fx.patient("pat_a"), &fx.record(index))
+        .is_stale
+}
+
+fn stale_prescriptions(fx: &TestContract) -> Vec<u64> {
+    let patient = fx.patient("pat_a");
+    fx.client
+        .get_stale_records(&patient, &patient, &RecordType::Prescription, &10)
+}
+
+#[test]
+fn test_without_policy_nothing_is_stale() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    fx.advance_time(10 * TWO_YEARS);
+
+    assert!(!is_stale(&fx, 0));
+    assert!(!is_stale(&fx, 1));
+    assert!(stale_prescriptions(&fx).is_empty());
+    let summary = fx.client.get_patient_profile_summary(&patient, &patient);
+    assert!(summary.stale_types.is_empty());
+    assert!(fx.client.get_staleness_policies().is_empty());
+}
+
+#[test]
+fn test_stale_after_exact_boundary() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    fx.client
+        .set_staleness_policy(&fx.admin(), &RecordType::Prescription, &TWO_YEARS);
+    assert_eq!(
+        fx.client.get_staleness_policies(),
+        vec![
+            &fx.env,
+            StalenessPolicy {
+                record_type: RecordType::Prescription,
+                max_age_seconds: TWO_YEARS,
+            }
+        ]
+    );
+
+    // Exactly at the window's end the record is still current
+    fx.set_time(FIXTURE_START_TIME + TWO_YEARS);
+    assert!(!is_stale(&fx, 0));
+    assert!(stale_prescriptions(&fx).is_empty());
+
+    fx.set_time(FIXTURE_START_TIME + TWO_YEARS + 1);
+    assert!(is_stale(&fx, 0));
+    assert!(!is_stale(&fx, 1));
+    assert_eq!(stale_prescriptions(&fx), vec![&fx.env, fx.record(0)]);
+    let summary = fx.client.get_patient_profile_summary(&patient, &patient);
+    assert_eq!(summary.stale_types, vec![&fx.env, RecordType::Prescription]);
+
+    // Removing the policy clears the flag without touching the record
+    fx.client
+        .set_staleness_policy(&fx.admin(), &RecordType::Prescription, &0);
+    assert!(!is_stale(&fx, 0));
+    assert!(fx.client.get_staleness_policies().is_empty());
+}
+
+#[test]
+fn test_staleness_access_rules() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    assert_err(
+        fx.client.try_set_staleness_policy(
+            &fx.provider("dr_a"),
+            &RecordType::Prescription,
+            &TWO_YEARS,
+        ),
+        ContractError::Unauthorized,
+    );
+    let stranger = Address::generate(&fx.env);
+    assert_err(
+        fx.client
+            .try_get_stale_records(&stranger, &patient, &RecordType::Prescription, &10),
+        ContractError::AccessDenied,
+    );
+    assert_err(
+        fx.client
+            .try_get_stale_records(&patient, &patient, &RecordType::Prescription, &0),
+        ContractError::InvalidInput,
+    );
+    // Admins may drive renewal campaigns across patients
+    fx.client
+        .get_stale_records(&fx.admin(), &patient, &RecordType::Prescription, &10);
+}
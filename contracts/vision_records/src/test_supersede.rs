@@ -0,0 +1,134 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{ContractError, RecordType, Role, VisionRecordsContract, VisionRecordsContractClient};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger, vec, Address, Env, String};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+const HASH_C: &str = "QmPZ9gcCEpqKTo6aq61g2nXGUhM4iCL3ewB6LDXZCtioEB";
+
+fn setup() -> (
+    Env,
+    VisionRecordsContractClient<'static>,
+    Address,
+    Address,
+    u64,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register(VisionRecordsContract, ());
+    let client = VisionRecordsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &None);
+
+    let provider = Address::generate(&env);
+    let patient = Address::generate(&env);
+    client.register_user(
+        &admin,
+        &provider,
+        &Role::Optometrist,
+        &String::from_str(&env, "Dr. Provider"),
+    );
+    let record_id = client.add_record(
+        &provider,
+        &patient,
+        &provider,
+        &RecordType::LabResult,
+        &String::from_str(&env, HASH_A),
+    );
+
+    (env, client, provider, patient, record_id)
+}
+
+#[test]
+fn test_chained_supersession() {
+    let (env, client, provider, patient, a) = setup();
+
+    env.ledger().set_timestamp(2_000);
+    let b = client.supersede_record(
+        &provider,
+        &a,
+        &String::from_str(&env, HASH_B),
+        &RecordType::LabResult,
+    );
+    env.ledger().set_timestamp(3_000);
+    let c = client.supersede_record(
+        &provider,
+        &b,
+        &String::from_str(&env, HASH_C),
+        &RecordType::LabResult,
+    );
+
+    assert_eq!(client.get_superseded_by(&a), Some(b));
+    assert_eq!(client.get_superseded_by(&b), Some(c));
+    assert_eq!(client.get_superseded_by(&c), None);
+
+    let new_record = client.get_record(&patient, &c);
+    assert_eq!(new_record.patient, patient);
+    assert_eq!(new_record.provider, provider);
+    assert_eq!(new_record.data_hash, String::from_str(&env, HASH_C));
+
+    // The old record keeps its content and gains a terminal version.
+    assert_eq!(client.get_record_version_count(&a), 2);
+    let terminal = client.get_record_version(&patient, &a, &2);
+    assert_eq!(terminal.superseded_by, Some(b));
+    assert_eq!(terminal.data_hash, String::from_str(&env, HASH_A));
+    assert_eq!(terminal.modified_at, 2_000);
+    assert_eq!(client.get_record(&patient, &a).updated_at, 2_000);
+
+    assert_eq!(client.get_patient_records(&patient), vec![&env, a, b, c]);
+    assert_eq!(
+        client.get_patient_records_filtered(&patient, &false),
+        vec![&env, a, b, c]
+    );
+    assert_eq!(
+        client.get_patient_records_filtered(&patient, &true),
+        vec![&env, c]
+    );
+}
+
+#[test]
+fn test_superseded_record_cannot_be_updated_or_resuperseded() {
+    let (env, client, provider, _patient, a) = setup();
+    client.supersede_record(
+        &provider,
+        &a,
+        &String::from_str(&env, HASH_B),
+        &RecordType::LabResult,
+    );
+
+    let res = client.try_update_record(&provider, &a, &String::from_str(&env, HASH_C));
+    assert_eq!(res, Err(Ok(ContractError::RecordSuperseded)));
+
+    let res = client.try_supersede_record(
+        &provider,
+        &a,
+        &String::from_str(&env, HASH_C),
+        &RecordType::LabResult,
+    );
+    assert_eq!(res, Err(Ok(ContractError::RecordSuperseded)));
+    assert_eq!(client.get_record_version_count(&a), 2);
+}
+
+#[test]
+fn test_supersede_requires_write_permission() {
+    let (env, client, _provider, patient, a) = setup();
+
+    let res = client.try_supersede_record(
+        &patient,
+        &a,
+        &String::from_str(&env, HASH_B),
+        &RecordType::LabResult,
+    );
+    assert_eq!(res, Err(Ok(ContractError::Unauthorized)));
+    assert_eq!(client.get_superseded_by(&a), None);
+    assert_eq!(client.get_patient_records(&patient).len(), 1);
+}
@@ -0,0 +1,100 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{temp_storage, AccessLevel, Role, VisionRecordsContract, VisionRecordsContractClient};
+use soroban_sdk::{symbol_short, testutils::Address as _, testutils::Ledger, Address, Env};
+
+const TTL: u32 = 100;
+
+fn setup() -> (Env, VisionRecordsContractClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VisionRecordsContract, ());
+    let client = VisionRecordsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &None);
+
+    (env, client, admin)
+}
+
+fn advance_ledgers(env: &Env, ledgers: u32) {
+    env.ledger().with_mut(|li| li.sequence_number += ledgers);
+}
+
+#[test]
+fn test_ledgers_for_seconds_rounds_up() {
+    assert_eq!(temp_storage::ledgers_for_seconds(0), 0);
+    assert_eq!(temp_storage::ledgers_for_seconds(1), 1);
+    assert_eq!(
+        temp_storage::ledgers_for_seconds(temp_storage::LEDGER_CLOSE_SECONDS * 3),
+        3
+    );
+    assert_eq!(temp_storage::ledgers_for_seconds(u64::MAX), u32::MAX);
+}
+
+#[test]
+fn test_temp_entry_vanishes_after_ttl_while_persistent_remains() {
+    let (env, client, _admin) = setup();
+    let temp_key = symbol_short!("TMP_TEST");
+    let persistent_key = symbol_short!("PER_TEST");
+
+    env.as_contract(&client.address, || {
+        temp_storage::set_temp(&env, &temp_key, &42u32, TTL);
+        env.storage().persistent().set(&persistent_key, &7u32);
+        env.storage()
+            .persistent()
+            .extend_ttl(&persistent_key, TTL * 10, TTL * 10);
+    });
+
+    advance_ledgers(&env, TTL - 1);
+    env.as_contract(&client.address, || {
+        assert_eq!(temp_storage::get_temp::<_, u32>(&env, &temp_key), Some(42));
+    });
+
+    advance_ledgers(&env, 2);
+    env.as_contract(&client.address, || {
+        assert!(!temp_storage::has_temp(&env, &temp_key));
+        assert_eq!(temp_storage::get_temp::<_, u32>(&env, &temp_key), None);
+        assert_eq!(
+            env.storage().persistent().get::<_, u32>(&persistent_key),
+            Some(7)
+        );
+    });
+}
+
+#[test]
+fn test_remove_temp() {
+    let (env, client, _admin) = setup();
+    let key = symbol_short!("TMP_TEST");
+
+    env.as_contract(&client.address, || {
+        temp_storage::set_temp(&env, &key, &1u32, TTL);
+        assert!(temp_storage::has_temp(&env, &key));
+        temp_storage::remove_temp(&env, &key);
+        assert!(!temp_storage::has_temp(&env, &key));
+    });
+}
+
+#[test]
+fn test_pending_grant_lives_in_temporary_storage() {
+    let (env, client, _admin) = setup();
+    let patient = Address::generate(&env);
+    let delegate = Address::generate(&env);
+    let grantee = Address::generate(&env);
+    client.delegate_role(&patient, &delegate, &Role::Optometrist, &0);
+
+    client.set_require_patient_approval(&patient, &true);
+    client.grant_access(&delegate, &patient, &grantee, &AccessLevel::Read, &86400);
+
+    env.as_contract(&client.address, || {
+        let key = super::approval::pending_grant_key(&patient, &grantee);
+        assert!(temp_storage::has_temp(&env, &key));
+        assert!(!env.storage().persistent().has(&key));
+    });
+}
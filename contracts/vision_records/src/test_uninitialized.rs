@@ -0,0 +1,753 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+//! Every endpoint that returns a `Result` must fail with `NotInitialized`
+//! on a fresh deployment. Add new endpoints here when they are added to
+//! the contract.
+
+use core::fmt::Debug;
+
+use super::{
+    AccessLevel, AccessTemplateEntry, BatchGrantInput, BatchRecordInput, ConsentType,
+    ContractError, DeactivationCascade, DigestScope, DisputeResolution, GrantPurpose,
+    HeartbeatConfig, IntraocularPressure, InvariantScope, JobParams, LegacyReadMode, LensType,
+    OptFundusPhotography, OptRetinalImaging, OptVisualField, OptionalContactLensData,
+    PendingCategory, Permission, PrescriptionData, RecordOrder, RecordPriority, RecordType, Role,
+    SlitLampFindings, SummaryVisibility, VisualAcuity,
+};
+use crate::circuit_breaker::PauseScope;
+use crate::events::admin_ops;
+use crate::examination::{OptPhysicalMeasurement, PhysicalMeasurement};
+use crate::hash_alg;
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Bytes, BytesN, String, Vec};
+use teye_common::AdminTier;
+
+fn uninitialized<T: Debug, E: Debug>(result: Result<T, Result<ContractError, E>>) {
+    assert_err(result, ContractError::NotInitialized);
+}
+
+fn text(fx: &TestContract, value: &str) -> String {
+    String::from_str(&fx.env, value)
+}
+
+fn prescription_data(fx: &TestContract) -> PrescriptionData {
+    PrescriptionData {
+        sphere: text(fx, "-1.00"),
+        cylinder: text(fx, "0.00"),
+        axis: text(fx, "0"),
+        add: text(fx, "0.00"),
+        pd: text(fx, "62"),
+    }
+}
+
+#[test]
+fn test_admin_and_config_endpoints_require_initialization() {
+    let fx = Fixture::new().build();
+    let (a, b) = (Address::generate(&fx.env), Address::generate(&fx.env));
+
+    uninitialized(fx.client.try_get_admin());
+    uninitialized(fx.client.try_propose_admin(&a, &b));
+    uninitialized(fx.client.try_accept_admin(&a));
+    uninitialized(fx.client.try_cancel_admin_transfer(&a));
+    uninitialized(
+        fx.client
+            .try_configure_multisig(&a, &Vec::from_array(&fx.env, [a.clone()]), &1),
+    );
+    uninitialized(fx.client.try_propose_admin_action(
+        &a,
+        &symbol_short!("ACTION"),
+        &BytesN::from_array(&fx.env, &[0; 32]),
+    ));
+    uninitialized(fx.client.try_approve_admin_action(&a, &1));
+    uninitialized(fx.client.try_set_rate_limit_config(&a, &10, &60, &0));
+    uninitialized(
+        fx.client
+            .try_set_encryption_key(&a, &text(&fx, "v1"), &text(&fx, "key"), &0),
+    );
+    uninitialized(fx.client.try_set_whitelist_enabled(&a, &true));
+    uninitialized(fx.client.try_add_to_whitelist(&a, &b));
+    uninitialized(fx.client.try_remove_from_whitelist(&a, &b));
+    uninitialized(fx.client.try_set_patient_self_registration(&a, &true));
+    uninitialized(fx.client.try_set_role_visibility(
+        &a,
+        &Role::Staff,
+        &RecordType::Examination,
+        &false,
+    ));
+    uninitialized(
+        fx.client
+            .try_set_record_type_policy(&a, &RecordType::Surgery, &None),
+    );
+    uninitialized(
+        fx.client
+            .try_set_staleness_policy(&a, &RecordType::Prescription, &1),
+    );
+    uninitialized(fx.client.try_set_residency_enforcement(&a, &true));
+    uninitialized(
+        fx.client
+            .try_set_patient_residency(&a, &b, &symbol_short!("EU")),
+    );
+    uninitialized(fx.client.try_set_provider_jurisdictions(
+        &a,
+        &b,
+        &Vec::from_array(&fx.env, [symbol_short!("EU")]),
+    ));
+    uninitialized(fx.client.try_register_observer(
+        &a,
+        &b,
+        &Vec::from_array(&fx.env, [symbol_short!("REC_ADD")]),
+    ));
+    uninitialized(fx.client.try_unregister_observer(&a, &b));
+    uninitialized(fx.client.try_set_observers_enabled(&a, &true));
+    uninitialized(
+        fx.client
+            .try_set_legacy_read_mode(&a, &LegacyReadMode::Disabled),
+    );
+    uninitialized(
+        fx.client
+            .try_create_organization(&a, &symbol_short!("ORG"), &b),
+    );
+    uninitialized(
+        fx.client
+            .try_create_unit(&a, &symbol_short!("ORG"), &symbol_short!("UNIT")),
+    );
+    uninitialized(fx.client.try_add_org_member(&a, &symbol_short!("ORG"), &b));
+    uninitialized(
+        fx.client
+            .try_remove_org_member(&a, &symbol_short!("ORG"), &b),
+    );
+    uninitialized(fx.client.try_add_member_to_unit(
+        &a,
+        &symbol_short!("ORG"),
+        &symbol_short!("UNIT"),
+        &b,
+    ));
+    uninitialized(fx.client.try_remove_member_from_unit(
+        &a,
+        &symbol_short!("ORG"),
+        &symbol_short!("UNIT"),
+        &b,
+    ));
+    uninitialized(fx.client.try_set_self_registration_daily_cap(&a, &10));
+    uninitialized(
+        fx.client
+            .try_set_auto_grant_on_registration(&a, &Some((AccessLevel::Read, 3_600))),
+    );
+    uninitialized(fx.client.try_register_hash_algorithm(&a, &hash_alg::SHA256));
+    uninitialized(fx.client.try_set_access_offer_window(&a, &3_600));
+    uninitialized(fx.client.try_set_priority_auto_critical(&a, &true));
+    uninitialized(fx.client.try_set_default_patient_quota(&a, &10));
+    uninitialized(fx.client.try_set_patient_quota(&a, &b, &10));
+    uninitialized(fx.client.try_flag_breach(
+        &a,
+        &Vec::from_array(&fx.env, [b.clone()]),
+        &text(&fx, "incident"),
+    ));
+    uninitialized(fx.client.try_get_breach_status(&a, &b));
+    uninitialized(fx.client.try_get_storage_footprint(&a, &b));
+    uninitialized(fx.client.try_get_record_debug(&a, &1));
+    uninitialized(fx.client.try_clear_breach(&a, &b));
+    uninitialized(fx.client.try_set_expiry_grace(&a, &30));
+    uninitialized(fx.client.try_set_max_grant_duration(&a, &3_600));
+    uninitialized(fx.client.try_set_revocation_grace(&a, &3_600));
+    uninitialized(fx.client.try_pause_contract(&a, &PauseScope::Global));
+    uninitialized(fx.client.try_resume_contract(&a, &PauseScope::Global));
+    uninitialized(
+        fx.client
+            .try_promote_admin(&a, &b, &AdminTier::OperatorAdmin),
+    );
+    uninitialized(fx.client.try_demote_admin(&a, &b));
+}
+
+#[test]
+fn test_user_endpoints_require_initialization() {
+    let fx = Fixture::new().build();
+    let (a, b) = (Address::generate(&fx.env), Address::generate(&fx.env));
+    let group = text(&fx, "billing");
+
+    uninitialized(
+        fx.client
+            .try_register_user(&a, &b, &Role::Optometrist, &text(&fx, "Dr. B")),
+    );
+    uninitialized(fx.client.try_self_register_patient(&a, &text(&fx, "Pat")));
+    uninitialized(fx.client.try_get_user(&a));
+    uninitialized(fx.client.try_freeze_user(&a, &b));
+    uninitialized(fx.client.try_unfreeze_user(&a, &b));
+    uninitialized(fx.client.try_freeze_my_access(&a, &false));
+    uninitialized(fx.client.try_unfreeze_my_access(&a));
+    uninitialized(
+        fx.client
+            .try_grant_custom_permission(&a, &b, &Permission::ReadAnyRecord),
+    );
+    uninitialized(
+        fx.client
+            .try_revoke_custom_permission(&a, &b, &Permission::ReadAnyRecord),
+    );
+    uninitialized(fx.client.try_delegate_role(&a, &b, &Role::Optometrist, &0));
+    uninitialized(fx.client.try_delegate_permissions(
+        &a,
+        &b,
+        &Vec::from_array(&fx.env, [Permission::AppendRecord]),
+        &0,
+    ));
+    uninitialized(fx.client.try_revoke_delegation(&a, &a, &b));
+    uninitialized(fx.client.try_set_delegation_cooldown(&a, &3_600));
+    uninitialized(
+        fx.client
+            .try_clear_delegation_cooldown(&a, &a, &b, &Role::Optometrist),
+    );
+    uninitialized(fx.client.try_create_acl_group(
+        &a,
+        &group,
+        &Vec::from_array(&fx.env, [Permission::ReadAnyRecord]),
+    ));
+    uninitialized(fx.client.try_add_user_to_group(&a, &b, &group));
+    uninitialized(fx.client.try_remove_user_from_group(&a, &b, &group));
+}
+
+#[test]
+fn test_record_endpoints_require_initialization() {
+    let fx = Fixture::new().build();
+    let (a, b) = (Address::generate(&fx.env), Address::generate(&fx.env));
+    let hash = text(&fx, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG");
+
+    uninitialized(
+        fx.client
+            .try_add_record(&a, &b, &a, &RecordType::Examination, &hash),
+    );
+    uninitialized(
+        fx.client
+            .try_add_record_v2(&a, &b, &a, &RecordType::Examination, &hash),
+    );
+    uninitialized(
+        fx.client
+            .try_begin_record(&a, &b, &a, &RecordType::Examination),
+    );
+    uninitialized(fx.client.try_commit_record(&a, &1, &hash));
+    uninitialized(fx.client.try_abandon_record(&a, &1));
+    uninitialized(fx.client.try_add_record_tagged(
+        &a,
+        &b,
+        &a,
+        &RecordType::Examination,
+        &hash,
+        &hash_alg::SHA256,
+    ));
+    uninitialized(fx.client.try_add_records(
+        &a,
+        &Vec::from_array(
+            &fx.env,
+            [BatchRecordInput {
+                patient: b.clone(),
+                record_type: RecordType::Examination,
+                data_hash: hash.clone(),
+            }],
+        ),
+    ));
+    uninitialized(fx.client.try_add_record_backdated(
+        &a,
+        &b,
+        &a,
+        &RecordType::Examination,
+        &hash,
+        &1,
+    ));
+    uninitialized(fx.client.try_add_record_with_coauthors(
+        &a,
+        &b,
+        &a,
+        &Vec::new(&fx.env),
+        &RecordType::Examination,
+        &hash,
+    ));
+    uninitialized(fx.client.try_add_record_with_ref(
+        &a,
+        &b,
+        &a,
+        &RecordType::Examination,
+        &hash,
+        &text(&fx, "DiagnosticReport/1"),
+    ));
+    uninitialized(
+        fx.client
+            .try_set_external_ref(&a, &1, &text(&fx, "DiagnosticReport/1")),
+    );
+    uninitialized(
+        fx.client
+            .try_get_record_by_external_ref(&a, &text(&fx, "DiagnosticReport/1")),
+    );
+    uninitialized(fx.client.try_define_record_preset(
+        &a,
+        &symbol_short!("exam"),
+        &RecordType::Examination,
+        &false,
+        &false,
+        &RecordPriority::Standard,
+    ));
+    uninitialized(fx.client.try_add_record_with_preset(
+        &a,
+        &b,
+        &a,
+        &symbol_short!("exam"),
+        &hash,
+        &Vec::new(&fx.env),
+        &None,
+    ));
+    uninitialized(fx.client.try_add_patient_note(&a, &hash));
+    uninitialized(fx.client.try_add_record_provisional(
+        &a,
+        &BytesN::from_array(&fx.env, &[0; 32]),
+        &RecordType::Examination,
+        &hash,
+    ));
+    uninitialized(fx.client.try_claim_provisional_records(
+        &b,
+        &BytesN::from_array(&fx.env, &[0; 32]),
+        &a,
+    ));
+    uninitialized(fx.client.try_get_record(&a, &1));
+    uninitialized(
+        fx.client
+            .try_read_record_as_admin(&a, &1, &String::from_str(&fx.env, "r")),
+    );
+    uninitialized(
+        fx.client
+            .try_get_records(&a, &Vec::from_array(&fx.env, [1])),
+    );
+    uninitialized(fx.client.try_get_record_count());
+    uninitialized(fx.client.try_get_state_digest(&DigestScope::Global));
+    uninitialized(fx.client.try_update_record(&a, &1, &hash));
+    uninitialized(fx.client.try_update_record_v2(&a, &1, &hash));
+    uninitialized(
+        fx.client
+            .try_update_record_tagged(&a, &1, &hash, &hash_alg::SHA256),
+    );
+    uninitialized(
+        fx.client
+            .try_update_records_atomic(&a, &Vec::from_array(&fx.env, [(1, hash.clone())])),
+    );
+    uninitialized(fx.client.try_get_record_version(&a, &1, &1));
+    uninitialized(fx.client.try_open_write_session(&a, &1, &600));
+    uninitialized(fx.client.try_update_record_in_session(&a, &1, &hash));
+    uninitialized(fx.client.try_close_write_session(&a, &1));
+    uninitialized(
+        fx.client
+            .try_redact_version(&a, &1, &1, &BytesN::from_array(&fx.env, &[0; 32])),
+    );
+    uninitialized(fx.client.try_get_redacted_original(&a, &1, &1));
+    uninitialized(
+        fx.client
+            .try_place_legal_hold(&a, &1, &BytesN::from_array(&fx.env, &[0; 32])),
+    );
+    uninitialized(fx.client.try_release_legal_hold(&a, &1));
+    uninitialized(fx.client.try_get_legal_holds(&a));
+    uninitialized(fx.client.try_get_record_summary(&a, &1));
+    uninitialized(fx.client.try_get_record_with_metadata(&a, &1));
+    uninitialized(fx.client.try_get_patient_records_meta(&a, &a, &0, &1));
+    uninitialized(
+        fx.client
+            .try_set_patient_label(&a, &1, &String::from_str(&fx.env, "x")),
+    );
+    uninitialized(fx.client.try_remove_patient_label(&a, &1));
+    uninitialized(
+        fx.client
+            .try_pin_version(&a, &1, &1, &symbol_short!("claim")),
+    );
+    uninitialized(fx.client.try_unpin_version(&a, &1, &symbol_short!("claim")));
+    uninitialized(fx.client.try_set_record_embargo(&a, &1, &86_400));
+    uninitialized(fx.client.try_clear_embargo(&a, &1));
+    uninitialized(fx.client.try_get_record_embargo(&a, &1));
+    uninitialized(fx.client.try_flag_record(&a, &1, &hash));
+    uninitialized(fx.client.try_respond_to_dispute(&a, &1, &hash));
+    uninitialized(
+        fx.client
+            .try_resolve_dispute(&a, &1, &DisputeResolution::Upheld),
+    );
+    uninitialized(fx.client.try_get_dispute(&a, &1));
+    uninitialized(fx.client.try_get_record_at(&a, &1, &0));
+    uninitialized(fx.client.try_get_record_history_page(&a, &1, &None, &10));
+    uninitialized(fx.client.try_get_record_history_bounded(&a, &1, &0));
+    uninitialized(fx.client.try_get_patient_grants_bounded(&a, &b, &0));
+    uninitialized(fx.client.try_get_audit_trail_bounded(&a, &b, &0));
+    uninitialized(fx.client.try_get_patient_records(&a));
+    uninitialized(fx.client.try_get_patient_records_filtered(&a, &true));
+    uninitialized(
+        fx.client
+            .try_get_stale_records(&a, &b, &RecordType::Prescription, &10),
+    );
+    uninitialized(fx.client.try_get_patient_records_as(&a, &b));
+    uninitialized(fx.client.try_get_patient_records_page(&a, &None, &10));
+    uninitialized(fx.client.try_compare_record_versions(&a, &1, &1, &2));
+    uninitialized(fx.client.try_attach_version_diff(
+        &a,
+        &1,
+        &1,
+        &2,
+        &String::from_str(&fx.env, "d"),
+    ));
+    uninitialized(fx.client.try_get_version_diff(&a, &1, &1, &2));
+    uninitialized(
+        fx.client
+            .try_verify_record_hash(&a, &1, &hash, &hash_alg::SHA256),
+    );
+    uninitialized(
+        fx.client
+            .try_supersede_record(&a, &1, &hash, &RecordType::Examination),
+    );
+    uninitialized(fx.client.try_pin_record(&b, &1));
+    uninitialized(fx.client.try_unpin_record(&b, &1));
+    uninitialized(fx.client.try_get_patient_records_ordered(
+        &a,
+        &b,
+        &RecordOrder::CreatedDesc,
+        &0,
+        &10,
+    ));
+    uninitialized(fx.client.try_get_activity_feed(&a, &b, &0, &10));
+    uninitialized(fx.client.try_get_compliance_page(&a, &0));
+    uninitialized(
+        fx.client
+            .try_set_record_priority(&a, &1, &RecordPriority::Critical),
+    );
+    uninitialized(fx.client.try_get_record_priority(&1));
+    uninitialized(
+        fx.client
+            .try_get_records_by_priority(&a, &RecordPriority::Critical, &0, &10),
+    );
+    uninitialized(fx.client.try_create_claim_attestation(&a, &1, &hash, &100));
+    uninitialized(fx.client.try_get_claim_attestations(&a, &1));
+    uninitialized(
+        fx.client
+            .try_void_claim_attestation(&a, &1, &hash, &text(&fx, "duplicate")),
+    );
+    uninitialized(fx.client.try_get_provider_stats(&a, &b));
+    uninitialized(fx.client.try_get_provider_monthly_created(&a, &b, &0));
+}
+
+#[test]
+fn test_clinical_endpoints_require_initialization() {
+    let fx = Fixture::new().build();
+    let (a, b) = (Address::generate(&fx.env), Address::generate(&fx.env));
+    let hash = text(&fx, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG");
+
+    uninitialized(fx.client.try_add_eye_examination(
+        &a,
+        &1,
+        &VisualAcuity {
+            uncorrected: PhysicalMeasurement {
+                left_eye: text(&fx, "20/20"),
+                right_eye: text(&fx, "20/20"),
+            },
+            corrected: OptPhysicalMeasurement::None,
+        },
+        &IntraocularPressure {
+            left_eye: 15,
+            right_eye: 15,
+            method: text(&fx, "tonometry"),
+            timestamp: 0,
+        },
+        &SlitLampFindings {
+            cornea: text(&fx, "clear"),
+            anterior_chamber: text(&fx, "deep"),
+            iris: text(&fx, "normal"),
+            lens: text(&fx, "clear"),
+        },
+        &OptVisualField::None,
+        &OptRetinalImaging::None,
+        &OptFundusPhotography::None,
+        &text(&fx, "routine"),
+    ));
+    uninitialized(fx.client.try_get_eye_examination(&a, &1));
+    uninitialized(fx.client.try_add_prescription(
+        &b,
+        &a,
+        &LensType::Glasses,
+        &prescription_data(&fx),
+        &prescription_data(&fx),
+        &OptionalContactLensData::None,
+        &3_600,
+        &hash,
+    ));
+    uninitialized(fx.client.try_get_prescription(&1));
+    uninitialized(fx.client.try_verify_prescription(&1, &a));
+    uninitialized(fx.client.try_create_profile(&a, &b, &hash, &hash, &hash));
+    uninitialized(
+        fx.client
+            .try_update_demographics(&a, &b, &hash, &hash, &hash),
+    );
+    uninitialized(fx.client.try_update_emergency_contact(&a, &b, &None));
+    uninitialized(fx.client.try_update_insurance(&a, &b, &None));
+    uninitialized(fx.client.try_add_medical_history_reference(&a, &b, &hash));
+    uninitialized(fx.client.try_get_profile(&b));
+    uninitialized(
+        fx.client
+            .try_set_summary_visibility(&b, &SummaryVisibility::Public),
+    );
+    uninitialized(fx.client.try_get_patient_profile_summary(&a, &b));
+}
+
+#[test]
+fn test_access_endpoints_require_initialization() {
+    let fx = Fixture::new().build();
+    let (a, b) = (Address::generate(&fx.env), Address::generate(&fx.env));
+    let template = symbol_short!("family");
+
+    uninitialized(
+        fx.client
+            .try_grant_access(&a, &a, &b, &AccessLevel::Read, &3_600),
+    );
+    uninitialized(
+        fx.client
+            .try_grant_access_v2(&a, &a, &b, &AccessLevel::Read, &3_600),
+    );
+    uninitialized(fx.client.try_grant_append_access(&a, &b, &3_600));
+    uninitialized(fx.client.try_grant_access_with_history(
+        &a,
+        &b,
+        &AccessLevel::Read,
+        &3_600,
+        &false,
+    ));
+    uninitialized(fx.client.try_grant_access_with_override(
+        &a,
+        &a,
+        &b,
+        &AccessLevel::Read,
+        &3_600,
+        &true,
+    ));
+    let pubkey = BytesN::from_array(&fx.env, &[1; 32]);
+    uninitialized(fx.client.try_register_signing_key(&a, &pubkey));
+    uninitialized(fx.client.try_approve_grant_with_signature(
+        &b,
+        &a,
+        &b,
+        &AccessLevel::Read,
+        &3_600,
+        &0,
+        &BytesN::from_array(&fx.env, &[0; 64]),
+        &pubkey,
+    ));
+    uninitialized(fx.client.try_grant_access_with_purpose(
+        &a,
+        &a,
+        &b,
+        &AccessLevel::Read,
+        &GrantPurpose::Payment,
+        &3_600,
+    ));
+    uninitialized(fx.client.try_grant_access_batch(
+        &a,
+        &Vec::from_array(
+            &fx.env,
+            [BatchGrantInput {
+                grantee: b.clone(),
+                level: AccessLevel::Read,
+                duration_seconds: 3_600,
+            }],
+        ),
+    ));
+    uninitialized(fx.client.try_set_require_patient_approval(&a, &true));
+    uninitialized(fx.client.try_approve_grant(&a, &b));
+    uninitialized(fx.client.try_grant_access_pending_acceptance(
+        &a,
+        &b,
+        &AccessLevel::Read,
+        &3_600,
+    ));
+    uninitialized(fx.client.try_accept_access(&b, &a));
+    uninitialized(fx.client.try_decline_access(&b, &a));
+    uninitialized(
+        fx.client
+            .try_create_session(&a, &b, &AccessLevel::Read, &600),
+    );
+    uninitialized(fx.client.try_revoke_session(&a, &b));
+    uninitialized(fx.client.try_create_access_template(
+        &a,
+        &template,
+        &Vec::from_array(
+            &fx.env,
+            [AccessTemplateEntry {
+                level: AccessLevel::Read,
+                duration_seconds: 3_600,
+            }],
+        ),
+    ));
+    uninitialized(fx.client.try_apply_access_template(
+        &a,
+        &template,
+        &Vec::from_array(&fx.env, [b.clone()]),
+    ));
+    uninitialized(fx.client.try_delete_access_template(&a, &template));
+    uninitialized(fx.client.try_set_provider_access_template(
+        &a,
+        &AccessLevel::Read,
+        &3_600,
+        &GrantPurpose::Treatment,
+    ));
+    uninitialized(fx.client.try_remove_provider_access_template(&a));
+    uninitialized(fx.client.try_accept_provider_template(&a, &b));
+    uninitialized(fx.client.try_get_grant_stats(&a, &a));
+    uninitialized(
+        fx.client
+            .try_grant_record_access(&a, &b, &1, &AccessLevel::Read, &3_600),
+    );
+    uninitialized(fx.client.try_revoke_record_access(&a, &b, &1));
+    uninitialized(
+        fx.client
+            .try_set_record_access_override(&a, &b, &1, &86_400),
+    );
+    uninitialized(fx.client.try_clear_record_access_override(&a, &b, &1));
+    uninitialized(fx.client.try_get_effective_access(&a, &a, &b, &1));
+    let code_hash = BytesN::from_array(&fx.env, &[7; 32]);
+    uninitialized(
+        fx.client
+            .try_create_share_code(&a, &1, &code_hash, &AccessLevel::Read, &86_400),
+    );
+    uninitialized(
+        fx.client
+            .try_redeem_share_code(&b, &1, &Bytes::from_slice(&fx.env, b"code")),
+    );
+    uninitialized(fx.client.try_cancel_share_code(&a, &1, &code_hash));
+    uninitialized(fx.client.try_share_with_user(
+        &a,
+        &b,
+        &Vec::from_array(&fx.env, [1]),
+        &AccessLevel::Read,
+        &86_400,
+    ));
+    uninitialized(fx.client.try_get_shares_given(&a));
+    uninitialized(fx.client.try_get_shares_received(&b));
+    uninitialized(
+        fx.client
+            .try_grant_consent(&a, &b, &ConsentType::Treatment, &3_600),
+    );
+    uninitialized(fx.client.try_revoke_consent(&a, &b));
+    uninitialized(fx.client.try_get_consent_proof(&a, &a, &b));
+    uninitialized(fx.client.try_compact_grant_indexes(&a, &b));
+    uninitialized(
+        fx.client
+            .try_check_invariants(&a, &InvariantScope::Patient(b.clone())),
+    );
+    uninitialized(fx.client.try_revoke_access(&a, &b));
+    uninitialized(fx.client.try_revoke_access_immediate(&a, &b));
+    uninitialized(fx.client.try_revoke_created_grant(&a, &b, &a));
+    uninitialized(fx.client.try_revoke_access_with_note(
+        &a,
+        &a,
+        &b,
+        &text(&fx, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"),
+    ));
+    uninitialized(fx.client.try_get_handoff_notes(&b));
+    uninitialized(fx.client.try_grant_org_access(
+        &a,
+        &symbol_short!("ORG"),
+        &None,
+        &AccessLevel::Read,
+        &3_600,
+    ));
+    uninitialized(
+        fx.client
+            .try_revoke_org_access(&a, &symbol_short!("ORG"), &None),
+    );
+    uninitialized(fx.client.try_cancel_revocation(&a, &b));
+    uninitialized(
+        fx.client
+            .try_gc_pending(&a, &PendingCategory::AccessOffer, &0, &10),
+    );
+    uninitialized(fx.client.try_start_job(
+        &a,
+        &admin_ops::PENDING_GC,
+        &JobParams::PendingGc(PendingCategory::AccessOffer),
+    ));
+    uninitialized(fx.client.try_run_job(&a, &1, &10));
+    uninitialized(fx.client.try_cancel_job(&a, &1));
+    uninitialized(fx.client.try_mark_contract_grantee(&a, &b));
+    uninitialized(fx.client.try_unmark_contract_grantee(&a, &b));
+    uninitialized(fx.client.try_set_contract_grant_max_duration(&a, &86_400));
+    uninitialized(fx.client.try_set_instance_usage_threshold(&a, &1));
+    uninitialized(fx.client.try_revoke_all_contract_grants(&a));
+    uninitialized(fx.client.try_purge_expired_grants(&a, &a));
+    uninitialized(fx.client.try_set_expiry_beacons(&a, &true));
+    uninitialized(fx.client.try_set_period_stats_enabled(&a, &false));
+    uninitialized(
+        fx.client
+            .try_get_period_counts(&RecordType::Diagnosis, &202401, &202412),
+    );
+    uninitialized(fx.client.try_set_counter_shards(&a, &4));
+    uninitialized(
+        fx.client
+            .try_transfer_admin_permission(&a, &b, &Permission::InfraAdmin),
+    );
+    uninitialized(fx.client.try_set_legacy_system_admin(&a, &false));
+    uninitialized(
+        fx.client
+            .try_upgrade(&a, &BytesN::from_array(&fx.env, &[0; 32])),
+    );
+    uninitialized(fx.client.try_emit_expiry_beacons(&a, &0));
+    uninitialized(fx.client.try_heartbeat(&a));
+    uninitialized(fx.client.try_set_heartbeat_config(
+        &a,
+        &HeartbeatConfig {
+            stale_threshold: 3_600,
+            lock_grants_when_stale: false,
+        },
+    ));
+    uninitialized(fx.client.try_revoke_all_access_for_grantee(&a, &b, &10));
+    uninitialized(fx.client.try_migrate_grantee(
+        &a,
+        &b,
+        &10,
+        &BytesN::from_array(&fx.env, &[0; 32]),
+    ));
+    uninitialized(fx.client.try_initiate_recovery(
+        &a,
+        &b,
+        &a,
+        &BytesN::from_array(&fx.env, &[0; 32]),
+    ));
+    uninitialized(fx.client.try_cancel_recovery(&b));
+    uninitialized(fx.client.try_finalize_recovery(
+        &a,
+        &b,
+        &Bytes::new(&fx.env),
+        &BytesN::from_array(&fx.env, &[0; 32]),
+        &10,
+    ));
+    uninitialized(
+        fx.client
+            .try_deactivate_user(&a, &b, &DeactivationCascade::RevokeAll, &10),
+    );
+    uninitialized(fx.client.try_reactivate_user(&a, &b));
+    uninitialized(fx.client.try_close_account(&a, &b, &10));
+    uninitialized(fx.client.try_get_cleanup_progress(&a, &b));
+}
+
+#[test]
+fn test_read_only_views_report_empty_state() {
+    let fx = Fixture::new().build();
+    let (a, b) = (Address::generate(&fx.env), Address::generate(&fx.env));
+
+    // Infallible views have nothing to read yet and answer with defaults.
+    assert!(!fx.client.is_initialized());
+    assert_eq!(fx.client.check_access(&a, &b), AccessLevel::None);
+    assert_eq!(fx.client.get_legacy_read_mode(), LegacyReadMode::Open);
+    assert!(fx.client.get_pending_admin().is_none());
+    assert!(!fx.client.profile_exists(&a));
+}
+
+#[test]
+fn test_endpoints_work_once_initialized() {
+    let fx = Fixture::new().build();
+    let admin = Address::generate(&fx.env);
+
+    uninitialized(fx.client.try_get_record_count());
+    fx.client.initialize(&admin, &None);
+    assert_eq!(fx.client.get_record_count(), 0);
+    assert_eq!(fx.client.get_admin(), admin);
+}
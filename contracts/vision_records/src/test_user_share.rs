@@ -0,0 +1,161 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{
+    events::{topics, UserShareEvent},
+    AccessLevel, ContractError, DeactivationCascade, RecordType, Role,
+};
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use soroban_sdk::{testutils::Address as _, vec, Address, String, TryFromVal, Vec};
+
+const HASH: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const DAY: u64 = 86_400;
+
+/// `pat_a` (the parent) owns two records; `pat_b` (their adult child) is
+/// also a patient.
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_b")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH)
+        .with_record(RecordType::Prescription, HASH)
+        .build()
+}
+
+fn records(fx: &TestContract) -> Vec<u64> {
+    vec![&fx.env, fx.record(0), fx.record(1)]
+}
+
+fn share(fx: &TestContract, grantee: &Address, record_ids: &Vec<u64>) {
+    fx.client.share_with_user(
+        &fx.patient("pat_a"),
+        grantee,
+        record_ids,
+        &AccessLevel::Read,
+        &DAY,
+    );
+}
+
+#[test]
+fn test_share_with_family_member() {
+    let fx = setup();
+    let (pat_a, pat_b) = (fx.patient("pat_a"), fx.patient("pat_b"));
+    share(&fx, &pat_b, &records(&fx));
+
+    // The event names both users
+    let data = fx
+        .event_data((topics::USR_SHR, pat_a.clone(), pat_b.clone()))
+        .unwrap();
+    let event = UserShareEvent::try_from_val(&fx.env, &data).unwrap();
+    assert_eq!(event.patient_name, Some(String::from_str(&fx.env, "pat_a")));
+    assert_eq!(event.grantee_name, String::from_str(&fx.env, "pat_b"));
+    assert_eq!(event.record_ids, records(&fx));
+
+    assert_eq!(
+        fx.client.check_record_access(&fx.record(0), &pat_b),
+        AccessLevel::Read
+    );
+    assert_eq!(
+        fx.client.check_record_access(&fx.record(1), &pat_b),
+        AccessLevel::Read
+    );
+
+    let given = fx.client.get_shares_given(&pat_a);
+    assert_eq!(given.len(), 1);
+    let entry = given.get(0).unwrap();
+    assert_eq!(entry.grantee, pat_b);
+    assert_eq!(entry.record_ids, records(&fx));
+    assert_eq!(entry.expires_at, FIXTURE_START_TIME + DAY);
+    assert_eq!(fx.client.get_shares_received(&pat_b), given);
+}
+
+#[test]
+fn test_grantee_registration_states() {
+    let fx = setup();
+    let (admin, pat_a, pat_b) = (fx.admin(), fx.patient("pat_a"), fx.patient("pat_b"));
+    let record_ids = records(&fx);
+    let share_to = |grantee: &Address| {
+        fx.client
+            .try_share_with_user(&pat_a, grantee, &record_ids, &AccessLevel::Read, &DAY)
+    };
+
+    let stranger = Address::generate(&fx.env);
+    assert_err(share_to(&stranger), ContractError::UserNotFound);
+    assert_err(share_to(&pat_a), ContractError::InvalidInput);
+
+    // Any role may receive a share once registered
+    fx.client.register_user(
+        &admin,
+        &stranger,
+        &Role::Staff,
+        &String::from_str(&fx.env, "aide"),
+    );
+    assert!(share_to(&stranger).is_ok());
+
+    fx.client
+        .deactivate_user(&admin, &pat_b, &DeactivationCascade::None, &10);
+    assert_err(share_to(&pat_b), ContractError::InvalidInput);
+}
+
+#[test]
+fn test_share_requires_owned_distinct_records() {
+    let fx = setup();
+    let (pat_a, pat_b) = (fx.patient("pat_a"), fx.patient("pat_b"));
+    let dr_a = fx.provider("dr_a");
+    let child_record = fx.client.add_record(
+        &dr_a,
+        &pat_b,
+        &dr_a,
+        &RecordType::Examination,
+        &String::from_str(&fx.env, HASH),
+    );
+
+    let share_records = |record_ids: Vec<u64>| {
+        fx.client
+            .try_share_with_user(&pat_a, &pat_b, &record_ids, &AccessLevel::Read, &DAY)
+    };
+    assert_err(
+        share_records(vec![&fx.env, fx.record(0), child_record]),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        share_records(vec![&fx.env, fx.record(0), fx.record(0)]),
+        ContractError::InvalidInput,
+    );
+    assert_err(
+        share_records(Vec::new(&fx.env)),
+        ContractError::InvalidInput,
+    );
+
+    // Nothing was granted by the failed attempts
+    assert_eq!(
+        fx.client.check_record_access(&fx.record(0), &pat_b),
+        AccessLevel::None
+    );
+    assert!(fx.client.get_shares_given(&pat_a).is_empty());
+}
+
+#[test]
+fn test_views_drop_revoked_and_lapsed_shares() {
+    let fx = setup();
+    let (pat_a, pat_b) = (fx.patient("pat_a"), fx.patient("pat_b"));
+    share(&fx, &pat_b, &records(&fx));
+
+    fx.client
+        .revoke_record_access(&pat_a, &pat_b, &fx.record(0));
+    let received = fx.client.get_shares_received(&pat_b);
+    assert_eq!(
+        received.get(0).unwrap().record_ids,
+        vec![&fx.env, fx.record(1)]
+    );
+
+    fx.advance_time(DAY + 1);
+    assert!(fx.client.get_shares_given(&pat_a).is_empty());
+    assert!(fx.client.get_shares_received(&pat_b).is_empty());
+}
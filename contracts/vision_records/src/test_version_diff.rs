@@ -0,0 +1,163 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{ContractError, RecordType};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+const HASH_C: &str = "QmZ4tDuvesekSs4qM5ZBKpXiZGun7S2CYtEZRB3DYXkjGx";
+const DIFF_A: &str = "QmdiffAaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+const DIFF_B: &str = "QmdiffBbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb";
+
+/// `dr_a`'s record for `pat_a` has three versions.
+fn setup() -> TestContract {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider("dr_b")
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .build();
+    let dr_a = fx.provider("dr_a");
+    fx.client
+        .update_record(&dr_a, &fx.record(0), &text(&fx, HASH_B));
+    fx.client
+        .update_record(&dr_a, &fx.record(0), &text(&fx, HASH_C));
+    fx
+}
+
+fn text(fx: &TestContract, value: &str) -> String {
+    String::from_str(&fx.env, value)
+}
+
+fn attach(
+    fx: &TestContract,
+    caller: &Address,
+    from_version: u32,
+    to_version: u32,
+    diff_hash: &str,
+) -> Result<(), ContractError> {
+    match fx.client.try_attach_version_diff(
+        caller,
+        &fx.record(0),
+        &from_version,
+        &to_version,
+        &text(fx, diff_hash),
+    ) {
+        Ok(Ok(())) => Ok(()),
+        Err(Ok(err)) => Err(err),
+        other => panic!("unexpected result {:?}", other),
+    }
+}
+
+fn diff(fx: &TestContract, from_version: u32, to_version: u32) -> Option<String> {
+    fx.client.get_version_diff(
+        &fx.patient("pat_a"),
+        &fx.record(0),
+        &from_version,
+        &to_version,
+    )
+}
+
+#[test]
+fn test_version_pair_is_unordered() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let record_id = fx.record(0);
+    attach(&fx, &fx.provider("dr_a"), 3, 1, DIFF_A).unwrap();
+
+    assert_eq!(diff(&fx, 1, 3), Some(text(&fx, DIFF_A)));
+    assert_eq!(diff(&fx, 3, 1), Some(text(&fx, DIFF_A)));
+    assert_eq!(diff(&fx, 1, 2), None);
+
+    for (from_version, to_version) in [(1, 3), (3, 1)] {
+        let cmp =
+            fx.client
+                .compare_record_versions(&patient, &record_id, &from_version, &to_version);
+        assert_eq!(cmp.diff_hash, Some(text(&fx, DIFF_A)));
+    }
+    let cmp = fx
+        .client
+        .compare_record_versions(&patient, &record_id, &2, &3);
+    assert_eq!(cmp.diff_hash, None);
+}
+
+#[test]
+fn test_only_admin_may_replace_a_diff() {
+    let fx = setup();
+    let dr_a = fx.provider("dr_a");
+    attach(&fx, &dr_a, 1, 2, DIFF_A).unwrap();
+
+    assert_eq!(
+        attach(&fx, &dr_a, 2, 1, DIFF_B),
+        Err(ContractError::Unauthorized)
+    );
+    // Re-attaching the same pointer is a no-op
+    assert_eq!(attach(&fx, &dr_a, 1, 2, DIFF_A), Ok(()));
+
+    attach(&fx, &fx.admin(), 2, 1, DIFF_B).unwrap();
+    assert_eq!(diff(&fx, 1, 2), Some(text(&fx, DIFF_B)));
+    fx.assert_invariants();
+}
+
+#[test]
+fn test_attach_requires_write_access() {
+    let fx = setup();
+    for caller in [
+        fx.provider("dr_b"),
+        fx.patient("pat_a"),
+        Address::generate(&fx.env),
+    ] {
+        assert_eq!(
+            attach(&fx, &caller, 1, 2, DIFF_A),
+            Err(ContractError::Unauthorized)
+        );
+    }
+    assert_eq!(diff(&fx, 1, 2), None);
+}
+
+#[test]
+fn test_get_requires_history_access() {
+    let fx = setup();
+    attach(&fx, &fx.provider("dr_a"), 1, 2, DIFF_A).unwrap();
+
+    assert_eq!(
+        fx.client
+            .get_version_diff(&fx.provider("dr_b"), &fx.record(0), &1, &2),
+        Some(text(&fx, DIFF_A))
+    );
+    assert_err(
+        fx.client
+            .try_get_version_diff(&Address::generate(&fx.env), &fx.record(0), &1, &2),
+        ContractError::Unauthorized,
+    );
+}
+
+#[test]
+fn test_both_versions_must_exist() {
+    let fx = setup();
+    let dr_a = fx.provider("dr_a");
+
+    assert_eq!(
+        attach(&fx, &dr_a, 1, 4, DIFF_A),
+        Err(ContractError::RecordNotFound)
+    );
+    assert_eq!(
+        attach(&fx, &dr_a, 0, 2, DIFF_A),
+        Err(ContractError::RecordNotFound)
+    );
+    assert_eq!(
+        attach(&fx, &dr_a, 2, 2, DIFF_A),
+        Err(ContractError::InvalidInput)
+    );
+    assert_eq!(
+        attach(&fx, &dr_a, 1, 2, ""),
+        Err(ContractError::InvalidInput)
+    );
+}
@@ -0,0 +1,148 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{ContractError, RecordType};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use crate::version_pin::{self, MAX_PINS_PER_RECORD};
+use soroban_sdk::{testutils::Address as _, Address, String, Symbol};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .build()
+}
+
+fn label(fx: &TestContract, value: &str) -> Symbol {
+    Symbol::new(&fx.env, value)
+}
+
+fn update(fx: &TestContract, hash: &str) {
+    fx.client.update_record(
+        &fx.provider("dr_a"),
+        &fx.record(0),
+        &String::from_str(&fx.env, hash),
+    );
+}
+
+#[test]
+fn test_pinned_version_survives_updates() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    let claim = label(&fx, "claim_2024_07");
+    fx.client
+        .pin_version(&fx.patient("pat_a"), &record_id, &1, &claim);
+
+    update(&fx, HASH_B);
+    update(&fx, HASH_A);
+    assert_eq!(fx.client.get_record_version_count(&record_id), 3);
+    assert_eq!(fx.client.get_pinned_version(&record_id, &claim), Some(1));
+    let pinned = fx
+        .client
+        .get_record_version(&fx.provider("dr_a"), &record_id, &1);
+    assert_eq!(pinned.version, 1);
+    assert_eq!(
+        fx.client
+            .get_pinned_version(&record_id, &label(&fx, "unknown")),
+        None
+    );
+}
+
+#[test]
+fn test_duplicate_labels_rejected() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    let provider = fx.provider("dr_a");
+    update(&fx, HASH_B);
+    let claim = label(&fx, "claim_2024_07");
+
+    fx.client.pin_version(&provider, &record_id, &1, &claim);
+    assert_err(
+        fx.client.try_pin_version(&provider, &record_id, &2, &claim),
+        ContractError::AlreadyExists,
+    );
+    assert_eq!(fx.client.get_pinned_version(&record_id, &claim), Some(1));
+
+    // One version may carry several labels.
+    fx.client
+        .pin_version(&provider, &record_id, &1, &label(&fx, "audit_q3"));
+    assert_eq!(fx.client.get_version_pins(&record_id).len(), 2);
+}
+
+#[test]
+fn test_pin_limit_and_access() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    let provider = fx.provider("dr_a");
+
+    assert_err(
+        fx.client.try_pin_version(
+            &Address::generate(&fx.env),
+            &record_id,
+            &1,
+            &label(&fx, "outsider"),
+        ),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client
+            .try_pin_version(&provider, &record_id, &2, &label(&fx, "future")),
+        ContractError::RecordNotFound,
+    );
+
+    let labels = [
+        "pin_0", "pin_1", "pin_2", "pin_3", "pin_4", "pin_5", "pin_6", "pin_7", "pin_8", "pin_9",
+    ];
+    assert_eq!(labels.len() as u32, MAX_PINS_PER_RECORD);
+    for name in labels {
+        fx.client
+            .pin_version(&provider, &record_id, &1, &label(&fx, name));
+    }
+    assert_err(
+        fx.client
+            .try_pin_version(&provider, &record_id, &1, &label(&fx, "pin_10")),
+        ContractError::PinLimitReached,
+    );
+}
+
+#[test]
+fn test_pinned_versions_refuse_removal_until_admin_unpins() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    update(&fx, HASH_B);
+    let claim = label(&fx, "claim_2024_07");
+    fx.client
+        .pin_version(&fx.provider("dr_a"), &record_id, &1, &claim);
+
+    let prune_check = |version: u32| {
+        fx.env.as_contract(&fx.contract_id, || {
+            version_pin::require_unpinned(&fx.env, record_id, version)
+        })
+    };
+    assert_eq!(prune_check(1), Err(ContractError::VersionPinned));
+    assert_eq!(prune_check(2), Ok(()));
+
+    // Only a SystemAdmin may remove a pin, not even the patient.
+    for caller in [fx.patient("pat_a"), fx.provider("dr_a")] {
+        assert_err(
+            fx.client.try_unpin_version(&caller, &record_id, &claim),
+            ContractError::Unauthorized,
+        );
+    }
+    fx.client.unpin_version(&fx.admin(), &record_id, &claim);
+    assert_eq!(fx.client.get_pinned_version(&record_id, &claim), None);
+    assert_eq!(prune_check(1), Ok(()));
+    assert_err(
+        fx.client.try_unpin_version(&fx.admin(), &record_id, &claim),
+        ContractError::RecordNotFound,
+    );
+}
@@ -0,0 +1,368 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::bounded::MAX_BOUNDED_ITEMS;
+use super::testutils::{assert_err, Fixture, TestContract};
+use super::{versioning, ContractError, RecordComparison, RecordType, Role};
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+const HASH_V1: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_V2: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+const HASH_V3: &str = "QmPZ9gcCEpqKTo6aq61g2nXGUhM4iCL3ewB6LDXZCtioEB";
+
+const PROVIDER: &str = "Dr. Provider";
+const PATIENT: &str = "Patient";
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider(PROVIDER)
+        .with_patient(PATIENT)
+        .with_record(RecordType::Examination, HASH_V1)
+        .build()
+}
+
+#[test]
+fn test_monotonic_timestamp_clamps() {
+    assert_eq!(versioning::monotonic_timestamp(100, 200), 200);
+    assert_eq!(versioning::monotonic_timestamp(100, 100), 101);
+    assert_eq!(versioning::monotonic_timestamp(100, 50), 101);
+    assert_eq!(versioning::monotonic_timestamp(u64::MAX, 0), u64::MAX);
+}
+
+#[test]
+fn test_record_creation_stores_first_version() {
+    let fx = setup();
+    let env = &fx.env;
+    let client = &fx.client;
+    let provider = fx.provider(PROVIDER);
+    let record_id = fx.record(0);
+
+    assert_eq!(client.get_record_version_count(&record_id), 1);
+    let v1 = client.get_record_version(&provider, &record_id, &1);
+    assert_eq!(v1.version, 1);
+    assert_eq!(v1.modified_at, 1_000);
+    assert_eq!(v1.data_hash, String::from_str(env, HASH_V1));
+}
+
+#[test]
+fn test_update_record_appends_version() {
+    let fx = setup();
+    let env = &fx.env;
+    let client = &fx.client;
+    let provider = fx.provider(PROVIDER);
+    let patient = fx.patient(PATIENT);
+    let record_id = fx.record(0);
+
+    fx.advance_time(1_000);
+    let version = client.update_record(&provider, &record_id, &String::from_str(env, HASH_V2));
+    assert_eq!(version, 2);
+
+    let record = client.get_record(&patient, &record_id);
+    assert_eq!(record.data_hash, String::from_str(env, HASH_V2));
+    assert_eq!(record.created_at, 1_000);
+    assert_eq!(record.updated_at, 2_000);
+
+    let v1 = client.get_record_version(&patient, &record_id, &1);
+    assert_eq!(v1.data_hash, String::from_str(env, HASH_V1));
+}
+
+#[test]
+fn test_rewound_clock_keeps_versions_ordered() {
+    let fx = setup();
+    let env = &fx.env;
+    let client = &fx.client;
+    let provider = fx.provider(PROVIDER);
+    let patient = fx.patient(PATIENT);
+    let record_id = fx.record(0);
+
+    fx.set_time(5_000);
+    client.update_record(&provider, &record_id, &String::from_str(env, HASH_V2));
+
+    // Rewind the ledger clock before the next update.
+    fx.set_time(3_000);
+    client.update_record(&provider, &record_id, &String::from_str(env, HASH_V3));
+
+    // And once more to exactly the same instant.
+    fx.set_time(5_001);
+    client.update_record(&provider, &record_id, &String::from_str(env, HASH_V1));
+
+    let count = client.get_record_version_count(&record_id);
+    assert_eq!(count, 4);
+
+    let mut previous = 0;
+    for version in 1..=count {
+        let entry = client.get_record_version(&patient, &record_id, &version);
+        assert!(entry.modified_at > previous);
+        previous = entry.modified_at;
+    }
+
+    assert_eq!(
+        client
+            .get_record_version(&patient, &record_id, &3)
+            .modified_at,
+        5_001
+    );
+    assert_eq!(
+        client
+            .get_record_version(&patient, &record_id, &4)
+            .modified_at,
+        5_002
+    );
+    assert_eq!(client.get_record(&patient, &record_id).updated_at, 5_002);
+}
+
+#[test]
+fn test_get_record_at_returns_version_current_at_time() {
+    let fx = setup();
+    let env = &fx.env;
+    let client = &fx.client;
+    let provider = fx.provider(PROVIDER);
+    let patient = fx.patient(PATIENT);
+    let record_id = fx.record(0);
+
+    fx.set_time(2_000);
+    client.update_record(&provider, &record_id, &String::from_str(env, HASH_V2));
+    fx.set_time(3_000);
+    client.update_record(&provider, &record_id, &String::from_str(env, HASH_V3));
+
+    assert_eq!(
+        client.get_record_at(&patient, &record_id, &1_500).version,
+        1
+    );
+    assert_eq!(
+        client.get_record_at(&patient, &record_id, &2_000).version,
+        2
+    );
+    assert_eq!(
+        client.get_record_at(&patient, &record_id, &9_999).version,
+        3
+    );
+
+    assert_err(
+        client.try_get_record_at(&patient, &record_id, &999),
+        ContractError::RecordNotFound,
+    );
+}
+
+#[test]
+fn test_version_at_every_boundary_of_a_long_history() {
+    let fx = setup();
+    let provider = fx.provider(PROVIDER);
+    let record_id = fx.record(0);
+    let created_at = fx
+        .client
+        .get_record_version(&provider, &record_id, &1)
+        .modified_at;
+    for version in 2..=20u64 {
+        fx.set_time(created_at + 1_000 * (version - 1));
+        let hash = if version % 2 == 0 { HASH_V2 } else { HASH_V1 };
+        fx.client
+            .update_record(&provider, &record_id, &String::from_str(&fx.env, hash));
+    }
+
+    fx.env.as_contract(&fx.client.address, || {
+        let at = |timestamp| versioning::get_version_at(&fx.env, record_id, timestamp);
+        assert!(at(created_at - 1).is_none());
+        for version in 1..=20u32 {
+            let written = created_at + 1_000 * u64::from(version - 1);
+            assert_eq!(at(written).unwrap().version, version);
+            assert_eq!(at(written + 999).unwrap().version, version);
+        }
+        assert_eq!(at(u64::MAX).unwrap().version, 20);
+    });
+}
+
+#[test]
+fn test_update_record_requires_write_permission() {
+    let fx = setup();
+    let env = &fx.env;
+    let client = &fx.client;
+    let patient = fx.patient(PATIENT);
+    let record_id = fx.record(0);
+
+    assert_err(
+        client.try_update_record(&patient, &record_id, &String::from_str(env, HASH_V2)),
+        ContractError::Unauthorized,
+    );
+    assert_eq!(client.get_record_version_count(&record_id), 1);
+}
+
+// ======================== Version Comparison ========================
+
+/// Builds the history H1, H1, H2, H2, H1 at timestamps 1000..=5000.
+fn alternating_history(fx: &TestContract, provider: &Address, record_id: u64) {
+    for (i, hash) in [HASH_V1, HASH_V2, HASH_V2, HASH_V1].iter().enumerate() {
+        fx.set_time(2_000 + 1_000 * i as u64);
+        fx.client
+            .update_record(provider, &record_id, &String::from_str(&fx.env, hash));
+    }
+}
+
+#[test]
+fn test_compare_versions_over_alternating_history() {
+    let fx = setup();
+    let client = &fx.client;
+    let provider = fx.provider(PROVIDER);
+    let patient = fx.patient(PATIENT);
+    let record_id = fx.record(0);
+    alternating_history(&fx, &provider, record_id);
+
+    let cmp = client.compare_record_versions(&patient, &record_id, &1, &5);
+    assert_eq!(
+        cmp,
+        RecordComparison {
+            record_id,
+            from_version: 1,
+            to_version: 5,
+            changed: false,
+            versions_between: 4,
+            elapsed_seconds: 4_000,
+            same_author: true,
+            intermediate_change_count: 2,
+            reversed: false,
+            diff_hash: None,
+        }
+    );
+
+    let cmp = client.compare_record_versions(&patient, &record_id, &1, &2);
+    assert!(!cmp.changed);
+    assert_eq!(cmp.intermediate_change_count, 0);
+
+    let cmp = client.compare_record_versions(&patient, &record_id, &2, &4);
+    assert!(cmp.changed);
+    assert_eq!(cmp.intermediate_change_count, 1);
+}
+
+#[test]
+fn test_compare_versions_normalizes_reversed_order() {
+    let fx = setup();
+    let client = &fx.client;
+    let provider = fx.provider(PROVIDER);
+    let patient = fx.patient(PATIENT);
+    let record_id = fx.record(0);
+    alternating_history(&fx, &provider, record_id);
+
+    let cmp = client.compare_record_versions(&patient, &record_id, &5, &3);
+    assert!(cmp.reversed);
+    assert_eq!(cmp.from_version, 5);
+    assert_eq!(cmp.to_version, 3);
+    assert!(cmp.changed);
+    assert_eq!(cmp.versions_between, 2);
+    assert_eq!(cmp.elapsed_seconds, 2_000);
+    assert_eq!(cmp.intermediate_change_count, 1);
+
+    let same = client.compare_record_versions(&patient, &record_id, &3, &3);
+    assert!(!same.reversed);
+    assert!(!same.changed);
+    assert_eq!(same.versions_between, 0);
+}
+
+#[test]
+fn test_compare_versions_reports_different_authors() {
+    let fx = setup();
+    let env = &fx.env;
+    let client = &fx.client;
+    let provider = fx.provider(PROVIDER);
+    let patient = fx.patient(PATIENT);
+    let record_id = fx.record(0);
+    let assistant = Address::generate(env);
+    client.delegate_role(&provider, &assistant, &Role::Optometrist, &0);
+
+    fx.set_time(2_000);
+    client.update_record(&assistant, &record_id, &String::from_str(env, HASH_V2));
+
+    let cmp = client.compare_record_versions(&patient, &record_id, &1, &2);
+    assert!(!cmp.same_author);
+    assert!(cmp.changed);
+}
+
+#[test]
+fn test_compare_versions_rejects_large_span() {
+    let fx = setup();
+    let client = &fx.client;
+    let patient = fx.patient(PATIENT);
+    let record_id = fx.record(0);
+
+    assert_eq!(versioning::MAX_COMPARE_SPAN, MAX_BOUNDED_ITEMS);
+    assert_err(
+        client.try_compare_record_versions(
+            &patient,
+            &record_id,
+            &1,
+            &(versioning::MAX_COMPARE_SPAN + 2),
+        ),
+        ContractError::VersionSpanTooLarge,
+    );
+    // A span of exactly the cap is walked, and stops at the missing version
+    assert_err(
+        client.try_compare_record_versions(
+            &patient,
+            &record_id,
+            &1,
+            &(versioning::MAX_COMPARE_SPAN + 1),
+        ),
+        ContractError::RecordNotFound,
+    );
+
+    assert_err(
+        client.try_compare_record_versions(&patient, &record_id, &1, &2),
+        ContractError::RecordNotFound,
+    );
+}
+
+/// Test-only hook: drops a record's history, as a record stored before
+/// versions were kept has none.
+fn strip_versions(fx: &TestContract, record_id: u64) {
+    fx.env.as_contract(&fx.client.address, || {
+        let storage = fx.env.storage().persistent();
+        storage.remove(&versioning::version_key(record_id, 1));
+        storage.remove(&versioning::version_count_key(record_id));
+        storage.remove(&versioning::summary_key(record_id));
+    });
+}
+
+#[test]
+fn test_records_without_history_are_seeded_before_changing() {
+    let fx = Fixture::new()
+        .with_admin()
+        .with_provider(PROVIDER)
+        .with_patient(PATIENT)
+        .with_record(RecordType::Examination, HASH_V1)
+        .with_record(RecordType::Examination, HASH_V1)
+        .build();
+    let env = &fx.env;
+    let provider = fx.provider(PROVIDER);
+    let (updated, superseded) = (fx.record(0), fx.record(1));
+    strip_versions(&fx, updated);
+    strip_versions(&fx, superseded);
+    assert_eq!(fx.client.get_record_version_count(&updated), 0);
+
+    fx.advance_time(1_000);
+    let version = fx
+        .client
+        .update_record(&provider, &updated, &String::from_str(env, HASH_V2));
+    assert_eq!(version, 2);
+    let v1 = fx.client.get_record_version(&provider, &updated, &1);
+    assert_eq!(v1.data_hash, String::from_str(env, HASH_V1));
+    assert_eq!(v1.modified_by, provider);
+    assert_eq!(v1.modified_at, 1_000);
+
+    let replacement = fx.client.supersede_record(
+        &provider,
+        &superseded,
+        &String::from_str(env, HASH_V3),
+        &RecordType::Examination,
+    );
+    assert_eq!(fx.client.get_record_version_count(&superseded), 2);
+    let v1 = fx.client.get_record_version(&provider, &superseded, &1);
+    assert_eq!(v1.data_hash, String::from_str(env, HASH_V1));
+    assert_eq!(v1.superseded_by, None);
+    let terminal = fx.client.get_record_version(&provider, &superseded, &2);
+    assert_eq!(terminal.data_hash, String::from_str(env, HASH_V1));
+    assert_eq!(terminal.superseded_by, Some(replacement));
+}
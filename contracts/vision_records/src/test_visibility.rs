@@ -0,0 +1,141 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{
+    AccessLevel, ConsentType, ContractError, RecordType, Role, VisionRecordsContract,
+    VisionRecordsContractClient,
+};
+use crate::testutils::assert_err;
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+
+struct Ctx {
+    env: Env,
+    client: VisionRecordsContractClient<'static>,
+    admin: Address,
+    provider: Address,
+    patient: Address,
+    pharmacist: Address,
+}
+
+/// A pharmacist holding a Full grant and consent from the patient.
+fn setup() -> Ctx {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VisionRecordsContract, ());
+    let client = VisionRecordsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &None);
+
+    let provider = Address::generate(&env);
+    client.register_user(
+        &admin,
+        &provider,
+        &Role::Ophthalmologist,
+        &String::from_str(&env, "Dr. Surgeon"),
+    );
+    let pharmacist = Address::generate(&env);
+    client.register_user(
+        &admin,
+        &pharmacist,
+        &Role::Pharmacist,
+        &String::from_str(&env, "Pharmacist"),
+    );
+
+    let patient = Address::generate(&env);
+    client.grant_access(&patient, &patient, &pharmacist, &AccessLevel::Full, &86_400);
+    client.grant_consent(&patient, &pharmacist, &ConsentType::Treatment, &86_400);
+
+    Ctx {
+        env,
+        client,
+        admin,
+        provider,
+        patient,
+        pharmacist,
+    }
+}
+
+fn add_record(ctx: &Ctx, record_type: RecordType) -> u64 {
+    ctx.client.add_record(
+        &ctx.provider,
+        &ctx.patient,
+        &ctx.provider,
+        &record_type,
+        &String::from_str(&ctx.env, HASH_A),
+    )
+}
+
+#[test]
+fn test_defaults_are_fully_visible() {
+    let ctx = setup();
+    let surgery = add_record(&ctx, RecordType::Surgery);
+    ctx.client.get_record(&ctx.pharmacist, &surgery);
+
+    let matrix = ctx.client.get_role_visibility(&Role::Pharmacist);
+    assert_eq!(matrix.len(), 7);
+    assert!(matrix.iter().all(|entry| entry.visible));
+}
+
+#[test]
+fn test_hidden_type_denied_despite_full_grant() {
+    let ctx = setup();
+    let surgery = add_record(&ctx, RecordType::Surgery);
+    let prescription = add_record(&ctx, RecordType::Prescription);
+
+    ctx.client
+        .set_role_visibility(&ctx.admin, &Role::Pharmacist, &RecordType::Surgery, &false);
+
+    assert_err(
+        ctx.client.try_get_record(&ctx.pharmacist, &surgery),
+        ContractError::Unauthorized,
+    );
+    ctx.client.get_record(&ctx.pharmacist, &prescription);
+
+    // The patient and the authoring provider are not restricted.
+    ctx.client.get_record(&ctx.patient, &surgery);
+    ctx.client.get_record(&ctx.provider, &surgery);
+
+    let matrix = ctx.client.get_role_visibility(&Role::Pharmacist);
+    for entry in matrix.iter() {
+        assert_eq!(entry.visible, entry.record_type != RecordType::Surgery);
+    }
+    // Other roles keep their defaults.
+    assert!(ctx
+        .client
+        .get_role_visibility(&Role::Optometrist)
+        .iter()
+        .all(|entry| entry.visible));
+}
+
+#[test]
+fn test_visibility_can_be_restored() {
+    let ctx = setup();
+    let surgery = add_record(&ctx, RecordType::Surgery);
+
+    ctx.client
+        .set_role_visibility(&ctx.admin, &Role::Pharmacist, &RecordType::Surgery, &false);
+    ctx.client
+        .set_role_visibility(&ctx.admin, &Role::Pharmacist, &RecordType::Surgery, &true);
+
+    ctx.client.get_record(&ctx.pharmacist, &surgery);
+}
+
+#[test]
+fn test_set_role_visibility_requires_admin() {
+    let ctx = setup();
+    let res = ctx.client.try_set_role_visibility(
+        &ctx.pharmacist,
+        &Role::Pharmacist,
+        &RecordType::Surgery,
+        &false,
+    );
+    assert_eq!(res, Err(Ok(ContractError::Unauthorized)));
+}
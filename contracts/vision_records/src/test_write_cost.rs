@@ -0,0 +1,209 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+extern crate std;
+
+use super::{AccessLevel, RecordType, Role, WriteCostAction, WriteCostEstimate};
+use crate::testutils::{Fixture, TestContract};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::xdr::{LedgerEntry, LedgerKey, Limits, ScVal, WriteXdr};
+use soroban_sdk::{Address, String};
+use std::boxed::Box;
+use std::vec::Vec as StdVec;
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+const DAY: u64 = 86_400;
+
+/// What an operation actually changed in the contract's storage.
+#[derive(Debug)]
+struct StorageDiff {
+    new_entries: u32,
+    modified_entries: u32,
+    bytes: u32,
+}
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_b")
+        .with_provider("dr_a")
+        .with_patient("pat_b")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .build()
+}
+
+/// The contract's data entries, leaving out auth nonces.
+fn contract_entries(fx: &TestContract) -> StdVec<(Box<LedgerKey>, Box<LedgerEntry>)> {
+    fx.env
+        .to_ledger_snapshot()
+        .ledger_entries
+        .into_iter()
+        .filter(|(key, _)| match key.as_ref() {
+            LedgerKey::ContractData(data) => !matches!(data.key, ScVal::LedgerKeyNonce(_)),
+            _ => false,
+        })
+        .map(|(key, (entry, _))| (key, entry))
+        .collect()
+}
+
+fn xdr_len(entry: &LedgerEntry) -> u32 {
+    entry.to_xdr(Limits::none()).unwrap().len() as u32
+}
+
+fn measure(fx: &TestContract, operation: impl FnOnce()) -> StorageDiff {
+    let before = contract_entries(fx);
+    operation();
+    let mut diff = StorageDiff {
+        new_entries: 0,
+        modified_entries: 0,
+        bytes: 0,
+    };
+    for (key, entry) in contract_entries(fx) {
+        match before.iter().find(|(old_key, _)| *old_key == key) {
+            None => {
+                diff.new_entries += 1;
+                diff.bytes += xdr_len(&entry);
+            }
+            // A TTL extension alone leaves the entry's data unchanged
+            Some((_, old)) if old.data != entry.data => {
+                diff.modified_entries += 1;
+                diff.bytes += xdr_len(&entry).saturating_sub(xdr_len(old));
+            }
+            Some(_) => {}
+        }
+    }
+    diff
+}
+
+fn assert_matches(estimate: WriteCostEstimate, actual: StorageDiff) {
+    assert_eq!(estimate.new_entries, actual.new_entries, "{:?}", actual);
+    assert_eq!(
+        estimate.modified_entries, actual.modified_entries,
+        "{:?}",
+        actual
+    );
+    // Bytes are approximate, but should stay within half of the estimate
+    assert!(
+        actual.bytes * 2 >= estimate.approx_bytes && actual.bytes <= estimate.approx_bytes * 3 / 2,
+        "estimated {} bytes, wrote {:?}",
+        estimate.approx_bytes,
+        actual
+    );
+}
+
+#[test]
+fn test_add_record_estimate_matches_storage() {
+    let fx = setup();
+    let dr_a = fx.provider("dr_a");
+    let patient = fx.patient("pat_a");
+
+    let actual = measure(&fx, || {
+        fx.client.add_record(
+            &dr_a,
+            &patient,
+            &dr_a,
+            &RecordType::Examination,
+            &String::from_str(&fx.env, HASH_B),
+        );
+    });
+    let estimate = fx
+        .client
+        .estimate_write_cost(&WriteCostAction::AddRecord, &(HASH_B.len() as u32));
+    assert_matches(estimate, actual);
+}
+
+#[test]
+fn test_update_record_estimate_matches_storage() {
+    let fx = setup();
+    let dr_a = fx.provider("dr_a");
+    // The patient already has an audited entry, so their audit count exists
+    fx.client
+        .update_record(&dr_a, &fx.record(0), &String::from_str(&fx.env, HASH_B));
+
+    let actual = measure(&fx, || {
+        fx.client
+            .update_record(&dr_a, &fx.record(0), &String::from_str(&fx.env, HASH_B));
+    });
+    let estimate = fx
+        .client
+        .estimate_write_cost(&WriteCostAction::UpdateRecord, &(HASH_B.len() as u32));
+    assert_matches(estimate, actual);
+}
+
+#[test]
+fn test_grant_access_estimate_matches_storage() {
+    let fx = setup();
+    let pat_a = fx.patient("pat_a");
+    let pat_b = fx.patient("pat_b");
+    let dr_a = fx.provider("dr_a");
+    // Both parties already appear in the grant indexes
+    fx.client
+        .grant_access(&pat_b, &pat_b, &dr_a, &AccessLevel::Read, &DAY);
+    fx.client.grant_access(
+        &pat_a,
+        &pat_a,
+        &fx.provider("dr_b"),
+        &AccessLevel::Read,
+        &DAY,
+    );
+
+    let actual = measure(&fx, || {
+        fx.client
+            .grant_access(&pat_a, &pat_a, &dr_a, &AccessLevel::Read, &DAY);
+    });
+    let estimate = fx
+        .client
+        .estimate_write_cost(&WriteCostAction::GrantAccess, &0);
+    assert_matches(estimate, actual);
+}
+
+#[test]
+fn test_register_user_estimate_matches_storage() {
+    let fx = setup();
+    let name = "dr_new";
+
+    let actual = measure(&fx, || {
+        fx.client.register_user(
+            &fx.admin(),
+            &Address::generate(&fx.env),
+            &Role::Optometrist,
+            &String::from_str(&fx.env, name),
+        );
+    });
+    let estimate = fx
+        .client
+        .estimate_write_cost(&WriteCostAction::RegisterUser, &(name.len() as u32));
+    assert_matches(estimate, actual);
+}
+
+#[test]
+fn test_estimate_scales_with_payload() {
+    let fx = setup();
+    let short = fx
+        .client
+        .estimate_write_cost(&WriteCostAction::AddRecord, &0);
+    let long = fx
+        .client
+        .estimate_write_cost(&WriteCostAction::AddRecord, &100);
+    assert_eq!(long.new_entries, short.new_entries);
+    assert_eq!(long.approx_bytes, short.approx_bytes + 200);
+
+    let grant_short = fx
+        .client
+        .estimate_write_cost(&WriteCostAction::GrantAccess, &0);
+    let grant_long = fx
+        .client
+        .estimate_write_cost(&WriteCostAction::GrantAccess, &100);
+    assert_eq!(grant_long, grant_short);
+
+    let huge = fx
+        .client
+        .estimate_write_cost(&WriteCostAction::AddRecord, &u32::MAX);
+    assert_eq!(huge.approx_bytes, u32::MAX);
+}
@@ -0,0 +1,146 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessLevel, ConsentType, ContractError, Permission, RecordType};
+use crate::testutils::{assert_err, Fixture, TestContract};
+use soroban_sdk::{testutils::Address as _, Address, String, Vec};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .build()
+}
+
+fn text(fx: &TestContract, value: &str) -> String {
+    String::from_str(&fx.env, value)
+}
+
+fn delegate(fx: &TestContract, permission: Permission) -> Address {
+    let delegate = Address::generate(&fx.env);
+    fx.client.delegate_permissions(
+        &fx.provider("dr_a"),
+        &delegate,
+        &Vec::from_array(&fx.env, [permission]),
+        &0,
+    );
+    delegate
+}
+
+fn add_as(fx: &TestContract, caller: &Address, provider: &Address) -> u64 {
+    fx.client.add_record(
+        caller,
+        &fx.patient("pat_a"),
+        provider,
+        &RecordType::Examination,
+        &text(fx, HASH_A),
+    )
+}
+
+#[test]
+fn test_append_only_delegate_cannot_modify_own_record() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    let appender = delegate(&fx, Permission::AppendRecord);
+
+    let record_id = add_as(&fx, &appender, &provider);
+    assert_eq!(
+        fx.client.get_record(&provider, &record_id).provider,
+        provider
+    );
+
+    assert_err(
+        fx.client
+            .try_update_record(&appender, &record_id, &text(&fx, HASH_B)),
+        ContractError::Unauthorized,
+    );
+    assert_err(
+        fx.client.try_supersede_record(
+            &appender,
+            &record_id,
+            &text(&fx, HASH_B),
+            &RecordType::Examination,
+        ),
+        ContractError::Unauthorized,
+    );
+    assert_eq!(fx.client.get_record_version_count(&record_id), 1);
+}
+
+#[test]
+fn test_write_delegate_can_append_and_modify() {
+    let fx = setup();
+    let provider = fx.provider("dr_a");
+    let writer = delegate(&fx, Permission::WriteRecord);
+
+    let record_id = add_as(&fx, &writer, &provider);
+    assert_eq!(
+        fx.client
+            .update_record(&writer, &record_id, &text(&fx, HASH_B)),
+        2
+    );
+}
+
+#[test]
+fn test_append_only_grant_limits_writes() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let clinic = Address::generate(&fx.env);
+    fx.client
+        .grant_consent(&patient, &clinic, &ConsentType::Treatment, &3_600);
+
+    // A read grant does not allow writes at all.
+    fx.client
+        .grant_access(&patient, &patient, &clinic, &AccessLevel::Read, &3_600);
+    assert_err(
+        fx.client.try_add_record(
+            &clinic,
+            &patient,
+            &clinic,
+            &RecordType::Examination,
+            &text(&fx, HASH_A),
+        ),
+        ContractError::Unauthorized,
+    );
+
+    fx.client.grant_append_access(&patient, &clinic, &3_600);
+    assert!(fx.client.is_append_only_grant(&patient, &clinic));
+    assert_eq!(
+        fx.client.check_access(&patient, &clinic),
+        AccessLevel::Write
+    );
+    let record_id = add_as(&fx, &clinic, &clinic);
+    assert_err(
+        fx.client
+            .try_update_record(&clinic, &record_id, &text(&fx, HASH_B)),
+        ContractError::Unauthorized,
+    );
+
+    // A plain write grant replaces the append-only one.
+    fx.client
+        .grant_access(&patient, &patient, &clinic, &AccessLevel::Write, &3_600);
+    assert!(!fx.client.is_append_only_grant(&patient, &clinic));
+    fx.client
+        .update_record(&clinic, &record_id, &text(&fx, HASH_B));
+}
+
+#[test]
+fn test_delegate_permissions_rejects_empty_list() {
+    let fx = setup();
+    assert_err(
+        fx.client.try_delegate_permissions(
+            &fx.provider("dr_a"),
+            &Address::generate(&fx.env),
+            &Vec::new(&fx.env),
+            &0,
+        ),
+        ContractError::InvalidInput,
+    );
+}
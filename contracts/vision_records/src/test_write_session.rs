@@ -0,0 +1,209 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::{AccessLevel, ConsentType, ContractError, Permission, RecordPriority, RecordType};
+use crate::testutils::{assert_err, Fixture, TestContract, FIXTURE_START_TIME};
+use crate::write_session::MAX_WRITE_SESSION_TTL_SECONDS;
+use soroban_sdk::{testutils::Address as _, Address, String, Vec};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+const HOUR: u64 = 3_600;
+
+fn setup() -> TestContract {
+    Fixture::new()
+        .with_admin()
+        .with_provider("dr_b")
+        .with_provider("dr_a")
+        .with_patient("pat_a")
+        .with_record(RecordType::Examination, HASH_A)
+        .build()
+}
+
+fn text(fx: &TestContract, value: &str) -> String {
+    String::from_str(&fx.env, value)
+}
+
+/// `dr_b` writing to `dr_a`'s record through the patient's write grant.
+fn grant_write(fx: &TestContract) -> Address {
+    let patient = fx.patient("pat_a");
+    let dr_b = fx.provider("dr_b");
+    fx.client
+        .grant_access(&patient, &patient, &dr_b, &AccessLevel::Write, &(24 * HOUR));
+    fx.client
+        .grant_consent(&patient, &dr_b, &ConsentType::Treatment, &(24 * HOUR));
+    dr_b
+}
+
+fn write(fx: &TestContract, caller: &Address) -> Result<u32, ContractError> {
+    match fx
+        .client
+        .try_update_record_in_session(caller, &fx.record(0), &text(fx, HASH_B))
+    {
+        Ok(Ok(version)) => Ok(version),
+        Err(Ok(err)) => Err(err),
+        other => panic!("unexpected result {:?}", other),
+    }
+}
+
+#[test]
+fn test_session_allows_updates_until_expiry() {
+    let fx = setup();
+    let dr_b = grant_write(&fx);
+    let record_id = fx.record(0);
+
+    let session = fx.client.open_write_session(&dr_b, &record_id, &HOUR);
+    assert_eq!(session.expires_at, FIXTURE_START_TIME + HOUR);
+    assert_eq!(write(&fx, &dr_b), Ok(2));
+    assert_eq!(write(&fx, &dr_b), Ok(3));
+    assert_eq!(fx.client.get_record_version_count(&record_id), 3);
+
+    fx.advance_time(HOUR);
+    assert!(fx.client.get_write_session(&dr_b, &record_id).is_none());
+    assert_eq!(write(&fx, &dr_b), Err(ContractError::SessionNotFound));
+}
+
+#[test]
+fn test_session_requires_write_access_and_bounded_ttl() {
+    let fx = setup();
+    let record_id = fx.record(0);
+    let dr_b = fx.provider("dr_b");
+
+    assert_err(
+        fx.client.try_open_write_session(&dr_b, &record_id, &HOUR),
+        ContractError::Unauthorized,
+    );
+    assert_eq!(write(&fx, &dr_b), Err(ContractError::SessionNotFound));
+
+    grant_write(&fx);
+    for ttl in [0, MAX_WRITE_SESSION_TTL_SECONDS + 1] {
+        assert_err(
+            fx.client.try_open_write_session(&dr_b, &record_id, &ttl),
+            ContractError::InvalidInput,
+        );
+    }
+}
+
+#[test]
+fn test_revocation_mid_session_invalidates_it() {
+    let fx = setup();
+    let patient = fx.patient("pat_a");
+    let dr_b = grant_write(&fx);
+    fx.client.open_write_session(&dr_b, &fx.record(0), &HOUR);
+    assert_eq!(write(&fx, &dr_b), Ok(2));
+
+    fx.client.revoke_access(&patient, &dr_b);
+    assert_eq!(write(&fx, &dr_b), Err(ContractError::SessionNotFound));
+
+    // A fresh grant does not revive the old session
+    grant_write(&fx);
+    assert_eq!(write(&fx, &dr_b), Err(ContractError::SessionNotFound));
+    fx.client.open_write_session(&dr_b, &fx.record(0), &HOUR);
+    assert_eq!(write(&fx, &dr_b), Ok(3));
+
+    fx.client.revoke_consent(&patient, &dr_b);
+    assert_eq!(write(&fx, &dr_b), Err(ContractError::SessionNotFound));
+}
+
+#[test]
+fn test_patient_freeze_invalidates_session() {
+    let fx = setup();
+    let dr_a = fx.provider("dr_a");
+    fx.client.open_write_session(&dr_a, &fx.record(0), &HOUR);
+
+    fx.client.freeze_my_access(&fx.patient("pat_a"), &false);
+    assert_eq!(write(&fx, &dr_a), Err(ContractError::SessionNotFound));
+}
+
+#[test]
+fn test_session_rechecks_lock_and_archive() {
+    let fx = setup();
+    let dr_a = fx.provider("dr_a");
+    let record_id = fx.record(0);
+    fx.client.open_write_session(&dr_a, &record_id, &HOUR);
+
+    fx.client
+        .set_record_priority(&dr_a, &record_id, &RecordPriority::Archivable);
+    assert_eq!(write(&fx, &dr_a), Err(ContractError::RecordImmutable));
+    assert_err(
+        fx.client.try_open_write_session(&dr_a, &record_id, &HOUR),
+        ContractError::RecordImmutable,
+    );
+
+    fx.client
+        .set_record_priority(&dr_a, &record_id, &RecordPriority::Standard);
+    fx.client.supersede_record(
+        &dr_a,
+        &record_id,
+        &text(&fx, HASH_B),
+        &RecordType::Examination,
+    );
+    assert_eq!(write(&fx, &dr_a), Err(ContractError::RecordSuperseded));
+}
+
+#[test]
+fn test_close_ends_session() {
+    let fx = setup();
+    let dr_a = fx.provider("dr_a");
+    let record_id = fx.record(0);
+    fx.client.open_write_session(&dr_a, &record_id, &HOUR);
+
+    fx.client.close_write_session(&dr_a, &record_id);
+    assert_eq!(write(&fx, &dr_a), Err(ContractError::SessionNotFound));
+    assert_err(
+        fx.client.try_close_write_session(&dr_a, &record_id),
+        ContractError::SessionNotFound,
+    );
+}
+
+#[test]
+fn test_delegated_session_ends_with_delegation() {
+    let fx = setup();
+    let delegate = Address::generate(&fx.env);
+    fx.client.delegate_permissions(
+        &fx.provider("dr_a"),
+        &delegate,
+        &Vec::from_array(&fx.env, [Permission::WriteRecord]),
+        &(FIXTURE_START_TIME + 600),
+    );
+
+    let session = fx
+        .client
+        .open_write_session(&delegate, &fx.record(0), &HOUR);
+    assert_eq!(session.expires_at, FIXTURE_START_TIME + 600);
+    fx.advance_time(600);
+    assert_eq!(write(&fx, &delegate), Err(ContractError::SessionNotFound));
+}
+
+#[test]
+fn test_session_update_skips_access_resolution() {
+    // A grantee's full check falls through the delegation lookups, then
+    // resolves consent, the grant and ABAC policies; the session skips all
+    // of it.
+    let fx = setup();
+    let dr_b = grant_write(&fx);
+    let record_id = fx.record(0);
+    fx.client.open_write_session(&dr_b, &record_id, &HOUR);
+
+    let mut budget = fx.env.cost_estimate().budget();
+    budget.reset_default();
+    fx.client
+        .update_record(&dr_b, &record_id, &text(&fx, HASH_B));
+    let full_check = budget.cpu_instruction_cost();
+
+    budget.reset_default();
+    fx.client
+        .update_record_in_session(&dr_b, &record_id, &text(&fx, HASH_A));
+    let in_session = budget.cpu_instruction_cost();
+
+    assert!(
+        in_session < full_check,
+        "session update cost {} >= full update cost {}",
+        in_session,
+        full_check
+    );
+}
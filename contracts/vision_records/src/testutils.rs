@@ -0,0 +1,300 @@
+//! Test fixtures for this contract.
+//!
+//! Available to this crate's tests and, behind the `testutils` feature, to
+//! downstream crates that test against the contract. Every fixture starts
+//! from the same ledger time and generates addresses in call order, so a
+//! given sequence of builder calls always produces the same state.
+//!
+//! Grants only take effect under an enabled ABAC policy, so every fixture
+//! installs `default_medical_access` with no conditions. Tests of the grant
+//! rules then need nothing beyond consent and the grant itself.
+//!
+//! ```ignore
+//! let fx = Fixture::new()
+//!     .with_admin()
+//!     .with_provider("dr_a")
+//!     .with_patient("pat_a")
+//!     .with_record(RecordType::Examination, "Qm...")
+//!     .build();
+//! fx.advance_time(60);
+//! assert_err(
+//!     fx.client.try_get_record(&fx.patient("pat_b"), &fx.record(0)),
+//!     ContractError::Unauthorized,
+//! );
+//! ```
+
+use alloc::string::String as StdString;
+use alloc::vec::Vec as StdVec;
+use core::fmt::Debug;
+
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::xdr::{ContractEventBody, ScVal};
+use soroban_sdk::{Address, Env, IntoVal, String, TryFromVal, Val, Vec};
+
+use crate::rbac::{
+    create_access_policy, AccessPolicy, CredentialType, PolicyConditions, SensitivityLevel,
+    TimeRestriction,
+};
+use crate::{
+    patient_index, ContractError, InvariantScope, RecordType, Role, VisionRecordsContract,
+    VisionRecordsContractClient,
+};
+
+/// Ledger timestamp every fixture starts at.
+pub const FIXTURE_START_TIME: u64 = 1_000;
+
+/// Builds a deployed, initialized contract with named users and records.
+///
+/// Builder steps run immediately and in order, so `with_admin` must come
+/// before any step that registers users, and `with_record` uses the most
+/// recently added provider and patient.
+pub struct Fixture {
+    inner: TestContract,
+}
+
+/// A deployed contract and the users and records created by a `Fixture`.
+pub struct TestContract {
+    pub env: Env,
+    pub contract_id: Address,
+    pub client: VisionRecordsContractClient<'static>,
+    admin: Option<Address>,
+    providers: StdVec<(StdString, Address)>,
+    patients: StdVec<(StdString, Address)>,
+    records: StdVec<u64>,
+}
+
+impl Default for Fixture {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Fixture {
+    /// Registers the contract in a fresh environment with all auths mocked.
+    pub fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(FIXTURE_START_TIME);
+
+        let contract_id = env.register(VisionRecordsContract, ());
+        let client = VisionRecordsContractClient::new(&env, &contract_id);
+        install_default_policy(&env, &contract_id);
+
+        Fixture {
+            inner: TestContract {
+                env,
+                contract_id,
+                client,
+                admin: None,
+                providers: StdVec::new(),
+                patients: StdVec::new(),
+                records: StdVec::new(),
+            },
+        }
+    }
+
+    /// Initializes the contract with a generated admin.
+    pub fn with_admin(mut self) -> Self {
+        let admin = Address::generate(&self.inner.env);
+        self.inner.client.initialize(&admin, &None);
+        self.inner.admin = Some(admin);
+        self
+    }
+
+    /// Registers an optometrist under `name`.
+    pub fn with_provider(self, name: &str) -> Self {
+        self.with_provider_role(name, Role::Optometrist)
+    }
+
+    /// Registers a provider with a specific role under `name`.
+    pub fn with_provider_role(mut self, name: &str, role: Role) -> Self {
+        let provider = self.register(name, role);
+        self.inner.providers.push((name.into(), provider));
+        self
+    }
+
+    /// Registers a patient under `name`.
+    pub fn with_patient(mut self, name: &str) -> Self {
+        let patient = self.register(name, Role::Patient);
+        self.inner.patients.push((name.into(), patient));
+        self
+    }
+
+    /// Adds a record written by the latest provider for the latest patient.
+    pub fn with_record(mut self, record_type: RecordType, data_hash: &str) -> Self {
+        let (_, provider) = self
+            .inner
+            .providers
+            .last()
+            .expect("with_record needs a provider");
+        let (_, patient) = self
+            .inner
+            .patients
+            .last()
+            .expect("with_record needs a patient");
+        let record_id = self.inner.client.add_record(
+            provider,
+            patient,
+            provider,
+            &record_type,
+            &String::from_str(&self.inner.env, data_hash),
+        );
+        self.inner.records.push(record_id);
+        self
+    }
+
+    pub fn build(self) -> TestContract {
+        self.inner
+    }
+
+    fn register(&self, name: &str, role: Role) -> Address {
+        let admin = self
+            .inner
+            .admin
+            .as_ref()
+            .expect("call with_admin before registering users");
+        let user = Address::generate(&self.inner.env);
+        self.inner.client.register_user(
+            admin,
+            &user,
+            &role,
+            &String::from_str(&self.inner.env, name),
+        );
+        user
+    }
+}
+
+impl TestContract {
+    pub fn admin(&self) -> Address {
+        self.admin.clone().expect("fixture has no admin")
+    }
+
+    pub fn provider(&self, name: &str) -> Address {
+        find(&self.providers, name)
+    }
+
+    pub fn patient(&self, name: &str) -> Address {
+        find(&self.patients, name)
+    }
+
+    /// The id of the `index`-th record added by `with_record`.
+    pub fn record(&self, index: usize) -> u64 {
+        self.records[index]
+    }
+
+    /// Moves the ledger clock forward by `seconds`.
+    pub fn advance_time(&self, seconds: u64) {
+        let now = self.env.ledger().timestamp();
+        self.env.ledger().set_timestamp(now.saturating_add(seconds));
+    }
+
+    /// Sets the ledger clock, which may move it backwards.
+    pub fn set_time(&self, timestamp: u64) {
+        self.env.ledger().set_timestamp(timestamp);
+    }
+
+    /// The data of the event the contract published under exactly `topics`
+    /// in the latest call, if it published one.
+    pub fn event_data<T: IntoVal<Env, Vec<Val>>>(&self, topics: T) -> Option<Val> {
+        let topics: Vec<Val> = topics.into_val(&self.env);
+        let expected: StdVec<ScVal> = topics
+            .iter()
+            .map(|topic| ScVal::try_from_val(&self.env, &topic).expect("topic converts to XDR"))
+            .collect();
+        let events = self
+            .env
+            .events()
+            .all()
+            .filter_by_contract(&self.contract_id);
+        events.events().iter().find_map(|event| {
+            let ContractEventBody::V0(body) = &event.body;
+            body.topics.iter().eq(expected.iter()).then(|| {
+                Val::try_from_val(&self.env, &body.data).expect("event data converts from XDR")
+            })
+        })
+    }
+
+    /// Whether the contract published an event under exactly `topics` in
+    /// the latest call.
+    pub fn published<T: IntoVal<Env, Vec<Val>>>(&self, topics: T) -> bool {
+        self.event_data(topics).is_some()
+    }
+
+    /// Asserts `check_invariants` finds nothing for every fixture user, the
+    /// records in each patient's index, and the fixture's own records. Run
+    /// it as the post-condition of multi-step scenarios.
+    pub fn assert_invariants(&self) {
+        let admin = self.admin();
+        let mut record_ids: StdVec<u64> = self.records.clone();
+        for (name, user) in self.patients.iter().chain(self.providers.iter()) {
+            let scope = InvariantScope::Patient(user.clone());
+            let violations = self.client.check_invariants(&admin, &scope);
+            assert!(
+                violations.is_empty(),
+                "invariants violated for {}: {:?}",
+                name,
+                violations
+            );
+            let indexed = self.env.as_contract(&self.contract_id, || {
+                patient_index::get_records(&self.env, user)
+            });
+            for record_id in indexed.iter() {
+                if !record_ids.contains(&record_id) {
+                    record_ids.push(record_id);
+                }
+            }
+        }
+        for record_id in record_ids {
+            let scope = InvariantScope::Record(record_id);
+            let violations = self.client.check_invariants(&admin, &scope);
+            assert!(
+                violations.is_empty(),
+                "invariants violated for record {}: {:?}",
+                record_id,
+                violations
+            );
+        }
+    }
+}
+
+fn find(named: &[(StdString, Address)], name: &str) -> Address {
+    named
+        .iter()
+        .find(|(n, _)| n == name)
+        .map(|(_, address)| address.clone())
+        .unwrap_or_else(|| panic!("no fixture user named {}", name))
+}
+
+/// Installs `default_medical_access` with no conditions, as every fixture
+/// does. Tests that register the contract by hand call this so grants take
+/// effect.
+pub fn install_default_policy(env: &Env, contract_id: &Address) {
+    env.as_contract(contract_id, || {
+        create_access_policy(
+            env,
+            AccessPolicy {
+                id: String::from_str(env, "default_medical_access"),
+                name: String::from_str(env, "Allow all"),
+                conditions: PolicyConditions {
+                    required_role: Role::None,
+                    time_restriction: TimeRestriction::None,
+                    required_credential: CredentialType::None,
+                    min_sensitivity_level: SensitivityLevel::Public,
+                    consent_required: false,
+                },
+                enabled: true,
+            },
+        );
+    });
+}
+
+/// Asserts that a `try_` client call failed with `expected`.
+pub fn assert_err<T: Debug, E: Debug>(
+    result: Result<T, Result<ContractError, E>>,
+    expected: ContractError,
+) {
+    match result {
+        Err(Ok(err)) => assert_eq!(err, expected),
+        other => panic!("expected Err({:?}), got {:?}", expected, other),
+    }
+}
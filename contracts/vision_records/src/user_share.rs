@@ -0,0 +1,112 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::errors::ContractError;
+use crate::expiry;
+use crate::AccessLevel;
+
+// ── Storage keys ──────────────────────────────────────────────
+const SHARES_GIVEN: Symbol = symbol_short!("SHR_GIVN");
+const SHARES_RECEIVED: Symbol = symbol_short!("SHR_RCVD");
+
+/// Maximum number of records one share may cover.
+pub const MAX_SHARE_RECORDS: u32 = 20;
+/// Maximum number of unexpired shares a patient may have given, and of
+/// patients a user may hold shares from.
+pub const MAX_SHARES: u32 = 50;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a user's share lists.
+fn extend_ttl_share_key(env: &Env, key: &(Symbol, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Records a patient shared with another registered user, e.g. a parent
+/// sharing exam results with an adult child coordinating their care. Each
+/// record is granted through an ordinary record-level grant; this entry
+/// only remembers which records went to whom.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserShare {
+    pub patient: Address,
+    pub grantee: Address,
+    pub record_ids: Vec<u64>,
+    pub level: AccessLevel,
+    pub shared_at: u64,
+    pub expires_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn given_key(patient: &Address) -> (Symbol, Address) {
+    (SHARES_GIVEN, patient.clone())
+}
+
+pub fn received_key(user: &Address) -> (Symbol, Address) {
+    (SHARES_RECEIVED, user.clone())
+}
+
+/// Shares the patient has given, oldest first, including lapsed ones not
+/// yet pruned.
+pub fn get_given(env: &Env, patient: &Address) -> Vec<UserShare> {
+    env.storage()
+        .persistent()
+        .get(&given_key(patient))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Patients who have shared records with `user`.
+pub fn get_sharers(env: &Env, user: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&received_key(user))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Stores a new share, dropping the patient's lapsed shares first. Fails
+/// with `ShareLimitReached` when the patient already has `MAX_SHARES`
+/// unexpired shares, or the grantee holds shares from `MAX_SHARES` other
+/// patients.
+pub fn add(env: &Env, share: &UserShare) -> Result<(), ContractError> {
+    let mut given = Vec::new(env);
+    for existing in get_given(env, &share.patient).iter() {
+        if !expiry::is_expired(env, existing.expires_at) {
+            given.push_back(existing);
+        }
+    }
+    if given.len() >= MAX_SHARES {
+        return Err(ContractError::ShareLimitReached);
+    }
+
+    let mut sharers = Vec::new(env);
+    for sharer in get_sharers(env, &share.grantee).iter() {
+        if sharer != share.patient && holds_share_from(env, &share.grantee, &sharer) {
+            sharers.push_back(sharer);
+        }
+    }
+    if sharers.len() >= MAX_SHARES {
+        return Err(ContractError::ShareLimitReached);
+    }
+    sharers.push_back(share.patient.clone());
+    given.push_back(share.clone());
+
+    let key = given_key(&share.patient);
+    env.storage().persistent().set(&key, &given);
+    extend_ttl_share_key(env, &key);
+    let key = received_key(&share.grantee);
+    env.storage().persistent().set(&key, &sharers);
+    extend_ttl_share_key(env, &key);
+    Ok(())
+}
+
+/// Whether `sharer` has an unexpired share with `user`.
+fn holds_share_from(env: &Env, user: &Address, sharer: &Address) -> bool {
+    get_given(env, sharer)
+        .iter()
+        .any(|share| share.grantee == *user && !expiry::is_expired(env, share.expires_at))
+}
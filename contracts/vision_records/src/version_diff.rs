@@ -0,0 +1,55 @@
+use soroban_sdk::{symbol_short, Env, String, Symbol};
+
+// ── Storage keys ──────────────────────────────────────────────
+const VERSION_DIFF: Symbol = symbol_short!("VER_DIFF");
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a version diff pointer.
+fn extend_ttl_diff_key(env: &Env, key: &(Symbol, u64, u32, u32)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// A diff is stored once per unordered version pair: `(2, 5)` and `(5, 2)`
+// name the same diff, always keyed lowest version first.
+
+/// The pair `(a, b)` with the lower version first.
+pub fn pair(a: u32, b: u32) -> (u32, u32) {
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+pub fn diff_key(record_id: u64, from_version: u32, to_version: u32) -> (Symbol, u64, u32, u32) {
+    let (low, high) = pair(from_version, to_version);
+    (VERSION_DIFF, record_id, low, high)
+}
+
+pub fn get(env: &Env, record_id: u64, from_version: u32, to_version: u32) -> Option<String> {
+    env.storage()
+        .persistent()
+        .get(&diff_key(record_id, from_version, to_version))
+}
+
+/// Points the version pair at `diff_hash`. Returns the previous pointer,
+/// if any; callers decide who may overwrite one.
+pub fn set(
+    env: &Env,
+    record_id: u64,
+    from_version: u32,
+    to_version: u32,
+    diff_hash: &String,
+) -> Option<String> {
+    let previous = get(env, record_id, from_version, to_version);
+    let key = diff_key(record_id, from_version, to_version);
+    env.storage().persistent().set(&key, diff_hash);
+    extend_ttl_diff_key(env, &key);
+    previous
+}
@@ -0,0 +1,90 @@
+use soroban_sdk::{symbol_short, Env, Map, Symbol};
+
+use crate::ContractError;
+
+// ── Storage keys ──────────────────────────────────────────────
+const VERSION_PINS: Symbol = symbol_short!("VER_PIN");
+
+/// Maximum number of named pins a single record may carry.
+pub const MAX_PINS_PER_RECORD: u32 = 10;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a record's version pins.
+fn extend_ttl_pins_key(env: &Env, key: &(Symbol, u64)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// A pin is a named pointer from a label (e.g. `claim_2024_07`) to one
+// version of a record, so outside parties can cite a version that later
+// updates will not move. Labels are unique per record; several labels may
+// point at the same version.
+
+pub fn pins_key(record_id: u64) -> (Symbol, u64) {
+    (VERSION_PINS, record_id)
+}
+
+/// The record's pins, label → version.
+pub fn get_pins(env: &Env, record_id: u64) -> Map<Symbol, u32> {
+    env.storage()
+        .persistent()
+        .get(&pins_key(record_id))
+        .unwrap_or(Map::new(env))
+}
+
+fn set_pins(env: &Env, record_id: u64, pins: &Map<Symbol, u32>) {
+    let key = pins_key(record_id);
+    if pins.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, pins);
+        extend_ttl_pins_key(env, &key);
+    }
+}
+
+pub fn get_pinned_version(env: &Env, record_id: u64, label: &Symbol) -> Option<u32> {
+    get_pins(env, record_id).get(label.clone())
+}
+
+/// Whether any label points at `version` of the record.
+pub fn is_pinned(env: &Env, record_id: u64, version: u32) -> bool {
+    get_pins(env, record_id).values().contains(version)
+}
+
+/// Fails with `VersionPinned` if the version is pinned. Anything that
+/// would remove or rewrite a stored version must call this first.
+pub fn require_unpinned(env: &Env, record_id: u64, version: u32) -> Result<(), ContractError> {
+    if is_pinned(env, record_id, version) {
+        return Err(ContractError::VersionPinned);
+    }
+    Ok(())
+}
+
+pub fn pin(env: &Env, record_id: u64, version: u32, label: &Symbol) -> Result<(), ContractError> {
+    let mut pins = get_pins(env, record_id);
+    if pins.contains_key(label.clone()) {
+        return Err(ContractError::AlreadyExists);
+    }
+    if pins.len() >= MAX_PINS_PER_RECORD {
+        return Err(ContractError::PinLimitReached);
+    }
+    pins.set(label.clone(), version);
+    set_pins(env, record_id, &pins);
+    Ok(())
+}
+
+/// Removes the pin and returns the version it pointed at.
+pub fn unpin(env: &Env, record_id: u64, label: &Symbol) -> Result<u32, ContractError> {
+    let mut pins = get_pins(env, record_id);
+    let version = pins
+        .get(label.clone())
+        .ok_or(ContractError::RecordNotFound)?;
+    pins.remove(label.clone());
+    set_pins(env, record_id, &pins);
+    Ok(version)
+}
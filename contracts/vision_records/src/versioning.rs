@@ -0,0 +1,466 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::bounded::MAX_BOUNDED_ITEMS;
+use crate::counters;
+use crate::errors::ContractError;
+use crate::version_diff;
+use crate::VisionRecord;
+
+// ── Storage keys ──────────────────────────────────────────────
+const REC_VERSION: Symbol = symbol_short!("REC_VER");
+const VERSION_COUNT: Symbol = symbol_short!("VER_CNT");
+const SUPERSEDED_BY: Symbol = symbol_short!("SUPER_BY");
+const PATIENT_VERSIONS: Symbol = symbol_short!("VER_TOT");
+const RECORD_SUMMARY: Symbol = symbol_short!("REC_SUM");
+const RECORD_AUTHORS: Symbol = symbol_short!("REC_AUTH");
+
+/// Maximum number of versions a single comparison may walk, kept to the
+/// bounded-query cap so one comparison fits in an invocation's read limit.
+pub const MAX_COMPARE_SPAN: u32 = MAX_BOUNDED_ITEMS;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a record version key.
+fn extend_ttl_version_key(env: &Env, key: &(Symbol, u64, u32)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+/// Extends the time-to-live (TTL) for record-id keyed entries.
+fn extend_ttl_count_key(env: &Env, key: &(Symbol, u64)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+/// Extends the time-to-live (TTL) for a patient's version total.
+fn extend_ttl_patient_key(env: &Env, key: &(Symbol, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A snapshot of a record's content at one point in its history.
+/// Version numbers start at 1 (the content the record was created with).
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RecordVersion {
+    pub record_id: u64,
+    pub version: u32,
+    pub data_hash: String,
+    pub key_version: Option<String>,
+    /// Algorithm that produced `data_hash`, or `unknown` if untagged.
+    pub hash_alg: Symbol,
+    pub modified_by: Address,
+    pub modified_at: u64,
+    /// Set on the terminal version written when the record is superseded.
+    pub superseded_by: Option<u64>,
+    /// Set once an admin has redacted this version; `data_hash` then holds
+    /// `redaction::TOMBSTONE`.
+    pub redacted: bool,
+}
+
+/// Summary of how a record changed between two versions.
+///
+/// `from_version` and `to_version` are reported as requested; when
+/// `from_version > to_version` the range is walked in ascending order and
+/// `reversed` is set.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordComparison {
+    pub record_id: u64,
+    pub from_version: u32,
+    pub to_version: u32,
+    /// Whether the content differs between the two endpoints.
+    pub changed: bool,
+    pub versions_between: u32,
+    pub elapsed_seconds: u64,
+    pub same_author: bool,
+    /// Number of single-step transitions in the range that changed the hash.
+    pub intermediate_change_count: u32,
+    pub reversed: bool,
+    /// Pointer to a rendered diff of the two versions, if one was attached.
+    pub diff_hash: Option<String>,
+}
+
+/// Revision statistics for a record, kept alongside its history so list
+/// views need not fetch it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordSummary {
+    pub record_id: u64,
+    pub version_count: u32,
+    pub last_modified_by: Address,
+    pub last_modified_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn version_key(record_id: u64, version: u32) -> (Symbol, u64, u32) {
+    (REC_VERSION, record_id, version)
+}
+
+pub fn version_count_key(record_id: u64) -> (Symbol, u64) {
+    (VERSION_COUNT, record_id)
+}
+
+pub fn summary_key(record_id: u64) -> (Symbol, u64) {
+    (RECORD_SUMMARY, record_id)
+}
+
+pub fn authors_key(record_id: u64) -> (Symbol, u64) {
+    (RECORD_AUTHORS, record_id)
+}
+
+pub fn superseded_by_key(record_id: u64) -> (Symbol, u64) {
+    (SUPERSEDED_BY, record_id)
+}
+
+pub fn patient_versions_key(patient: &Address) -> (Symbol, Address) {
+    (PATIENT_VERSIONS, patient.clone())
+}
+
+/// Returns the number of versions stored across all of a patient's
+/// records. Versions written before the total was kept are not counted.
+pub fn get_patient_version_total(env: &Env, patient: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&patient_versions_key(patient))
+        .unwrap_or(0)
+}
+
+fn set_patient_version_total(env: &Env, patient: &Address, total: u64) {
+    let key = patient_versions_key(patient);
+    env.storage().persistent().set(&key, &total);
+    extend_ttl_patient_key(env, &key);
+}
+
+/// Moves `count` versions from one patient's total to another's, for a
+/// record that changes hands.
+pub fn move_patient_versions(env: &Env, from: &Address, to: &Address, count: u32) {
+    let count = u64::from(count);
+    let remaining = get_patient_version_total(env, from).saturating_sub(count);
+    set_patient_version_total(env, from, remaining);
+    let total = get_patient_version_total(env, to).saturating_add(count);
+    set_patient_version_total(env, to, total);
+}
+
+/// Returns the record that replaced `record_id`, if it was superseded.
+pub fn get_superseded_by(env: &Env, record_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&superseded_by_key(record_id))
+}
+
+pub fn is_superseded(env: &Env, record_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&superseded_by_key(record_id))
+}
+
+/// Returns the number of versions stored for a record.
+pub fn get_version_count(env: &Env, record_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&version_count_key(record_id))
+        .unwrap_or(0)
+}
+
+/// Returns a record's revision statistics. Records whose history predates
+/// the stored summary have it derived from the history instead.
+pub fn get_summary(env: &Env, record_id: u64) -> Option<RecordSummary> {
+    env.storage()
+        .persistent()
+        .get(&summary_key(record_id))
+        .or_else(|| {
+            let count = get_version_count(env, record_id);
+            get_version(env, record_id, count).map(|last| summary_of(&last))
+        })
+}
+
+/// The summary of a history whose latest version is `last`.
+fn summary_of(last: &RecordVersion) -> RecordSummary {
+    RecordSummary {
+        record_id: last.record_id,
+        version_count: last.version,
+        last_modified_by: last.modified_by.clone(),
+        last_modified_at: last.modified_at,
+    }
+}
+
+/// Retrieves a specific version of a record.
+pub fn get_version(env: &Env, record_id: u64, version: u32) -> Option<RecordVersion> {
+    env.storage()
+        .persistent()
+        .get(&version_key(record_id, version))
+}
+
+/// Everyone but the creator who wrote a version of a record, each listed
+/// once in the order they first wrote one. The creator is version 1's
+/// author and is left out, so creating a record writes nothing here.
+pub fn get_later_authors(env: &Env, record_id: u64) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&authors_key(record_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Whether `author` wrote any version of a record.
+pub fn is_author(env: &Env, record_id: u64, author: &Address) -> bool {
+    get_version(env, record_id, 1).is_some_and(|first| first.modified_by == *author)
+        || get_later_authors(env, record_id).contains(author)
+}
+
+/// Adds the author of `entry` to the record's later authors unless they
+/// are the creator or already listed, so the entry grows with distinct
+/// authors rather than with versions.
+fn add_author(env: &Env, entry: &RecordVersion) {
+    if entry.version == 1 || is_author(env, entry.record_id, &entry.modified_by) {
+        return;
+    }
+    let mut authors = get_later_authors(env, entry.record_id);
+    authors.push_back(entry.modified_by.clone());
+    let key = authors_key(entry.record_id);
+    env.storage().persistent().set(&key, &authors);
+    extend_ttl_count_key(env, &key);
+}
+
+/// Returns a timestamp that never precedes `previous`.
+///
+/// The ledger clock is not guaranteed to move forward between calls (test
+/// manipulation, cross-call ordering), so a timestamp at or before the
+/// previous one is clamped to `previous + 1`. This keeps version history
+/// strictly ordered, which the time-travel lookup relies on.
+pub fn monotonic_timestamp(previous: u64, now: u64) -> u64 {
+    if now <= previous {
+        previous.saturating_add(1)
+    } else {
+        now
+    }
+}
+
+/// Appends a new version to a record's history and returns it.
+///
+/// `modified_at` is clamped with `monotonic_timestamp` against the previous
+/// version, so history timestamps are strictly increasing even if the
+/// ledger clock moves backwards between updates. The version is added to
+/// `patient`'s version total.
+pub fn append_version(
+    env: &Env,
+    record_id: u64,
+    patient: &Address,
+    data_hash: String,
+    key_version: Option<String>,
+    hash_alg: Symbol,
+    modified_by: Address,
+    modified_at: u64,
+) -> Result<RecordVersion, ContractError> {
+    push_version(
+        env,
+        record_id,
+        patient,
+        data_hash,
+        key_version,
+        hash_alg,
+        modified_by,
+        modified_at,
+        None,
+    )
+}
+
+/// Writes version 1 from `record` itself when its history is empty, as it
+/// is for records stored before versions were kept, so the next version
+/// follows the content the record was created with.
+pub fn seed_initial_version(env: &Env, record: &VisionRecord) -> Result<(), ContractError> {
+    if get_version_count(env, record.id) > 0 {
+        return Ok(());
+    }
+    push_version(
+        env,
+        record.id,
+        &record.patient,
+        record.data_hash.clone(),
+        record.key_version.clone(),
+        record.hash_alg.clone(),
+        record.provider.clone(),
+        record.created_at,
+        None,
+    )?;
+    Ok(())
+}
+
+/// Marks `record_id` as superseded by `new_record_id` and appends a
+/// terminal version recording it. The terminal version keeps the last
+/// content unchanged; no further versions may follow it.
+pub fn mark_superseded(
+    env: &Env,
+    record_id: u64,
+    patient: &Address,
+    new_record_id: u64,
+    modified_by: Address,
+    modified_at: u64,
+) -> Result<RecordVersion, ContractError> {
+    let last = get_version(env, record_id, get_version_count(env, record_id))
+        .ok_or(ContractError::RecordNotFound)?;
+
+    let key = superseded_by_key(record_id);
+    env.storage().persistent().set(&key, &new_record_id);
+    extend_ttl_count_key(env, &key);
+
+    push_version(
+        env,
+        record_id,
+        patient,
+        last.data_hash,
+        last.key_version,
+        last.hash_alg,
+        modified_by,
+        modified_at,
+        Some(new_record_id),
+    )
+}
+
+fn push_version(
+    env: &Env,
+    record_id: u64,
+    patient: &Address,
+    data_hash: String,
+    key_version: Option<String>,
+    hash_alg: Symbol,
+    modified_by: Address,
+    modified_at: u64,
+    superseded_by: Option<u64>,
+) -> Result<RecordVersion, ContractError> {
+    let count = get_version_count(env, record_id);
+    let modified_at = match get_version(env, record_id, count) {
+        Some(prev) => monotonic_timestamp(prev.modified_at, modified_at),
+        None => modified_at,
+    };
+
+    let next = counters::checked_next_u32(count)?;
+    let entry = RecordVersion {
+        record_id,
+        version: next,
+        data_hash,
+        key_version,
+        hash_alg,
+        modified_by,
+        modified_at,
+        superseded_by,
+        redacted: false,
+    };
+
+    add_author(env, &entry);
+
+    let key = version_key(record_id, next);
+    env.storage().persistent().set(&key, &entry);
+    extend_ttl_version_key(env, &key);
+
+    let count_key = version_count_key(record_id);
+    env.storage().persistent().set(&count_key, &next);
+    extend_ttl_count_key(env, &count_key);
+
+    // Every version is written here, so the summary never drifts from the
+    // history. Anything that removes versions must rewrite it too.
+    let summary_key = summary_key(record_id);
+    env.storage()
+        .persistent()
+        .set(&summary_key, &summary_of(&entry));
+    extend_ttl_count_key(env, &summary_key);
+
+    let total = get_patient_version_total(env, patient).saturating_add(1);
+    set_patient_version_total(env, patient, total);
+
+    Ok(entry)
+}
+
+/// Overwrites a stored version in place. Only redaction uses this; every
+/// other change to history appends a version.
+pub fn replace_version(env: &Env, entry: &RecordVersion) {
+    let key = version_key(entry.record_id, entry.version);
+    env.storage().persistent().set(&key, entry);
+    extend_ttl_version_key(env, &key);
+}
+
+/// Returns the latest version that was current at `timestamp`, if any.
+///
+/// Version timestamps are strictly increasing (see `monotonic_timestamp`),
+/// so this binary-searches the history and reads about log2(count)
+/// versions.
+#[allow(clippy::arithmetic_side_effects)]
+pub fn get_version_at(env: &Env, record_id: u64, timestamp: u64) -> Option<RecordVersion> {
+    // Invariant: versions below `low` were current by `timestamp`, versions
+    // from `high` on were not
+    let mut low = 1u32;
+    let mut high = get_version_count(env, record_id).saturating_add(1);
+    let mut found = None;
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let entry = get_version(env, record_id, mid)?;
+        if entry.modified_at <= timestamp {
+            low = mid + 1;
+            found = Some(entry);
+        } else {
+            high = mid;
+        }
+    }
+    found
+}
+
+/// Compares two versions of a record by walking every version between them.
+///
+/// Hashes are compared after `plaintext` has been applied, so re-encryption
+/// under a new key version is not counted as a content change. The walk is
+/// bounded by `MAX_COMPARE_SPAN`.
+pub fn compare_versions(
+    env: &Env,
+    record_id: u64,
+    from_version: u32,
+    to_version: u32,
+    plaintext: impl Fn(&RecordVersion) -> String,
+) -> Result<RecordComparison, ContractError> {
+    let reversed = from_version > to_version;
+    let (low, high) = if reversed {
+        (to_version, from_version)
+    } else {
+        (from_version, to_version)
+    };
+
+    let span = high.saturating_sub(low);
+    if span > MAX_COMPARE_SPAN {
+        return Err(ContractError::VersionSpanTooLarge);
+    }
+
+    let first = get_version(env, record_id, low).ok_or(ContractError::RecordNotFound)?;
+    let mut previous = first.clone();
+    let mut previous_hash = plaintext(&first);
+    let first_hash = previous_hash.clone();
+    let mut intermediate_change_count: u32 = 0;
+
+    for version in low.saturating_add(1)..=high {
+        let current = get_version(env, record_id, version).ok_or(ContractError::RecordNotFound)?;
+        let current_hash = plaintext(&current);
+        if current_hash != previous_hash {
+            intermediate_change_count = intermediate_change_count.saturating_add(1);
+        }
+        previous = current;
+        previous_hash = current_hash;
+    }
+
+    Ok(RecordComparison {
+        record_id,
+        from_version,
+        to_version,
+        changed: first_hash != previous_hash,
+        versions_between: span,
+        elapsed_seconds: previous.modified_at.saturating_sub(first.modified_at),
+        same_author: first.modified_by == previous.modified_by,
+        intermediate_change_count,
+        reversed,
+        diff_hash: version_diff::get(env, record_id, low, high),
+    })
+}
@@ -0,0 +1,96 @@
+use soroban_sdk::{contracttype, symbol_short, Env, Symbol, Vec};
+
+use crate::{RecordType, Role};
+
+// ── Storage keys ──────────────────────────────────────────────
+const ROLE_HIDDEN_TYPES: Symbol = symbol_short!("ROLE_HID");
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for a role's hidden record types.
+fn extend_ttl_role_key(env: &Env, key: &(Symbol, Role)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Whether a role may see records of one type.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleVisibility {
+    pub record_type: RecordType,
+    pub visible: bool,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// Only the hidden record types are stored, so every role sees every
+// record type until an admin hides one.
+
+pub fn role_key(role: &Role) -> (Symbol, Role) {
+    (ROLE_HIDDEN_TYPES, role.clone())
+}
+
+/// Every record type, in declaration order.
+pub fn all_record_types(env: &Env) -> Vec<RecordType> {
+    Vec::from_array(
+        env,
+        [
+            RecordType::Examination,
+            RecordType::Prescription,
+            RecordType::Diagnosis,
+            RecordType::Treatment,
+            RecordType::Surgery,
+            RecordType::LabResult,
+            RecordType::PatientNote,
+        ],
+    )
+}
+
+pub fn get_hidden_types(env: &Env, role: &Role) -> Vec<RecordType> {
+    env.storage()
+        .persistent()
+        .get(&role_key(role))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn is_visible(env: &Env, role: &Role, record_type: &RecordType) -> bool {
+    !get_hidden_types(env, role).contains(record_type)
+}
+
+pub fn set_visible(env: &Env, role: &Role, record_type: &RecordType, visible: bool) {
+    let key = role_key(role);
+    let mut hidden = get_hidden_types(env, role);
+    let index = hidden.first_index_of(record_type);
+
+    match (visible, index) {
+        (true, Some(i)) => {
+            hidden.remove(i);
+        }
+        (false, None) => hidden.push_back(record_type.clone()),
+        _ => return,
+    }
+
+    if hidden.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &hidden);
+        extend_ttl_role_key(env, &key);
+    }
+}
+
+/// The role's visibility for every record type.
+pub fn get_matrix(env: &Env, role: &Role) -> Vec<RoleVisibility> {
+    let hidden = get_hidden_types(env, role);
+    let mut matrix = Vec::new(env);
+    for record_type in all_record_types(env).iter() {
+        matrix.push_back(RoleVisibility {
+            visible: !hidden.contains(&record_type),
+            record_type,
+        });
+    }
+    matrix
+}
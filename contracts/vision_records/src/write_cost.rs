@@ -0,0 +1,116 @@
+use soroban_sdk::contracttype;
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Operations whose storage cost `estimate_write_cost` can estimate.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WriteCostAction {
+    AddRecord,
+    UpdateRecord,
+    GrantAccess,
+    RegisterUser,
+}
+
+/// The ledger entries an operation writes, for fee budgeting.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WriteCostEstimate {
+    /// Entries the operation creates.
+    pub new_entries: u32,
+    /// Existing entries the operation rewrites.
+    pub modified_entries: u32,
+    /// Approximate bytes added to the ledger: the XDR size of the new
+    /// entries plus the growth of the modified ones.
+    pub approx_bytes: u32,
+}
+
+/// What one operation writes. `payload_copies` is the number of entries
+/// storing the caller's payload (a data hash or a name) verbatim.
+struct WriteLayout {
+    new_entries: u32,
+    modified_entries: u32,
+    fixed_bytes: u32,
+    payload_copies: u32,
+}
+
+// ── Layout table ─────────────────────────────────────────────
+//
+// Counts describe the steady state: the patient, provider and grantee
+// involved already hold data here, so their indexes and counters exist and
+// are modified rather than created. On a first write those entries are new
+// instead, and once a patient's activity feed has wrapped its entry is
+// rewritten rather than created. Instance storage is a single entry. Any
+// change to what these operations store must update this table; the
+// `test_write_cost` tests diff real storage against it.
+
+/// `add_record` at the default priority. New: the record, version 1, the
+/// version count, the record summary, a commitment log entry and an
+/// activity entry. Modified: the provider's record counter shard, the
+/// patient's version total, the provider's stats and monthly count, the
+/// provider's record index, the patient's record list, the commitment
+/// head, the activity count, the month's anonymized count for the record
+/// type and the patient's record summary.
+const ADD_RECORD: WriteLayout = WriteLayout {
+    new_entries: 6,
+    modified_entries: 10,
+    fixed_bytes: 2_000,
+    payload_copies: 2,
+};
+
+/// `update_record` by an existing author. New: the version, an activity
+/// entry, the audit entry and its record, actor, patient and patient
+/// sequence indexes. Modified: the instance (audit counter), the record,
+/// the version count, the record summary, the patient's version total, the
+/// provider's stats, the activity count and the patient's audit count. An
+/// author other than the creator also writes the record's later-author
+/// list the first time, and the patient's first audited entry creates
+/// their audit count.
+const UPDATE_RECORD: WriteLayout = WriteLayout {
+    new_entries: 7,
+    modified_entries: 8,
+    fixed_bytes: 1_720,
+    payload_copies: 1,
+};
+
+/// `grant_access` by the patient to a new grantee for treatment. New: the
+/// grant, its revision and an activity entry. Modified: the instance
+/// (compliance log counter), the patient's grantee list, the grantee's
+/// patient index, the activity count and the compliance log page.
+const GRANT_ACCESS: WriteLayout = WriteLayout {
+    new_entries: 3,
+    modified_entries: 5,
+    fixed_bytes: 950,
+    payload_copies: 0,
+};
+
+/// `register_user`, without a registration auto-grant. New: the user,
+/// their role assignment and their role history.
+const REGISTER_USER: WriteLayout = WriteLayout {
+    new_entries: 3,
+    modified_entries: 0,
+    fixed_bytes: 600,
+    payload_copies: 1,
+};
+
+fn layout(action: WriteCostAction) -> &'static WriteLayout {
+    match action {
+        WriteCostAction::AddRecord => &ADD_RECORD,
+        WriteCostAction::UpdateRecord => &UPDATE_RECORD,
+        WriteCostAction::GrantAccess => &GRANT_ACCESS,
+        WriteCostAction::RegisterUser => &REGISTER_USER,
+    }
+}
+
+/// Estimates the storage `action` writes for a payload of about
+/// `approx_payload_len` bytes.
+pub fn estimate(action: WriteCostAction, approx_payload_len: u32) -> WriteCostEstimate {
+    let layout = layout(action);
+    WriteCostEstimate {
+        new_entries: layout.new_entries,
+        modified_entries: layout.modified_entries,
+        approx_bytes: layout
+            .fixed_bytes
+            .saturating_add(layout.payload_copies.saturating_mul(approx_payload_len)),
+    }
+}
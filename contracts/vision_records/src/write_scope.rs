@@ -0,0 +1,42 @@
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+// ── Storage keys ──────────────────────────────────────────────
+const APPEND_ONLY: Symbol = symbol_short!("GRT_APND");
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Extends the time-to-live (TTL) for an append-only flag.
+fn extend_ttl_flag_key(env: &Env, key: &(Symbol, Address, Address)) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+// ── Storage Functions ────────────────────────────────────────
+//
+// A `Write` or `Full` access grant normally lets the grantee both add and
+// modify the patient's records. The append-only flag, kept beside the
+// grant, narrows it to adding new records. Only flagged grants have an
+// entry, so grants stored before the flag existed keep full write.
+
+pub fn append_only_key(patient: &Address, grantee: &Address) -> (Symbol, Address, Address) {
+    (APPEND_ONLY, patient.clone(), grantee.clone())
+}
+
+pub fn is_append_only(env: &Env, patient: &Address, grantee: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&append_only_key(patient, grantee))
+}
+
+/// Sets or clears the flag on the grant just stored for `patient` → `grantee`.
+pub fn set_append_only(env: &Env, patient: &Address, grantee: &Address, append_only: bool) {
+    let key = append_only_key(patient, grantee);
+    if append_only {
+        env.storage().persistent().set(&key, &true);
+        extend_ttl_flag_key(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
@@ -0,0 +1,82 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::rbac::{self, Permission};
+use crate::{deactivation, expiry, grant_revision, temp_storage};
+
+// ── Storage keys ──────────────────────────────────────────────
+const WRITE_SESSION: Symbol = symbol_short!("WR_SESS");
+
+/// Longest write session a provider can open in one call.
+pub const MAX_WRITE_SESSION_TTL_SECONDS: u64 = 3_600;
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A provider's standing permission to write new versions of one record,
+/// checked in full once when opened.
+///
+/// `grant_revision` and `access_epoch` are the patient's counters at
+/// opening; any change to them (a revoked grant or consent, a patient
+/// freeze) ends the session. Held in temporary storage.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WriteSession {
+    pub caller: Address,
+    pub record_id: u64,
+    pub patient: Address,
+    pub opened_at: u64,
+    pub expires_at: u64,
+    pub grant_revision: u64,
+    pub access_epoch: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn session_key(caller: &Address, record_id: u64) -> (Symbol, Address, u64) {
+    (WRITE_SESSION, caller.clone(), record_id)
+}
+
+/// Stores a session until slightly past its expiry, replacing any
+/// earlier one for the same caller and record.
+pub fn set_session(env: &Env, session: &WriteSession) {
+    let key = session_key(&session.caller, session.record_id);
+    let lifetime = session.expires_at.saturating_sub(session.opened_at);
+    let ttl =
+        temp_storage::ledgers_for_seconds(lifetime).saturating_add(temp_storage::TEMP_TTL_SHORT);
+    temp_storage::set_temp(env, &key, session, ttl);
+}
+
+pub fn remove_session(env: &Env, caller: &Address, record_id: u64) {
+    temp_storage::remove_temp(env, &session_key(caller, record_id));
+}
+
+/// Returns the session if it is still usable: unexpired, the caller not
+/// frozen or deactivated since, and the patient's access counters
+/// unchanged. These are all single reads; none of the grant or delegation
+/// resolution behind the original check is repeated.
+pub fn get_live(env: &Env, caller: &Address, record_id: u64) -> Option<WriteSession> {
+    let session: WriteSession = temp_storage::get_temp(env, &session_key(caller, record_id))?;
+    let live = !expiry::is_expired(env, session.expires_at)
+        && !rbac::is_frozen(env, caller)
+        && !deactivation::is_deactivated(env, caller)
+        && grant_revision::get_revision(env, &session.patient, caller) == session.grant_revision
+        && grant_revision::get_epoch(env, &session.patient) == session.access_epoch;
+    live.then_some(session)
+}
+
+/// When the delegation letting `caller` write `provider`'s records runs
+/// out, or `None` if it never does. Delegations have no revocation, only
+/// expiry, so a session opened through one is capped at this.
+pub fn delegation_expiry(env: &Env, provider: &Address, caller: &Address) -> Option<u64> {
+    let role = rbac::get_active_delegation(env, provider, caller)
+        .filter(|d| rbac::get_base_permissions(env, &d.role).contains(&Permission::WriteRecord))
+        .map(|d| d.expires_at);
+    let scoped = rbac::get_active_scoped_delegation(env, provider, caller)
+        .filter(|d| d.permissions.contains(&Permission::WriteRecord))
+        .map(|d| d.expires_at);
+    // 0 means the delegation never expires
+    match (role, scoped) {
+        (Some(0), _) | (_, Some(0)) => None,
+        (Some(a), Some(b)) => Some(a.max(b)),
+        (a, b) => a.or(b),
+    }
+}
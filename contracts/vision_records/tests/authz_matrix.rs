@@ -0,0 +1,304 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+//! Authorization regression matrix.
+//!
+//! Every (persona × action × target) combination runs against a freshly
+//! built contract and is compared with the [`EXPECTED`] table below. A
+//! change to any authorization rule flips at least one cell, and the test
+//! reports every cell that moved at once.
+//!
+//! An action counts as allowed only if the call succeeds *and* needed no
+//! signature other than the persona's, so endpoints that authenticate a
+//! fixed party (e.g. `revoke_access` requiring the patient) are judged by
+//! who would have to sign. Actions with an [`AuthAction`] counterpart must
+//! also agree with `authorize`.
+//!
+//! Adding a persona is one row in [`EXPECTED`] and one arm in
+//! [`World::persona`]; adding an action is one entry in [`ACTIONS`], one
+//! column in the table and one arm in [`World::run`].
+
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, String};
+use vision_records::deactivation::DeactivationCascade;
+use vision_records::testutils::{Fixture, TestContract};
+use vision_records::{AccessLevel, AuthAction, ConsentType, RecordType, Role};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+const DAY: u64 = 86_400;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Persona {
+    Admin,
+    Patient,
+    /// The provider who authored the patient's record.
+    AuthoringProvider,
+    /// A provider the authoring provider delegated its role to.
+    DelegatedProvider,
+    /// Staff holding the patient's `Read` grant and consent.
+    ReadGrantee,
+    /// Staff holding the patient's `Write` grant and consent.
+    WriteGrantee,
+    /// An address never registered with the contract.
+    Stranger,
+    /// A provider an admin has deactivated.
+    Deactivated,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Action {
+    /// `add_record` for the target patient under the target's provider.
+    Add,
+    /// `update_record` on the target record.
+    Update,
+    /// `get_record` on the target record.
+    Read,
+    /// `grant_access` of `Read` to a new address on the target patient.
+    Grant,
+    /// `revoke_access` of the target patient's grant to its grantee.
+    Revoke,
+    /// `authorize` for rolling back the target record, which has no
+    /// endpoint of its own.
+    Rollback,
+}
+
+/// Whose records the action is aimed at.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Target {
+    /// The patient's record, authored by the authoring provider. Every
+    /// persona's relationship is to this patient and record.
+    Own,
+    /// Another patient's record, authored by another provider, with no
+    /// grants or delegations to any persona.
+    Other,
+}
+
+const ACTION_COUNT: usize = 6;
+
+const ACTIONS: [Action; ACTION_COUNT] = [
+    Action::Add,
+    Action::Update,
+    Action::Read,
+    Action::Grant,
+    Action::Revoke,
+    Action::Rollback,
+];
+
+const Y: bool = true;
+const N: bool = false;
+
+struct Row {
+    persona: Persona,
+    own: [bool; ACTION_COUNT],
+    other: [bool; ACTION_COUNT],
+}
+
+/// Expected outcomes, with columns in [`ACTIONS`] order.
+#[rustfmt::skip]
+const EXPECTED: &[Row] = &[
+    //                                    add update read grant revoke rollback
+    // Admins read only through read_record_as_admin, with a stated reason
+    Row { persona: Persona::Admin,             own: [Y, Y, N, Y, N, Y], other: [Y, Y, N, Y, N, Y] },
+    Row { persona: Persona::Patient,           own: [N, N, Y, Y, Y, N], other: [N, N, N, N, N, N] },
+    // Providers' roles carry ReadAnyRecord
+    Row { persona: Persona::AuthoringProvider, own: [Y, Y, Y, N, N, Y], other: [N, N, Y, N, N, N] },
+    Row { persona: Persona::DelegatedProvider, own: [Y, Y, Y, N, N, Y], other: [N, N, Y, N, N, N] },
+    Row { persona: Persona::ReadGrantee,       own: [N, N, Y, N, N, N], other: [N, N, N, N, N, N] },
+    // A write grant modifies records but does not add them for the provider
+    Row { persona: Persona::WriteGrantee,      own: [N, Y, Y, N, N, Y], other: [N, N, N, N, N, N] },
+    Row { persona: Persona::Stranger,          own: [N, N, N, N, N, N], other: [N, N, N, N, N, N] },
+    Row { persona: Persona::Deactivated,       own: [N, N, N, N, N, N], other: [N, N, N, N, N, N] },
+];
+
+struct World {
+    fx: TestContract,
+    stranger: Address,
+}
+
+impl World {
+    fn new() -> Self {
+        let fx = Fixture::new()
+            .with_admin()
+            .with_provider("other_provider")
+            .with_patient("other_patient")
+            .with_record(RecordType::Examination, HASH_A)
+            .with_provider("author")
+            .with_patient("patient")
+            .with_record(RecordType::Examination, HASH_A)
+            .with_provider("delegate")
+            .with_provider("deactivated")
+            .with_provider_role("read_grantee", Role::Staff)
+            .with_provider_role("write_grantee", Role::Staff)
+            .build();
+
+        let patient = fx.patient("patient");
+        for (name, level) in [
+            ("read_grantee", AccessLevel::Read),
+            ("write_grantee", AccessLevel::Write),
+        ] {
+            let grantee = fx.provider(name);
+            fx.client
+                .grant_consent(&patient, &grantee, &ConsentType::Treatment, &DAY);
+            fx.client
+                .grant_access(&patient, &patient, &grantee, &level, &DAY);
+        }
+        fx.client.delegate_role(
+            &fx.provider("author"),
+            &fx.provider("delegate"),
+            &Role::Optometrist,
+            &0,
+        );
+        fx.client.deactivate_user(
+            &fx.admin(),
+            &fx.provider("deactivated"),
+            &DeactivationCascade::None,
+            &1,
+        );
+
+        let stranger = Address::generate(&fx.env);
+        World { fx, stranger }
+    }
+
+    fn persona(&self, persona: Persona) -> Address {
+        match persona {
+            Persona::Admin => self.fx.admin(),
+            Persona::Patient => self.fx.patient("patient"),
+            Persona::AuthoringProvider => self.fx.provider("author"),
+            Persona::DelegatedProvider => self.fx.provider("delegate"),
+            Persona::ReadGrantee => self.fx.provider("read_grantee"),
+            Persona::WriteGrantee => self.fx.provider("write_grantee"),
+            Persona::Stranger => self.stranger.clone(),
+            Persona::Deactivated => self.fx.provider("deactivated"),
+        }
+    }
+
+    /// The target's patient, provider, record and a grantee of the patient.
+    fn target(&self, target: Target) -> (Address, Address, u64, Address) {
+        match target {
+            Target::Own => (
+                self.fx.patient("patient"),
+                self.fx.provider("author"),
+                self.fx.record(1),
+                self.fx.provider("read_grantee"),
+            ),
+            Target::Other => (
+                self.fx.patient("other_patient"),
+                self.fx.provider("other_provider"),
+                self.fx.record(0),
+                self.fx.provider("read_grantee"),
+            ),
+        }
+    }
+
+    /// What `authorize` reports for `action`, where it has a counterpart.
+    fn authorize(&self, caller: &Address, action: Action, target: Target) -> Option<bool> {
+        let (patient, _, record_id, _) = self.target(target);
+        let auth_action = match action {
+            Action::Update => AuthAction::WriteRecord(record_id),
+            Action::Read => AuthAction::ReadRecord(record_id),
+            Action::Grant => AuthAction::GrantAccess(patient),
+            Action::Rollback => AuthAction::Rollback(record_id),
+            Action::Add | Action::Revoke => return None,
+        };
+        Some(self.fx.client.authorize(caller, &auth_action).allowed)
+    }
+
+    /// Whether `caller` may perform `action` on `target`.
+    fn run(&self, caller: &Address, action: Action, target: Target) -> bool {
+        let client = &self.fx.client;
+        let (patient, provider, record_id, grantee) = self.target(target);
+        let hash = |value: &str| String::from_str(&self.fx.env, value);
+        let succeeded = match action {
+            Action::Add => client
+                .try_add_record(
+                    caller,
+                    &patient,
+                    &provider,
+                    &RecordType::Examination,
+                    &hash(HASH_B),
+                )
+                .is_ok(),
+            Action::Update => client
+                .try_update_record(caller, &record_id, &hash(HASH_B))
+                .is_ok(),
+            Action::Read => client.try_get_record(caller, &record_id).is_ok(),
+            Action::Grant => client
+                .try_grant_access(
+                    caller,
+                    &patient,
+                    &Address::generate(&self.fx.env),
+                    &AccessLevel::Read,
+                    &DAY,
+                )
+                .is_ok(),
+            Action::Revoke => client.try_revoke_access(&patient, &grantee).is_ok(),
+            Action::Rollback => {
+                return client
+                    .authorize(caller, &AuthAction::Rollback(record_id))
+                    .allowed
+            }
+        };
+        // Auths are mocked, so a success still needs only the persona's
+        // signature to count
+        succeeded
+            && self
+                .fx
+                .env
+                .auths()
+                .iter()
+                .all(|(address, _)| address == caller)
+    }
+}
+
+#[test]
+fn test_authorization_matrix() {
+    let mut failures: Vec<std::string::String> = Vec::new();
+    for row in EXPECTED {
+        for (target, expected) in [(Target::Own, row.own), (Target::Other, row.other)] {
+            for (action, want) in ACTIONS.iter().zip(expected) {
+                let world = World::new();
+                let caller = world.persona(row.persona);
+                let cell = format!("{:?} {:?} on {:?}", row.persona, action, target);
+
+                if let Some(decided) = world.authorize(&caller, *action, target) {
+                    if decided != want {
+                        failures.push(format!("{}: authorize says {}", cell, decided));
+                    }
+                }
+                let got = world.run(&caller, *action, target);
+                if got != want {
+                    failures.push(format!("{}: expected {}, got {}", cell, want, got));
+                }
+            }
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "authorization matrix changed:\n{}",
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn test_matrix_covers_every_persona_once() {
+    let personas = [
+        Persona::Admin,
+        Persona::Patient,
+        Persona::AuthoringProvider,
+        Persona::DelegatedProvider,
+        Persona::ReadGrantee,
+        Persona::WriteGrantee,
+        Persona::Stranger,
+        Persona::Deactivated,
+    ];
+    for persona in personas {
+        let rows = EXPECTED.iter().filter(|row| row.persona == persona).count();
+        assert_eq!(rows, 1, "{:?} has {} rows", persona, rows);
+    }
+    assert_eq!(EXPECTED.len(), personas.len());
+}
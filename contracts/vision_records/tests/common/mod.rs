@@ -16,7 +16,7 @@ pub fn setup_test_env() -> TestContext {
     let client = VisionRecordsContractClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
-    client.initialize(&admin);
+    client.initialize(&admin, &None);
 
     TestContext { env, client, admin }
 }
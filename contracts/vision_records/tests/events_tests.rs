@@ -0,0 +1,2594 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+//! Schema tests for every event the contract publishes.
+//!
+//! Each test checks the exact topic tuple and payload of one event against
+//! the constants in `vision_records::events::topics`. Events with an entry
+//! point that emits them are driven through the contract client; the rest
+//! are published directly from inside the contract context.
+
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    xdr::{ContractEventBody, ScVal},
+    Address, Bytes, BytesN, Env, IntoVal, String, Symbol, TryFromVal, Val, Vec,
+};
+use vision_records::appointment::AppointmentType;
+use vision_records::circuit_breaker::PauseScope;
+use vision_records::confirmation;
+use vision_records::emergency::EmergencyCondition;
+use vision_records::errors::{ErrorCategory, ErrorContext, ErrorSeverity};
+use vision_records::events::{self, admin_ops, topics, *};
+use vision_records::offer::DEFAULT_OFFER_WINDOW_SECONDS;
+use vision_records::patient_index;
+use vision_records::rbac::{self, AccessPolicy, PolicyConditions, TimeRestriction};
+use vision_records::recovery::RECOVERY_TIMELOCK_SECONDS;
+use vision_records::signed_grant::{self, GrantApproval};
+use vision_records::{
+    AccessAction, AccessLevel, AccessResult, BatchRecordInput, ConsentType, CredentialType,
+    DeactivationCascade, DisputeResolution, GrantPurpose, LegacyReadMode, OrgGrant,
+    PendingCategory, PendingGrant, RecordPriority, RecordType, Role, SensitivityLevel,
+    VerificationStatus, VisionRecordsContract, VisionRecordsContractClient,
+};
+
+const HASH_A: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const HASH_B: &str = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+const NOW: u64 = 1_700_000_000;
+
+struct Ctx {
+    env: Env,
+    contract_id: Address,
+    client: VisionRecordsContractClient<'static>,
+    admin: Address,
+}
+
+fn setup() -> Ctx {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(NOW);
+
+    let contract_id = env.register(VisionRecordsContract, ());
+    let client = VisionRecordsContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &None);
+
+    Ctx {
+        env,
+        contract_id,
+        client,
+        admin,
+    }
+}
+
+fn to_xdr(env: &Env, val: &Val) -> ScVal {
+    ScVal::try_from_val(env, val).unwrap()
+}
+
+/// Asserts that the most recent event whose first topic is `topics[0]`
+/// carries exactly `topics` and `data`.
+fn assert_event<T, D>(env: &Env, topics: T, data: D)
+where
+    T: IntoVal<Env, Vec<Val>>,
+    D: IntoVal<Env, Val>,
+{
+    let expected_topics: std::vec::Vec<ScVal> = topics
+        .into_val(env)
+        .iter()
+        .map(|t| to_xdr(env, &t))
+        .collect();
+    let expected_data = to_xdr(env, &data.into_val(env));
+
+    let all = env.events().all();
+    let (actual_topics, actual_data) = all
+        .events()
+        .iter()
+        .rev()
+        .find_map(|event| {
+            let ContractEventBody::V0(body) = &event.body;
+            let topics: std::vec::Vec<ScVal> = body.topics.iter().cloned().collect();
+            (topics.first() == expected_topics.first()).then(|| (topics, body.data.clone()))
+        })
+        .unwrap_or_else(|| panic!("no event published for {:?}", expected_topics.first()));
+
+    assert_eq!(actual_topics, expected_topics);
+    assert_eq!(actual_data, expected_data);
+}
+
+fn register(ctx: &Ctx, role: Role, name: &str) -> Address {
+    let user = Address::generate(&ctx.env);
+    ctx.client
+        .register_user(&ctx.admin, &user, &role, &String::from_str(&ctx.env, name));
+    user
+}
+
+fn add_record(ctx: &Ctx, provider: &Address, patient: &Address) -> u64 {
+    ctx.client.add_record(
+        provider,
+        patient,
+        provider,
+        &RecordType::Examination,
+        &String::from_str(&ctx.env, HASH_A),
+    )
+}
+
+/// Runs `f` as the contract, so publishers can be called directly.
+fn as_contract(ctx: &Ctx, f: impl FnOnce(&Env)) {
+    ctx.env.as_contract(&ctx.contract_id, || f(&ctx.env));
+}
+
+/// Enables the allow-all ABAC policy grants need before they take effect.
+fn allow_all_policy(ctx: &Ctx) {
+    as_contract(ctx, |env| {
+        rbac::create_access_policy(
+            env,
+            AccessPolicy {
+                id: String::from_str(env, "default_medical_access"),
+                name: String::from_str(env, "Allow all"),
+                conditions: PolicyConditions {
+                    required_role: Role::None,
+                    time_restriction: TimeRestriction::None,
+                    required_credential: CredentialType::None,
+                    min_sensitivity_level: SensitivityLevel::Public,
+                    consent_required: false,
+                },
+                enabled: true,
+            },
+        );
+    });
+}
+
+fn addr(ctx: &Ctx) -> Address {
+    Address::generate(&ctx.env)
+}
+
+fn text(ctx: &Ctx, s: &str) -> String {
+    String::from_str(&ctx.env, s)
+}
+
+// ── End-to-end: events emitted by contract entry points ──────
+
+#[test]
+fn test_initialize_event() {
+    let ctx = setup();
+    assert_event(
+        &ctx.env,
+        (topics::INIT,),
+        InitializedEvent {
+            admin: ctx.admin.clone(),
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_user_registered_event() {
+    let ctx = setup();
+    let user = register(&ctx, Role::Optometrist, "Dr. Reg");
+    assert_event(
+        &ctx.env,
+        (topics::USR_REG, user.clone()),
+        UserRegisteredEvent {
+            user,
+            role: Role::Optometrist,
+            name: text(&ctx, "Dr. Reg"),
+            self_registered: false,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_self_registered_user_event() {
+    let ctx = setup();
+    ctx.client.set_patient_self_registration(&ctx.admin, &true);
+    let patient = addr(&ctx);
+    ctx.client
+        .self_register_patient(&patient, &text(&ctx, "Pat"));
+    assert_event(
+        &ctx.env,
+        (topics::USR_REG, patient.clone()),
+        UserRegisteredEvent {
+            user: patient,
+            role: Role::Patient,
+            name: text(&ctx, "Pat"),
+            self_registered: true,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_record_added_event() {
+    let ctx = setup();
+    let provider = register(&ctx, Role::Optometrist, "Dr. Add");
+    let patient = addr(&ctx);
+    let record_id = add_record(&ctx, &provider, &patient);
+    assert_event(
+        &ctx.env,
+        (topics::REC_ADD, patient.clone(), provider.clone()),
+        RecordAddedEvent {
+            record_id,
+            patient,
+            provider,
+            record_type: RecordType::Examination,
+            coauthors: Vec::new(&ctx.env),
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_coauthored_record_added_event() {
+    let ctx = setup();
+    let provider = register(&ctx, Role::Optometrist, "Dr. Sign");
+    let coauthor = register(&ctx, Role::Optometrist, "Dr. Measure");
+    let patient = addr(&ctx);
+    let coauthors = Vec::from_array(&ctx.env, [coauthor]);
+    let record_id = ctx.client.add_record_with_coauthors(
+        &provider,
+        &patient,
+        &provider,
+        &coauthors,
+        &RecordType::Examination,
+        &text(&ctx, HASH_A),
+    );
+    assert_event(
+        &ctx.env,
+        (topics::REC_ADD, patient.clone(), provider.clone()),
+        RecordAddedEvent {
+            record_id,
+            patient,
+            provider,
+            record_type: RecordType::Examination,
+            coauthors,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_batch_records_added_events() {
+    let ctx = setup();
+    let provider = register(&ctx, Role::Optometrist, "Dr. Batch");
+    let patient = addr(&ctx);
+    let mut inputs = Vec::new(&ctx.env);
+    for _ in 0..2 {
+        inputs.push_back(BatchRecordInput {
+            patient: patient.clone(),
+            record_type: RecordType::Prescription,
+            data_hash: text(&ctx, HASH_A),
+        });
+    }
+    let ids = ctx.client.add_records(&provider, &inputs);
+
+    assert_event(
+        &ctx.env,
+        (topics::REC_ADD, patient.clone(), provider.clone()),
+        RecordAddedEvent {
+            record_id: ids.get(1).unwrap(),
+            patient,
+            provider: provider.clone(),
+            record_type: RecordType::Prescription,
+            coauthors: Vec::new(&ctx.env),
+            timestamp: NOW,
+        },
+    );
+    assert_event(
+        &ctx.env,
+        (topics::BATCH_R, provider.clone()),
+        BatchRecordsAddedEvent {
+            provider,
+            count: 2,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_access_granted_event() {
+    let ctx = setup();
+    let patient = addr(&ctx);
+    let grantee = addr(&ctx);
+    ctx.client
+        .grant_access(&patient, &patient, &grantee, &AccessLevel::Read, &3_600);
+    assert_event(
+        &ctx.env,
+        (topics::ACC_GRT, patient.clone(), grantee.clone()),
+        AccessGrantedEvent {
+            created_by: patient.clone(),
+            patient,
+            grantee,
+            level: AccessLevel::Read,
+            purpose: GrantPurpose::Treatment,
+            duration_seconds: 3_600,
+            expires_at: NOW + 3_600,
+            grant_revision: 1,
+            access_epoch: 0,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_access_granted_event_carries_creator() {
+    let ctx = setup();
+    allow_all_policy(&ctx);
+    let patient = addr(&ctx);
+    let full = addr(&ctx);
+    let grantee = addr(&ctx);
+    ctx.client
+        .grant_access(&patient, &patient, &full, &AccessLevel::Full, &7_200);
+    ctx.client
+        .grant_consent(&patient, &full, &ConsentType::Treatment, &7_200);
+    ctx.client
+        .grant_access(&full, &patient, &grantee, &AccessLevel::Read, &3_600);
+    assert_event(
+        &ctx.env,
+        (topics::ACC_GRT, patient.clone(), grantee.clone()),
+        AccessGrantedEvent {
+            patient,
+            grantee,
+            level: AccessLevel::Read,
+            purpose: GrantPurpose::Treatment,
+            duration_seconds: 3_600,
+            expires_at: NOW + 3_600,
+            created_by: full,
+            grant_revision: 1,
+            access_epoch: 0,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_access_granted_event_carries_purpose() {
+    let ctx = setup();
+    let patient = addr(&ctx);
+    let grantee = addr(&ctx);
+    ctx.client.grant_access_with_purpose(
+        &patient,
+        &patient,
+        &grantee,
+        &AccessLevel::Read,
+        &GrantPurpose::Payment,
+        &3_600,
+    );
+    assert_event(
+        &ctx.env,
+        (topics::ACC_GRT, patient.clone(), grantee.clone()),
+        AccessGrantedEvent {
+            created_by: patient.clone(),
+            patient,
+            grantee,
+            level: AccessLevel::Read,
+            purpose: GrantPurpose::Payment,
+            duration_seconds: 3_600,
+            expires_at: NOW + 3_600,
+            grant_revision: 1,
+            access_epoch: 0,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_access_revoked_event() {
+    let ctx = setup();
+    let patient = addr(&ctx);
+    let grantee = addr(&ctx);
+    ctx.client
+        .grant_access(&patient, &patient, &grantee, &AccessLevel::Read, &3_600);
+    ctx.client.revoke_access(&patient, &grantee);
+    assert_event(
+        &ctx.env,
+        (topics::ACC_REV, patient.clone(), grantee.clone()),
+        AccessRevokedEvent {
+            patient,
+            grantee,
+            grant_revision: 2,
+            access_epoch: 0,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_admin_transfer_events() {
+    let ctx = setup();
+    let next = addr(&ctx);
+
+    ctx.client.propose_admin(&ctx.admin, &next);
+    assert_event(
+        &ctx.env,
+        (topics::ADM_PROP, ctx.admin.clone()),
+        AdminTransferProposedEvent {
+            current_admin: ctx.admin.clone(),
+            proposed_admin: next.clone(),
+            timestamp: NOW,
+        },
+    );
+
+    ctx.client.cancel_admin_transfer(&ctx.admin);
+    assert_event(
+        &ctx.env,
+        (topics::ADM_CNCL, ctx.admin.clone()),
+        AdminTransferCancelledEvent {
+            admin: ctx.admin.clone(),
+            cancelled_proposed: next.clone(),
+            timestamp: NOW,
+        },
+    );
+
+    ctx.client.propose_admin(&ctx.admin, &next);
+    ctx.client.accept_admin(&next);
+    assert_event(
+        &ctx.env,
+        (topics::ADM_ACPT, next.clone()),
+        AdminTransferAcceptedEvent {
+            old_admin: ctx.admin.clone(),
+            new_admin: next,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_user_frozen_and_unfrozen_events() {
+    let ctx = setup();
+    let user = register(&ctx, Role::Staff, "Frozen");
+
+    ctx.client.freeze_user(&ctx.admin, &user);
+    assert_event(
+        &ctx.env,
+        (topics::USR_FRZ, user.clone()),
+        UserFrozenEvent {
+            user: user.clone(),
+            frozen_by: ctx.admin.clone(),
+            timestamp: NOW,
+        },
+    );
+
+    ctx.client.unfreeze_user(&ctx.admin, &user);
+    assert_event(
+        &ctx.env,
+        (topics::USR_UNFRZ, user.clone()),
+        UserUnfrozenEvent {
+            user,
+            unfrozen_by: ctx.admin.clone(),
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_consent_events() {
+    let ctx = setup();
+    let patient = addr(&ctx);
+    let grantee = addr(&ctx);
+
+    ctx.client
+        .grant_consent(&patient, &grantee, &ConsentType::Treatment, &3_600);
+    assert_event(
+        &ctx.env,
+        (topics::CST_GRT, patient.clone(), grantee.clone()),
+        ConsentGrantedEvent {
+            patient: patient.clone(),
+            grantee: grantee.clone(),
+            consent_type: ConsentType::Treatment,
+            expires_at: NOW + 3_600,
+            grant_revision: 1,
+            access_epoch: 0,
+            timestamp: NOW,
+        },
+    );
+
+    ctx.client.revoke_consent(&patient, &grantee);
+    assert_event(
+        &ctx.env,
+        (topics::CST_REV, patient.clone(), grantee.clone()),
+        ConsentRevokedEvent {
+            patient,
+            grantee,
+            grant_revision: 2,
+            access_epoch: 0,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_record_superseded_event() {
+    let ctx = setup();
+    let provider = register(&ctx, Role::Optometrist, "Dr. Fix");
+    let patient = addr(&ctx);
+    let old_id = add_record(&ctx, &provider, &patient);
+    let new_id = ctx.client.supersede_record(
+        &provider,
+        &old_id,
+        &text(&ctx, HASH_B),
+        &RecordType::Examination,
+    );
+    assert_event(
+        &ctx.env,
+        (topics::REC_SUPER, old_id, new_id),
+        RecordSupersededEvent {
+            old_record_id: old_id,
+            new_record_id: new_id,
+            patient,
+            superseded_by: provider,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_contract_paused_and_resumed_events() {
+    let ctx = setup();
+
+    ctx.client.pause_contract(&ctx.admin, &PauseScope::Global);
+    assert_event(
+        &ctx.env,
+        (topics::PAUSE,),
+        ContractPausedEvent {
+            caller: ctx.admin.clone(),
+            scope: PauseScope::Global,
+            timestamp: NOW,
+        },
+    );
+
+    ctx.client.resume_contract(&ctx.admin, &PauseScope::Global);
+    assert_event(
+        &ctx.env,
+        (topics::RESUME,),
+        ContractResumedEvent {
+            caller: ctx.admin.clone(),
+            scope: PauseScope::Global,
+            timestamp: NOW,
+        },
+    );
+}
+
+// ── Direct: publishers without an entry point in this test ───
+
+#[test]
+fn test_record_access_granted_event() {
+    let ctx = setup();
+    let (patient, grantee) = (addr(&ctx), addr(&ctx));
+    as_contract(&ctx, |env| {
+        events::publish_record_access_granted(
+            env,
+            patient.clone(),
+            grantee.clone(),
+            7,
+            AccessLevel::Read,
+            60,
+            NOW + 60,
+        )
+    });
+    assert_event(
+        &ctx.env,
+        (topics::REC_GRT, patient.clone(), grantee.clone(), 7u64),
+        RecordAccessGrantedEvent {
+            patient,
+            grantee,
+            record_id: 7,
+            level: AccessLevel::Read,
+            duration_seconds: 60,
+            expires_at: NOW + 60,
+            grant_revision: 0,
+            access_epoch: 0,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_access_expired_and_swept_events() {
+    let ctx = setup();
+    let (patient, grantee) = (addr(&ctx), addr(&ctx));
+    as_contract(&ctx, |env| {
+        events::publish_access_expired(env, patient.clone(), grantee.clone(), NOW - 1);
+        events::publish_grantee_access_swept(env, grantee.clone(), ctx.admin.clone(), 3, 1);
+    });
+    assert_event(
+        &ctx.env,
+        (topics::ACC_EXP, patient.clone(), grantee.clone()),
+        AccessExpiredEvent {
+            patient,
+            grantee: grantee.clone(),
+            expired_at: NOW - 1,
+            grant_revision: 0,
+            access_epoch: 0,
+            purged_at: NOW,
+        },
+    );
+    assert_event(
+        &ctx.env,
+        (topics::ACC_SWEEP, grantee.clone()),
+        GranteeAccessSweptEvent {
+            grantee,
+            revoked_by: ctx.admin.clone(),
+            revoked: 3,
+            remaining: 1,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_batch_access_granted_event() {
+    let ctx = setup();
+    let patient = addr(&ctx);
+    as_contract(&ctx, |env| {
+        events::publish_batch_access_granted(env, patient.clone(), 4)
+    });
+    assert_event(
+        &ctx.env,
+        (topics::BATCH_A, patient.clone()),
+        BatchAccessGrantedEvent {
+            patient,
+            count: 4,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_access_violation_event() {
+    let ctx = setup();
+    let caller = addr(&ctx);
+    as_contract(&ctx, |env| {
+        events::publish_access_violation(
+            env,
+            caller.clone(),
+            text(&ctx, "add_record"),
+            text(&ctx, "permission:WriteRecord"),
+        )
+    });
+    assert_event(
+        &ctx.env,
+        (topics::ACC_VIOL, caller.clone(), text(&ctx, "add_record")),
+        AccessViolationEvent {
+            caller,
+            action: text(&ctx, "add_record"),
+            required_permission: text(&ctx, "permission:WriteRecord"),
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_provider_events() {
+    let ctx = setup();
+    let provider = addr(&ctx);
+    as_contract(&ctx, |env| {
+        events::publish_provider_registered(env, provider.clone(), text(&ctx, "Clinic"), 9);
+        events::publish_provider_verified(
+            env,
+            provider.clone(),
+            ctx.admin.clone(),
+            VerificationStatus::Verified,
+        );
+    });
+    assert_event(
+        &ctx.env,
+        (topics::PROV_REG, provider.clone()),
+        ProviderRegisteredEvent {
+            provider: provider.clone(),
+            name: text(&ctx, "Clinic"),
+            provider_id: 9,
+            timestamp: NOW,
+        },
+    );
+    assert_event(
+        &ctx.env,
+        (topics::PROV_VER, provider.clone(), ctx.admin.clone()),
+        ProviderVerifiedEvent {
+            provider,
+            verifier: ctx.admin.clone(),
+            status: VerificationStatus::Verified,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_examination_added_event() {
+    let ctx = setup();
+    as_contract(&ctx, |env| events::publish_examination_added(env, 11));
+    assert_event(
+        &ctx.env,
+        (topics::EXAM_ADD, 11u64),
+        ExaminationAddedEvent {
+            record_id: 11,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_meta_access_granted_event() {
+    let ctx = setup();
+    let (patient, grantee, relayer) = (addr(&ctx), addr(&ctx), addr(&ctx));
+    as_contract(&ctx, |env| {
+        events::publish_meta_access_granted(
+            env,
+            patient.clone(),
+            grantee.clone(),
+            AccessLevel::Write,
+            relayer.clone(),
+            NOW + 10,
+            5,
+        )
+    });
+    assert_event(
+        &ctx.env,
+        (topics::META_GRT, patient.clone(), grantee.clone()),
+        MetaAccessGrantedEvent {
+            patient,
+            grantee,
+            level: AccessLevel::Write,
+            relayer,
+            expires_at: NOW + 10,
+            nonce: 5,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_profile_events() {
+    let ctx = setup();
+    let patient = addr(&ctx);
+    as_contract(&ctx, |env| {
+        events::publish_profile_created(env, patient.clone());
+        events::publish_profile_updated(env, patient.clone());
+    });
+    assert_event(
+        &ctx.env,
+        (topics::PROF_C, patient.clone()),
+        ProfileCreatedEvent {
+            patient: patient.clone(),
+            timestamp: NOW,
+        },
+    );
+    assert_event(
+        &ctx.env,
+        (topics::PROF_U, patient.clone()),
+        ProfileUpdatedEvent {
+            patient,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_error_event() {
+    let ctx = setup();
+    let user = addr(&ctx);
+    let context = ErrorContext {
+        category: ErrorCategory::Authorization,
+        severity: ErrorSeverity::Medium,
+        message: text(&ctx, "denied"),
+        user: Some(user.clone()),
+        resource_id: Some(text(&ctx, "add_record")),
+        timestamp: NOW,
+        retryable: false,
+    };
+    as_contract(&ctx, |env| events::publish_error(env, 3, context.clone()));
+    assert_event(
+        &ctx.env,
+        (
+            topics::ERROR,
+            ErrorCategory::Authorization,
+            ErrorSeverity::Medium,
+        ),
+        ErrorEvent {
+            error_code: 3,
+            category: ErrorCategory::Authorization,
+            severity: ErrorSeverity::Medium,
+            message: text(&ctx, "denied"),
+            user: Some(user),
+            resource_id: Some(text(&ctx, "add_record")),
+            retryable: false,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_emergency_events() {
+    let ctx = setup();
+    let (patient, requester, contact) = (addr(&ctx), addr(&ctx), addr(&ctx));
+    as_contract(&ctx, |env| {
+        events::publish_emergency_access_granted(
+            env,
+            1,
+            patient.clone(),
+            requester.clone(),
+            EmergencyCondition::Unconscious,
+            NOW + 100,
+        );
+        events::publish_emergency_access_revoked(env, 1, patient.clone(), ctx.admin.clone());
+        events::publish_emergency_contact_notified(env, 1, patient.clone(), contact.clone());
+        events::publish_emergency_access_used(env, 1, patient.clone(), requester.clone(), Some(2));
+    });
+    assert_event(
+        &ctx.env,
+        (topics::EMRG_GRT, patient.clone(), requester.clone()),
+        EmergencyAccessGrantedEvent {
+            access_id: 1,
+            patient: patient.clone(),
+            requester: requester.clone(),
+            condition: EmergencyCondition::Unconscious,
+            expires_at: NOW + 100,
+            timestamp: NOW,
+        },
+    );
+    assert_event(
+        &ctx.env,
+        (topics::EMRG_REV, patient.clone(), ctx.admin.clone()),
+        EmergencyAccessRevokedEvent {
+            access_id: 1,
+            patient: patient.clone(),
+            revoker: ctx.admin.clone(),
+            timestamp: NOW,
+        },
+    );
+    assert_event(
+        &ctx.env,
+        (topics::EMRG_NOT, patient.clone(), contact.clone()),
+        EmergencyContactNotifiedEvent {
+            access_id: 1,
+            patient: patient.clone(),
+            contact,
+            timestamp: NOW,
+        },
+    );
+    assert_event(
+        &ctx.env,
+        (topics::EMRG_USE, patient.clone(), requester.clone()),
+        EmergencyAccessUsedEvent {
+            access_id: 1,
+            patient,
+            requester,
+            record_id: Some(2),
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_appointment_events() {
+    let ctx = setup();
+    let (patient, provider) = (addr(&ctx), addr(&ctx));
+    let by = ctx.admin.clone();
+    as_contract(&ctx, |env| {
+        events::publish_appointment_scheduled(
+            env,
+            1,
+            patient.clone(),
+            provider.clone(),
+            AppointmentType::FollowUp,
+            NOW + 500,
+        );
+        events::publish_appointment_confirmed(
+            env,
+            1,
+            patient.clone(),
+            provider.clone(),
+            by.clone(),
+        );
+        events::publish_appointment_cancelled(
+            env,
+            1,
+            patient.clone(),
+            provider.clone(),
+            by.clone(),
+        );
+        events::publish_appointment_rescheduled(
+            env,
+            1,
+            patient.clone(),
+            provider.clone(),
+            NOW + 500,
+            NOW + 900,
+            by.clone(),
+        );
+        events::publish_appointment_completed(
+            env,
+            1,
+            patient.clone(),
+            provider.clone(),
+            by.clone(),
+        );
+        events::publish_appointment_reminder(env, 1, patient.clone(), provider.clone(), NOW + 900);
+        events::publish_appointment_verified(env, 1, patient.clone(), provider.clone(), by.clone());
+    });
+
+    let pair = (patient.clone(), provider.clone());
+    assert_event(
+        &ctx.env,
+        (topics::APPT_SCH, pair.0.clone(), pair.1.clone()),
+        AppointmentScheduledEvent {
+            appointment_id: 1,
+            patient: patient.clone(),
+            provider: provider.clone(),
+            appointment_type: AppointmentType::FollowUp,
+            scheduled_at: NOW + 500,
+            timestamp: NOW,
+        },
+    );
+    assert_event(
+        &ctx.env,
+        (topics::APPT_CFM, pair.0.clone(), pair.1.clone()),
+        AppointmentConfirmedEvent {
+            appointment_id: 1,
+            patient: patient.clone(),
+            provider: provider.clone(),
+            confirmed_by: by.clone(),
+            timestamp: NOW,
+        },
+    );
+    assert_event(
+        &ctx.env,
+        (topics::APPT_CNL, pair.0.clone(), pair.1.clone()),
+        AppointmentCancelledEvent {
+            appointment_id: 1,
+            patient: patient.clone(),
+            provider: provider.clone(),
+            cancelled_by: by.clone(),
+            timestamp: NOW,
+        },
+    );
+    assert_event(
+        &ctx.env,
+        (topics::APPT_RSCH, pair.0.clone(), pair.1.clone()),
+        AppointmentRescheduledEvent {
+            appointment_id: 1,
+            patient: patient.clone(),
+            provider: provider.clone(),
+            old_scheduled_at: NOW + 500,
+            new_scheduled_at: NOW + 900,
+            rescheduled_by: by.clone(),
+            timestamp: NOW,
+        },
+    );
+    assert_event(
+        &ctx.env,
+        (topics::APPT_CMP, pair.0.clone(), pair.1.clone()),
+        AppointmentCompletedEvent {
+            appointment_id: 1,
+            patient: patient.clone(),
+            provider: provider.clone(),
+            completed_by: by.clone(),
+            timestamp: NOW,
+        },
+    );
+    assert_event(
+        &ctx.env,
+        (topics::APPT_RMD, pair.0.clone(), pair.1.clone()),
+        AppointmentReminderEvent {
+            appointment_id: 1,
+            patient: patient.clone(),
+            provider: provider.clone(),
+            scheduled_at: NOW + 900,
+            timestamp: NOW,
+        },
+    );
+    assert_event(
+        &ctx.env,
+        (topics::APPT_VER, pair.0, pair.1),
+        AppointmentVerifiedEvent {
+            appointment_id: 1,
+            patient,
+            provider,
+            verifier: by,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_audit_log_entry_event() {
+    let ctx = setup();
+    let (actor, patient) = (addr(&ctx), addr(&ctx));
+    let entry = vision_records::audit::AuditEntry {
+        id: 4,
+        timestamp: NOW - 5,
+        actor: actor.clone(),
+        patient: patient.clone(),
+        record_id: Some(8),
+        action: AccessAction::Read,
+        result: AccessResult::Success,
+        reason: None,
+        ip_address: None,
+        user_agent: None,
+    };
+    as_contract(&ctx, |env| events::publish_audit_log_entry(env, &entry));
+    assert_event(
+        &ctx.env,
+        (topics::AUDIT, actor.clone(), patient.clone()),
+        AuditLogEntryEvent {
+            entry_id: 4,
+            actor,
+            patient,
+            record_id: Some(8),
+            action: AccessAction::Read,
+            result: AccessResult::Success,
+            reason: None,
+            timestamp: NOW - 5,
+        },
+    );
+}
+
+#[test]
+fn test_rate_limit_events() {
+    let ctx = setup();
+    let address = addr(&ctx);
+    let op = text(&ctx, "add_record");
+    as_contract(&ctx, |env| {
+        events::publish_rate_limit_exceeded(env, address.clone(), op.clone(), 11, 10, NOW + 60);
+        events::publish_rate_limit_config_updated(env, op.clone(), 10, 60, ctx.admin.clone());
+        events::publish_rate_limit_bypass_updated(env, address.clone(), true, ctx.admin.clone());
+    });
+    assert_event(
+        &ctx.env,
+        (topics::RL_EXCD, address.clone(), op.clone()),
+        RateLimitExceededEvent {
+            address: address.clone(),
+            operation: op.clone(),
+            current_count: 11,
+            max_requests: 10,
+            reset_at: NOW + 60,
+            timestamp: NOW,
+        },
+    );
+    assert_event(
+        &ctx.env,
+        (topics::RL_CONFIG, op.clone()),
+        RateLimitConfigUpdatedEvent {
+            operation: op,
+            max_requests: 10,
+            window_seconds: 60,
+            updated_by: ctx.admin.clone(),
+            timestamp: NOW,
+        },
+    );
+    assert_event(
+        &ctx.env,
+        (topics::RL_BYPASS, address.clone()),
+        RateLimitBypassUpdatedEvent {
+            address,
+            bypass_enabled: true,
+            updated_by: ctx.admin.clone(),
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_policy_credential_and_sensitivity_events() {
+    let ctx = setup();
+    let user = addr(&ctx);
+    as_contract(&ctx, |env| {
+        events::publish_policy_created(env, text(&ctx, "pol-1"), ctx.admin.clone());
+        events::publish_credential_set(
+            env,
+            user.clone(),
+            CredentialType::MedicalLicense,
+            ctx.admin.clone(),
+        );
+        events::publish_sensitivity_set(env, 3, SensitivityLevel::Restricted, ctx.admin.clone());
+    });
+    assert_event(
+        &ctx.env,
+        (topics::POL_CRT,),
+        PolicyCreatedEvent {
+            policy_id: text(&ctx, "pol-1"),
+            created_by: ctx.admin.clone(),
+            timestamp: NOW,
+        },
+    );
+    assert_event(
+        &ctx.env,
+        (topics::CRED_SET, user.clone()),
+        CredentialSetEvent {
+            user,
+            credential: CredentialType::MedicalLicense,
+            set_by: ctx.admin.clone(),
+            timestamp: NOW,
+        },
+    );
+    assert_event(
+        &ctx.env,
+        (topics::SENS_SET, 3u64),
+        SensitivitySetEvent {
+            record_id: 3,
+            sensitivity: SensitivityLevel::Restricted,
+            set_by: ctx.admin.clone(),
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_record_priority_changed_event() {
+    let ctx = setup();
+    as_contract(&ctx, |env| {
+        events::publish_record_priority_changed(
+            env,
+            6,
+            ctx.admin.clone(),
+            RecordPriority::Standard,
+            RecordPriority::Critical,
+        )
+    });
+    assert_event(
+        &ctx.env,
+        (topics::PRIO_CHG, 6u64),
+        RecordPriorityChangedEvent {
+            record_id: 6,
+            changed_by: ctx.admin.clone(),
+            previous: RecordPriority::Standard,
+            priority: RecordPriority::Critical,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_grant_approval_events() {
+    let ctx = setup();
+    let (patient, grantee, delegate) = (addr(&ctx), addr(&ctx), addr(&ctx));
+    let pending = PendingGrant {
+        patient: patient.clone(),
+        grantee: grantee.clone(),
+        level: AccessLevel::Read,
+        purpose: GrantPurpose::Treatment,
+        duration_seconds: 60,
+        requested_by: delegate.clone(),
+        requested_at: NOW,
+        expires_at: NOW + 1_000,
+    };
+    as_contract(&ctx, |env| {
+        events::publish_grant_pending(env, &pending);
+        events::publish_grant_approved(env, patient.clone(), grantee.clone(), delegate.clone());
+        events::publish_forced_grant(
+            env,
+            ctx.admin.clone(),
+            patient.clone(),
+            grantee.clone(),
+            AccessLevel::Read,
+            NOW + 60,
+        );
+    });
+    assert_event(
+        &ctx.env,
+        (topics::GRT_PEND, patient.clone(), grantee.clone()),
+        GrantPendingEvent {
+            patient: patient.clone(),
+            grantee: grantee.clone(),
+            level: AccessLevel::Read,
+            requested_by: delegate.clone(),
+            approval_deadline: NOW + 1_000,
+            timestamp: NOW,
+        },
+    );
+    assert_event(
+        &ctx.env,
+        (topics::GRT_APPR, patient.clone(), grantee.clone()),
+        GrantApprovedEvent {
+            patient: patient.clone(),
+            grantee: grantee.clone(),
+            requested_by: delegate,
+            timestamp: NOW,
+        },
+    );
+    assert_event(
+        &ctx.env,
+        (topics::GRT_FORCE, patient.clone(), grantee.clone()),
+        ForcedGrantEvent {
+            forced_by: ctx.admin.clone(),
+            patient,
+            grantee,
+            level: AccessLevel::Read,
+            expires_at: NOW + 60,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_access_offer_events() {
+    let ctx = setup();
+    let (patient, accepter, decliner) = (addr(&ctx), addr(&ctx), addr(&ctx));
+    let window = ctx.client.get_access_offer_window();
+
+    ctx.client
+        .grant_access_pending_acceptance(&patient, &accepter, &AccessLevel::Read, &3_600);
+    assert_event(
+        &ctx.env,
+        (topics::ACC_OFR, patient.clone(), accepter.clone()),
+        AccessOfferedEvent {
+            patient: patient.clone(),
+            grantee: accepter.clone(),
+            level: AccessLevel::Read,
+            duration_seconds: 3_600,
+            offer_expires_at: NOW + window,
+            timestamp: NOW,
+        },
+    );
+
+    ctx.env.ledger().set_timestamp(NOW + 100);
+    ctx.client.accept_access(&accepter, &patient);
+    assert_event(
+        &ctx.env,
+        (topics::ACC_ACPT, patient.clone(), accepter.clone()),
+        AccessOfferAcceptedEvent {
+            patient: patient.clone(),
+            grantee: accepter,
+            expires_at: NOW + 100 + 3_600,
+            timestamp: NOW + 100,
+        },
+    );
+
+    ctx.client
+        .grant_access_pending_acceptance(&patient, &decliner, &AccessLevel::Read, &3_600);
+    ctx.client.decline_access(&decliner, &patient);
+    assert_event(
+        &ctx.env,
+        (topics::ACC_DECL, patient.clone(), decliner.clone()),
+        AccessOfferDeclinedEvent {
+            patient,
+            grantee: decliner,
+            timestamp: NOW + 100,
+        },
+    );
+}
+
+#[test]
+fn test_hash_algorithm_registered_event() {
+    let ctx = setup();
+    let alg = Symbol::new(&ctx.env, "sha3_256");
+    ctx.client.register_hash_algorithm(&ctx.admin, &alg);
+    assert_event(
+        &ctx.env,
+        (topics::HASH_REG, alg.clone()),
+        HashAlgorithmRegisteredEvent {
+            hash_alg: alg,
+            registered_by: ctx.admin.clone(),
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_claim_attestation_events() {
+    let ctx = setup();
+    let provider = register(&ctx, Role::Optometrist, "Dr. Claim");
+    let patient = addr(&ctx);
+    let record_id = add_record(&ctx, &provider, &patient);
+    let claim_ref = text(&ctx, HASH_B);
+
+    ctx.client
+        .create_claim_attestation(&provider, &record_id, &claim_ref, &2_500);
+    assert_event(
+        &ctx.env,
+        (topics::CLM_ATT, record_id, provider.clone()),
+        ClaimAttestedEvent {
+            record_id,
+            provider: provider.clone(),
+            claim_ref_hash: claim_ref.clone(),
+            amount_hint: 2_500,
+            timestamp: NOW,
+        },
+    );
+
+    ctx.client.void_claim_attestation(
+        &provider,
+        &record_id,
+        &claim_ref,
+        &text(&ctx, "entered in error"),
+    );
+    assert_event(
+        &ctx.env,
+        (topics::CLM_VOID, record_id),
+        ClaimAttestationVoidedEvent {
+            record_id,
+            claim_ref_hash: claim_ref,
+            voided_by: provider,
+            reason: text(&ctx, "entered in error"),
+            timestamp: NOW,
+        },
+    );
+}
+
+fn has_grant(ctx: &Ctx, patient: &Address, grantee: &Address) -> bool {
+    ctx.env.as_contract(&ctx.contract_id, || {
+        ctx.env.storage().persistent().has(&(
+            symbol_short!("ACCESS"),
+            patient.clone(),
+            grantee.clone(),
+        ))
+    })
+}
+
+#[test]
+fn test_admin_op_event_for_purge() {
+    let ctx = setup();
+    let patient = addr(&ctx);
+    let grantees = [addr(&ctx), addr(&ctx), addr(&ctx)];
+    for (grantee, duration) in grantees.iter().zip([3_600u64, 3_600, 7_200]) {
+        ctx.client
+            .grant_access(&patient, &patient, grantee, &AccessLevel::Read, &duration);
+    }
+
+    ctx.env.ledger().set_timestamp(NOW + 3_700);
+    let purged = ctx.client.purge_expired_grants(&patient, &patient);
+    assert_event(
+        &ctx.env,
+        (topics::ADMIN_OP, admin_ops::PURGE_GRANTS),
+        AdminOpEvent {
+            operation: admin_ops::PURGE_GRANTS,
+            caller: patient.clone(),
+            target: patient.clone(),
+            range_start: 0,
+            range_end: 3,
+            touched: 2,
+            timestamp: NOW + 3_700,
+        },
+    );
+
+    let remaining = grantees
+        .iter()
+        .filter(|grantee| has_grant(&ctx, &patient, grantee))
+        .count();
+    assert_eq!(purged, 2);
+    assert_eq!(remaining, 1);
+}
+
+#[test]
+fn test_admin_op_event_for_grantee_sweep() {
+    let ctx = setup();
+    let grantee = addr(&ctx);
+    let patients = [addr(&ctx), addr(&ctx), addr(&ctx)];
+    for patient in patients.iter() {
+        ctx.client
+            .grant_access(patient, patient, &grantee, &AccessLevel::Read, &3_600);
+    }
+
+    let result = ctx
+        .client
+        .revoke_all_access_for_grantee(&ctx.admin, &grantee, &2);
+    assert_event(
+        &ctx.env,
+        (topics::ADMIN_OP, admin_ops::GRANTEE_SWEEP),
+        AdminOpEvent {
+            operation: admin_ops::GRANTEE_SWEEP,
+            caller: ctx.admin.clone(),
+            target: grantee.clone(),
+            range_start: 0,
+            range_end: 2,
+            touched: 2,
+            timestamp: NOW,
+        },
+    );
+
+    let still_granted = patients
+        .iter()
+        .filter(|patient| has_grant(&ctx, patient, &grantee))
+        .count();
+    assert_eq!(result.revoked, 2);
+    assert_eq!(still_granted, 1);
+    assert_eq!(ctx.client.get_grantee_patients(&grantee).len(), 1);
+}
+
+#[test]
+fn test_admin_op_event_for_index_compaction() {
+    let ctx = setup();
+    let patient = addr(&ctx);
+    let grantees = [addr(&ctx), addr(&ctx), addr(&ctx)];
+    for grantee in grantees.iter() {
+        ctx.client
+            .grant_access(&patient, &patient, grantee, &AccessLevel::Read, &3_600);
+    }
+    ctx.client.revoke_access(&patient, &grantees[0]);
+
+    let result = ctx.client.compact_grant_indexes(&ctx.admin, &patient);
+    assert_event(
+        &ctx.env,
+        (topics::ADMIN_OP, admin_ops::COMPACT_INDEX),
+        AdminOpEvent {
+            operation: admin_ops::COMPACT_INDEX,
+            caller: ctx.admin.clone(),
+            target: patient.clone(),
+            range_start: 0,
+            range_end: 3,
+            touched: 1,
+            timestamp: NOW,
+        },
+    );
+    assert_eq!(result.grantees_removed, 1);
+    assert!(ctx.client.verify_grant_indexes(&patient));
+}
+
+#[test]
+fn test_admin_op_event_for_patient_index_repair() {
+    let ctx = setup();
+    let provider = register(&ctx, Role::Optometrist, "Dr. Index");
+    let patient = addr(&ctx);
+    let first = add_record(&ctx, &provider, &patient);
+    let second = add_record(&ctx, &provider, &patient);
+    as_contract(&ctx, |env| {
+        env.storage().persistent().set(
+            &patient_index::records_key(&patient),
+            &Vec::from_array(env, [second, first, second]),
+        );
+    });
+
+    let result = ctx.client.repair_patient_index(&ctx.admin, &patient);
+    assert_event(
+        &ctx.env,
+        (topics::ADMIN_OP, admin_ops::REPAIR_INDEX),
+        AdminOpEvent {
+            operation: admin_ops::REPAIR_INDEX,
+            caller: ctx.admin.clone(),
+            target: patient.clone(),
+            range_start: 0,
+            range_end: 3,
+            touched: 3,
+            timestamp: NOW,
+        },
+    );
+    assert_eq!(result.duplicates_removed, 1);
+    assert!(ctx.client.verify_patient_index(&patient, &0).ordered);
+}
+
+#[test]
+fn test_expiry_beacon_event() {
+    let ctx = setup();
+    let (patient, grantee) = (addr(&ctx), addr(&ctx));
+    as_contract(&ctx, |env| {
+        events::publish_expiry_beacon(env, patient.clone(), grantee.clone(), NOW - 1, 7);
+    });
+    assert_event(
+        &ctx.env,
+        (topics::EXP_BCN, patient.clone(), grantee.clone()),
+        ExpiryBeaconEvent {
+            patient,
+            grantee,
+            expired_at: NOW - 1,
+            bucket: 7,
+            grant_revision: 0,
+            access_epoch: 0,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_admin_read_event() {
+    let ctx = setup();
+    let provider = register(&ctx, Role::Optometrist, "Dr. Read");
+    let patient = addr(&ctx);
+    let record_id = add_record(&ctx, &provider, &patient);
+    let reason_hash = String::from_str(&ctx.env, HASH_A);
+
+    ctx.client
+        .read_record_as_admin(&ctx.admin, &record_id, &reason_hash);
+    assert_event(
+        &ctx.env,
+        (topics::ADM_READ, patient.clone(), ctx.admin.clone()),
+        AdminReadEvent {
+            admin: ctx.admin.clone(),
+            patient,
+            record_id,
+            reason_hash,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_session_events() {
+    let ctx = setup();
+    let patient = addr(&ctx);
+    let session_key = addr(&ctx);
+
+    ctx.client
+        .create_session(&patient, &session_key, &AccessLevel::Read, &600);
+    assert_event(
+        &ctx.env,
+        (topics::SESS_NEW, patient.clone(), session_key.clone()),
+        SessionCreatedEvent {
+            patient: patient.clone(),
+            session_key: session_key.clone(),
+            scope: AccessLevel::Read,
+            expires_at: NOW + 600,
+            timestamp: NOW,
+        },
+    );
+
+    ctx.env.ledger().set_timestamp(NOW + 100);
+    ctx.client.revoke_session(&patient, &session_key);
+    assert_event(
+        &ctx.env,
+        (topics::SESS_REV, patient.clone(), session_key.clone()),
+        SessionRevokedEvent {
+            patient,
+            session_key,
+            timestamp: NOW + 100,
+        },
+    );
+}
+
+#[test]
+fn test_version_diff_attached_event() {
+    let ctx = setup();
+    let provider = register(&ctx, Role::Optometrist, "Dr. Diff");
+    let patient = register(&ctx, Role::Patient, "Pat");
+    let record_id = add_record(&ctx, &provider, &patient);
+    ctx.client
+        .update_record(&provider, &record_id, &text(&ctx, HASH_B));
+
+    ctx.client
+        .attach_version_diff(&provider, &record_id, &2, &1, &text(&ctx, HASH_A));
+    assert_event(
+        &ctx.env,
+        (topics::VER_DIFF, record_id),
+        VersionDiffAttachedEvent {
+            record_id,
+            from_version: 1,
+            to_version: 2,
+            diff_hash: text(&ctx, HASH_A),
+            previous: None,
+            attached_by: provider,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_external_ref_set_event() {
+    let ctx = setup();
+    let provider = register(&ctx, Role::Optometrist, "Dr. Ref");
+    let patient = register(&ctx, Role::Patient, "Pat");
+    let record_id = add_record(&ctx, &provider, &patient);
+
+    ctx.client
+        .set_external_ref(&provider, &record_id, &text(&ctx, "DiagnosticReport/abc"));
+    assert_event(
+        &ctx.env,
+        (topics::XREF_SET, record_id),
+        ExternalRefSetEvent {
+            record_id,
+            external_ref: text(&ctx, "DiagnosticReport/abc"),
+            previous: None,
+            set_by: provider.clone(),
+            timestamp: NOW,
+        },
+    );
+
+    ctx.env.ledger().set_timestamp(NOW + 100);
+    ctx.client
+        .set_external_ref(&ctx.admin, &record_id, &text(&ctx, "DiagnosticReport/xyz"));
+    assert_event(
+        &ctx.env,
+        (topics::XREF_SET, record_id),
+        ExternalRefSetEvent {
+            record_id,
+            external_ref: text(&ctx, "DiagnosticReport/xyz"),
+            previous: Some(text(&ctx, "DiagnosticReport/abc")),
+            set_by: ctx.admin.clone(),
+            timestamp: NOW + 100,
+        },
+    );
+}
+
+#[test]
+fn test_provisional_claimed_event() {
+    let ctx = setup();
+    let provider = register(&ctx, Role::Optometrist, "Dr. Walk-in");
+    let patient = register(&ctx, Role::Patient, "Pat");
+    let identity = BytesN::from_array(&ctx.env, &[7; 32]);
+    for _ in 0..2 {
+        ctx.client.add_record_provisional(
+            &provider,
+            &identity,
+            &RecordType::Examination,
+            &text(&ctx, HASH_A),
+        );
+    }
+
+    ctx.client
+        .claim_provisional_records(&patient, &identity, &ctx.admin);
+    assert_event(
+        &ctx.env,
+        (topics::PROV_CLM, patient.clone()),
+        ProvisionalClaimedEvent {
+            patient: patient.clone(),
+            provisional_hash: identity,
+            record_count: 2,
+            approved_by: ctx.admin.clone(),
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_breach_events() {
+    let ctx = setup();
+    let first = register(&ctx, Role::Patient, "Pat A");
+    let second = register(&ctx, Role::Patient, "Pat B");
+
+    ctx.client.flag_breach(
+        &ctx.admin,
+        &Vec::from_array(&ctx.env, [first.clone(), second.clone()]),
+        &text(&ctx, HASH_B),
+    );
+    let flagged = ctx
+        .env
+        .events()
+        .all()
+        .events()
+        .iter()
+        .filter(|event| {
+            let ContractEventBody::V0(body) = &event.body;
+            body.topics.first() == Some(&to_xdr(&ctx.env, &topics::BRCH_SET.into_val(&ctx.env)))
+        })
+        .count();
+    assert_eq!(flagged, 2);
+    assert_event(
+        &ctx.env,
+        (topics::BRCH_SET, second.clone()),
+        BreachFlaggedEvent {
+            patient: second,
+            incident_ref_hash: text(&ctx, HASH_B),
+            flagged_by: ctx.admin.clone(),
+            timestamp: NOW,
+        },
+    );
+
+    ctx.env.ledger().set_timestamp(NOW + 60);
+    ctx.client.clear_breach(&ctx.admin, &first);
+    assert_event(
+        &ctx.env,
+        (topics::BRCH_CLR, first.clone()),
+        BreachClearedEvent {
+            patient: first,
+            cleared_by: ctx.admin.clone(),
+            timestamp: NOW + 60,
+        },
+    );
+}
+
+#[test]
+fn test_signed_grant_events() {
+    let ctx = setup();
+    let patient = register(&ctx, Role::Patient, "Pat A");
+    let grantee = addr(&ctx);
+    let relayer = addr(&ctx);
+    let key = SigningKey::from_bytes(&[7; 32]);
+    let pubkey = BytesN::from_array(&ctx.env, &key.verifying_key().to_bytes());
+
+    ctx.client.register_signing_key(&patient, &pubkey);
+    assert_event(
+        &ctx.env,
+        (topics::SIGN_KEY, patient.clone()),
+        SigningKeyRegisteredEvent {
+            patient: patient.clone(),
+            pubkey: pubkey.clone(),
+            timestamp: NOW,
+        },
+    );
+
+    let approval = GrantApproval {
+        contract: ctx.contract_id.clone(),
+        patient: patient.clone(),
+        grantee: grantee.clone(),
+        level: AccessLevel::Read,
+        duration_seconds: 3_600,
+        nonce: 0,
+    };
+    let message: std::vec::Vec<u8> = signed_grant::approval_message(&ctx.env, &approval)
+        .iter()
+        .collect();
+    let signature = BytesN::from_array(&ctx.env, &key.sign(&message).to_bytes());
+    ctx.client.approve_grant_with_signature(
+        &relayer,
+        &patient,
+        &grantee,
+        &AccessLevel::Read,
+        &3_600,
+        &0,
+        &signature,
+        &pubkey,
+    );
+    assert_event(
+        &ctx.env,
+        (topics::ACC_GRT, patient.clone(), grantee.clone()),
+        AccessGrantedEvent {
+            created_by: patient.clone(),
+            patient: patient.clone(),
+            grantee: grantee.clone(),
+            level: AccessLevel::Read,
+            purpose: GrantPurpose::Treatment,
+            duration_seconds: 3_600,
+            expires_at: NOW + 3_600,
+            grant_revision: 1,
+            access_epoch: 0,
+            timestamp: NOW,
+        },
+    );
+    assert_event(
+        &ctx.env,
+        (topics::GRT_RLY, patient.clone(), grantee.clone()),
+        GrantRelayedEvent {
+            patient,
+            grantee,
+            relayer,
+            nonce: 0,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_grant_migrated_event() {
+    let ctx = setup();
+    let patient = register(&ctx, Role::Patient, "Pat A");
+    let old_grantee = addr(&ctx);
+    let new_grantee = addr(&ctx);
+
+    ctx.client
+        .grant_access(&patient, &patient, &old_grantee, &AccessLevel::Read, &3_600);
+    let confirm = ctx
+        .client
+        .compute_confirmation(&confirmation::MIGRATE_GRANTEE, &old_grantee);
+    ctx.client
+        .migrate_grantee(&old_grantee, &new_grantee, &10, &confirm);
+    assert_event(
+        &ctx.env,
+        (topics::GRT_MIG, patient.clone(), old_grantee.clone()),
+        GrantMigratedEvent {
+            patient,
+            old_grantee,
+            new_grantee,
+            expires_at: NOW + 3_600,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_auto_grant_events() {
+    let ctx = setup();
+    let provider = register(&ctx, Role::Optometrist, "Dr A");
+    ctx.client
+        .set_auto_grant_on_registration(&ctx.admin, &Some((AccessLevel::Read, 86_400)));
+
+    let patient = addr(&ctx);
+    ctx.client.register_user(
+        &provider,
+        &patient,
+        &Role::Patient,
+        &String::from_str(&ctx.env, "Pat A"),
+    );
+    assert_event(
+        &ctx.env,
+        (topics::GRT_AUTO, patient.clone(), provider.clone()),
+        AutoGrantEvent {
+            patient: patient.clone(),
+            grantee: provider.clone(),
+            level: AccessLevel::Read,
+            expires_at: NOW + 86_400,
+            timestamp: NOW,
+        },
+    );
+    assert_event(
+        &ctx.env,
+        (topics::ACC_GRT, patient.clone(), provider.clone()),
+        AccessGrantedEvent {
+            created_by: patient.clone(),
+            patient,
+            grantee: provider,
+            level: AccessLevel::Read,
+            purpose: GrantPurpose::Treatment,
+            duration_seconds: 86_400,
+            expires_at: NOW + 86_400,
+            grant_revision: 1,
+            access_epoch: 0,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_version_pin_events() {
+    let ctx = setup();
+    let provider = register(&ctx, Role::Optometrist, "Dr A");
+    let patient = register(&ctx, Role::Patient, "Pat A");
+    let record_id = add_record(&ctx, &provider, &patient);
+    let label = Symbol::new(&ctx.env, "claim_2024_07");
+
+    ctx.client.pin_version(&provider, &record_id, &1, &label);
+    assert_event(
+        &ctx.env,
+        (topics::VER_PIN, record_id),
+        VersionPinnedEvent {
+            record_id,
+            version: 1,
+            label: label.clone(),
+            pinned_by: provider,
+            timestamp: NOW,
+        },
+    );
+
+    ctx.client.unpin_version(&ctx.admin, &record_id, &label);
+    assert_event(
+        &ctx.env,
+        (topics::VER_UNPIN, record_id),
+        VersionUnpinnedEvent {
+            record_id,
+            version: 1,
+            label,
+            unpinned_by: ctx.admin.clone(),
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_version_redacted_event() {
+    let ctx = setup();
+    let provider = register(&ctx, Role::Optometrist, "Dr A");
+    let patient = register(&ctx, Role::Patient, "Pat A");
+    let record_id = add_record(&ctx, &provider, &patient);
+    ctx.client
+        .update_record(&provider, &record_id, &String::from_str(&ctx.env, HASH_B));
+    let reason_hash = BytesN::from_array(&ctx.env, &[7; 32]);
+
+    ctx.client
+        .redact_version(&ctx.admin, &record_id, &1, &reason_hash);
+    assert_event(
+        &ctx.env,
+        (topics::VER_RDCT, record_id, 1u32),
+        VersionRedactedEvent {
+            record_id,
+            version: 1,
+            reason_hash,
+            redacted_by: ctx.admin.clone(),
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_org_access_events() {
+    let ctx = setup();
+    let patient = register(&ctx, Role::Patient, "Pat A");
+    let org = symbol_short!("HOSP");
+    let unit = Some(symbol_short!("OPHTH"));
+    ctx.client.create_organization(&ctx.admin, &org, &ctx.admin);
+    ctx.client
+        .create_unit(&ctx.admin, &org, &symbol_short!("OPHTH"));
+
+    ctx.client
+        .grant_org_access(&patient, &org, &unit, &AccessLevel::Read, &3_600);
+    assert_event(
+        &ctx.env,
+        (topics::ORG_GRANT, patient.clone(), org.clone()),
+        OrgAccessGrantedEvent {
+            grant: OrgGrant {
+                patient: patient.clone(),
+                org: org.clone(),
+                unit: unit.clone(),
+                level: AccessLevel::Read,
+                granted_at: NOW,
+                expires_at: NOW + 3_600,
+            },
+            timestamp: NOW,
+        },
+    );
+
+    ctx.client.revoke_org_access(&patient, &org, &unit);
+    assert_event(
+        &ctx.env,
+        (topics::ORG_RVK, patient.clone(), org.clone()),
+        OrgAccessRevokedEvent {
+            patient,
+            org,
+            unit,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_write_session_events() {
+    let ctx = setup();
+    let provider = register(&ctx, Role::Optometrist, "Dr A");
+    let patient = register(&ctx, Role::Patient, "Pat A");
+    let record_id = add_record(&ctx, &provider, &patient);
+
+    ctx.client.open_write_session(&provider, &record_id, &600);
+    assert_event(
+        &ctx.env,
+        (topics::WS_OPEN, provider.clone(), record_id),
+        WriteSessionOpenedEvent {
+            caller: provider.clone(),
+            record_id,
+            expires_at: NOW + 600,
+            timestamp: NOW,
+        },
+    );
+
+    ctx.client.close_write_session(&provider, &record_id);
+    assert_event(
+        &ctx.env,
+        (topics::WS_CLOSE, provider.clone(), record_id),
+        WriteSessionClosedEvent {
+            caller: provider,
+            record_id,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_embargo_events() {
+    let ctx = setup();
+    let provider = register(&ctx, Role::Optometrist, "Dr A");
+    let patient = register(&ctx, Role::Patient, "Pat A");
+    let record_id = add_record(&ctx, &provider, &patient);
+    let release_at = NOW + 86_400;
+
+    ctx.client
+        .set_record_embargo(&provider, &record_id, &release_at);
+    assert_event(
+        &ctx.env,
+        (topics::EMB_SET, record_id),
+        EmbargoSetEvent {
+            record_id,
+            patient: patient.clone(),
+            provider: provider.clone(),
+            release_at,
+            timestamp: NOW,
+        },
+    );
+
+    ctx.client.clear_embargo(&provider, &record_id);
+    assert_event(
+        &ctx.env,
+        (topics::EMB_CLEAR, record_id),
+        EmbargoClearedEvent {
+            record_id,
+            patient,
+            provider,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_grant_cap_override_event() {
+    let ctx = setup();
+    let patient = register(&ctx, Role::Patient, "Pat A");
+    let grantee = addr(&ctx);
+    let year = 31_536_000;
+    ctx.client.set_max_grant_duration(&ctx.admin, &year);
+
+    ctx.client.grant_access_with_override(
+        &ctx.admin,
+        &patient,
+        &grantee,
+        &AccessLevel::Read,
+        &(2 * year),
+        &true,
+    );
+    assert_event(
+        &ctx.env,
+        (topics::GRT_OVCAP, patient.clone(), grantee.clone()),
+        GrantCapOverriddenEvent {
+            admin: ctx.admin.clone(),
+            patient,
+            grantee,
+            duration_seconds: 2 * year,
+            max_duration_seconds: year,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_share_code_events() {
+    let ctx = setup();
+    let provider = register(&ctx, Role::Optometrist, "Dr A");
+    let patient = register(&ctx, Role::Patient, "Pat A");
+    let record_id = add_record(&ctx, &provider, &patient);
+    let friend = addr(&ctx);
+    let expires_at = NOW + 86_400;
+
+    let first = Bytes::from_slice(&ctx.env, b"first-code");
+    let first_hash: BytesN<32> = ctx.env.crypto().sha256(&first).to_bytes();
+    ctx.client.create_share_code(
+        &patient,
+        &record_id,
+        &first_hash,
+        &AccessLevel::Read,
+        &expires_at,
+    );
+    assert_event(
+        &ctx.env,
+        (topics::SHR_NEW, patient.clone(), record_id),
+        ShareCodeCreatedEvent {
+            patient: patient.clone(),
+            record_id,
+            code_hash: first_hash,
+            level: AccessLevel::Read,
+            expires_at,
+            timestamp: NOW,
+        },
+    );
+
+    ctx.client.redeem_share_code(&friend, &record_id, &first);
+    assert_event(
+        &ctx.env,
+        (topics::SHR_RDM, patient.clone(), record_id),
+        ShareCodeRedeemedEvent {
+            patient: patient.clone(),
+            record_id,
+            redeemer: friend,
+            timestamp: NOW,
+        },
+    );
+
+    let second_hash = BytesN::from_array(&ctx.env, &[2; 32]);
+    ctx.client.create_share_code(
+        &patient,
+        &record_id,
+        &second_hash,
+        &AccessLevel::Read,
+        &expires_at,
+    );
+    ctx.client
+        .cancel_share_code(&patient, &record_id, &second_hash);
+    assert_event(
+        &ctx.env,
+        (topics::SHR_CNCL, patient.clone(), record_id),
+        ShareCodeCancelledEvent {
+            patient,
+            record_id,
+            code_hash: second_hash,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_pending_gc_event() {
+    let ctx = setup();
+    let patient = register(&ctx, Role::Patient, "Pat A");
+    let grantee = addr(&ctx);
+    ctx.client
+        .grant_access_pending_acceptance(&patient, &grantee, &AccessLevel::Read, &86_400);
+
+    // Past the end of the day the offer lapsed on
+    let later = NOW + DEFAULT_OFFER_WINDOW_SECONDS + 86_400;
+    ctx.env.ledger().set_timestamp(later);
+    ctx.client
+        .gc_pending(&ctx.admin, &PendingCategory::AccessOffer, &0, &10);
+    assert_event(
+        &ctx.env,
+        (topics::PEND_GC,),
+        PendingGcEvent {
+            category: PendingCategory::AccessOffer,
+            caller: ctx.admin.clone(),
+            removed: 1,
+            next_cursor: None,
+            timestamp: later,
+        },
+    );
+}
+
+#[test]
+fn test_record_override_events() {
+    let ctx = setup();
+    let provider = register(&ctx, Role::Optometrist, "Dr A");
+    let patient = register(&ctx, Role::Patient, "Pat A");
+    let record_id = add_record(&ctx, &provider, &patient);
+    let grantee = addr(&ctx);
+    let expires_at = NOW + 7 * 86_400;
+
+    ctx.client
+        .set_record_access_override(&patient, &grantee, &record_id, &expires_at);
+    assert_event(
+        &ctx.env,
+        (topics::OVR_SET, patient.clone(), record_id),
+        RecordOverrideSetEvent {
+            patient: patient.clone(),
+            grantee: grantee.clone(),
+            record_id,
+            expires_at,
+            timestamp: NOW,
+        },
+    );
+
+    ctx.client
+        .clear_record_access_override(&patient, &grantee, &record_id);
+    assert_event(
+        &ctx.env,
+        (topics::OVR_CLEAR, patient.clone(), record_id),
+        RecordOverrideClearedEvent {
+            patient,
+            grantee,
+            record_id,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_heartbeat_event() {
+    let ctx = setup();
+    ctx.env.ledger().set_sequence_number(42);
+    ctx.client.heartbeat(&ctx.admin);
+    assert_event(
+        &ctx.env,
+        (topics::HEARTBEAT, ctx.admin.clone()),
+        HeartbeatEvent {
+            admin: ctx.admin.clone(),
+            ledger: 42,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_revocation_grace_events() {
+    let ctx = setup();
+    let patient = addr(&ctx);
+    let grantee = addr(&ctx);
+    ctx.client.set_revocation_grace(&ctx.admin, &600);
+    ctx.client
+        .grant_access(&patient, &patient, &grantee, &AccessLevel::Read, &3_600);
+
+    ctx.client.revoke_access(&patient, &grantee);
+    assert_event(
+        &ctx.env,
+        (topics::REV_SCHED, patient.clone(), grantee.clone()),
+        RevocationScheduledEvent {
+            patient: patient.clone(),
+            grantee: grantee.clone(),
+            effective_at: NOW + 600,
+            grant_revision: 2,
+            access_epoch: 0,
+            timestamp: NOW,
+        },
+    );
+
+    ctx.client.cancel_revocation(&patient, &grantee);
+    assert_event(
+        &ctx.env,
+        (topics::REV_CNCL, patient.clone(), grantee.clone()),
+        RevocationCancelledEvent {
+            patient: patient.clone(),
+            grantee: grantee.clone(),
+            expires_at: NOW + 3_600,
+            grant_revision: 3,
+            access_epoch: 0,
+            timestamp: NOW,
+        },
+    );
+
+    ctx.client.revoke_access_immediate(&patient, &grantee);
+    assert_event(
+        &ctx.env,
+        (topics::REV_IMM, patient.clone(), grantee.clone()),
+        AccessRevokedImmediatelyEvent {
+            patient,
+            grantee,
+            grant_revision: 4,
+            access_epoch: 0,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_recovery_events() {
+    let ctx = setup();
+    let provider = register(&ctx, Role::Optometrist, "Dr. Who");
+    let old = register(&ctx, Role::Patient, "Pat");
+    let new = addr(&ctx);
+    let record_id = add_record(&ctx, &provider, &old);
+    ctx.client
+        .grant_access(&old, &old, &provider, &AccessLevel::Read, &(30 * 86_400));
+    let secret = Bytes::from_slice(&ctx.env, b"secret");
+    let challenge = ctx.env.crypto().sha256(&secret).to_bytes();
+
+    ctx.client
+        .initiate_recovery(&ctx.admin, &old, &new, &challenge);
+    assert_event(
+        &ctx.env,
+        (topics::RCV_INIT, old.clone(), new.clone()),
+        RecoveryInitiatedEvent {
+            old_patient: old.clone(),
+            new_patient: new.clone(),
+            initiated_by: ctx.admin.clone(),
+            unlocks_at: NOW + RECOVERY_TIMELOCK_SECONDS,
+            timestamp: NOW,
+        },
+    );
+
+    ctx.client.cancel_recovery(&old);
+    assert_event(
+        &ctx.env,
+        (topics::RCV_CNCL, old.clone(), new.clone()),
+        RecoveryCancelledEvent {
+            old_patient: old.clone(),
+            new_patient: new.clone(),
+            timestamp: NOW,
+        },
+    );
+
+    ctx.client
+        .initiate_recovery(&ctx.admin, &old, &new, &challenge);
+    let later = NOW + RECOVERY_TIMELOCK_SECONDS;
+    ctx.env.ledger().set_timestamp(later);
+    let confirm = ctx
+        .client
+        .compute_confirmation(&confirmation::FINALIZE_RECOVERY, &old);
+    ctx.client
+        .finalize_recovery(&ctx.admin, &old, &secret, &confirm, &10);
+    assert_event(
+        &ctx.env,
+        (topics::RCV_REC, old.clone(), new.clone()),
+        RecoveryRecordReboundEvent {
+            old_patient: old.clone(),
+            new_patient: new.clone(),
+            record_id,
+            timestamp: later,
+        },
+    );
+    assert_event(
+        &ctx.env,
+        (topics::RCV_GRT, old.clone(), new.clone()),
+        RecoveryGrantReboundEvent {
+            old_patient: old.clone(),
+            new_patient: new.clone(),
+            grantee: provider,
+            expires_at: NOW + 30 * 86_400,
+            timestamp: later,
+        },
+    );
+    assert_event(
+        &ctx.env,
+        (topics::RCV_USER, old.clone(), new.clone()),
+        RecoveryUserReboundEvent {
+            old_patient: old.clone(),
+            new_patient: new.clone(),
+            role: Role::Patient,
+            timestamp: later,
+        },
+    );
+    assert_event(
+        &ctx.env,
+        (topics::RCV_FIN, old.clone(), new.clone()),
+        RecoveryFinalizedEvent {
+            old_patient: old,
+            new_patient: new,
+            finalized_by: ctx.admin.clone(),
+            records_rebound: 1,
+            grants_rebound: 1,
+            timestamp: later,
+        },
+    );
+}
+
+#[test]
+fn test_dispute_events() {
+    let ctx = setup();
+    let provider = register(&ctx, Role::Optometrist, "Dr. Who");
+    let patient = register(&ctx, Role::Patient, "Pat");
+    let record_id = add_record(&ctx, &provider, &patient);
+    let reason = String::from_str(&ctx.env, HASH_A);
+    let response = String::from_str(&ctx.env, HASH_B);
+
+    ctx.client.flag_record(&patient, &record_id, &reason);
+    assert_event(
+        &ctx.env,
+        (topics::DSP_OPEN, patient.clone(), record_id),
+        DisputeOpenedEvent {
+            patient: patient.clone(),
+            record_id,
+            reason_hash: reason,
+            timestamp: NOW,
+        },
+    );
+
+    ctx.client
+        .respond_to_dispute(&provider, &record_id, &response);
+    assert_event(
+        &ctx.env,
+        (topics::DSP_RESP, provider.clone(), record_id),
+        DisputeRespondedEvent {
+            provider: provider.clone(),
+            record_id,
+            response_hash: response,
+            timestamp: NOW,
+        },
+    );
+
+    ctx.client
+        .resolve_dispute(&provider, &record_id, &DisputeResolution::Amended);
+    assert_event(
+        &ctx.env,
+        (topics::DSP_RSLV, provider.clone(), record_id),
+        DisputeResolvedEvent {
+            resolved_by: provider,
+            record_id,
+            resolution: DisputeResolution::Amended,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_patient_access_freeze_events() {
+    let ctx = setup();
+    let patient = register(&ctx, Role::Patient, "Pat");
+
+    ctx.client.freeze_my_access(&patient, &true);
+    assert_event(
+        &ctx.env,
+        (topics::PAT_FRZ, patient.clone()),
+        PatientAccessFrozenEvent {
+            patient: patient.clone(),
+            allow_emergency: true,
+            access_epoch: 1,
+            timestamp: NOW,
+        },
+    );
+
+    ctx.client.unfreeze_my_access(&patient);
+    assert_event(
+        &ctx.env,
+        (topics::PAT_UNFRZ, patient.clone()),
+        PatientAccessUnfrozenEvent {
+            patient,
+            access_epoch: 2,
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_observer_registration_events() {
+    let ctx = setup();
+    let observer = addr(&ctx);
+    let subscribed = Vec::from_array(&ctx.env, [symbol_short!("REC_ADD")]);
+
+    ctx.client
+        .register_observer(&ctx.admin, &observer, &subscribed);
+    assert_event(
+        &ctx.env,
+        (topics::OBS_REG, observer.clone()),
+        ObserverRegisteredEvent {
+            observer: observer.clone(),
+            events: subscribed,
+            registered_by: ctx.admin.clone(),
+            timestamp: NOW,
+        },
+    );
+
+    ctx.client.unregister_observer(&ctx.admin, &observer);
+    assert_event(
+        &ctx.env,
+        (topics::OBS_UNREG, observer.clone()),
+        ObserverUnregisteredEvent {
+            observer,
+            unregistered_by: ctx.admin.clone(),
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_legacy_read_mode_event() {
+    let ctx = setup();
+    ctx.client
+        .set_legacy_read_mode(&ctx.admin, &LegacyReadMode::AdminOnly);
+    assert_event(
+        &ctx.env,
+        (topics::LEG_READ,),
+        LegacyReadModeChangedEvent {
+            mode: LegacyReadMode::AdminOnly,
+            changed_by: ctx.admin.clone(),
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_deactivation_events() {
+    let ctx = setup();
+    let provider = register(&ctx, Role::Optometrist, "Dr A");
+    let patient = register(&ctx, Role::Patient, "Pat A");
+    ctx.client
+        .delegate_role(&provider, &addr(&ctx), &Role::Optometrist, &0);
+    ctx.client
+        .grant_access(&patient, &patient, &provider, &AccessLevel::Read, &3_600);
+
+    ctx.client
+        .deactivate_user(&ctx.admin, &provider, &DeactivationCascade::RevokeAll, &10);
+    assert_event(
+        &ctx.env,
+        (topics::USR_DEACT, provider.clone()),
+        UserDeactivatedEvent {
+            user: provider.clone(),
+            cascade: DeactivationCascade::RevokeAll,
+            deactivated_by: ctx.admin.clone(),
+            timestamp: NOW,
+        },
+    );
+    assert_event(
+        &ctx.env,
+        (topics::DLG_CLEAN, provider.clone()),
+        DelegationsCleanedEvent {
+            user: provider.clone(),
+            revoked: 1,
+            revoked_by: ctx.admin.clone(),
+            timestamp: NOW,
+        },
+    );
+    assert_event(
+        &ctx.env,
+        (topics::GRT_CLEAN, provider.clone()),
+        GrantsCleanedEvent {
+            user: provider.clone(),
+            revoked: 1,
+            revoked_by: ctx.admin.clone(),
+            timestamp: NOW,
+        },
+    );
+
+    ctx.client.reactivate_user(&ctx.admin, &provider);
+    assert_event(
+        &ctx.env,
+        (topics::USR_REACT, provider.clone()),
+        UserReactivatedEvent {
+            user: provider,
+            reactivated_by: ctx.admin.clone(),
+            timestamp: NOW,
+        },
+    );
+}
+
+#[test]
+fn test_topic_symbols_are_unique() {
+    let all: [Symbol; 110] = [
+        topics::ADM_PROP,
+        topics::ADM_ACPT,
+        topics::ADM_CNCL,
+        topics::INIT,
+        topics::USR_REG,
+        topics::REC_ADD,
+        topics::ACC_GRT,
+        topics::REC_GRT,
+        topics::ACC_REV,
+        topics::BATCH_R,
+        topics::PAUSE,
+        topics::RESUME,
+        topics::ACC_VIOL,
+        topics::ACC_EXP,
+        topics::PROV_REG,
+        topics::PROV_VER,
+        topics::BATCH_A,
+        topics::EXAM_ADD,
+        topics::META_GRT,
+        topics::CST_GRT,
+        topics::CST_REV,
+        topics::PROF_C,
+        topics::PROF_U,
+        topics::ERROR,
+        topics::EMRG_GRT,
+        topics::EMRG_REV,
+        topics::EMRG_NOT,
+        topics::EMRG_USE,
+        topics::APPT_SCH,
+        topics::APPT_CFM,
+        topics::APPT_CNL,
+        topics::APPT_RSCH,
+        topics::APPT_CMP,
+        topics::APPT_RMD,
+        topics::APPT_VER,
+        topics::AUDIT,
+        topics::RL_EXCD,
+        topics::RL_CONFIG,
+        topics::RL_BYPASS,
+        topics::POL_CRT,
+        topics::CRED_SET,
+        topics::SENS_SET,
+        topics::PRIO_CHG,
+        topics::ACC_SWEEP,
+        topics::USR_FRZ,
+        topics::USR_UNFRZ,
+        topics::GRT_PEND,
+        topics::GRT_APPR,
+        topics::GRT_FORCE,
+        topics::REC_SUPER,
+        topics::ACC_OFR,
+        topics::ACC_ACPT,
+        topics::ACC_DECL,
+        topics::HASH_REG,
+        topics::CLM_ATT,
+        topics::CLM_VOID,
+        topics::ADMIN_OP,
+        topics::SESS_NEW,
+        topics::SESS_REV,
+        topics::XREF_SET,
+        topics::PROV_CLM,
+        topics::BRCH_SET,
+        topics::BRCH_CLR,
+        topics::SIGN_KEY,
+        topics::GRT_RLY,
+        topics::GRT_MIG,
+        topics::GRT_AUTO,
+        topics::VER_PIN,
+        topics::VER_UNPIN,
+        topics::USR_DEACT,
+        topics::USR_REACT,
+        topics::DLG_CLEAN,
+        topics::GRT_CLEAN,
+        topics::EMB_SET,
+        topics::EMB_CLEAR,
+        topics::GRT_OVCAP,
+        topics::SHR_NEW,
+        topics::SHR_RDM,
+        topics::SHR_CNCL,
+        topics::PEND_GC,
+        topics::OVR_SET,
+        topics::OVR_CLEAR,
+        topics::HEARTBEAT,
+        topics::REV_SCHED,
+        topics::REV_CNCL,
+        topics::REV_IMM,
+        topics::RCV_INIT,
+        topics::RCV_CNCL,
+        topics::RCV_REC,
+        topics::RCV_GRT,
+        topics::RCV_USER,
+        topics::RCV_FIN,
+        topics::DSP_OPEN,
+        topics::DSP_RESP,
+        topics::DSP_RSLV,
+        topics::PAT_FRZ,
+        topics::PAT_UNFRZ,
+        topics::OBS_REG,
+        topics::OBS_UNREG,
+        topics::OBS_FAIL,
+        topics::LEG_READ,
+        topics::VER_RDCT,
+        topics::ORG_GRANT,
+        topics::ORG_RVK,
+        topics::WS_OPEN,
+        topics::WS_CLOSE,
+        topics::RES_OVRD,
+        topics::EXP_BCN,
+        topics::ADM_READ,
+        topics::VER_DIFF,
+    ];
+    for (i, a) in all.iter().enumerate() {
+        for b in all.iter().skip(i + 1) {
+            assert_ne!(a, b);
+        }
+    }
+}
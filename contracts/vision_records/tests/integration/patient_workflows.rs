@@ -222,14 +222,9 @@ fn test_patient_cannot_grant_self_access() {
     let ctx = setup_test_env();
     let patient = create_test_user(&ctx, vision_records::Role::Patient, "Patient");
 
-    // Patient already has access to their own records, but explicit grant should work
-    // (The system allows this, but it's redundant)
-    ctx.client
-        .grant_access(&patient, &patient, &patient, &AccessLevel::Full, &86400u64);
-
-    // Verify access
-    assert_eq!(
-        ctx.client.check_access(&patient, &patient),
-        AccessLevel::Full
-    );
+    // Patient already has access to their own records, so a self-grant is rejected
+    let result =
+        ctx.client
+            .try_grant_access(&patient, &patient, &patient, &AccessLevel::Full, &86400u64);
+    assert_eq!(result, Err(Ok(vision_records::ContractError::InvalidInput)));
 }
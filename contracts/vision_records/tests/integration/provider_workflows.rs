@@ -2,7 +2,7 @@
 
 use super::{create_test_user, setup_test_env};
 use soroban_sdk::{testutils::Address as _, Address, String, Vec};
-use vision_records::{RecordType, Role, VerificationStatus};
+use vision_records::{record_counter, RecordType, Role, VerificationStatus};
 
 /// Test complete provider onboarding workflow
 #[test]
@@ -214,7 +214,7 @@ fn test_provider_create_records_workflow() {
         &hash1,
     );
 
-    assert_eq!(record_id1, 1);
+    assert_eq!(record_counter::split(record_id1).1, 1);
 
     // Provider creates prescription record
     let hash2 = String::from_str(&ctx.env, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdH");
@@ -226,7 +226,7 @@ fn test_provider_create_records_workflow() {
         &hash2,
     );
 
-    assert_eq!(record_id2, 2);
+    assert_eq!(record_id2, record_id1 + 1);
 
     // Verify records exist
     let record1 = ctx.client.get_record(&provider, &record_id1);
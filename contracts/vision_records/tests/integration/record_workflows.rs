@@ -2,7 +2,7 @@
 
 use super::{create_test_user, setup_test_env};
 use soroban_sdk::{testutils::Ledger, String};
-use vision_records::{AccessLevel, RecordType, Role};
+use vision_records::{record_counter, AccessLevel, RecordType, Role};
 
 /// Test complete record creation workflow
 #[test]
@@ -28,7 +28,7 @@ fn test_record_creation_workflow() {
         &data_hash,
     );
 
-    assert_eq!(record_id, 1);
+    assert_eq!(record_counter::split(record_id).1, 1);
 
     // Verify record exists
     let record = ctx.client.get_record(&provider, &record_id);
@@ -156,10 +156,10 @@ fn test_multiple_record_types_workflow() {
     );
 
     // Verify all records exist
-    assert_eq!(exam_id, 1);
-    assert_eq!(presc_id, 2);
-    assert_eq!(diag_id, 3);
-    assert_eq!(treat_id, 4);
+    assert_eq!(record_counter::split(exam_id).1, 1);
+    assert_eq!(presc_id, exam_id + 1);
+    assert_eq!(diag_id, exam_id + 2);
+    assert_eq!(treat_id, exam_id + 3);
 
     // Verify record types
     let exam_record = ctx.client.get_record(&patient, &exam_id);
@@ -26,7 +26,7 @@ fn setup() -> (Env, VisionRecordsContractClient<'static>) {
     let client = VisionRecordsContractClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
-    client.initialize(&admin);
+    client.initialize(&admin, &None);
 
     (env, client)
 }
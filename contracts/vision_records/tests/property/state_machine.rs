@@ -16,7 +16,7 @@ use proptest::prelude::*;
 use soroban_sdk::testutils::Address as _;
 use soroban_sdk::{Address, Env, String};
 use vision_records::{
-    AccessLevel, ContractError, RecordType, Role, VisionRecordsContract,
+    record_counter, AccessLevel, ContractError, RecordType, Role, VisionRecordsContract,
     VisionRecordsContractClient,
 };
 
@@ -30,7 +30,7 @@ fn setup() -> (Env, VisionRecordsContractClient<'static>, Address) {
     let client = VisionRecordsContractClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
-    client.initialize(&admin);
+    client.initialize(&admin, &None);
 
     (env, client, admin)
 }
@@ -45,7 +45,7 @@ proptest! {
 
         // Contract is already initialized — a second call must error
         let second_admin = Address::generate(&env);
-        let result = client.try_initialize(&second_admin);
+        let result = client.try_initialize(&second_admin, &None);
 
         prop_assert!(result.is_err(), "Double initialize must always fail");
         match result {
@@ -71,7 +71,7 @@ proptest! {
         let client = VisionRecordsContractClient::new(&env, &contract_id);
 
         let admin = Address::generate(&env);
-        client.initialize(&admin);
+        client.initialize(&admin, &None);
 
         prop_assert_eq!(client.get_admin(), admin);
     }
@@ -109,8 +109,8 @@ proptest! {
             &hash,
         );
 
-        // Record ID must equal 1 for the first record
-        prop_assert_eq!(record_id, 1u64);
+        // First record in the provider's counter shard
+        prop_assert_eq!(record_counter::split(record_id).1, 1u64);
 
         // Stored record must match inputs
         let record = client.get_record(&provider, &record_id);
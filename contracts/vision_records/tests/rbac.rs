@@ -181,7 +181,13 @@ fn test_role_assignment_expiration() {
     ctx.env.ledger().set_timestamp(100);
     let expire_at = 100;
     ctx.env.as_contract(&ctx.client.address, || {
-        vision_records::rbac::assign_role(&ctx.env, user.clone(), Role::Optometrist, expire_at);
+        vision_records::rbac::assign_role(
+            &ctx.env,
+            user.clone(),
+            Role::Optometrist,
+            expire_at,
+            &user,
+        );
     });
 
     // At timestamp 100, role is EXPIRED (must be strictly > 100)
@@ -193,7 +199,7 @@ fn test_role_assignment_expiration() {
 
     // Test infinite duration `expires_at == 0` bound
     ctx.env.as_contract(&ctx.client.address, || {
-        vision_records::rbac::assign_role(&ctx.env, user.clone(), Role::Optometrist, 0);
+        vision_records::rbac::assign_role(&ctx.env, user.clone(), Role::Optometrist, 0, &user);
     });
 
     // Jump forward in time 10 years to ensure it never expires
@@ -28,7 +28,7 @@ fuzz_target!(|actions: Vec<FuzzAction>| {
     let client = VisionRecordsContractClient::new(&env, &contract_id);
 
     // Initialize
-    let _ = client.try_initialize(&admin);
+    let _ = client.try_initialize(&admin, &None);
 
     let mut users = vec![admin.clone()];
     let provider = Address::generate(&env);